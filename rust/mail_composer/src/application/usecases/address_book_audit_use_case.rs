@@ -0,0 +1,272 @@
+use crate::domain::interfaces::address_book::AddressBookPort;
+use chrono::{Local, NaiveDate};
+use serde::Serialize;
+use share::error::app_error::AppResult;
+use std::collections::BTreeMap;
+
+/// アドレスブックの監査で検出された問題
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AddressBookIssue {
+    /// 名前やメールアドレスが空のエントリ
+    EmptyField { name: String, field: String },
+    /// 構文的に不正なメールアドレス
+    InvalidAddress {
+        name: String,
+        address: String,
+        reason: String,
+    },
+    /// 異なる名前に同じメールアドレスが登録されている
+    DuplicateAddress { address: String, names: Vec<String> },
+    /// グループのメンバーがアドレスブックに存在しない
+    UnknownMember { group: String, member: String },
+    /// 有効期間が終了したエントリ
+    Expired {
+        name: String,
+        valid_until: NaiveDate,
+    },
+    /// グループのメンバーが有効期間外のため、グループの展開時に除外される
+    OutOfPeriodMember { group: String, member: String },
+}
+
+impl std::fmt::Display for AddressBookIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyField { name, field } => write!(f, "{name}: {field}が空です"),
+            Self::InvalidAddress {
+                name,
+                address,
+                reason,
+            } => write!(f, "{name}: メールアドレスが不正です（{address}: {reason}）"),
+            Self::DuplicateAddress { address, names } => write!(
+                f,
+                "{address}: 複数の名前に登録されています（{}）",
+                names.join(", ")
+            ),
+            Self::UnknownMember { group, member } => {
+                write!(f, "{group}: メンバー{member}がアドレスブックに存在しません")
+            }
+            Self::Expired { name, valid_until } => {
+                write!(f, "{name}: 有効期間が終了しています（{valid_until}まで）")
+            }
+            Self::OutOfPeriodMember { group, member } => write!(
+                f,
+                "{group}: メンバー{member}は有効期間外のため、宛先から除外されます"
+            ),
+        }
+    }
+}
+
+/// アドレスブックの監査結果
+#[derive(Debug, Default, Serialize)]
+pub struct AddressBookAuditReport {
+    /// 監査したエントリ数
+    pub total_entries: usize,
+    /// 検出された問題
+    pub issues: Vec<AddressBookIssue>,
+}
+
+impl AddressBookAuditReport {
+    /// 問題が検出されなかったかどうか
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// アドレスブックの内容を検証するユースケース
+pub struct AddressBookAuditUseCase<A: AddressBookPort> {
+    address_book_port: A,
+}
+
+impl<A: AddressBookPort> AddressBookAuditUseCase<A> {
+    /// 新しいAddressBookAuditUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `address_book_port` - 監査対象のアドレスブック
+    ///
+    /// ## Returns
+    /// * AddressBookAuditUseCaseのインスタンス
+    pub fn new(address_book_port: A) -> Self {
+        Self { address_book_port }
+    }
+
+    /// アドレスブックの全てのエントリを検証する
+    ///
+    /// 空のフィールド、不正なメールアドレス、異なる名前間でのメールアドレスの重複、
+    /// 存在しないグループメンバー、有効期間が終了したエントリ、
+    /// グループの展開時に除外される有効期間外のメンバーを検出する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<AddressBookAuditReport>`
+    /// * 失敗時 - エントリの一覧を取得できない場合の`Err<AppError>`
+    pub fn audit(&self) -> AppResult<AddressBookAuditReport> {
+        let entries = self.address_book_port.list_entries()?;
+        let mut issues = Vec::new();
+        let mut names_by_address: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let today = Local::now().date_naive();
+
+        for entry in &entries {
+            if entry.name.trim().is_empty() {
+                issues.push(AddressBookIssue::EmptyField {
+                    name: entry.name.clone(),
+                    field: "name".to_string(),
+                });
+            }
+
+            if let Some(valid_until) = entry.valid_until
+                && valid_until < today
+            {
+                issues.push(AddressBookIssue::Expired {
+                    name: entry.name.clone(),
+                    valid_until,
+                });
+            }
+
+            match &entry.address {
+                Some(address) if address.trim().is_empty() => {
+                    issues.push(AddressBookIssue::EmptyField {
+                        name: entry.name.clone(),
+                        field: "address".to_string(),
+                    });
+                }
+                Some(address) => {
+                    if let Err(reason) = check_address_syntax(address) {
+                        issues.push(AddressBookIssue::InvalidAddress {
+                            name: entry.name.clone(),
+                            address: address.clone(),
+                            reason: reason.to_string(),
+                        });
+                    }
+                    names_by_address
+                        .entry(address.trim().to_lowercase())
+                        .or_default()
+                        .push(entry.name.clone());
+                }
+                None => {
+                    for member in &entry.members {
+                        match entries
+                            .iter()
+                            .find(|e| &e.name == member || e.aliases.contains(member))
+                        {
+                            None => issues.push(AddressBookIssue::UnknownMember {
+                                group: entry.name.clone(),
+                                member: member.clone(),
+                            }),
+                            Some(found) if !found.is_valid_on(today) => {
+                                issues.push(AddressBookIssue::OutOfPeriodMember {
+                                    group: entry.name.clone(),
+                                    member: member.clone(),
+                                });
+                            }
+                            Some(_) => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        issues.extend(
+            names_by_address
+                .into_iter()
+                .filter(|(_, names)| names.len() > 1)
+                .map(|(address, names)| AddressBookIssue::DuplicateAddress { address, names }),
+        );
+
+        Ok(AddressBookAuditReport {
+            total_entries: entries.len(),
+            issues,
+        })
+    }
+}
+
+/// メールアドレスの構文を検証する
+///
+/// ## Returns
+/// * 成功時 - `Ok(())`
+/// * 失敗時 - 不正な理由
+fn check_address_syntax(address: &str) -> Result<(), &'static str> {
+    if address.chars().any(char::is_whitespace) {
+        return Err("空白を含んでいます");
+    }
+    let Some((local, domain)) = address.split_once('@') else {
+        return Err("@がありません");
+    };
+    if local.is_empty() || domain.is_empty() {
+        return Err("@の前後が空です");
+    }
+    if domain.contains('@') {
+        return Err("@が複数あります");
+    }
+    if !domain.contains('.') || domain.starts_with('.') || domain.ends_with('.') {
+        return Err("ドメインの形式が不正です");
+    }
+    if local.starts_with('.') || local.ends_with('.') || address.contains("..") {
+        return Err("ドットの位置が不正です");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter;
+
+    #[test]
+    fn test_audit() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "山田太郎", "address": "Yamada@example.com" },
+                { "name": "佐藤さん", "address": "sato@@example" },
+                { "name": "鈴木さん", "address": "" },
+                { "name": "開発チーム", "members": ["山田さん", "田中さん"] }
+            ]"#,
+        )
+        .unwrap();
+
+        let report = AddressBookAuditUseCase::new(adapter).audit().unwrap();
+
+        assert_eq!(report.total_entries, 5);
+        assert_eq!(report.issues.len(), 4);
+        assert!(report.issues.contains(&AddressBookIssue::EmptyField {
+            name: "鈴木さん".to_string(),
+            field: "address".to_string(),
+        }));
+        assert!(report.issues.contains(&AddressBookIssue::UnknownMember {
+            group: "開発チーム".to_string(),
+            member: "田中さん".to_string(),
+        }));
+        assert!(report.issues.contains(&AddressBookIssue::DuplicateAddress {
+            address: "yamada@example.com".to_string(),
+            names: vec!["山田さん".to_string(), "山田太郎".to_string()],
+        }));
+        assert!(matches!(
+            &report.issues[0],
+            AddressBookIssue::InvalidAddress { name, .. } if name == "佐藤さん"
+        ));
+    }
+
+    #[test]
+    fn test_audit_reports_out_of_period_members() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com", "valid_until": "2000-03-31" },
+                { "name": "田中さん", "address": "tanaka@example.com" },
+                { "name": "開発チーム", "members": ["山田さん", "田中さん"] }
+            ]"#,
+        )
+        .unwrap();
+
+        let report = AddressBookAuditUseCase::new(adapter).audit().unwrap();
+
+        assert!(
+            report
+                .issues
+                .contains(&AddressBookIssue::OutOfPeriodMember {
+                    group: "開発チーム".to_string(),
+                    member: "山田さん".to_string(),
+                })
+        );
+        assert_eq!(report.issues.len(), 2);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::domain::{
+    entities::address_book::AddressBookEntry,
+    interfaces::address_book::{AddressBookPort, AddressBookWritePort},
+};
+use share::{error::app_error::AppResult, logging};
+
+/// 取り込み元と取り込み先で同じ名前に異なる内容が登録されているエントリ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportConflict {
+    /// 取り込み先に登録済みのエントリ
+    pub existing: AddressBookEntry,
+    /// 取り込み元のエントリ
+    pub imported: AddressBookEntry,
+}
+
+/// アドレスブックの取り込み結果
+#[derive(Debug, Default)]
+pub struct AddressBookImportReport {
+    /// 新たに追加された名前
+    pub added: Vec<String>,
+    /// 取り込み先と同じ内容のため変更しなかった名前
+    pub unchanged: Vec<String>,
+    /// 内容が異なるエントリ（上書きした場合も含む）
+    pub conflicts: Vec<ImportConflict>,
+    /// 競合したエントリを取り込み元の内容で上書きしたかどうか
+    pub overwritten: bool,
+}
+
+/// 他形式のアドレスブック（Outlookの連絡先CSVなど）を取り込むユースケース
+pub struct AddressBookImportUseCase<S, W>
+where
+    S: AddressBookPort,
+    W: AddressBookWritePort,
+{
+    source_port: S,
+    target_port: W,
+}
+
+impl<S, W> AddressBookImportUseCase<S, W>
+where
+    S: AddressBookPort,
+    W: AddressBookWritePort,
+{
+    /// 新しいAddressBookImportUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `source_port` - 取り込み元のアドレスブック
+    /// * `target_port` - 取り込み先のアドレスブック
+    ///
+    /// ## Returns
+    /// * AddressBookImportUseCaseのインスタンス
+    pub fn new(source_port: S, target_port: W) -> Self {
+        Self {
+            source_port,
+            target_port,
+        }
+    }
+
+    /// 取り込み元のエントリを取り込み先にマージして保存する
+    ///
+    /// 取り込み先にない名前は追加し、同じ名前で内容が異なるものは競合として報告する
+    ///
+    /// ## Arguments
+    /// * `overwrite` - 競合したエントリを取り込み元の内容で上書きするかどうか
+    /// * `is_dry_run` - ドライランモード（true の場合、取り込み先を保存しない）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<AddressBookImportReport>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn import(
+        &mut self,
+        overwrite: bool,
+        is_dry_run: bool,
+    ) -> AppResult<AddressBookImportReport> {
+        let _span = logging::operation_span("address_book_import").entered();
+        let existing_entries = self.target_port.list_entries()?;
+        let mut report = AddressBookImportReport {
+            overwritten: overwrite,
+            ..Default::default()
+        };
+
+        for imported in self.source_port.list_entries()? {
+            let name = imported.name.clone();
+            match existing_entries.iter().find(|e| e.name == name) {
+                None => {
+                    self.target_port.add_entry(imported)?;
+                    report.added.push(name);
+                }
+                Some(existing)
+                    if existing.address == imported.address
+                        && existing.members == imported.members =>
+                {
+                    report.unchanged.push(name);
+                }
+                Some(existing) => {
+                    if overwrite {
+                        // 取り込み先で設定された別名と表示名は維持する
+                        let merged = AddressBookEntry {
+                            aliases: existing.aliases.clone(),
+                            display_name: existing.display_name.clone(),
+                            ..imported.clone()
+                        };
+                        self.target_port.update_entry(&name, merged)?;
+                    }
+                    report.conflicts.push(ImportConflict {
+                        existing: existing.clone(),
+                        imported,
+                    });
+                }
+            }
+        }
+
+        if !is_dry_run {
+            self.target_port.save()?;
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbound::{
+        json_address_book_adapter::JsonAddressBookAdapter,
+        outlook_csv_address_book_adapter::OutlookCsvAddressBookAdapter,
+    };
+
+    #[test]
+    fn test_import() {
+        let source = OutlookCsvAddressBookAdapter::from_csv_str(
+            "E-mail Display Name,E-mail Address\n\
+             山田さん,yamada@example.com\n\
+             佐藤さん,sato.new@example.com\n\
+             鈴木さん,suzuki@example.com\n",
+        )
+        .unwrap();
+        let target = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "佐藤さん", "address": "sato@example.com" }
+            ]"#,
+        )
+        .unwrap();
+
+        let mut use_case = AddressBookImportUseCase::new(source, target);
+        let report = use_case.import(false, true).unwrap();
+
+        assert_eq!(report.added, vec!["鈴木さん"]);
+        assert_eq!(report.unchanged, vec!["山田さん"]);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(
+            use_case.target_port.resolve("佐藤さん").unwrap().as_str(),
+            "sato@example.com"
+        );
+        assert_eq!(
+            use_case.target_port.resolve("鈴木さん").unwrap().as_str(),
+            "suzuki@example.com"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::domain::{
+    entities::address_book::AddressBookEntry,
+    interfaces::address_book::AddressBookWritePort,
+    value_objects::{address_query::AddressQuery, email_address::EmailAddress},
+};
+use share::{error::app_error::AppResult, logging};
+
+/// アドレスブックのエントリを一覧・検索・追加・削除するユースケース
+///
+/// 追加・削除した内容はその都度アドレスブックに保存する
+pub struct AddressBookManagementUseCase<A: AddressBookWritePort> {
+    address_book_port: A,
+}
+
+impl<A: AddressBookWritePort> AddressBookManagementUseCase<A> {
+    /// 新しいAddressBookManagementUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `address_book_port` - 編集対象のアドレスブック
+    ///
+    /// ## Returns
+    /// * AddressBookManagementUseCaseのインスタンス
+    pub fn new(address_book_port: A) -> Self {
+        Self { address_book_port }
+    }
+
+    /// 全てのエントリを登録順に取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<AddressBookEntry>>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn list(&self) -> AppResult<Vec<AddressBookEntry>> {
+        self.address_book_port.list_entries()
+    }
+
+    /// 検索条件に一致するエントリを取得する
+    ///
+    /// ## Arguments
+    /// * `query` - 検索条件
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<AddressBookEntry>>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        self.address_book_port.search(query)
+    }
+
+    /// エントリを追加して保存する
+    ///
+    /// ## Arguments
+    /// * `entry` - 追加するエントリ
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - メールアドレスが不正な場合、名前や別名が重複する場合、または保存に失敗した場合の`Err<AppError>`
+    pub fn add(&mut self, entry: AddressBookEntry) -> AppResult<()> {
+        let _span = logging::operation_span("address_book_add").entered();
+        if let Some(address) = &entry.address {
+            EmailAddress::parse(address.as_str())?;
+        }
+        self.address_book_port.add_entry(entry)?;
+        self.address_book_port.save()
+    }
+
+    /// 名前に対応するエントリを削除して保存する
+    ///
+    /// ## Arguments
+    /// * `name` - 削除するエントリの名前
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<AddressBookEntry>` (削除したエントリ)
+    /// * 失敗時 - 名前が見つからない場合、または保存に失敗した場合の`Err<AppError>`
+    pub fn remove(&mut self, name: &str) -> AppResult<AddressBookEntry> {
+        let _span = logging::operation_span("address_book_remove").entered();
+        let removed = self.address_book_port.remove_entry(name)?;
+        self.address_book_port.save()?;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter;
+    use std::fs;
+
+    #[test]
+    fn test_add_and_remove() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_address_book_management_{}",
+            std::process::id()
+        ));
+        let path = dir.join("address_book.json");
+        let mut use_case = AddressBookManagementUseCase::new(
+            JsonAddressBookAdapter::load_or_empty(&path).unwrap(),
+        );
+
+        use_case
+            .add(AddressBookEntry::person("山田", "yamada@example.com"))
+            .unwrap();
+        use_case
+            .add(AddressBookEntry::person("田中", "tanaka@example.com"))
+            .unwrap();
+        let invalid = use_case.add(AddressBookEntry::person("佐藤", "not-an-address"));
+        let duplicate = use_case.add(AddressBookEntry::person("山田", "other@example.com"));
+        let found = use_case
+            .search(&AddressQuery::new().with_name_contains("田中"))
+            .unwrap();
+        let removed = use_case.remove("山田").unwrap();
+        let saved = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(invalid.is_err());
+        assert!(duplicate.is_err());
+        assert_eq!(found.len(), 1);
+        assert_eq!(removed.address.as_deref(), Some("yamada@example.com"));
+        assert_eq!(use_case.list().unwrap().len(), 1);
+        assert!(saved.contains("tanaka@example.com"));
+        assert!(!saved.contains("yamada@example.com"));
+    }
+
+    #[test]
+    fn test_add_keeps_env_var_references() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_address_book_management_raw_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("address_book.json");
+        fs::write(
+            &path,
+            r#"[{ "name": "上長", "address": "${MAIL_COMPOSER_UNSET_BOSS:-boss}@example.com" }]"#,
+        )
+        .unwrap();
+        let mut use_case = AddressBookManagementUseCase::new(
+            JsonAddressBookAdapter::load_from_address_book(&path).unwrap(),
+        );
+
+        use_case
+            .add(AddressBookEntry::person("山田", "yamada@example.com"))
+            .unwrap();
+        let saved = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(saved.contains("${MAIL_COMPOSER_UNSET_BOSS:-boss}@example.com"));
+        assert!(!saved.contains("\"boss@example.com\""));
+    }
+}
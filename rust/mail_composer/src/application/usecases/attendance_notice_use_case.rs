@@ -0,0 +1,157 @@
+use crate::{
+    application::usecases::generic_mail_use_case::GenericMailUseCase,
+    domain::{
+        interfaces::{
+            address_book::AddressBookPort, configuration::ConfigurationPort,
+            confirmation::ConfirmationPort, mail_client::MailClientPort,
+            mail_config::MailConfigPort, work_time::WorkTimePort,
+        },
+        value_objects::mail_objects::WorkTime,
+    },
+};
+use chrono::NaiveDate;
+use share::{error::app_error::AppResult, logging};
+use std::collections::HashMap;
+
+/// 遅刻・早退連絡の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttendanceNoticeKind {
+    /// 遅刻（出社予定時刻を連絡する）
+    LateArrival,
+    /// 早退（退社予定時刻を連絡する）
+    EarlyLeave,
+}
+
+impl AttendanceNoticeKind {
+    /// テンプレートのメール種別
+    pub fn mail_type(self) -> &'static str {
+        match self {
+            Self::LateArrival => "late_arrival",
+            Self::EarlyLeave => "early_leave",
+        }
+    }
+
+    /// 作業記録の備考に使用する表記
+    fn label(self) -> &'static str {
+        match self {
+            Self::LateArrival => "遅刻連絡（出社予定",
+            Self::EarlyLeave => "早退連絡（退社予定",
+        }
+    }
+}
+
+/// 遅刻・早退連絡メール作成のユースケース
+///
+/// `mail_templates.json`の`late_arrival`または`early_leave`を使用し、件名・本文の`{expected_time}`に
+/// 出社・退社予定時刻、`{reason}`に理由を埋め込む。送信後、作業記録の備考に連絡内容を記録する
+pub struct AttendanceNoticeUseCase<A, C, M, MC, CF, W>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+    W: WorkTimePort,
+{
+    mail_use_case: GenericMailUseCase<A, C, M, MC, CF>,
+    work_time_port: W,
+}
+
+impl<A, C, M, MC, CF, W> AttendanceNoticeUseCase<A, C, M, MC, CF, W>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+    W: WorkTimePort,
+{
+    /// 新しいAttendanceNoticeUseCaseを作成する
+    pub fn new(
+        address_book_port: A,
+        configuration_port: C,
+        mail_client_port: M,
+        mail_config_port: MC,
+        confirmation_port: CF,
+        work_time_port: W,
+    ) -> Self {
+        Self {
+            mail_use_case: GenericMailUseCase::new(
+                address_book_port,
+                configuration_port,
+                mail_client_port,
+                mail_config_port,
+                confirmation_port,
+            ),
+            work_time_port,
+        }
+    }
+
+    /// 遅刻・早退連絡メールを作成・送信し、作業記録に備考として記録する
+    ///
+    /// ドライランの場合は作業記録を変更しない
+    ///
+    /// ## Arguments
+    /// * `kind` - 遅刻または早退
+    /// * `date` - 対象日付
+    /// * `expected_time` - 出社・退社予定時刻
+    /// * `reason` - 理由
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - テンプレートが見つからない場合、送信に失敗した場合、または記録に失敗した場合の`Err<AppError>`
+    pub fn send_notice(
+        &self,
+        kind: AttendanceNoticeKind,
+        date: NaiveDate,
+        expected_time: &WorkTime,
+        reason: &str,
+        is_dry_run: bool,
+    ) -> AppResult<()> {
+        let _span = logging::operation_span("attendance_notice").entered();
+        let extra_vars = HashMap::from([
+            (
+                "expected_time".to_string(),
+                expected_time.as_str().to_string(),
+            ),
+            ("reason".to_string(), reason.trim().to_string()),
+        ]);
+        self.mail_use_case
+            .send(kind.mail_type(), &extra_vars, is_dry_run)?;
+
+        if !is_dry_run {
+            self.work_time_port
+                .annotate(date, &notice_note(kind, expected_time, reason))?;
+        }
+        Ok(())
+    }
+}
+
+/// 作業記録の備考に記録する連絡内容を作成する
+fn notice_note(kind: AttendanceNoticeKind, expected_time: &WorkTime, reason: &str) -> String {
+    let note = format!("{} {}）", kind.label(), expected_time.as_str());
+    match reason.trim() {
+        "" => note,
+        reason => format!("{note}: {reason}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notice_note() {
+        let time = WorkTime::new("10:30").unwrap();
+
+        assert_eq!(
+            notice_note(AttendanceNoticeKind::LateArrival, &time, " 電車遅延 "),
+            "遅刻連絡（出社予定 10:30）: 電車遅延"
+        );
+        assert_eq!(
+            notice_note(AttendanceNoticeKind::EarlyLeave, &time, ""),
+            "早退連絡（退社予定 10:30）"
+        );
+    }
+}
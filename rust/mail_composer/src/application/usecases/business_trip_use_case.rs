@@ -0,0 +1,174 @@
+use crate::{
+    application::usecases::generic_mail_use_case::GenericMailUseCase,
+    domain::{
+        entities::work_record::BUSINESS_TRIP_NOTE_PREFIX,
+        interfaces::{
+            address_book::AddressBookPort, configuration::ConfigurationPort,
+            confirmation::ConfirmationPort, mail_client::MailClientPort,
+            mail_config::MailConfigPort, work_time::WorkTimePort,
+        },
+        value_objects::mail_template::{format_date_range, format_date_with_weekday},
+    },
+};
+use chrono::NaiveDate;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::collections::HashMap;
+
+/// 出張連絡メールのテンプレートのメール種別
+pub const BUSINESS_TRIP_MAIL_TYPE: &str = "business_trip";
+
+/// 出張連絡メール作成のユースケース
+///
+/// `mail_templates.json`の`business_trip`を使用し、件名・本文の`{destination}`に出張先、
+/// `{trip_start}`・`{trip_end}`に出張の初日と最終日、`{trip_period}`に期間を埋め込む
+pub struct BusinessTripUseCase<A, C, M, MC, CF, W>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+    W: WorkTimePort,
+{
+    mail_use_case: GenericMailUseCase<A, C, M, MC, CF>,
+    work_time_port: W,
+    suppress_remote_work_start: bool,
+}
+
+impl<A, C, M, MC, CF, W> BusinessTripUseCase<A, C, M, MC, CF, W>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+    W: WorkTimePort,
+{
+    /// 新しいBusinessTripUseCaseを作成する
+    pub fn new(
+        address_book_port: A,
+        configuration_port: C,
+        mail_client_port: M,
+        mail_config_port: MC,
+        confirmation_port: CF,
+        work_time_port: W,
+    ) -> Self {
+        Self {
+            mail_use_case: GenericMailUseCase::new(
+                address_book_port,
+                configuration_port,
+                mail_client_port,
+                mail_config_port,
+                confirmation_port,
+            ),
+            work_time_port,
+            suppress_remote_work_start: false,
+        }
+    }
+
+    /// 出張期間中の在宅勤務開始メールを抑止するか指定する
+    ///
+    /// 抑止する場合は、出張期間の各日の作業記録に出張先を備考として記録し、
+    /// 在宅勤務開始メールの作成時にその日を出張の日として扱う
+    ///
+    /// ## Arguments
+    /// * `suppress` - 在宅勤務開始メールを抑止する場合は`true`
+    ///
+    /// ## Returns
+    /// * 設定が反映されたBusinessTripUseCaseのインスタンス
+    pub fn with_remote_work_start_suppressed(mut self, suppress: bool) -> Self {
+        self.suppress_remote_work_start = suppress;
+        self
+    }
+
+    /// 出張連絡メールを作成・送信する
+    ///
+    /// ドライランの場合は作業記録を変更しない
+    ///
+    /// ## Arguments
+    /// * `start` - 出張の初日
+    /// * `end` - 出張の最終日（日帰りの場合は初日と同じ日付）
+    /// * `destination` - 出張先
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 期間または出張先が不正な場合、テンプレートが見つからない場合、送信または記録に失敗した場合の`Err<AppError>`
+    pub fn send_business_trip(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        destination: &str,
+        is_dry_run: bool,
+    ) -> AppResult<()> {
+        let _span = logging::operation_span("business_trip").entered();
+        let destination = destination.trim();
+        self.mail_use_case.send(
+            BUSINESS_TRIP_MAIL_TYPE,
+            &trip_values(start, end, destination)?,
+            is_dry_run,
+        )?;
+
+        if self.suppress_remote_work_start && !is_dry_run {
+            let note = format!("{BUSINESS_TRIP_NOTE_PREFIX}{destination}）");
+            for date in start.iter_days().take_while(|date| *date <= end) {
+                self.work_time_port.annotate(date, &note)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 出張の期間と出張先からテンプレートに渡す値を作成する
+fn trip_values(
+    start: NaiveDate,
+    end: NaiveDate,
+    destination: &str,
+) -> AppResult<HashMap<String, String>> {
+    if end < start {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-MAIL-001")
+            .with_message(format!(
+                "出張の最終日が初日より前です。詳細: {start} - {end}"
+            ))
+            .with_action("出張の初日と最終日を確認してください。"));
+    }
+    if destination.is_empty() {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-MAIL-002")
+            .with_message("出張先が指定されていません。")
+            .with_action("出張先を指定してください（例: 大阪支社）。"));
+    }
+
+    Ok(HashMap::from([
+        ("destination".to_string(), destination.to_string()),
+        ("trip_start".to_string(), format_date_with_weekday(start)),
+        ("trip_end".to_string(), format_date_with_weekday(end)),
+        ("trip_period".to_string(), format_date_range(start, end)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_trip_values() {
+        let values = trip_values(date("2025-04-24"), date("2025-04-25"), "大阪支社").unwrap();
+
+        assert_eq!(values["destination"], "大阪支社");
+        assert_eq!(values["trip_period"], "2025/04/24（木）〜2025/04/25（金）");
+        assert!(trip_values(date("2025-04-25"), date("2025-04-24"), "大阪支社").is_err());
+        assert!(trip_values(date("2025-04-24"), date("2025-04-24"), "").is_err());
+    }
+}
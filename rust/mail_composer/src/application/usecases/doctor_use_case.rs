@@ -0,0 +1,378 @@
+use crate::domain::{
+    interfaces::{
+        address_book::AddressBookPort, configuration::ConfigurationPort,
+        environment_probe::EnvironmentProbePort, mail_config::MailConfigPort,
+    },
+    value_objects::{app_configuration::AppConfiguration, mail_config::MailTypeConfig},
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::path::Path;
+
+/// 診断項目の結果
+#[derive(Debug)]
+pub enum CheckStatus {
+    /// 問題なし
+    Passed,
+    /// 問題あり（原因と対処法を含む）
+    Failed(AppError),
+    /// 前提となる項目が失敗したため確認できなかった
+    Skipped,
+}
+
+/// 診断項目
+#[derive(Debug)]
+pub struct DoctorCheck {
+    /// 診断項目名
+    pub name: &'static str,
+    /// 診断結果
+    pub status: CheckStatus,
+}
+
+/// 環境診断の結果
+#[derive(Debug, Default)]
+pub struct DoctorReport {
+    /// 実行順の診断項目
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    /// 全ての診断項目が問題なしかどうか
+    pub fn is_healthy(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| matches!(check.status, CheckStatus::Passed))
+    }
+
+    /// 診断結果を1項目ずつ整形する（失敗した項目には対処法を添える）
+    ///
+    /// ## Returns
+    /// * 表示用の文字列
+    pub fn render(&self) -> String {
+        let mut lines = Vec::new();
+        for check in &self.checks {
+            match &check.status {
+                CheckStatus::Passed => lines.push(format!("✅ {}", check.name)),
+                CheckStatus::Skipped => lines.push(format!(
+                    "⏭️  {}（前提の項目が失敗したため未確認）",
+                    check.name
+                )),
+                CheckStatus::Failed(error) => {
+                    lines.push(format!("❌ {}: {}", check.name, error.message));
+                    if let Some(action) = &error.action {
+                        lines.extend(action.lines().map(|line| format!("   対処: {line}")));
+                    }
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn push(&mut self, name: &'static str, result: AppResult<()>) {
+        let status = match result {
+            Ok(()) => CheckStatus::Passed,
+            Err(error) => CheckStatus::Failed(error),
+        };
+        self.checks.push(DoctorCheck { name, status });
+    }
+
+    fn skip(&mut self, names: &[&'static str]) {
+        self.checks.extend(names.iter().map(|&name| DoctorCheck {
+            name,
+            status: CheckStatus::Skipped,
+        }));
+    }
+}
+
+/// 設定ファイル、アドレスブック、テンプレート、出力先、Thunderbirdをまとめて診断するユースケース
+pub struct DoctorUseCase<C, MC, A, E>
+where
+    C: ConfigurationPort,
+    MC: MailConfigPort,
+    A: AddressBookPort,
+    E: EnvironmentProbePort,
+{
+    configuration_port: C,
+    mail_config_port: MC,
+    address_book_loader: fn(&Path) -> AppResult<A>,
+    probe_port: E,
+}
+
+impl<C, MC, A, E> DoctorUseCase<C, MC, A, E>
+where
+    C: ConfigurationPort,
+    MC: MailConfigPort,
+    A: AddressBookPort,
+    E: EnvironmentProbePort,
+{
+    /// 新しいDoctorUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `configuration_port` - 設定の読み込みに使用するポート
+    /// * `mail_config_port` - メールテンプレートの読み込みに使用するポート
+    /// * `address_book_loader` - 設定されたパスからアドレスブックを読み込む関数
+    /// * `probe_port` - ディレクトリと実行ファイルの確認に使用するポート
+    ///
+    /// ## Returns
+    /// * DoctorUseCaseのインスタンス
+    pub fn new(
+        configuration_port: C,
+        mail_config_port: MC,
+        address_book_loader: fn(&Path) -> AppResult<A>,
+        probe_port: E,
+    ) -> Self {
+        Self {
+            configuration_port,
+            mail_config_port,
+            address_book_loader,
+            probe_port,
+        }
+    }
+
+    /// 全ての診断項目を確認する
+    ///
+    /// 失敗した項目があっても中断せず、確認できる項目は全て確認する
+    ///
+    /// ## Returns
+    /// * 診断結果
+    pub fn diagnose(&self) -> DoctorReport {
+        let _span = logging::operation_span("doctor").entered();
+        let mut report = DoctorReport::default();
+
+        let exists = self.configuration_port.configuration_exists();
+        report.push(
+            "設定ファイル",
+            if exists {
+                Ok(())
+            } else {
+                Err(AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-CONF-001")
+                    .with_message("設定ファイルが見つかりません。")
+                    .with_action(
+                        "初期設定（config init）を実行して設定ファイルを作成してください。",
+                    ))
+            },
+        );
+
+        // 設定値に不備があっても、読み込めた値で残りの項目を確認する
+        let config = if exists {
+            let validation = self.configuration_port.load_configuration().map(|_| ());
+            report.push("設定値", validation);
+            self.configuration_port
+                .load_unvalidated_configuration()
+                .ok()
+        } else {
+            report.skip(&["設定値"]);
+            None
+        };
+
+        match &config {
+            Some(config) => self.check_environment(config, &mut report),
+            None => report.skip(&[
+                "アドレスブック",
+                "出力ディレクトリ",
+                "Thunderbird実行ファイル",
+            ]),
+        }
+
+        let templates = self.mail_config_port.load_mail_config();
+        let app_variables = config
+            .as_ref()
+            .map(|config| config.variables.clone())
+            .unwrap_or_default();
+        let placeholders = templates.as_ref().ok().map(|mail_config| {
+            let variables = mail_config.template_variables(&app_variables);
+            let mut mail_types: Vec<_> = mail_config.mail_types.iter().collect();
+            mail_types.sort_by_key(|(mail_type, _)| mail_type.as_str());
+            let problems = mail_types
+                .into_iter()
+                .filter_map(|(mail_type, template)| {
+                    let unknown = template.unknown_placeholders(&variables);
+                    (!unknown.is_empty()).then(|| format!("{mail_type}: {}", unknown.join(", ")))
+                })
+                .collect::<Vec<_>>();
+            if problems.is_empty() {
+                Ok(())
+            } else {
+                Err(AppError::new(ErrorKind::UnprocessableEntity)
+                    .with_code("MC-CONF-002")
+                    .with_message(format!(
+                        "置換されないプレースホルダーがあります。詳細: {}",
+                        problems.join(" / ")
+                    ))
+                    .with_action(format!(
+                        "件名では{}、本文では{}、両方で{}と、差し込み送信の{}、休暇申請の{}、遅刻・早退連絡の{}、出張連絡の{}、variablesに定義した値のみ使用できます。",
+                        braced(&MailTypeConfig::SUBJECT_PLACEHOLDERS),
+                        braced(&MailTypeConfig::BODY_PLACEHOLDERS),
+                        braced(&MailTypeConfig::DATE_PLACEHOLDERS),
+                        braced(&MailTypeConfig::RECIPIENT_PLACEHOLDERS),
+                        braced(&MailTypeConfig::LEAVE_PLACEHOLDERS),
+                        braced(&MailTypeConfig::NOTICE_PLACEHOLDERS),
+                        braced(&MailTypeConfig::TRIP_PLACEHOLDERS)
+                    )))
+            }
+        });
+        report.push("メールテンプレート", templates.map(|_| ()));
+        match placeholders {
+            Some(result) => report.push("テンプレートのプレースホルダー", result),
+            None => report.skip(&["テンプレートのプレースホルダー"]),
+        }
+
+        report
+    }
+
+    /// 設定値に依存する項目（アドレスブック、出力先、Thunderbird）を確認する
+    fn check_environment(&self, config: &AppConfiguration, report: &mut DoctorReport) {
+        let address_book = (self.address_book_loader)(&config.address_book_path()).map(|_| ());
+        report.push("アドレスブック", address_book);
+
+        report.push(
+            "出力ディレクトリ",
+            self.probe_port.check_writable_dir(config.output_dir_path()),
+        );
+
+        let thunderbird = if config.thunderbird_exe.trim().is_empty() {
+            Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-CONF-003")
+                .with_message("Thunderbird実行ファイルのパスが設定されていません。")
+                .with_action(
+                    "config.jsonのthunderbird_exeフィールドにThunderbirdのパスを設定してください。",
+                ))
+        } else {
+            self.probe_port
+                .check_executable(Path::new(&config.thunderbird_exe))
+        };
+        report.push("Thunderbird実行ファイル", thunderbird);
+    }
+}
+
+/// プレースホルダー名を`{name}`形式で列挙する
+fn braced(names: &[&str]) -> String {
+    names
+        .iter()
+        .map(|name| format!("{{{name}}}"))
+        .collect::<Vec<_>>()
+        .join("・")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::mail_config::MailConfig,
+        infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter,
+    };
+
+    struct StubConfiguration {
+        from: &'static str,
+    }
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            let config = self.load_unvalidated_configuration()?;
+            config.validate()?;
+            Ok(config)
+        }
+
+        fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(AppConfiguration {
+                from: self.from.to_string(),
+                department: "開発部".to_string(),
+                thunderbird_exe: "/opt/thunderbird".to_string(),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    struct StubMailConfig;
+
+    impl MailConfigPort for StubMailConfig {
+        fn load_mail_config(&self) -> Result<MailConfig, AppError> {
+            Ok(MailConfig {
+                mail_types: serde_json::from_str(
+                    r#"{
+                        "remote_work_start": {
+                            "to_names": ["○○さん"],
+                            "cc_names": [],
+                            "subject_template": "【開始】{department} {name}",
+                            "body_template": "本文"
+                        }
+                    }"#,
+                )?,
+                ..MailConfig::default()
+            })
+        }
+    }
+
+    struct StubProbe;
+
+    impl EnvironmentProbePort for StubProbe {
+        fn check_writable_dir(&self, _path: &Path) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn check_executable(&self, path: &Path) -> AppResult<()> {
+            Err(AppError::new(ErrorKind::NotFound)
+                .with_message(format!(
+                    "実行ファイルが見つかりません。詳細: {}",
+                    path.display()
+                ))
+                .with_action("thunderbird_exeを確認してください。"))
+        }
+    }
+
+    fn load_address_book(_path: &Path) -> AppResult<JsonAddressBookAdapter> {
+        JsonAddressBookAdapter::from_json_str(
+            r#"[{ "name": "○○さん", "address": "a@example.com" }]"#,
+        )
+    }
+
+    #[test]
+    fn test_diagnose_reports_every_problem() {
+        let use_case = DoctorUseCase::new(
+            StubConfiguration { from: "" },
+            StubMailConfig,
+            load_address_book,
+            StubProbe,
+        );
+
+        let report = use_case.diagnose();
+        let status = |name: &str| {
+            &report
+                .checks
+                .iter()
+                .find(|check| check.name == name)
+                .unwrap()
+                .status
+        };
+
+        assert!(!report.is_healthy());
+        assert!(matches!(status("設定ファイル"), CheckStatus::Passed));
+        assert!(matches!(status("設定値"), CheckStatus::Failed(_)));
+        assert!(matches!(status("アドレスブック"), CheckStatus::Passed));
+        assert!(matches!(status("出力ディレクトリ"), CheckStatus::Passed));
+        assert!(matches!(
+            status("Thunderbird実行ファイル"),
+            CheckStatus::Failed(_)
+        ));
+        assert!(matches!(status("メールテンプレート"), CheckStatus::Passed));
+        assert!(matches!(
+            status("テンプレートのプレースホルダー"),
+            CheckStatus::Failed(_)
+        ));
+
+        let rendered = report.render();
+        assert!(rendered.contains("❌ Thunderbird実行ファイル"));
+        assert!(rendered.contains("   対処: thunderbird_exeを確認してください。"));
+        assert!(rendered.contains("{name}"));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::domain::{
+    interfaces::{notification::NotificationPort, work_time::WorkTimePort},
+    value_objects::{
+        app_configuration::DEFAULT_END_REMINDER_TIME, mail_objects::WorkTime,
+        time_zone::WorkTimeZone,
+    },
+};
+use chrono::{DateTime, FixedOffset};
+use share::{error::app_error::AppResult, logging};
+
+/// 通知のタイトル
+const REMINDER_TITLE: &str = "在宅勤務終了の記録忘れ";
+
+/// 今日の作業開始が記録されているのに終了が記録されていない場合、通知する内容を作成する
+///
+/// ## Arguments
+/// * `work_time_port` - 作業記録の読み込みに使用するポート
+/// * `now` - 現在日時
+/// * `remind_after` - この時刻以降に通知する
+/// * `time_zone` - 今日の日付と時刻の判定に使用するタイムゾーン
+///
+/// ## Returns
+/// * 成功時 - `Ok<Option<String>>` (通知する場合は本文、通知しない場合は`None`)
+/// * 失敗時 - 作業記録の読み込みに失敗した場合の`Err<AppError>`
+fn pending_end_reminder<W: WorkTimePort>(
+    work_time_port: &W,
+    now: DateTime<FixedOffset>,
+    remind_after: &WorkTime,
+    time_zone: &WorkTimeZone,
+) -> AppResult<Option<String>> {
+    let now = time_zone.localize(now);
+    if WorkTime::from_time(now.time()) < *remind_after {
+        return Ok(None);
+    }
+    let Some(record) = work_time_port.load_record(now.date_naive())? else {
+        return Ok(None);
+    };
+    let record = record.localized(time_zone);
+    match (&record.start, &record.end) {
+        (Some(start), None) => Ok(Some(format!(
+            "{}に作業を開始しましたが、終了が記録されていません。在宅勤務終了メールを送信してください。",
+            start.as_str()
+        ))),
+        _ => Ok(None),
+    }
+}
+
+/// 作業終了の記録忘れを通知するユースケース
+pub struct EndReminderUseCase<W: WorkTimePort, N: NotificationPort> {
+    work_time_port: W,
+    notification_port: N,
+    remind_after: WorkTime,
+    time_zone: WorkTimeZone,
+}
+
+impl<W: WorkTimePort, N: NotificationPort> EndReminderUseCase<W, N> {
+    /// 新しいEndReminderUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `work_time_port` - 作業記録の読み込みに使用するポート
+    /// * `notification_port` - 通知に使用するポート
+    ///
+    /// ## Returns
+    /// * EndReminderUseCaseのインスタンス
+    pub fn new(work_time_port: W, notification_port: N) -> Self {
+        Self {
+            work_time_port,
+            notification_port,
+            remind_after: WorkTime::new(DEFAULT_END_REMINDER_TIME)
+                .expect("既定の通知時刻はHH:MM形式"),
+            time_zone: WorkTimeZone::default(),
+        }
+    }
+
+    /// 通知を始める時刻を指定する
+    ///
+    /// ## Arguments
+    /// * `remind_after` - この時刻以降に通知する
+    ///
+    /// ## Returns
+    /// * 通知時刻が設定されたEndReminderUseCaseのインスタンス
+    pub fn with_remind_after(mut self, remind_after: WorkTime) -> Self {
+        self.remind_after = remind_after;
+        self
+    }
+
+    /// 今日の日付と時刻の判定に使用するタイムゾーンを指定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * タイムゾーンが設定されたEndReminderUseCaseのインスタンス
+    pub fn with_time_zone(mut self, time_zone: WorkTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// 現在日時で終了の記録忘れを確認し、必要な場合は通知する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<bool>` (通知した場合は`true`)
+    /// * 失敗時 - `Err<AppError>`
+    pub fn remind(&self) -> AppResult<bool> {
+        self.remind_at(self.time_zone.now())
+    }
+
+    /// 指定した日時で終了の記録忘れを確認し、必要な場合は通知する
+    ///
+    /// ## Arguments
+    /// * `now` - 確認する日時
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<bool>` (通知した場合は`true`)
+    /// * 失敗時 - 作業記録の読み込みまたは通知に失敗した場合の`Err<AppError>`
+    pub fn remind_at(&self, now: DateTime<FixedOffset>) -> AppResult<bool> {
+        let _span = logging::operation_span("end_reminder").entered();
+        let reminder = pending_end_reminder(
+            &self.work_time_port,
+            now,
+            &self.remind_after,
+            &self.time_zone,
+        )?;
+        match reminder {
+            Some(message) => {
+                self.notification_port.notify(REMINDER_TITLE, &message)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::work_record::WorkRecord;
+    use chrono::NaiveDate;
+    use std::cell::RefCell;
+
+    struct StubWorkTime;
+
+    impl WorkTimePort for StubWorkTime {
+        fn save_start_time(
+            &self,
+            _date: NaiveDate,
+            _started_at: &DateTime<FixedOffset>,
+        ) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn save_end_time(
+            &self,
+            _date: NaiveDate,
+            _ended_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn pause(
+            &self,
+            _date: NaiveDate,
+            _paused_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn resume(
+            &self,
+            _date: NaiveDate,
+            _resumed_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn load_record(&self, date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+            let mut record = WorkRecord::default();
+            record.set_start(WorkTime::new("09:00").unwrap());
+            // 4/2は終了も記録済み、4/3は記録なし
+            match date.to_string().as_str() {
+                "2025-04-01" => Ok(Some(record)),
+                "2025-04-02" => {
+                    record.set_end(WorkTime::new("18:00").unwrap());
+                    Ok(Some(record))
+                }
+                _ => Ok(None),
+            }
+        }
+
+        fn load_records(
+            &self,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingNotification {
+        messages: RefCell<Vec<String>>,
+    }
+
+    impl NotificationPort for &RecordingNotification {
+        fn notify(&self, _title: &str, message: &str) -> AppResult<()> {
+            self.messages.borrow_mut().push(message.to_string());
+            Ok(())
+        }
+    }
+
+    fn at(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    #[test]
+    fn test_remind_after_configured_time() {
+        let notification = RecordingNotification::default();
+        let use_case = EndReminderUseCase::new(StubWorkTime, &notification)
+            .with_time_zone(WorkTimeZone::parse("Asia/Tokyo").unwrap());
+
+        assert!(!use_case.remind_at(at("2025-04-01T18:59:00+09:00")).unwrap());
+        assert!(use_case.remind_at(at("2025-04-01T19:00:00+09:00")).unwrap());
+        assert!(notification.messages.borrow()[0].contains("09:00に作業を開始しました"));
+    }
+
+    #[test]
+    fn test_no_reminder_when_ended_or_not_started() {
+        let notification = RecordingNotification::default();
+        let use_case = EndReminderUseCase::new(StubWorkTime, &notification)
+            .with_remind_after(WorkTime::new("17:00").unwrap())
+            .with_time_zone(WorkTimeZone::parse("Asia/Tokyo").unwrap());
+
+        assert!(!use_case.remind_at(at("2025-04-02T20:00:00+09:00")).unwrap());
+        assert!(!use_case.remind_at(at("2025-04-03T20:00:00+09:00")).unwrap());
+        assert!(notification.messages.borrow().is_empty());
+    }
+}
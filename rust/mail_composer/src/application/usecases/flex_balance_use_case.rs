@@ -0,0 +1,317 @@
+use crate::domain::{
+    interfaces::{
+        holiday_calendar::{HolidayCalendarPort, weekdays_between},
+        work_time::WorkTimePort,
+    },
+    value_objects::{
+        time_zone::WorkTimeZone,
+        work_hours::{DurationFormat, StandardWorkHours},
+    },
+};
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use share::error::app_error::AppResult;
+
+/// 月初から基準日までのフレックスタイムの貸借
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FlexBalance {
+    /// 集計期間の初日（月初）
+    pub month_start: NaiveDate,
+    /// 基準日（集計期間の最終日）
+    pub date: NaiveDate,
+    /// 実績の作業時間（分）
+    pub worked_minutes: i64,
+    /// 基準日までの所定労働日数
+    pub expected_workdays: usize,
+    /// 基準日までの所定労働時間（分）
+    pub expected_minutes: i64,
+    /// 貸借（分、正の値は所定労働時間より多く働いていることを表す）
+    pub balance_minutes: i64,
+}
+
+impl FlexBalance {
+    /// 作業記録に保存されていない作業時間を実績と貸借に加える
+    ///
+    /// ## Arguments
+    /// * `minutes` - 加える作業時間（分）
+    pub fn add_worked_minutes(&mut self, minutes: i64) {
+        self.worked_minutes += minutes;
+        self.balance_minutes += minutes;
+    }
+
+    /// 貸借を符号付きで整形する（例: `+1:30`、`-0:45`）
+    ///
+    /// ## Arguments
+    /// * `duration_format` - 作業時間の表示形式
+    ///
+    /// ## Returns
+    /// * 整形した貸借
+    pub fn format_balance(&self, duration_format: DurationFormat) -> String {
+        let sign = if self.balance_minutes > 0 { "+" } else { "" };
+        format!("{sign}{}", duration_format.format(self.balance_minutes))
+    }
+
+    /// 貸借の内訳をテキストに整形する
+    ///
+    /// ## Arguments
+    /// * `duration_format` - 作業時間の表示形式
+    ///
+    /// ## Returns
+    /// * 整形した貸借の内訳
+    pub fn render(&self, duration_format: DurationFormat) -> String {
+        [
+            format!("期間: {}〜{}", self.month_start, self.date),
+            format!("実績: {}", duration_format.format(self.worked_minutes)),
+            format!(
+                "所定: {}（{}日）",
+                duration_format.format(self.expected_minutes),
+                self.expected_workdays
+            ),
+            format!("貸借: {}", self.format_balance(duration_format)),
+        ]
+        .join("\n")
+    }
+}
+
+/// 月初から基準日までの作業記録を読み込み、フレックスタイムの貸借を計算する
+///
+/// 所定労働日数は土日（祝日カレンダーを指定した場合は祝日も）を除いて数え、
+/// 休日の作業時間は実績にのみ加える
+///
+/// ## Arguments
+/// * `work_time_port` - 作業記録の読み込みに使用するポート
+/// * `date` - 基準日
+/// * `standard_hours` - 1日あたりの所定労働時間
+/// * `holiday_calendar` - 祝日の判定に使用するポート（`None`の場合は土日のみを除く）
+///
+/// ## Returns
+/// * 成功時 - `Ok<FlexBalance>`
+/// * 失敗時 - 作業記録の読み込みまたは祝日の判定に失敗した場合の`Err<AppError>`
+pub fn calculate_flex_balance<W: WorkTimePort>(
+    work_time_port: &W,
+    date: NaiveDate,
+    standard_hours: StandardWorkHours,
+    holiday_calendar: Option<&dyn HolidayCalendarPort>,
+) -> AppResult<FlexBalance> {
+    let month_start = date.with_day(1).unwrap_or(date);
+    let worked_minutes: i64 = work_time_port
+        .load_records(month_start, date)?
+        .iter()
+        .filter_map(|(_, record)| record.duration())
+        .sum();
+    let expected_workdays = match holiday_calendar {
+        Some(calendar) => calendar.working_days(month_start, date)?,
+        None => weekdays_between(month_start, date),
+    };
+    let expected_minutes = standard_hours.daily_minutes() * expected_workdays as i64;
+
+    Ok(FlexBalance {
+        month_start,
+        date,
+        worked_minutes,
+        expected_workdays,
+        expected_minutes,
+        balance_minutes: worked_minutes - expected_minutes,
+    })
+}
+
+/// 今月のフレックスタイムの貸借を照会するユースケース
+pub struct FlexBalanceUseCase<W: WorkTimePort> {
+    work_time_port: W,
+    standard_hours: StandardWorkHours,
+    time_zone: WorkTimeZone,
+    holiday_calendar: Option<Box<dyn HolidayCalendarPort>>,
+}
+
+impl<W: WorkTimePort> FlexBalanceUseCase<W> {
+    /// 新しいFlexBalanceUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `work_time_port` - 作業記録の読み込みに使用するポート
+    ///
+    /// ## Returns
+    /// * FlexBalanceUseCaseのインスタンス
+    pub fn new(work_time_port: W) -> Self {
+        Self {
+            work_time_port,
+            standard_hours: StandardWorkHours::default(),
+            time_zone: WorkTimeZone::default(),
+            holiday_calendar: None,
+        }
+    }
+
+    /// 所定労働時間の計算に使用する1日あたりの所定労働時間を指定する
+    ///
+    /// ## Arguments
+    /// * `standard_hours` - 1日あたりの所定労働時間
+    ///
+    /// ## Returns
+    /// * 所定労働時間が設定されたFlexBalanceUseCaseのインスタンス
+    pub fn with_standard_hours(mut self, standard_hours: StandardWorkHours) -> Self {
+        self.standard_hours = standard_hours;
+        self
+    }
+
+    /// 今日の日付の判定に使用するタイムゾーンを指定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * タイムゾーンが設定されたFlexBalanceUseCaseのインスタンス
+    pub fn with_time_zone(mut self, time_zone: WorkTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// 所定労働日数から祝日を除くための祝日カレンダーを指定する
+    ///
+    /// ## Arguments
+    /// * `holiday_calendar` - 祝日の判定に使用するポート
+    ///
+    /// ## Returns
+    /// * 祝日カレンダーが設定されたFlexBalanceUseCaseのインスタンス
+    pub fn with_holiday_calendar(
+        mut self,
+        holiday_calendar: impl HolidayCalendarPort + 'static,
+    ) -> Self {
+        self.holiday_calendar = Some(Box::new(holiday_calendar));
+        self
+    }
+
+    /// 月初から今日までの貸借を計算する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<FlexBalance>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn current(&self) -> AppResult<FlexBalance> {
+        self.as_of(self.time_zone.today())
+    }
+
+    /// 月初から指定日までの貸借を計算する
+    ///
+    /// ## Arguments
+    /// * `date` - 基準日
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<FlexBalance>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn as_of(&self, date: NaiveDate) -> AppResult<FlexBalance> {
+        calculate_flex_balance(
+            &self.work_time_port,
+            date,
+            self.standard_hours,
+            self.holiday_calendar.as_deref(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{entities::work_record::WorkRecord, value_objects::mail_objects::WorkTime},
+        infrastructure::outbound::japanese_holiday_calendar_adapter::JapaneseHolidayCalendarAdapter,
+    };
+    use chrono::{DateTime, FixedOffset};
+
+    struct StubWorkTime;
+
+    impl WorkTimePort for StubWorkTime {
+        fn save_start_time(
+            &self,
+            _date: NaiveDate,
+            _started_at: &DateTime<FixedOffset>,
+        ) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn save_end_time(
+            &self,
+            _date: NaiveDate,
+            _ended_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn pause(
+            &self,
+            _date: NaiveDate,
+            _paused_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn resume(
+            &self,
+            _date: NaiveDate,
+            _resumed_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn load_record(&self, _date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+            Ok(None)
+        }
+
+        fn load_records(
+            &self,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+            let record = |start: &str, end: &str| {
+                let mut record = WorkRecord::default();
+                record.set_start(WorkTime::new(start).unwrap());
+                record.set_end(WorkTime::new(end).unwrap());
+                record
+            };
+            Ok([
+                ("2025-04-28", record("09:00", "18:00")),
+                ("2025-04-29", record("10:00", "12:00")),
+                ("2025-04-30", record("09:00", "17:00")),
+                ("2025-05-01", record("09:00", "19:00")),
+            ]
+            .into_iter()
+            .map(|(date, record)| (date.parse().unwrap(), record))
+            .filter(|(date, _)| (from..=to).contains(date))
+            .collect())
+        }
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_balance_without_holidays() {
+        let balance = FlexBalanceUseCase::new(StubWorkTime)
+            .as_of(date("2025-05-01"))
+            .unwrap();
+
+        assert_eq!(balance.month_start, date("2025-05-01"));
+        assert_eq!(balance.worked_minutes, 600);
+        assert_eq!(balance.expected_workdays, 1);
+        assert_eq!(balance.balance_minutes, 120);
+        assert_eq!(balance.format_balance(DurationFormat::Clock), "+2:00");
+    }
+
+    #[test]
+    fn test_balance_with_holiday_calendar() {
+        let balance = FlexBalanceUseCase::new(StubWorkTime)
+            .with_standard_hours(StandardWorkHours::from_hours(7.5).unwrap())
+            .with_holiday_calendar(JapaneseHolidayCalendarAdapter::new())
+            .as_of(date("2025-04-30"))
+            .unwrap();
+
+        // 4/29（昭和の日）は所定労働日に数えず、作業時間は実績に加える
+        assert_eq!(balance.expected_workdays, 21);
+        assert_eq!(balance.expected_minutes, 21 * 450);
+        assert_eq!(balance.worked_minutes, 540 + 120 + 480);
+        assert_eq!(balance.balance_minutes, 1140 - 9450);
+        assert!(
+            balance
+                .render(DurationFormat::Clock)
+                .contains("貸借: -138:30")
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::domain::interfaces::{mail_client::MailClientPort, outbox::OutboxPort};
+use share::{
+    error::app_error::{AppError, AppResult},
+    logging,
+};
+
+/// 送信待ちキューの再送結果
+#[derive(Debug, Default)]
+pub struct FlushOutboxReport {
+    /// 送信に成功したアイテムの識別子
+    pub sent: Vec<String>,
+    /// 送信に失敗したアイテムの識別子と原因
+    pub failed: Vec<(String, AppError)>,
+}
+
+impl FlushOutboxReport {
+    /// 全てのアイテムの送信に成功したかどうか
+    pub fn is_all_sent(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// 送信待ちキューを再送するユースケース
+pub struct FlushOutboxUseCase<O, M>
+where
+    O: OutboxPort,
+    M: MailClientPort,
+{
+    outbox_port: O,
+    mail_client_port: M,
+}
+
+impl<O, M> FlushOutboxUseCase<O, M>
+where
+    O: OutboxPort,
+    M: MailClientPort,
+{
+    /// 新しいFlushOutboxUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `outbox_port` - 送信待ちキュー
+    /// * `mail_client_port` - 再送に使用するメールクライアント
+    ///
+    /// ## Returns
+    /// * FlushOutboxUseCaseのインスタンス
+    pub fn new(outbox_port: O, mail_client_port: M) -> Self {
+        Self {
+            outbox_port,
+            mail_client_port,
+        }
+    }
+
+    /// 送信待ちキューの全てのメールドラフトを再送する
+    ///
+    /// 送信に成功したアイテムはキューから削除し、失敗したアイテムはキューに残す
+    ///
+    /// ## Arguments
+    /// * `is_dry_run` - ドライランモード（true の場合、キューからの削除も行わない）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<FlushOutboxReport>`
+    /// * 失敗時 - キューの読み込みまたは削除に失敗した場合の`Err<AppError>`
+    pub fn flush_outbox(&self, is_dry_run: bool) -> AppResult<FlushOutboxReport> {
+        let _span = logging::operation_span("flush_outbox").entered();
+        let mut report = FlushOutboxReport::default();
+
+        for item in self.outbox_port.list()? {
+            match self.mail_client_port.compose_mail(item.draft(), is_dry_run) {
+                Ok(()) => {
+                    if !is_dry_run {
+                        self.outbox_port.remove(item.id())?;
+                    }
+                    report.sent.push(item.id().to_string());
+                }
+                Err(e) => report.failed.push((item.id().to_string(), e)),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            entities::mail_draft::MailDraft,
+            value_objects::{
+                email_address::EmailAddress,
+                mail_objects::{MailBody, Subject},
+            },
+        },
+        infrastructure::outbound::{
+            json_outbox_adapter::JsonOutboxAdapter,
+            thunderbird_mail_client_adapter::ThunderbirdMailClientAdapter,
+        },
+    };
+
+    #[test]
+    fn test_flush_outbox() {
+        let dir = tempfile::tempdir().unwrap();
+        let outbox = JsonOutboxAdapter::new(dir.path().to_string_lossy());
+
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("test@example.com").unwrap()],
+            vec![],
+            Subject::new("テスト").unwrap(),
+            MailBody::new("テスト本文"),
+        );
+        outbox.enqueue(&draft).unwrap();
+
+        let use_case =
+            FlushOutboxUseCase::new(outbox, ThunderbirdMailClientAdapter::new("thunderbird"));
+
+        // ドライランではキューから削除されない
+        let report = use_case.flush_outbox(true).unwrap();
+        assert_eq!(report.sent.len(), 1);
+        assert!(report.is_all_sent());
+        assert_eq!(use_case.outbox_port.list().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,347 @@
+use crate::domain::{
+    entities::mail_draft::MailDraft,
+    interfaces::{
+        address_book::AddressBookPort, configuration::ConfigurationPort,
+        confirmation::ConfirmationPort, mail_client::MailClientPort, mail_config::MailConfigPort,
+    },
+    value_objects::{
+        mail_config::MailTypeConfig,
+        mail_objects::{MailBody, Subject, WorkTime},
+        mail_template::date_values,
+    },
+};
+use chrono::NaiveDate;
+use serde_json::{Map, Value};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::{collections::HashMap, path::PathBuf};
+
+/// `mail_templates.json`に定義された任意のメール種別のメールを作成するユースケース
+///
+/// 勤務時間の記録などを伴わず、テンプレートの宛先・件名・本文だけでメールを作成する
+pub struct GenericMailUseCase<A, C, M, MC, CF>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+{
+    address_book_port: A,
+    configuration_port: C,
+    mail_client_port: M,
+    mail_config_port: MC,
+    confirmation_port: CF,
+}
+
+impl<A, C, M, MC, CF> GenericMailUseCase<A, C, M, MC, CF>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+{
+    /// 新しいGenericMailUseCaseを作成する
+    pub fn new(
+        address_book_port: A,
+        configuration_port: C,
+        mail_client_port: M,
+        mail_config_port: MC,
+        confirmation_port: CF,
+    ) -> Self {
+        Self {
+            address_book_port,
+            configuration_port,
+            mail_client_port,
+            mail_config_port,
+            confirmation_port,
+        }
+    }
+
+    /// 指定したメール種別のメールを作成・送信する
+    ///
+    /// 件名・本文では`{department}`・`{from}`・`{time}`・`{date}`などの組み込みのプレースホルダーと、
+    /// `variables`に定義した値、`extra_vars`で指定した値を使用できる
+    ///
+    /// ## Arguments
+    /// * `mail_type` - `mail_templates.json`のメール種別
+    /// * `extra_vars` - 実行時に指定する値（`variables`の同じ名前の値より優先する）
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - テンプレートが見つからない場合、送信が中止された場合、または送信に失敗した場合の`Err<AppError>`
+    pub fn send(
+        &self,
+        mail_type: &str,
+        extra_vars: &HashMap<String, String>,
+        is_dry_run: bool,
+    ) -> AppResult<()> {
+        let _span = logging::operation_span("generic_mail").entered();
+        let draft = compose_draft(
+            &self.address_book_port,
+            &self.configuration_port,
+            &self.mail_config_port,
+            mail_type,
+            extra_vars,
+        )?;
+
+        if !is_dry_run && !self.confirmation_port.confirm(&draft)? {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-MAIL-003")
+                .with_message("メールの送信を中止しました。")
+                .with_action("宛先と内容を確認し、再度実行してください。"));
+        }
+        self.mail_client_port.compose_mail(&draft, is_dry_run)
+    }
+}
+
+/// 指定したメール種別のテンプレートから宛先を解決し、件名・本文を生成したメールドラフトを作成する
+///
+/// ## Arguments
+/// * `address_book_port` - 宛先の解決に使用するアドレスブック
+/// * `configuration_port` - アプリケーション設定
+/// * `mail_config_port` - メールテンプレート設定
+/// * `mail_type` - `mail_templates.json`のメール種別
+/// * `extra_vars` - 実行時に指定する値（`variables`の同じ名前の値より優先する）
+///
+/// ## Returns
+/// * 成功時 - `Ok<MailDraft>`
+/// * 失敗時 - テンプレートが見つからない場合、宛先を解決できない場合、または件名・本文の生成に失敗した場合の`Err<AppError>`
+pub(crate) fn compose_draft<A, C, MC>(
+    address_book_port: &A,
+    configuration_port: &C,
+    mail_config_port: &MC,
+    mail_type: &str,
+    extra_vars: &HashMap<String, String>,
+) -> AppResult<MailDraft>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    MC: MailConfigPort,
+{
+    let config = configuration_port.load_configuration()?;
+    let mail_config = mail_config_port.load_mail_config()?;
+
+    let template = mail_config
+        .get_localized_mail_type(mail_type, config.locale.as_deref())
+        .ok_or_else(|| {
+            AppError::new(ErrorKind::NotFound)
+                .with_code("MC-MAIL-004")
+                .with_message(format!("{mail_type} 設定が見つかりません"))
+                .with_action(format!(
+                    "mail_templates.jsonに{mail_type}のテンプレートを追加してください。"
+                ))
+        })?;
+
+    // メールアドレスを解決
+    let to_names: Vec<&str> = template.to_names.iter().map(|s| s.as_str()).collect();
+    let cc_names: Vec<&str> = template.cc_names.iter().map(|s| s.as_str()).collect();
+    let to_addresses = address_book_port.resolve_many(&to_names)?;
+    let cc_addresses = address_book_port.resolve_many(&cc_names)?;
+
+    // 件名と本文をテンプレートから生成（対象日の値は利用者定義の値より優先する）
+    let now = config.time_zone()?.now();
+    let now_time = WorkTime::from_time(now.time());
+    let mut variables = mail_config.template_variables(&config.variables);
+    variables.extend(
+        extra_vars
+            .iter()
+            .map(|(name, value)| (name.clone(), Value::String(value.clone()))),
+    );
+    variables.extend(date_values(now.date_naive()));
+    let subject = Subject::new(config.decorate_subject(&template.format_subject(
+        &variables,
+        &config.department,
+        &config.from,
+        now_time.as_str(),
+    )?))?;
+    let body = MailBody::new(&template.format_body(&variables, &[])?);
+    let html_body = template.html_body(&body);
+    let attachments = existing_attachments(template, &variables, now.date_naive())?;
+
+    Ok(MailDraft::new(to_addresses, cc_addresses, subject, body)
+        .with_headers(template.headers.clone())?
+        .with_html_body(html_body)
+        .with_attachments(attachments))
+}
+
+/// テンプレートの添付ファイルのうち、存在するファイルのパスを取得する
+///
+/// ## Arguments
+/// * `template` - メール種別のテンプレート
+/// * `variables` - 件名・本文の描画に使用した値
+/// * `date` - メールの対象日
+///
+/// ## Returns
+/// * 成功時 - `Ok<Vec<PathBuf>>` (存在するファイルのパス)
+/// * 失敗時 - パスのテンプレートの構文が不正な場合の`Err<AppError>`
+pub(crate) fn existing_attachments(
+    template: &MailTypeConfig,
+    variables: &Map<String, Value>,
+    date: NaiveDate,
+) -> AppResult<Vec<PathBuf>> {
+    Ok(template
+        .format_attachments(variables, date)?
+        .into_iter()
+        .filter(|path| path.is_file())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::{app_configuration::AppConfiguration, mail_config::MailConfig},
+        infrastructure::outbound::{
+            json_address_book_adapter::JsonAddressBookAdapter,
+            terminal_confirmation_adapter::AutoConfirmationAdapter,
+        },
+    };
+    use std::cell::RefCell;
+
+    struct StubConfiguration;
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(AppConfiguration {
+                from: "佐藤".to_string(),
+                department: "開発部".to_string(),
+                thunderbird_exe: "/opt/thunderbird".to_string(),
+                variables: [("project".to_string(), Value::from("基盤刷新"))].into(),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    struct StubMailConfig;
+
+    impl MailConfigPort for StubMailConfig {
+        fn load_mail_config(&self) -> AppResult<MailConfig> {
+            Ok(MailConfig {
+                mail_types: serde_json::from_str(
+                    r#"{
+                        "release_notice": {
+                            "to_names": ["山田"],
+                            "cc_names": [],
+                            "subject_template": "【リリース】{project} {version}",
+                            "body_template": "{project}の{version}をリリースしました。"
+                        }
+                    }"#,
+                )?,
+                ..MailConfig::default()
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingMailClient {
+        drafts: RefCell<Vec<MailDraft>>,
+    }
+
+    impl MailClientPort for &RecordingMailClient {
+        fn compose_mail(&self, draft: &MailDraft, _is_dry_run: bool) -> AppResult<()> {
+            self.drafts.borrow_mut().push(draft.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_send_user_defined_mail_type() {
+        let address_book = JsonAddressBookAdapter::from_json_str(
+            r#"[{ "name": "山田", "address": "yamada@example.com" }]"#,
+        )
+        .unwrap();
+        let mail_client = RecordingMailClient::default();
+        let use_case = GenericMailUseCase::new(
+            address_book,
+            StubConfiguration,
+            &mail_client,
+            StubMailConfig,
+            AutoConfirmationAdapter,
+        );
+        let extra_vars = HashMap::from([("version".to_string(), "v2.1".to_string())]);
+
+        use_case.send("release_notice", &extra_vars, true).unwrap();
+        let drafts = mail_client.drafts.borrow();
+
+        assert_eq!(drafts[0].to()[0].as_str(), "yamada@example.com");
+        assert_eq!(drafts[0].subject().as_str(), "【リリース】基盤刷新 v2.1");
+        assert_eq!(
+            drafts[0].body().as_str(),
+            "基盤刷新のv2.1をリリースしました。"
+        );
+        assert!(use_case.send("unknown", &extra_vars, true).is_err());
+    }
+
+    struct RejectingConfirmation;
+
+    impl ConfirmationPort for RejectingConfirmation {
+        fn confirm(&self, _draft: &MailDraft) -> AppResult<bool> {
+            Ok(false)
+        }
+    }
+
+    #[test]
+    fn test_send_rejected_by_confirmation() {
+        let address_book = JsonAddressBookAdapter::from_json_str(
+            r#"[{ "name": "山田", "address": "yamada@example.com" }]"#,
+        )
+        .unwrap();
+        let mail_client = RecordingMailClient::default();
+        let use_case = GenericMailUseCase::new(
+            address_book,
+            StubConfiguration,
+            &mail_client,
+            StubMailConfig,
+            RejectingConfirmation,
+        );
+        let extra_vars = HashMap::from([("version".to_string(), "v2.1".to_string())]);
+
+        let error = use_case
+            .send("release_notice", &extra_vars, false)
+            .unwrap_err();
+        assert_eq!(error.kind, ErrorKind::BadRequest);
+        assert!(mail_client.drafts.borrow().is_empty());
+
+        // ドライランでは確認を行わない
+        use_case.send("release_notice", &extra_vars, true).unwrap();
+        assert_eq!(mail_client.drafts.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_existing_attachments() {
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_attachments_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let report = dir.join("report_2024-06-03.txt");
+        std::fs::write(&report, "日報").unwrap();
+        let template: MailTypeConfig = serde_json::from_value(serde_json::json!({
+            "to_names": [],
+            "cc_names": [],
+            "subject_template": "日報",
+            "body_template": "",
+            "attachments": [
+                dir.join("report_{date}.txt"),
+                dir.join("missing_{date}.txt"),
+            ],
+        }))
+        .unwrap();
+
+        let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let attachments = existing_attachments(&template, &Map::new(), date).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(attachments, vec![report]);
+    }
+}
@@ -0,0 +1,499 @@
+use crate::domain::{
+    entities::address_book::AddressBookEntry,
+    interfaces::{
+        address_book::AddressBookWritePort,
+        configuration::{ConfigurationPort, ConfigurationWritePort},
+        mail_config::MailConfigWritePort,
+        prompt::PromptPort,
+        thunderbird_locator::ThunderbirdLocatorPort,
+    },
+    value_objects::{
+        app_configuration::AppConfiguration,
+        email_address::EmailAddress,
+        mail_config::{MailConfig, MailTypeConfig},
+        mail_objects::BodyFormat,
+    },
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// 入力が不正な場合に再入力を求める回数の上限
+const MAX_ATTEMPTS: usize = 3;
+
+/// 初期設定の結果
+#[derive(Debug)]
+pub struct InitConfigurationReport {
+    /// 保存したアプリケーション設定
+    pub configuration: AppConfiguration,
+    /// アドレスブックに新たに追加した名前
+    pub added_addresses: Vec<String>,
+}
+
+/// 対話形式で`app.json`と`mail_templates.json`を作成するユースケース
+pub struct InitConfigurationUseCase<P, L, C, M, A>
+where
+    P: PromptPort,
+    L: ThunderbirdLocatorPort,
+    C: ConfigurationPort + ConfigurationWritePort,
+    M: MailConfigWritePort,
+    A: AddressBookWritePort,
+{
+    prompt_port: P,
+    locator_port: L,
+    config_port: C,
+    mail_config_port: M,
+    address_book_port: A,
+    force: bool,
+}
+
+impl<P, L, C, M, A> InitConfigurationUseCase<P, L, C, M, A>
+where
+    P: PromptPort,
+    L: ThunderbirdLocatorPort,
+    C: ConfigurationPort + ConfigurationWritePort,
+    M: MailConfigWritePort,
+    A: AddressBookWritePort,
+{
+    /// 新しいInitConfigurationUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `prompt_port` - 利用者への問い合わせ
+    /// * `locator_port` - Thunderbirdの自動検出
+    /// * `config_port` - アプリケーション設定の保存先
+    /// * `mail_config_port` - メールテンプレートの保存先
+    /// * `address_book_port` - 宛先のメールアドレスを登録するアドレスブック
+    ///
+    /// ## Returns
+    /// * InitConfigurationUseCaseのインスタンス
+    pub fn new(
+        prompt_port: P,
+        locator_port: L,
+        config_port: C,
+        mail_config_port: M,
+        address_book_port: A,
+    ) -> Self {
+        Self {
+            prompt_port,
+            locator_port,
+            config_port,
+            mail_config_port,
+            address_book_port,
+            force: false,
+        }
+    }
+
+    /// 既存の設定ファイルとメールテンプレートを上書きするかを指定する
+    ///
+    /// ## Arguments
+    /// * `force` - 上書きする場合は`true`（既定では既存のファイルがあれば中断する）
+    ///
+    /// ## Returns
+    /// * 上書きの可否が設定されたInitConfigurationUseCaseのインスタンス
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// 設定値を問い合わせ、設定ファイルとメールテンプレートを作成する
+    ///
+    /// アドレスブックで解決できない宛先はメールアドレスを問い合わせて登録する。
+    /// 上書きが指定されていない場合、設定ファイルかメールテンプレートが既にあれば問い合わせる前に中断する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<InitConfigurationReport>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn run(&mut self) -> AppResult<InitConfigurationReport> {
+        let _span = logging::operation_span("config_init").entered();
+        if !self.force {
+            let existing = [
+                (self.config_port.configuration_exists(), "設定ファイル"),
+                (
+                    self.mail_config_port.mail_config_exists(),
+                    "メールテンプレート",
+                ),
+            ]
+            .into_iter()
+            .filter_map(|(exists, label)| exists.then_some(label))
+            .collect::<Vec<_>>();
+            if !existing.is_empty() {
+                return Err(AppError::new(ErrorKind::Conflict)
+                    .with_code("MC-CONF-037")
+                    .with_message(format!("{}が既に存在します。", existing.join("と")))
+                    .with_action("上書きする場合は--forceを指定してください。"));
+            }
+        }
+
+        let from = self.ask_required("差出人名", None)?;
+        let department = self.ask_required("差出部署", None)?;
+
+        let detected = self
+            .locator_port
+            .locate()
+            .map(|path| path.to_string_lossy().into_owned());
+        let thunderbird_exe =
+            self.ask_required("Thunderbird実行ファイルのパス", detected.as_deref())?;
+
+        let to_names = loop_until_some(
+            || {
+                let names = split_names(
+                    &self
+                        .prompt_port
+                        .ask("宛先(To)の名前（カンマ区切り）", None)?,
+                );
+                Ok((!names.is_empty()).then_some(names))
+            },
+            "宛先(To)",
+        )?;
+        let cc_names = split_names(
+            &self
+                .prompt_port
+                .ask("CCの名前（カンマ区切り、省略可）", None)?,
+        );
+
+        let mut added_addresses = Vec::new();
+        for name in to_names.iter().chain(&cc_names) {
+            if self.address_book_port.expand(name).is_ok() {
+                continue;
+            }
+            let address = self.ask_address(name)?;
+            self.address_book_port
+                .add_entry(AddressBookEntry::person(name.as_str(), address.as_str()))?;
+            added_addresses.push(name.clone());
+        }
+
+        let configuration = AppConfiguration {
+            from,
+            department,
+            thunderbird_exe: thunderbird_exe.replace('\\', "/"),
+            ..AppConfiguration::default()
+        };
+        configuration.validate()?;
+
+        self.config_port.save_configuration(&configuration)?;
+        self.mail_config_port
+            .save_mail_config(&starter_mail_config(&to_names, &cc_names))?;
+        self.address_book_port.save()?;
+
+        Ok(InitConfigurationReport {
+            configuration,
+            added_addresses,
+        })
+    }
+
+    /// 空でない値が入力されるまで問い合わせる
+    fn ask_required(&self, question: &str, default: Option<&str>) -> AppResult<String> {
+        loop_until_some(
+            || {
+                let answer = self.prompt_port.ask(question, default)?;
+                Ok((!answer.trim().is_empty()).then(|| answer.trim().to_string()))
+            },
+            question,
+        )
+    }
+
+    /// 形式が正しいメールアドレスが入力されるまで問い合わせる
+    fn ask_address(&self, name: &str) -> AppResult<EmailAddress> {
+        let question = format!("{name}のメールアドレス");
+        loop_until_some(
+            || {
+                let answer = self.prompt_port.ask(&question, None)?;
+                Ok(EmailAddress::parse(&answer).ok())
+            },
+            &question,
+        )
+    }
+}
+
+/// 値が得られるまで最大[`MAX_ATTEMPTS`]回問い合わせを繰り返す
+fn loop_until_some<T>(mut ask: impl FnMut() -> AppResult<Option<T>>, label: &str) -> AppResult<T> {
+    for _ in 0..MAX_ATTEMPTS {
+        if let Some(value) = ask()? {
+            return Ok(value);
+        }
+    }
+    Err(AppError::new(ErrorKind::BadRequest)
+        .with_code("MC-CONF-004")
+        .with_message(format!(
+            "{label}の入力が正しくないため、初期設定を中断しました。"
+        ))
+        .with_action("もう一度初期設定を実行し、正しい値を入力してください。"))
+}
+
+/// カンマ（全角・半角）区切りの名前を分割する
+fn split_names(input: &str) -> Vec<String> {
+    input
+        .split([',', '、', '，'])
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 在宅勤務の開始・終了連絡と週報用の初期テンプレートを作成する
+fn starter_mail_config(to_names: &[String], cc_names: &[String]) -> MailConfig {
+    let template = |subject: &str, body: &str| MailTypeConfig {
+        to_names: to_names.to_vec(),
+        cc_names: cc_names.to_vec(),
+        subject_template: subject.to_string(),
+        body_template: body.to_string(),
+        headers: BTreeMap::new(),
+        body_format: BodyFormat::default(),
+        attachments: Vec::new(),
+    };
+
+    MailConfig {
+        mail_types: HashMap::from([
+            (
+                "remote_work_start".to_string(),
+                template(
+                    "【在宅勤務開始】{department} {from} {time}",
+                    "お疲れ様です。\n\n本日は在宅勤務を開始いたします。\n\nよろしくお願いいたします。",
+                ),
+            ),
+            (
+                "remote_work_end".to_string(),
+                template(
+                    "【在宅勤務終了】{department} {from} {time}",
+                    "お疲れ様です。\n\n本日の在宅勤務を終了いたします。\n作業時間: {work_time}（実働 {duration}）\n\nよろしくお願いいたします。",
+                ),
+            ),
+            (
+                "weekly_report".to_string(),
+                template(
+                    "【週報】{department} {from} {time}",
+                    "お疲れ様です。\n\n今週（{period}）の勤務状況をご報告いたします。\n\n{summary}\n\n{daily_table}\n\nよろしくお願いいたします。",
+                ),
+            ),
+            (
+                "leave_request".to_string(),
+                template(
+                    "【休暇申請】{department} {from} {leave_period}",
+                    "お疲れ様です。\n\n下記の通り休暇を申請いたします。\n\n期間: {leave_period}（{leave_days}日間）\n理由: {reason}\n\nよろしくお願いいたします。",
+                ),
+            ),
+            (
+                "late_arrival".to_string(),
+                template(
+                    "【遅刻連絡】{department} {from}",
+                    "お疲れ様です。\n\n本日は{reason}のため遅刻いたします。\n{expected_time}頃に業務を開始する予定です。\n\nよろしくお願いいたします。",
+                ),
+            ),
+            (
+                "business_trip".to_string(),
+                template(
+                    "【出張連絡】{department} {from} {trip_period}",
+                    "お疲れ様です。\n\n下記の通り出張いたします。\n\n出張先: {destination}\n期間: {trip_period}\n\nよろしくお願いいたします。",
+                ),
+            ),
+            (
+                "early_leave".to_string(),
+                template(
+                    "【早退連絡】{department} {from}",
+                    "お疲れ様です。\n\n本日は{reason}のため早退いたします。\n{expected_time}頃に業務を終了する予定です。\n\nよろしくお願いいたします。",
+                ),
+            ),
+        ]),
+        ..MailConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::interfaces::address_book::AddressBookPort,
+        infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter,
+    };
+    use std::{cell::RefCell, collections::VecDeque, path::PathBuf};
+
+    struct ScriptedPrompt {
+        answers: RefCell<VecDeque<&'static str>>,
+    }
+
+    impl PromptPort for ScriptedPrompt {
+        fn ask(&self, _question: &str, default: Option<&str>) -> AppResult<String> {
+            let answer = self.answers.borrow_mut().pop_front().unwrap_or_default();
+            Ok(if answer.is_empty() {
+                default.unwrap_or_default().to_string()
+            } else {
+                answer.to_string()
+            })
+        }
+    }
+
+    struct FixedLocator;
+
+    impl ThunderbirdLocatorPort for FixedLocator {
+        fn locate(&self) -> Option<PathBuf> {
+            Some(PathBuf::from(
+                "C:\\Program Files\\Mozilla Thunderbird\\thunderbird.exe",
+            ))
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryConfigWriter {
+        saved: RefCell<Option<AppConfiguration>>,
+    }
+
+    impl ConfigurationPort for &MemoryConfigWriter {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            self.saved
+                .borrow()
+                .clone()
+                .ok_or_else(|| AppError::new(ErrorKind::NotFound))
+        }
+
+        fn configuration_exists(&self) -> bool {
+            self.saved.borrow().is_some()
+        }
+    }
+
+    impl ConfigurationWritePort for &MemoryConfigWriter {
+        fn save_configuration(&self, config: &AppConfiguration) -> AppResult<()> {
+            *self.saved.borrow_mut() = Some(config.clone());
+            Ok(())
+        }
+
+        fn save_field(&self, field: &str, value: &str) -> AppResult<()> {
+            let mut saved = self.saved.borrow_mut();
+            saved
+                .get_or_insert_with(AppConfiguration::default)
+                .set_field(field, value)
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryMailConfigWriter {
+        saved: RefCell<Option<MailConfig>>,
+    }
+
+    impl MailConfigWritePort for &MemoryMailConfigWriter {
+        fn save_mail_config(&self, mail_config: &MailConfig) -> AppResult<()> {
+            *self.saved.borrow_mut() = Some(mail_config.clone());
+            Ok(())
+        }
+
+        fn mail_config_exists(&self) -> bool {
+            self.saved.borrow().is_some()
+        }
+    }
+
+    fn prompt(answers: &[&'static str]) -> ScriptedPrompt {
+        ScriptedPrompt {
+            answers: RefCell::new(answers.iter().copied().collect()),
+        }
+    }
+
+    #[test]
+    fn test_run_writes_configuration_and_templates() {
+        let config_writer = MemoryConfigWriter::default();
+        let mail_config_writer = MemoryMailConfigWriter::default();
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_init_config_{}", std::process::id()));
+        let address_book_path = dir.join("address_book.json");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            &address_book_path,
+            r#"[{ "name": "○○さん", "address": "boss@example.com" }]"#,
+        )
+        .unwrap();
+        let address_book = JsonAddressBookAdapter::load_or_empty(&address_book_path).unwrap();
+        let mut use_case = InitConfigurationUseCase::new(
+            prompt(&[
+                "山田",
+                "",
+                "開発部",
+                "",
+                "○○さん",
+                "△△さん、□□さん",
+                "not-an-address",
+                "sankaku@example.com",
+                "shikaku@example.com",
+            ]),
+            FixedLocator,
+            &config_writer,
+            &mail_config_writer,
+            address_book,
+        );
+
+        let report = use_case.run().unwrap();
+        let saved_book = JsonAddressBookAdapter::load_from_address_book(&address_book_path);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(report.added_addresses, vec!["△△さん", "□□さん"]);
+        let config = config_writer.saved.borrow().clone().unwrap();
+        assert_eq!(config.from, "山田");
+        assert_eq!(config.department, "開発部");
+        assert_eq!(
+            config.thunderbird_exe,
+            "C:/Program Files/Mozilla Thunderbird/thunderbird.exe"
+        );
+
+        let mail_config = mail_config_writer.saved.borrow().clone().unwrap();
+        let start = mail_config.get_mail_type("remote_work_start").unwrap();
+        assert_eq!(start.to_names, vec!["○○さん"]);
+        assert_eq!(start.cc_names, vec!["△△さん", "□□さん"]);
+        assert!(mail_config.get_mail_type("remote_work_end").is_some());
+
+        let saved_book = saved_book.unwrap();
+        assert_eq!(
+            saved_book.resolve("△△さん").unwrap().as_str(),
+            "sankaku@example.com"
+        );
+        assert_eq!(
+            saved_book.resolve("□□さん").unwrap().as_str(),
+            "shikaku@example.com"
+        );
+    }
+
+    #[test]
+    fn test_run_aborts_after_repeated_empty_input() {
+        let config_writer = MemoryConfigWriter::default();
+        let mail_config_writer = MemoryMailConfigWriter::default();
+        let mut use_case = InitConfigurationUseCase::new(
+            prompt(&[]),
+            FixedLocator,
+            &config_writer,
+            &mail_config_writer,
+            JsonAddressBookAdapter::from_json_str("[]").unwrap(),
+        );
+
+        assert!(use_case.run().is_err());
+        assert!(config_writer.saved.borrow().is_none());
+    }
+
+    #[test]
+    fn test_run_refuses_to_overwrite_without_force() {
+        let config_writer = MemoryConfigWriter::default();
+        let mail_config_writer = MemoryMailConfigWriter::default();
+        *mail_config_writer.saved.borrow_mut() = Some(MailConfig::default());
+        let mut use_case = InitConfigurationUseCase::new(
+            prompt(&[]),
+            FixedLocator,
+            &config_writer,
+            &mail_config_writer,
+            JsonAddressBookAdapter::from_json_str("[]").unwrap(),
+        );
+
+        let refused = use_case.run().unwrap_err();
+        assert_eq!(refused.code, Some("MC-CONF-037"));
+        assert!(refused.message.contains("メールテンプレート"));
+
+        // 上書きを指定した場合は問い合わせに進む（ここでは入力がないため中断する）
+        let mut use_case = use_case.with_force(true);
+        assert_eq!(use_case.run().unwrap_err().code, Some("MC-CONF-004"));
+    }
+
+    #[test]
+    fn test_split_names() {
+        assert_eq!(split_names("a, b、c，,"), vec!["a", "b", "c"]);
+        assert!(split_names("  ").is_empty());
+    }
+}
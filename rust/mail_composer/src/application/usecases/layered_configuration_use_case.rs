@@ -0,0 +1,338 @@
+use crate::domain::{
+    interfaces::configuration::ConfigurationPort,
+    value_objects::{
+        app_configuration::AppConfiguration,
+        effective_configuration::{ConfigurationSource, EffectiveConfiguration},
+    },
+};
+use serde_json::{Map, Value};
+use share::{
+    config::{
+        loader::ConfigLoader,
+        source::{ConfigSource, ValueSource},
+    },
+    error::app_error::AppResult,
+};
+use std::collections::BTreeMap;
+
+/// 既定値 → 設定ファイル → 一元管理の設定 → 環境変数 → コマンドライン引数の順に設定を重ね合わせるユースケース
+///
+/// 各層を[`ConfigSource`]として[`ConfigLoader`]に渡し、後の層ほど優先してマージする。
+/// [`ConfigurationPort`]を実装するため、他のユースケースに設定の読み込み元として渡せる
+pub struct LayeredConfigurationUseCase<C: ConfigurationPort> {
+    file_port: C,
+    remote_source: Option<Box<dyn ConfigSource>>,
+    env_lookup: fn(&str) -> Option<String>,
+    command_line_overrides: Vec<(String, String)>,
+}
+
+impl<C: ConfigurationPort> LayeredConfigurationUseCase<C> {
+    /// 新しいLayeredConfigurationUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `file_port` - 設定ファイルを読み込むポート
+    ///
+    /// ## Returns
+    /// * LayeredConfigurationUseCaseのインスタンス
+    pub fn new(file_port: C) -> Self {
+        Self {
+            file_port,
+            remote_source: None,
+            env_lookup: |name| std::env::var(name).ok(),
+            command_line_overrides: Vec::new(),
+        }
+    }
+
+    /// 一元管理された設定の取得元を設定する
+    ///
+    /// 取得した値は設定ファイルより優先し、環境変数とコマンドライン引数で上書きできる
+    ///
+    /// ## Arguments
+    /// * `source` - 一元管理された設定の取得元（`config_url`のHTTPSサーバーなど）
+    ///
+    /// ## Returns
+    /// * 取得元が設定されたLayeredConfigurationUseCaseのインスタンス
+    pub fn with_remote_source(mut self, source: impl ConfigSource + 'static) -> Self {
+        self.remote_source = Some(Box::new(source));
+        self
+    }
+
+    /// 環境変数の取得方法を設定する
+    ///
+    /// ## Arguments
+    /// * `env_lookup` - 環境変数名から値を取得する関数
+    ///
+    /// ## Returns
+    /// * 環境変数の取得方法が設定されたLayeredConfigurationUseCaseのインスタンス
+    pub fn with_env_lookup(mut self, env_lookup: fn(&str) -> Option<String>) -> Self {
+        self.env_lookup = env_lookup;
+        self
+    }
+
+    /// コマンドライン引数で指定された設定値を追加する
+    ///
+    /// ## Arguments
+    /// * `overrides` - 設定項目名と値の組（後に指定したものが優先される）
+    ///
+    /// ## Returns
+    /// * 設定値が追加されたLayeredConfigurationUseCaseのインスタンス
+    pub fn with_command_line_overrides(
+        mut self,
+        overrides: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.command_line_overrides.extend(overrides);
+        self
+    }
+
+    /// 全ての層をマージし、検証済みの設定を取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<EffectiveConfiguration>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn resolve(&self) -> AppResult<EffectiveConfiguration> {
+        let effective = self.resolve_unvalidated()?;
+        effective.configuration.validate()?;
+        Ok(effective)
+    }
+
+    /// 検証を行わずに全ての層をマージする
+    ///
+    /// 設定に不備がある状態でも、どの層の値が使われているかを確認する場合に使用する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<EffectiveConfiguration>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn resolve_unvalidated(&self) -> AppResult<EffectiveConfiguration> {
+        let mut loader = ConfigLoader::new();
+        let mut sources = BTreeMap::new();
+        for (source, layer) in self.layers()? {
+            // 既定値は取得元の記録がない項目として表示する
+            if source != ConfigurationSource::Default
+                && let Some(Value::Object(fields)) = layer.load()?
+            {
+                sources.extend(
+                    fields
+                        .iter()
+                        .filter(|(_, value)| !value.is_null())
+                        .map(|(key, _)| (key.clone(), source)),
+                );
+            }
+            loader = loader.with_source(layer);
+        }
+
+        Ok(EffectiveConfiguration {
+            configuration: loader.load()?,
+            sources,
+        })
+    }
+
+    /// 優先度の低い順に各層の取得元を作成する
+    fn layers(&self) -> AppResult<Vec<(ConfigurationSource, ValueSource)>> {
+        let mut layers = vec![(
+            ConfigurationSource::Default,
+            ValueSource::from_serialize("defaults", &AppConfiguration::default())?,
+        )];
+
+        if self.file_port.configuration_exists() {
+            let file = self.file_port.load_unvalidated_configuration()?;
+            layers.push((
+                ConfigurationSource::File,
+                ValueSource::from_serialize("file", &file)?,
+            ));
+        }
+
+        if let Some(remote) = &self.remote_source
+            && let Some(value) = remote.load()?
+        {
+            layers.push((
+                ConfigurationSource::Remote,
+                ValueSource::new(remote.name(), value),
+            ));
+        }
+
+        let mut environment = Map::new();
+        for field in AppConfiguration::OVERRIDABLE_FIELDS {
+            let name = AppConfiguration::env_var_name(field);
+            if let Some(value) = (self.env_lookup)(&name).filter(|v| !v.trim().is_empty()) {
+                let value = Self::field_value(field, &value).map_err(|e| {
+                    let message = format!("環境変数{name}の値が不正です。詳細: {value}");
+                    e.with_message(message)
+                })?;
+                environment.insert(field.to_string(), value);
+            }
+        }
+        layers.push((
+            ConfigurationSource::Environment,
+            ValueSource::new("environment", Value::Object(environment)),
+        ));
+
+        let mut command_line = Map::new();
+        for (field, value) in &self.command_line_overrides {
+            command_line.insert(field.clone(), Self::field_value(field, value)?);
+        }
+        layers.push((
+            ConfigurationSource::CommandLine,
+            ValueSource::new("command line", Value::Object(command_line)),
+        ));
+
+        Ok(layers)
+    }
+
+    /// 文字列で指定された設定値を、設定ファイルと同じ型のJSONの値に変換する
+    ///
+    /// 値の検証と正規化は[`AppConfiguration::set_field`]と同じ規則で行う
+    fn field_value(field: &str, value: &str) -> AppResult<Value> {
+        let mut config = AppConfiguration::default();
+        config.set_field(field, value)?;
+        Ok(serde_json::to_value(&config)?
+            .get(field)
+            .cloned()
+            .unwrap_or(Value::Null))
+    }
+}
+
+impl<C: ConfigurationPort> ConfigurationPort for LayeredConfigurationUseCase<C> {
+    fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        self.resolve().map(|effective| effective.configuration)
+    }
+
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        self.resolve_unvalidated()
+            .map(|effective| effective.configuration)
+    }
+
+    fn configuration_exists(&self) -> bool {
+        self.file_port.configuration_exists()
+    }
+
+    fn load_effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        self.resolve_unvalidated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubConfiguration {
+        exists: bool,
+    }
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            let config = self.load_unvalidated_configuration()?;
+            config.validate()?;
+            Ok(config)
+        }
+
+        fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(AppConfiguration {
+                from: "山田".to_string(),
+                department: "開発部".to_string(),
+                thunderbird_exe: "thunderbird".to_string(),
+                messages_per_minute: Some(5),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            self.exists
+        }
+    }
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "MAIL_COMPOSER_DEPARTMENT" => Some("営業部".to_string()),
+            "MAIL_COMPOSER_FROM" => Some("環境".to_string()),
+            "MAIL_COMPOSER_THUNDERBIRD_TIMEOUT_SECS" => Some("30".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_layer_precedence() {
+        let use_case = LayeredConfigurationUseCase::new(StubConfiguration { exists: true })
+            .with_env_lookup(lookup)
+            .with_command_line_overrides([
+                ("from".to_string(), "佐藤".to_string()),
+                ("messages_per_minute".to_string(), "10".to_string()),
+            ]);
+
+        let effective = use_case.resolve().unwrap();
+
+        assert_eq!(effective.configuration.from, "佐藤");
+        assert_eq!(effective.configuration.department, "営業部");
+        assert_eq!(effective.configuration.thunderbird_exe, "thunderbird");
+        assert_eq!(effective.configuration.thunderbird_timeout_secs, Some(30));
+        assert_eq!(effective.configuration.messages_per_minute, Some(10));
+        assert_eq!(effective.sources["from"], ConfigurationSource::CommandLine);
+        assert_eq!(
+            effective.sources["department"],
+            ConfigurationSource::Environment
+        );
+        assert_eq!(effective.sources["log_dir"], ConfigurationSource::File);
+
+        let rendered = effective.render();
+        assert!(rendered.contains(r#"from = "佐藤"  # command line"#));
+        assert!(rendered.contains(r#"department = "営業部"  # environment"#));
+        assert_eq!(use_case.load_configuration().unwrap().from, "佐藤");
+    }
+
+    #[test]
+    fn test_remote_layer_precedence() {
+        let remote = ValueSource::new(
+            "https://config.example/app.json",
+            serde_json::json!({ "department": "総務部", "log_dir": "remote/logs" }),
+        );
+        let use_case = LayeredConfigurationUseCase::new(StubConfiguration { exists: true })
+            .with_remote_source(remote)
+            .with_env_lookup(lookup);
+
+        let effective = use_case.resolve().unwrap();
+
+        assert_eq!(effective.configuration.log_dir, "remote/logs");
+        assert_eq!(effective.configuration.department, "営業部");
+        assert_eq!(effective.configuration.from, "環境");
+        assert_eq!(effective.sources["log_dir"], ConfigurationSource::Remote);
+        assert_eq!(
+            effective.sources["department"],
+            ConfigurationSource::Environment
+        );
+        assert_eq!(
+            effective.sources["thunderbird_exe"],
+            ConfigurationSource::File
+        );
+    }
+
+    #[test]
+    fn test_defaults_without_file() {
+        let use_case = LayeredConfigurationUseCase::new(StubConfiguration { exists: false })
+            .with_env_lookup(|_| None);
+
+        let effective = use_case.resolve_unvalidated().unwrap();
+
+        assert_eq!(effective.configuration, AppConfiguration::default());
+        assert!(effective.sources.is_empty());
+        assert!(
+            effective
+                .render()
+                .contains("log_dir = \"rust/mail_composer/logs\"  # default")
+        );
+        assert!(use_case.resolve().is_err());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let use_case = LayeredConfigurationUseCase::new(StubConfiguration { exists: true })
+            .with_env_lookup(|_| None)
+            .with_command_line_overrides([("unknown".to_string(), "x".to_string())]);
+        assert!(use_case.resolve().is_err());
+
+        let use_case = LayeredConfigurationUseCase::new(StubConfiguration { exists: true })
+            .with_env_lookup(|name| {
+                (name == "MAIL_COMPOSER_MESSAGES_PER_MINUTE").then(|| "abc".to_string())
+            });
+        let error = use_case.resolve().unwrap_err();
+        assert!(error.message.contains("MAIL_COMPOSER_MESSAGES_PER_MINUTE"));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    application::usecases::generic_mail_use_case::GenericMailUseCase,
+    domain::{
+        interfaces::{
+            address_book::AddressBookPort, configuration::ConfigurationPort,
+            confirmation::ConfirmationPort, holiday_calendar::weekdays_between,
+            mail_client::MailClientPort, mail_config::MailConfigPort,
+        },
+        value_objects::mail_template::{format_date_range, format_date_with_weekday},
+    },
+};
+use chrono::NaiveDate;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::collections::HashMap;
+
+/// 休暇申請メールのテンプレートのメール種別
+pub const LEAVE_REQUEST_MAIL_TYPE: &str = "leave_request";
+
+/// 休暇申請メール作成のユースケース
+///
+/// `mail_templates.json`の`leave_request`を使用し、件名・本文の`{leave_start}`・`{leave_end}`に
+/// 休暇の初日と最終日、`{leave_period}`に期間、`{leave_days}`に土日を除いた日数、`{reason}`に理由を埋め込む
+pub struct LeaveRequestUseCase<A, C, M, MC, CF>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+{
+    mail_use_case: GenericMailUseCase<A, C, M, MC, CF>,
+}
+
+impl<A, C, M, MC, CF> LeaveRequestUseCase<A, C, M, MC, CF>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+{
+    /// 新しいLeaveRequestUseCaseを作成する
+    pub fn new(
+        address_book_port: A,
+        configuration_port: C,
+        mail_client_port: M,
+        mail_config_port: MC,
+        confirmation_port: CF,
+    ) -> Self {
+        Self {
+            mail_use_case: GenericMailUseCase::new(
+                address_book_port,
+                configuration_port,
+                mail_client_port,
+                mail_config_port,
+                confirmation_port,
+            ),
+        }
+    }
+
+    /// 休暇申請メールを作成・送信する
+    ///
+    /// ## Arguments
+    /// * `start` - 休暇の初日
+    /// * `end` - 休暇の最終日（1日のみの場合は初日と同じ日付）
+    /// * `reason` - 休暇の理由
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 期間が不正な場合、テンプレートが見つからない場合、または送信に失敗した場合の`Err<AppError>`
+    pub fn send_leave_request(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        reason: &str,
+        is_dry_run: bool,
+    ) -> AppResult<()> {
+        let _span = logging::operation_span("leave_request").entered();
+        self.mail_use_case.send(
+            LEAVE_REQUEST_MAIL_TYPE,
+            &leave_values(start, end, reason)?,
+            is_dry_run,
+        )
+    }
+}
+
+/// 休暇の期間と理由からテンプレートに渡す値を作成する
+fn leave_values(
+    start: NaiveDate,
+    end: NaiveDate,
+    reason: &str,
+) -> AppResult<HashMap<String, String>> {
+    if end < start {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-MAIL-005")
+            .with_message(format!(
+                "休暇の最終日が初日より前です。詳細: {start} - {end}"
+            ))
+            .with_action("休暇の初日と最終日を確認してください。"));
+    }
+
+    Ok(HashMap::from([
+        ("leave_start".to_string(), format_date_with_weekday(start)),
+        ("leave_end".to_string(), format_date_with_weekday(end)),
+        ("leave_period".to_string(), format_date_range(start, end)),
+        (
+            "leave_days".to_string(),
+            weekdays_between(start, end).to_string(),
+        ),
+        ("reason".to_string(), reason.trim().to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_leave_values() {
+        let values = leave_values(date("2025-04-25"), date("2025-04-28"), " 私用のため ").unwrap();
+
+        assert_eq!(values["leave_period"], "2025/04/25（金）〜2025/04/28（月）");
+        assert_eq!(values["leave_days"], "2");
+        assert_eq!(values["reason"], "私用のため");
+
+        let values = leave_values(date("2025-04-25"), date("2025-04-25"), "通院").unwrap();
+        assert_eq!(values["leave_period"], "2025/04/25（金）");
+        assert_eq!(values["leave_days"], "1");
+
+        assert!(leave_values(date("2025-04-28"), date("2025-04-25"), "").is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    application::usecases::generic_mail_use_case::existing_attachments,
+    domain::{
+        entities::mail_draft::MailDraft,
+        interfaces::{
+            address_book::AddressBookPort, configuration::ConfigurationPort,
+            confirmation::ConfirmationPort, mail_client::MailClientPort,
+            mail_config::MailConfigPort,
+        },
+        value_objects::{
+            email_address::EmailAddress,
+            mail_objects::{MailBody, Subject, WorkTime},
+            mail_template::{date_values, template_values},
+        },
+    },
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+
+/// 宛先ごとに個別のメールを作成する差し込み送信のユースケース
+///
+/// テンプレートの`to_names`（グループはメンバー全員に展開する）の宛先ごとに1通ずつメールを作成し、
+/// `{recipient_name}`に宛先の名前、`{recipient_address}`にメールアドレスを埋め込む。
+/// `cc_names`の宛先は全てのメールのCCに設定する
+pub struct MailMergeUseCase<A, C, M, MC, CF>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+{
+    address_book_port: A,
+    configuration_port: C,
+    mail_client_port: M,
+    mail_config_port: MC,
+    confirmation_port: CF,
+}
+
+impl<A, C, M, MC, CF> MailMergeUseCase<A, C, M, MC, CF>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    M: MailClientPort,
+    MC: MailConfigPort,
+    CF: ConfirmationPort,
+{
+    /// 新しいMailMergeUseCaseを作成する
+    pub fn new(
+        address_book_port: A,
+        configuration_port: C,
+        mail_client_port: M,
+        mail_config_port: MC,
+        confirmation_port: CF,
+    ) -> Self {
+        Self {
+            address_book_port,
+            configuration_port,
+            mail_client_port,
+            mail_config_port,
+            confirmation_port,
+        }
+    }
+
+    /// 宛先ごとに個別のメールを作成・送信する
+    ///
+    /// 送信前の確認で中止された宛先がある場合も、残りの宛先への送信は続ける
+    ///
+    /// ## Arguments
+    /// * `mail_type` - 使用するテンプレートのメール種別
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<EmailAddress>>` (メールを作成した宛先)
+    /// * 失敗時 - テンプレートが見つからない場合、宛先を解決できない場合、または送信に失敗した場合の`Err<AppError>`
+    pub fn send_personalized(
+        &self,
+        mail_type: &str,
+        is_dry_run: bool,
+    ) -> AppResult<Vec<EmailAddress>> {
+        let _span = logging::operation_span("mail_merge").entered();
+        let config = self.configuration_port.load_configuration()?;
+        let mail_config = self.mail_config_port.load_mail_config()?;
+
+        let template = mail_config
+            .get_localized_mail_type(mail_type, config.locale.as_deref())
+            .ok_or_else(|| {
+                AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-MAIL-006")
+                    .with_message(format!("{mail_type} 設定が見つかりません"))
+                    .with_action(format!(
+                        "mail_templates.jsonに{mail_type}のテンプレートを追加してください。"
+                    ))
+            })?;
+
+        // 宛先を解決
+        let to_names: Vec<&str> = template.to_names.iter().map(|s| s.as_str()).collect();
+        let cc_names: Vec<&str> = template.cc_names.iter().map(|s| s.as_str()).collect();
+        let recipients = self.address_book_port.resolve_many(&to_names)?;
+        let cc_addresses = self.address_book_port.resolve_many(&cc_names)?;
+        if recipients.is_empty() {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-MAIL-007")
+                .with_message(format!("{mail_type}の宛先がありません。"))
+                .with_action("mail_templates.jsonのto_namesに宛先を追加してください。"));
+        }
+
+        let now = config.time_zone()?.now();
+        let now_time = WorkTime::from_time(now.time());
+        let mut variables = mail_config.template_variables(&config.variables);
+        variables.extend(date_values(now.date_naive()));
+        let attachments = existing_attachments(template, &variables, now.date_naive())?;
+
+        let mut composed = Vec::new();
+        for recipient in recipients {
+            // 宛先ごとの値は利用者定義の値より優先する
+            let recipient_name = self.recipient_name(&recipient)?;
+            let mut values = variables.clone();
+            values.extend(template_values(&[
+                ("recipient_name", &recipient_name),
+                ("recipient_address", recipient.as_str()),
+            ]));
+
+            let subject = Subject::new(config.decorate_subject(&template.format_subject(
+                &values,
+                &config.department,
+                &config.from,
+                now_time.as_str(),
+            )?))?;
+            let body = MailBody::new(&template.format_body(&values, &[])?);
+            let html_body = template.html_body(&body);
+            let draft =
+                MailDraft::new(vec![recipient.clone()], cc_addresses.clone(), subject, body)
+                    .with_headers(template.headers.clone())?
+                    .with_html_body(html_body)
+                    .with_attachments(attachments.clone());
+
+            if !is_dry_run && !self.confirmation_port.confirm(&draft)? {
+                continue;
+            }
+            self.mail_client_port.compose_mail(&draft, is_dry_run)?;
+            composed.push(recipient);
+        }
+        Ok(composed)
+    }
+
+    /// 宛先の名前を取得する（表示名、アドレスブックの名前、メールアドレスの順に使用する）
+    fn recipient_name(&self, recipient: &EmailAddress) -> AppResult<String> {
+        if let Some(display_name) = recipient.display_name() {
+            return Ok(display_name.to_string());
+        }
+        Ok(self
+            .address_book_port
+            .reverse_resolve(recipient.as_str())?
+            .unwrap_or_else(|| recipient.as_str().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::{app_configuration::AppConfiguration, mail_config::MailConfig},
+        infrastructure::outbound::{
+            json_address_book_adapter::JsonAddressBookAdapter,
+            terminal_confirmation_adapter::AutoConfirmationAdapter,
+        },
+    };
+    use std::cell::RefCell;
+
+    struct StubConfiguration;
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(AppConfiguration {
+                from: "佐藤".to_string(),
+                department: "総務部".to_string(),
+                thunderbird_exe: "/opt/thunderbird".to_string(),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    struct StubMailConfig;
+
+    impl MailConfigPort for StubMailConfig {
+        fn load_mail_config(&self) -> AppResult<MailConfig> {
+            Ok(MailConfig {
+                mail_types: serde_json::from_str(
+                    r#"{
+                        "announcement": {
+                            "to_names": ["開発チーム"],
+                            "cc_names": ["部長"],
+                            "subject_template": "【ご案内】{recipient_name}様",
+                            "body_template": "{recipient_name}様\n\n{recipient_address}宛てのご案内です。"
+                        }
+                    }"#,
+                )?,
+                ..MailConfig::default()
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingMailClient {
+        drafts: RefCell<Vec<MailDraft>>,
+    }
+
+    impl MailClientPort for &RecordingMailClient {
+        fn compose_mail(&self, draft: &MailDraft, _is_dry_run: bool) -> AppResult<()> {
+            self.drafts.borrow_mut().push(draft.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_send_personalized() {
+        let address_book = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田", "address": "yamada@example.com" },
+                { "name": "田中", "address": "tanaka@example.com", "display_name": "田中太郎" },
+                { "name": "部長", "address": "boss@example.com" },
+                { "name": "開発チーム", "members": ["山田", "田中"] }
+            ]"#,
+        )
+        .unwrap();
+        let mail_client = RecordingMailClient::default();
+        let use_case = MailMergeUseCase::new(
+            address_book,
+            StubConfiguration,
+            &mail_client,
+            StubMailConfig,
+            AutoConfirmationAdapter,
+        );
+
+        let composed = use_case.send_personalized("announcement", true).unwrap();
+        let drafts = mail_client.drafts.borrow();
+
+        assert_eq!(composed.len(), 2);
+        assert_eq!(drafts.len(), 2);
+        for draft in drafts.iter() {
+            assert_eq!(draft.to().len(), 1);
+            assert_eq!(draft.cc()[0].as_str(), "boss@example.com");
+        }
+        assert_eq!(drafts[0].subject().as_str(), "【ご案内】山田様");
+        assert_eq!(
+            drafts[0].body().as_str(),
+            "山田様\n\nyamada@example.com宛てのご案内です。"
+        );
+        assert!(drafts[1].subject().as_str().contains("田中"));
+        assert!(use_case.send_personalized("unknown", true).is_err());
+    }
+}
@@ -1,2 +1,26 @@
+pub mod address_book_audit_use_case;
+pub mod address_book_import_use_case;
+pub mod address_book_management_use_case;
+pub mod attendance_notice_use_case;
+pub mod business_trip_use_case;
 pub mod configuration_use_case;
+pub mod doctor_use_case;
+pub mod end_reminder_use_case;
+pub mod flex_balance_use_case;
+pub mod flush_outbox_use_case;
+pub mod generic_mail_use_case;
+pub mod init_configuration_use_case;
+pub mod layered_configuration_use_case;
+pub mod leave_request_use_case;
+pub mod mail_merge_use_case;
+pub mod preflight_use_case;
+pub mod preview_use_case;
 pub mod remote_work_mail_use_case;
+pub mod scheduler_use_case;
+pub mod thunderbird_detection_use_case;
+pub mod weekly_summary_use_case;
+pub mod work_statistics_use_case;
+pub mod work_time_edit_use_case;
+pub mod work_time_export_use_case;
+pub mod work_time_import_use_case;
+pub mod work_time_report_use_case;
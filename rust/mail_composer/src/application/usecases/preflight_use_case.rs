@@ -0,0 +1,201 @@
+use crate::domain::interfaces::{address_book::AddressBookPort, mail_config::MailConfigPort};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+
+/// 名前解決に失敗したテンプレートの宛先
+#[derive(Debug)]
+pub struct UnresolvedRecipient {
+    /// メールの種類（mail_templates.jsonのキー）
+    pub mail_type: String,
+    /// 宛先の種類（`to_names`または`cc_names`）
+    pub field: &'static str,
+    /// 解決できなかった名前
+    pub name: String,
+    /// 解決に失敗した原因
+    pub error: AppError,
+}
+
+/// 送信前チェックの結果
+#[derive(Debug, Default)]
+pub struct PreflightReport {
+    /// 確認したメールの種類の数
+    pub checked_mail_types: usize,
+    /// 名前解決に失敗した宛先
+    pub unresolved: Vec<UnresolvedRecipient>,
+}
+
+impl PreflightReport {
+    /// 全ての宛先が解決できたかどうか
+    pub fn is_ready(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+/// 全てのメールテンプレートの宛先がアドレスブックで解決できるかを送信前に確認するユースケース
+pub struct PreflightUseCase<A, MC>
+where
+    A: AddressBookPort,
+    MC: MailConfigPort,
+{
+    address_book_port: A,
+    mail_config_port: MC,
+}
+
+impl<A, MC> PreflightUseCase<A, MC>
+where
+    A: AddressBookPort,
+    MC: MailConfigPort,
+{
+    /// 新しいPreflightUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `address_book_port` - 宛先の解決に使用するアドレスブック
+    /// * `mail_config_port` - メールテンプレートの読み込みに使用するポート
+    ///
+    /// ## Returns
+    /// * PreflightUseCaseのインスタンス
+    pub fn new(address_book_port: A, mail_config_port: MC) -> Self {
+        Self {
+            address_book_port,
+            mail_config_port,
+        }
+    }
+
+    /// 全てのメールテンプレートの`to_names`と`cc_names`を解決し、失敗したものを全て報告する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<PreflightReport>`
+    /// * 失敗時 - メールテンプレートの読み込みに失敗した場合の`Err<AppError>`
+    pub fn check(&self) -> AppResult<PreflightReport> {
+        let _span = logging::operation_span("preflight").entered();
+        let mail_config = self.mail_config_port.load_mail_config()?;
+        let mut mail_types: Vec<_> = mail_config.mail_types.iter().collect();
+        mail_types.sort_by_key(|(mail_type, _)| mail_type.as_str());
+
+        let mut report = PreflightReport {
+            checked_mail_types: mail_types.len(),
+            ..Default::default()
+        };
+        for (mail_type, config) in mail_types {
+            let fields = [
+                ("to_names", &config.to_names),
+                ("cc_names", &config.cc_names),
+            ];
+            for (field, names) in fields {
+                for name in names {
+                    if let Err(error) = self.address_book_port.expand(name) {
+                        report.unresolved.push(UnresolvedRecipient {
+                            mail_type: mail_type.clone(),
+                            field,
+                            name: name.clone(),
+                            error,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// 全ての宛先が解決できることを確認する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 解決できなかった宛先を全て列挙した`Err<AppError>`
+    pub fn ensure_ready(&self) -> AppResult<()> {
+        let report = self.check()?;
+        if report.is_ready() {
+            return Ok(());
+        }
+
+        let details = report
+            .unresolved
+            .iter()
+            .map(|r| {
+                format!(
+                    "{}.{}: {}（{}）",
+                    r.mail_type, r.field, r.name, r.error.message
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+            .with_code("MC-CONF-005")
+            .with_message(format!(
+                "{}件の宛先をアドレスブックで解決できません。\n{details}",
+                report.unresolved.len()
+            ))
+            .with_action("mail_templates.jsonの宛先と、アドレスブックの内容を確認してください。"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::mail_config::MailConfig,
+        infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter,
+    };
+
+    struct StubMailConfig;
+
+    impl MailConfigPort for StubMailConfig {
+        fn load_mail_config(&self) -> Result<MailConfig, AppError> {
+            let mail_types = serde_json::from_str(
+                r#"{
+                    "remote_work_start": {
+                        "to_names": ["山田さん", "田中さん"],
+                        "cc_names": ["開発チーム"],
+                        "subject_template": "",
+                        "body_template": ""
+                    },
+                    "remote_work_end": {
+                        "to_names": ["山田さん"],
+                        "cc_names": ["鈴木さん"],
+                        "subject_template": "",
+                        "body_template": ""
+                    }
+                }"#,
+            )?;
+            Ok(MailConfig {
+                mail_types,
+                ..MailConfig::default()
+            })
+        }
+    }
+
+    #[test]
+    fn test_check_reports_all_unresolved() {
+        let address_book = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "開発チーム", "members": ["山田さん"] }
+            ]"#,
+        )
+        .unwrap();
+        let use_case = PreflightUseCase::new(address_book, StubMailConfig);
+
+        let report = use_case.check().unwrap();
+
+        assert_eq!(report.checked_mail_types, 2);
+        let unresolved: Vec<(&str, &str, &str)> = report
+            .unresolved
+            .iter()
+            .map(|r| (r.mail_type.as_str(), r.field, r.name.as_str()))
+            .collect();
+        assert_eq!(
+            unresolved,
+            vec![
+                ("remote_work_end", "cc_names", "鈴木さん"),
+                ("remote_work_start", "to_names", "田中さん"),
+            ]
+        );
+        assert!(use_case.ensure_ready().is_err());
+    }
+}
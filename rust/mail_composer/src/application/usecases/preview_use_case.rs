@@ -0,0 +1,139 @@
+use crate::{
+    application::usecases::generic_mail_use_case::compose_draft,
+    domain::{
+        entities::mail_draft::MailDraft,
+        interfaces::{
+            address_book::AddressBookPort, configuration::ConfigurationPort,
+            mail_config::MailConfigPort,
+        },
+    },
+};
+use share::error::app_error::AppResult;
+use std::collections::HashMap;
+
+/// メールを送信せずに、宛先と件名・本文を展開した結果を確認するユースケース
+///
+/// メールクライアントを一切使用せず、送信時と同じ手順で宛先を解決し、テンプレートから件名・本文を生成する。
+/// メールクライアントの起動引数を表示するドライランとは異なり、実際に作成されるメールの内容を確認できる。
+/// 作成したメールドラフトの表示や書き出しは呼び出し側が行う
+pub struct PreviewUseCase<A, C, MC>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    MC: MailConfigPort,
+{
+    address_book_port: A,
+    configuration_port: C,
+    mail_config_port: MC,
+}
+
+impl<A, C, MC> PreviewUseCase<A, C, MC>
+where
+    A: AddressBookPort,
+    C: ConfigurationPort,
+    MC: MailConfigPort,
+{
+    /// 新しいPreviewUseCaseを作成する
+    pub fn new(address_book_port: A, configuration_port: C, mail_config_port: MC) -> Self {
+        Self {
+            address_book_port,
+            configuration_port,
+            mail_config_port,
+        }
+    }
+
+    /// 指定したメール種別のメールドラフトを作成する
+    ///
+    /// ## Arguments
+    /// * `mail_type` - `mail_templates.json`のメール種別
+    /// * `extra_vars` - 実行時に指定する値（`variables`の同じ名前の値より優先する）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<MailDraft>`
+    /// * 失敗時 - テンプレートが見つからない場合、宛先を解決できない場合、または件名・本文の生成に失敗した場合の`Err<AppError>`
+    pub fn render(
+        &self,
+        mail_type: &str,
+        extra_vars: &HashMap<String, String>,
+    ) -> AppResult<MailDraft> {
+        compose_draft(
+            &self.address_book_port,
+            &self.configuration_port,
+            &self.mail_config_port,
+            mail_type,
+            extra_vars,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::{app_configuration::AppConfiguration, mail_config::MailConfig},
+        infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter,
+    };
+
+    struct StubConfiguration;
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(AppConfiguration {
+                from: "佐藤".to_string(),
+                department: "開発部".to_string(),
+                thunderbird_exe: "/opt/thunderbird".to_string(),
+                subject_prefix: Some("[社内]".to_string()),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    struct StubMailConfig;
+
+    impl MailConfigPort for StubMailConfig {
+        fn load_mail_config(&self) -> AppResult<MailConfig> {
+            Ok(MailConfig {
+                mail_types: serde_json::from_str(
+                    r#"{
+                        "release_notice": {
+                            "to_names": ["開発チーム"],
+                            "cc_names": ["部長"],
+                            "subject_template": "【リリース】{version}",
+                            "body_template": "{version}をリリースしました。"
+                        }
+                    }"#,
+                )?,
+                ..MailConfig::default()
+            })
+        }
+    }
+
+    fn use_case() -> PreviewUseCase<JsonAddressBookAdapter, StubConfiguration, StubMailConfig> {
+        let address_book = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田", "address": "yamada@example.com" },
+                { "name": "田中", "address": "tanaka@example.com" },
+                { "name": "部長", "address": "boss@example.com" },
+                { "name": "開発チーム", "members": ["山田", "田中"] }
+            ]"#,
+        )
+        .unwrap();
+        PreviewUseCase::new(address_book, StubConfiguration, StubMailConfig)
+    }
+
+    #[test]
+    fn test_render() {
+        let extra_vars = HashMap::from([("version".to_string(), "v2.1".to_string())]);
+
+        let draft = use_case().render("release_notice", &extra_vars).unwrap();
+
+        assert_eq!(draft.to().len(), 2);
+        assert_eq!(draft.cc()[0].as_str(), "boss@example.com");
+        assert_eq!(draft.subject().as_str(), "[社内]【リリース】v2.1");
+        assert!(use_case().render("unknown", &extra_vars).is_err());
+    }
+}
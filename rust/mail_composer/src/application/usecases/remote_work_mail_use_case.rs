@@ -1,39 +1,77 @@
-use crate::domain::{
-    entities::mail_draft::MailDraft,
-    interfaces::{
-        address_book::AddressBookPort, configuration::ConfigurationPort,
-        mail_client::MailClientPort, mail_config::MailConfigPort, work_time::WorkTimePort,
+use crate::{
+    application::usecases::{
+        flex_balance_use_case::calculate_flex_balance, generic_mail_use_case::existing_attachments,
+        weekly_summary_use_case::summarize_week,
     },
-    value_objects::{
-        email_address::EmailAddress,
-        mail_objects::{MailBody, Subject, WorkTime, WorkTimeRange},
+    domain::{
+        entities::{mail_draft::MailDraft, work_record::WorkRecord},
+        interfaces::{
+            address_book::AddressBookPort, configuration::ConfigurationPort,
+            confirmation::ConfirmationPort, daily_notes::DailyNotesPort,
+            holiday_calendar::HolidayCalendarPort, mail_client::MailClientPort,
+            mail_config::MailConfigPort, prompt::PromptPort, work_time::WorkTimePort,
+        },
+        value_objects::{
+            email_address::EmailAddress,
+            mail_objects::{MailBody, Subject, WorkTime, WorkTimeRange},
+            mail_template::date_values,
+            recipient_overrides::RecipientOverrides,
+        },
     },
 };
-use share::error::app_error::AppResult;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Utc, Weekday};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+
+/// 週報メールのテンプレートのメール種別
+pub const WEEKLY_REPORT_MAIL_TYPE: &str = "weekly_report";
+
+/// 勤怠メールを送信する前に確認を促す休日の種類
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayOff {
+    /// 土曜日
+    Saturday,
+    /// 日曜日
+    Sunday,
+    /// 祝日（祝日名）
+    Holiday(String),
+}
 
 /// 在宅勤務メール作成のユースケース
-pub struct RemoteWorkMailUseCase<A, C, M, W, MC>
+pub struct RemoteWorkMailUseCase<A, C, M, W, MC, CF>
 where
     A: AddressBookPort,
     C: ConfigurationPort,
     M: MailClientPort,
     W: WorkTimePort,
     MC: MailConfigPort,
+    CF: ConfirmationPort,
 {
     address_book_port: A,
     configuration_port: C,
     mail_client_port: M,
     work_time_port: W,
     mail_config_port: MC,
+    confirmation_port: CF,
+    holiday_calendar: Option<Box<dyn HolidayCalendarPort>>,
+    daily_notes: Option<Box<dyn DailyNotesPort>>,
+    tasks_prompt: Option<Box<dyn PromptPort>>,
+    recipient_overrides: RecipientOverrides,
 }
 
-impl<A, C, M, W, MC> RemoteWorkMailUseCase<A, C, M, W, MC>
+impl<A, C, M, W, MC, CF> RemoteWorkMailUseCase<A, C, M, W, MC, CF>
 where
     A: AddressBookPort,
     C: ConfigurationPort,
     M: MailClientPort,
     W: WorkTimePort,
     MC: MailConfigPort,
+    CF: ConfirmationPort,
 {
     /// 新しいRemoteWorkMailUseCaseを作成する
     pub fn new(
@@ -42,6 +80,7 @@ where
         mail_client_port: M,
         work_time_port: W,
         mail_config_port: MC,
+        confirmation_port: CF,
     ) -> Self {
         Self {
             address_book_port,
@@ -49,12 +88,218 @@ where
             mail_client_port,
             work_time_port,
             mail_config_port,
+            confirmation_port,
+            holiday_calendar: None,
+            daily_notes: None,
+            tasks_prompt: None,
+            recipient_overrides: RecipientOverrides::default(),
+        }
+    }
+
+    /// 休日に勤怠メールを送信する際の警告に使用する祝日カレンダーを指定する
+    ///
+    /// ## Arguments
+    /// * `holiday_calendar` - 祝日の判定に使用するポート
+    ///
+    /// ## Returns
+    /// * 祝日カレンダーが設定されたRemoteWorkMailUseCaseのインスタンス
+    pub fn with_holiday_calendar(
+        mut self,
+        holiday_calendar: impl HolidayCalendarPort + 'static,
+    ) -> Self {
+        self.holiday_calendar = Some(Box::new(holiday_calendar));
+        self
+    }
+
+    /// 在宅勤務終了メールの`{tasks_done}`に埋め込む作業メモの取得元を指定する
+    ///
+    /// ## Arguments
+    /// * `daily_notes` - 日ごとの作業メモを取得するポート
+    ///
+    /// ## Returns
+    /// * 作業メモの取得元が設定されたRemoteWorkMailUseCaseのインスタンス
+    pub fn with_daily_notes(mut self, daily_notes: impl DailyNotesPort + 'static) -> Self {
+        self.daily_notes = Some(Box::new(daily_notes));
+        self
+    }
+
+    /// 作業メモがない場合に、`{tasks_done}`に埋め込む作業内容の入力を求めるプロンプトを指定する
+    ///
+    /// ## Arguments
+    /// * `prompt` - 作業内容の入力に使用するポート
+    ///
+    /// ## Returns
+    /// * プロンプトが設定されたRemoteWorkMailUseCaseのインスタンス
+    pub fn with_tasks_prompt(mut self, prompt: impl PromptPort + 'static) -> Self {
+        self.tasks_prompt = Some(Box::new(prompt));
+        self
+    }
+
+    /// テンプレートの宛先を置き換える・追加する宛先を指定する
+    ///
+    /// ## Arguments
+    /// * `recipient_overrides` - 今回の送信に限り使用する宛先の指定
+    ///
+    /// ## Returns
+    /// * 宛先の指定が設定されたRemoteWorkMailUseCaseのインスタンス
+    pub fn with_recipient_overrides(mut self, recipient_overrides: RecipientOverrides) -> Self {
+        self.recipient_overrides = recipient_overrides;
+        self
+    }
+
+    /// 今日が休日かどうかを判定する
+    ///
+    /// 土日は常に、祝日は祝日カレンダーを指定した場合に休日とする。
+    /// 勤怠メールを送信する前の警告は、呼び出し側が表示言語に合わせて作成する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Option<DayOff>>` (休日の場合はその種類、勤務日の場合は`None`)
+    /// * 失敗時 - 設定の読み込みまたは祝日の判定に失敗した場合の`Err<AppError>`
+    pub fn day_off(&self) -> AppResult<Option<DayOff>> {
+        let config = self.configuration_port.load_configuration()?;
+        self.day_off_on(config.time_zone()?.today())
+    }
+
+    /// 指定日が休日かどうかを判定する
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付（過去の日付の記録を補う場合はその日付）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Option<DayOff>>` (休日の場合はその種類、勤務日の場合は`None`)
+    /// * 失敗時 - 祝日の判定に失敗した場合の`Err<AppError>`
+    pub fn day_off_on(&self, date: NaiveDate) -> AppResult<Option<DayOff>> {
+        let holiday_name = match &self.holiday_calendar {
+            Some(calendar) => calendar.holiday_name(date)?,
+            None => None,
+        };
+        Ok(match holiday_name {
+            Some(name) => Some(DayOff::Holiday(name)),
+            None => match date.weekday() {
+                Weekday::Sat => Some(DayOff::Saturday),
+                Weekday::Sun => Some(DayOff::Sunday),
+                _ => None,
+            },
+        })
+    }
+
+    /// 指定日に行った作業を取得する
+    ///
+    /// 作業メモがある場合はその内容、ない場合はプロンプトで入力された内容を使用する
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<String>` (作業内容、作業メモもプロンプトもない場合は空文字列)
+    /// * 失敗時 - 作業メモの読み込みまたは入力に失敗した場合の`Err<AppError>`
+    fn tasks_done(&self, date: NaiveDate) -> AppResult<String> {
+        if let Some(daily_notes) = &self.daily_notes
+            && let Some(note) = daily_notes.load_note(date)?
+        {
+            return Ok(note);
+        }
+        match &self.tasks_prompt {
+            Some(prompt) => prompt.ask("本日の作業内容", None),
+            None => Ok(String::new()),
         }
     }
 
+    /// 送信前に確認を行い、メールドラフトを作成・送信する
+    ///
+    /// ドライランの場合は確認を行わない
+    ///
+    /// ## Arguments
+    /// * `draft` - メールドラフト
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 送信が中止された場合または送信に失敗した場合の`Err<AppError>`
+    fn compose_with_confirmation(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        self.confirm(draft, is_dry_run)?;
+        self.mail_client_port.compose_mail(draft, is_dry_run)
+    }
+
+    /// 送信前に確認を行う（ドライランの場合は確認を行わない）
+    ///
+    /// ## Arguments
+    /// * `draft` - メールドラフト
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 送信が中止された場合の`Err<AppError>`
+    fn confirm(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        if !is_dry_run && !self.confirmation_port.confirm(draft)? {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-MAIL-009")
+                .with_message("メールの送信を中止しました。")
+                .with_action("宛先と内容を確認し、再度実行してください。"));
+        }
+        Ok(())
+    }
+
     /// 名前のリストからメールアドレスのリストを解決する
-    fn resolve_email_addresses(&self, names: &[&str]) -> AppResult<Vec<EmailAddress>> {
-        self.address_book_port.resolve_many(names)
+    fn resolve_email_addresses(&self, names: &[String]) -> AppResult<Vec<EmailAddress>> {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        self.address_book_port.resolve_many(&names)
+    }
+
+    /// テンプレートの宛先に宛先の指定を反映し、To・Ccのメールアドレスを解決する
+    fn resolve_recipients(
+        &self,
+        to_names: &[String],
+        cc_names: &[String],
+    ) -> AppResult<(Vec<EmailAddress>, Vec<EmailAddress>)> {
+        let to_addresses =
+            self.resolve_email_addresses(&self.recipient_overrides.apply_to(to_names))?;
+        let cc_addresses =
+            self.resolve_email_addresses(&self.recipient_overrides.apply_cc(cc_names))?;
+        Ok((to_addresses, cc_addresses))
+    }
+
+    /// 記録する日時が未来でないことを確認する
+    fn ensure_not_future(at: &DateTime<FixedOffset>) -> AppResult<()> {
+        if *at > Utc::now() {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-MAIL-010")
+                .with_message(format!("未来の日時は記録できません。詳細: {at}"))
+                .with_action("--date・--timeには現在以前の日時を指定してください。"));
+        }
+        Ok(())
+    }
+
+    /// 作業開始日時を記録できる（未来の日時でも出張の日でもない）ことを確認する
+    fn ensure_startable(&self, started_at: &DateTime<FixedOffset>) -> AppResult<()> {
+        Self::ensure_not_future(started_at)?;
+
+        // 出張連絡で在宅勤務開始メールを抑止した日は記録しない
+        let date = started_at.date_naive();
+        if self
+            .work_time_port
+            .load_record(date)?
+            .is_some_and(|record| record.is_business_trip())
+        {
+            return Err(AppError::new(ErrorKind::Conflict)
+                .with_code("MC-MAIL-011")
+                .with_message(format!("{date}は出張として記録されているため、在宅勤務開始メールを作成しません。"))
+                .with_action("在宅勤務に変更した場合は、作業時間ファイルの備考から出張の記録を削除してください。"));
+        }
+        Ok(())
+    }
+
+    /// メールを作成せずに、指定した日時を作業開始日時として記録する
+    ///
+    /// ## Arguments
+    /// * `started_at` - 作業開始日時（記録し忘れた日を補う場合はその日時）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 未来の日時の場合、出張の日の場合、または保存に失敗した場合の`Err<AppError>`
+    pub fn record_remote_work_start_at(&self, started_at: &DateTime<FixedOffset>) -> AppResult<()> {
+        self.ensure_startable(started_at)?;
+        self.work_time_port.save_today_start_time(started_at)
     }
 
     /// 在宅勤務開始メールを作成・送信する
@@ -66,46 +311,99 @@ where
     /// * 成功時 - `Ok(())`
     /// * 失敗時 - `Err<AppError>`
     pub fn send_remote_work_start(&self, is_dry_run: bool) -> AppResult<()> {
+        let config = self.configuration_port.load_configuration()?;
+        self.send_remote_work_start_at(&config.time_zone()?.now(), is_dry_run)
+    }
+
+    /// 指定した日時を作業開始日時として記録し、在宅勤務開始メールを作成・送信する
+    ///
+    /// ## Arguments
+    /// * `started_at` - 作業開始日時（記録し忘れた日を補う場合はその日時）
+    /// * `is_dry_run` - ドライランモード（作業開始日時は記録しない）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn send_remote_work_start_at(
+        &self,
+        started_at: &DateTime<FixedOffset>,
+        is_dry_run: bool,
+    ) -> AppResult<()> {
+        let _span = logging::operation_span("remote_work_start").entered();
         let config = self.configuration_port.load_configuration()?;
         let mail_config = self.mail_config_port.load_mail_config()?;
 
         // 在宅勤務開始設定を取得
         let start_config = mail_config
-            .get_mail_type("remote_work_start")
+            .get_localized_mail_type("remote_work_start", config.locale.as_deref())
             .ok_or_else(|| {
-                share::error::app_error::AppError::new(share::error::kind::ErrorKind::NotFound)
+                AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-MAIL-012")
                     .with_message("remote_work_start 設定が見つかりません")
             })?;
 
-        // 現在時刻を取得
-        let now_time = WorkTime::now()?;
+        // 設定されたタイムゾーンでの開始時刻
+        let started_at = config.time_zone()?.localize(*started_at);
+        let now_time = WorkTime::from_time(started_at.time());
 
-        // 作業開始時刻を保存
-        self.work_time_port.save_today_start_time(&now_time)?;
+        // メールアドレスを解決（宛先の誤りで開始日時だけが記録されないよう、記録より先に行う）
+        let (to_addresses, cc_addresses) =
+            self.resolve_recipients(&start_config.to_names, &start_config.cc_names)?;
 
-        // メールアドレスを解決
-        let to_names: Vec<&str> = start_config.to_names.iter().map(|s| s.as_str()).collect();
-        let cc_names: Vec<&str> = start_config.cc_names.iter().map(|s| s.as_str()).collect();
-        let to_addresses = self.resolve_email_addresses(&to_names)?;
-        let cc_addresses = self.resolve_email_addresses(&cc_names)?;
+        // 記録できる日時かどうかは問い合わせる前に確認する
+        self.ensure_startable(&started_at)?;
 
-        // 件名と本文をテンプレートから生成
-        let subject = Subject::new(start_config.format_subject(
+        // 件名と本文をテンプレートから生成（対象日の値は利用者定義の値より優先する）
+        let mut variables = mail_config.template_variables(&config.variables);
+        variables.extend(date_values(started_at.date_naive()));
+        let subject = Subject::new(config.decorate_subject(&start_config.format_subject(
+            &variables,
             &config.department,
             &config.from,
             now_time.as_str(),
-        ))?;
+        )?))?;
 
-        let body = MailBody::new(&start_config.format_body(None));
+        let body = MailBody::new(&start_config.format_body(&variables, &[])?);
 
         // メールドラフトを作成
-        let draft = MailDraft::new(to_addresses, cc_addresses, subject, body);
+        let html_body = start_config.html_body(&body);
+        let attachments = existing_attachments(start_config, &variables, started_at.date_naive())?;
+        let draft = MailDraft::new(to_addresses, cc_addresses, subject, body)
+            .with_headers(start_config.headers.clone())?
+            .with_html_body(html_body)
+            .with_attachments(attachments)
+            .with_detail("作業開始", started_at.format("%Y-%m-%d %H:%M").to_string());
+
+        // 送信を確認してから作業開始日時を保存する（中止した場合とドライランの場合は記録しない）
+        self.confirm(&draft, is_dry_run)?;
+        if !is_dry_run {
+            self.work_time_port.save_today_start_time(&started_at)?;
+        }
+
         // メール送信/ドライラン
         self.mail_client_port.compose_mail(&draft, is_dry_run)
     }
 
+    /// メールを作成せずに、指定した日時を作業終了日時として記録する
+    ///
+    /// ## Arguments
+    /// * `ended_at` - 作業終了日時（記録し忘れた日を補う場合はその日時）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - 未来の日時の場合、または保存に失敗した場合の`Err<AppError>`
+    pub fn record_remote_work_end_at(
+        &self,
+        ended_at: &DateTime<FixedOffset>,
+    ) -> AppResult<WorkRecord> {
+        Self::ensure_not_future(ended_at)?;
+        self.work_time_port.save_today_end_time(ended_at)
+    }
+
     /// 在宅勤務終了メールを作成・送信する
     ///
+    /// 本文で`{tasks_done}`を使用している場合は、その日の作業メモ（ない場合はプロンプトで入力した内容）を埋め込む
+    ///
     /// ## Arguments
     /// * `is_dry_run` - ドライランモード
     ///
@@ -113,105 +411,510 @@ where
     /// * 成功時 - `Ok(())`
     /// * 失敗時 - `Err<AppError>`
     pub fn send_remote_work_end(&self, is_dry_run: bool) -> AppResult<()> {
+        let config = self.configuration_port.load_configuration()?;
+        self.send_remote_work_end_at(&config.time_zone()?.now(), is_dry_run)
+    }
+
+    /// 指定した日時を作業終了日時として記録し、在宅勤務終了メールを作成・送信する
+    ///
+    /// 本文で`{tasks_done}`を使用している場合は、その日の作業メモ（ない場合はプロンプトで入力した内容）を埋め込む
+    ///
+    /// ## Arguments
+    /// * `ended_at` - 作業終了日時（記録し忘れた日を補う場合はその日時）
+    /// * `is_dry_run` - ドライランモード（作業終了日時は記録しない）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn send_remote_work_end_at(
+        &self,
+        ended_at: &DateTime<FixedOffset>,
+        is_dry_run: bool,
+    ) -> AppResult<()> {
+        let _span = logging::operation_span("remote_work_end").entered();
         let config = self.configuration_port.load_configuration()?;
         let mail_config = self.mail_config_port.load_mail_config()?;
 
         // 在宅勤務終了設定を取得
         let end_config = mail_config
-            .get_mail_type("remote_work_end")
+            .get_localized_mail_type("remote_work_end", config.locale.as_deref())
             .ok_or_else(|| {
-                share::error::app_error::AppError::new(share::error::kind::ErrorKind::NotFound)
+                AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-MAIL-013")
                     .with_message("remote_work_end 設定が見つかりません")
             })?;
 
-        // 現在時刻を取得
-        let end_time = WorkTime::now()?;
+        // 設定されたタイムゾーンでの終了時刻
+        let time_zone = config.time_zone()?;
+        let ended_at = time_zone.localize(*ended_at);
+        let end_time = WorkTime::from_time(ended_at.time());
 
-        // 今日の開始時刻を読み込み
-        let start_time = self
-            .work_time_port
-            .load_today_start_time()?
-            .unwrap_or_else(|| WorkTime::new("--:--").unwrap());
+        // メールアドレスを解決（宛先の誤りで終了日時だけが記録されないよう、記録より先に行う）
+        let (to_addresses, cc_addresses) =
+            self.resolve_recipients(&end_config.to_names, &end_config.cc_names)?;
 
-        // メールアドレスを解決
-        let to_names: Vec<&str> = end_config.to_names.iter().map(|s| s.as_str()).collect();
-        let cc_names: Vec<&str> = end_config.cc_names.iter().map(|s| s.as_str()).collect();
-        let to_addresses = self.resolve_email_addresses(&to_names)?;
-        let cc_addresses = self.resolve_email_addresses(&cc_names)?;
+        // 記録後の作業記録を求め、その日の開始時刻を取得（別のタイムゾーンで記録した開始時刻も変換する）
+        // 終了日時は送信を確認してから保存する
+        Self::ensure_not_future(&ended_at)?;
+        let stored = self
+            .work_time_port
+            .load_record(ended_at.date_naive())?
+            .unwrap_or_default();
+        let mut record = stored.clone();
+        record.set_ended_at(ended_at);
+        let record = record.localized(&time_zone);
 
-        // 作業時間範囲を作成
-        let work_range = WorkTimeRange::new(start_time, end_time.clone());
+        // 最初の開始から最後の終了までの範囲を作成（開始時刻が記録されていない場合は不明として表示する）
+        let work_time = match &record.start {
+            Some(start_time) => {
+                WorkTimeRange::new(start_time.clone(), end_time.clone()).to_string()
+            }
+            None => format!("--:---{}", end_time.as_str()),
+        };
+        // 一時停止と再開で区切られた作業区間
+        let sessions = record
+            .sessions()
+            .iter()
+            .map(|session| session.label())
+            .collect::<Vec<_>>()
+            .join(" / ");
 
-        // 件名と本文をテンプレートから生成
-        let subject = Subject::new(end_config.format_subject(
+        // 件名と本文をテンプレートから生成（対象日の値は利用者定義の値より優先する）
+        let mut variables = mail_config.template_variables(&config.variables);
+        variables.extend(date_values(ended_at.date_naive()));
+        let subject = Subject::new(config.decorate_subject(&end_config.format_subject(
+            &variables,
             &config.department,
             &config.from,
             end_time.as_str(),
-        ))?;
+        )?))?;
+
+        // 休憩を除いた作業時間と、所定労働時間を超えた分を残業時間として設定された形式で表示する
+        // （開始時刻が未記録の場合はどちらも`--:--`とする）
+        let standard_hours = config.standard_work_hours()?;
+        let duration_format = config.duration_format.unwrap_or_default();
+        let duration = record.duration().map_or_else(
+            || "--:--".to_string(),
+            |minutes| duration_format.format(minutes),
+        );
+        let overtime = record.duration().map_or_else(
+            || "--:--".to_string(),
+            |minutes| duration_format.format(standard_hours.daily_overtime(minutes)),
+        );
+
+        // 月初から今日までのフレックスタイムの貸借（保存前の今日の作業時間を実績に反映する）
+        let mut flex = calculate_flex_balance(
+            &self.work_time_port,
+            ended_at.date_naive(),
+            standard_hours,
+            self.holiday_calendar.as_deref(),
+        )?;
+        flex.add_worked_minutes(
+            record.duration().unwrap_or_default() - stored.duration().unwrap_or_default(),
+        );
+        let flex_balance = flex.format_balance(duration_format);
+
+        // 本文で使用している場合のみ、作業メモの読み込みや入力を行う
+        let tasks_done = if end_config.body_uses("tasks_done") {
+            self.tasks_done(ended_at.date_naive())?
+        } else {
+            String::new()
+        };
 
-        let body = MailBody::new(&end_config.format_body(Some(&work_range.to_string())));
+        let values = [
+            ("work_time", work_time.as_str()),
+            ("sessions", sessions.as_str()),
+            ("duration", duration.as_str()),
+            ("overtime", overtime.as_str()),
+            ("flex_balance", flex_balance.as_str()),
+            ("tasks_done", tasks_done.as_str()),
+        ];
+        let body = MailBody::new(&end_config.format_body(&variables, &values)?);
 
         // メールドラフトを作成
-        let draft = MailDraft::new(to_addresses, cc_addresses, subject, body);
+        let html_body = end_config.html_body(&body);
+        let attachments = existing_attachments(end_config, &variables, ended_at.date_naive())?;
+        let draft = MailDraft::new(to_addresses, cc_addresses, subject, body)
+            .with_headers(end_config.headers.clone())?
+            .with_html_body(html_body)
+            .with_attachments(attachments)
+            .with_detail("作業時間", work_time.as_str())
+            .with_detail("実働", duration.as_str())
+            .with_detail("残業", overtime.as_str())
+            .with_detail("フレックス貸借", flex_balance.as_str());
+
+        // 送信を確認してから終了日時と作業時間を保存する（中止した場合とドライランの場合は記録しない）
+        self.confirm(&draft, is_dry_run)?;
+        if !is_dry_run {
+            self.record_remote_work_end_at(&ended_at)?;
+        }
 
         // メール送信/ドライラン
         self.mail_client_port.compose_mail(&draft, is_dry_run)
     }
+
+    /// 今週の勤務状況を要約した週報メールを作成・送信する
+    ///
+    /// `mail_templates.json`の`weekly_report`を使用し、本文の`{period}`に集計期間、
+    /// `{summary}`に勤務日数・合計作業時間・終了時刻の未記録日、`{daily_table}`に日ごとの開始・終了時刻と作業時間の表を埋め込む
+    ///
+    /// ## Arguments
+    /// * `is_dry_run` - ドライランモード
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn send_weekly_report(&self, is_dry_run: bool) -> AppResult<()> {
+        let _span = logging::operation_span("weekly_report").entered();
+        let config = self.configuration_port.load_configuration()?;
+        let mail_config = self.mail_config_port.load_mail_config()?;
+
+        // 週報設定を取得
+        let report_config = mail_config
+            .get_localized_mail_type(WEEKLY_REPORT_MAIL_TYPE, config.locale.as_deref())
+            .ok_or_else(|| {
+                AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-MAIL-014")
+                    .with_message("weekly_report 設定が見つかりません")
+                    .with_action(
+                        "mail_templates.jsonにweekly_reportのテンプレートを追加してください。",
+                    )
+            })?;
+
+        // 今週の作業記録を集計
+        let time_zone = config.time_zone()?;
+        let today = time_zone.today();
+        let summary = summarize_week(&self.work_time_port, today, &time_zone)?;
+        let period = summary.period();
+        let duration_format = config.duration_format.unwrap_or_default();
+        let summary_text = summary.render(duration_format);
+        let daily_table = summary.render_daily_table(duration_format);
+
+        // メールアドレスを解決
+        let (to_addresses, cc_addresses) =
+            self.resolve_recipients(&report_config.to_names, &report_config.cc_names)?;
+
+        // 件名と本文をテンプレートから生成（件名の{time}には集計期間を使用する）
+        let mut variables = mail_config.template_variables(&config.variables);
+        variables.extend(date_values(today));
+        let subject = Subject::new(config.decorate_subject(&report_config.format_subject(
+            &variables,
+            &config.department,
+            &config.from,
+            &period,
+        )?))?;
+        let body = MailBody::new(&report_config.format_body(
+            &variables,
+            &[
+                ("period", &period),
+                ("summary", &summary_text),
+                ("daily_table", &daily_table),
+            ],
+        )?);
+
+        // メールドラフトを作成
+        let html_body = report_config.html_body(&body);
+        let attachments = existing_attachments(report_config, &variables, today)?;
+        let draft = MailDraft::new(to_addresses, cc_addresses, subject, body)
+            .with_headers(report_config.headers.clone())?
+            .with_html_body(html_body)
+            .with_attachments(attachments);
+
+        // メール送信/ドライラン
+        self.compose_with_confirmation(&draft, is_dry_run)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::infrastructure::outbound::{
+        japanese_holiday_calendar_adapter::JapaneseHolidayCalendarAdapter,
         json_address_book_adapter::JsonAddressBookAdapter,
         json_configuration_adapter::JsonConfigurationAdapter,
         json_mail_config_adapter::JsonMailConfigAdapter,
         json_work_time_adapter::JsonWorkTimeAdapter,
+        terminal_confirmation_adapter::AutoConfirmationAdapter,
         thunderbird_mail_client_adapter::ThunderbirdMailClientAdapter,
     };
+    use tempfile::TempDir;
+
+    /// 一時ディレクトリに作業記録を保存するアダプターを作成する
+    fn work_time_in(dir: &TempDir) -> JsonWorkTimeAdapter {
+        JsonWorkTimeAdapter::new(dir.path().to_string_lossy(), "work_times.json")
+    }
+
+    #[test]
+    fn test_day_off() {
+        let dir = tempfile::tempdir().unwrap();
+        let use_case = RemoteWorkMailUseCase::new(
+            JsonAddressBookAdapter::from_json_str("[]").unwrap(),
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time_in(&dir),
+            JsonMailConfigAdapter::new(),
+            AutoConfirmationAdapter,
+        );
+        let date = |value: &str| value.parse::<NaiveDate>().unwrap();
+
+        assert_eq!(use_case.day_off_on(date("2025-04-29")).unwrap(), None);
+        assert_eq!(
+            use_case.day_off_on(date("2025-04-26")).unwrap(),
+            Some(DayOff::Saturday)
+        );
+
+        let use_case = use_case.with_holiday_calendar(JapaneseHolidayCalendarAdapter::new());
+        assert_eq!(
+            use_case.day_off_on(date("2025-04-29")).unwrap(),
+            Some(DayOff::Holiday("昭和の日".to_string()))
+        );
+        assert_eq!(use_case.day_off_on(date("2025-04-30")).unwrap(), None);
+    }
+
+    struct StubDailyNotes;
+
+    impl DailyNotesPort for StubDailyNotes {
+        fn load_note(&self, date: NaiveDate) -> AppResult<Option<String>> {
+            Ok((date.day() == 3).then(|| "- 資料作成\n- レビュー".to_string()))
+        }
+    }
+
+    struct StubPrompt;
+
+    impl PromptPort for StubPrompt {
+        fn ask(&self, _question: &str, _default: Option<&str>) -> AppResult<String> {
+            Ok("問い合わせ対応".to_string())
+        }
+    }
+
+    #[test]
+    fn test_tasks_done() {
+        let dir = tempfile::tempdir().unwrap();
+        let use_case = RemoteWorkMailUseCase::new(
+            JsonAddressBookAdapter::from_json_str("[]").unwrap(),
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time_in(&dir),
+            JsonMailConfigAdapter::new(),
+            AutoConfirmationAdapter,
+        );
+        let date = |value: &str| value.parse::<NaiveDate>().unwrap();
+
+        assert_eq!(use_case.tasks_done(date("2024-06-03")).unwrap(), "");
+
+        let use_case = use_case.with_daily_notes(StubDailyNotes);
+        assert_eq!(
+            use_case.tasks_done(date("2024-06-03")).unwrap(),
+            "- 資料作成\n- レビュー"
+        );
+        assert_eq!(use_case.tasks_done(date("2024-06-04")).unwrap(), "");
+
+        let use_case = use_case.with_tasks_prompt(StubPrompt);
+        assert_eq!(
+            use_case.tasks_done(date("2024-06-04")).unwrap(),
+            "問い合わせ対応"
+        );
+    }
+
+    #[test]
+    fn test_record_backfilled_work_time() {
+        let dir = tempfile::tempdir().unwrap();
+        let use_case = RemoteWorkMailUseCase::new(
+            JsonAddressBookAdapter::from_json_str("[]").unwrap(),
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time_in(&dir),
+            JsonMailConfigAdapter::new(),
+            AutoConfirmationAdapter,
+        );
+        let at = |value: &str| DateTime::parse_from_rfc3339(value).unwrap();
+
+        let started = use_case.record_remote_work_start_at(&at("2024-06-01T09:00:00+09:00"));
+        let record = use_case.record_remote_work_end_at(&at("2024-06-01T18:00:00+09:00"));
+        let future = use_case
+            .record_remote_work_start_at(&(Utc::now().fixed_offset() + chrono::Duration::days(1)));
+
+        assert!(started.is_ok());
+        let record = record.unwrap();
+        assert_eq!(record.start.unwrap().as_str(), "09:00");
+        assert_eq!(record.end.unwrap().as_str(), "18:00");
+        assert!(future.is_err());
+    }
+
+    struct DecliningConfirmation;
+
+    impl ConfirmationPort for DecliningConfirmation {
+        fn confirm(&self, _draft: &MailDraft) -> AppResult<bool> {
+            Ok(false)
+        }
+    }
+
+    #[test]
+    fn test_declined_mail_is_not_recorded() {
+        let dir = tempfile::tempdir().unwrap();
+        let use_case = RemoteWorkMailUseCase::new(
+            JsonAddressBookAdapter::load_from_address_book(std::path::Path::new(
+                "rust/mail_composer/config/address_book.json",
+            ))
+            .unwrap(),
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time_in(&dir),
+            JsonMailConfigAdapter::new(),
+            DecliningConfirmation,
+        );
+        let at = |value: &str| DateTime::parse_from_rfc3339(value).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+
+        let started = use_case.send_remote_work_start_at(&at("2024-06-03T09:00:00+09:00"), false);
+        let after_start = use_case.work_time_port.load_record(date);
+        use_case
+            .record_remote_work_start_at(&at("2024-06-03T09:00:00+09:00"))
+            .unwrap();
+        let ended = use_case.send_remote_work_end_at(&at("2024-06-03T18:00:00+09:00"), false);
+        let after_end = use_case.work_time_port.load_record(date);
+
+        assert_eq!(started.unwrap_err().code, Some("MC-MAIL-009"));
+        assert_eq!(after_start.unwrap(), None);
+        assert_eq!(ended.unwrap_err().code, Some("MC-MAIL-009"));
+        assert_eq!(after_end.unwrap().unwrap().end, None);
+    }
+
+    #[test]
+    fn test_resolve_recipients_with_overrides() {
+        let dir = tempfile::tempdir().unwrap();
+        let use_case = RemoteWorkMailUseCase::new(
+            JsonAddressBookAdapter::from_json_str(
+                r#"[
+                    { "name": "部長", "address": "boss@example.com" },
+                    { "name": "佐藤さん", "address": "sato@example.com" },
+                    { "name": "総務", "address": "soumu@example.com" }
+                ]"#,
+            )
+            .unwrap(),
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time_in(&dir),
+            JsonMailConfigAdapter::new(),
+            AutoConfirmationAdapter,
+        );
+        let template_to = vec!["部長".to_string()];
+        let addresses = |addresses: Vec<EmailAddress>| {
+            addresses
+                .iter()
+                .map(|address| address.as_str().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let use_case = use_case.with_recipient_overrides(
+            RecipientOverrides::new()
+                .with_to(["佐藤さん"])
+                .with_cc(["総務"]),
+        );
+        let (to, cc) = use_case.resolve_recipients(&template_to, &[]).unwrap();
+        assert_eq!(addresses(to), vec!["boss@example.com", "sato@example.com"]);
+        assert_eq!(addresses(cc), vec!["soumu@example.com"]);
+
+        let use_case = use_case.with_recipient_overrides(
+            RecipientOverrides::new()
+                .with_to(["佐藤さん"])
+                .with_replace(true),
+        );
+        let (to, _) = use_case.resolve_recipients(&template_to, &[]).unwrap();
+        assert_eq!(addresses(to), vec!["sato@example.com"]);
+
+        let use_case =
+            use_case.with_recipient_overrides(RecipientOverrides::new().with_to(["存在しない人"]));
+        assert!(use_case.resolve_recipients(&template_to, &[]).is_err());
+    }
 
     #[test]
     fn test_remote_work_start_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
         let address_book = JsonAddressBookAdapter::load_from_address_book(std::path::Path::new(
             "rust/mail_composer/config/address_book.json",
         ))
         .unwrap();
-        let config = JsonConfigurationAdapter::with_default_path();
-        let mail_client = ThunderbirdMailClientAdapter::new("thunderbird");
-        let work_time = JsonWorkTimeAdapter::with_default_settings();
-        let mail_config = JsonMailConfigAdapter::new();
+        let use_case = RemoteWorkMailUseCase::new(
+            address_book,
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time_in(&dir),
+            JsonMailConfigAdapter::new(),
+            AutoConfirmationAdapter,
+        );
+        let started_at = DateTime::parse_from_rfc3339("2024-06-03T09:00:00+09:00").unwrap();
 
-        let use_case =
-            RemoteWorkMailUseCase::new(address_book, config, mail_client, work_time, mail_config);
+        // ドライランでは作業開始日時を記録しない
+        let result = use_case.send_remote_work_start_at(&started_at, true);
 
-        // ドライランでテスト
-        let result = use_case.send_remote_work_start(true);
         assert!(result.is_ok());
+        assert_eq!(
+            use_case
+                .work_time_port
+                .load_record(started_at.date_naive())
+                .unwrap(),
+            None
+        );
+        assert!(!dir.path().join("work_times.json").exists());
     }
 
     #[test]
     fn test_remote_work_end_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
         let address_book = JsonAddressBookAdapter::load_from_address_book(std::path::Path::new(
             "rust/mail_composer/config/address_book.json",
         ))
         .unwrap();
-        let config = JsonConfigurationAdapter::with_default_path();
-        let mail_client = ThunderbirdMailClientAdapter::new("thunderbird");
-        let work_time = JsonWorkTimeAdapter::with_default_settings();
-        let mail_config = JsonMailConfigAdapter::new();
+        let work_time = work_time_in(&dir);
 
         // 事前に開始時間を設定
-        let start_time = WorkTime::new("09:00").unwrap();
-        work_time.save_today_start_time(&start_time).unwrap();
+        let started_at = DateTime::parse_from_rfc3339("2024-06-03T09:00:00+09:00").unwrap();
+        let ended_at = DateTime::parse_from_rfc3339("2024-06-03T18:00:00+09:00").unwrap();
+        work_time.save_today_start_time(&started_at).unwrap();
+        let before = std::fs::read_to_string(dir.path().join("work_times.json")).unwrap();
 
-        let use_case =
-            RemoteWorkMailUseCase::new(address_book, config, mail_client, work_time, mail_config);
+        let use_case = RemoteWorkMailUseCase::new(
+            address_book,
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time,
+            JsonMailConfigAdapter::new(),
+            AutoConfirmationAdapter,
+        );
+
+        // ドライランでは作業終了日時と作業時間を記録しない
+        let result = use_case.send_remote_work_end_at(&ended_at, true);
 
-        let result = use_case.send_remote_work_end(true);
-        match &result {
-            Ok(_) => println!("✅ Remote work end test passed!"),
-            Err(e) => println!("❌ Remote work end test failed: {}", e),
-        }
         assert!(result.is_ok());
+        let record = use_case
+            .work_time_port
+            .load_record(ended_at.date_naive())
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.end, None);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("work_times.json")).unwrap(),
+            before
+        );
+    }
+
+    #[test]
+    fn test_weekly_report_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let address_book = JsonAddressBookAdapter::load_from_address_book(std::path::Path::new(
+            "rust/mail_composer/config/address_book.json",
+        ))
+        .unwrap();
+        let use_case = RemoteWorkMailUseCase::new(
+            address_book,
+            JsonConfigurationAdapter::with_default_path(),
+            ThunderbirdMailClientAdapter::new("thunderbird"),
+            work_time_in(&dir),
+            JsonMailConfigAdapter::new(),
+            AutoConfirmationAdapter,
+        );
+
+        assert!(use_case.send_weekly_report(true).is_ok());
     }
 }
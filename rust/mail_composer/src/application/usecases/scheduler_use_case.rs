@@ -0,0 +1,234 @@
+use crate::domain::value_objects::{
+    app_configuration::{ScheduledAction, ScheduledTask},
+    time_zone::WorkTimeZone,
+};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeDelta, Timelike};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::time::Duration;
+
+/// 設定されたスケジュールに従って常駐し、処理を実行するユースケース
+///
+/// 1分ごとに現在時刻とスケジュールを照合し、一致した処理を実行する。
+/// 処理に時間がかかって過ぎた分のスケジュールも、次の照合でまとめて実行する。
+/// 処理が失敗してもログに記録して常駐を続ける
+pub struct SchedulerUseCase {
+    tasks: Vec<ScheduledTask>,
+    time_zone: WorkTimeZone,
+}
+
+impl SchedulerUseCase {
+    /// 新しいSchedulerUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `tasks` - 実行する処理とスケジュールの組
+    ///
+    /// ## Returns
+    /// * SchedulerUseCaseのインスタンス
+    pub fn new(tasks: Vec<ScheduledTask>) -> Self {
+        Self {
+            tasks,
+            time_zone: WorkTimeZone::default(),
+        }
+    }
+
+    /// スケジュールの解釈に使用するタイムゾーンを指定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * タイムゾーンが設定されたSchedulerUseCaseのインスタンス
+    pub fn with_time_zone(mut self, time_zone: WorkTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// 指定日時に実行する処理を取得する
+    ///
+    /// ## Arguments
+    /// * `at` - 判定する日時（設定したタイムゾーンでの日時）
+    ///
+    /// ## Returns
+    /// * 設定順の処理（同じ処理は1回のみ）
+    pub fn due_at(&self, at: &NaiveDateTime) -> Vec<ScheduledAction> {
+        let mut actions = Vec::new();
+        for task in &self.tasks {
+            if task.schedule.matches(at) && !actions.contains(&task.action) {
+                actions.push(task.action);
+            }
+        }
+        actions
+    }
+
+    /// 指定した分の次の分から、指定日時の分までに実行する処理を取得する
+    ///
+    /// ## Arguments
+    /// * `last_checked` - 前回照合した分（設定したタイムゾーンでの日時）
+    /// * `now` - 現在日時（設定したタイムゾーンでの日時）
+    ///
+    /// ## Returns
+    /// * 実行予定順の処理（同じ処理は1回のみ）
+    pub fn due_between(
+        &self,
+        last_checked: &NaiveDateTime,
+        now: &NaiveDateTime,
+    ) -> Vec<ScheduledAction> {
+        let mut actions = Vec::new();
+        let mut minute = *last_checked + TimeDelta::minutes(1);
+        while minute <= *now {
+            for action in self.due_at(&minute) {
+                if !actions.contains(&action) {
+                    actions.push(action);
+                }
+            }
+            minute += TimeDelta::minutes(1);
+        }
+        actions
+    }
+
+    /// 指定日時より後の、各スケジュールの次回の実行予定を取得する
+    ///
+    /// ## Arguments
+    /// * `after` - 基準日時（設定したタイムゾーンでの日時）
+    ///
+    /// ## Returns
+    /// * 実行日時順の実行予定
+    pub fn upcoming(&self, after: &NaiveDateTime) -> Vec<(NaiveDateTime, ScheduledAction)> {
+        let mut upcoming: Vec<(NaiveDateTime, ScheduledAction)> = self
+            .tasks
+            .iter()
+            .filter_map(|task| Some((task.schedule.next_after(after)?, task.action)))
+            .collect();
+        upcoming.sort_by_key(|(at, _)| *at);
+        upcoming
+    }
+
+    /// 常駐してスケジュールに従って処理を実行する
+    ///
+    /// プロセスが終了されるまで戻らない
+    ///
+    /// ## Arguments
+    /// * `execute` - 処理を実行する関数（実行する処理と、実行時点の日時を受け取る）
+    ///
+    /// ## Returns
+    /// * 失敗時 - スケジュールが設定されていない場合の`Err<AppError>`
+    pub fn run(
+        &self,
+        mut execute: impl FnMut(ScheduledAction, DateTime<FixedOffset>) -> AppResult<()>,
+    ) -> AppResult<()> {
+        if self.tasks.is_empty() {
+            return Err(AppError::new(ErrorKind::NotFound)
+                .with_code("MC-WORK-001")
+                .with_message("常駐モードで実行するスケジュールが設定されていません。")
+                .with_action(
+                    "config.jsonのschedulesに実行する処理を追加してください（例: {\"schedule\": \"0 9 * * 1-5\", \"action\": \"start_mail\"}）。",
+                ));
+        }
+
+        let now = self.time_zone.now().naive_local();
+        for (at, action) in self.upcoming(&now) {
+            tracing::info!(action = action.as_str(), next = %at, "スケジュールを登録しました");
+        }
+
+        // 起動した分のスケジュールも実行するため、前回の照合は起動の1分前とする
+        let mut last_checked = truncate_to_minute(&now) - TimeDelta::minutes(1);
+        loop {
+            let now = truncate_to_minute(&self.time_zone.now().naive_local());
+            for action in self.due_between(&last_checked, &now) {
+                tracing::info!(
+                    action = action.as_str(),
+                    "スケジュールされた処理を実行します"
+                );
+                // 失敗はin_spanがエラーイベントとして出力するため、次の処理を続ける
+                let _ = logging::in_span(action.as_str(), || execute(action, self.time_zone.now()));
+            }
+            // 時計が戻った場合に同じ分を再度実行しないよう、照合済みの分は戻さない
+            last_checked = last_checked.max(now);
+
+            // 処理を終えた時点の時刻から、次の分の開始まで待機する
+            let now = self.time_zone.now().naive_local();
+            let elapsed = Duration::new(u64::from(now.second()), now.nanosecond() % 1_000_000_000);
+            std::thread::sleep(Duration::from_secs(60).saturating_sub(elapsed));
+        }
+    }
+}
+
+/// 日時の秒以下を切り捨てる
+fn truncate_to_minute(at: &NaiveDateTime) -> NaiveDateTime {
+    at.with_second(0)
+        .and_then(|at| at.with_nanosecond(0))
+        .unwrap_or(*at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::cron_schedule::CronSchedule;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_due_and_upcoming() {
+        let task = |schedule: &str, action| ScheduledTask {
+            schedule: CronSchedule::parse(schedule).unwrap(),
+            action,
+        };
+        let use_case = SchedulerUseCase::new(vec![
+            task("0 9 * * 1-5", ScheduledAction::StartMail),
+            task("30 18 * * 1-5", ScheduledAction::EndReminder),
+            task("0 9 * * 1", ScheduledAction::StartMail),
+            task("0 17 * * 5", ScheduledAction::WeeklyReport),
+        ]);
+        let monday = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let at = |hour, minute| monday.and_hms_opt(hour, minute, 0).unwrap();
+
+        assert_eq!(use_case.due_at(&at(9, 0)), vec![ScheduledAction::StartMail]);
+        assert!(use_case.due_at(&at(9, 1)).is_empty());
+
+        let upcoming = use_case.upcoming(&at(12, 0));
+        let first = upcoming.first().unwrap();
+        assert_eq!(first, &(at(18, 30), ScheduledAction::EndReminder));
+        assert_eq!(
+            upcoming.last().unwrap(),
+            &(
+                NaiveDate::from_ymd_opt(2024, 6, 10)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap(),
+                ScheduledAction::StartMail
+            )
+        );
+    }
+
+    #[test]
+    fn test_due_between_catches_up_skipped_minutes() {
+        let task = |schedule: &str, action| ScheduledTask {
+            schedule: CronSchedule::parse(schedule).unwrap(),
+            action,
+        };
+        let use_case = SchedulerUseCase::new(vec![
+            task("0 9 * * *", ScheduledAction::StartMail),
+            task("1 9 * * *", ScheduledAction::EndReminder),
+            task("2 9 * * *", ScheduledAction::StartMail),
+        ]);
+        let at = |minute| {
+            NaiveDate::from_ymd_opt(2024, 6, 3)
+                .unwrap()
+                .and_hms_opt(9, minute, 0)
+                .unwrap()
+        };
+
+        // 9:00の処理が9:02まで続いた場合も、9:01と9:02の処理を実行する
+        assert_eq!(
+            use_case.due_between(&at(0), &at(2)),
+            vec![ScheduledAction::EndReminder, ScheduledAction::StartMail]
+        );
+        assert!(use_case.due_between(&at(2), &at(2)).is_empty());
+        assert!(use_case.due_between(&at(2), &at(1)).is_empty());
+    }
+}
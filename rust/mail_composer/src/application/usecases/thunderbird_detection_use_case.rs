@@ -0,0 +1,293 @@
+use crate::domain::interfaces::{
+    configuration::{ConfigurationPort, ConfigurationWritePort},
+    environment_probe::EnvironmentProbePort,
+    prompt::PromptPort,
+    thunderbird_locator::ThunderbirdLocatorPort,
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::path::Path;
+
+/// Thunderbird実行ファイルの検出結果
+#[derive(Debug, PartialEq, Eq)]
+pub struct ThunderbirdDetectionReport {
+    /// 設定ファイルに記載されていたパス
+    pub configured: String,
+    /// 使用するパス（設定値が有効な場合は設定値、そうでない場合は検出したパス）
+    pub resolved: String,
+    /// 設定値が空または存在しないため、自動検出したかどうか
+    pub detected: bool,
+    /// 検出したパスを設定ファイルに書き戻したかどうか
+    pub written_back: bool,
+}
+
+/// 設定されたThunderbird実行ファイルが空または無効な場合に自動検出するユースケース
+pub struct ThunderbirdDetectionUseCase<C, L, E>
+where
+    C: ConfigurationPort + ConfigurationWritePort,
+    L: ThunderbirdLocatorPort,
+    E: EnvironmentProbePort,
+{
+    configuration_port: C,
+    locator_port: L,
+    probe_port: E,
+    write_back_prompt: Option<Box<dyn PromptPort>>,
+}
+
+impl<C, L, E> ThunderbirdDetectionUseCase<C, L, E>
+where
+    C: ConfigurationPort + ConfigurationWritePort,
+    L: ThunderbirdLocatorPort,
+    E: EnvironmentProbePort,
+{
+    /// 新しいThunderbirdDetectionUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `configuration_port` - 設定の読み込みと書き戻しに使用するポート
+    /// * `locator_port` - Thunderbirdの検出に使用するポート
+    /// * `probe_port` - 設定されたパスが実行可能かの確認に使用するポート
+    ///
+    /// ## Returns
+    /// * ThunderbirdDetectionUseCaseのインスタンス
+    pub fn new(configuration_port: C, locator_port: L, probe_port: E) -> Self {
+        Self {
+            configuration_port,
+            locator_port,
+            probe_port,
+            write_back_prompt: None,
+        }
+    }
+
+    /// 検出したパスを設定ファイルに書き戻すかを確認するプロンプトを設定する
+    ///
+    /// ## Arguments
+    /// * `prompt` - 書き戻すかどうかの入力に使用するプロンプト
+    ///
+    /// ## Returns
+    /// * プロンプトが設定されたThunderbirdDetectionUseCaseのインスタンス
+    pub fn with_write_back_prompt(mut self, prompt: impl PromptPort + 'static) -> Self {
+        self.write_back_prompt = Some(Box::new(prompt));
+        self
+    }
+
+    /// 使用するThunderbird実行ファイルを決定する
+    ///
+    /// 設定値が空、または実行できない場合のみ自動検出を行う。
+    /// 書き戻す場合は`thunderbird_exe`のみを書き換え、他の項目は設定ファイルの記述のまま残す
+    ///
+    /// ## Arguments
+    /// * `write_back` - 検出したパスを確認せずに設定ファイルに書き戻すかどうか
+    ///   （`false`の場合もプロンプトが設定されていれば書き戻すかを確認する）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<ThunderbirdDetectionReport>`
+    /// * 失敗時 - 設定値が無効で、Thunderbirdも検出できなかった場合の`Err<AppError>`
+    pub fn resolve(&self, write_back: bool) -> AppResult<ThunderbirdDetectionReport> {
+        let _span = logging::operation_span("thunderbird_detection").entered();
+        let config = self.configuration_port.load_unvalidated_configuration()?;
+        let configured = config.thunderbird_exe.clone();
+
+        let stale = match configured.trim() {
+            "" => true,
+            path => self.probe_port.check_executable(Path::new(path)).is_err(),
+        };
+        if !stale {
+            return Ok(ThunderbirdDetectionReport {
+                resolved: configured.clone(),
+                configured,
+                detected: false,
+                written_back: false,
+            });
+        }
+
+        let Some(found) = self.locator_port.locate() else {
+            return Err(AppError::new(ErrorKind::NotFound)
+                .with_code("MC-CONF-006")
+                .with_message("Thunderbird実行ファイルが見つかりません。")
+                .with_action(
+                    "Thunderbirdをインストールするか、config.jsonのthunderbird_exeフィールドにパスを設定してください。",
+                ));
+        };
+        let resolved = found.to_string_lossy().replace('\\', "/");
+        tracing::info!(configured = %configured, path = %resolved, "Thunderbird実行ファイルを検出しました");
+
+        let write_back = write_back || self.confirm_write_back(&resolved);
+        if write_back {
+            self.configuration_port
+                .save_field("thunderbird_exe", &resolved)?;
+        }
+
+        Ok(ThunderbirdDetectionReport {
+            configured,
+            resolved,
+            detected: true,
+            written_back: write_back,
+        })
+    }
+
+    /// 検出したパスを設定ファイルに書き戻すかを確認する
+    ///
+    /// プロンプトが設定されていない場合、設定ファイルがない場合、入力できない場合は書き戻さない
+    fn confirm_write_back(&self, resolved: &str) -> bool {
+        let Some(prompt) = &self.write_back_prompt else {
+            return false;
+        };
+        if !self.configuration_port.configuration_exists() {
+            return false;
+        }
+        let question = format!(
+            "Thunderbirdが見つかりました（{resolved}）。config.jsonのthunderbird_exeに保存しますか？ (y/N)"
+        );
+        prompt
+            .ask(&question, Some("N"))
+            .is_ok_and(|answer| matches!(answer.to_ascii_lowercase().as_str(), "y" | "yes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::app_configuration::AppConfiguration;
+    use std::{cell::RefCell, path::PathBuf};
+
+    struct MemoryConfiguration {
+        config: RefCell<AppConfiguration>,
+    }
+
+    impl MemoryConfiguration {
+        fn with_exe(thunderbird_exe: &str) -> Self {
+            Self {
+                config: RefCell::new(AppConfiguration {
+                    from: "山田".to_string(),
+                    department: "開発部".to_string(),
+                    thunderbird_exe: thunderbird_exe.to_string(),
+                    ..AppConfiguration::default()
+                }),
+            }
+        }
+    }
+
+    impl ConfigurationPort for MemoryConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(self.config.borrow().clone())
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    impl ConfigurationWritePort for MemoryConfiguration {
+        fn save_configuration(&self, config: &AppConfiguration) -> AppResult<()> {
+            *self.config.borrow_mut() = config.clone();
+            Ok(())
+        }
+
+        fn save_field(&self, field: &str, value: &str) -> AppResult<()> {
+            let mut config = self.config.borrow_mut();
+            config.set_field(field, value)
+        }
+    }
+
+    struct FixedLocator(Option<&'static str>);
+
+    impl ThunderbirdLocatorPort for FixedLocator {
+        fn locate(&self) -> Option<PathBuf> {
+            self.0.map(PathBuf::from)
+        }
+    }
+
+    struct StubProbe;
+
+    impl EnvironmentProbePort for StubProbe {
+        fn check_writable_dir(&self, _path: &Path) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn check_executable(&self, path: &Path) -> AppResult<()> {
+            if path == Path::new("/usr/bin/thunderbird") {
+                Ok(())
+            } else {
+                Err(AppError::new(ErrorKind::NotFound))
+            }
+        }
+    }
+
+    #[test]
+    fn test_keep_valid_configured_path() {
+        let use_case = ThunderbirdDetectionUseCase::new(
+            MemoryConfiguration::with_exe("/usr/bin/thunderbird"),
+            FixedLocator(Some("/opt/thunderbird/thunderbird")),
+            StubProbe,
+        );
+
+        let report = use_case.resolve(true).unwrap();
+
+        assert!(!report.detected);
+        assert_eq!(report.resolved, "/usr/bin/thunderbird");
+    }
+
+    #[test]
+    fn test_detect_and_write_back_stale_path() {
+        let use_case = ThunderbirdDetectionUseCase::new(
+            MemoryConfiguration::with_exe("/old/thunderbird"),
+            FixedLocator(Some("/opt/thunderbird/thunderbird")),
+            StubProbe,
+        );
+
+        let report = use_case.resolve(true).unwrap();
+
+        assert!(report.detected && report.written_back);
+        assert_eq!(report.configured, "/old/thunderbird");
+        assert_eq!(
+            use_case.configuration_port.config.borrow().thunderbird_exe,
+            "/opt/thunderbird/thunderbird"
+        );
+    }
+
+    struct FixedPrompt(&'static str);
+
+    impl PromptPort for FixedPrompt {
+        fn ask(&self, _question: &str, _default: Option<&str>) -> AppResult<String> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_write_back_after_confirmation() {
+        for (answer, expected) in [("y", "/opt/thunderbird/thunderbird"), ("N", "")] {
+            let use_case = ThunderbirdDetectionUseCase::new(
+                MemoryConfiguration::with_exe(""),
+                FixedLocator(Some("/opt/thunderbird/thunderbird")),
+                StubProbe,
+            )
+            .with_write_back_prompt(FixedPrompt(answer));
+
+            let report = use_case.resolve(false).unwrap();
+
+            assert!(report.detected);
+            assert_eq!(report.resolved, "/opt/thunderbird/thunderbird");
+            assert_eq!(report.written_back, !expected.is_empty());
+            assert_eq!(
+                use_case.configuration_port.config.borrow().thunderbird_exe,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_found() {
+        let use_case = ThunderbirdDetectionUseCase::new(
+            MemoryConfiguration::with_exe(""),
+            FixedLocator(None),
+            StubProbe,
+        );
+
+        assert!(use_case.resolve(false).is_err());
+    }
+}
@@ -0,0 +1,369 @@
+use crate::domain::{
+    entities::work_record::WorkRecord,
+    interfaces::work_time::WorkTimePort,
+    value_objects::{
+        mail_objects::WorkTime, mail_template::weekday_name, time_zone::WorkTimeZone,
+        work_hours::DurationFormat,
+    },
+};
+use chrono::{Datelike, Days, NaiveDate};
+use serde::Serialize;
+use share::error::app_error::AppResult;
+
+/// 1週間分の勤務状況の要約
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WeeklyWorkSummary {
+    /// 週の初日（月曜日）
+    pub week_start: NaiveDate,
+    /// 週の最終日（日曜日）
+    pub week_end: NaiveDate,
+    /// 勤務日数（開始時刻が記録されている日数）
+    pub worked_days: usize,
+    /// 合計作業時間（分）
+    pub total_minutes: i64,
+    /// 開始時刻はあるが終了時刻が記録されていない日（当日を除く）
+    pub missing_end_dates: Vec<NaiveDate>,
+    /// 勤務日ごとの開始・終了時刻と作業時間（日付順）
+    pub days: Vec<DailyWorkEntry>,
+}
+
+/// 1日分の勤務状況
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyWorkEntry {
+    /// 日付
+    pub date: NaiveDate,
+    /// 作業開始時刻
+    pub start: Option<WorkTime>,
+    /// 作業終了時刻
+    pub end: Option<WorkTime>,
+    /// 休憩を除いた作業時間（分）
+    pub duration_minutes: Option<i64>,
+}
+
+impl WeeklyWorkSummary {
+    /// 作業記録から週の要約を作成する
+    ///
+    /// ## Arguments
+    /// * `week_start` - 週の初日（月曜日）
+    /// * `records` - 週の作業記録
+    /// * `today` - 今日の日付（今日以降の終了時刻の未記録は集計しない）
+    ///
+    /// ## Returns
+    /// * WeeklyWorkSummaryのインスタンス
+    pub fn from_records(
+        week_start: NaiveDate,
+        records: &[(NaiveDate, WorkRecord)],
+        today: NaiveDate,
+    ) -> Self {
+        let worked: Vec<&(NaiveDate, WorkRecord)> = records
+            .iter()
+            .filter(|(_, record)| record.start.is_some())
+            .collect();
+        Self {
+            week_start,
+            week_end: week_start + Days::new(6),
+            worked_days: worked.len(),
+            total_minutes: worked.iter().filter_map(|(_, r)| r.duration()).sum(),
+            missing_end_dates: worked
+                .iter()
+                .filter(|(date, record)| record.end.is_none() && *date < today)
+                .map(|(date, _)| *date)
+                .collect(),
+            days: worked
+                .iter()
+                .map(|(date, record)| DailyWorkEntry {
+                    date: *date,
+                    start: record.start.clone(),
+                    end: record.end.clone(),
+                    duration_minutes: record.duration(),
+                })
+                .collect(),
+        }
+    }
+
+    /// 集計期間の表示（例: `2025-03-31〜2025-04-06`）
+    pub fn period(&self) -> String {
+        format!("{}〜{}", self.week_start, self.week_end)
+    }
+
+    /// 要約をテキストに整形する
+    ///
+    /// ## Arguments
+    /// * `duration_format` - 作業時間の表示形式
+    ///
+    /// ## Returns
+    /// * 整形した要約
+    pub fn render(&self, duration_format: DurationFormat) -> String {
+        let missing = if self.missing_end_dates.is_empty() {
+            "なし".to_string()
+        } else {
+            self.missing_end_dates
+                .iter()
+                .map(NaiveDate::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        [
+            format!("期間: {}", self.period()),
+            format!("勤務日数: {}日", self.worked_days),
+            format!(
+                "合計作業時間: {}",
+                duration_format.format(self.total_minutes)
+            ),
+            format!("終了時刻の記録がない日: {missing}"),
+        ]
+        .join("\n")
+    }
+
+    /// 勤務日ごとの開始・終了時刻と作業時間を表に整形する
+    ///
+    /// ## Arguments
+    /// * `duration_format` - 作業時間の表示形式
+    ///
+    /// ## Returns
+    /// * 整形した表（記録がない時刻は`--:--`と表示する）
+    pub fn render_daily_table(&self, duration_format: DurationFormat) -> String {
+        let time =
+            |time: &Option<WorkTime>| time.as_ref().map_or("--:--", WorkTime::as_str).to_string();
+        let mut lines = vec!["日付        曜日 開始  終了  作業時間".to_string()];
+        lines.extend(self.days.iter().map(|day| {
+            let duration = day.duration_minutes.map_or_else(
+                || "--:--".to_string(),
+                |minutes| duration_format.format(minutes),
+            );
+            format!(
+                "{} ({}) {} {} {duration:>6}",
+                day.date,
+                weekday_name(day.date),
+                time(&day.start),
+                time(&day.end),
+            )
+        }));
+        lines.join("\n")
+    }
+}
+
+/// 指定日を含む週（月曜始まり）の初日と最終日を取得する
+///
+/// ## Arguments
+/// * `date` - 基準日
+///
+/// ## Returns
+/// * `(月曜日, 日曜日)`
+pub fn week_range(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let monday = date - Days::new(u64::from(date.weekday().num_days_from_monday()));
+    (monday, monday + Days::new(6))
+}
+
+/// 指定日を含む週の作業記録を読み込み、要約する
+///
+/// ## Arguments
+/// * `work_time_port` - 作業記録の読み込みに使用するポート
+/// * `date` - 基準日
+/// * `time_zone` - 今日の日付と時刻の表示に使用するタイムゾーン
+///
+/// ## Returns
+/// * 成功時 - `Ok<WeeklyWorkSummary>`
+/// * 失敗時 - 作業記録の読み込みに失敗した場合の`Err<AppError>`
+pub fn summarize_week<W: WorkTimePort>(
+    work_time_port: &W,
+    date: NaiveDate,
+    time_zone: &WorkTimeZone,
+) -> AppResult<WeeklyWorkSummary> {
+    let (week_start, week_end) = week_range(date);
+    let records: Vec<(NaiveDate, WorkRecord)> = work_time_port
+        .load_records(week_start, week_end)?
+        .into_iter()
+        .map(|(date, record)| (date, record.localized(time_zone)))
+        .collect();
+    Ok(WeeklyWorkSummary::from_records(
+        week_start,
+        &records,
+        time_zone.today(),
+    ))
+}
+
+/// 今週の勤務状況を要約するユースケース
+pub struct WeeklySummaryUseCase<W: WorkTimePort> {
+    work_time_port: W,
+    time_zone: WorkTimeZone,
+}
+
+impl<W: WorkTimePort> WeeklySummaryUseCase<W> {
+    /// 新しいWeeklySummaryUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `work_time_port` - 作業記録の読み込みに使用するポート
+    ///
+    /// ## Returns
+    /// * WeeklySummaryUseCaseのインスタンス
+    pub fn new(work_time_port: W) -> Self {
+        Self {
+            work_time_port,
+            time_zone: WorkTimeZone::default(),
+        }
+    }
+
+    /// 今日の日付と時刻の表示に使用するタイムゾーンを指定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * タイムゾーンが設定されたWeeklySummaryUseCaseのインスタンス
+    pub fn with_time_zone(mut self, time_zone: WorkTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// 今週の勤務状況を要約する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WeeklyWorkSummary>`
+    /// * 失敗時 - 作業記録の読み込みに失敗した場合の`Err<AppError>`
+    pub fn current_week(&self) -> AppResult<WeeklyWorkSummary> {
+        self.week_of(self.time_zone.today())
+    }
+
+    /// 指定日を含む週の勤務状況を要約する
+    ///
+    /// ## Arguments
+    /// * `date` - 基準日
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WeeklyWorkSummary>`
+    /// * 失敗時 - 作業記録の読み込みに失敗した場合の`Err<AppError>`
+    pub fn week_of(&self, date: NaiveDate) -> AppResult<WeeklyWorkSummary> {
+        summarize_week(&self.work_time_port, date, &self.time_zone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::mail_objects::WorkTime;
+    use chrono::{DateTime, FixedOffset};
+
+    struct StubWorkTime;
+
+    impl WorkTimePort for StubWorkTime {
+        fn save_start_time(
+            &self,
+            _date: NaiveDate,
+            _started_at: &DateTime<FixedOffset>,
+        ) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn save_end_time(
+            &self,
+            _date: NaiveDate,
+            _ended_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn pause(
+            &self,
+            _date: NaiveDate,
+            _paused_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn resume(
+            &self,
+            _date: NaiveDate,
+            _resumed_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn load_record(&self, _date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+            Ok(None)
+        }
+
+        fn load_records(
+            &self,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+            let record = |start: &str, end: Option<&str>| {
+                let mut record = WorkRecord::default();
+                record.set_start(WorkTime::new(start).unwrap());
+                if let Some(end) = end {
+                    record.set_end(WorkTime::new(end).unwrap());
+                }
+                record
+            };
+            Ok([
+                ("2025-03-30", record("09:00", Some("18:00"))),
+                ("2025-03-31", record("09:00", Some("18:00"))),
+                ("2025-04-01", record("09:30", None)),
+                ("2025-04-02", record("10:00", Some("18:30"))),
+            ]
+            .into_iter()
+            .map(|(date, record)| (date.parse().unwrap(), record))
+            .filter(|(date, _)| (from..=to).contains(date))
+            .collect())
+        }
+    }
+
+    #[test]
+    fn test_week_range() {
+        let date = NaiveDate::from_ymd_opt(2025, 4, 2).unwrap();
+        assert_eq!(
+            week_range(date),
+            (
+                NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 4, 6).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_week_of() {
+        let summary = WeeklySummaryUseCase::new(StubWorkTime)
+            .week_of(NaiveDate::from_ymd_opt(2025, 4, 3).unwrap())
+            .unwrap();
+
+        assert_eq!(summary.worked_days, 3);
+        assert_eq!(summary.total_minutes, 9 * 60 + 8 * 60 + 30);
+        assert_eq!(
+            summary.missing_end_dates,
+            vec![NaiveDate::from_ymd_opt(2025, 4, 1).unwrap()]
+        );
+
+        let text = summary.render(DurationFormat::Clock);
+        assert!(text.contains("期間: 2025-03-31〜2025-04-06"));
+        assert!(text.contains("合計作業時間: 17:30"));
+        assert!(text.contains("終了時刻の記録がない日: 2025-04-01"));
+
+        let table = summary.render_daily_table(DurationFormat::Clock);
+        assert_eq!(
+            table.lines().skip(1).collect::<Vec<_>>(),
+            [
+                "2025-03-31 (月) 09:00 18:00   9:00",
+                "2025-04-01 (火) 09:30 --:--  --:--",
+                "2025-04-02 (水) 10:00 18:30   8:30",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_today_without_end_is_not_missing() {
+        let today = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+        let records = StubWorkTime
+            .load_records(today - Days::new(1), today)
+            .unwrap();
+
+        let summary = WeeklyWorkSummary::from_records(week_range(today).0, &records, today);
+
+        assert!(summary.missing_end_dates.is_empty());
+        assert!(
+            summary
+                .render(DurationFormat::Clock)
+                .contains("終了時刻の記録がない日: なし")
+        );
+    }
+}
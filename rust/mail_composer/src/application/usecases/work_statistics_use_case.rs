@@ -0,0 +1,400 @@
+use crate::domain::{
+    interfaces::work_time::WorkTimePort,
+    value_objects::{time_zone::WorkTimeZone, work_hours::DurationFormat},
+};
+use chrono::NaiveDate;
+use serde::Serialize;
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::collections::BTreeMap;
+
+/// 勤務傾向の統計の出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatisticsFormat {
+    /// 端末表示用のテキスト
+    Text,
+    /// JSON
+    Json,
+}
+
+/// ヒストグラムの1区間（1時間単位）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HistogramBucket {
+    /// 区間の開始（時刻の場合は時、作業時間の場合は時間数）
+    pub hour: i64,
+    /// 区間に含まれる日数
+    pub count: usize,
+}
+
+/// 作業時間が最長または最短だった日
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkDayDuration {
+    /// 日付
+    pub date: NaiveDate,
+    /// 作業時間（分）
+    pub duration_minutes: i64,
+}
+
+/// 指定期間の勤務傾向の統計
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkStatistics {
+    /// 集計期間の初日
+    pub from: NaiveDate,
+    /// 集計期間の最終日
+    pub to: NaiveDate,
+    /// 作業時間を計算できた日数
+    pub worked_days: usize,
+    /// 平均開始時刻
+    pub average_start: Option<String>,
+    /// 平均終了時刻
+    pub average_end: Option<String>,
+    /// 1日あたりの平均作業時間（分）
+    pub average_minutes: Option<i64>,
+    /// 開始時刻の分布（時ごと）
+    pub start_histogram: Vec<HistogramBucket>,
+    /// 終了時刻の分布（時ごと）
+    pub end_histogram: Vec<HistogramBucket>,
+    /// 作業時間の分布（時間数ごと）
+    pub duration_histogram: Vec<HistogramBucket>,
+    /// 作業時間が最長だった日
+    pub longest_day: Option<WorkDayDuration>,
+    /// 作業時間が最短だった日
+    pub shortest_day: Option<WorkDayDuration>,
+    /// 作業時間の表示形式
+    #[serde(skip)]
+    pub duration_format: DurationFormat,
+}
+
+impl WorkStatistics {
+    /// 指定した形式で統計を整形する
+    ///
+    /// ## Arguments
+    /// * `format` - 出力形式
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<String>`
+    /// * 失敗時 - JSONへの変換に失敗した場合の`Err<AppError>`
+    pub fn render(&self, format: StatisticsFormat) -> AppResult<String> {
+        match format {
+            StatisticsFormat::Text => Ok(self.render_text()),
+            StatisticsFormat::Json => serde_json::to_string_pretty(self).map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-WORK-002")
+                    .with_message("統計のJSONへの変換に失敗しました。")
+                    .with_action("データの内容を確認してください。")
+                    .with_source(e)
+            }),
+        }
+    }
+
+    /// 端末表示用のテキストに整形する
+    fn render_text(&self) -> String {
+        let missing = || "--:--".to_string();
+        let format_minutes = |minutes: i64| self.duration_format.format(minutes);
+        let format_day = |day: &Option<WorkDayDuration>| {
+            day.as_ref().map_or_else(missing, |day| {
+                format!("{}（{}）", day.date, format_minutes(day.duration_minutes))
+            })
+        };
+
+        let mut lines = vec![
+            format!("{}〜{} 勤務傾向", self.from, self.to),
+            format!("集計日数: {}日", self.worked_days),
+            format!(
+                "平均開始時刻: {}",
+                self.average_start.clone().unwrap_or_else(missing)
+            ),
+            format!(
+                "平均終了時刻: {}",
+                self.average_end.clone().unwrap_or_else(missing)
+            ),
+            format!(
+                "平均作業時間: {}",
+                self.average_minutes.map_or_else(missing, format_minutes)
+            ),
+            format!("最長の日: {}", format_day(&self.longest_day)),
+            format!("最短の日: {}", format_day(&self.shortest_day)),
+        ];
+        let histograms = [
+            ("開始時刻の分布", &self.start_histogram, "時台"),
+            ("終了時刻の分布", &self.end_histogram, "時台"),
+            ("作業時間の分布", &self.duration_histogram, "時間台"),
+        ];
+        for (title, buckets, unit) in histograms {
+            lines.push(String::new());
+            lines.push(format!("{title}:"));
+            lines.extend(buckets.iter().map(|bucket| {
+                format!(
+                    "  {:>2}{unit} {} {}",
+                    bucket.hour,
+                    "#".repeat(bucket.count),
+                    bucket.count
+                )
+            }));
+        }
+        lines.join("\n")
+    }
+}
+
+/// 値の平均（値がない場合は`None`）
+fn average(values: &[i64]) -> Option<i64> {
+    (!values.is_empty()).then(|| values.iter().sum::<i64>() / values.len() as i64)
+}
+
+/// 分単位の値を1時間ごとの区間に集計する
+fn histogram(minutes: &[i64]) -> Vec<HistogramBucket> {
+    let mut counts: BTreeMap<i64, usize> = BTreeMap::new();
+    for value in minutes {
+        *counts.entry(value.div_euclid(60)).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .map(|(hour, count)| HistogramBucket { hour, count })
+        .collect()
+}
+
+/// 作業記録から勤務傾向を集計するユースケース
+pub struct WorkStatisticsUseCase<W: WorkTimePort> {
+    work_time_port: W,
+    duration_format: DurationFormat,
+    time_zone: WorkTimeZone,
+}
+
+impl<W: WorkTimePort> WorkStatisticsUseCase<W> {
+    /// 新しいWorkStatisticsUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `work_time_port` - 作業記録の読み込みに使用するポート
+    ///
+    /// ## Returns
+    /// * WorkStatisticsUseCaseのインスタンス
+    pub fn new(work_time_port: W) -> Self {
+        Self {
+            work_time_port,
+            duration_format: DurationFormat::default(),
+            time_zone: WorkTimeZone::default(),
+        }
+    }
+
+    /// テキスト出力での作業時間の表示形式を指定する
+    ///
+    /// ## Arguments
+    /// * `duration_format` - 作業時間の表示形式
+    ///
+    /// ## Returns
+    /// * 表示形式が設定されたWorkStatisticsUseCaseのインスタンス
+    pub fn with_duration_format(mut self, duration_format: DurationFormat) -> Self {
+        self.duration_format = duration_format;
+        self
+    }
+
+    /// 開始時刻と終了時刻の集計に使用するタイムゾーンを指定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 集計に使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * タイムゾーンが設定されたWorkStatisticsUseCaseのインスタンス
+    pub fn with_time_zone(mut self, time_zone: WorkTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// 指定期間の勤務傾向を集計する
+    ///
+    /// 開始時刻と終了時刻の両方が記録されている日のみを集計する
+    ///
+    /// ## Arguments
+    /// * `from` - 集計期間の初日
+    /// * `to` - 集計期間の最終日
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkStatistics>`
+    /// * 失敗時 - 期間が不正な場合、または作業記録の読み込みに失敗した場合の`Err<AppError>`
+    pub fn statistics(&self, from: NaiveDate, to: NaiveDate) -> AppResult<WorkStatistics> {
+        if from > to {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-WORK-003")
+                .with_message(format!("集計期間が不正です。詳細: {from}〜{to}"))
+                .with_action("初日には最終日以前の日付を指定してください。"));
+        }
+
+        let days: Vec<(NaiveDate, i64, i64, i64)> = self
+            .work_time_port
+            .load_records(from, to)?
+            .into_iter()
+            .map(|(date, record)| (date, record.localized(&self.time_zone)))
+            .filter_map(|(date, record)| {
+                Some((
+                    date,
+                    record.start.as_ref()?.minutes_of_day(),
+                    record.end.as_ref()?.minutes_of_day(),
+                    record.duration()?,
+                ))
+            })
+            .collect();
+
+        let starts: Vec<i64> = days.iter().map(|(_, start, _, _)| *start).collect();
+        let ends: Vec<i64> = days.iter().map(|(_, _, end, _)| *end).collect();
+        let durations: Vec<i64> = days.iter().map(|(_, _, _, minutes)| *minutes).collect();
+        let clock = |minutes: i64| format!("{:02}:{:02}", minutes / 60, minutes % 60);
+        let to_day = |(date, _, _, minutes): &(NaiveDate, i64, i64, i64)| WorkDayDuration {
+            date: *date,
+            duration_minutes: *minutes,
+        };
+
+        Ok(WorkStatistics {
+            from,
+            to,
+            worked_days: days.len(),
+            average_start: average(&starts).map(clock),
+            average_end: average(&ends).map(clock),
+            average_minutes: average(&durations),
+            start_histogram: histogram(&starts),
+            end_histogram: histogram(&ends),
+            duration_histogram: histogram(&durations),
+            // 同じ作業時間の日が複数ある場合は、早い日付を採用する
+            longest_day: days
+                .iter()
+                .max_by(|a, b| a.3.cmp(&b.3).then(b.0.cmp(&a.0)))
+                .map(to_day),
+            shortest_day: days.iter().min_by_key(|day| day.3).map(to_day),
+            duration_format: self.duration_format,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{entities::work_record::WorkRecord, value_objects::mail_objects::WorkTime};
+    use chrono::{DateTime, FixedOffset};
+
+    struct StubWorkTime;
+
+    impl WorkTimePort for StubWorkTime {
+        fn save_start_time(
+            &self,
+            _date: NaiveDate,
+            _started_at: &DateTime<FixedOffset>,
+        ) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn save_end_time(
+            &self,
+            _date: NaiveDate,
+            _ended_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn pause(
+            &self,
+            _date: NaiveDate,
+            _paused_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn resume(
+            &self,
+            _date: NaiveDate,
+            _resumed_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn load_record(&self, _date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+            Ok(None)
+        }
+
+        fn load_records(
+            &self,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+            let record = |start: &str, end: Option<&str>| {
+                let mut record = WorkRecord::default();
+                record.set_start(WorkTime::new(start).unwrap());
+                if let Some(end) = end {
+                    record.set_end(WorkTime::new(end).unwrap());
+                }
+                record
+            };
+            Ok([
+                ("2025-04-01", record("09:00", Some("18:00"))),
+                ("2025-04-02", record("09:30", Some("20:00"))),
+                ("2025-04-03", record("10:00", Some("17:00"))),
+                ("2025-04-04", record("09:15", None)),
+            ]
+            .into_iter()
+            .map(|(date, record)| (date.parse().unwrap(), record))
+            .filter(|(date, _)| (from..=to).contains(date))
+            .collect())
+        }
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_statistics() {
+        let statistics = WorkStatisticsUseCase::new(StubWorkTime)
+            .statistics(date("2025-04-01"), date("2025-04-30"))
+            .unwrap();
+
+        assert_eq!(statistics.worked_days, 3);
+        assert_eq!(statistics.average_start.as_deref(), Some("09:30"));
+        assert_eq!(statistics.average_end.as_deref(), Some("18:20"));
+        assert_eq!(statistics.average_minutes, Some(530));
+        assert_eq!(
+            statistics.start_histogram,
+            vec![
+                HistogramBucket { hour: 9, count: 2 },
+                HistogramBucket { hour: 10, count: 1 },
+            ]
+        );
+        assert_eq!(
+            statistics.longest_day,
+            Some(WorkDayDuration {
+                date: date("2025-04-02"),
+                duration_minutes: 630,
+            })
+        );
+        assert_eq!(
+            statistics.shortest_day.map(|day| day.date),
+            Some(date("2025-04-03"))
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let statistics = WorkStatisticsUseCase::new(StubWorkTime)
+            .with_duration_format(DurationFormat::Japanese)
+            .statistics(date("2025-04-01"), date("2025-04-30"))
+            .unwrap();
+
+        let text = statistics.render(StatisticsFormat::Text).unwrap();
+        assert!(text.contains("平均作業時間: 8時間50分"));
+        assert!(text.contains("最長の日: 2025-04-02（10時間30分）"));
+        assert!(text.contains("   9時台 ## 2"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&statistics.render(StatisticsFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["worked_days"], 3);
+        assert_eq!(json["longest_day"]["date"], "2025-04-02");
+        assert!(json.get("duration_format").is_none());
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let result = WorkStatisticsUseCase::new(StubWorkTime)
+            .statistics(date("2025-04-30"), date("2025-04-01"));
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::domain::{
+    entities::work_record::WorkRecord,
+    interfaces::work_time::WorkTimePort,
+    value_objects::{
+        mail_objects::{WorkTime, WorkTimeRange},
+        time_zone::WorkTimeZone,
+    },
+};
+use chrono::NaiveDate;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+
+/// 作業記録の修正内容
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkTimeEdit {
+    /// 修正後の作業開始時刻
+    pub start: Option<WorkTime>,
+    /// 修正後の作業終了時刻
+    pub end: Option<WorkTime>,
+    /// 置き換える備考（空文字列の場合は備考を削除する）
+    pub note: Option<String>,
+}
+
+impl WorkTimeEdit {
+    /// 修正する項目がないかを判定する
+    pub fn is_empty(&self) -> bool {
+        self.start.is_none() && self.end.is_none() && self.note.is_none()
+    }
+}
+
+/// 指定日の作業記録の開始・終了時刻と備考を修正するユースケース
+pub struct WorkTimeEditUseCase<W: WorkTimePort> {
+    work_time_port: W,
+    time_zone: WorkTimeZone,
+}
+
+impl<W: WorkTimePort> WorkTimeEditUseCase<W> {
+    /// 新しいWorkTimeEditUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `work_time_port` - 作業記録の保存先
+    ///
+    /// ## Returns
+    /// * WorkTimeEditUseCaseのインスタンス
+    pub fn new(work_time_port: W) -> Self {
+        Self {
+            work_time_port,
+            time_zone: WorkTimeZone::default(),
+        }
+    }
+
+    /// 時刻の解釈に使用するタイムゾーンを指定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * タイムゾーンが設定されたWorkTimeEditUseCaseのインスタンス
+    pub fn with_time_zone(mut self, time_zone: WorkTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// 指定日の作業記録を修正して保存する
+    ///
+    /// 開始・終了時刻は、指定されなかった方に記録済みの時刻を使用して組み合わせを検証する。
+    /// 終了時刻が開始時刻より前の場合は、日付をまたぐ勤務として翌日の終了時刻とする。
+    /// 備考は既存の備考を置き換える
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    /// * `edit` - 修正内容
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - 修正内容がない場合、未来の日付の場合、開始時刻と終了時刻が同じ場合、または保存に失敗した場合の`Err<AppError>`
+    pub fn edit(&self, date: NaiveDate, edit: &WorkTimeEdit) -> AppResult<WorkRecord> {
+        let _span = logging::operation_span("work_time_edit").entered();
+        if edit.is_empty() {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-WORK-004")
+                .with_message("修正する項目が指定されていません。")
+                .with_action("--start・--end・--noteのいずれかを指定してください。"));
+        }
+        if date > self.time_zone.today() {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-WORK-005")
+                .with_message(format!(
+                    "未来の日付の作業記録は修正できません。詳細: {date}"
+                ))
+                .with_action("今日以前の日付を指定してください。"));
+        }
+
+        // 指定されなかった時刻は記録済みの時刻を使用する
+        let stored = self
+            .work_time_port
+            .load_record(date)?
+            .unwrap_or_default()
+            .localized(&self.time_zone);
+        let start = edit.start.clone().or(stored.start);
+        let end = edit.end.clone().or(stored.end);
+        let edits_time = edit.start.is_some() || edit.end.is_some();
+
+        // 開始時刻を修正した場合も、日付をまたぐかどうかが変わるため終了日時を求め直す
+        let ended_at = match (&start, &end) {
+            (Some(start), Some(end)) if edits_time && start == end => {
+                return Err(AppError::new(ErrorKind::BadRequest)
+                    .with_code("MC-WORK-006")
+                    .with_message(format!(
+                        "開始時刻と終了時刻が同じです。詳細: {}-{}",
+                        start.as_str(),
+                        end.as_str()
+                    ))
+                    .with_action("開始時刻と異なる終了時刻を指定してください。"));
+            }
+            (start, Some(end)) if edits_time => {
+                let overnight = start.as_ref().is_some_and(|start| {
+                    WorkTimeRange::new(start.clone(), end.clone()).crosses_midnight()
+                });
+                let end_date = if overnight {
+                    date.succ_opt().unwrap_or(date)
+                } else {
+                    date
+                };
+                Some(
+                    self.time_zone
+                        .resolve_local(end_date.and_time(end.time()))?,
+                )
+            }
+            _ => None,
+        };
+
+        if let Some(start) = &edit.start {
+            let started_at = self.time_zone.resolve_local(date.and_time(start.time()))?;
+            self.work_time_port.save_start_time(date, &started_at)?;
+        }
+        if let Some(ended_at) = &ended_at {
+            self.work_time_port.save_end_time(date, ended_at)?;
+        }
+        if let Some(note) = &edit.note {
+            self.work_time_port.replace_note(date, note)?;
+        }
+        Ok(self
+            .work_time_port
+            .load_record(date)?
+            .unwrap_or_default()
+            .localized(&self.time_zone))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbound::json_work_time_adapter::JsonWorkTimeAdapter;
+    use std::fs;
+
+    #[test]
+    fn test_edit_work_time() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_work_time_edit_{}",
+            std::process::id()
+        ));
+        let time_zone = WorkTimeZone::parse("Asia/Tokyo").unwrap();
+        let use_case = WorkTimeEditUseCase::new(JsonWorkTimeAdapter::new(
+            dir.to_string_lossy(),
+            "work_times.json",
+        ))
+        .with_time_zone(time_zone);
+        let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let time = |time: &str| Some(WorkTime::new(time).unwrap());
+
+        let started = use_case
+            .edit(
+                date,
+                &WorkTimeEdit {
+                    start: time("09:00"),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let same = use_case.edit(
+            date,
+            &WorkTimeEdit {
+                end: time("09:00"),
+                ..Default::default()
+            },
+        );
+        let ended = use_case
+            .edit(
+                date,
+                &WorkTimeEdit {
+                    end: time("18:00"),
+                    note: Some("打刻漏れを修正".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        // 記録済みの終了時刻と同じ開始時刻は、開始時刻のみの修正でも受け付けない
+        let same_start = use_case.edit(
+            date,
+            &WorkTimeEdit {
+                start: time("18:00"),
+                ..Default::default()
+            },
+        );
+        let renoted = use_case
+            .edit(
+                date,
+                &WorkTimeEdit {
+                    note: Some("直行直帰".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let night = NaiveDate::from_ymd_opt(2024, 6, 4).unwrap();
+        let overnight = use_case
+            .edit(
+                night,
+                &WorkTimeEdit {
+                    start: time("22:00"),
+                    end: time("06:30"),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let empty = use_case.edit(date, &WorkTimeEdit::default());
+        let future = use_case.edit(
+            NaiveDate::from_ymd_opt(2999, 1, 1).unwrap(),
+            &WorkTimeEdit {
+                start: time("09:00"),
+                ..Default::default()
+            },
+        );
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(started.start, time("09:00"));
+        assert!(same.is_err());
+        assert_eq!(ended.start, time("09:00"));
+        assert_eq!(ended.end, time("18:00"));
+        assert_eq!(ended.notes.as_deref(), Some("打刻漏れを修正"));
+        assert!(same_start.is_err());
+        assert_eq!(renoted.start, time("09:00"));
+        assert_eq!(renoted.notes.as_deref(), Some("直行直帰"));
+        assert_eq!(overnight.end, time("06:30"));
+        assert_eq!(overnight.duration(), Some(510));
+        assert!(empty.is_err());
+        assert!(future.is_err());
+    }
+}
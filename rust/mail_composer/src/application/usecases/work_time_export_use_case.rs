@@ -0,0 +1,152 @@
+use crate::{
+    application::usecases::work_time_report_use_case::month_range,
+    domain::interfaces::{work_time::WorkTimePort, work_time_export::WorkTimeExportPort},
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::path::PathBuf;
+
+/// 作業記録を勤怠表に貼り付けられる形式で書き出すユースケース
+pub struct WorkTimeExportUseCase<W: WorkTimePort, E: WorkTimeExportPort> {
+    work_time_port: W,
+    export_port: E,
+}
+
+impl<W: WorkTimePort, E: WorkTimeExportPort> WorkTimeExportUseCase<W, E> {
+    /// 新しいWorkTimeExportUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `work_time_port` - 作業記録の読み込みに使用するポート
+    /// * `export_port` - 書き出しに使用するポート
+    ///
+    /// ## Returns
+    /// * WorkTimeExportUseCaseのインスタンス
+    pub fn new(work_time_port: W, export_port: E) -> Self {
+        Self {
+            work_time_port,
+            export_port,
+        }
+    }
+
+    /// 指定月の作業記録を`work_times_YYYY-MM`という名前で書き出す
+    ///
+    /// ## Arguments
+    /// * `year` - 対象年
+    /// * `month` - 対象月（1〜12）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<PathBuf>` (出力したファイルのパス)
+    /// * 失敗時 - 月が不正な場合、記録がない場合、または書き出しに失敗した場合の`Err<AppError>`
+    pub fn export_month(&self, year: i32, month: u32) -> AppResult<PathBuf> {
+        let _span = logging::operation_span("work_time_export").entered();
+        let (first_day, last_day) = month_range(year, month)?;
+        let records = self.work_time_port.load_records(first_day, last_day)?;
+        if records.is_empty() {
+            return Err(AppError::new(ErrorKind::NotFound)
+                .with_code("MC-WORK-007")
+                .with_message(format!("{year}年{month}月の作業記録がありません。"))
+                .with_action("対象月を確認してください。"));
+        }
+        self.export_port
+            .export(&format!("work_times_{year}-{month:02}"), &records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::work_record::WorkRecord;
+    use chrono::{DateTime, FixedOffset, NaiveDate};
+    use std::cell::RefCell;
+
+    struct StubWorkTime;
+
+    impl WorkTimePort for StubWorkTime {
+        fn save_start_time(
+            &self,
+            _date: NaiveDate,
+            _started_at: &DateTime<FixedOffset>,
+        ) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn save_end_time(
+            &self,
+            _date: NaiveDate,
+            _ended_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn pause(
+            &self,
+            _date: NaiveDate,
+            _paused_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn resume(
+            &self,
+            _date: NaiveDate,
+            _resumed_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn load_record(&self, _date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+            Ok(None)
+        }
+
+        fn load_records(
+            &self,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+            let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+            Ok(if (from..=to).contains(&date) {
+                vec![(date, WorkRecord::default())]
+            } else {
+                Vec::new()
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingExport {
+        exported: RefCell<Vec<(String, usize)>>,
+    }
+
+    impl WorkTimeExportPort for RecordingExport {
+        fn export(
+            &self,
+            file_stem: &str,
+            records: &[(NaiveDate, WorkRecord)],
+        ) -> AppResult<PathBuf> {
+            self.exported
+                .borrow_mut()
+                .push((file_stem.to_string(), records.len()));
+            Ok(PathBuf::from(format!("{file_stem}.csv")))
+        }
+    }
+
+    #[test]
+    fn test_export_month() {
+        let use_case = WorkTimeExportUseCase::new(StubWorkTime, RecordingExport::default());
+
+        assert_eq!(
+            use_case.export_month(2025, 4).unwrap(),
+            PathBuf::from("work_times_2025-04.csv")
+        );
+        assert!(use_case.export_month(2025, 5).is_err());
+        assert_eq!(
+            *use_case.export_port.exported.borrow(),
+            vec![("work_times_2025-04".to_string(), 1)]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::domain::interfaces::{
+    time_entry_source::{TimeEntry, TimeEntrySourcePort},
+    work_time::WorkTimePort,
+};
+use chrono::NaiveDate;
+use share::{error::app_error::AppResult, logging};
+use std::collections::BTreeMap;
+
+/// 作業時間の取り込み結果
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct WorkTimeImportReport {
+    /// 作業記録を取り込んだ日付
+    pub imported: Vec<NaiveDate>,
+    /// 取り込み先に作業記録があるため取り込まなかった日付
+    pub skipped: Vec<NaiveDate>,
+}
+
+/// 外部の作業時間記録サービスのデータを作業記録として取り込むユースケース
+///
+/// 同じ日の複数の作業時間は、最初の開始から最後の終了までを1日の作業記録とし、
+/// 作業時間の間の空き時間は休憩として記録する
+pub struct WorkTimeImportUseCase<S, W>
+where
+    S: TimeEntrySourcePort,
+    W: WorkTimePort,
+{
+    source_port: S,
+    target_port: W,
+}
+
+impl<S, W> WorkTimeImportUseCase<S, W>
+where
+    S: TimeEntrySourcePort,
+    W: WorkTimePort,
+{
+    /// 新しいWorkTimeImportUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `source_port` - 取り込み元の作業時間
+    /// * `target_port` - 取り込み先の作業記録
+    ///
+    /// ## Returns
+    /// * WorkTimeImportUseCaseのインスタンス
+    pub fn new(source_port: S, target_port: W) -> Self {
+        Self {
+            source_port,
+            target_port,
+        }
+    }
+
+    /// 取り込み元の作業時間を日ごとにまとめて取り込み先に保存する
+    ///
+    /// 取り込み先に作業記録がある日は、重複として取り込まない
+    ///
+    /// ## Arguments
+    /// * `is_dry_run` - ドライランモード（true の場合、取り込み先に保存しない）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkTimeImportReport>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn import(&self, is_dry_run: bool) -> AppResult<WorkTimeImportReport> {
+        let _span = logging::operation_span("work_time_import").entered();
+        let mut by_date: BTreeMap<NaiveDate, Vec<TimeEntry>> = BTreeMap::new();
+        for entry in self.source_port.list_entries()? {
+            by_date.entry(entry.date()).or_default().push(entry);
+        }
+
+        let mut report = WorkTimeImportReport::default();
+        for (date, entries) in by_date {
+            if self.target_port.load_record(date)?.is_some() {
+                report.skipped.push(date);
+                continue;
+            }
+            if !is_dry_run {
+                self.save_day(date, entries)?;
+            }
+            report.imported.push(date);
+        }
+        Ok(report)
+    }
+
+    /// 1日分の作業時間を開始・休憩・終了として保存する
+    fn save_day(&self, date: NaiveDate, mut entries: Vec<TimeEntry>) -> AppResult<()> {
+        entries.sort_by_key(|entry| entry.started_at);
+        let Some(first) = entries.first() else {
+            return Ok(());
+        };
+        self.target_port.save_start_time(date, &first.started_at)?;
+
+        let mut ended_at = first.ended_at;
+        for entry in &entries[1..] {
+            // 重なる作業時間はつなげ、空き時間がある場合のみ休憩とする
+            if entry.started_at > ended_at {
+                self.target_port.pause(date, &ended_at)?;
+                self.target_port.resume(date, &entry.started_at)?;
+            }
+            ended_at = ended_at.max(entry.ended_at);
+        }
+        self.target_port.save_end_time(date, &ended_at)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::value_objects::time_zone::WorkTimeZone,
+        infrastructure::outbound::{
+            json_work_time_adapter::JsonWorkTimeAdapter,
+            time_tracker_csv_adapter::TimeTrackerCsvAdapter,
+        },
+    };
+    use chrono::{DateTime, FixedOffset};
+    use std::fs;
+
+    fn at(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    #[test]
+    fn test_import_with_breaks_and_duplicates() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_work_time_import_{}",
+            std::process::id()
+        ));
+        let target = JsonWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.json");
+        let existing = NaiveDate::from_ymd_opt(2025, 4, 2).unwrap();
+        target
+            .save_start_time(existing, &at("2025-04-02T10:00:00+09:00"))
+            .unwrap();
+        let source = TimeTrackerCsvAdapter::from_csv_str(
+            "Description,Start date,Start time,End date,End time\n\
+             午後,2025-04-01,13:00:00,2025-04-01,18:00:00\n\
+             午前,2025-04-01,09:00:00,2025-04-01,12:00:00\n\
+             追加,2025-04-01,17:30:00,2025-04-01,18:30:00\n\
+             重複,2025-04-02,09:00:00,2025-04-02,18:00:00\n",
+            &WorkTimeZone::parse("Asia/Tokyo").unwrap(),
+        )
+        .unwrap();
+        let use_case = WorkTimeImportUseCase::new(source, target);
+
+        let dry_run = use_case.import(true);
+        let report = use_case.import(false);
+        let imported = use_case
+            .target_port
+            .load_record(NaiveDate::from_ymd_opt(2025, 4, 1).unwrap());
+        let kept = use_case.target_port.load_record(existing);
+        let _ = fs::remove_dir_all(&dir);
+
+        let report = report.unwrap();
+        assert_eq!(dry_run.unwrap(), report);
+        assert_eq!(
+            report.imported,
+            [NaiveDate::from_ymd_opt(2025, 4, 1).unwrap()]
+        );
+        assert_eq!(report.skipped, [existing]);
+        let imported = imported.unwrap().unwrap();
+        assert_eq!(imported.start.as_ref().unwrap().as_str(), "09:00");
+        assert_eq!(imported.end.as_ref().unwrap().as_str(), "18:30");
+        assert_eq!(imported.break_minutes(), 60);
+        assert_eq!(imported.duration(), Some(510));
+        assert!(kept.unwrap().unwrap().end.is_none());
+    }
+}
@@ -0,0 +1,496 @@
+use crate::domain::{
+    interfaces::{
+        holiday_calendar::{HolidayCalendarPort, weekdays_between},
+        work_time::WorkTimePort,
+    },
+    value_objects::{
+        mail_objects::WorkTime,
+        time_zone::WorkTimeZone,
+        work_hours::{DurationFormat, StandardWorkHours},
+    },
+};
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::collections::BTreeMap;
+
+/// 月次レポートの出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// 端末表示用のテキスト
+    Text,
+    /// Markdownの表
+    Markdown,
+}
+
+/// 1日分の勤務実績
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyWorkSummary {
+    /// 日付
+    pub date: NaiveDate,
+    /// 作業開始時刻
+    pub start: Option<WorkTime>,
+    /// 作業終了時刻
+    pub end: Option<WorkTime>,
+    /// 作業時間（分）
+    pub duration_minutes: Option<i64>,
+    /// 残業時間（分）
+    pub overtime_minutes: Option<i64>,
+}
+
+/// 1か月分の勤務実績
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MonthlyWorkReport {
+    /// 対象年
+    pub year: i32,
+    /// 対象月
+    pub month: u32,
+    /// 記録がある日の勤務実績（日付順）
+    pub days: Vec<DailyWorkSummary>,
+    /// 合計作業時間（分）
+    pub total_minutes: i64,
+    /// 作業時間を計算できた日数
+    pub worked_days: usize,
+    /// 所定労働日数（土日と、祝日カレンダーを指定した場合は祝日を除いた日数）
+    pub expected_workdays: usize,
+    /// 1日あたりの平均作業時間（分）
+    pub average_minutes: Option<i64>,
+    /// 平均開始時刻
+    pub average_start: Option<String>,
+    /// 平均終了時刻
+    pub average_end: Option<String>,
+    /// 合計残業時間（分）
+    pub total_overtime_minutes: i64,
+    /// 週の初日（月曜日）ごとの残業時間（分）
+    pub weekly_overtime_minutes: BTreeMap<NaiveDate, i64>,
+    /// 作業時間の表示形式
+    pub duration_format: DurationFormat,
+}
+
+impl MonthlyWorkReport {
+    /// 指定した形式でレポートを整形する
+    ///
+    /// ## Arguments
+    /// * `format` - 出力形式
+    ///
+    /// ## Returns
+    /// * 整形したレポート
+    pub fn render(&self, format: ReportFormat) -> String {
+        let title = format!("{}年{:02}月 勤務実績", self.year, self.month);
+        let missing = || "--:--".to_string();
+        let format_minutes = |minutes: i64| self.duration_format.format(minutes);
+        let rows = self.days.iter().map(|day| {
+            [
+                day.date.to_string(),
+                weekday_name(day.date).to_string(),
+                day.start
+                    .as_ref()
+                    .map_or_else(missing, |t| t.as_str().to_string()),
+                day.end
+                    .as_ref()
+                    .map_or_else(missing, |t| t.as_str().to_string()),
+                day.duration_minutes.map_or_else(missing, format_minutes),
+                day.overtime_minutes.map_or_else(missing, format_minutes),
+            ]
+        });
+        let mut summary = vec![
+            (
+                "合計作業時間",
+                format!(
+                    "{}（{}日）",
+                    format_minutes(self.total_minutes),
+                    self.worked_days
+                ),
+            ),
+            ("所定労働日数", format!("{}日", self.expected_workdays)),
+            (
+                "平均作業時間",
+                self.average_minutes.map_or_else(missing, format_minutes),
+            ),
+            (
+                "平均開始時刻",
+                self.average_start.clone().unwrap_or_else(missing),
+            ),
+            (
+                "平均終了時刻",
+                self.average_end.clone().unwrap_or_else(missing),
+            ),
+            ("合計残業時間", format_minutes(self.total_overtime_minutes)),
+        ];
+        summary.extend(
+            self.weekly_overtime_minutes
+                .iter()
+                .map(|(monday, minutes)| {
+                    (
+                        "週の残業時間",
+                        format!("{monday}〜 {}", format_minutes(*minutes)),
+                    )
+                }),
+        );
+
+        let mut lines = Vec::new();
+        match format {
+            ReportFormat::Text => {
+                lines.push(title);
+                lines.push("日付        曜日 開始  終了  作業時間   残業".to_string());
+                lines.extend(rows.map(|[date, weekday, start, end, duration, overtime]| {
+                    format!("{date} ({weekday}) {start} {end} {duration:>6} {overtime:>6}")
+                }));
+                lines.push(String::new());
+                lines.extend(
+                    summary
+                        .into_iter()
+                        .map(|(label, value)| format!("{label}: {value}")),
+                );
+            }
+            ReportFormat::Markdown => {
+                lines.push(format!("# {title}"));
+                lines.push(String::new());
+                lines.push("| 日付 | 曜日 | 開始 | 終了 | 作業時間 | 残業 |".to_string());
+                lines.push("| --- | --- | --- | --- | ---: | ---: |".to_string());
+                lines.extend(rows.map(|row| format!("| {} |", row.join(" | "))));
+                lines.push(String::new());
+                lines.extend(
+                    summary
+                        .into_iter()
+                        .map(|(label, value)| format!("- {label}: {value}")),
+                );
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// 曜日の日本語表記
+fn weekday_name(date: NaiveDate) -> &'static str {
+    ["月", "火", "水", "木", "金", "土", "日"][date.weekday().num_days_from_monday() as usize]
+}
+
+/// 値の平均（値がない場合は`None`）
+fn average(values: &[i64]) -> Option<i64> {
+    (!values.is_empty()).then(|| values.iter().sum::<i64>() / values.len() as i64)
+}
+
+/// 指定月の初日と末日を取得する
+///
+/// ## Arguments
+/// * `year` - 対象年
+/// * `month` - 対象月（1〜12）
+///
+/// ## Returns
+/// * 成功時 - `Ok<(初日, 末日)>`
+/// * 失敗時 - 月が不正な場合の`Err<AppError>`
+pub fn month_range(year: i32, month: u32) -> AppResult<(NaiveDate, NaiveDate)> {
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
+        AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-WORK-008")
+            .with_message(format!("対象月が不正です。詳細: {year}-{month}"))
+            .with_action("月は1〜12の範囲で指定してください。")
+    })?;
+    let last_day = first_day
+        .checked_add_months(chrono::Months::new(1))
+        .and_then(|next_month| next_month.pred_opt())
+        .unwrap_or(NaiveDate::MAX);
+    Ok((first_day, last_day))
+}
+
+/// 作業記録を月単位で集計するユースケース
+pub struct WorkTimeReportUseCase<W: WorkTimePort> {
+    work_time_port: W,
+    standard_hours: StandardWorkHours,
+    duration_format: DurationFormat,
+    time_zone: WorkTimeZone,
+    holiday_calendar: Option<Box<dyn HolidayCalendarPort>>,
+}
+
+impl<W: WorkTimePort> WorkTimeReportUseCase<W> {
+    /// 新しいWorkTimeReportUseCaseを作成する
+    ///
+    /// ## Arguments
+    /// * `work_time_port` - 作業記録の読み込みに使用するポート
+    ///
+    /// ## Returns
+    /// * WorkTimeReportUseCaseのインスタンス
+    pub fn new(work_time_port: W) -> Self {
+        Self {
+            work_time_port,
+            standard_hours: StandardWorkHours::default(),
+            duration_format: DurationFormat::default(),
+            time_zone: WorkTimeZone::default(),
+            holiday_calendar: None,
+        }
+    }
+
+    /// 残業時間の算出に使用する所定労働時間を指定する
+    ///
+    /// ## Arguments
+    /// * `standard_hours` - 1日あたりの所定労働時間
+    ///
+    /// ## Returns
+    /// * 所定労働時間が設定されたWorkTimeReportUseCaseのインスタンス
+    pub fn with_standard_hours(mut self, standard_hours: StandardWorkHours) -> Self {
+        self.standard_hours = standard_hours;
+        self
+    }
+
+    /// レポートでの作業時間の表示形式を指定する
+    ///
+    /// ## Arguments
+    /// * `duration_format` - 作業時間の表示形式
+    ///
+    /// ## Returns
+    /// * 表示形式が設定されたWorkTimeReportUseCaseのインスタンス
+    pub fn with_duration_format(mut self, duration_format: DurationFormat) -> Self {
+        self.duration_format = duration_format;
+        self
+    }
+
+    /// 開始時刻と終了時刻の表示に使用するタイムゾーンを指定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 表示に使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * タイムゾーンが設定されたWorkTimeReportUseCaseのインスタンス
+    pub fn with_time_zone(mut self, time_zone: WorkTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// 所定労働日数から祝日を除くための祝日カレンダーを指定する
+    ///
+    /// ## Arguments
+    /// * `holiday_calendar` - 祝日の判定に使用するポート
+    ///
+    /// ## Returns
+    /// * 祝日カレンダーが設定されたWorkTimeReportUseCaseのインスタンス
+    pub fn with_holiday_calendar(
+        mut self,
+        holiday_calendar: impl HolidayCalendarPort + 'static,
+    ) -> Self {
+        self.holiday_calendar = Some(Box::new(holiday_calendar));
+        self
+    }
+
+    /// 指定月の勤務実績を集計する
+    ///
+    /// ## Arguments
+    /// * `year` - 対象年
+    /// * `month` - 対象月（1〜12）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<MonthlyWorkReport>`
+    /// * 失敗時 - 月が不正な場合、または作業記録の読み込みに失敗した場合の`Err<AppError>`
+    pub fn monthly_report(&self, year: i32, month: u32) -> AppResult<MonthlyWorkReport> {
+        let (first_day, last_day) = month_range(year, month)?;
+
+        let days: Vec<DailyWorkSummary> = self
+            .work_time_port
+            .load_records(first_day, last_day)?
+            .into_iter()
+            .map(|(date, record)| (date, record.localized(&self.time_zone)))
+            .map(|(date, record)| DailyWorkSummary {
+                duration_minutes: record.duration(),
+                overtime_minutes: record
+                    .duration()
+                    .map(|minutes| self.standard_hours.daily_overtime(minutes)),
+                start: record.start,
+                end: record.end,
+                date,
+            })
+            .collect();
+
+        let durations: Vec<i64> = days.iter().filter_map(|d| d.duration_minutes).collect();
+        let starts: Vec<i64> = days
+            .iter()
+            .filter_map(|d| Some(d.start.as_ref()?.minutes_of_day()))
+            .collect();
+        let ends: Vec<i64> = days
+            .iter()
+            .filter_map(|d| Some(d.end.as_ref()?.minutes_of_day()))
+            .collect();
+        let clock = |minutes: i64| format!("{:02}:{:02}", minutes / 60, minutes % 60);
+        let weekly_overtime_minutes = self.standard_hours.weekly_overtime(
+            days.iter()
+                .filter_map(|d| Some((d.date, d.duration_minutes?))),
+        );
+
+        let expected_workdays = match &self.holiday_calendar {
+            Some(calendar) => calendar.working_days(first_day, last_day)?,
+            None => weekdays_between(first_day, last_day),
+        };
+
+        Ok(MonthlyWorkReport {
+            year,
+            month,
+            total_minutes: durations.iter().sum(),
+            worked_days: durations.len(),
+            expected_workdays,
+            average_minutes: average(&durations),
+            average_start: average(&starts).map(clock),
+            average_end: average(&ends).map(clock),
+            total_overtime_minutes: weekly_overtime_minutes.values().sum(),
+            weekly_overtime_minutes,
+            duration_format: self.duration_format,
+            days,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::entities::work_record::WorkRecord,
+        infrastructure::outbound::japanese_holiday_calendar_adapter::JapaneseHolidayCalendarAdapter,
+    };
+    use chrono::{DateTime, FixedOffset};
+
+    struct StubWorkTime;
+
+    impl WorkTimePort for StubWorkTime {
+        fn save_start_time(
+            &self,
+            _date: NaiveDate,
+            _started_at: &DateTime<FixedOffset>,
+        ) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn save_end_time(
+            &self,
+            _date: NaiveDate,
+            _ended_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn pause(
+            &self,
+            _date: NaiveDate,
+            _paused_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn resume(
+            &self,
+            _date: NaiveDate,
+            _resumed_at: &DateTime<FixedOffset>,
+        ) -> AppResult<WorkRecord> {
+            Ok(WorkRecord::default())
+        }
+
+        fn load_record(&self, _date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+            Ok(None)
+        }
+
+        fn load_records(
+            &self,
+            from: NaiveDate,
+            to: NaiveDate,
+        ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+            let record = |start: &str, end: Option<&str>| {
+                let mut record = WorkRecord::default();
+                record.set_start(WorkTime::new(start).unwrap());
+                if let Some(end) = end {
+                    record.set_end(WorkTime::new(end).unwrap());
+                }
+                record
+            };
+            Ok([
+                ("2025-03-31", record("09:00", Some("18:00"))),
+                ("2025-04-01", record("09:00", Some("18:00"))),
+                ("2025-04-02", record("10:00", Some("18:30"))),
+                ("2025-04-03", record("09:30", None)),
+            ]
+            .into_iter()
+            .map(|(date, record)| (date.parse().unwrap(), record))
+            .filter(|(date, _)| (from..=to).contains(date))
+            .collect())
+        }
+    }
+
+    #[test]
+    fn test_monthly_report() {
+        let report = WorkTimeReportUseCase::new(StubWorkTime)
+            .monthly_report(2025, 4)
+            .unwrap();
+
+        assert_eq!(report.days.len(), 3);
+        assert_eq!(report.total_minutes, 9 * 60 + 8 * 60 + 30);
+        assert_eq!(report.worked_days, 2);
+        assert_eq!(report.expected_workdays, 22);
+        assert_eq!(report.average_minutes, Some(525));
+        assert_eq!(report.average_start.as_deref(), Some("09:30"));
+        assert_eq!(report.average_end.as_deref(), Some("18:15"));
+        assert_eq!(report.total_overtime_minutes, 90);
+        assert_eq!(
+            report.weekly_overtime_minutes,
+            BTreeMap::from([("2025-03-31".parse().unwrap(), 90)])
+        );
+
+        let text = report.render(ReportFormat::Text);
+        assert!(text.starts_with("2025年04月 勤務実績"));
+        assert!(text.contains("2025-04-03 (木) 09:30 --:--  --:--"));
+        assert!(text.contains("合計作業時間: 17:30（2日）"));
+        assert!(text.contains("合計残業時間: 1:30"));
+        assert!(text.contains("週の残業時間: 2025-03-31〜 1:30"));
+
+        let markdown = report.render(ReportFormat::Markdown);
+        assert!(markdown.contains("| 2025-04-01 | 火 | 09:00 | 18:00 | 9:00 | 1:00 |"));
+        assert!(markdown.contains("- 平均作業時間: 8:45"));
+    }
+
+    #[test]
+    fn test_monthly_report_with_standard_hours() {
+        let report = WorkTimeReportUseCase::new(StubWorkTime)
+            .with_standard_hours(StandardWorkHours::from_hours(7.5).unwrap())
+            .monthly_report(2025, 4)
+            .unwrap();
+
+        assert_eq!(report.days[0].overtime_minutes, Some(90));
+        assert_eq!(report.days[2].overtime_minutes, None);
+        assert_eq!(report.total_overtime_minutes, 150);
+    }
+
+    #[test]
+    fn test_expected_workdays_exclude_holidays() {
+        let report = WorkTimeReportUseCase::new(StubWorkTime)
+            .with_holiday_calendar(JapaneseHolidayCalendarAdapter::new())
+            .monthly_report(2025, 4)
+            .unwrap();
+
+        // 2025年4月: 平日22日のうち4/29（昭和の日）が祝日
+        assert_eq!(report.expected_workdays, 21);
+        assert!(
+            report
+                .render(ReportFormat::Text)
+                .contains("所定労働日数: 21日")
+        );
+    }
+
+    #[test]
+    fn test_render_with_duration_format() {
+        let report = WorkTimeReportUseCase::new(StubWorkTime)
+            .with_duration_format(DurationFormat::Japanese)
+            .monthly_report(2025, 4)
+            .unwrap();
+
+        let text = report.render(ReportFormat::Text);
+        assert!(text.contains("合計作業時間: 17時間30分（2日）"));
+        assert!(text.contains("平均作業時間: 8時間45分"));
+    }
+
+    #[test]
+    fn test_invalid_month() {
+        assert!(
+            WorkTimeReportUseCase::new(StubWorkTime)
+                .monthly_report(2025, 13)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,630 @@
+use crate::domain::value_objects::{address_query::AddressQuery, email_address::EmailAddress};
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::collections::{BTreeMap, HashSet};
+
+/// AddressBookエントリを表現する構造体
+///
+/// `address`を持つエントリは個人、`members`を持つエントリはグループ（配布リスト）を表す。
+/// `valid_from`/`valid_until`を指定したエントリは、その期間（両端を含む）のみ名前解決の対象になる
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressBookEntry {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<NaiveDate>,
+}
+
+impl AddressBookEntry {
+    /// 個人のエントリを作成する
+    ///
+    /// ## Arguments
+    /// * `name` - 名前
+    /// * `address` - メールアドレス
+    ///
+    /// ## Returns
+    /// * 個人のAddressBookEntry
+    pub fn person(name: impl Into<String>, address: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            address: Some(address.into()),
+            members: Vec::new(),
+            aliases: Vec::new(),
+            display_name: None,
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    /// グループのエントリを作成する
+    ///
+    /// ## Arguments
+    /// * `name` - グループ名
+    /// * `members` - メンバーの名前の一覧
+    ///
+    /// ## Returns
+    /// * グループのAddressBookEntry
+    pub fn group(name: impl Into<String>, members: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            address: None,
+            members,
+            aliases: Vec::new(),
+            display_name: None,
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    /// 別名を設定する
+    ///
+    /// ## Arguments
+    /// * `aliases` - 名前の代わりに使用できる別名の一覧
+    ///
+    /// ## Returns
+    /// * 別名が設定されたAddressBookEntry
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// 宛先に表示する名前を設定する
+    ///
+    /// ## Arguments
+    /// * `display_name` - 宛先に表示する名前（例: `山田太郎`）
+    ///
+    /// ## Returns
+    /// * 表示名が設定されたAddressBookEntry
+    pub fn with_display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// 有効期間を設定する
+    ///
+    /// ## Arguments
+    /// * `valid_from` - 有効期間の開始日（`None`の場合は制限なし）
+    /// * `valid_until` - 有効期間の終了日（`None`の場合は制限なし）
+    ///
+    /// ## Returns
+    /// * 有効期間が設定されたAddressBookEntry
+    pub fn with_validity(
+        mut self,
+        valid_from: Option<NaiveDate>,
+        valid_until: Option<NaiveDate>,
+    ) -> Self {
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        self
+    }
+
+    /// 指定された日付にエントリが有効かどうかを判定する
+    ///
+    /// ## Arguments
+    /// * `date` - 判定する日付
+    ///
+    /// ## Returns
+    /// * 有効期間内の場合は`true`
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool {
+        self.valid_from.is_none_or(|from| from <= date)
+            && self.valid_until.is_none_or(|until| date <= until)
+    }
+
+    /// グループのエントリかどうかを判定する
+    ///
+    /// ## Returns
+    /// * グループの場合は`true`
+    pub fn is_group(&self) -> bool {
+        self.address.is_none()
+    }
+}
+
+/// 名前からメールアドレスを引くアドレスブックを表現するエンティティ
+///
+/// 読み込み元の形式（JSON、vCardなど）に依存しない名前解決の規則を提供する
+#[derive(Debug, Clone, Default)]
+pub struct AddressBook {
+    map: BTreeMap<String, usize>,
+    reverse_map: BTreeMap<String, usize>,
+    entries: Vec<AddressBookEntry>,
+    reference_date: Option<NaiveDate>,
+}
+
+impl AddressBook {
+    /// エントリの一覧からAddressBookを作成する
+    ///
+    /// ## Arguments
+    /// * `entries` - AddressBookエントリの一覧
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<AddressBook>`
+    /// * 失敗時 - 名前の重複やエントリの不整合がある場合の`Err<AppError>`
+    pub fn from_entries(entries: Vec<AddressBookEntry>) -> AppResult<Self> {
+        // 重複チェック
+        let mut names = HashSet::new();
+        for entry in &entries {
+            if !names.insert(&entry.name) {
+                return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                    .with_code("MC-ADDR-001")
+                    .with_message(format!(
+                        "重複する名前が見つかりました。詳細: {}",
+                        entry.name
+                    ))
+                    .with_action("AddressBook内の名前は一意である必要があります。"));
+            }
+        }
+
+        // 個人とグループの整合性チェック
+        for entry in &entries {
+            if entry.address.is_some() != entry.members.is_empty() {
+                return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                    .with_code("MC-ADDR-002")
+                    .with_message(format!(
+                        "エントリの形式が正しくありません。詳細: {}",
+                        entry.name
+                    ))
+                    .with_action(
+                        "各エントリにはaddressとmembersのどちらか一方だけを指定してください。",
+                    ));
+            }
+        }
+
+        // 名前と別名からエントリの位置を引く索引を作成
+        let mut map: BTreeMap<String, usize> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (entry.name.clone(), index))
+            .collect();
+        for (index, entry) in entries.iter().enumerate() {
+            for alias in &entry.aliases {
+                let registered = *map.entry(alias.clone()).or_insert(index);
+                if registered != index {
+                    return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                        .with_code("MC-ADDR-003")
+                        .with_message(format!(
+                            "別名'{}'が'{}'と'{}'で重複しています。",
+                            alias, entries[registered].name, entry.name
+                        ))
+                        .with_action(
+                            "別名は他のエントリの名前や別名と重複しないようにしてください。",
+                        ));
+                }
+            }
+        }
+
+        // メールアドレスから名前を引く索引を作成（同じアドレスの場合は先に登録されたものを優先）
+        let mut reverse_map = BTreeMap::new();
+        for (index, entry) in entries.iter().enumerate() {
+            if let Some(address) = &entry.address {
+                reverse_map
+                    .entry(address.trim().to_lowercase())
+                    .or_insert(index);
+            }
+        }
+
+        Ok(Self {
+            map,
+            reverse_map,
+            entries,
+            reference_date: None,
+        })
+    }
+
+    /// 全てのエントリを取得する
+    ///
+    /// ## Returns
+    /// * 全てのAddressBookエントリのスライス
+    pub fn entries(&self) -> &[AddressBookEntry] {
+        &self.entries
+    }
+
+    /// 名前の一覧を取得する
+    ///
+    /// ## Returns
+    /// * 登録されている名前の一覧（別名は含まない）
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.entries.iter().map(|e| e.name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// エントリを追加する
+    ///
+    /// ## Arguments
+    /// * `entry` - 追加するエントリ
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 名前や別名が重複する場合の`Err<AppError>`（AddressBookは変更されない）
+    pub fn add_entry(&mut self, entry: AddressBookEntry) -> AppResult<()> {
+        let mut entries = self.entries.clone();
+        entries.push(entry);
+        self.replace_entries(entries)?;
+        Ok(())
+    }
+
+    /// 名前に対応するエントリを置き換える
+    ///
+    /// エントリの並び順は維持される
+    ///
+    /// ## Arguments
+    /// * `key_name` - 置き換え対象のエントリの名前
+    /// * `entry` - 新しいエントリ
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 名前が見つからない場合や、置き換え後に不整合がある場合の`Err<AppError>`
+    pub fn update_entry(&mut self, key_name: &str, entry: AddressBookEntry) -> AppResult<()> {
+        let index = self.index_of(key_name)?;
+        let mut entries = self.entries.clone();
+        entries[index] = entry;
+        self.replace_entries(entries)?;
+        Ok(())
+    }
+
+    /// 名前に対応するエントリを削除する
+    ///
+    /// ## Arguments
+    /// * `key_name` - 削除対象のエントリの名前
+    ///
+    /// ## Returns
+    /// * 成功時 - 削除したエントリ
+    /// * 失敗時 - 名前が見つからない場合の`Err<AppError>`
+    pub fn remove_entry(&mut self, key_name: &str) -> AppResult<AddressBookEntry> {
+        let index = self.index_of(key_name)?;
+        let mut entries = self.entries.clone();
+        let removed = entries.remove(index);
+        self.replace_entries(entries)?;
+        Ok(removed)
+    }
+
+    /// 有効期間の判定に使用する日付を設定する
+    ///
+    /// ## Arguments
+    /// * `date` - 判定に使用する日付
+    ///
+    /// ## Returns
+    /// * 判定日が設定されたAddressBook
+    pub fn with_reference_date(mut self, date: NaiveDate) -> Self {
+        self.reference_date = Some(date);
+        self
+    }
+
+    /// 有効期間の判定に使用する日付を取得する（未設定の場合は今日）
+    fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+            .unwrap_or_else(|| Local::now().date_naive())
+    }
+
+    /// 判定日を維持したままエントリを置き換える
+    fn replace_entries(&mut self, entries: Vec<AddressBookEntry>) -> AppResult<()> {
+        let rebuilt = Self::from_entries(entries)?;
+        *self = Self {
+            reference_date: self.reference_date,
+            ..rebuilt
+        };
+        Ok(())
+    }
+
+    /// 有効期間外のエントリを参照した場合のエラーを作成する
+    fn out_of_period_error(entry: &AddressBookEntry) -> AppError {
+        let format_date = |date: Option<NaiveDate>| date.map(|d| d.to_string()).unwrap_or_default();
+        AppError::new(ErrorKind::NotFound)
+            .with_code("MC-ADDR-004")
+            .with_message(format!(
+                "'{}'は有効期間（{}〜{}）外のため使用できません。",
+                entry.name,
+                format_date(entry.valid_from),
+                format_date(entry.valid_until)
+            ))
+            .with_action("テンプレートの宛先を、現在有効な名前に更新してください。")
+    }
+
+    /// 名前（別名は含まない）に対応するエントリの位置を取得する
+    fn index_of(&self, key_name: &str) -> AppResult<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.name == key_name)
+            .ok_or_else(|| {
+                AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-ADDR-005")
+                    .with_message(format!("'{key_name}'はAddressBookに登録されていません。"))
+                    .with_action("登録済みの名前を指定してください。")
+            })
+    }
+
+    /// 名前に対応するエントリを取得する
+    fn find(&self, key_name: &str) -> AppResult<&AddressBookEntry> {
+        self.map
+            .get(key_name)
+            .map(|&index| &self.entries[index])
+            .ok_or_else(|| {
+                let suggestions = self.suggest(key_name);
+                let action = if suggestions.is_empty() {
+                    "AddressBookの内容と指定した名前を確認してください。".to_string()
+                } else {
+                    format!(
+                        "もしかして: {} ？ AddressBookの内容と指定した名前を確認してください。",
+                        suggestions.join("、")
+                    )
+                };
+                AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-ADDR-006")
+                    .with_message(format!(
+                        "指定された名前に対応するメールアドレスが見つかりません。詳細: {key_name}"
+                    ))
+                    .with_action(action)
+            })
+    }
+
+    /// 指定された名前に近い登録済みの名前を、近い順に最大3件取得する
+    ///
+    /// 敬称とひらがな/カタカナの違いを無視した部分一致、または編集距離が名前の長さの1/3以下のものを候補とする
+    ///
+    /// ## Arguments
+    /// * `key_name` - 見つからなかった名前
+    ///
+    /// ## Returns
+    /// * 候補となる名前の一覧
+    pub fn suggest(&self, key_name: &str) -> Vec<&str> {
+        const MAX_SUGGESTIONS: usize = 3;
+
+        let key = normalize_name(key_name);
+        if key.is_empty() {
+            return Vec::new();
+        }
+
+        let mut candidates: Vec<(usize, &str)> = self
+            .map
+            .keys()
+            .filter_map(|name| {
+                let normalized = normalize_name(name);
+                let distance = levenshtein(&key, &normalized);
+                let threshold = key.chars().count().max(normalized.chars().count()) / 3;
+                let is_partial = !normalized.is_empty()
+                    && (normalized.contains(&key) || key.contains(&normalized));
+                (distance <= threshold.max(1) || is_partial).then_some((distance, name.as_str()))
+            })
+            .collect();
+        candidates.sort();
+        candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, name)| name)
+            .collect()
+    }
+
+    /// 名前または別名に対応するメールアドレスを取得する
+    ///
+    /// メールアドレスには、エントリの表示名（未設定の場合は名前）が表示名として設定される
+    ///
+    /// ## Arguments
+    /// * `key_name` - 取得対象のメールアドレスに対応する名前または別名
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<EmailAddress>`
+    /// * 失敗時 - 名前が見つからない場合、有効期間外の場合、グループの場合の`Err<AppError>`
+    pub fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        let entry = self.find(key_name)?;
+        if !entry.is_valid_on(self.reference_date()) {
+            return Err(Self::out_of_period_error(entry));
+        }
+        match &entry.address {
+            Some(address) => Ok(EmailAddress::parse(address)?
+                .with_display_name(entry.display_name.as_deref().unwrap_or(&entry.name))),
+            None => Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-ADDR-007")
+                .with_message(format!(
+                    "'{key_name}'はグループのため、単一のメールアドレスに解決できません。"
+                ))
+                .with_action("グループを指定する場合は、メンバー全員に展開して解決してください。")),
+        }
+    }
+
+    /// 検索条件に一致するエントリを登録順に取得する
+    ///
+    /// ## Arguments
+    /// * `query` - 検索条件
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<&AddressBookEntry>>`
+    /// * 失敗時 - 所属の条件に指定したグループが見つからない場合の`Err<AppError>`
+    pub fn search(&self, query: &AddressQuery) -> AppResult<Vec<&AddressBookEntry>> {
+        let members = match query.member_of() {
+            Some(group) => Some(self.group_members(group)?),
+            None => None,
+        };
+        let name_contains = query.name_contains().map(str::to_lowercase);
+        let domain = query
+            .domain()
+            .map(|d| d.trim_start_matches('@').to_lowercase());
+
+        Ok(self
+            .entries
+            .iter()
+            .filter(|entry| {
+                name_contains.as_deref().is_none_or(|text| {
+                    std::iter::once(&entry.name)
+                        .chain(&entry.aliases)
+                        .any(|name| name.to_lowercase().contains(text))
+                })
+            })
+            .filter(|entry| {
+                domain.as_deref().is_none_or(|domain| {
+                    entry
+                        .address
+                        .as_deref()
+                        .and_then(|address| address.rsplit_once('@'))
+                        .is_some_and(|(_, d)| d.trim().to_lowercase() == domain)
+                })
+            })
+            .filter(|entry| {
+                members
+                    .as_ref()
+                    .is_none_or(|members| members.contains(entry.name.as_str()))
+            })
+            .collect())
+    }
+
+    /// グループに直接または入れ子のグループを介して所属するエントリの名前を取得する
+    fn group_members(&self, group: &str) -> AppResult<HashSet<&str>> {
+        let mut members = HashSet::new();
+        let mut pending = vec![self.find(group)?];
+        while let Some(entry) = pending.pop() {
+            for member in &entry.members {
+                if let Ok(member) = self.find(member)
+                    && members.insert(member.name.as_str())
+                {
+                    pending.push(member);
+                }
+            }
+        }
+        Ok(members)
+    }
+
+    /// メールアドレスに対応する名前を取得する
+    ///
+    /// メールアドレスの大文字・小文字は区別しない
+    ///
+    /// ## Arguments
+    /// * `address` - 検索するメールアドレス
+    ///
+    /// ## Returns
+    /// * 登録されている場合は名前、登録されていない場合は`None`
+    pub fn reverse_resolve(&self, address: &str) -> Option<&str> {
+        self.reverse_map
+            .get(&address.trim().to_lowercase())
+            .map(|&index| self.entries[index].name.as_str())
+    }
+
+    /// 名前に対応するメールアドレスを、グループの場合はメンバー全員に展開して取得する
+    ///
+    /// グループのメンバーには個人のほか別のグループ（例: 部署 → チーム → メンバー）を指定でき、再帰的に展開する。
+    /// 複数のグループに属するメンバーのメールアドレスは1件にまとめ、有効期間外のメンバーは除外する
+    /// （除外されるメンバーはアドレスブックの監査で報告する）
+    ///
+    /// ## Arguments
+    /// * `key_name` - 個人またはグループの名前
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<EmailAddress>>`
+    /// * 失敗時 - 名前またはメンバーが見つからない場合、グループが循環している場合の`Err<AppError>`
+    pub fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        let mut addresses = Vec::new();
+        self.expand_into(key_name, &mut Vec::new(), &mut addresses)?;
+        Ok(addresses)
+    }
+
+    /// グループを再帰的に展開し、メールアドレスを追加する
+    ///
+    /// ## Arguments
+    /// * `key_name` - 展開する個人またはグループの名前
+    /// * `path` - 展開中のグループの名前（循環の検出に使用）
+    /// * `addresses` - 展開したメールアドレスの追加先
+    fn expand_into<'a>(
+        &'a self,
+        key_name: &str,
+        path: &mut Vec<&'a str>,
+        addresses: &mut Vec<EmailAddress>,
+    ) -> AppResult<()> {
+        let entry = self.find(key_name)?;
+        if !entry.is_valid_on(self.reference_date()) {
+            // グループ経由で参照された有効期間外のメンバーは読み飛ばす
+            if path.is_empty() {
+                return Err(Self::out_of_period_error(entry));
+            }
+            return Ok(());
+        }
+        if !entry.is_group() {
+            let address = self.resolve(key_name)?;
+            if !addresses.contains(&address) {
+                addresses.push(address);
+            }
+            return Ok(());
+        }
+
+        if path.contains(&entry.name.as_str()) {
+            path.push(&entry.name);
+            return Err(AppError::new(ErrorKind::Conflict)
+                .with_code("MC-ADDR-008")
+                .with_message(format!(
+                    "グループが循環しています。詳細: {}",
+                    path.join(" → ")
+                ))
+                .with_action("グループのメンバーに、自身を含むグループを指定しないでください。"));
+        }
+
+        path.push(&entry.name);
+        for member in &entry.members {
+            self.expand_into(member, path, addresses).map_err(|e| {
+                if e.kind == ErrorKind::Conflict {
+                    return e;
+                }
+                AppError::new(ErrorKind::UnavailableForLegalReasons)
+                    .with_code("MC-ADDR-009")
+                    .with_message(format!(
+                        "グループ'{}'のメンバー'{member}'を解決できません。",
+                        entry.name
+                    ))
+                    .with_action("グループのメンバーには登録済みの個人またはグループの名前を指定してください。")
+                    .with_source(e)
+            })?;
+        }
+        path.pop();
+        Ok(())
+    }
+}
+
+/// 名前の比較用に、空白と敬称を除去し、ひらがなをカタカナに、英字を小文字に揃える
+fn normalize_name(name: &str) -> String {
+    const HONORIFICS: [&str; 4] = ["さん", "様", "さま", "殿"];
+
+    let mut name = name.trim();
+    for honorific in HONORIFICS {
+        if let Some(stripped) = name.strip_suffix(honorific) {
+            name = stripped;
+            break;
+        }
+    }
+
+    name.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            // ひらがな（ぁ〜ゖ）をカタカナ（ァ〜ヶ）に変換する
+            'ぁ'..='ゖ' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+            _ => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// 2つの文字列の編集距離（レーベンシュタイン距離）を文字単位で計算する
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current.push(
+                (previous[j] + cost)
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
@@ -1,15 +1,87 @@
 use crate::domain::value_objects::{
     email_address::EmailAddress,
-    mail_objects::{MailBody, Subject},
+    mail_objects::{MailBody, MailPriority, Subject},
 };
+use serde::{Deserialize, Serialize};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// 返信先を指定するヘッダー名
+pub const HEADER_REPLY_TO: &str = "Reply-To";
+/// 優先度を指定するヘッダー名
+pub const HEADER_X_PRIORITY: &str = "X-Priority";
+/// 開封確認の通知先を指定するヘッダー名
+pub const HEADER_DISPOSITION_NOTIFICATION_TO: &str = "Disposition-Notification-To";
+/// メッセージの作成時に設定するため、追加ヘッダーとして指定できないヘッダー名
+pub const RESERVED_HEADERS: [&str; 9] = [
+    "Date",
+    "From",
+    "To",
+    "Cc",
+    "Subject",
+    "Message-ID",
+    "MIME-Version",
+    "Content-Type",
+    "Content-Transfer-Encoding",
+];
+
+/// 追加ヘッダーの名前と値を検証する
+///
+/// 名前はRFC 5322のfield-name（`:`を除く表示可能なASCII文字）とし、
+/// メッセージの作成時に設定するヘッダーは指定できない。値には改行を含められない
+///
+/// ## Arguments
+/// * `name` - ヘッダー名
+/// * `value` - ヘッダー値
+///
+/// ## Returns
+/// * 成功時 - `Ok(())`
+/// * 失敗時 - 名前または値が不正な場合の`Err<AppError>`
+pub fn validate_header(name: &str, value: &str) -> AppResult<()> {
+    if name.is_empty() || !name.bytes().all(|b| b.is_ascii_graphic() && b != b':') {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-MAIL-060")
+            .with_message(format!("ヘッダー名'{name}'が不正です。"))
+            .with_action("ヘッダー名には`:`と空白を除くASCII文字のみ使用してください。"));
+    }
+    if let Some(reserved) = RESERVED_HEADERS
+        .iter()
+        .find(|reserved| reserved.eq_ignore_ascii_case(name))
+    {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-MAIL-061")
+            .with_message(format!(
+                "ヘッダー'{reserved}'は追加ヘッダーとして設定できません。"
+            ))
+            .with_action("宛先・件名はテンプレートの各フィールドで指定してください。"));
+    }
+    if value.contains(['\r', '\n']) {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-MAIL-062")
+            .with_message(format!("ヘッダー'{name}'の値に改行が含まれています。"))
+            .with_action("ヘッダーの値は1行で指定してください。"));
+    }
+    Ok(())
+}
 
 /// メールドラフトを表現するエンティティ
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MailDraft {
     to: Vec<EmailAddress>,
     cc: Vec<EmailAddress>,
     subject: Subject,
     body: MailBody,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default)]
+    attachments: Vec<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    html_body: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    details: Vec<(String, String)>,
 }
 
 impl MailDraft {
@@ -29,7 +101,88 @@ impl MailDraft {
         subject: Subject,
         body: MailBody,
     ) -> Self {
-        Self { to, cc, subject, body }
+        Self {
+            to,
+            cc,
+            subject,
+            body,
+            headers: BTreeMap::new(),
+            attachments: Vec::new(),
+            html_body: None,
+            details: Vec::new(),
+        }
+    }
+
+    /// 追加ヘッダーを設定する
+    ///
+    /// 大文字・小文字を区別せずに同名のヘッダーが既に設定されている場合は上書きする
+    ///
+    /// ## Arguments
+    /// * `name` - ヘッダー名
+    /// * `value` - ヘッダー値
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<MailDraft>` (ヘッダーが設定されたインスタンス)
+    /// * 失敗時 - ヘッダーの名前または値が不正な場合の`Err<AppError>`
+    pub fn with_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> AppResult<Self> {
+        let (name, value) = (name.into(), value.into());
+        validate_header(&name, &value)?;
+        self.insert_header(name, value);
+        Ok(self)
+    }
+
+    /// 複数の追加ヘッダーをまとめて設定する
+    ///
+    /// ## Arguments
+    /// * `headers` - ヘッダー名とヘッダー値の組
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<MailDraft>` (ヘッダーが設定されたインスタンス)
+    /// * 失敗時 - いずれかのヘッダーの名前または値が不正な場合の`Err<AppError>`
+    pub fn with_headers<I, K, V>(self, headers: I) -> AppResult<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        headers
+            .into_iter()
+            .try_fold(self, |draft, (name, value)| draft.with_header(name, value))
+    }
+
+    /// 返信先（Reply-To）を設定する
+    pub fn with_reply_to(mut self, reply_to: &EmailAddress) -> Self {
+        self.insert_header(HEADER_REPLY_TO.to_string(), reply_to.as_str().to_string());
+        self
+    }
+
+    /// 優先度（X-Priority）を設定する
+    pub fn with_priority(mut self, priority: MailPriority) -> Self {
+        self.insert_header(
+            HEADER_X_PRIORITY.to_string(),
+            priority.as_header_value().to_string(),
+        );
+        self
+    }
+
+    /// 開封確認（Disposition-Notification-To）の通知先を設定する
+    pub fn with_read_receipt(mut self, notify_to: &EmailAddress) -> Self {
+        self.insert_header(
+            HEADER_DISPOSITION_NOTIFICATION_TO.to_string(),
+            notify_to.as_str().to_string(),
+        );
+        self
+    }
+
+    /// 大文字・小文字を区別せずに同名のヘッダーを置き換えて、検証済みのヘッダーを設定する
+    fn insert_header(&mut self, name: String, value: String) {
+        self.headers
+            .retain(|key, _| !key.eq_ignore_ascii_case(&name));
+        self.headers.insert(name, value);
     }
 
     /// TO宛先を取得する
@@ -52,11 +205,99 @@ impl MailDraft {
         &self.body
     }
 
+    /// 添付ファイルを追加する
+    ///
+    /// ## Arguments
+    /// * `path` - 添付ファイルのパス
+    ///
+    /// ## Returns
+    /// * 添付ファイルが追加されたMailDraftのインスタンス
+    pub fn with_attachment(mut self, path: impl Into<PathBuf>) -> Self {
+        self.attachments.push(path.into());
+        self
+    }
+
+    /// 複数の添付ファイルをまとめて追加する
+    ///
+    /// ## Arguments
+    /// * `paths` - 添付ファイルのパス
+    ///
+    /// ## Returns
+    /// * 添付ファイルが追加されたMailDraftのインスタンス
+    pub fn with_attachments<I, P>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        self.attachments.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// 添付ファイルを取得する
+    pub fn attachments(&self) -> &[PathBuf] {
+        &self.attachments
+    }
+
+    /// HTMLメール用の本文を設定する
+    ///
+    /// HTMLメールに対応したメールクライアントでは本文の代わりに使用し、
+    /// 対応していないメールクライアントでは無視して本文をそのまま使用する
+    ///
+    /// ## Arguments
+    /// * `html_body` - HTML形式の本文（`None`の場合はプレーンテキストの本文のみ使用する）
+    ///
+    /// ## Returns
+    /// * HTML形式の本文が設定されたMailDraftのインスタンス
+    pub fn with_html_body(mut self, html_body: Option<String>) -> Self {
+        self.html_body = html_body;
+        self
+    }
+
+    /// HTMLメール用の本文を取得する
+    pub fn html_body(&self) -> Option<&str> {
+        self.html_body.as_deref()
+    }
+
+    /// ドライランのプレビューに表示する補足情報を追加する
+    ///
+    /// 補足情報はメールの内容には含めず、作業時間などメール作成時に計算した値の確認に使用する
+    ///
+    /// ## Arguments
+    /// * `name` - 項目名（例: `作業時間`）
+    /// * `value` - 値（例: `09:00-18:00`）
+    ///
+    /// ## Returns
+    /// * 補足情報が追加されたMailDraftのインスタンス
+    pub fn with_detail(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.details.push((name.into(), value.into()));
+        self
+    }
+
+    /// ドライランのプレビューに表示する補足情報を追加した順に取得する
+    pub fn details(&self) -> &[(String, String)] {
+        &self.details
+    }
+
+    /// 追加ヘッダーを取得する
+    pub fn headers(&self) -> &BTreeMap<String, String> {
+        &self.headers
+    }
+
+    /// 指定した名前の追加ヘッダーの値を取得する
+    ///
+    /// ヘッダー名の大文字・小文字は区別しない
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
     /// TO宛先をカンマ区切りの文字列として取得する
     pub fn to_addresses_as_string(&self) -> String {
         self.to
             .iter()
-            .map(|addr| addr.as_str())
+            .map(|addr| addr.to_mailbox_string())
             .collect::<Vec<_>>()
             .join(",")
     }
@@ -65,8 +306,58 @@ impl MailDraft {
     pub fn cc_addresses_as_string(&self) -> String {
         self.cc
             .iter()
-            .map(|addr| addr.as_str())
+            .map(|addr| addr.to_mailbox_string())
             .collect::<Vec<_>>()
             .join(",")
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_draft() -> MailDraft {
+        MailDraft::new(
+            vec![EmailAddress::parse("to@example.com").unwrap()],
+            vec![],
+            Subject::new("件名").unwrap(),
+            MailBody::new("本文"),
+        )
+    }
+
+    #[test]
+    fn test_with_header_replaces_ignoring_case() {
+        let draft = sample_draft()
+            .with_header("X-Foo", "1")
+            .unwrap()
+            .with_header("x-foo", "2")
+            .unwrap();
+
+        assert_eq!(draft.headers().len(), 1);
+        assert_eq!(draft.header("X-FOO"), Some("2"));
+    }
+
+    #[test]
+    fn test_reject_invalid_header_name() {
+        for name in ["", "X Foo", "X-Foo:", "X-ヘッダー"] {
+            let error = sample_draft().with_header(name, "1").unwrap_err();
+            assert_eq!(error.code, Some("MC-MAIL-060"), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_reject_reserved_header() {
+        for name in ["From", "date", "MIME-Version"] {
+            let error = sample_draft().with_header(name, "x").unwrap_err();
+            assert_eq!(error.code, Some("MC-MAIL-061"), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_reject_line_break_in_value() {
+        for value in ["1\r\nBcc: evil@example.com", "1\nbody", "1\r"] {
+            let error = sample_draft().with_header("X-Foo", value).unwrap_err();
+            assert_eq!(error.code, Some("MC-MAIL-062"));
+        }
+    }
+}
@@ -1,2 +1,4 @@
+pub mod address_book;
 pub mod mail_draft;
-pub mod start_time_map;
\ No newline at end of file
+pub mod outbox_item;
+pub mod work_record;
\ No newline at end of file
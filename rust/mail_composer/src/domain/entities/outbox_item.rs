@@ -0,0 +1,35 @@
+use crate::domain::entities::mail_draft::MailDraft;
+
+/// 送信待ちキューに保存されたメールドラフトを表現するエンティティ
+#[derive(Debug, Clone)]
+pub struct OutboxItem {
+    id: String,
+    draft: MailDraft,
+}
+
+impl OutboxItem {
+    /// 新しいOutboxItemを作成する
+    ///
+    /// ## Arguments
+    /// * `id` - キュー内で一意な識別子
+    /// * `draft` - 保存されたメールドラフト
+    ///
+    /// ## Returns
+    /// * OutboxItemのインスタンス
+    pub fn new(id: impl Into<String>, draft: MailDraft) -> Self {
+        Self {
+            id: id.into(),
+            draft,
+        }
+    }
+
+    /// 識別子を取得する
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// メールドラフトを取得する
+    pub fn draft(&self) -> &MailDraft {
+        &self.draft
+    }
+}
@@ -0,0 +1,459 @@
+use crate::domain::value_objects::{
+    mail_objects::{WorkTime, WorkTimeRange},
+    time_zone::WorkTimeZone,
+};
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::collections::BTreeMap;
+
+/// 出張の日の作業記録に追記する備考の接頭辞（例: `出張（大阪支社）`）
+pub const BUSINESS_TRIP_NOTE_PREFIX: &str = "出張（";
+
+/// 1日分の作業記録を表現するエンティティ
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "StoredWorkRecord")]
+pub struct WorkRecord {
+    /// 作業開始時刻
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<WorkTime>,
+    /// 作業終了時刻
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<WorkTime>,
+    /// 作業開始日時（記録時のUTCオフセット付き）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<FixedOffset>>,
+    /// 作業終了日時（記録時のUTCオフセット付き）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    /// 休憩
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub breaks: Vec<WorkBreak>,
+    /// 作業時間（分）。開始時刻と終了時刻が揃っている場合のみ、休憩時間を除いて記録する
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<i64>,
+    /// 備考
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// 休憩1回分を表現する値
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkBreak {
+    /// 休憩開始時刻
+    pub start: WorkTime,
+    /// 休憩終了時刻（休憩中の場合は`None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<WorkTime>,
+}
+
+impl WorkBreak {
+    /// 休憩時間（分）を取得する（休憩中の場合は`None`）
+    pub fn minutes(&self) -> Option<i64> {
+        Some(WorkTimeRange::new(self.start.clone(), self.end.clone()?).duration_minutes())
+    }
+}
+
+/// 一時停止と再開で区切られた作業区間1つ分を表現する値
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkSession {
+    /// 区間の開始時刻
+    pub start: WorkTime,
+    /// 区間の終了時刻（作業中の場合は`None`）
+    pub end: Option<WorkTime>,
+}
+
+impl WorkSession {
+    /// 区間を`開始-終了`形式で表す（作業中の場合は`開始-`）
+    pub fn label(&self) -> String {
+        let end = self.end.as_ref().map_or("", WorkTime::as_str);
+        format!("{}-{end}", self.start.as_str())
+    }
+}
+
+impl WorkRecord {
+    /// 開始時刻を設定し、作業時間を再計算する（日時の記録は破棄する）
+    pub fn set_start(&mut self, start: WorkTime) {
+        self.start = Some(start);
+        self.started_at = None;
+        self.update_duration();
+    }
+
+    /// 終了時刻を設定し、作業時間を再計算する（日時の記録は破棄する）
+    ///
+    /// 一時停止中の場合は、終了時刻で休憩を終える
+    pub fn set_end(&mut self, end: WorkTime) {
+        self.close_open_break(&end);
+        self.end = Some(end);
+        self.ended_at = None;
+        self.update_duration();
+    }
+
+    /// 開始日時を記録し、開始時刻と作業時間を更新する
+    pub fn set_started_at(&mut self, started_at: DateTime<FixedOffset>) {
+        self.start = Some(WorkTime::from_time(started_at.time()));
+        self.started_at = Some(started_at);
+        self.update_duration();
+    }
+
+    /// 終了日時を記録し、終了時刻と作業時間を更新する
+    ///
+    /// 一時停止中の場合は、終了時刻で休憩を終える
+    pub fn set_ended_at(&mut self, ended_at: DateTime<FixedOffset>) {
+        let end = WorkTime::from_time(ended_at.time());
+        self.close_open_break(&end);
+        self.end = Some(end);
+        self.ended_at = Some(ended_at);
+        self.update_duration();
+    }
+
+    /// 備考を追記する（既存の備考がある場合は`, `で区切って末尾に追加する）
+    ///
+    /// ## Arguments
+    /// * `note` - 追記する備考
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::entities::work_record::WorkRecord;
+    /// let mut record = WorkRecord::default();
+    /// record.add_note("遅刻連絡");
+    /// record.add_note("客先訪問");
+    /// assert_eq!(record.notes.as_deref(), Some("遅刻連絡, 客先訪問"));
+    /// ```
+    pub fn add_note(&mut self, note: &str) {
+        let note = note.trim();
+        if note.is_empty() {
+            return;
+        }
+        self.notes = Some(match self.notes.take() {
+            Some(notes) if !notes.is_empty() => format!("{notes}, {note}"),
+            _ => note.to_string(),
+        });
+    }
+
+    /// 備考を置き換える（空の場合は備考を削除する）
+    ///
+    /// ## Arguments
+    /// * `note` - 新しい備考
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::entities::work_record::WorkRecord;
+    /// let mut record = WorkRecord::default();
+    /// record.add_note("遅刻連絡");
+    /// record.set_note("打刻漏れを修正");
+    /// assert_eq!(record.notes.as_deref(), Some("打刻漏れを修正"));
+    /// record.set_note("");
+    /// assert_eq!(record.notes, None);
+    /// ```
+    pub fn set_note(&mut self, note: &str) {
+        let note = note.trim();
+        self.notes = (!note.is_empty()).then(|| note.to_string());
+    }
+
+    /// 出張の日として記録されているか判定する
+    ///
+    /// ## Returns
+    /// * 備考に[`BUSINESS_TRIP_NOTE_PREFIX`]で始まる項目がある場合は`true`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::entities::work_record::WorkRecord;
+    /// let mut record = WorkRecord::default();
+    /// record.add_note("遅刻連絡");
+    /// assert!(!record.is_business_trip());
+    /// record.add_note("出張（大阪支社）");
+    /// assert!(record.is_business_trip());
+    /// ```
+    pub fn is_business_trip(&self) -> bool {
+        self.notes.as_deref().is_some_and(|notes| {
+            notes
+                .split(", ")
+                .any(|note| note.starts_with(BUSINESS_TRIP_NOTE_PREFIX))
+        })
+    }
+
+    /// 休憩を追加し、作業時間を再計算する
+    ///
+    /// ## Arguments
+    /// * `start` - 休憩開始時刻
+    /// * `end` - 休憩終了時刻（休憩中の場合は`None`）
+    pub fn add_break(&mut self, start: WorkTime, end: Option<WorkTime>) {
+        self.breaks.push(WorkBreak { start, end });
+        self.update_duration();
+    }
+
+    /// 作業を一時停止する（終了時刻のない休憩を追加する）
+    ///
+    /// ## Arguments
+    /// * `at` - 一時停止した時刻
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 作業中でない（未開始・終了済み・一時停止中）場合の`Err<AppError>`
+    pub fn pause(&mut self, at: WorkTime) -> AppResult<()> {
+        let problem = if self.start.is_none() {
+            Some("作業開始が記録されていないため、一時停止できません。")
+        } else if self.end.is_some() {
+            Some("作業終了が記録されているため、一時停止できません。")
+        } else if self.is_paused() {
+            Some("既に一時停止しています。")
+        } else {
+            None
+        };
+        if let Some(message) = problem {
+            return Err(AppError::new(ErrorKind::Conflict)
+                .with_code("MC-WORK-009")
+                .with_message(message)
+                .with_action("作業中であることを確認してから一時停止してください。"));
+        }
+        self.add_break(at, None);
+        Ok(())
+    }
+
+    /// 一時停止した作業を再開する（終了時刻のない休憩を終える）
+    ///
+    /// ## Arguments
+    /// * `at` - 再開した時刻
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 一時停止していない場合の`Err<AppError>`
+    pub fn resume(&mut self, at: WorkTime) -> AppResult<()> {
+        if !self.is_paused() {
+            return Err(AppError::new(ErrorKind::Conflict)
+                .with_code("MC-WORK-010")
+                .with_message("一時停止していないため、再開できません。")
+                .with_action("先に作業の一時停止を記録してください。"));
+        }
+        self.close_open_break(&at);
+        self.update_duration();
+        Ok(())
+    }
+
+    /// 一時停止中（終了時刻のない休憩がある）かどうか
+    pub fn is_paused(&self) -> bool {
+        self.breaks
+            .iter()
+            .any(|work_break| work_break.end.is_none())
+    }
+
+    /// 開始時刻から終了時刻までを、休憩で区切った作業区間の一覧を取得する
+    ///
+    /// ## Returns
+    /// * 作業区間の一覧（開始時刻が記録されていない場合は空）
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::{entities::work_record::WorkRecord, value_objects::mail_objects::WorkTime};
+    /// let time = |value: &str| WorkTime::new(value).unwrap();
+    /// let mut record = WorkRecord::default();
+    /// record.set_start(time("09:00"));
+    /// record.pause(time("12:00")).unwrap();
+    /// record.resume(time("13:00")).unwrap();
+    /// record.set_end(time("18:00"));
+    /// let labels: Vec<String> = record.sessions().iter().map(|s| s.label()).collect();
+    /// assert_eq!(labels, ["09:00-12:00", "13:00-18:00"]);
+    /// assert_eq!(record.duration(), Some(480));
+    /// ```
+    pub fn sessions(&self) -> Vec<WorkSession> {
+        let Some(mut start) = self.start.clone() else {
+            return Vec::new();
+        };
+        let mut sessions = Vec::new();
+        for work_break in &self.breaks {
+            sessions.push(WorkSession {
+                start,
+                end: Some(work_break.start.clone()),
+            });
+            match &work_break.end {
+                Some(end) => start = end.clone(),
+                None => return sessions,
+            }
+        }
+        sessions.push(WorkSession {
+            start,
+            end: self.end.clone(),
+        });
+        sessions
+    }
+
+    /// 終了した休憩の合計時間（分）を取得する
+    pub fn break_minutes(&self) -> i64 {
+        self.breaks.iter().filter_map(WorkBreak::minutes).sum()
+    }
+
+    /// 記録された日時を指定したタイムゾーンの時刻で表した作業記録を取得する
+    ///
+    /// 日時が記録されていない（時刻のみの）記録はそのまま返す
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 表示に使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * 開始時刻と終了時刻を変換した作業記録
+    pub fn localized(&self, time_zone: &WorkTimeZone) -> Self {
+        let mut record = self.clone();
+        if let Some(started_at) = self.started_at {
+            record.start = Some(WorkTime::from_time(time_zone.localize(started_at).time()));
+        }
+        if let Some(ended_at) = self.ended_at {
+            record.end = Some(WorkTime::from_time(time_zone.localize(ended_at).time()));
+        }
+        record
+    }
+
+    /// 作業時間（分）を取得する（記録されていない場合は開始時刻と終了時刻から計算する）
+    pub fn duration(&self) -> Option<i64> {
+        self.duration_minutes.or_else(|| self.computed_duration())
+    }
+
+    /// 開始時刻と終了時刻が揃っている場合、作業時間の範囲を取得する
+    pub fn range(&self) -> Option<WorkTimeRange> {
+        Some(WorkTimeRange::new(self.start.clone()?, self.end.clone()?))
+    }
+
+    /// 開始時刻と終了時刻から休憩時間を除いた作業時間を計算する
+    ///
+    /// 開始日時と終了日時が両方記録されている場合は、UTCオフセットの違いを考慮してその差を使用する
+    fn computed_duration(&self) -> Option<i64> {
+        let minutes = match (self.started_at, self.ended_at) {
+            (Some(started_at), Some(ended_at)) if started_at <= ended_at => {
+                (ended_at - started_at).num_minutes()
+            }
+            _ => self.range()?.duration_minutes(),
+        };
+        Some((minutes - self.break_minutes()).max(0))
+    }
+
+    /// 終了時刻のない休憩を指定した時刻で終える
+    fn close_open_break(&mut self, at: &WorkTime) {
+        for work_break in self.breaks.iter_mut().filter(|b| b.end.is_none()) {
+            work_break.end = Some(at.clone());
+        }
+    }
+
+    /// 作業時間を再計算する
+    fn update_duration(&mut self) {
+        self.duration_minutes = self.computed_duration();
+    }
+}
+
+/// 保存されている作業記録の形式
+///
+/// 以前のバージョンは日付ごとに開始時刻の文字列のみを保存していたため、両方の形式を読み込む
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredWorkRecord {
+    StartOnly(WorkTime),
+    Record {
+        #[serde(default)]
+        start: Option<WorkTime>,
+        #[serde(default)]
+        end: Option<WorkTime>,
+        #[serde(default)]
+        started_at: Option<DateTime<FixedOffset>>,
+        #[serde(default)]
+        ended_at: Option<DateTime<FixedOffset>>,
+        #[serde(default)]
+        breaks: Vec<WorkBreak>,
+        #[serde(default)]
+        duration_minutes: Option<i64>,
+        #[serde(default)]
+        notes: Option<String>,
+    },
+}
+
+impl From<StoredWorkRecord> for WorkRecord {
+    fn from(stored: StoredWorkRecord) -> Self {
+        match stored {
+            StoredWorkRecord::StartOnly(start) => Self {
+                start: Some(start),
+                ..Self::default()
+            },
+            StoredWorkRecord::Record {
+                start,
+                end,
+                started_at,
+                ended_at,
+                breaks,
+                duration_minutes,
+                notes,
+            } => Self {
+                start,
+                end,
+                started_at,
+                ended_at,
+                breaks,
+                duration_minutes,
+                notes,
+            },
+        }
+    }
+}
+
+/// 日付（YYYY-MM-DD）ごとの作業記録を管理するエンティティ
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkRecordMap(pub BTreeMap<String, WorkRecord>);
+
+impl WorkRecordMap {
+    /// 新しいWorkRecordMapを作成する
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 指定された日付の開始日時を記録する
+    pub fn set_started_at(&mut self, key: String, at: DateTime<FixedOffset>) -> &WorkRecord {
+        let record = self.0.entry(key).or_default();
+        record.set_started_at(at);
+        record
+    }
+
+    /// 指定された日付の終了日時を記録する
+    pub fn set_ended_at(&mut self, key: String, at: DateTime<FixedOffset>) -> &WorkRecord {
+        let record = self.0.entry(key).or_default();
+        record.set_ended_at(at);
+        record
+    }
+
+    /// 指定された日付の作業を一時停止する
+    pub fn pause(&mut self, key: String, at: DateTime<FixedOffset>) -> AppResult<&WorkRecord> {
+        let record = self.0.entry(key).or_default();
+        record.pause(WorkTime::from_time(at.time()))?;
+        Ok(record)
+    }
+
+    /// 指定された日付の一時停止した作業を再開する
+    pub fn resume(&mut self, key: String, at: DateTime<FixedOffset>) -> AppResult<&WorkRecord> {
+        let record = self.0.entry(key).or_default();
+        record.resume(WorkTime::from_time(at.time()))?;
+        Ok(record)
+    }
+
+    /// 指定された日付の作業記録に備考を追記する
+    pub fn add_note(&mut self, key: String, note: &str) -> &WorkRecord {
+        let record = self.0.entry(key).or_default();
+        record.add_note(note);
+        record
+    }
+
+    /// 指定された日付の作業記録の備考を置き換える
+    pub fn set_note(&mut self, key: String, note: &str) -> &WorkRecord {
+        let record = self.0.entry(key).or_default();
+        record.set_note(note);
+        record
+    }
+
+    /// 指定された日付の作業記録を取得する
+    pub fn get(&self, key: &str) -> Option<&WorkRecord> {
+        self.0.get(key)
+    }
+
+    /// 全ての作業記録を取得する
+    pub fn entries(&self) -> &BTreeMap<String, WorkRecord> {
+        &self.0
+    }
+}
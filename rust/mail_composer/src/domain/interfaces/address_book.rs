@@ -1,5 +1,12 @@
-use crate::domain::value_objects::email_address::EmailAddress;
-use share::error::app_error::AppResult;
+use crate::domain::{
+    entities::address_book::{AddressBook, AddressBookEntry},
+    value_objects::{address_query::AddressQuery, email_address::EmailAddress},
+};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::sync::Arc;
 
 /// アドレスブック操作のためのポート（セカンダリポート）
 pub trait AddressBookPort {
@@ -13,8 +20,78 @@ pub trait AddressBookPort {
     /// * 失敗時 - [`Err<AppError>`]
     fn resolve(&self, key_name: &str) -> AppResult<EmailAddress>;
 
+    /// AddressBookから名前に対応するメールアドレスを、グループの場合はメンバー全員に展開して取得する
+    ///
+    /// グループをサポートしないアダプターでは[`AddressBookPort::resolve`]の結果を返す
+    ///
+    /// ## Arguments
+    /// * `key_name` - 個人またはグループの名前(AddressBookのキー)
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<Vec<EmailAddress>>`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        self.resolve(key_name).map(|address| vec![address])
+    }
+
+    /// 検索条件に一致するエントリを取得する
+    ///
+    /// デフォルトでは全てのエントリを取得してから絞り込む
+    ///
+    /// ## Arguments
+    /// * `query` - 検索条件
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<Vec<AddressBookEntry>>`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        let book = AddressBook::from_entries(self.list_entries()?)?;
+        Ok(book.search(query)?.into_iter().cloned().collect())
+    }
+
+    /// メールアドレスに対応する名前を取得する
+    ///
+    /// デフォルトでは全てのエントリを走査する。一覧を取得できないアダプターでは`None`を返す
+    ///
+    /// ## Arguments
+    /// * `address` - 検索するメールアドレス
+    ///
+    /// ## Returns
+    /// * 成功時 - 登録されている場合は[`Ok<Some<String>>`]、登録されていない場合は[`Ok<None>`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        let Ok(entries) = self.list_entries() else {
+            return Ok(None);
+        };
+        Ok(entries
+            .into_iter()
+            .find(|entry| {
+                entry
+                    .address
+                    .as_deref()
+                    .is_some_and(|a| a.trim().eq_ignore_ascii_case(address.trim()))
+            })
+            .map(|entry| entry.name))
+    }
+
+    /// AddressBookの全てのエントリを取得する
+    ///
+    /// 一覧を取得できないアダプター（ディレクトリサービスなど）ではエラーを返す
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<Vec<AddressBookEntry>>`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-ADDR-010")
+            .with_message("このアドレスブックはエントリの一覧取得に対応していません。")
+            .with_action("ファイル形式のアドレスブックを指定してください。"))
+    }
+
     /// AddressBookから複数のメールアドレスを取得する
     ///
+    /// グループはメンバー全員に展開し、重複したメールアドレスは最初の1件のみ残す
+    ///
     /// ## Arguments
     /// * `key_names` - 取得対象のメールアドレスに対応する名前(AddressBookのキー)のスライス
     ///
@@ -22,9 +99,82 @@ pub trait AddressBookPort {
     /// * 成功時 - [`Ok<Vec<EmailAddress>>`]
     /// * 失敗時 - [`Err<AppError>`]
     fn resolve_many(&self, key_names: &[&str]) -> AppResult<Vec<EmailAddress>> {
-        key_names
-            .iter()
-            .map(|key_name| self.resolve(key_name))
-            .collect()
+        let mut addresses: Vec<EmailAddress> = Vec::new();
+        for key_name in key_names {
+            for address in self.expand(key_name)? {
+                if !addresses.contains(&address) {
+                    addresses.push(address);
+                }
+            }
+        }
+        Ok(addresses)
+    }
+}
+
+/// 常駐モードで複数の処理から同じアドレスブックを共有するための実装
+impl<T: AddressBookPort + ?Sized> AddressBookPort for Arc<T> {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        (**self).resolve(key_name)
     }
+
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        (**self).expand(key_name)
+    }
+
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        (**self).search(query)
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        (**self).reverse_resolve(address)
+    }
+
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        (**self).list_entries()
+    }
+
+    fn resolve_many(&self, key_names: &[&str]) -> AppResult<Vec<EmailAddress>> {
+        (**self).resolve_many(key_names)
+    }
+}
+
+/// アドレスブックの編集と保存のためのポート（セカンダリポート）
+pub trait AddressBookWritePort: AddressBookPort {
+    /// エントリを追加する
+    ///
+    /// ## Arguments
+    /// * `entry` - 追加するエントリ
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn add_entry(&mut self, entry: AddressBookEntry) -> AppResult<()>;
+
+    /// 名前に対応するエントリを置き換える
+    ///
+    /// ## Arguments
+    /// * `key_name` - 置き換え対象のエントリの名前
+    /// * `entry` - 新しいエントリ
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn update_entry(&mut self, key_name: &str, entry: AddressBookEntry) -> AppResult<()>;
+
+    /// 名前に対応するエントリを削除する
+    ///
+    /// ## Arguments
+    /// * `key_name` - 削除対象のエントリの名前
+    ///
+    /// ## Returns
+    /// * 成功時 - 削除したエントリ
+    /// * 失敗時 - [`Err<AppError>`]
+    fn remove_entry(&mut self, key_name: &str) -> AppResult<AddressBookEntry>;
+
+    /// 編集内容を永続化する
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn save(&self) -> AppResult<()>;
 }
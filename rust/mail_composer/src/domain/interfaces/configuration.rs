@@ -1,5 +1,7 @@
+use crate::domain::value_objects::{
+    app_configuration::AppConfiguration, effective_configuration::EffectiveConfiguration,
+};
 use share::error::app_error::AppResult;
-use crate::domain::value_objects::app_configuration::AppConfiguration;
 
 /// 設定読み込みのためのポート（セカンダリポート）
 pub trait ConfigurationPort {
@@ -10,10 +12,82 @@ pub trait ConfigurationPort {
     /// * 失敗時 - [`Err<AppError>`]
     fn load_configuration(&self) -> AppResult<AppConfiguration>;
 
+    /// 検証を行わずにアプリケーション設定を読み込む
+    ///
+    /// 他の設定ソースで値を補完してから検証する場合に使用する。
+    /// デフォルトでは[`ConfigurationPort::load_configuration`]の結果を返す
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<AppConfiguration>`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        self.load_configuration()
+    }
+
     /// 設定ファイルが存在するかチェックする
     ///
     /// ## Returns
     /// * ファイルが存在する場合 - `true`
     /// * ファイルが存在しない場合 - `false`
     fn configuration_exists(&self) -> bool;
+
+    /// 検証を行わずにアプリケーション設定を読み込み、各設定項目の取得元と共に返す
+    ///
+    /// 設定値を上書きするデコレーターは、上書きした項目の取得元を置き換える。
+    /// デフォルトでは設定ファイルがなければ既定値を、あれば設定ファイルに記述された項目を取得元とする
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<EffectiveConfiguration>`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn load_effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        if !self.configuration_exists() {
+            return Ok(EffectiveConfiguration::defaults());
+        }
+        self.load_unvalidated_configuration()
+            .map(EffectiveConfiguration::from_file)
+    }
+}
+
+impl<T: ConfigurationPort + ?Sized> ConfigurationPort for &T {
+    fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        (**self).load_configuration()
+    }
+
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        (**self).load_unvalidated_configuration()
+    }
+
+    fn configuration_exists(&self) -> bool {
+        (**self).configuration_exists()
+    }
+
+    fn load_effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        (**self).load_effective_configuration()
+    }
+}
+
+/// 設定保存のためのポート（セカンダリポート）
+pub trait ConfigurationWritePort {
+    /// アプリケーション設定を保存する
+    ///
+    /// ## Arguments
+    /// * `config` - 保存するアプリケーション設定
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn save_configuration(&self, config: &AppConfiguration) -> AppResult<()>;
+
+    /// 設定ファイルの1項目のみを文字列値で書き換える
+    ///
+    /// 他の項目は`${VAR}`形式の参照を含め、設定ファイルの記述のまま残す
+    ///
+    /// ## Arguments
+    /// * `field` - 設定項目名
+    /// * `value` - 設定する値
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn save_field(&self, field: &str, value: &str) -> AppResult<()>;
 }
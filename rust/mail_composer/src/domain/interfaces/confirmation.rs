@@ -0,0 +1,21 @@
+use crate::domain::entities::mail_draft::MailDraft;
+use share::error::app_error::AppResult;
+
+/// 送信前の確認のためのポート（セカンダリポート）
+pub trait ConfirmationPort {
+    /// メールドラフトを送信してよいか確認する
+    ///
+    /// ## Arguments
+    /// * `draft` - 送信対象のメールドラフト
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<bool>` (送信してよい場合は`true`)
+    /// * 失敗時 - `Err<AppError>`
+    fn confirm(&self, draft: &MailDraft) -> AppResult<bool>;
+}
+
+impl<T: ConfirmationPort + ?Sized> ConfirmationPort for Box<T> {
+    fn confirm(&self, draft: &MailDraft) -> AppResult<bool> {
+        (**self).confirm(draft)
+    }
+}
@@ -0,0 +1,15 @@
+use chrono::NaiveDate;
+use share::error::app_error::AppResult;
+
+/// 日ごとの作業メモを取得するためのポート（セカンダリポート）
+pub trait DailyNotesPort {
+    /// 指定日の作業メモを取得する
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Option<String>>` (作業メモ、メモがない場合や空の場合は`None`)
+    /// * 失敗時 - `Err<AppError>`
+    fn load_note(&self, date: NaiveDate) -> AppResult<Option<String>>;
+}
@@ -0,0 +1,25 @@
+use share::error::app_error::AppResult;
+use std::path::Path;
+
+/// 実行環境（ディレクトリや実行ファイル）を確認するためのポート（セカンダリポート）
+pub trait EnvironmentProbePort {
+    /// ディレクトリに書き込めるかを確認する
+    ///
+    /// ## Arguments
+    /// * `path` - 確認するディレクトリのパス（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - 対処法を含む[`Err<AppError>`]
+    fn check_writable_dir(&self, path: &Path) -> AppResult<()>;
+
+    /// 実行ファイルが存在し、実行できるかを確認する
+    ///
+    /// ## Arguments
+    /// * `path` - 実行ファイルのパス、または`PATH`から探すコマンド名
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - 対処法を含む[`Err<AppError>`]
+    fn check_executable(&self, path: &Path) -> AppResult<()>;
+}
@@ -0,0 +1,80 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use share::error::app_error::AppResult;
+
+/// 祝日を判定するためのポート（セカンダリポート）
+pub trait HolidayCalendarPort {
+    /// 指定日の祝日名を取得する
+    ///
+    /// ## Arguments
+    /// * `date` - 判定する日付
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Option<String>>` (祝日の場合は祝日名、祝日でない場合は`None`)
+    /// * 失敗時 - `Err<AppError>`
+    fn holiday_name(&self, date: NaiveDate) -> AppResult<Option<String>>;
+
+    /// 指定日が祝日かどうかを判定する
+    ///
+    /// ## Arguments
+    /// * `date` - 判定する日付
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<bool>` (祝日の場合は`true`)
+    /// * 失敗時 - `Err<AppError>`
+    fn is_holiday(&self, date: NaiveDate) -> AppResult<bool> {
+        Ok(self.holiday_name(date)?.is_some())
+    }
+
+    /// 指定期間の勤務日数（土日と祝日を除いた日数）を数える
+    ///
+    /// ## Arguments
+    /// * `from` - 期間の初日
+    /// * `to` - 期間の最終日
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<usize>` (勤務日数)
+    /// * 失敗時 - `Err<AppError>`
+    fn working_days(&self, from: NaiveDate, to: NaiveDate) -> AppResult<usize> {
+        let mut count = 0;
+        for date in from.iter_days().take_while(|date| *date <= to) {
+            if !is_weekend(date) && !self.is_holiday(date)? {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// 土曜日または日曜日かどうかを判定する
+///
+/// ## Arguments
+/// * `date` - 判定する日付
+///
+/// ## Returns
+/// * 土曜日または日曜日の場合は`true`
+///
+/// ## Examples
+/// ```rust
+/// use chrono::NaiveDate;
+/// use mail_composer::domain::interfaces::holiday_calendar::is_weekend;
+/// assert!(is_weekend(NaiveDate::from_ymd_opt(2025, 4, 5).unwrap()));
+/// assert!(!is_weekend(NaiveDate::from_ymd_opt(2025, 4, 7).unwrap()));
+/// ```
+pub fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// 土日のみを除いた指定期間の平日の日数を数える
+///
+/// ## Arguments
+/// * `from` - 期間の初日
+/// * `to` - 期間の最終日
+///
+/// ## Returns
+/// * 平日の日数
+pub fn weekdays_between(from: NaiveDate, to: NaiveDate) -> usize {
+    from.iter_days()
+        .take_while(|date| *date <= to)
+        .filter(|date| !is_weekend(*date))
+        .count()
+}
@@ -13,4 +13,10 @@ pub trait MailClientPort {
     /// * 成功時 - `Ok(())`
     /// * 失敗時 - `Err<AppError>`
     fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()>;
-}
\ No newline at end of file
+}
+
+impl<T: MailClientPort + ?Sized> MailClientPort for Box<T> {
+    fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        (**self).compose_mail(draft, is_dry_run)
+    }
+}
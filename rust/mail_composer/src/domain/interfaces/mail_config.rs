@@ -3,4 +3,36 @@ use share::error::app_error::AppError;
 
 pub trait MailConfigPort {
     fn load_mail_config(&self) -> Result<MailConfig, AppError>;
-}
\ No newline at end of file
+}
+
+impl<T: MailConfigPort + ?Sized> MailConfigPort for &T {
+    fn load_mail_config(&self) -> Result<MailConfig, AppError> {
+        (**self).load_mail_config()
+    }
+}
+
+impl<T: MailConfigPort + ?Sized> MailConfigPort for Box<T> {
+    fn load_mail_config(&self) -> Result<MailConfig, AppError> {
+        (**self).load_mail_config()
+    }
+}
+
+/// メールテンプレート保存のためのポート（セカンダリポート）
+pub trait MailConfigWritePort {
+    /// メールテンプレートを保存する
+    ///
+    /// ## Arguments
+    /// * `mail_config` - 保存するメールテンプレート
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn save_mail_config(&self, mail_config: &MailConfig) -> Result<(), AppError>;
+
+    /// 保存先にメールテンプレートが既に存在するかチェックする
+    ///
+    /// ## Returns
+    /// * ファイルが存在する場合 - `true`
+    /// * ファイルが存在しない場合 - `false`
+    fn mail_config_exists(&self) -> bool;
+}
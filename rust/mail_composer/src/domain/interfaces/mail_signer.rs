@@ -0,0 +1,23 @@
+use share::error::app_error::AppResult;
+
+/// メール署名のためのポート（セカンダリポート）
+///
+/// 署名はメッセージとして出力する直前のMIMEエンティティに対して行い、
+/// 署名対象のエンティティと署名を`multipart/signed`のエンティティにまとめる
+pub trait MailSignerPort {
+    /// MIMEエンティティに署名する
+    ///
+    /// ## Arguments
+    /// * `entity` - 署名対象のMIMEエンティティ（`Content-Type`などのヘッダーと本文、CRLF改行）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<String>` (`multipart/signed`のMIMEエンティティ、CRLF改行)
+    /// * 失敗時 - `Err<AppError>`
+    fn sign(&self, entity: &str) -> AppResult<String>;
+}
+
+impl<T: MailSignerPort + ?Sized> MailSignerPort for Box<T> {
+    fn sign(&self, entity: &str) -> AppResult<String> {
+        (**self).sign(entity)
+    }
+}
@@ -1,5 +1,18 @@
 pub mod address_book;
 pub mod configuration;
+pub mod confirmation;
+pub mod daily_notes;
+pub mod environment_probe;
+pub mod holiday_calendar;
 pub mod mail_client;
 pub mod mail_config;
+pub mod mail_signer;
+pub mod notification;
+pub mod outbox;
+pub mod prompt;
+pub mod secret_cipher;
+pub mod thunderbird_locator;
+pub mod time_entry_source;
 pub mod work_time;
+pub mod work_time_archive;
+pub mod work_time_export;
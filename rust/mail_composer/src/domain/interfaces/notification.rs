@@ -0,0 +1,15 @@
+use share::error::app_error::AppResult;
+
+/// 利用者への通知のためのポート（セカンダリポート）
+pub trait NotificationPort {
+    /// 通知を表示する
+    ///
+    /// ## Arguments
+    /// * `title` - 通知のタイトル
+    /// * `message` - 通知の本文
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 通知を表示できなかった場合の`Err<AppError>`
+    fn notify(&self, title: &str, message: &str) -> AppResult<()>;
+}
@@ -0,0 +1,32 @@
+use crate::domain::entities::{mail_draft::MailDraft, outbox_item::OutboxItem};
+use share::error::app_error::AppResult;
+
+/// 送信待ちキュー操作のためのポート（セカンダリポート）
+pub trait OutboxPort {
+    /// メールドラフトを送信待ちキューに追加する
+    ///
+    /// ## Arguments
+    /// * `draft` - キューに追加するメールドラフト
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<String>` (追加されたアイテムの識別子)
+    /// * 失敗時 - `Err<AppError>`
+    fn enqueue(&self, draft: &MailDraft) -> AppResult<String>;
+
+    /// 送信待ちキューのアイテムを古い順に取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<OutboxItem>>`
+    /// * 失敗時 - `Err<AppError>`
+    fn list(&self) -> AppResult<Vec<OutboxItem>>;
+
+    /// 送信待ちキューからアイテムを削除する
+    ///
+    /// ## Arguments
+    /// * `id` - 削除対象のアイテムの識別子
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - `Err<AppError>`
+    fn remove(&self, id: &str) -> AppResult<()>;
+}
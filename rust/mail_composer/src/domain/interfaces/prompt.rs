@@ -0,0 +1,15 @@
+use share::error::app_error::AppResult;
+
+/// 利用者に値の入力を求めるためのポート（セカンダリポート）
+pub trait PromptPort {
+    /// 質問を表示し、入力された値を取得する
+    ///
+    /// ## Arguments
+    /// * `question` - 表示する質問
+    /// * `default` - 何も入力されなかった場合に使用する値
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<String>`]（前後の空白を除いた入力値、または既定値）
+    /// * 失敗時 - [`Err<AppError>`]
+    fn ask(&self, question: &str, default: Option<&str>) -> AppResult<String>;
+}
@@ -0,0 +1,24 @@
+use share::error::app_error::AppResult;
+
+/// 設定ファイルに保存する機密値を暗号化・復号するためのポート（セカンダリポート）
+pub trait SecretCipherPort {
+    /// 機密値を暗号化する
+    ///
+    /// ## Arguments
+    /// * `plaintext` - 暗号化する値
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<String>`]（Base64形式の暗号文）
+    /// * 失敗時 - [`Err<AppError>`]
+    fn encrypt(&self, plaintext: &str) -> AppResult<String>;
+
+    /// 暗号化された機密値を復号する
+    ///
+    /// ## Arguments
+    /// * `ciphertext` - [`SecretCipherPort::encrypt`]で作成したBase64形式の暗号文
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<String>`]（復号した値）
+    /// * 失敗時 - [`Err<AppError>`]
+    fn decrypt(&self, ciphertext: &str) -> AppResult<String>;
+}
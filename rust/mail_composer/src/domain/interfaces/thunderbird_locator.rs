@@ -0,0 +1,10 @@
+use std::path::PathBuf;
+
+/// インストール済みのThunderbirdを探すためのポート（セカンダリポート）
+pub trait ThunderbirdLocatorPort {
+    /// Thunderbird実行ファイルのパスを探す
+    ///
+    /// ## Returns
+    /// * 見つかった場合は実行ファイルのパス、見つからない場合は`None`
+    fn locate(&self) -> Option<PathBuf>;
+}
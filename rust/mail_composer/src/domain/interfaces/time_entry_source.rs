@@ -0,0 +1,30 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use share::error::app_error::AppResult;
+
+/// 外部の作業時間記録サービスから取り込む、1件分の作業時間
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeEntry {
+    /// 開始日時（UTCオフセット付き）
+    pub started_at: DateTime<FixedOffset>,
+    /// 終了日時（UTCオフセット付き）
+    pub ended_at: DateTime<FixedOffset>,
+    /// 作業内容の説明
+    pub description: Option<String>,
+}
+
+impl TimeEntry {
+    /// 作業記録の日付（開始日時の日付）を取得する
+    pub fn date(&self) -> NaiveDate {
+        self.started_at.date_naive()
+    }
+}
+
+/// 外部の作業時間記録サービス（Toggl Track、Clockifyなど）のデータを読み込むためのポート（セカンダリポート）
+pub trait TimeEntrySourcePort {
+    /// 作業時間の一覧を取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<TimeEntry>>`
+    /// * 失敗時 - `Err<AppError>`
+    fn list_entries(&self) -> AppResult<Vec<TimeEntry>>;
+}
@@ -1,34 +1,155 @@
-use share::error::app_error::AppResult;
-use crate::domain::value_objects::mail_objects::WorkTime;
-use chrono::NaiveDate;
+use crate::domain::{
+    entities::work_record::WorkRecord,
+    value_objects::{mail_objects::WorkTime, time_zone::WorkTimeZone},
+};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
 
 /// 作業時間管理のためのポート（セカンダリポート）
 pub trait WorkTimePort {
-    /// 指定日の作業開始時刻を保存する
+    /// 指定日の作業開始日時を保存する
     ///
     /// ## Arguments
     /// * `date` - 対象日付
-    /// * `start_time` - 開始時刻
+    /// * `started_at` - 開始日時（記録時のUTCオフセット付き）
     ///
     /// ## Returns
     /// * 成功時 - `Ok(())`
     /// * 失敗時 - `Err<AppError>`
-    fn save_start_time(&self, date: NaiveDate, start_time: &WorkTime) -> AppResult<()>;
+    fn save_start_time(&self, date: NaiveDate, started_at: &DateTime<FixedOffset>)
+    -> AppResult<()>;
 
-    /// 今日の作業開始時刻を保存する
+    /// 開始日時の日付の作業開始日時を保存する
     ///
     /// ## Arguments
-    /// * `start_time` - 開始時刻
+    /// * `started_at` - 開始日時（記録時のUTCオフセット付き）
     ///
     /// ## Returns
     /// * 成功時 - `Ok(())`
     /// * 失敗時 - `Err<AppError>`
-    fn save_today_start_time(&self, start_time: &WorkTime) -> AppResult<()> {
-        use chrono::Local;
-        let today = Local::now().date_naive();
-        self.save_start_time(today, start_time)
+    fn save_today_start_time(&self, started_at: &DateTime<FixedOffset>) -> AppResult<()> {
+        self.save_start_time(started_at.date_naive(), started_at)
     }
 
+    /// 指定日の作業終了日時を保存し、作業時間を記録する
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    /// * `ended_at` - 終了日時（記録時のUTCオフセット付き）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - `Err<AppError>`
+    fn save_end_time(
+        &self,
+        date: NaiveDate,
+        ended_at: &DateTime<FixedOffset>,
+    ) -> AppResult<WorkRecord>;
+
+    /// 終了日時の日付の作業終了日時を保存し、作業時間を記録する
+    ///
+    /// ## Arguments
+    /// * `ended_at` - 終了日時（記録時のUTCオフセット付き）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - `Err<AppError>`
+    fn save_today_end_time(&self, ended_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        self.save_end_time(ended_at.date_naive(), ended_at)
+    }
+
+    /// 指定日の作業を一時停止する
+    ///
+    /// 休憩時刻は、一時停止日時のUTCオフセットでの時刻として記録する
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    /// * `paused_at` - 一時停止日時（記録時のUTCオフセット付き）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - 作業中でない場合、または保存に失敗した場合の`Err<AppError>`
+    fn pause(&self, date: NaiveDate, paused_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord>;
+
+    /// 指定日の一時停止した作業を再開する
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    /// * `resumed_at` - 再開日時（記録時のUTCオフセット付き）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - 一時停止していない場合、または保存に失敗した場合の`Err<AppError>`
+    fn resume(&self, date: NaiveDate, resumed_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord>;
+
+    /// 指定日の作業記録に備考を追記する
+    ///
+    /// 作業記録がない日は、備考のみの作業記録を作成する。
+    /// デフォルトでは備考の記録に対応していないエラーを返す
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    /// * `note` - 追記する備考（例: `遅刻連絡（出社予定 10:30）`）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - `Err<AppError>`
+    fn annotate(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        let _ = (date, note);
+        Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-WORK-011")
+            .with_message("この作業時間の保存先は備考の記録に対応していません。")
+            .with_action("JSON・JSON Lines・SQLite形式の作業時間ファイルを使用してください。"))
+    }
+
+    /// 指定日の作業記録の備考を置き換える
+    ///
+    /// 作業記録がない日は、備考のみの作業記録を作成する。空の備考を指定した場合は備考を削除する。
+    /// デフォルトでは備考の記録に対応していないエラーを返す
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    /// * `note` - 新しい備考
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecord>` (保存後の作業記録)
+    /// * 失敗時 - `Err<AppError>`
+    fn replace_note(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        let _ = (date, note);
+        Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-WORK-042")
+            .with_message("この作業時間の保存先は備考の記録に対応していません。")
+            .with_action("JSON・JSON Lines・SQLite形式の作業時間ファイルを使用してください。"))
+    }
+
+    /// 指定日の作業記録を読み込む
+    ///
+    /// ## Arguments
+    /// * `date` - 対象日付
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Option<WorkRecord>>` (記録がない場合はNone)
+    /// * 失敗時 - `Err<AppError>`
+    fn load_record(&self, date: NaiveDate) -> AppResult<Option<WorkRecord>>;
+
+    /// 指定期間の作業記録を日付順に読み込む
+    ///
+    /// ## Arguments
+    /// * `from` - 期間の開始日（この日を含む）
+    /// * `to` - 期間の終了日（この日を含む）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<(NaiveDate, WorkRecord)>>` (記録がある日のみ)
+    /// * 失敗時 - `Err<AppError>`
+    fn load_records(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<(NaiveDate, WorkRecord)>>;
+
     /// 指定日の作業開始時刻を読み込む
     ///
     /// ## Arguments
@@ -37,16 +158,83 @@ pub trait WorkTimePort {
     /// ## Returns
     /// * 成功時 - `Ok<Option<WorkTime>>` (記録がない場合はNone)
     /// * 失敗時 - `Err<AppError>`
-    fn load_start_time(&self, date: NaiveDate) -> AppResult<Option<WorkTime>>;
+    fn load_start_time(&self, date: NaiveDate) -> AppResult<Option<WorkTime>> {
+        Ok(self.load_record(date)?.and_then(|record| record.start))
+    }
 
     /// 今日の作業開始時刻を読み込む
     ///
+    /// ## Arguments
+    /// * `time_zone` - 今日の日付と開始時刻の表示に使用するタイムゾーン
+    ///
     /// ## Returns
     /// * 成功時 - `Ok<Option<WorkTime>>` (記録がない場合はNone)
     /// * 失敗時 - `Err<AppError>`
-    fn load_today_start_time(&self) -> AppResult<Option<WorkTime>> {
-        use chrono::Local;
-        let today = Local::now().date_naive();
-        self.load_start_time(today)
+    fn load_today_start_time(&self, time_zone: &WorkTimeZone) -> AppResult<Option<WorkTime>> {
+        Ok(self
+            .load_record(time_zone.today())?
+            .and_then(|record| record.localized(time_zone).start))
+    }
+}
+
+impl<T: WorkTimePort + ?Sized> WorkTimePort for Box<T> {
+    fn save_start_time(
+        &self,
+        date: NaiveDate,
+        started_at: &DateTime<FixedOffset>,
+    ) -> AppResult<()> {
+        (**self).save_start_time(date, started_at)
+    }
+
+    fn save_today_start_time(&self, started_at: &DateTime<FixedOffset>) -> AppResult<()> {
+        (**self).save_today_start_time(started_at)
+    }
+
+    fn save_end_time(
+        &self,
+        date: NaiveDate,
+        ended_at: &DateTime<FixedOffset>,
+    ) -> AppResult<WorkRecord> {
+        (**self).save_end_time(date, ended_at)
+    }
+
+    fn save_today_end_time(&self, ended_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        (**self).save_today_end_time(ended_at)
+    }
+
+    fn pause(&self, date: NaiveDate, paused_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        (**self).pause(date, paused_at)
+    }
+
+    fn resume(&self, date: NaiveDate, resumed_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        (**self).resume(date, resumed_at)
+    }
+
+    fn annotate(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        (**self).annotate(date, note)
+    }
+
+    fn replace_note(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        (**self).replace_note(date, note)
+    }
+
+    fn load_record(&self, date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+        (**self).load_record(date)
+    }
+
+    fn load_records(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+        (**self).load_records(from, to)
+    }
+
+    fn load_start_time(&self, date: NaiveDate) -> AppResult<Option<WorkTime>> {
+        (**self).load_start_time(date)
+    }
+
+    fn load_today_start_time(&self, time_zone: &WorkTimeZone) -> AppResult<Option<WorkTime>> {
+        (**self).load_today_start_time(time_zone)
     }
-}
\ No newline at end of file
+}
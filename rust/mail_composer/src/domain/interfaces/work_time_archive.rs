@@ -0,0 +1,53 @@
+use chrono::{Datelike, Months, NaiveDate};
+use share::error::app_error::AppResult;
+
+/// 古い作業記録をアーカイブに移動するためのポート（セカンダリポート）
+///
+/// アーカイブした作業記録も、期間を指定した読み込みの対象に含める
+pub trait WorkTimeArchivePort {
+    /// 指定日より前の作業記録をアーカイブに移動する
+    ///
+    /// ## Arguments
+    /// * `cutoff` - この日より前（この日を含まない）の作業記録を移動する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<usize>` (移動した日数)
+    /// * 失敗時 - `Err<AppError>`
+    fn archive_before(&self, cutoff: NaiveDate) -> AppResult<usize>;
+
+    /// 指定日の月と、その前の`months`か月分を残し、それより古い作業記録をアーカイブに移動する
+    ///
+    /// ## Arguments
+    /// * `today` - 基準日
+    /// * `months` - 残す月数（0の場合は当月分のみ残す）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<usize>` (移動した日数)
+    /// * 失敗時 - `Err<AppError>`
+    fn archive_older_than(&self, today: NaiveDate, months: u32) -> AppResult<usize> {
+        self.archive_before(retention_cutoff(today, months))
+    }
+}
+
+/// 直近`months`か月分を残す場合に、アーカイブの対象となる境界日を求める
+///
+/// ## Arguments
+/// * `today` - 基準日
+/// * `months` - 残す月数（基準日の月は含まない）
+///
+/// ## Returns
+/// * 境界日（この日より前の作業記録をアーカイブする）
+///
+/// ## Examples
+/// ```rust
+/// use chrono::NaiveDate;
+/// use mail_composer::domain::interfaces::work_time_archive::retention_cutoff;
+/// let today = NaiveDate::from_ymd_opt(2025, 4, 15).unwrap();
+/// assert_eq!(retention_cutoff(today, 3), NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+/// ```
+pub fn retention_cutoff(today: NaiveDate, months: u32) -> NaiveDate {
+    let first_day = today.with_day(1).unwrap_or(today);
+    first_day
+        .checked_sub_months(Months::new(months))
+        .unwrap_or(NaiveDate::MIN)
+}
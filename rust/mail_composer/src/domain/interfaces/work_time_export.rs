@@ -0,0 +1,18 @@
+use crate::domain::entities::work_record::WorkRecord;
+use chrono::NaiveDate;
+use share::error::app_error::AppResult;
+use std::path::PathBuf;
+
+/// 作業記録をファイルに書き出すためのポート（セカンダリポート）
+pub trait WorkTimeExportPort {
+    /// 作業記録を書き出す
+    ///
+    /// ## Arguments
+    /// * `file_stem` - 拡張子を除いた出力ファイル名（例: `work_times_2025-04`）
+    /// * `records` - 日付順の作業記録
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<PathBuf>` (出力したファイルのパス)
+    /// * 失敗時 - `Err<AppError>`
+    fn export(&self, file_stem: &str, records: &[(NaiveDate, WorkRecord)]) -> AppResult<PathBuf>;
+}
@@ -0,0 +1,70 @@
+/// アドレスブックの検索条件を表現する値オブジェクト
+///
+/// 指定した条件は全て満たす必要がある（AND条件）。条件を指定しない場合は全てのエントリが対象になる
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddressQuery {
+    name_contains: Option<String>,
+    domain: Option<String>,
+    member_of: Option<String>,
+}
+
+impl AddressQuery {
+    /// 条件を持たない検索条件を作成する
+    ///
+    /// ## Returns
+    /// * AddressQueryのインスタンス
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 名前または別名の部分一致を条件に追加する
+    ///
+    /// ## Arguments
+    /// * `text` - 名前に含まれる文字列（英字の大文字・小文字は区別しない）
+    ///
+    /// ## Returns
+    /// * 条件が追加されたAddressQuery
+    pub fn with_name_contains(mut self, text: impl Into<String>) -> Self {
+        self.name_contains = Some(text.into());
+        self
+    }
+
+    /// メールアドレスのドメインを条件に追加する
+    ///
+    /// ## Arguments
+    /// * `domain` - メールアドレスの`@`以降（例: `example.com`）
+    ///
+    /// ## Returns
+    /// * 条件が追加されたAddressQuery
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// グループへの所属を条件に追加する
+    ///
+    /// ## Arguments
+    /// * `group` - グループ名（入れ子のグループのメンバーも対象になる）
+    ///
+    /// ## Returns
+    /// * 条件が追加されたAddressQuery
+    pub fn with_member_of(mut self, group: impl Into<String>) -> Self {
+        self.member_of = Some(group.into());
+        self
+    }
+
+    /// 名前の部分一致の条件を取得する
+    pub fn name_contains(&self) -> Option<&str> {
+        self.name_contains.as_deref()
+    }
+
+    /// ドメインの条件を取得する
+    pub fn domain(&self) -> Option<&str> {
+        self.domain.as_deref()
+    }
+
+    /// グループへの所属の条件を取得する
+    pub fn member_of(&self) -> Option<&str> {
+        self.member_of.as_deref()
+    }
+}
@@ -1,12 +1,30 @@
+use crate::domain::value_objects::{
+    cron_schedule::CronSchedule,
+    language::Language,
+    mail_objects::WorkTime,
+    time_zone::WorkTimeZone,
+    work_hours::{DurationFormat, StandardWorkHours},
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use share::error::{
     app_error::{AppError, AppResult},
     kind::ErrorKind,
 };
-use std::path::{Path, PathBuf};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// 設定値を上書きする環境変数名の接頭辞
+pub const ENV_PREFIX: &str = "MAIL_COMPOSER_";
+
+/// 作業終了の記録忘れを通知する時刻の既定値
+pub const DEFAULT_END_REMINDER_TIME: &str = "19:00";
 
 /// アプリケーション設定を表現する値オブジェクト
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AppConfiguration {
     /// 差出人名
     pub from: String,
@@ -14,7 +32,13 @@ pub struct AppConfiguration {
     pub department: String,
     /// Thunderbird実行ファイルのパス
     pub thunderbird_exe: String,
-    /// ログディレクトリ
+    /// Thunderbirdプロセスの終了を待機するタイムアウト秒数（未設定または0の場合は既定の60秒。超過した場合はプロセスを強制終了する）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thunderbird_timeout_secs: Option<u64>,
+    /// タイムアウトを過ぎたThunderbirdプロセスを強制終了せずに切り離すかどうか（既定は`false`）
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub thunderbird_detach_on_timeout: bool,
+    /// ログディレクトリ（日ごとのログファイル`mail_composer.<YYYY-MM-DD>.log`を書き込む）
     pub log_dir: String,
     /// 入力ディレクトリ
     pub input_dir: String,
@@ -24,34 +48,586 @@ pub struct AppConfiguration {
     pub output_dir: String,
     /// 作業開始時間ファイル名
     pub start_time_file: String,
+    /// 複数メールを連続送信する際の1分あたりの最大送信数（未設定の場合は制限しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messages_per_minute: Option<u32>,
+    /// 1日の所定労働時間（残業時間の計算に使用、未設定の場合は8時間）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_daily_hours: Option<f64>,
+    /// メール本文やレポートでの作業時間の表示形式（未設定の場合はH:MM形式）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_format: Option<DurationFormat>,
+    /// 作業時刻の記録と表示に使用するタイムゾーン（IANAタイムゾーン名、未設定の場合はローカル）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// 作業時間ファイルに残す月数（これより古い作業記録は年ごとのアーカイブに移動する、未設定の場合は移動しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_time_retention_months: Option<u32>,
+    /// 作業終了の記録忘れを通知する時刻（HH:MM形式、未設定の場合は19:00）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_reminder_time: Option<String>,
+    /// メールテンプレートの言語（例: `en`で`remote_work_start.en`を優先して使用する、未設定の場合は言語別のテンプレートを使用しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// ヘルプ・確認・処理結果の表示言語（`ja`または`en`、未設定の場合は環境変数`LANG`などから判定する）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui_language: Option<String>,
+    /// 全てのメールの件名の先頭に付ける文字列（例: `【社外秘】`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_prefix: Option<String>,
+    /// 全てのメールの件名の末尾に付ける文字列（例: ` [#1234]`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_suffix: Option<String>,
+    /// 日ごとの作業メモ（`<日付>.md`）を置くディレクトリ（未設定の場合は出力ディレクトリの`notes`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes_dir: Option<String>,
+    /// メールテンプレートファイルのパス（未設定の場合は既定の配置の`mail_templates.json`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mail_templates_file: Option<String>,
+    /// 一元管理された設定ファイルのURL（設定した場合は設定ファイルの値より優先して使用する）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_url: Option<String>,
+    /// 一元管理されたメールテンプレートファイルのURL（設定した場合は`mail_templates_file`より優先する）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mail_templates_url: Option<String>,
+    /// 作業記録の保存形式（未設定の場合は`json`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_time_store: Option<WorkTimeStore>,
+    /// 件名・本文で使用できる利用者定義の値（例: `{"project": "基盤刷新"}`で`{project}`を使用できる）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, Value>,
+    /// IMAPの下書きフォルダにアップロードするメッセージの署名設定（未設定の場合は署名しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing: Option<SigningConfiguration>,
+    /// IMAPの下書きフォルダへのアップロード設定
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imap: Option<ImapConfiguration>,
+    /// 社内ディレクトリ（LDAP/Active Directory）による名前解決の設定
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ldap: Option<LdapConfiguration>,
+    /// 常駐モード（`daemon`）で実行する処理とスケジュール
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedules: Vec<ScheduledTask>,
+}
+
+/// 作業記録の保存形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkTimeStore {
+    /// 日ごとの作業記録をまとめたJSONファイル（`work_times.json`）
+    #[default]
+    Json,
+    /// 打刻を1行ずつ追記するイベントログ（`work_times.jsonl`）
+    Jsonl,
+    /// SQLiteデータベース（`work_times.sqlite3`）
+    Sqlite,
+}
+
+impl WorkTimeStore {
+    /// 設定ファイルで指定できる保存形式の名前
+    pub const NAMES: [&'static str; 3] = ["json", "jsonl", "sqlite"];
+}
+
+impl FromStr for WorkTimeStore {
+    type Err = AppError;
+
+    fn from_str(name: &str) -> AppResult<Self> {
+        match name.trim() {
+            "json" => Ok(Self::Json),
+            "jsonl" => Ok(Self::Jsonl),
+            "sqlite" => Ok(Self::Sqlite),
+            other => Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-CONF-038")
+                .with_message(format!("作業記録の保存形式が不正です。詳細: {other}"))
+                .with_action(format!(
+                    "次のいずれかを指定してください: {}",
+                    Self::NAMES.join(", ")
+                ))),
+        }
+    }
+}
+
+/// 常駐モードで実行する処理
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledAction {
+    /// 作業開始が未記録の場合に、在宅勤務開始メールを作成する（送信前に確認する）
+    StartMail,
+    /// 作業終了の記録忘れを通知する
+    EndReminder,
+    /// 今週の勤務状況の週報メールを作成する（送信前に確認する）
+    WeeklyReport,
+}
+
+impl ScheduledAction {
+    /// 表示用の名前を取得する
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::StartMail => "start_mail",
+            Self::EndReminder => "end_reminder",
+            Self::WeeklyReport => "weekly_report",
+        }
+    }
+}
+
+/// 常駐モードで実行する処理とスケジュールの組を表現する値オブジェクト
+///
+/// 例: `{"schedule": "0 9 * * 1-5", "action": "start_mail"}`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledTask {
+    /// cron形式の実行スケジュール（設定したタイムゾーンで解釈する）
+    pub schedule: CronSchedule,
+    /// 実行する処理
+    pub action: ScheduledAction,
+}
+
+/// IMAPサーバーへの接続設定を表現する値オブジェクト
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImapConfiguration {
+    /// IMAPサーバーのホスト名
+    pub host: String,
+    /// IMAPサーバーのポート番号
+    #[serde(default = "ImapConfiguration::default_port")]
+    pub port: u16,
+    /// ログインユーザー名
+    pub username: String,
+    /// Fromヘッダーに設定する差出人メールアドレス
+    pub sender_address: String,
+    /// 下書きを保存するフォルダ名
+    #[serde(default = "ImapConfiguration::default_folder")]
+    pub folder: String,
+    /// TLS（IMAPS）で接続するかどうか
+    #[serde(default = "ImapConfiguration::default_use_tls")]
+    pub use_tls: bool,
+}
+
+impl ImapConfiguration {
+    fn default_port() -> u16 {
+        993
+    }
+
+    fn default_folder() -> String {
+        "Drafts".to_string()
+    }
+
+    fn default_use_tls() -> bool {
+        true
+    }
+}
+
+/// 送信メールの署名方式ごとの設定を表現する値オブジェクト
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum SigningConfiguration {
+    /// S/MIME署名
+    Smime {
+        /// 署名に使用する証明書（PEM形式）のパス
+        certificate: String,
+        /// 署名に使用する秘密鍵（PEM形式）のパス
+        private_key: String,
+    },
+    /// GPG（OpenPGP）署名
+    Gpg {
+        /// 署名に使用する鍵ID（未設定の場合はGPGのデフォルト鍵）
+        #[serde(default)]
+        key_id: Option<String>,
+    },
+}
+
+/// LDAPサーバーへの接続と検索の設定を表現する値オブジェクト
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LdapConfiguration {
+    /// LDAPサーバーのURL（例: `ldaps://ldap.example.com`）
+    pub url: String,
+    /// 検索の起点となるベースDN
+    pub base_dn: String,
+    /// 検索フィルターのテンプレート（`{name}`が検索する名前に置換される）
+    #[serde(default = "LdapConfiguration::default_filter_template")]
+    pub filter_template: String,
+    /// バインドに使用するDN（未設定の場合は匿名バインド）
+    #[serde(default)]
+    pub bind_dn: Option<String>,
+    /// メールアドレスを保持する属性名
+    #[serde(default = "LdapConfiguration::default_mail_attribute")]
+    pub mail_attribute: String,
+    /// 検索結果のキャッシュファイル（ワークスペースルートからの相対パス）
+    #[serde(default = "LdapConfiguration::default_cache_file")]
+    pub cache_file: String,
+}
+
+impl LdapConfiguration {
+    fn default_filter_template() -> String {
+        "(displayName={name})".to_string()
+    }
+
+    fn default_mail_attribute() -> String {
+        "mail".to_string()
+    }
+
+    fn default_cache_file() -> String {
+        "rust/mail_composer/data/ldap_cache.json".to_string()
+    }
+}
+
+impl Default for AppConfiguration {
+    /// ワークスペース内の従来の配置を使用し、差出人などは未設定の設定を作成する
+    fn default() -> Self {
+        Self {
+            from: String::new(),
+            department: String::new(),
+            thunderbird_exe: String::new(),
+            thunderbird_timeout_secs: None,
+            thunderbird_detach_on_timeout: false,
+            log_dir: "rust/mail_composer/logs".to_string(),
+            input_dir: "rust/mail_composer/config".to_string(),
+            address_book_file: "address_book.json".to_string(),
+            output_dir: "rust/mail_composer/data".to_string(),
+            start_time_file: "work_start_time.json".to_string(),
+            messages_per_minute: None,
+            standard_daily_hours: None,
+            duration_format: None,
+            timezone: None,
+            work_time_retention_months: None,
+            end_reminder_time: None,
+            locale: None,
+            ui_language: None,
+            subject_prefix: None,
+            subject_suffix: None,
+            notes_dir: None,
+            mail_templates_file: None,
+            config_url: None,
+            mail_templates_url: None,
+            work_time_store: None,
+            variables: BTreeMap::new(),
+            signing: None,
+            imap: None,
+            ldap: None,
+            schedules: Vec::new(),
+        }
+    }
 }
 
 impl AppConfiguration {
+    /// 名前を指定して上書きできる設定項目
+    pub const OVERRIDABLE_FIELDS: [&'static str; 24] = [
+        "from",
+        "department",
+        "thunderbird_exe",
+        "log_dir",
+        "input_dir",
+        "address_book_file",
+        "output_dir",
+        "start_time_file",
+        "thunderbird_timeout_secs",
+        "messages_per_minute",
+        "standard_daily_hours",
+        "duration_format",
+        "timezone",
+        "work_time_retention_months",
+        "end_reminder_time",
+        "locale",
+        "ui_language",
+        "subject_prefix",
+        "subject_suffix",
+        "notes_dir",
+        "mail_templates_file",
+        "config_url",
+        "mail_templates_url",
+        "work_time_store",
+    ];
+
+    /// 設定項目を上書きする環境変数名を取得する
+    ///
+    /// ## Arguments
+    /// * `field` - 設定項目名（例: `thunderbird_exe`）
+    ///
+    /// ## Returns
+    /// * 環境変数名（例: `MAIL_COMPOSER_THUNDERBIRD_EXE`）
+    pub fn env_var_name(field: &str) -> String {
+        format!("{ENV_PREFIX}{}", field.to_ascii_uppercase())
+    }
+
+    /// 名前を指定して設定項目の値を上書きする
+    ///
+    /// ## Arguments
+    /// * `field` - 設定項目名（[`AppConfiguration::OVERRIDABLE_FIELDS`]のいずれか）
+    /// * `value` - 設定する値（数値項目は0以上の整数、`standard_daily_hours`は小数も可）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 未知の設定項目名、または値が不正な場合のAppError
+    pub fn set_field(&mut self, field: &str, value: &str) -> AppResult<()> {
+        let target = match field {
+            "from" => &mut self.from,
+            "department" => &mut self.department,
+            "thunderbird_exe" => {
+                self.thunderbird_exe = value.replace('\\', "/");
+                return Ok(());
+            }
+            "log_dir" => &mut self.log_dir,
+            "input_dir" => &mut self.input_dir,
+            "address_book_file" => &mut self.address_book_file,
+            "output_dir" => &mut self.output_dir,
+            "start_time_file" => &mut self.start_time_file,
+            "thunderbird_timeout_secs" => {
+                self.thunderbird_timeout_secs = Some(parse_number(field, value)?);
+                return Ok(());
+            }
+            "messages_per_minute" => {
+                self.messages_per_minute = Some(parse_number(field, value)?);
+                return Ok(());
+            }
+            "standard_daily_hours" => {
+                let hours = value.trim().parse().map_err(|_| {
+                    AppError::new(ErrorKind::UnavailableForLegalReasons)
+                        .with_code("MC-CONF-007")
+                        .with_message(format!("設定項目'{field}'の値が不正です。詳細: {value}"))
+                        .with_action("時間数を数値で指定してください（例: 7.5）。")
+                })?;
+                self.standard_daily_hours = Some(hours);
+                return Ok(());
+            }
+            "duration_format" => {
+                self.duration_format = Some(value.parse()?);
+                return Ok(());
+            }
+            "timezone" => {
+                self.timezone = Some(WorkTimeZone::parse(value)?.name().to_string());
+                return Ok(());
+            }
+            "work_time_retention_months" => {
+                self.work_time_retention_months = Some(parse_number(field, value)?);
+                return Ok(());
+            }
+            "end_reminder_time" => {
+                self.end_reminder_time = Some(WorkTime::new(value.trim())?.as_str().to_string());
+                return Ok(());
+            }
+            "locale" => {
+                self.locale = Some(value.trim().to_string());
+                return Ok(());
+            }
+            "ui_language" => {
+                self.ui_language = Some(Language::parse(value)?.code().to_string());
+                return Ok(());
+            }
+            "subject_prefix" => {
+                self.subject_prefix = Some(value.to_string());
+                return Ok(());
+            }
+            "subject_suffix" => {
+                self.subject_suffix = Some(value.to_string());
+                return Ok(());
+            }
+            "notes_dir" => {
+                self.notes_dir = Some(value.to_string());
+                return Ok(());
+            }
+            "mail_templates_file" => {
+                self.mail_templates_file = Some(value.replace('\\', "/"));
+                return Ok(());
+            }
+            "config_url" => {
+                self.config_url = Some(value.to_string());
+                return Ok(());
+            }
+            "mail_templates_url" => {
+                self.mail_templates_url = Some(value.to_string());
+                return Ok(());
+            }
+            "work_time_store" => {
+                self.work_time_store = Some(value.parse()?);
+                return Ok(());
+            }
+            _ => {
+                return Err(AppError::new(ErrorKind::BadRequest)
+                    .with_code("MC-CONF-008")
+                    .with_message(format!("設定項目'{field}'は上書きできません。"))
+                    .with_action(format!(
+                        "次のいずれかを指定してください: {}",
+                        Self::OVERRIDABLE_FIELDS.join(", ")
+                    )));
+            }
+        };
+        *target = value.to_string();
+        Ok(())
+    }
+
     /// 設定値を検証する
     ///
+    /// 最初の問題で中断せず、全ての問題を1つのエラーにまとめて返す
+    ///
     /// ## Returns
     /// * 成功時 - `Ok(())`
-    /// * 失敗時 - 検証エラーのAppError
+    /// * 失敗時 - 全ての検証エラーを列挙したAppError
     pub fn validate(&self) -> AppResult<()> {
-        if self.from.trim().is_empty() {
-            return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
-                .with_message("差出人名が設定されていません。")
-                .with_action("config.jsonのfromフィールドに差出人名を設定してください。"));
+        match AppError::aggregate(self.validation_errors()) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// 設定値の問題を全て取得する
+    ///
+    /// ## Returns
+    /// * 検証エラーの一覧（問題がない場合は空）
+    pub fn validation_errors(&self) -> Vec<AppError> {
+        let required = [
+            (&self.from, "from", "差出人名"),
+            (&self.department, "department", "差出部署"),
+            (
+                &self.thunderbird_exe,
+                "thunderbird_exe",
+                "Thunderbird実行ファイルのパス",
+            ),
+            (&self.log_dir, "log_dir", "ログディレクトリ"),
+            (&self.input_dir, "input_dir", "入力ディレクトリ"),
+            (
+                &self.address_book_file,
+                "address_book_file",
+                "アドレスブックファイル名",
+            ),
+            (&self.output_dir, "output_dir", "出力ディレクトリ"),
+            (
+                &self.start_time_file,
+                "start_time_file",
+                "作業開始時間ファイル名",
+            ),
+        ];
+
+        let mut errors: Vec<AppError> = required
+            .into_iter()
+            .filter(|(value, _, _)| value.trim().is_empty())
+            .map(|(_, field, label)| {
+                AppError::new(ErrorKind::UnavailableForLegalReasons)
+                    .with_code("MC-CONF-009")
+                    .with_message(format!("{label}が設定されていません。"))
+                    .with_action(format!(
+                        "config.jsonの{field}フィールドに{label}を設定してください。"
+                    ))
+            })
+            .collect();
+
+        if self.messages_per_minute == Some(0) {
+            errors.push(
+                AppError::new(ErrorKind::UnavailableForLegalReasons)
+                    .with_code("MC-CONF-010")
+                    .with_message("1分あたりの最大送信数に0は指定できません。")
+                    .with_action(
+                        "config.jsonのmessages_per_minuteフィールドに1以上の値を設定するか、削除してください。",
+                    ),
+            );
         }
 
-        if self.department.trim().is_empty() {
-            return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
-                .with_message("差出部署が設定されていません。")
-                .with_action("config.jsonのdepartmentフィールドに部署名を設定してください。"));
+        if let Err(error) = self.standard_work_hours() {
+            errors.push(error);
         }
 
-        if self.thunderbird_exe.trim().is_empty() {
-            return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
-                .with_message("Thunderbird実行ファイルのパスが設定されていません。")
-                .with_action("config.jsonのthunderbird_exeフィールドにThunderbirdのパスを設定してください。"));
+        if let Err(error) = self.time_zone() {
+            errors.push(error);
         }
 
-        Ok(())
+        if let Err(error) = self.end_reminder_time() {
+            errors.push(error);
+        }
+
+        if let Err(error) = self.ui_language() {
+            errors.push(error);
+        }
+
+        if let Some(locale) = &self.locale
+            && (locale.is_empty()
+                || !locale
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        {
+            errors.push(
+                AppError::new(ErrorKind::UnavailableForLegalReasons)
+                    .with_code("MC-CONF-011")
+                    .with_message(format!("メールテンプレートの言語が不正です。詳細: {locale}"))
+                    .with_action(
+                        "config.jsonのlocaleフィールドに言語コード（例: ja、en）を設定するか、削除してください。",
+                    ),
+            );
+        }
+
+        errors
+    }
+
+    /// 1日の所定労働時間を取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - 設定値（未設定の場合は既定値の8時間）
+    /// * 失敗時 - 設定値が範囲外の場合のAppError
+    pub fn standard_work_hours(&self) -> AppResult<StandardWorkHours> {
+        match self.standard_daily_hours {
+            Some(hours) => StandardWorkHours::from_hours(hours),
+            None => Ok(StandardWorkHours::default()),
+        }
+    }
+
+    /// 作業時刻の記録と表示に使用するタイムゾーンを取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - 設定値（未設定の場合はローカルタイムゾーン）
+    /// * 失敗時 - 未知のタイムゾーン名が設定されている場合のAppError
+    pub fn time_zone(&self) -> AppResult<WorkTimeZone> {
+        match &self.timezone {
+            Some(name) => WorkTimeZone::parse(name),
+            None => Ok(WorkTimeZone::default()),
+        }
+    }
+
+    /// 作業終了の記録忘れを通知する時刻を取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - 設定値（未設定の場合は既定値の19:00）
+    /// * 失敗時 - HH:MM形式でない値が設定されている場合のAppError
+    pub fn end_reminder_time(&self) -> AppResult<WorkTime> {
+        WorkTime::new(
+            self.end_reminder_time
+                .as_deref()
+                .unwrap_or(DEFAULT_END_REMINDER_TIME),
+        )
+    }
+
+    /// ヘルプ・確認・処理結果の表示言語を取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - 設定値（未設定の場合は環境変数`LC_ALL`・`LC_MESSAGES`・`LANG`から判定した言語）
+    /// * 失敗時 - `ja`・`en`以外の値が設定されている場合のAppError
+    pub fn ui_language(&self) -> AppResult<Language> {
+        match &self.ui_language {
+            Some(code) => Language::parse(code),
+            None => Ok(Language::detect()),
+        }
+    }
+
+    /// テンプレートから描画した件名に、設定された接頭辞と接尾辞を付ける
+    ///
+    /// ## Arguments
+    /// * `subject` - テンプレートから描画した件名
+    ///
+    /// ## Returns
+    /// * 接頭辞と接尾辞を付けた件名
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::app_configuration::AppConfiguration;
+    /// let config = AppConfiguration {
+    ///     subject_prefix: Some("【社外秘】".to_string()),
+    ///     subject_suffix: Some(" [#1234]".to_string()),
+    ///     ..AppConfiguration::default()
+    /// };
+    /// assert_eq!(config.decorate_subject("週報"), "【社外秘】週報 [#1234]");
+    /// assert_eq!(AppConfiguration::default().decorate_subject("週報"), "週報");
+    /// ```
+    pub fn decorate_subject(&self, subject: &str) -> String {
+        format!(
+            "{}{subject}{}",
+            self.subject_prefix.as_deref().unwrap_or_default(),
+            self.subject_suffix.as_deref().unwrap_or_default()
+        )
     }
 
     /// アドレスブックファイルのフルパスを取得する
@@ -78,6 +654,17 @@ impl AppConfiguration {
         Path::new(&self.output_dir)
     }
 
+    /// 日ごとの作業メモのディレクトリのパスを取得する
+    ///
+    /// ## Returns
+    /// * `notes_dir`、未設定の場合は出力ディレクトリの`notes`
+    pub fn notes_dir_path(&self) -> PathBuf {
+        match &self.notes_dir {
+            Some(notes_dir) => PathBuf::from(notes_dir),
+            None => self.output_dir_path().join("notes"),
+        }
+    }
+
     /// ログディレクトリのパスを取得する
     ///
     /// ## Returns
@@ -86,3 +673,13 @@ impl AppConfiguration {
         Path::new(&self.log_dir)
     }
 }
+
+/// 数値の設定項目の値を解析する
+fn parse_number<T: FromStr>(field: &str, value: &str) -> AppResult<T> {
+    value.trim().parse().map_err(|_| {
+        AppError::new(ErrorKind::UnavailableForLegalReasons)
+            .with_code("MC-CONF-012")
+            .with_message(format!("設定項目'{field}'の値が不正です。詳細: {value}"))
+            .with_action("0以上の整数を指定してください。")
+    })
+}
@@ -0,0 +1,192 @@
+use chrono::{Datelike, Duration, NaiveDateTime, Timelike};
+use serde::{Deserialize, Serialize};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::fmt;
+
+/// 次回の実行日時を探索する期間（分）。うるう年の2月29日のみの指定も見つけられるよう4年強とする
+const SEARCH_LIMIT_MINUTES: i64 = 60 * 24 * (366 * 4 + 1);
+
+/// cron形式（`分 時 日 月 曜日`）の実行スケジュールを表現する値オブジェクト
+///
+/// 各項目には`*`、数値、範囲（`1-5`）、間隔（`*/15`・`0-30/10`）、およびそれらのカンマ区切りを指定できる。
+/// 曜日は0〜7（0と7は日曜日）で指定する。日と曜日の両方を指定した場合は、どちらかに一致すれば実行する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CronSchedule {
+    text: String,
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days: Vec<bool>,
+    months: Vec<bool>,
+    weekdays: Vec<bool>,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl CronSchedule {
+    /// cron形式の文字列を解析する
+    ///
+    /// ## Arguments
+    /// * `text` - `分 時 日 月 曜日`を空白で区切った文字列（例: 平日9時は`0 9 * * 1-5`）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<CronSchedule>`
+    /// * 失敗時 - 項目数が5つでない場合、または値が範囲外の場合の`Err<AppError>`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::cron_schedule::CronSchedule;
+    /// assert!(CronSchedule::parse("30 18 * * 1-5").is_ok());
+    /// assert!(CronSchedule::parse("*/15 9-18 1,15 * *").is_ok());
+    /// assert!(CronSchedule::parse("0 24 * * *").is_err());
+    /// assert!(CronSchedule::parse("0 9 * *").is_err());
+    /// ```
+    pub fn parse(text: impl Into<String>) -> AppResult<Self> {
+        let text = text.into();
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(invalid_schedule(&text));
+        };
+
+        let mut weekdays = parse_field(weekday, 0, 7).ok_or_else(|| invalid_schedule(&text))?;
+        // 7は日曜日として扱う
+        if weekdays[7] {
+            weekdays[0] = true;
+        }
+        weekdays.truncate(7);
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59).ok_or_else(|| invalid_schedule(&text))?,
+            hours: parse_field(hour, 0, 23).ok_or_else(|| invalid_schedule(&text))?,
+            days: parse_field(day, 1, 31).ok_or_else(|| invalid_schedule(&text))?,
+            months: parse_field(month, 1, 12).ok_or_else(|| invalid_schedule(&text))?,
+            weekdays,
+            days_restricted: day != "*",
+            weekdays_restricted: weekday != "*",
+            text,
+        })
+    }
+
+    /// 指定した日時（秒以下は無視する）がスケジュールに一致するかを判定する
+    ///
+    /// ## Arguments
+    /// * `at` - 判定する日時（スケジュールを解釈するタイムゾーンでの日時）
+    ///
+    /// ## Returns
+    /// * 一致する場合は`true`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use mail_composer::domain::value_objects::cron_schedule::CronSchedule;
+    /// let schedule = CronSchedule::parse("0 9 * * 1-5").unwrap();
+    /// let monday = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+    /// let sunday = NaiveDate::from_ymd_opt(2024, 6, 2).unwrap();
+    /// assert!(schedule.matches(&monday.and_hms_opt(9, 0, 30).unwrap()));
+    /// assert!(!schedule.matches(&monday.and_hms_opt(9, 1, 0).unwrap()));
+    /// assert!(!schedule.matches(&sunday.and_hms_opt(9, 0, 0).unwrap()));
+    /// ```
+    pub fn matches(&self, at: &NaiveDateTime) -> bool {
+        let day = self.days[at.day() as usize];
+        let weekday = self.weekdays[at.weekday().num_days_from_sunday() as usize];
+        let date_matches = match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            _ => day && weekday,
+        };
+        self.minutes[at.minute() as usize]
+            && self.hours[at.hour() as usize]
+            && self.months[at.month() as usize]
+            && date_matches
+    }
+
+    /// 指定した日時より後で、最初にスケジュールに一致する日時を取得する
+    ///
+    /// ## Arguments
+    /// * `after` - 基準日時
+    ///
+    /// ## Returns
+    /// * 一致する日時（分単位、4年以内に一致する日時がない場合は`None`）
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use mail_composer::domain::value_objects::cron_schedule::CronSchedule;
+    /// let schedule = CronSchedule::parse("30 18 * * 1-5").unwrap();
+    /// let friday_night = NaiveDate::from_ymd_opt(2024, 6, 7).unwrap().and_hms_opt(19, 0, 0).unwrap();
+    /// let next = schedule.next_after(&friday_night).unwrap();
+    /// assert_eq!(next.to_string(), "2024-06-10 18:30:00");
+    /// ```
+    pub fn next_after(&self, after: &NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        (0..SEARCH_LIMIT_MINUTES)
+            .map(|offset| start + Duration::minutes(offset))
+            .find(|at| self.matches(at))
+    }
+
+    /// cron形式の文字列を取得する
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl TryFrom<String> for CronSchedule {
+    type Error = AppError;
+
+    fn try_from(value: String) -> AppResult<Self> {
+        Self::parse(value)
+    }
+}
+
+impl From<CronSchedule> for String {
+    fn from(schedule: CronSchedule) -> Self {
+        schedule.text
+    }
+}
+
+/// 1つの項目を解析し、`min`〜`max`の値ごとに一致するかを表す配列（添字は値）を作成する
+fn parse_field(field: &str, min: u32, max: u32) -> Option<Vec<bool>> {
+    let mut allowed = vec![false; max as usize + 1];
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|s| *s > 0)?),
+            None => (part, 1),
+        };
+        let (first, last) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((first, last)) => (first.parse().ok()?, last.parse().ok()?),
+                None => {
+                    let value = range.parse().ok()?;
+                    // `5/10`のような間隔付きの単一値は、その値から最大値までとする
+                    (value, if part.contains('/') { max } else { value })
+                }
+            },
+        };
+        if first < min || last > max || first > last {
+            return None;
+        }
+        for value in (first..=last).step_by(step as usize) {
+            allowed[value as usize] = true;
+        }
+    }
+    Some(allowed)
+}
+
+/// スケジュールの形式が不正な場合のエラーを作成する
+fn invalid_schedule(text: &str) -> AppError {
+    AppError::new(ErrorKind::UnavailableForLegalReasons)
+        .with_code("MC-CONF-013")
+        .with_message(format!("スケジュールの形式が不正です。詳細: {text}"))
+        .with_action(
+            "`分 時 日 月 曜日`のcron形式で指定してください（例: 平日9時は`0 9 * * 1-5`）。",
+        )
+}
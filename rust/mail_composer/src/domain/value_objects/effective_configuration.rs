@@ -0,0 +1,130 @@
+use crate::domain::value_objects::app_configuration::AppConfiguration;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// 設定値の取得元（後のものほど優先される）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigurationSource {
+    /// 組み込みの既定値
+    Default,
+    /// 設定ファイル
+    File,
+    /// `config_url`で指定された一元管理の設定ファイル
+    Remote,
+    /// 環境変数（`MAIL_COMPOSER_*`）
+    Environment,
+    /// コマンドライン引数
+    CommandLine,
+}
+
+impl ConfigurationSource {
+    /// 表示用の名前を取得する
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::File => "file",
+            Self::Remote => "remote",
+            Self::Environment => "environment",
+            Self::CommandLine => "command line",
+        }
+    }
+}
+
+/// 全ての層をマージした設定と、各設定項目の取得元
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveConfiguration {
+    /// マージ後の設定
+    pub configuration: AppConfiguration,
+    /// 設定項目名ごとの取得元（記載のない項目は既定値）
+    pub sources: BTreeMap<String, ConfigurationSource>,
+}
+
+impl EffectiveConfiguration {
+    /// 既定値のみからなるEffectiveConfigurationを作成する
+    ///
+    /// ## Returns
+    /// * EffectiveConfigurationのインスタンス
+    pub fn defaults() -> Self {
+        Self {
+            configuration: AppConfiguration::default(),
+            sources: BTreeMap::new(),
+        }
+    }
+
+    /// 設定ファイルから読み込んだ設定からEffectiveConfigurationを作成する
+    ///
+    /// 設定ファイルに記述された項目（省略可能な項目は値があるもの）の取得元を設定ファイルとする
+    ///
+    /// ## Arguments
+    /// * `configuration` - 設定ファイルから読み込んだ設定
+    ///
+    /// ## Returns
+    /// * EffectiveConfigurationのインスタンス
+    pub fn from_file(configuration: AppConfiguration) -> Self {
+        let sources = match serde_json::to_value(&configuration) {
+            Ok(serde_json::Value::Object(fields)) => fields
+                .keys()
+                .map(|key| (key.clone(), ConfigurationSource::File))
+                .collect(),
+            _ => BTreeMap::new(),
+        };
+        Self {
+            configuration,
+            sources,
+        }
+    }
+
+    /// マージ後の設定を`項目名 = 値  # 取得元`の形式で整形する
+    ///
+    /// ## Returns
+    /// * 1行に1項目を出力した文字列
+    pub fn render(&self) -> String {
+        let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(&self.configuration)
+        else {
+            return String::new();
+        };
+
+        fields
+            .iter()
+            .map(|(key, value)| {
+                let source = self
+                    .sources
+                    .get(key)
+                    .copied()
+                    .unwrap_or(ConfigurationSource::Default);
+                format!("{key} = {value}  # {}", source.as_str())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_sources() {
+        let mut effective = EffectiveConfiguration::from_file(AppConfiguration {
+            from: "山田".to_string(),
+            ..AppConfiguration::default()
+        });
+        effective.configuration.department = "営業部".to_string();
+        effective
+            .sources
+            .insert("department".to_string(), ConfigurationSource::Environment);
+
+        let rendered = effective.render();
+
+        assert!(rendered.contains(r#"from = "山田"  # file"#));
+        assert!(rendered.contains(r#"department = "営業部"  # environment"#));
+    }
+
+    #[test]
+    fn test_render_defaults() {
+        let rendered = EffectiveConfiguration::defaults().render();
+
+        assert!(rendered.contains("log_dir = \"rust/mail_composer/logs\"  # default"));
+    }
+}
@@ -5,12 +5,20 @@ use share::error::{
 };
 
 /// メールアドレスを表現する値オブジェクト
+///
+/// 宛先の表示に使用する表示名（例: `山田太郎`）を任意で保持する
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct EmailAddress(String);
+#[serde(try_from = "String", into = "String")]
+pub struct EmailAddress {
+    address: String,
+    display_name: Option<String>,
+}
 
 impl EmailAddress {
     /// EmailAddressを表現する文字列から[`EmailAddress`]構造体を生成する
     ///
+    /// `sample@example.com`のほか、`"山田太郎" <sample@example.com>`のような表示名付きの形式も受け付ける
+    ///
     /// ## Arguments
     /// * `email_address` - 生成対象のメールアドレスを表現する文字列
     ///
@@ -19,15 +27,40 @@ impl EmailAddress {
     /// * 失敗時 - [`Err<AppError>`]
     pub fn parse(email_address: impl Into<String>) -> AppResult<Self> {
         let email_address = email_address.into();
+        let (display_name, address) = match email_address.trim().strip_suffix('>') {
+            Some(rest) => match rest.rsplit_once('<') {
+                Some((name, address)) => (unquote_display_name(name), address.trim().to_string()),
+                None => (None, email_address.clone()),
+            },
+            None => (None, email_address.trim().to_string()),
+        };
+
         // TODO: より厳密なバリデーションを実装する
-        if !email_address.contains('@') {
+        if !address.contains('@') {
             return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-ADDR-011")
                 .with_message(format!(
                     "メールアドレスの形式が不正です。詳細: {email_address}"
                 ))
                 .with_action("正しいメールアドレスを指定してください。"));
         }
-        Ok(Self(email_address))
+        Ok(Self {
+            address,
+            display_name,
+        })
+    }
+
+    /// 表示名を設定する
+    ///
+    /// ## Arguments
+    /// * `display_name` - 表示名（空白のみの場合は表示名なしとして扱う）
+    ///
+    /// ## Returns
+    /// * 表示名が設定された[`EmailAddress`]
+    pub fn with_display_name(mut self, display_name: impl Into<String>) -> Self {
+        let display_name = display_name.into();
+        self.display_name = (!display_name.trim().is_empty()).then_some(display_name);
+        self
     }
 
     /// [`EmailAddress`]を表現する文字列を返す
@@ -36,7 +69,7 @@ impl EmailAddress {
     /// * `&self` - 文字列を取得対象の[`EmailAddress`]
     ///
     /// ## Returns
-    /// * 文字列を取得対象の[`EmailAddress`]を表現する文字列の参照
+    /// * 文字列を取得対象の[`EmailAddress`]を表現する文字列の参照（表示名を含まない）
     ///
     /// ## Examples
     /// ```rust
@@ -45,6 +78,63 @@ impl EmailAddress {
     /// assert_eq!(email.as_str(), "sample@example.com");
     /// ```
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.address
+    }
+
+    /// 表示名を返す
+    ///
+    /// ## Returns
+    /// * 表示名が設定されている場合は`Some`
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// 表示名付きの宛先を表現する文字列を返す
+    ///
+    /// ## Returns
+    /// * 表示名がある場合は`"表示名" <アドレス>`、ない場合はアドレスのみ
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::email_address::EmailAddress;
+    /// let email = EmailAddress::parse("yamada@example.com")
+    ///     .unwrap()
+    ///     .with_display_name("山田太郎");
+    /// assert_eq!(email.to_mailbox_string(), "\"山田太郎\" <yamada@example.com>");
+    /// ```
+    pub fn to_mailbox_string(&self) -> String {
+        match &self.display_name {
+            Some(name) => format!(
+                "\"{}\" <{}>",
+                name.replace('\\', "\\\\").replace('"', "\\\""),
+                self.address
+            ),
+            None => self.address.clone(),
+        }
+    }
+}
+
+impl TryFrom<String> for EmailAddress {
+    type Error = AppError;
+
+    fn try_from(value: String) -> AppResult<Self> {
+        Self::parse(value)
+    }
+}
+
+impl From<EmailAddress> for String {
+    fn from(value: EmailAddress) -> Self {
+        value.to_mailbox_string()
     }
 }
+
+/// 表示名の前後の空白と引用符を取り除き、エスケープを解除する
+fn unquote_display_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    let name = name
+        .strip_prefix('"')
+        .and_then(|n| n.strip_suffix('"'))
+        .map(|n| n.replace("\\\"", "\"").replace("\\\\", "\\"))
+        .unwrap_or_else(|| name.to_string());
+    (!name.is_empty()).then_some(name)
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+
+/// 言語を判定する環境変数（先に見つかったものを使用する）
+const LOCALE_ENV_VARS: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// ヘルプ・確認・処理結果などの画面に表示する言語を表現する値オブジェクト
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    /// 日本語
+    #[default]
+    #[serde(rename = "ja")]
+    Japanese,
+    /// 英語
+    #[serde(rename = "en")]
+    English,
+}
+
+impl Language {
+    /// 言語コードを解析する
+    ///
+    /// ## Arguments
+    /// * `code` - 言語コード（`ja`または`en`、`ja_JP.UTF-8`のような地域・文字コード付きも可）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Language>`
+    /// * 失敗時 - 対応していない言語の場合の`Err<AppError>`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::language::Language;
+    /// assert_eq!(Language::parse("ja").unwrap(), Language::Japanese);
+    /// assert_eq!(Language::parse("en_US.UTF-8").unwrap(), Language::English);
+    /// assert!(Language::parse("fr").is_err());
+    /// ```
+    pub fn parse(code: &str) -> AppResult<Self> {
+        let primary = code
+            .trim()
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match primary.as_str() {
+            "ja" => Ok(Self::Japanese),
+            "en" => Ok(Self::English),
+            _ => Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-CONF-014")
+                .with_message(format!("表示言語の指定が不正です。詳細: {code}"))
+                .with_action("ja（日本語）またはen（英語）を指定してください。")),
+        }
+    }
+
+    /// ロケール（例: `LANG`の値）から言語を判定する
+    ///
+    /// 日本語以外のロケールは英語とする
+    ///
+    /// ## Arguments
+    /// * `locale` - ロケール（例: `ja_JP.UTF-8`、`de_DE.UTF-8`、`C`）
+    ///
+    /// ## Returns
+    /// * 判定した言語（空の場合は`None`）
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::language::Language;
+    /// assert_eq!(Language::from_locale("ja_JP.UTF-8"), Some(Language::Japanese));
+    /// assert_eq!(Language::from_locale("de_DE.UTF-8"), Some(Language::English));
+    /// assert_eq!(Language::from_locale(""), None);
+    /// ```
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        if locale.trim().is_empty() {
+            return None;
+        }
+        Some(Self::parse(locale).unwrap_or(Self::English))
+    }
+
+    /// 環境変数`LC_ALL`・`LC_MESSAGES`・`LANG`から言語を判定する
+    ///
+    /// ## Returns
+    /// * 判定した言語（いずれも未設定の場合は日本語）
+    pub fn detect() -> Self {
+        Self::detect_with(|name| std::env::var(name).ok())
+    }
+
+    /// 指定した方法で取得した環境変数から言語を判定する
+    ///
+    /// ## Arguments
+    /// * `lookup` - 環境変数名から値を取得する関数
+    ///
+    /// ## Returns
+    /// * 判定した言語（いずれも未設定の場合は日本語）
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::language::Language;
+    /// let lookup = |name: &str| (name == "LANG").then(|| "en_US.UTF-8".to_string());
+    /// assert_eq!(Language::detect_with(lookup), Language::English);
+    /// assert_eq!(Language::detect_with(|_| None), Language::Japanese);
+    /// ```
+    pub fn detect_with(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        LOCALE_ENV_VARS
+            .iter()
+            .find_map(|name| lookup(name).and_then(|value| Self::from_locale(&value)))
+            .unwrap_or_default()
+    }
+
+    /// 言語コード（`ja`または`en`）を取得する
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Japanese => "ja",
+            Self::English => "en",
+        }
+    }
+}
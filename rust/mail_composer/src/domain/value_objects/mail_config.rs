@@ -1,37 +1,369 @@
-use serde::Deserialize;
-use std::collections::HashMap;
+use crate::domain::value_objects::{
+    mail_objects::{BodyFormat, MailBody},
+    mail_template::{is_placeholder_name, render_template, template_values},
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MailConfig {
     pub mail_types: HashMap<String, MailTypeConfig>,
+    /// 全てのメール種別の件名・本文で使用できる利用者定義の値（`mail_templates.json`の`variables`）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, Value>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MailTypeConfig {
     pub to_names: Vec<String>,
     pub cc_names: Vec<String>,
     pub subject_template: String,
     pub body_template: String,
+    /// 追加ヘッダー（Reply-To、X-Priority、Disposition-Notification-Toなど）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// 本文テンプレートの記述形式（`plain`または`markdown`）
+    #[serde(default, skip_serializing_if = "BodyFormat::is_plain")]
+    pub body_format: BodyFormat,
+    /// 添付ファイルのパス（`{date}`などのプレースホルダーを使用でき、存在するファイルのみ添付する）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
 }
 
 impl MailConfig {
+    /// `mail_templates.json`で利用者定義の値を記述するキー（メール種別名には使用できない）
+    pub const VARIABLES_KEY: &'static str = "variables";
+
     pub fn get_mail_type(&self, mail_type: &str) -> Option<&MailTypeConfig> {
         self.mail_types.get(mail_type)
     }
+
+    /// 言語を指定してメール種別のテンプレートを取得する
+    ///
+    /// `remote_work_start.en`のように`<メール種別>.<言語>`で定義されたテンプレートを優先し、
+    /// 定義されていない場合は言語の指定がないテンプレートを使用する
+    ///
+    /// ## Arguments
+    /// * `mail_type` - メール種別（例: `remote_work_start`）
+    /// * `locale` - 言語（例: `ja`、`en`、`None`の場合は言語の指定がないテンプレートのみ使用する）
+    ///
+    /// ## Returns
+    /// * テンプレート（該当するものがない場合は`None`）
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::mail_config::MailConfig;
+    /// let mail_config: MailConfig = serde_json::from_str(
+    ///     r#"{"mail_types": {
+    ///         "remote_work_start": {"to_names": [], "cc_names": [], "subject_template": "開始", "body_template": ""},
+    ///         "remote_work_start.en": {"to_names": [], "cc_names": [], "subject_template": "Start", "body_template": ""}
+    ///     }}"#,
+    /// )
+    /// .unwrap();
+    /// let subject = |locale| {
+    ///     mail_config
+    ///         .get_localized_mail_type("remote_work_start", locale)
+    ///         .map(|template| template.subject_template.as_str())
+    /// };
+    /// assert_eq!(subject(Some("en")), Some("Start"));
+    /// assert_eq!(subject(Some("fr")), Some("開始"));
+    /// assert_eq!(subject(None), Some("開始"));
+    /// ```
+    pub fn get_localized_mail_type(
+        &self,
+        mail_type: &str,
+        locale: Option<&str>,
+    ) -> Option<&MailTypeConfig> {
+        locale
+            .and_then(|locale| self.get_mail_type(&format!("{mail_type}.{locale}")))
+            .or_else(|| self.get_mail_type(mail_type))
+    }
+
+    /// テンプレートで使用する利用者定義の値を取得する
+    ///
+    /// 同じ名前の値は、アプリケーション設定よりメールテンプレートの定義を優先する
+    ///
+    /// ## Arguments
+    /// * `app_variables` - アプリケーション設定（`app.json`）の`variables`
+    ///
+    /// ## Returns
+    /// * 名前と値の組
+    pub fn template_variables(
+        &self,
+        app_variables: &BTreeMap<String, Value>,
+    ) -> Map<String, Value> {
+        app_variables
+            .iter()
+            .chain(&self.variables)
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    }
 }
 
 impl MailTypeConfig {
-    pub fn format_subject(&self, department: &str, from: &str, time: &str) -> String {
-        self.subject_template
-            .replace("{department}", department)
-            .replace("{from}", from)
-            .replace("{time}", time)
+    /// 件名テンプレートで使用できるプレースホルダー
+    pub const SUBJECT_PLACEHOLDERS: [&'static str; 3] = ["department", "from", "time"];
+    /// 件名・本文テンプレートの両方で使用できる、メールの対象日のプレースホルダー
+    pub const DATE_PLACEHOLDERS: [&'static str; 3] = ["date", "date_jp", "weekday"];
+    /// 差し込み送信で件名・本文テンプレートの両方で使用できる、宛先ごとのプレースホルダー
+    pub const RECIPIENT_PLACEHOLDERS: [&'static str; 2] = ["recipient_name", "recipient_address"];
+    /// 休暇申請（`leave_request`）で件名・本文テンプレートの両方で使用できるプレースホルダー
+    pub const LEAVE_PLACEHOLDERS: [&'static str; 5] = [
+        "leave_start",
+        "leave_end",
+        "leave_period",
+        "leave_days",
+        "reason",
+    ];
+    /// 遅刻・早退連絡（`late_arrival`・`early_leave`）で件名・本文テンプレートの両方で使用できるプレースホルダー
+    pub const NOTICE_PLACEHOLDERS: [&'static str; 2] = ["expected_time", "reason"];
+    /// 出張連絡（`business_trip`）で件名・本文テンプレートの両方で使用できるプレースホルダー
+    pub const TRIP_PLACEHOLDERS: [&'static str; 4] =
+        ["destination", "trip_start", "trip_end", "trip_period"];
+    /// 本文テンプレートで使用できるプレースホルダー
+    pub const BODY_PLACEHOLDERS: [&'static str; 9] = [
+        "work_time",
+        "sessions",
+        "duration",
+        "overtime",
+        "flex_balance",
+        "period",
+        "summary",
+        "daily_table",
+        "tasks_done",
+    ];
+
+    /// 本文テンプレートを外部ファイルから読み込む場合の`body_template`の接頭辞
+    pub const BODY_FILE_PREFIX: &'static str = "file:";
+
+    /// 本文テンプレートを読み込む外部ファイルのパスを取得する
+    ///
+    /// ## Returns
+    /// * `body_template`が`file:<パス>`の形式の場合はパス、それ以外の場合は`None`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::mail_config::MailTypeConfig;
+    /// let template: MailTypeConfig = serde_json::from_str(
+    ///     r#"{"to_names": [], "cc_names": [], "subject_template": "終了",
+    ///         "body_template": "file:templates/remote_work_end.txt"}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(template.body_template_file(), Some("templates/remote_work_end.txt"));
+    /// ```
+    pub fn body_template_file(&self) -> Option<&str> {
+        self.body_template
+            .strip_prefix(Self::BODY_FILE_PREFIX)
+            .map(str::trim)
     }
 
-    pub fn format_body(&self, work_time: Option<&str>) -> String {
-        match work_time {
-            Some(time) => self.body_template.replace("{work_time}", time),
-            None => self.body_template.to_string(),
+    /// 本文テンプレートで指定したプレースホルダーを使用しているかどうか
+    ///
+    /// ## Arguments
+    /// * `name` - プレースホルダー名（例: `tasks_done`）
+    ///
+    /// ## Returns
+    /// * `{name}`形式で使用している場合は`true`
+    pub fn body_uses(&self, name: &str) -> bool {
+        placeholders(&self.body_template).any(|placeholder| placeholder == name)
+    }
+
+    /// テンプレートに含まれる未知のプレースホルダーを取得する
+    ///
+    /// ## Arguments
+    /// * `variables` - 利用者定義の値（ここに含まれる名前は件名・本文のどちらでも使用できる）
+    ///
+    /// ## Returns
+    /// * 置換されずに残る`{name}`形式のプレースホルダーの一覧
+    pub fn unknown_placeholders(&self, variables: &Map<String, Value>) -> Vec<String> {
+        let mut unknown = placeholders(&self.subject_template)
+            .filter(|name| {
+                !Self::SUBJECT_PLACEHOLDERS.contains(name)
+                    && !Self::DATE_PLACEHOLDERS.contains(name)
+                    && !Self::RECIPIENT_PLACEHOLDERS.contains(name)
+                    && !Self::LEAVE_PLACEHOLDERS.contains(name)
+                    && !Self::NOTICE_PLACEHOLDERS.contains(name)
+                    && !Self::TRIP_PLACEHOLDERS.contains(name)
+                    && !variables.contains_key(*name)
+            })
+            .map(|name| format!("{{{name}}}"))
+            .collect::<Vec<_>>();
+        unknown.extend(
+            placeholders(&self.body_template)
+                .filter(|name| {
+                    !Self::BODY_PLACEHOLDERS.contains(name)
+                        && !Self::DATE_PLACEHOLDERS.contains(name)
+                        && !Self::RECIPIENT_PLACEHOLDERS.contains(name)
+                        && !Self::LEAVE_PLACEHOLDERS.contains(name)
+                        && !Self::NOTICE_PLACEHOLDERS.contains(name)
+                        && !Self::TRIP_PLACEHOLDERS.contains(name)
+                        && !variables.contains_key(*name)
+                })
+                .map(|name| format!("{{{name}}}")),
+        );
+        unknown
+    }
+
+    /// 本文の記述形式に応じて、HTMLメール用の本文を作成する
+    ///
+    /// ## Arguments
+    /// * `body` - テンプレートから生成した本文
+    ///
+    /// ## Returns
+    /// * `body_format`が`markdown`の場合はHTMLに変換した本文、`plain`の場合は`None`
+    pub fn html_body(&self, body: &MailBody) -> Option<String> {
+        match self.body_format {
+            BodyFormat::Plain => None,
+            BodyFormat::Markdown => Some(body.to_html()),
         }
     }
-}
\ No newline at end of file
+
+    /// テンプレートの問題を全て取得する
+    ///
+    /// ## Arguments
+    /// * `mail_type` - エラーメッセージに表示するメール種別
+    ///
+    /// ## Returns
+    /// * 検証エラーの一覧（問題がない場合は空）
+    pub fn validation_errors(&self, mail_type: &str) -> Vec<AppError> {
+        let mut errors = Vec::new();
+        let mut push = |message: String, field: &str| {
+            errors.push(
+                AppError::new(ErrorKind::UnprocessableEntity)
+                    .with_code("MC-MAIL-015")
+                    .with_message(message)
+                    .with_action(format!(
+                        "mail_templates.jsonの各メール種別の{field}フィールドを設定してください。"
+                    )),
+            );
+        };
+
+        if self.to_names.iter().all(|name| name.trim().is_empty()) {
+            push(
+                format!("mail type '{mail_type}'の宛先が設定されていません。"),
+                "to_names",
+            );
+        }
+        if self.subject_template.trim().is_empty() {
+            push(
+                format!("mail type '{mail_type}'の件名テンプレートが設定されていません。"),
+                "subject_template",
+            );
+        }
+        if self.body_template.trim().is_empty() {
+            push(
+                format!("mail type '{mail_type}'の本文テンプレートが設定されていません。"),
+                "body_template",
+            );
+        }
+
+        errors
+    }
+
+    /// 件名テンプレートを描画する
+    ///
+    /// ## Arguments
+    /// * `variables` - 利用者定義の値（組み込みのプレースホルダーと同じ名前の値は使用しない）
+    /// * `department` - `{department}`に埋め込む差出部署
+    /// * `from` - `{from}`に埋め込む差出人名
+    /// * `time` - `{time}`に埋め込む時刻
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<String>` (描画後の件名)
+    /// * 失敗時 - テンプレートの構文が不正な場合の`Err<AppError>`
+    pub fn format_subject(
+        &self,
+        variables: &Map<String, Value>,
+        department: &str,
+        from: &str,
+        time: &str,
+    ) -> AppResult<String> {
+        render_template(
+            &self.subject_template,
+            &with_builtins(
+                variables,
+                &[("department", department), ("from", from), ("time", time)],
+            ),
+        )
+    }
+
+    /// 本文テンプレートを描画する
+    ///
+    /// ## Arguments
+    /// * `variables` - 利用者定義の値（組み込みのプレースホルダーと同じ名前の値は使用しない）
+    /// * `values` - プレースホルダー名と値の組（指定されなかった`{name}`形式のプレースホルダーはそのまま残す）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<String>` (描画後の本文)
+    /// * 失敗時 - テンプレートの構文が不正な場合の`Err<AppError>`
+    pub fn format_body(
+        &self,
+        variables: &Map<String, Value>,
+        values: &[(&str, &str)],
+    ) -> AppResult<String> {
+        render_template(&self.body_template, &with_builtins(variables, values))
+    }
+
+    /// 添付ファイルのパスのテンプレートを描画する
+    ///
+    /// パスの`{date}`はディレクトリの区切りと区別するため`2024-06-03`の形式で埋め込む
+    ///
+    /// ## Arguments
+    /// * `variables` - 利用者定義の値
+    /// * `date` - メールの対象日
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<PathBuf>>` (描画後のパス。ファイルの存在は確認しない)
+    /// * 失敗時 - テンプレートの構文が不正な場合の`Err<AppError>`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use mail_composer::domain::value_objects::mail_config::MailTypeConfig;
+    /// use serde_json::Map;
+    /// use std::path::PathBuf;
+    /// let template: MailTypeConfig = serde_json::from_str(
+    ///     r#"{"to_names": [], "cc_names": [], "subject_template": "終了", "body_template": "",
+    ///         "attachments": ["reports/daily_{date}.xlsx"]}"#,
+    /// )
+    /// .unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+    /// let paths = template.format_attachments(&Map::new(), date).unwrap();
+    /// assert_eq!(paths, vec![PathBuf::from("reports/daily_2024-06-03.xlsx")]);
+    /// ```
+    pub fn format_attachments(
+        &self,
+        variables: &Map<String, Value>,
+        date: NaiveDate,
+    ) -> AppResult<Vec<PathBuf>> {
+        let values = with_builtins(variables, &[("date", &date.format("%Y-%m-%d").to_string())]);
+        self.attachments
+            .iter()
+            .map(|path| render_template(path, &values).map(PathBuf::from))
+            .collect()
+    }
+}
+
+/// 利用者定義の値に組み込みのプレースホルダーの値を加える（同じ名前は組み込みの値を優先する）
+fn with_builtins(variables: &Map<String, Value>, values: &[(&str, &str)]) -> Map<String, Value> {
+    let mut merged = variables.clone();
+    merged.extend(template_values(values));
+    merged
+}
+
+/// テンプレートから`{name}`形式のプレースホルダー名を取り出す
+fn placeholders(template: &str) -> impl Iterator<Item = &str> {
+    template.split('{').skip(1).filter_map(|rest| {
+        let (name, _) = rest.split_once('}')?;
+        is_placeholder_name(name).then_some(name)
+    })
+}
@@ -1,4 +1,7 @@
+use chrono::{Duration, NaiveTime, Timelike};
+use pulldown_cmark::{Event, Options, Parser};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use share::error::{
     app_error::{AppError, AppResult},
     kind::ErrorKind,
@@ -21,6 +24,7 @@ impl Subject {
         let subject = subject.into();
         if subject.trim().is_empty() {
             return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-MAIL-016")
                 .with_message("件名が空です。")
                 .with_action("適切な件名を設定してください。"));
         }
@@ -58,42 +62,178 @@ impl MailBody {
     pub fn to_crlf(&self) -> String {
         self.0.replace('\n', "\r\n")
     }
+
+    /// Markdownとして解釈し、HTMLに変換する
+    ///
+    /// 段落内の改行はプレーンテキストの本文と同じ見た目になるように`<br />`に変換する
+    ///
+    /// ## Returns
+    /// * HTML文字列
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::mail_objects::MailBody;
+    /// let html = MailBody::new("本日の作業:\n\n- 資料作成\n- **レビュー**").to_html();
+    /// assert!(html.contains("<li>資料作成</li>"));
+    /// assert!(html.contains("<strong>レビュー</strong>"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let events = Parser::new_ext(&self.0, options).map(|event| match event {
+            Event::SoftBreak => Event::HardBreak,
+            event => event,
+        });
+
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, events);
+        html
+    }
+}
+
+/// 本文テンプレートの記述形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BodyFormat {
+    /// プレーンテキスト
+    #[default]
+    Plain,
+    /// Markdown（HTMLメールに対応したメールクライアントではHTMLに変換して送信する）
+    Markdown,
+}
+
+impl BodyFormat {
+    /// プレーンテキストかどうか
+    pub fn is_plain(&self) -> bool {
+        *self == BodyFormat::Plain
+    }
+}
+
+/// メールの優先度を表現する値オブジェクト
+///
+/// `X-Priority`ヘッダーの値（1が最高、5が最低）に対応する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MailPriority {
+    Highest,
+    High,
+    Normal,
+    Low,
+    Lowest,
+}
+
+impl MailPriority {
+    /// `X-Priority`ヘッダーに設定する文字列表現に変換する
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::mail_objects::MailPriority;
+    /// assert_eq!(MailPriority::High.as_header_value(), "2 (High)");
+    /// ```
+    pub const fn as_header_value(&self) -> &'static str {
+        match self {
+            MailPriority::Highest => "1 (Highest)",
+            MailPriority::High => "2 (High)",
+            MailPriority::Normal => "3 (Normal)",
+            MailPriority::Low => "4 (Low)",
+            MailPriority::Lowest => "5 (Lowest)",
+        }
+    }
 }
 
 /// 時刻を表現する値オブジェクト（HH:MM形式）
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct WorkTime(String);
+///
+/// 保存時や表示時はHH:MM形式の文字列として扱う
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct WorkTime {
+    time: NaiveTime,
+    text: String,
+}
 
 impl WorkTime {
     /// 時刻を作成する
     ///
     /// ## Arguments
-    /// * `time` - 時刻文字列（HH:MM形式）
+    /// * `time` - 時刻文字列（HH:MM形式、時と分はそれぞれ2桁）
     ///
     /// ## Returns
     /// * 成功時 - `Ok<WorkTime>`
-    /// * 失敗時 - `Err<AppError>`
+    /// * 失敗時 - 形式が不正、または存在しない時刻の場合の`Err<AppError>`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::mail_objects::WorkTime;
+    /// assert_eq!(WorkTime::new("09:05").unwrap().as_str(), "09:05");
+    /// assert!(WorkTime::new("25:99").is_err());
+    /// assert!(WorkTime::new("9:5").is_err());
+    /// ```
     pub fn new(time: impl Into<String>) -> AppResult<Self> {
-        let time = time.into();
-        // 簡単なHH:MM形式の検証
-        if !time.matches(':').count() == 1 || time.len() != 5 {
-            return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
-                .with_message("時刻の形式が不正です。")
-                .with_action("HH:MM形式で時刻を指定してください。"));
+        let text = time.into();
+        NaiveTime::parse_from_str(&text, "%H:%M")
+            .ok()
+            // chronoは1桁の時・分も受け付けるため、HH:MM形式に戻して一致するか確認する
+            .filter(|time| time.format("%H:%M").to_string() == text)
+            .map(Self::from_time)
+            .ok_or_else(|| {
+                AppError::new(ErrorKind::UnavailableForLegalReasons)
+                    .with_code("MC-MAIL-017")
+                    .with_message(format!("時刻の形式が不正です。詳細: {text}"))
+                    .with_action("HH:MM形式（00:00〜23:59）で時刻を指定してください。")
+            })
+    }
+
+    /// 時刻から作成する（秒以下は切り捨てる）
+    ///
+    /// ## Arguments
+    /// * `time` - 時刻
+    ///
+    /// ## Returns
+    /// * WorkTimeのインスタンス
+    pub fn from_time(time: NaiveTime) -> Self {
+        let time = time
+            .with_second(0)
+            .and_then(|time| time.with_nanosecond(0))
+            .unwrap_or(time);
+        Self {
+            text: time.format("%H:%M").to_string(),
+            time,
         }
-        Ok(Self(time))
     }
 
     /// 現在時刻を取得する
     pub fn now() -> AppResult<Self> {
         use chrono::Local;
-        let now = Local::now().format("%H:%M").to_string();
-        Self::new(now)
+        Ok(Self::from_time(Local::now().time()))
+    }
+
+    /// 時刻を取得する
+    pub fn time(&self) -> NaiveTime {
+        self.time
+    }
+
+    /// 0時からの経過分を取得する
+    pub fn minutes_of_day(&self) -> i64 {
+        i64::from(self.time.hour() * 60 + self.time.minute())
     }
 
-    /// 時刻文字列を取得する
+    /// 時刻文字列（HH:MM形式）を取得する
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.text
+    }
+}
+
+impl TryFrom<String> for WorkTime {
+    type Error = AppError;
+
+    fn try_from(time: String) -> AppResult<Self> {
+        Self::new(time)
+    }
+}
+
+impl From<WorkTime> for String {
+    fn from(time: WorkTime) -> Self {
+        time.text
     }
 }
 
@@ -127,8 +267,54 @@ impl WorkTimeRange {
         &self.end
     }
 
-    /// 作業時間を文字列として表現する
-    pub fn to_string(&self) -> String {
-        format!("{}-{}", self.start.as_str(), self.end.as_str())
+    /// 終了時刻が開始時刻より前（日付をまたぐ勤務）かどうか
+    pub fn crosses_midnight(&self) -> bool {
+        self.end < self.start
     }
-}
\ No newline at end of file
+
+    /// 作業時間を計算する
+    ///
+    /// 終了時刻が開始時刻より前の場合は、翌日の終了時刻として扱う
+    ///
+    /// ## Returns
+    /// * 作業時間
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use chrono::Duration;
+    /// use mail_composer::domain::value_objects::mail_objects::{WorkTime, WorkTimeRange};
+    /// let night = WorkTimeRange::new(WorkTime::new("22:00").unwrap(), WorkTime::new("06:30").unwrap());
+    /// assert!(night.crosses_midnight());
+    /// assert_eq!(night.duration(), Duration::minutes(510));
+    /// ```
+    pub fn duration(&self) -> Duration {
+        let duration = self.end.time() - self.start.time();
+        if self.crosses_midnight() {
+            duration + Duration::days(1)
+        } else {
+            duration
+        }
+    }
+
+    /// 作業時間を分単位で計算する
+    ///
+    /// ## Returns
+    /// * 作業時間（分）
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::mail_objects::{WorkTime, WorkTimeRange};
+    /// let range = WorkTimeRange::new(WorkTime::new("09:15").unwrap(), WorkTime::new("18:00").unwrap());
+    /// assert_eq!(range.duration_minutes(), 525);
+    /// ```
+    pub fn duration_minutes(&self) -> i64 {
+        self.duration().num_minutes()
+    }
+}
+
+impl fmt::Display for WorkTimeRange {
+    /// 作業時間を`開始-終了`の形式で表現する
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start.as_str(), self.end.as_str())
+    }
+}
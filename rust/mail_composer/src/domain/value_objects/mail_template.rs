@@ -0,0 +1,211 @@
+use chrono::{Datelike, NaiveDate};
+use serde_json::{Map, Value};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use tera::{Context, Tera};
+
+/// 件名・本文テンプレートをTera形式で描画する
+///
+/// `{{ name }}`による埋め込みに加えて、`{% if %}`や`{% for %}`による条件分岐と繰り返し、
+/// `upper`や`escape`などのフィルターを使用できる。
+/// 従来の`{name}`形式のプレースホルダーは、値が指定されたものだけを`{{ name }}`として扱い、
+/// 値が指定されなかったものはそのまま残す
+///
+/// ## Arguments
+/// * `template` - テンプレート文字列
+/// * `values` - プレースホルダー名と値の組
+///
+/// ## Returns
+/// * 成功時 - `Ok<String>` (描画した文字列)
+/// * 失敗時 - テンプレートの構文が不正、または未定義の値を参照した場合の`Err<AppError>`
+///
+/// ## Examples
+/// ```rust
+/// use mail_composer::domain::value_objects::mail_template::render_template;
+/// use serde_json::{Map, json};
+/// let mut values = Map::new();
+/// values.insert("from".to_string(), json!("山田"));
+/// values.insert("tasks".to_string(), json!(["設計", "レビュー"]));
+/// let rendered = render_template(
+///     "{from}です。{% for task in tasks %}・{{ task }}{% endfor %}{unknown}",
+///     &values,
+/// )
+/// .unwrap();
+/// assert_eq!(rendered, "山田です。・設計・レビュー{unknown}");
+/// assert!(render_template("{% if %}", &values).is_err());
+/// ```
+pub fn render_template(template: &str, values: &Map<String, Value>) -> AppResult<String> {
+    let template = to_tera_syntax(template, |name| values.contains_key(name));
+    let context = Context::from_serialize(values).map_err(template_error)?;
+    // メールはプレーンテキストのため自動エスケープは行わず、必要な箇所はescapeフィルターで指定する
+    Tera::one_off(&template, &context, false).map_err(template_error)
+}
+
+/// プレースホルダー名と文字列の値の組から、テンプレートに渡す値を作成する
+///
+/// ## Arguments
+/// * `values` - プレースホルダー名と値の組
+///
+/// ## Returns
+/// * テンプレートに渡す値
+pub fn template_values(values: &[(&str, &str)]) -> Map<String, Value> {
+    values
+        .iter()
+        .map(|(name, value)| (name.to_string(), Value::String(value.to_string())))
+        .collect()
+}
+
+/// 日付から`{date}`・`{date_jp}`・`{weekday}`に埋め込む値を作成する
+///
+/// ## Arguments
+/// * `date` - メールの対象日
+///
+/// ## Returns
+/// * テンプレートに渡す値
+///
+/// ## Examples
+/// ```rust
+/// use chrono::NaiveDate;
+/// use mail_composer::domain::value_objects::mail_template::date_values;
+/// let values = date_values(NaiveDate::from_ymd_opt(2024, 6, 3).unwrap());
+/// assert_eq!(values["date"], "2024/06/03");
+/// assert_eq!(values["date_jp"], "2024年6月3日");
+/// assert_eq!(values["weekday"], "月");
+/// ```
+pub fn date_values(date: NaiveDate) -> Map<String, Value> {
+    template_values(&[
+        ("date", &date.format("%Y/%m/%d").to_string()),
+        (
+            "date_jp",
+            &format!("{}年{}月{}日", date.year(), date.month(), date.day()),
+        ),
+        ("weekday", weekday_name(date)),
+    ])
+}
+
+/// 日付を曜日付きの表記（例: `2024/06/03（月）`）にする
+///
+/// ## Arguments
+/// * `date` - 日付
+///
+/// ## Returns
+/// * 曜日付きの日付
+///
+/// ## Examples
+/// ```rust
+/// use chrono::NaiveDate;
+/// use mail_composer::domain::value_objects::mail_template::format_date_with_weekday;
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+/// assert_eq!(format_date_with_weekday(date), "2024/06/03（月）");
+/// ```
+pub fn format_date_with_weekday(date: NaiveDate) -> String {
+    format!("{}（{}）", date.format("%Y/%m/%d"), weekday_name(date))
+}
+
+/// 期間を曜日付きの表記（例: `2024/06/03（月）〜2024/06/05（水）`）にする
+///
+/// ## Arguments
+/// * `start` - 期間の初日
+/// * `end` - 期間の最終日
+///
+/// ## Returns
+/// * 曜日付きの期間（初日と最終日が同じ場合は1日分の日付）
+///
+/// ## Examples
+/// ```rust
+/// use chrono::NaiveDate;
+/// use mail_composer::domain::value_objects::mail_template::format_date_range;
+/// let start = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 6, 5).unwrap();
+/// assert_eq!(format_date_range(start, end), "2024/06/03（月）〜2024/06/05（水）");
+/// assert_eq!(format_date_range(start, start), "2024/06/03（月）");
+/// ```
+pub fn format_date_range(start: NaiveDate, end: NaiveDate) -> String {
+    if start == end {
+        format_date_with_weekday(start)
+    } else {
+        format!(
+            "{}〜{}",
+            format_date_with_weekday(start),
+            format_date_with_weekday(end)
+        )
+    }
+}
+
+/// 曜日の日本語表記（例: `月`）
+///
+/// ## Arguments
+/// * `date` - 日付
+///
+/// ## Returns
+/// * 曜日を表す1文字
+pub fn weekday_name(date: NaiveDate) -> &'static str {
+    ["月", "火", "水", "木", "金", "土", "日"][date.weekday().num_days_from_monday() as usize]
+}
+
+/// 従来の`{name}`形式のプレースホルダーのうち、値があるものをTeraの`{{ name }}`形式に変換する
+///
+/// Teraの`{{ }}`・`{% %}`・`{# #}`の中はそのまま残す
+fn to_tera_syntax(template: &str, is_known: impl Fn(&str) -> bool) -> String {
+    let mut converted = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        converted.push_str(&rest[..open]);
+        rest = &rest[open..];
+
+        let closing = match rest.as_bytes().get(1) {
+            Some(b'{') => Some("}}"),
+            Some(b'%') => Some("%}"),
+            Some(b'#') => Some("#}"),
+            _ => None,
+        };
+        if let Some(closing) = closing {
+            let end = rest[2..]
+                .find(closing)
+                .map_or(rest.len(), |i| i + 2 + closing.len());
+            converted.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        match rest[1..].split_once('}') {
+            Some((name, after)) if is_placeholder_name(name) && is_known(name) => {
+                converted.push_str(&format!("{{{{ {name} }}}}"));
+                rest = after;
+            }
+            _ => {
+                converted.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    converted.push_str(rest);
+    converted
+}
+
+/// プレースホルダー名として使用できる文字列か判定する
+pub(crate) fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// テンプレートの描画に失敗した場合のエラーを作成する
+fn template_error(e: tera::Error) -> AppError {
+    // Teraのエラーは原因をsourceに持つため、メッセージに含めて表示する
+    let mut detail = e.to_string();
+    let mut source = std::error::Error::source(&e);
+    while let Some(cause) = source {
+        detail.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+    AppError::new(ErrorKind::UnprocessableEntity)
+        .with_code("MC-MAIL-018")
+        .with_message(format!(
+            "メールテンプレートの描画に失敗しました。詳細: {detail}"
+        ))
+        .with_action(
+            "mail_templates.jsonのテンプレートの構文とプレースホルダー名を確認してください。",
+        )
+        .with_source(e)
+}
@@ -1,4 +1,12 @@
+pub mod address_query;
 pub mod app_configuration;
+pub mod cron_schedule;
+pub mod effective_configuration;
 pub mod email_address;
+pub mod language;
 pub mod mail_config;
 pub mod mail_objects;
+pub mod mail_template;
+pub mod recipient_overrides;
+pub mod time_zone;
+pub mod work_hours;
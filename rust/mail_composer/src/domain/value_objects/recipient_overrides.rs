@@ -0,0 +1,106 @@
+/// 1回の送信に限り、テンプレートの宛先を置き換える・追加する指定を表現する値オブジェクト
+///
+/// 宛先はアドレスブックの名前（個人またはグループ）で指定する。
+/// 置き換えを指定しない場合は、テンプレートの宛先の後ろに追加する
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecipientOverrides {
+    to: Vec<String>,
+    cc: Vec<String>,
+    replace: bool,
+}
+
+impl RecipientOverrides {
+    /// 宛先の指定を持たないRecipientOverridesを作成する
+    ///
+    /// ## Returns
+    /// * RecipientOverridesのインスタンス
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Toに指定する名前を追加する
+    ///
+    /// ## Arguments
+    /// * `names` - アドレスブックの名前
+    ///
+    /// ## Returns
+    /// * 名前が追加されたRecipientOverrides
+    pub fn with_to(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.to.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Ccに指定する名前を追加する
+    ///
+    /// ## Arguments
+    /// * `names` - アドレスブックの名前
+    ///
+    /// ## Returns
+    /// * 名前が追加されたRecipientOverrides
+    pub fn with_cc(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.cc.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// 指定した宛先でテンプレートの宛先を置き換えるかを指定する
+    ///
+    /// ## Arguments
+    /// * `replace` - trueの場合、名前を指定したTo・Ccはテンプレートの宛先を使用しない
+    ///
+    /// ## Returns
+    /// * 置き換えの指定が設定されたRecipientOverrides
+    pub fn with_replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+
+    /// テンプレートのToに指定を反映した名前を取得する
+    ///
+    /// ## Arguments
+    /// * `template_names` - テンプレートのToの名前
+    ///
+    /// ## Returns
+    /// * 指定を反映した名前（重複は除く）
+    ///
+    /// ## Examples
+    /// ```
+    /// use mail_composer::domain::value_objects::recipient_overrides::RecipientOverrides;
+    ///
+    /// let template = vec!["部長".to_string(), "課長".to_string()];
+    /// let overrides = RecipientOverrides::new().with_to(["佐藤", "課長"]);
+    /// assert_eq!(overrides.apply_to(&template), vec!["部長", "課長", "佐藤"]);
+    ///
+    /// let overrides = overrides.with_replace(true);
+    /// assert_eq!(overrides.apply_to(&template), vec!["佐藤", "課長"]);
+    /// ```
+    pub fn apply_to(&self, template_names: &[String]) -> Vec<String> {
+        self.apply(template_names, &self.to)
+    }
+
+    /// テンプレートのCcに指定を反映した名前を取得する
+    ///
+    /// ## Arguments
+    /// * `template_names` - テンプレートのCcの名前
+    ///
+    /// ## Returns
+    /// * 指定を反映した名前（重複は除く）
+    pub fn apply_cc(&self, template_names: &[String]) -> Vec<String> {
+        self.apply(template_names, &self.cc)
+    }
+
+    /// テンプレートの名前に指定した名前を追加する（置き換えの場合は指定した名前のみ）
+    fn apply(&self, template_names: &[String], names: &[String]) -> Vec<String> {
+        let base = if self.replace && !names.is_empty() {
+            &[][..]
+        } else {
+            template_names
+        };
+        let mut merged: Vec<String> = Vec::new();
+        for name in base.iter().chain(names) {
+            if !merged.contains(name) {
+                merged.push(name.clone());
+            }
+        }
+        merged
+    }
+}
@@ -0,0 +1,134 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+
+/// 作業時刻の記録と表示に使用するタイムゾーンを表現する値オブジェクト
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WorkTimeZone {
+    /// 実行環境のローカルタイムゾーン
+    #[default]
+    Local,
+    /// IANAタイムゾーン名で指定したタイムゾーン（例: `Asia/Tokyo`）
+    Named(Tz),
+}
+
+impl WorkTimeZone {
+    /// 実行環境のローカルタイムゾーンを表す設定値
+    pub const LOCAL: &'static str = "local";
+
+    /// 設定値からタイムゾーンを作成する
+    ///
+    /// ## Arguments
+    /// * `name` - `local`、またはIANAタイムゾーン名（例: `Asia/Tokyo`、`UTC`）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkTimeZone>`
+    /// * 失敗時 - 未知のタイムゾーン名の場合の`Err<AppError>`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::time_zone::WorkTimeZone;
+    /// assert_eq!(WorkTimeZone::parse("local").unwrap(), WorkTimeZone::Local);
+    /// assert_eq!(WorkTimeZone::parse("Asia/Tokyo").unwrap().name(), "Asia/Tokyo");
+    /// assert!(WorkTimeZone::parse("Mars/Olympus").is_err());
+    /// ```
+    pub fn parse(name: &str) -> AppResult<Self> {
+        let name = name.trim();
+        if name.is_empty() || name.eq_ignore_ascii_case(Self::LOCAL) {
+            return Ok(Self::Local);
+        }
+        name.parse::<Tz>().map(Self::Named).map_err(|_| {
+            AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-CONF-015")
+                .with_message(format!("タイムゾーンが不正です。詳細: {name}"))
+                .with_action(
+                    "config.jsonのtimezoneフィールドに'local'またはIANAタイムゾーン名（例: Asia/Tokyo）を設定してください。",
+                )
+        })
+    }
+
+    /// 設定値として表す名前を取得する
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Local => Self::LOCAL,
+            Self::Named(tz) => tz.name(),
+        }
+    }
+
+    /// このタイムゾーンでの現在日時を取得する
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        self.localize(Utc::now().fixed_offset())
+    }
+
+    /// このタイムゾーンでの今日の日付を取得する
+    pub fn today(&self) -> NaiveDate {
+        self.now().date_naive()
+    }
+
+    /// 日時をこのタイムゾーンでの日時に変換する
+    ///
+    /// ## Arguments
+    /// * `instant` - 変換する日時（記録時のUTCオフセット付き）
+    ///
+    /// ## Returns
+    /// * 同じ時点を、このタイムゾーンのUTCオフセットで表した日時
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use chrono::DateTime;
+    /// use mail_composer::domain::value_objects::time_zone::WorkTimeZone;
+    /// let utc = DateTime::parse_from_rfc3339("2025-04-01T00:30:00+00:00").unwrap();
+    /// let tokyo = WorkTimeZone::parse("Asia/Tokyo").unwrap().localize(utc);
+    /// assert_eq!(tokyo.to_rfc3339(), "2025-04-01T09:30:00+09:00");
+    /// ```
+    pub fn localize(&self, instant: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        match self {
+            Self::Local => instant.with_timezone(&Local).fixed_offset(),
+            Self::Named(tz) => instant.with_timezone(tz).fixed_offset(),
+        }
+    }
+
+    /// このタイムゾーンでの日時（UTCオフセットなし）を、UTCオフセット付きの日時に変換する
+    ///
+    /// 夏時間の切り替えで同じ日時が2回ある場合は、早い方を使用する
+    ///
+    /// ## Arguments
+    /// * `local` - このタイムゾーンでの日時
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<DateTime<FixedOffset>>`
+    /// * 失敗時 - 夏時間の切り替えで存在しない日時の場合の`Err<AppError>`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use mail_composer::domain::value_objects::time_zone::WorkTimeZone;
+    /// let local = NaiveDateTime::parse_from_str("2025-04-01 09:30", "%Y-%m-%d %H:%M").unwrap();
+    /// let tokyo = WorkTimeZone::parse("Asia/Tokyo").unwrap().resolve_local(local).unwrap();
+    /// assert_eq!(tokyo.to_rfc3339(), "2025-04-01T09:30:00+09:00");
+    /// ```
+    pub fn resolve_local(&self, local: NaiveDateTime) -> AppResult<DateTime<FixedOffset>> {
+        let resolved = match self {
+            Self::Local => Local
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|at| at.fixed_offset()),
+            Self::Named(tz) => tz
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|at| at.fixed_offset()),
+        };
+        resolved.ok_or_else(|| {
+            AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-CONF-016")
+                .with_message(format!(
+                    "タイムゾーン'{}'に存在しない日時です。詳細: {local}",
+                    self.name()
+                ))
+                .with_action("夏時間の切り替え時刻を避けて指定してください。")
+        })
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::{Datelike, Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{collections::BTreeMap, str::FromStr};
+
+/// 所定労働時間（1日あたり）を表現する値オブジェクト
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StandardWorkHours {
+    daily_minutes: i64,
+}
+
+impl StandardWorkHours {
+    /// 設定されていない場合の1日の所定労働時間
+    pub const DEFAULT_DAILY_HOURS: f64 = 8.0;
+
+    /// 時間数から所定労働時間を作成する
+    ///
+    /// ## Arguments
+    /// * `hours` - 1日の所定労働時間（例: `7.5`）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<StandardWorkHours>`
+    /// * 失敗時 - 0以下または24を超える場合の`Err<AppError>`
+    pub fn from_hours(hours: f64) -> AppResult<Self> {
+        if hours.is_nan() || hours <= 0.0 || hours > 24.0 {
+            return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-WORK-012")
+                .with_message(format!("1日の所定労働時間が不正です。詳細: {hours}"))
+                .with_action(
+                    "config.jsonのstandard_daily_hoursフィールドに0より大きく24以下の時間数を設定してください。",
+                ));
+        }
+        Ok(Self {
+            daily_minutes: (hours * 60.0).round() as i64,
+        })
+    }
+
+    /// 1日の所定労働時間（分）を取得する
+    pub fn daily_minutes(&self) -> i64 {
+        self.daily_minutes
+    }
+
+    /// 1日の残業時間（分）を計算する
+    ///
+    /// ## Arguments
+    /// * `worked_minutes` - その日の作業時間（分）
+    ///
+    /// ## Returns
+    /// * 所定労働時間を超えた分（超えていない場合は0）
+    pub fn daily_overtime(&self, worked_minutes: i64) -> i64 {
+        (worked_minutes - self.daily_minutes).max(0)
+    }
+
+    /// 週ごとの残業時間（分）を計算する
+    ///
+    /// 各日の残業時間を、その日を含む週（月曜始まり）ごとに合計する
+    ///
+    /// ## Arguments
+    /// * `worked` - 日付とその日の作業時間（分）の組
+    ///
+    /// ## Returns
+    /// * 週の初日（月曜日）ごとの残業時間
+    pub fn weekly_overtime(
+        &self,
+        worked: impl IntoIterator<Item = (NaiveDate, i64)>,
+    ) -> BTreeMap<NaiveDate, i64> {
+        let mut weeks = BTreeMap::new();
+        for (date, minutes) in worked {
+            let monday = date - Days::new(u64::from(date.weekday().num_days_from_monday()));
+            *weeks.entry(monday).or_insert(0) += self.daily_overtime(minutes);
+        }
+        weeks
+    }
+}
+
+impl Default for StandardWorkHours {
+    fn default() -> Self {
+        Self {
+            daily_minutes: (Self::DEFAULT_DAILY_HOURS * 60.0) as i64,
+        }
+    }
+}
+
+/// 分数をH:MM形式に整形する
+///
+/// ## Arguments
+/// * `minutes` - 分数（負の値は`-`を付けて表す）
+///
+/// ## Returns
+/// * H:MM形式の文字列
+///
+/// ## Examples
+/// ```rust
+/// use mail_composer::domain::value_objects::work_hours::format_minutes;
+/// assert_eq!(format_minutes(545), "9:05");
+/// assert_eq!(format_minutes(-30), "-0:30");
+/// ```
+pub fn format_minutes(minutes: i64) -> String {
+    let sign = if minutes < 0 { "-" } else { "" };
+    let minutes = minutes.abs();
+    format!("{sign}{}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// 作業時間の表示形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DurationFormat {
+    /// H:MM形式（例: `9:30`）
+    #[default]
+    Clock,
+    /// 日本語表記（例: `9時間30分`）
+    Japanese,
+    /// 10進数の時間数（例: `9.5h`）
+    DecimalHours,
+}
+
+impl DurationFormat {
+    /// 設定ファイルで指定できる表示形式の名前
+    pub const NAMES: [&'static str; 3] = ["clock", "japanese", "decimal_hours"];
+
+    /// 分数を表示形式に従って整形する
+    ///
+    /// ## Arguments
+    /// * `minutes` - 分数
+    ///
+    /// ## Returns
+    /// * 整形された文字列
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use mail_composer::domain::value_objects::work_hours::DurationFormat;
+    /// assert_eq!(DurationFormat::Clock.format(570), "9:30");
+    /// assert_eq!(DurationFormat::Japanese.format(570), "9時間30分");
+    /// assert_eq!(DurationFormat::Japanese.format(540), "9時間");
+    /// assert_eq!(DurationFormat::DecimalHours.format(570), "9.5h");
+    /// assert_eq!(DurationFormat::DecimalHours.format(545), "9.08h");
+    /// ```
+    pub fn format(&self, minutes: i64) -> String {
+        match self {
+            DurationFormat::Clock => format_minutes(minutes),
+            DurationFormat::Japanese => {
+                let sign = if minutes < 0 { "-" } else { "" };
+                let (hours, minutes) = (minutes.abs() / 60, minutes.abs() % 60);
+                match (hours, minutes) {
+                    (0, minutes) => format!("{sign}{minutes}分"),
+                    (hours, 0) => format!("{sign}{hours}時間"),
+                    (hours, minutes) => format!("{sign}{hours}時間{minutes}分"),
+                }
+            }
+            DurationFormat::DecimalHours => {
+                let hours = format!("{:.2}", minutes as f64 / 60.0);
+                format!("{}h", hours.trim_end_matches('0').trim_end_matches('.'))
+            }
+        }
+    }
+}
+
+impl FromStr for DurationFormat {
+    type Err = AppError;
+
+    fn from_str(name: &str) -> AppResult<Self> {
+        match name.trim() {
+            "clock" => Ok(DurationFormat::Clock),
+            "japanese" => Ok(DurationFormat::Japanese),
+            "decimal_hours" => Ok(DurationFormat::DecimalHours),
+            other => Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-WORK-013")
+                .with_message(format!("作業時間の表示形式が不正です。詳細: {other}"))
+                .with_action(format!(
+                    "次のいずれかを指定してください: {}",
+                    Self::NAMES.join(", ")
+                ))),
+        }
+    }
+}
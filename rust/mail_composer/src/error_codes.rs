@@ -0,0 +1,396 @@
+/// mail_composerが使用するエラーコードと、そのエラーの概要の一覧
+///
+/// エラーコードは`MC-<分類>-<連番>`の形式とし、1つのコードは1箇所のエラーにのみ割り当てる。
+/// 新しいエラーを追加する場合は、分類ごとの未使用の連番をこの一覧に登録してから使用する
+pub const ERROR_CODES: &[(&str, &str)] = &[
+    ("MC-ADDR-001", "重複する名前が見つかりました。"),
+    ("MC-ADDR-002", "エントリの形式が正しくありません。"),
+    ("MC-ADDR-003", "別名'…'が'…'と'…'で重複しています。"),
+    (
+        "MC-ADDR-004",
+        "'…'は有効期間（…〜…）外のため使用できません。",
+    ),
+    ("MC-ADDR-005", "'…'はAddressBookに登録されていません。"),
+    (
+        "MC-ADDR-006",
+        "指定された名前に対応するメールアドレスが見つかりません。",
+    ),
+    (
+        "MC-ADDR-007",
+        "'…'はグループのため、単一のメールアドレスに解決できません。",
+    ),
+    ("MC-ADDR-008", "グループが循環しています。"),
+    ("MC-ADDR-009", "グループ'…'のメンバー'…'を解決できません。"),
+    (
+        "MC-ADDR-010",
+        "このアドレスブックはエントリの一覧取得に対応していません。",
+    ),
+    ("MC-ADDR-011", "メールアドレスの形式が不正です。"),
+    (
+        "MC-ADDR-012",
+        "AddressBookファイルの更新日時の取得に失敗しました。",
+    ),
+    (
+        "MC-ADDR-013",
+        "'…'はいずれのアドレスブックにも見つかりません。参照したアドレスブック: …",
+    ),
+    (
+        "MC-ADDR-014",
+        "AddressBookファイルの読み込みに失敗しました。",
+    ),
+    ("MC-ADDR-015", "AddressBookの解析に失敗しました。"),
+    (
+        "MC-ADDR-016",
+        "保存先のAddressBookファイルが指定されていません。",
+    ),
+    (
+        "MC-ADDR-017",
+        "AddressBookファイルの書き込みに失敗しました。",
+    ),
+    (
+        "MC-ADDR-018",
+        "LDAPキャッシュファイルの読み込みに失敗しました。",
+    ),
+    (
+        "MC-ADDR-019",
+        "LDAPキャッシュファイルの解析に失敗しました。",
+    ),
+    (
+        "MC-ADDR-020",
+        "LDAPキャッシュファイルの書き込みに失敗しました。",
+    ),
+    ("MC-ADDR-021", "ldapsearchの起動に失敗しました。"),
+    ("MC-ADDR-022", "LDAPの検索に失敗しました。"),
+    (
+        "MC-ADDR-023",
+        "ディレクトリに'…'に対応するメールアドレスが見つかりません。",
+    ),
+    (
+        "MC-ADDR-024",
+        "ディレクトリに'…'に該当するエントリが複数見つかりました。",
+    ),
+    ("MC-ADDR-025", "連絡先CSVの読み込みに失敗しました。"),
+    ("MC-ADDR-026", "連絡先CSVの解析に失敗しました。"),
+    (
+        "MC-ADDR-027",
+        "連絡先CSVに電子メールアドレスの列が見つかりません。",
+    ),
+    ("MC-ADDR-028", "Thunderbirdのアドレス帳を開けませんでした。"),
+    (
+        "MC-ADDR-029",
+        "Thunderbirdのアドレス帳の読み込みに失敗しました。",
+    ),
+    ("MC-ADDR-030", "vCardファイルの読み込みに失敗しました。"),
+    ("MC-ADDR-031", "シート'…'の読み込みに失敗しました。"),
+    ("MC-ADDR-032", "シート'…'に列'…'が見つかりません。"),
+    ("MC-CLI-001", "診断で問題が見つかりました。"),
+    ("MC-CLI-002", "設定のJSONへの変換に失敗しました。"),
+    ("MC-CLI-003", "設定に…件の問題があります。"),
+    ("MC-CLI-004", "設定ファイルがありません。"),
+    ("MC-CLI-005", "アドレスブックに…件の問題があります。"),
+    ("MC-CLI-006", "manページの出力に失敗しました。"),
+    ("MC-CLI-007", "manページの書き込みに失敗しました。"),
+    ("MC-CLI-008", "通知コマンド'…'の起動に失敗しました。"),
+    ("MC-CLI-009", "デスクトップ通知の表示に失敗しました。"),
+    ("MC-CLI-010", "エディタ'…'の起動に失敗しました。"),
+    ("MC-CLI-011", "エディタ'…'が異常終了しました。"),
+    ("MC-CLI-012", "入力の読み込みに失敗しました。"),
+    ("MC-CLI-013", "入力が終了したため、処理を中断しました。"),
+    ("MC-CLI-014", "送信待ちのメール…件の再送に失敗しました。"),
+    ("MC-CLI-015", "テンプレートの宛先…件を解決できません。"),
+    ("MC-CLI-016", "取り込むファイルの形式を判定できません。"),
+    ("MC-CONF-001", "設定ファイルが見つかりません。"),
+    ("MC-CONF-002", "置換されないプレースホルダーがあります。"),
+    (
+        "MC-CONF-003",
+        "Thunderbird実行ファイルのパスが設定されていません。",
+    ),
+    (
+        "MC-CONF-004",
+        "…の入力が正しくないため、初期設定を中断しました。",
+    ),
+    ("MC-CONF-005", "…件の宛先をアドレスブックで解決できません。"),
+    ("MC-CONF-006", "Thunderbird実行ファイルが見つかりません。"),
+    ("MC-CONF-007", "設定項目'…'の値が不正です。"),
+    ("MC-CONF-008", "設定項目'…'は上書きできません。"),
+    ("MC-CONF-009", "…が設定されていません。"),
+    ("MC-CONF-010", "1分あたりの最大送信数に0は指定できません。"),
+    ("MC-CONF-011", "メールテンプレートの言語が不正です。"),
+    ("MC-CONF-012", "設定項目'…'の値が不正です。"),
+    ("MC-CONF-013", "スケジュールの形式が不正です。"),
+    ("MC-CONF-014", "表示言語の指定が不正です。"),
+    ("MC-CONF-015", "タイムゾーンが不正です。"),
+    ("MC-CONF-016", "タイムゾーン'…'に存在しない日時です。"),
+    ("MC-CONF-017", "--setの指定が不正です。"),
+    ("MC-CONF-018", "設定項目'…'は--setで上書きできません。"),
+    ("MC-CONF-019", "curlの起動に失敗しました。"),
+    ("MC-CONF-020", "取得した設定ファイルの保存に失敗しました。"),
+    ("MC-CONF-021", "設定ファイルの取得に失敗しました。"),
+    ("MC-CONF-022", "設定ファイルのパスの解決に失敗しました。"),
+    ("MC-CONF-023", "設定ファイルの読み込みに失敗しました。"),
+    ("MC-CONF-024", "設定ファイルの書き込みに失敗しました。"),
+    ("MC-CONF-025", "ディレクトリが存在しません。"),
+    ("MC-CONF-026", "ディレクトリに書き込めません。"),
+    ("MC-CONF-027", "実行ファイルが見つかりません。"),
+    ("MC-CONF-028", "実行ファイルの情報の取得に失敗しました。"),
+    ("MC-CONF-029", "ファイルに実行権限がありません。"),
+    ("MC-CONF-030", "設定値の暗号鍵が見つかりません。"),
+    ("MC-CONF-031", "OpenSSLの起動に失敗しました。"),
+    ("MC-CONF-032", "OpenSSLへの入力の受け渡しに失敗しました。"),
+    ("MC-CONF-033", "OpenSSLプロセスの待機に失敗しました。"),
+    ("MC-CONF-034", "OpenSSLの出力の解析に失敗しました。"),
+    ("MC-CONF-035", "OpenSSLの出力の解析に失敗しました。"),
+    (
+        "MC-CONF-036",
+        "設定ファイルがJSONオブジェクトではありません。",
+    ),
+    ("MC-CONF-037", "…が既に存在します。"),
+    ("MC-CONF-038", "作業記録の保存形式が不正です。"),
+    (
+        "MC-CONF-039",
+        "署名が設定されていますが、署名できるメールクライアントが設定されていません。",
+    ),
+    (
+        "MC-CONF-040",
+        "取得した設定ファイルの読み込みに失敗しました。",
+    ),
+    ("MC-CONF-041", "取得した設定ファイルがJSONではありません。"),
+    ("MC-MAIL-001", "出張の最終日が初日より前です。"),
+    ("MC-MAIL-002", "出張先が指定されていません。"),
+    ("MC-MAIL-003", "メールの送信を中止しました。"),
+    ("MC-MAIL-004", "… 設定が見つかりません。"),
+    ("MC-MAIL-005", "休暇の最終日が初日より前です。"),
+    ("MC-MAIL-006", "… 設定が見つかりません。"),
+    ("MC-MAIL-007", "…の宛先がありません。"),
+    ("MC-MAIL-009", "メールの送信を中止しました。"),
+    ("MC-MAIL-010", "未来の日時は記録できません。"),
+    (
+        "MC-MAIL-011",
+        "…は出張として記録されているため、在宅勤務開始メールを作成しません。",
+    ),
+    ("MC-MAIL-012", "remote_work_start 設定が見つかりません。"),
+    ("MC-MAIL-013", "remote_work_end 設定が見つかりません。"),
+    ("MC-MAIL-014", "weekly_report 設定が見つかりません。"),
+    (
+        "MC-MAIL-015",
+        "メールテンプレートの必須項目が設定されていません。",
+    ),
+    ("MC-MAIL-016", "件名が空です。"),
+    ("MC-MAIL-017", "時刻の形式が不正です。"),
+    ("MC-MAIL-018", "メールテンプレートの描画に失敗しました。"),
+    ("MC-MAIL-019", "osascriptの起動に失敗しました。"),
+    ("MC-MAIL-020", "osascriptプロセスの待機に失敗しました。"),
+    ("MC-MAIL-021", "メールでのメッセージ作成に失敗しました。"),
+    ("MC-MAIL-022", "添付ファイルの読み込みに失敗しました。"),
+    ("MC-MAIL-023", "GPGの起動に失敗しました。"),
+    ("MC-MAIL-024", "GPGへの署名対象の受け渡しに失敗しました。"),
+    ("MC-MAIL-025", "GPGプロセスの待機に失敗しました。"),
+    ("MC-MAIL-026", "GPGによる署名に失敗しました。"),
+    ("MC-MAIL-027", "GPGの出力の解析に失敗しました。"),
+    ("MC-MAIL-028", "IMAPの接続設定が見つかりません。"),
+    ("MC-MAIL-029", "IMAPのパスワードが設定されていません。"),
+    (
+        "MC-MAIL-030",
+        "アップロードするメッセージの書き出しに失敗しました。",
+    ),
+    ("MC-MAIL-031", "curlの起動に失敗しました。"),
+    ("MC-MAIL-032", "curlプロセスの待機に失敗しました。"),
+    (
+        "MC-MAIL-033",
+        "IMAPサーバーへの下書きのアップロードに失敗しました。",
+    ),
+    (
+        "MC-MAIL-034",
+        "mail_config.jsonファイルの読み込みに失敗しました。",
+    ),
+    (
+        "MC-MAIL-035",
+        "mail_config.jsonファイルの解析に失敗しました。",
+    ),
+    (
+        "MC-MAIL-036",
+        "mail_configのvariablesの解析に失敗しました。",
+    ),
+    (
+        "MC-MAIL-037",
+        "mail_configのmail type '…'の解析に失敗しました。",
+    ),
+    (
+        "MC-MAIL-038",
+        "mail type '…'の本文テンプレートファイルの読み込みに失敗しました。",
+    ),
+    (
+        "MC-MAIL-039",
+        "mail_config.jsonファイルの書き込みに失敗しました。",
+    ),
+    (
+        "MC-MAIL-040",
+        "メールドラフトのJSONへの変換に失敗しました。",
+    ),
+    ("MC-MAIL-041", "送信待ちキューへの書き込みに失敗しました。"),
+    ("MC-MAIL-042", "送信待ちキューの読み込みに失敗しました。"),
+    ("MC-MAIL-043", "送信待ちメール'…'の読み込みに失敗しました。"),
+    ("MC-MAIL-044", "送信待ちメール'…'の解析に失敗しました。"),
+    ("MC-MAIL-045", "送信待ちメール'…'の削除に失敗しました。"),
+    (
+        "MC-MAIL-046",
+        "プレビューファイルの書き込みに失敗しました。",
+    ),
+    (
+        "MC-MAIL-047",
+        "1分あたりの最大送信数には1以上を指定してください。",
+    ),
+    (
+        "MC-MAIL-048",
+        "OpenSSLへの署名対象の受け渡しに失敗しました。",
+    ),
+    ("MC-MAIL-049", "OpenSSLの起動に失敗しました。"),
+    ("MC-MAIL-050", "S/MIME署名に失敗しました。"),
+    ("MC-MAIL-051", "確認の入力の読み込みに失敗しました。"),
+    ("MC-MAIL-052", "Thunderbirdプロセスの待機に失敗しました。"),
+    ("MC-MAIL-053", "Thunderbirdの起動に失敗しました。"),
+    (
+        "MC-MAIL-054",
+        "Thunderbirdが…秒以内に終了しなかったため、プロセスを強制終了しました。",
+    ),
+    ("MC-MAIL-055", "Thunderbirdがエラー終了しました。"),
+    ("MC-MAIL-056", "OpenSSLプロセスの待機に失敗しました。"),
+    ("MC-MAIL-057", "OpenSSLの出力の解析に失敗しました。"),
+    (
+        "MC-MAIL-058",
+        "Thunderbirdではヘッダー（…）を設定できません。",
+    ),
+    (
+        "MC-MAIL-059",
+        "メールアプリではヘッダー（…）を設定できません。",
+    ),
+    ("MC-MAIL-060", "ヘッダー名'…'が不正です。"),
+    (
+        "MC-MAIL-061",
+        "ヘッダー'…'は追加ヘッダーとして設定できません。",
+    ),
+    ("MC-MAIL-062", "ヘッダー'…'の値に改行が含まれています。"),
+    (
+        "MC-MAIL-063",
+        "mail type '…'のヘッダー'…'と'…'が重複しています。",
+    ),
+    ("MC-MAIL-064", "curlへの認証情報の受け渡しに失敗しました。"),
+    (
+        "MC-WORK-001",
+        "常駐モードで実行するスケジュールが設定されていません。",
+    ),
+    ("MC-WORK-002", "統計のJSONへの変換に失敗しました。"),
+    ("MC-WORK-003", "集計期間が不正です。"),
+    ("MC-WORK-004", "修正する項目が指定されていません。"),
+    ("MC-WORK-005", "未来の日付の作業記録は修正できません。"),
+    ("MC-WORK-006", "開始時刻と終了時刻が同じです。"),
+    ("MC-WORK-007", "…年…月の作業記録がありません。"),
+    ("MC-WORK-008", "対象月が不正です。"),
+    ("MC-WORK-009", "一時停止していないため、再開できません。"),
+    ("MC-WORK-010", "一時停止していないため、再開できません。"),
+    (
+        "MC-WORK-011",
+        "この作業時間の保存先は備考の記録に対応していません。",
+    ),
+    ("MC-WORK-012", "1日の所定労働時間が不正です。"),
+    ("MC-WORK-013", "作業時間の表示形式が不正です。"),
+    ("MC-WORK-014", "作業記録のCSVへの変換に失敗しました。"),
+    ("MC-WORK-015", "作業記録のCSVへの変換に失敗しました。"),
+    ("MC-WORK-016", "作業記録のCSVへの変換に失敗しました。"),
+    ("MC-WORK-017", "CSVファイルの書き込みに失敗しました。"),
+    ("MC-WORK-018", "祝日CSVの読み込みに失敗しました。"),
+    ("MC-WORK-019", "祝日CSVの文字コードがUTF-8ではありません。"),
+    ("MC-WORK-020", "祝日CSVの解析に失敗しました。"),
+    ("MC-WORK-021", "祝日CSVの日付が不正です。"),
+    ("MC-WORK-022", "作業時間ファイルの読み込みに失敗しました。"),
+    ("MC-WORK-023", "作業時間ファイルの解析に失敗しました。"),
+    ("MC-WORK-024", "JSONへの変換に失敗しました。"),
+    ("MC-WORK-025", "作業時間ファイルの書き込みに失敗しました。"),
+    ("MC-WORK-026", "作業時間ログの読み込みに失敗しました。"),
+    ("MC-WORK-027", "作業時間ログの解析に失敗しました。"),
+    ("MC-WORK-028", "JSONへの変換に失敗しました。"),
+    ("MC-WORK-029", "作業時間ファイルの書き込みに失敗しました。"),
+    ("MC-WORK-030", "JSONへの変換に失敗しました。"),
+    ("MC-WORK-031", "作業時間ログへの追記に失敗しました。"),
+    ("MC-WORK-032", "作業メモの読み込みに失敗しました。"),
+    ("MC-WORK-033", "作業時間データベースを開けませんでした。"),
+    (
+        "MC-WORK-034",
+        "作業時間データベースの初期化に失敗しました。",
+    ),
+    ("MC-WORK-035", "作業時間データベースの操作に失敗しました。"),
+    (
+        "MC-WORK-036",
+        "作業時間データベースの値を解析できませんでした。",
+    ),
+    ("MC-WORK-037", "作業時間CSVの読み込みに失敗しました。"),
+    ("MC-WORK-038", "作業時間CSVの解析に失敗しました。"),
+    (
+        "MC-WORK-039",
+        "作業時間CSVに開始日・開始時刻・終了日・終了時刻の列が見つかりません。",
+    ),
+    ("MC-WORK-040", "作業時間CSVの終了日時が開始日時より前です。"),
+    ("MC-WORK-041", "作業時間CSVの日時が不正です。"),
+    (
+        "MC-WORK-042",
+        "この作業時間の保存先は備考の記録に対応していません。",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::BTreeMap, fs, path::Path};
+
+    /// ソースファイルで`with_code`・`ctx_code`に指定されたエラーコードと、その使用箇所を収集する
+    fn collect_used_codes(dir: &Path, used: &mut BTreeMap<String, Vec<String>>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                collect_used_codes(&path, used);
+                continue;
+            }
+            let source = fs::read_to_string(&path).unwrap();
+            for (i, _) in source.match_indices("_code(\"MC-") {
+                let start = i + "_code(\"".len();
+                let end = start + source[start..].find('"').unwrap();
+                used.entry(source[start..end].to_string())
+                    .or_default()
+                    .push(path.display().to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_codes_are_registered_and_unique() {
+        let mut registered = BTreeMap::new();
+        for (code, summary) in ERROR_CODES {
+            assert!(
+                registered.insert(*code, *summary).is_none(),
+                "{code}が重複して登録されています"
+            );
+            assert!(!summary.is_empty(), "{code}の概要がありません");
+        }
+
+        let mut used = BTreeMap::new();
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        for module in ["application", "domain", "infrastructure"] {
+            collect_used_codes(&src.join(module), &mut used);
+        }
+
+        for (code, paths) in &used {
+            assert!(
+                registered.contains_key(code.as_str()),
+                "{code}が登録されていません"
+            );
+            assert_eq!(
+                paths.len(),
+                1,
+                "{code}が複数箇所で使用されています: {paths:?}"
+            );
+        }
+        for code in registered.keys() {
+            assert!(used.contains_key(*code), "{code}は使用されていません");
+        }
+    }
+}
@@ -0,0 +1,2346 @@
+use crate::{
+    APP_NAME,
+    application::usecases::{
+        address_book_audit_use_case::AddressBookAuditUseCase,
+        address_book_import_use_case::AddressBookImportUseCase,
+        address_book_management_use_case::AddressBookManagementUseCase,
+        attendance_notice_use_case::{AttendanceNoticeKind, AttendanceNoticeUseCase},
+        business_trip_use_case::{BUSINESS_TRIP_MAIL_TYPE, BusinessTripUseCase},
+        doctor_use_case::DoctorUseCase,
+        end_reminder_use_case::EndReminderUseCase,
+        flex_balance_use_case::FlexBalanceUseCase,
+        flush_outbox_use_case::FlushOutboxUseCase,
+        generic_mail_use_case::GenericMailUseCase,
+        init_configuration_use_case::InitConfigurationUseCase,
+        layered_configuration_use_case::LayeredConfigurationUseCase,
+        leave_request_use_case::{LEAVE_REQUEST_MAIL_TYPE, LeaveRequestUseCase},
+        mail_merge_use_case::MailMergeUseCase,
+        preflight_use_case::PreflightUseCase,
+        preview_use_case::PreviewUseCase,
+        remote_work_mail_use_case::{RemoteWorkMailUseCase, WEEKLY_REPORT_MAIL_TYPE},
+        scheduler_use_case::SchedulerUseCase,
+        thunderbird_detection_use_case::ThunderbirdDetectionUseCase,
+        weekly_summary_use_case::WeeklySummaryUseCase,
+        work_statistics_use_case::{StatisticsFormat, WorkStatisticsUseCase},
+        work_time_edit_use_case::{WorkTimeEdit, WorkTimeEditUseCase},
+        work_time_export_use_case::WorkTimeExportUseCase,
+        work_time_import_use_case::WorkTimeImportUseCase,
+        work_time_report_use_case::{ReportFormat, WorkTimeReportUseCase},
+    },
+    domain::{
+        entities::{address_book::AddressBookEntry, work_record::WorkRecord},
+        interfaces::{
+            address_book::AddressBookPort, configuration::ConfigurationPort,
+            confirmation::ConfirmationPort, mail_client::MailClientPort,
+            mail_config::MailConfigPort, work_time::WorkTimePort,
+        },
+        value_objects::{
+            address_query::AddressQuery,
+            app_configuration::{AppConfiguration, ScheduledAction, WorkTimeStore},
+            effective_configuration::EffectiveConfiguration,
+            language::Language,
+            mail_objects::WorkTime,
+            recipient_overrides::RecipientOverrides,
+            time_zone::WorkTimeZone,
+            work_hours::DurationFormat,
+        },
+    },
+    infrastructure::inbound::messages::{Failure, Messages, Written},
+    infrastructure::outbound::{
+        caching_address_book_adapter::CachingAddressBookAdapter,
+        command_line_override_configuration_adapter::parse_assignments,
+        composite_address_book_adapter::CompositeAddressBookAdapter,
+        csv_work_time_export_adapter::CsvWorkTimeExportAdapter,
+        desktop_notification_adapter::DesktopNotificationAdapter,
+        dry_run_preview_mail_client_adapter::DryRunPreviewMailClientAdapter,
+        encrypted_field_configuration_adapter::EncryptedFieldConfigurationAdapter,
+        external_editor_adapter::ExternalEditorAdapter,
+        hot_reload_configuration_adapter::{
+            HotReloadConfigurationAdapter, HotReloadMailConfigAdapter,
+        },
+        http_configuration_adapter::{HttpConfigurationAdapter, HttpMailConfigAdapter},
+        japanese_holiday_calendar_adapter::JapaneseHolidayCalendarAdapter,
+        json_address_book_adapter::JsonAddressBookAdapter,
+        json_configuration_adapter::JsonConfigurationAdapter,
+        json_mail_config_adapter::JsonMailConfigAdapter,
+        json_outbox_adapter::JsonOutboxAdapter,
+        json_work_time_adapter::JsonWorkTimeAdapter,
+        jsonl_work_time_adapter::JsonlWorkTimeAdapter,
+        ldap_address_book_adapter::LdapAddressBookAdapter,
+        local_environment_probe_adapter::LocalEnvironmentProbeAdapter,
+        mail_client_factory::{ThunderbirdDetection, configured_mail_client},
+        markdown_daily_notes_adapter::MarkdownDailyNotesAdapter,
+        openssl_secret_cipher_adapter::OpenSslSecretCipherAdapter,
+        outlook_csv_address_book_adapter::OutlookCsvAddressBookAdapter,
+        preview_mail_client_adapter::{PreviewMailClientAdapter, PreviewOutput},
+        queueing_mail_client_adapter::QueueingMailClientAdapter,
+        sqlite_work_time_adapter::SqliteWorkTimeAdapter,
+        terminal_confirmation_adapter::{AutoConfirmationAdapter, TerminalConfirmationAdapter},
+        terminal_notification_adapter::TerminalNotificationAdapter,
+        terminal_prompt_adapter::TerminalPromptAdapter,
+        thunderbird_address_book_adapter::ThunderbirdAddressBookAdapter,
+        thunderbird_locator_adapter::ThunderbirdLocatorAdapter,
+        time_tracker_csv_adapter::TimeTrackerCsvAdapter,
+        vcf_address_book_adapter::VcfAddressBookAdapter,
+        xlsx_address_book_adapter::{XlsxAddressBookAdapter, XlsxAddressBookLayout},
+        xlsx_work_time_export_adapter::XlsxWorkTimeExportAdapter,
+    },
+};
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveDate};
+use clap::{
+    ArgAction, ArgGroup, Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum,
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+    utils::workspace::{ensure_directory_exists, workspace_path},
+};
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
+};
+use tracing::level_filters::LevelFilter;
+
+/// 既定値、設定ファイル、`config_url`、環境変数、`--set`の順に重ね合わせた設定の読み込み元（暗号化された値は復号しない）
+type CliLayeredConfiguration = LayeredConfigurationUseCase<JsonConfigurationAdapter>;
+
+/// コマンドラインで指定した設定ファイルと上書き値を反映し、`enc:`で始まる値を復号する設定の読み込み元
+type CliConfigurationAdapter =
+    EncryptedFieldConfigurationAdapter<CliLayeredConfiguration, OpenSslSecretCipherAdapter>;
+
+/// 設定に従って選択し、再試行できる失敗は送信待ちキューに退避するメールクライアント
+type CliMailClient = DryRunPreviewMailClientAdapter<
+    QueueingMailClientAdapter<Box<dyn MailClientPort>, JsonOutboxAdapter>,
+>;
+
+/// コマンドラインから使用する在宅勤務の開始・終了メールのユースケース
+type CliRemoteWorkMailUseCase<C = CliConfigurationAdapter, MC = Box<dyn MailConfigPort>> =
+    RemoteWorkMailUseCase<
+        CompositeAddressBookAdapter,
+        C,
+        CliMailClient,
+        Box<dyn WorkTimePort>,
+        MC,
+        Box<dyn ConfirmationPort>,
+    >;
+
+/// 在宅勤務の連絡メールを作成し、作業時間を記録するコマンドラインツール
+#[derive(Debug, Parser)]
+#[command(name = APP_NAME, version, about)]
+pub struct Cli {
+    /// 設定ファイルのパス（未指定の場合は環境変数MAIL_COMPOSER_CONFIG、既定の配置の順に使用する）
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// この実行に限って設定値を上書きする（例: --set from=山田、複数指定可）
+    #[arg(long = "set", global = true, value_name = "項目名=値")]
+    pub overrides: Vec<String>,
+
+    /// 詳細なログを表示する（-vでデバッグ、-vvでトレースまで表示）
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+
+    /// エラー以外のログを表示しない
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// メールを作成する前の確認を省略する
+    #[arg(short, long, global = true)]
+    pub yes: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// 読み込み済みの設定（1回の実行の間は設定ファイルを読み直さない）
+    #[arg(skip)]
+    configuration: OnceLock<AppConfiguration>,
+}
+
+/// サブコマンド
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// 作業開始時刻を記録し、在宅勤務開始メールを作成する
+    Start(SendArgs),
+    /// 作業終了時刻を記録し、在宅勤務終了メールを作成する
+    End(SendArgs),
+    /// メールを送信せずに、宛先と件名・本文を展開した結果を表示する
+    Preview(PreviewArgs),
+    /// 設定ファイルを作成・診断する
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// アドレスブックの宛先を一覧・検索・追加・削除・検証する
+    #[command(subcommand)]
+    Addressbook(AddressBookCommand),
+    /// 作業記録を確認する
+    #[command(subcommand)]
+    Worktime(WorkTimeCommand),
+    /// `mail_templates.json`の任意のメール種別のメールを作成する
+    Mail(MailArgs),
+    /// テンプレートの`to_names`の宛先ごとに個別のメールを作成する（差し込み送信）
+    Merge(MergeArgs),
+    /// 休暇申請メールを作成する
+    Leave(LeaveArgs),
+    /// 遅刻・早退連絡メールを作成し、作業記録の備考に記録する
+    Notice(NoticeArgs),
+    /// 出張連絡メールを作成する
+    Trip(TripArgs),
+    /// 今週の勤務状況の週報メールを作成する
+    Weekly(ComposeArgs),
+    /// 送信待ちキューのメールを扱う
+    #[command(subcommand)]
+    Outbox(OutboxCommand),
+    /// 常駐し、設定のschedulesに従って開始メールの作成や終了の記録忘れの通知を行う
+    Daemon(DaemonArgs),
+    /// CLIの定義からmanページ（roff形式）を生成する
+    Man(ManArgs),
+}
+
+impl Command {
+    /// サブコマンド名を取得する（ログの処理名に使用する）
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Start(_) => "start",
+            Self::End(_) => "end",
+            Self::Preview(_) => "preview",
+            Self::Config(_) => "config",
+            Self::Addressbook(_) => "addressbook",
+            Self::Worktime(_) => "worktime",
+            Self::Mail(_) => "mail",
+            Self::Merge(_) => "merge",
+            Self::Leave(_) => "leave",
+            Self::Notice(_) => "notice",
+            Self::Trip(_) => "trip",
+            Self::Weekly(_) => "weekly",
+            Self::Outbox(_) => "outbox",
+            Self::Daemon(_) => "daemon",
+            Self::Man(_) => "man",
+        }
+    }
+}
+
+/// メールを作成するサブコマンドの共通の引数
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("recipients").args(["to", "cc"]).multiple(true)))]
+pub struct SendArgs {
+    /// メールクライアントを起動せず、作成するメールの内容を表示する
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// 記録し忘れた日を補う場合の対象日（例: 2024-06-01、--timeと合わせて指定する）
+    #[arg(long, value_name = "YYYY-MM-DD", requires = "time")]
+    pub date: Option<NaiveDate>,
+
+    /// 記録する時刻（例: 09:00、未指定の場合は現在時刻）
+    #[arg(long, value_name = "HH:MM", value_parser = parse_work_time)]
+    pub time: Option<WorkTime>,
+
+    /// メールを作成せずに作業時刻の記録だけを行う
+    #[arg(long, conflicts_with = "dry_run")]
+    pub no_mail: bool,
+
+    /// メールクライアントを起動せず、送信待ちキューに保存する（`outbox flush`で送信する）
+    #[arg(long, conflicts_with_all = ["dry_run", "no_mail"])]
+    pub queue: bool,
+
+    /// テンプレートのToに追加する宛先（アドレスブックの名前、カンマ区切り・複数指定可）
+    #[arg(
+        long,
+        value_name = "名前",
+        value_delimiter = ',',
+        conflicts_with = "no_mail"
+    )]
+    pub to: Vec<String>,
+
+    /// テンプレートのCcに追加する宛先（アドレスブックの名前、カンマ区切り・複数指定可）
+    #[arg(
+        long,
+        value_name = "名前",
+        value_delimiter = ',',
+        conflicts_with = "no_mail"
+    )]
+    pub cc: Vec<String>,
+
+    /// `--to`・`--cc`を指定した宛先は、テンプレートの宛先に追加せずに置き換える
+    #[arg(long, requires = "recipients")]
+    pub replace_recipients: bool,
+}
+
+impl SendArgs {
+    /// `--date`・`--time`の指定から記録する日時を決定する
+    ///
+    /// ## Arguments
+    /// * `time_zone` - 日時の解釈に使用するタイムゾーン
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<DateTime<FixedOffset>>` (未指定の項目は現在の日付・時刻を使用する)
+    /// * 失敗時 - 夏時間の切り替えで存在しない日時の場合の`Err<AppError>`
+    pub fn recorded_at(&self, time_zone: &WorkTimeZone) -> AppResult<DateTime<FixedOffset>> {
+        let now = time_zone.now();
+        if self.date.is_none() && self.time.is_none() {
+            return Ok(now);
+        }
+        let date = self.date.unwrap_or(now.date_naive());
+        let time = self.time.as_ref().map_or(now.time(), |time| time.time());
+        time_zone.resolve_local(date.and_time(time))
+    }
+
+    /// `--to`・`--cc`の指定から今回の送信に限り使用する宛先の指定を作成する
+    pub fn recipient_overrides(&self) -> RecipientOverrides {
+        RecipientOverrides::new()
+            .with_to(&self.to)
+            .with_cc(&self.cc)
+            .with_replace(self.replace_recipients)
+    }
+}
+
+/// `start`・`end`以外のメールを作成するサブコマンドの共通の引数
+#[derive(Debug, Args)]
+pub struct ComposeArgs {
+    /// メールクライアントを起動せず、作成するメールの内容を表示する
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// メールクライアントを起動せず、送信待ちキューに保存する（`outbox flush`で送信する）
+    #[arg(long, conflicts_with = "dry_run")]
+    pub queue: bool,
+}
+
+/// `mail`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct MailArgs {
+    /// `mail_templates.json`のメール種別（例: release_notice）
+    pub mail_type: String,
+
+    /// テンプレートに渡す値（例: --var version=v2.1、複数指定可）
+    #[arg(long = "var", value_name = "名前=値", value_parser = parse_assignment)]
+    pub vars: Vec<(String, String)>,
+
+    #[command(flatten)]
+    pub compose: ComposeArgs,
+}
+
+/// `merge`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct MergeArgs {
+    /// `mail_templates.json`のメール種別（`{recipient_name}`・`{recipient_address}`を使用できる）
+    pub mail_type: String,
+
+    #[command(flatten)]
+    pub compose: ComposeArgs,
+}
+
+/// `leave`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct LeaveArgs {
+    /// 休暇の初日（例: 2024-06-03）
+    #[arg(value_name = "YYYY-MM-DD")]
+    pub start: NaiveDate,
+
+    /// 休暇の最終日（未指定の場合は初日の1日のみ）
+    #[arg(value_name = "YYYY-MM-DD")]
+    pub end: Option<NaiveDate>,
+
+    /// 休暇の理由
+    #[arg(long, default_value = "")]
+    pub reason: String,
+
+    #[command(flatten)]
+    pub compose: ComposeArgs,
+}
+
+/// 遅刻・早退連絡の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NoticeKind {
+    /// 遅刻
+    Late,
+    /// 早退
+    Early,
+}
+
+impl From<NoticeKind> for AttendanceNoticeKind {
+    fn from(kind: NoticeKind) -> Self {
+        match kind {
+            NoticeKind::Late => Self::LateArrival,
+            NoticeKind::Early => Self::EarlyLeave,
+        }
+    }
+}
+
+/// `notice`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct NoticeArgs {
+    /// 連絡の種類
+    #[arg(value_enum)]
+    pub kind: NoticeKind,
+
+    /// 出社・退社予定時刻（例: 10:30）
+    #[arg(long, value_name = "HH:MM", value_parser = parse_work_time)]
+    pub time: WorkTime,
+
+    /// 対象日（未指定の場合は今日）
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    pub date: Option<NaiveDate>,
+
+    /// 遅刻・早退の理由
+    #[arg(long, default_value = "")]
+    pub reason: String,
+
+    #[command(flatten)]
+    pub compose: ComposeArgs,
+}
+
+/// `trip`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct TripArgs {
+    /// 出張の初日（例: 2024-06-03）
+    #[arg(value_name = "YYYY-MM-DD")]
+    pub start: NaiveDate,
+
+    /// 出張の最終日（未指定の場合は日帰り）
+    #[arg(value_name = "YYYY-MM-DD")]
+    pub end: Option<NaiveDate>,
+
+    /// 出張先
+    #[arg(long)]
+    pub destination: String,
+
+    /// 出張期間中の在宅勤務開始メールを作成しないよう、各日の作業記録に出張を記録する
+    #[arg(long)]
+    pub suppress_start_mail: bool,
+
+    #[command(flatten)]
+    pub compose: ComposeArgs,
+}
+
+/// `outbox`サブコマンド
+#[derive(Debug, Subcommand)]
+pub enum OutboxCommand {
+    /// 送信待ちキューの全てのメールを再送する
+    Flush {
+        /// メールクライアントを起動せず、再送するメールの内容を表示する（キューから削除しない）
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// `daemon`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct DaemonArgs {
+    /// メールクライアントを起動せず、作成するメールの内容を表示する
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// `man`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct ManArgs {
+    /// サブコマンドごとのmanページを書き出すディレクトリ（未指定の場合はコマンド全体のページを標準出力に表示する）
+    #[arg(long, short, value_name = "DIR")]
+    pub output: Option<PathBuf>,
+}
+
+/// `preview`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct PreviewArgs {
+    /// `mail_templates.json`のメール種別（例: remote_work_start）
+    pub mail_type: String,
+
+    /// テンプレートに渡す値（例: --var version=v2.1、複数指定可）
+    #[arg(long = "var", value_name = "名前=値", value_parser = parse_assignment)]
+    pub vars: Vec<(String, String)>,
+
+    /// プレビューを書き出すファイル（未指定の場合は標準出力に表示する）
+    #[arg(long, short, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+/// `config`サブコマンド
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// 対話形式で設定ファイルとメールテンプレートを作成する
+    Init {
+        /// 既存の設定ファイルとメールテンプレートを上書きする
+        #[arg(long)]
+        force: bool,
+    },
+    /// 設定ファイル、アドレスブック、テンプレート、Thunderbirdをまとめて診断する
+    Doctor,
+    /// 環境変数と`--set`の上書きを反映した設定と、各項目の取得元を表示する
+    Show {
+        /// JSON形式で表示する
+        #[arg(long)]
+        json: bool,
+    },
+    /// 使用する設定ファイルのパスを表示する
+    Path,
+    /// 環境変数と`--set`の上書きを反映した設定を検証する
+    Validate,
+    /// 全てのメールテンプレートの宛先がアドレスブックで解決できるか確認する
+    Preflight,
+    /// 設定ファイルをエディタ（環境変数VISUAL・EDITOR）で開き、保存後に検証する
+    Edit,
+}
+
+/// `addressbook`サブコマンド
+#[derive(Debug, Subcommand)]
+pub enum AddressBookCommand {
+    /// 登録されている全ての宛先を表示する
+    List,
+    /// 名前・ドメイン・グループで宛先を検索する
+    Search(SearchArgs),
+    /// 宛先またはグループを追加する
+    Add(AddArgs),
+    /// 宛先またはグループを削除する
+    Rm {
+        /// 削除する宛先の名前
+        name: String,
+    },
+    /// アドレスブックの不正なメールアドレスや重複などを検出する
+    #[command(alias = "audit")]
+    Validate,
+    /// 他形式のアドレスブック（vCard、Outlookの連絡先CSV、Excel、Thunderbird）を取り込む
+    Import(AddressBookImportArgs),
+}
+
+/// 取り込むアドレスブックの形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AddressBookFormat {
+    /// vCard（.vcf）
+    Vcf,
+    /// Outlookの連絡先CSV（.csv）
+    OutlookCsv,
+    /// Excelブック（.xlsx、1行目が見出しのシート）
+    Xlsx,
+    /// Thunderbirdのアドレス帳（abook.sqlite）
+    Thunderbird,
+}
+
+impl AddressBookFormat {
+    /// ファイルの拡張子から形式を判定する
+    ///
+    /// ## Arguments
+    /// * `path` - 取り込むファイルのパス
+    ///
+    /// ## Returns
+    /// * 判定した形式（拡張子から判定できない場合は`None`）
+    fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "vcf" => Some(Self::Vcf),
+            "csv" => Some(Self::OutlookCsv),
+            "xlsx" => Some(Self::Xlsx),
+            "sqlite" => Some(Self::Thunderbird),
+            _ => None,
+        }
+    }
+}
+
+/// `addressbook import`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct AddressBookImportArgs {
+    /// 取り込むファイルのパス
+    pub path: PathBuf,
+
+    /// 取り込むファイルの形式（未指定の場合は拡張子から判定する）
+    #[arg(long, value_enum)]
+    pub format: Option<AddressBookFormat>,
+
+    /// 同じ名前で内容が異なるエントリを取り込むファイルの内容で上書きする
+    #[arg(long)]
+    pub overwrite: bool,
+
+    /// アドレスブックを保存せず、取り込み結果のみを表示する
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// `addressbook search`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct SearchArgs {
+    /// 名前または別名に含まれる文字列
+    pub text: Option<String>,
+
+    /// メールアドレスのドメイン（例: example.com）
+    #[arg(long)]
+    pub domain: Option<String>,
+
+    /// 所属するグループ
+    #[arg(long)]
+    pub group: Option<String>,
+}
+
+/// `addressbook add`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct AddArgs {
+    /// 宛先の名前（テンプレートのto_names・cc_namesで指定する名前）
+    pub name: String,
+
+    /// メールアドレス（グループの場合は--membersを指定する）
+    #[arg(required_unless_present = "members", conflicts_with = "members")]
+    pub address: Option<String>,
+
+    /// グループのメンバーの名前（カンマ区切り）
+    #[arg(long, value_delimiter = ',')]
+    pub members: Vec<String>,
+
+    /// メールの宛先に表示する名前
+    #[arg(long)]
+    pub display_name: Option<String>,
+
+    /// 別名（複数指定可）
+    #[arg(long = "alias")]
+    pub aliases: Vec<String>,
+}
+
+impl AddArgs {
+    /// 引数から追加するエントリを作成する
+    fn to_entry(&self) -> AddressBookEntry {
+        let entry = match &self.address {
+            Some(address) => AddressBookEntry::person(&self.name, address),
+            None => AddressBookEntry::group(&self.name, self.members.clone()),
+        }
+        .with_aliases(self.aliases.clone());
+        match &self.display_name {
+            Some(display_name) => entry.with_display_name(display_name),
+            None => entry,
+        }
+    }
+}
+
+/// `worktime`サブコマンド
+#[derive(Debug, Subcommand)]
+pub enum WorkTimeCommand {
+    /// 1日・1週間・1か月分の作業記録を表示する
+    Show(ShowArgs),
+    /// 月ごとの勤務実績を表示する
+    Report(ReportArgs),
+    /// 指定日の作業記録の開始・終了時刻と備考を修正する
+    Edit(EditArgs),
+    /// 月ごとの作業記録を勤怠表に貼り付けられる形式で書き出す
+    Export(ExportArgs),
+    /// 月初から基準日までのフレックスタイムの貸借を表示する
+    Flex(FlexArgs),
+    /// 指定期間の開始・終了時刻や作業時間の傾向を集計する
+    Stats(StatsArgs),
+    /// 作業時間記録サービスから書き出したCSVを作業記録として取り込む
+    Import(WorkTimeImportArgs),
+}
+
+/// `worktime show`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct ShowArgs {
+    /// 基準日を含む週（月曜始まり）の作業記録を表示する
+    #[arg(long, conflicts_with = "month")]
+    pub week: bool,
+
+    /// 基準日を含む月の勤務実績を表示する
+    #[arg(long)]
+    pub month: bool,
+
+    /// 基準日（未指定の場合は今日）
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    pub date: Option<NaiveDate>,
+}
+
+/// `worktime report`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    /// 対象年（未指定の場合は今年）
+    #[arg(long)]
+    pub year: Option<i32>,
+
+    /// 対象月（未指定の場合は今月）
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    pub month: Option<u32>,
+
+    /// Markdownの表として出力する
+    #[arg(long)]
+    pub markdown: bool,
+}
+
+/// `worktime edit`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct EditArgs {
+    /// 対象日（例: 2024-06-01）
+    #[arg(value_name = "YYYY-MM-DD")]
+    pub date: NaiveDate,
+
+    /// 修正後の作業開始時刻（例: 09:00）
+    #[arg(long, value_name = "HH:MM", value_parser = parse_work_time)]
+    pub start: Option<WorkTime>,
+
+    /// 修正後の作業終了時刻（例: 18:00）
+    #[arg(long, value_name = "HH:MM", value_parser = parse_work_time)]
+    pub end: Option<WorkTime>,
+
+    /// 作業記録の備考（既存の備考を置き換える。空文字列で削除する）
+    #[arg(long)]
+    pub note: Option<String>,
+}
+
+impl EditArgs {
+    /// 指定された項目から作業記録の修正内容を作成する
+    pub fn to_edit(&self) -> WorkTimeEdit {
+        WorkTimeEdit {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            note: self.note.clone(),
+        }
+    }
+}
+
+/// 作業記録の書き出し形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// BOM付きUTF-8のCSV
+    #[default]
+    Csv,
+    /// Excelブック
+    Xlsx,
+}
+
+/// `worktime export`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// 対象年（未指定の場合は今年）
+    #[arg(long)]
+    pub year: Option<i32>,
+
+    /// 対象月（未指定の場合は今月）
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    pub month: Option<u32>,
+
+    /// 書き出し形式
+    #[arg(long, value_enum, default_value_t)]
+    pub format: ExportFormat,
+}
+
+/// `worktime flex`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct FlexArgs {
+    /// 基準日（未指定の場合は今日）
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    pub date: Option<NaiveDate>,
+}
+
+/// `worktime stats`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    /// 集計期間の初日（未指定の場合は最終日までの30日間）
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    pub from: Option<NaiveDate>,
+
+    /// 集計期間の最終日（未指定の場合は今日）
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    pub to: Option<NaiveDate>,
+
+    /// JSON形式で出力する
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// `worktime import`サブコマンドの引数
+#[derive(Debug, Args)]
+pub struct WorkTimeImportArgs {
+    /// 取り込むCSVファイルのパス
+    pub path: PathBuf,
+
+    /// 作業記録を保存せず、取り込み結果のみを表示する
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl Cli {
+    /// 表示言語に合わせたヘルプでコマンドライン引数を解析する
+    ///
+    /// 解析前は設定ファイルの場所が決まらないため、表示言語は環境変数から判定する。
+    /// 引数が不正な場合やヘルプを表示した場合はプロセスを終了する
+    ///
+    /// ## Returns
+    /// * 解析したCliのインスタンス
+    pub fn parse_localized() -> Self {
+        let matches = Messages::new(Self::env_language())
+            .localize_command(Self::command())
+            .get_matches();
+        Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+    }
+
+    /// 環境変数から表示言語を判定する
+    ///
+    /// ## Returns
+    /// * `MAIL_COMPOSER_UI_LANGUAGE`の言語（未設定または不正な場合は`LANG`などのロケールから判定した言語）
+    pub fn env_language() -> Language {
+        std::env::var(AppConfiguration::env_var_name("ui_language"))
+            .ok()
+            .and_then(|code| Language::parse(&code).ok())
+            .unwrap_or_else(Language::detect)
+    }
+
+    /// 設定の`ui_language`に従った文言を取得する
+    ///
+    /// ## Returns
+    /// * 表示言語に合わせたMessages（設定を読み込めない場合は環境変数から判定した言語）
+    ///
+    /// 読み込みのエラーはサブコマンドの実行時に報告されるため、ここではログに残して既定の言語を使う
+    pub fn messages(&self) -> Messages {
+        let language = self
+            .configuration()
+            .and_then(|config| config.ui_language())
+            .unwrap_or_else(|e| {
+                tracing::debug!(error = %e, "設定から表示言語を判定できませんでした");
+                Self::env_language()
+            });
+        Messages::new(language)
+    }
+
+    /// `-v`・`--quiet`の指定からログの出力レベルを決定する
+    ///
+    /// ## Returns
+    /// * `--quiet`の場合は`ERROR`、未指定の場合は`INFO`、`-v`の場合は`DEBUG`、`-vv`以上の場合は`TRACE`
+    pub fn log_level(&self) -> LevelFilter {
+        if self.quiet {
+            return LevelFilter::ERROR;
+        }
+        match self.verbose {
+            0 => LevelFilter::INFO,
+            1 => LevelFilter::DEBUG,
+            _ => LevelFilter::TRACE,
+        }
+    }
+
+    /// 指定された出力レベルで標準エラー出力と設定の`log_dir`のファイルにログを書き出すロガーを初期化する
+    ///
+    /// 環境変数`MAIL_COMPOSER_LOG`（または`RUST_LOG`）でモジュールごとの出力レベルを指定できる。
+    /// 設定を読み込めない場合やログディレクトリを作成できない場合は、標準エラー出力にのみ書き出す。
+    /// ロガーが既に初期化されている場合は何もしない
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 環境変数の指定の形式が不正な場合の`Err<AppError>`
+    pub fn init_logging(&self) -> AppResult<()> {
+        let file = self.configuration().and_then(|config| {
+            let time_zone = config.time_zone()?;
+            let log_dir = workspace_path(config.log_dir_path())?;
+            ensure_directory_exists(&log_dir)?;
+            Ok((log_dir, time_zone))
+        });
+        match file {
+            Ok((log_dir, time_zone)) => {
+                logging::init_with_file(APP_NAME, self.log_level(), &log_dir, move || {
+                    time_zone.today()
+                })
+            }
+            Err(_) => logging::init(APP_NAME, self.log_level()),
+        }
+    }
+
+    /// 指定されたサブコマンドを実行する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - 設定の読み込みやユースケースの実行に失敗した場合の`Err<AppError>`
+    pub fn run(&self) -> AppResult<()> {
+        logging::in_span(self.command.name(), || {
+            self.warn_missing_end();
+            self.dispatch()
+        })
+    }
+
+    /// 設定の`end_reminder_time`を過ぎても作業終了が記録されていない場合に警告を表示する
+    ///
+    /// 終了を記録する`end`と、設定やマニュアルを扱うサブコマンド、常駐モードでは確認しない。
+    /// 確認に失敗してもサブコマンドの実行は妨げない
+    fn warn_missing_end(&self) {
+        if matches!(
+            self.command,
+            Command::End(_) | Command::Config(_) | Command::Daemon(_) | Command::Man(_)
+        ) {
+            return;
+        }
+        let result = self.configuration().and_then(|config| {
+            EndReminderUseCase::new(
+                Self::work_time_adapter(config)?,
+                TerminalNotificationAdapter::new(),
+            )
+            .with_remind_after(config.end_reminder_time()?)
+            .with_time_zone(config.time_zone()?)
+            .remind()
+        });
+        if let Err(e) = result {
+            tracing::debug!(error = %e, "作業終了の記録忘れを確認できませんでした");
+        }
+    }
+
+    /// サブコマンドに対応する処理を呼び出す
+    fn dispatch(&self) -> AppResult<()> {
+        match &self.command {
+            Command::Start(args) => self.run_start(args),
+            Command::End(args) => self.run_end(args),
+            Command::Preview(args) => self.run_preview(args),
+            Command::Config(ConfigCommand::Init { force }) => self.run_config_init(*force),
+            Command::Config(ConfigCommand::Doctor) => self.run_config_doctor(),
+            Command::Config(ConfigCommand::Show { json }) => self.run_config_show(*json),
+            Command::Config(ConfigCommand::Path) => self.run_config_path(),
+            Command::Config(ConfigCommand::Validate) => self.run_config_validate(),
+            Command::Config(ConfigCommand::Preflight) => self.run_config_preflight(),
+            Command::Config(ConfigCommand::Edit) => self.run_config_edit(),
+            Command::Addressbook(AddressBookCommand::List) => self.run_addressbook_list(),
+            Command::Addressbook(AddressBookCommand::Search(args)) => {
+                self.run_addressbook_search(args)
+            }
+            Command::Addressbook(AddressBookCommand::Add(args)) => self.run_addressbook_add(args),
+            Command::Addressbook(AddressBookCommand::Rm { name }) => self.run_addressbook_rm(name),
+            Command::Addressbook(AddressBookCommand::Validate) => self.run_addressbook_validate(),
+            Command::Addressbook(AddressBookCommand::Import(args)) => {
+                self.run_addressbook_import(args)
+            }
+            Command::Worktime(WorkTimeCommand::Show(args)) => self.run_worktime_show(args),
+            Command::Worktime(WorkTimeCommand::Report(args)) => self.run_worktime_report(args),
+            Command::Worktime(WorkTimeCommand::Edit(args)) => self.run_worktime_edit(args),
+            Command::Worktime(WorkTimeCommand::Export(args)) => self.run_worktime_export(args),
+            Command::Worktime(WorkTimeCommand::Flex(args)) => self.run_worktime_flex(args),
+            Command::Worktime(WorkTimeCommand::Stats(args)) => self.run_worktime_stats(args),
+            Command::Worktime(WorkTimeCommand::Import(args)) => self.run_worktime_import(args),
+            Command::Mail(args) => self.run_mail(args),
+            Command::Merge(args) => self.run_merge(args),
+            Command::Leave(args) => self.run_leave(args),
+            Command::Notice(args) => self.run_notice(args),
+            Command::Trip(args) => self.run_trip(args),
+            Command::Weekly(args) => self.run_weekly(args),
+            Command::Outbox(OutboxCommand::Flush { dry_run }) => self.run_outbox_flush(*dry_run),
+            Command::Daemon(args) => self.run_daemon(args),
+            Command::Man(args) => self.run_man(args),
+        }
+    }
+
+    /// 設定ファイル、環境変数、`--set`の順に上書きした設定を取得する（初回のみ読み込む）
+    fn configuration(&self) -> AppResult<&AppConfiguration> {
+        if let Some(config) = self.configuration.get() {
+            return Ok(config);
+        }
+        let config = self.configuration_adapter()?.load_configuration()?;
+        Ok(self.configuration.get_or_init(|| config))
+    }
+
+    /// 既定値、設定ファイル、`config_url`、環境変数、`--set`の順に重ね合わせた設定の読み込み元を作成する（暗号化された値は復号しない）
+    fn layered_configuration(&self) -> AppResult<CliLayeredConfiguration> {
+        let file_adapter = JsonConfigurationAdapter::locate(self.config.as_deref())?;
+        let layered = LayeredConfigurationUseCase::new(file_adapter)
+            .with_command_line_overrides(parse_assignments(&self.overrides)?);
+
+        // config_urlは設定ファイル・環境変数・--setのいずれでも指定できるため、一度重ね合わせてから取得する。
+        // ここで読み込めない場合は、設定を読み込む際に同じエラーを報告する
+        let config_url = layered
+            .resolve_unvalidated()
+            .ok()
+            .and_then(|effective| effective.configuration.config_url);
+        Ok(match config_url {
+            Some(url) => {
+                layered.with_remote_source(HttpConfigurationAdapter::with_default_cache(url))
+            }
+            None => layered,
+        })
+    }
+
+    /// 設定ファイル、環境変数、`--set`の順に上書きし、暗号化された値を復号する設定の読み込み元を作成する
+    fn configuration_adapter(&self) -> AppResult<CliConfigurationAdapter> {
+        Ok(EncryptedFieldConfigurationAdapter::new(
+            self.layered_configuration()?,
+            OpenSslSecretCipherAdapter::new(),
+        ))
+    }
+
+    /// 設定の`work_time_store`に従って作業記録の保存先を作成する
+    fn work_time_adapter(config: &AppConfiguration) -> AppResult<Box<dyn WorkTimePort>> {
+        let retention_months = config.work_time_retention_months;
+        Ok(match config.work_time_store.unwrap_or_default() {
+            WorkTimeStore::Json => {
+                let adapter = JsonWorkTimeAdapter::with_default_settings();
+                Box::new(match retention_months {
+                    Some(months) => adapter.with_retention_months(months),
+                    None => adapter,
+                })
+            }
+            WorkTimeStore::Jsonl => Box::new(JsonlWorkTimeAdapter::with_default_settings()),
+            WorkTimeStore::Sqlite => {
+                let adapter = SqliteWorkTimeAdapter::with_default_settings()?;
+                Box::new(match retention_months {
+                    Some(months) => adapter.with_retention_months(months),
+                    None => adapter,
+                })
+            }
+        })
+    }
+
+    /// 設定の`mail_templates_url`または`mail_templates_file`に従ってメールテンプレートの読み込み元を作成する
+    fn mail_config_adapter(config: &AppConfiguration) -> Box<dyn MailConfigPort> {
+        match &config.mail_templates_url {
+            Some(url) => Box::new(HttpMailConfigAdapter::with_default_cache(url)),
+            None => Box::new(Self::mail_config_file_adapter(config)),
+        }
+    }
+
+    /// 設定の`mail_templates_file`に従ってローカルのメールテンプレートの読み込み元を作成する
+    fn mail_config_file_adapter(config: &AppConfiguration) -> JsonMailConfigAdapter {
+        match &config.mail_templates_file {
+            Some(path) => JsonMailConfigAdapter::from_path(path),
+            None => JsonMailConfigAdapter::new(),
+        }
+    }
+
+    /// 設定に従ってメールクライアントを作成する
+    ///
+    /// 再試行できる送信の失敗は送信待ちキューに退避し、ドライランの場合は作成するメールを表示する
+    ///
+    /// ## Arguments
+    /// * `config` - メールクライアントの選択に使用する設定
+    /// * `thunderbird_detection` - Thunderbird実行ファイルの自動検出に使用するユースケース
+    /// * `queue` - 送信を試みずに送信待ちキューに保存する場合は`true`
+    fn mail_client(
+        config: &AppConfiguration,
+        thunderbird_detection: &ThunderbirdDetection,
+        queue: bool,
+    ) -> AppResult<CliMailClient> {
+        Ok(DryRunPreviewMailClientAdapter::new(
+            QueueingMailClientAdapter::new(
+                configured_mail_client(config, thunderbird_detection)?,
+                JsonOutboxAdapter::with_default_settings(),
+                queue,
+            ),
+        ))
+    }
+
+    /// 設定されたThunderbird実行ファイルが無効な場合に自動検出するユースケースを作成する
+    ///
+    /// ## Arguments
+    /// * `interactive` - 検出したパスを設定ファイルに書き戻すかを端末で確認する場合は`true`
+    fn thunderbird_detection(&self, interactive: bool) -> AppResult<ThunderbirdDetection> {
+        let detection = ThunderbirdDetectionUseCase::new(
+            JsonConfigurationAdapter::locate(self.config.as_deref())?,
+            ThunderbirdLocatorAdapter::new(),
+            LocalEnvironmentProbeAdapter::new(),
+        );
+        Ok(match interactive {
+            true => detection.with_write_back_prompt(TerminalPromptAdapter::new()),
+            false => detection,
+        })
+    }
+
+    /// 送信前の確認に使用するアダプターを作成する（`--yes`の場合は確認を省略する）
+    fn confirmation_adapter(&self) -> Box<dyn ConfirmationPort> {
+        if self.yes {
+            Box::new(AutoConfirmationAdapter)
+        } else {
+            Box::new(TerminalConfirmationAdapter::new().with_language(self.messages().language()))
+        }
+    }
+
+    /// 設定されたパスからアドレスブックを読み込み、内容をデバッグレベルのログに出力する
+    ///
+    /// `ldap`が設定されている場合は、アドレスブックで見つからない名前を社内ディレクトリで解決する
+    fn address_book(config: &AppConfiguration) -> AppResult<CompositeAddressBookAdapter> {
+        let path = config.address_book_path();
+        tracing::debug!(path = %path.display(), "アドレスブックを読み込みます");
+        let address_book = JsonAddressBookAdapter::load_from_address_book(&path)?;
+        address_book.log_contents();
+
+        Self::with_directory(
+            config,
+            CompositeAddressBookAdapter::new()
+                .with_source(path.display().to_string(), address_book),
+        )
+    }
+
+    /// 常駐中に読み込み済みのアドレスブックを使用し、`ldap`が設定されていれば社内ディレクトリを追加する
+    ///
+    /// ## Arguments
+    /// * `config` - 社内ディレクトリの設定に使用する設定
+    /// * `cache` - ファイルが更新された場合のみ再読み込みするアドレスブック
+    fn cached_address_book(
+        config: &AppConfiguration,
+        cache: &Arc<CachingAddressBookAdapter<JsonAddressBookAdapter>>,
+    ) -> AppResult<CompositeAddressBookAdapter> {
+        let label = config.address_book_path().display().to_string();
+        Self::with_directory(
+            config,
+            CompositeAddressBookAdapter::new().with_source(label, Arc::clone(cache)),
+        )
+    }
+
+    /// `ldap`が設定されている場合、アドレスブックで見つからない名前を社内ディレクトリで解決する
+    fn with_directory(
+        config: &AppConfiguration,
+        composite: CompositeAddressBookAdapter,
+    ) -> AppResult<CompositeAddressBookAdapter> {
+        Ok(match &config.ldap {
+            Some(ldap) => composite.with_source(
+                "LDAP",
+                LdapAddressBookAdapter::from_configuration(ldap.clone())?,
+            ),
+            None => composite,
+        })
+    }
+
+    /// 端末から確認・入力を行う在宅勤務の開始・終了メールのユースケースを作成する
+    ///
+    /// ## Arguments
+    /// * `queue` - 送信を試みずに送信待ちキューに保存する場合は`true`
+    fn remote_work_mail_use_case(&self, queue: bool) -> AppResult<CliRemoteWorkMailUseCase> {
+        let config = self.configuration()?;
+        Ok(Self::unattended_remote_work_mail_use_case(
+            config,
+            Self::address_book(config)?,
+            self.configuration_adapter()?,
+            Self::mail_config_adapter(config),
+            Self::work_time_adapter(config)?,
+            self.confirmation_adapter(),
+            Self::mail_client(config, &self.thunderbird_detection(!self.yes)?, queue)?,
+        )?
+        .with_tasks_prompt(TerminalPromptAdapter::new()))
+    }
+
+    /// 端末からの入力を行わない在宅勤務の開始・終了メールのユースケースを作成する
+    ///
+    /// ## Arguments
+    /// * `config` - アダプターの作成に使用する設定
+    /// * `address_book` - 名前解決に使用するアドレスブック
+    /// * `configuration_port` - ユースケースが設定の読み込みに使用するポート
+    /// * `mail_config_port` - メールテンプレートの読み込みに使用するポート
+    /// * `work_time` - 作業記録の保存先
+    /// * `confirmation` - 送信前の確認に使用するアダプター
+    /// * `mail_client` - メールの作成に使用するメールクライアント
+    fn unattended_remote_work_mail_use_case<C, MC>(
+        config: &AppConfiguration,
+        address_book: CompositeAddressBookAdapter,
+        configuration_port: C,
+        mail_config_port: MC,
+        work_time: Box<dyn WorkTimePort>,
+        confirmation: Box<dyn ConfirmationPort>,
+        mail_client: CliMailClient,
+    ) -> AppResult<CliRemoteWorkMailUseCase<C, MC>>
+    where
+        C: ConfigurationPort,
+        MC: MailConfigPort,
+    {
+        Ok(RemoteWorkMailUseCase::new(
+            address_book,
+            configuration_port,
+            mail_client,
+            work_time,
+            mail_config_port,
+            confirmation,
+        )
+        .with_holiday_calendar(JapaneseHolidayCalendarAdapter::new())
+        .with_daily_notes(MarkdownDailyNotesAdapter::new(config.notes_dir_path())))
+    }
+
+    fn run_start(&self, args: &SendArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let started_at = args.recorded_at(&config.time_zone()?)?;
+        let use_case = self
+            .remote_work_mail_use_case(args.queue)?
+            .with_recipient_overrides(args.recipient_overrides());
+
+        if args.no_mail {
+            use_case.record_remote_work_start_at(&started_at)?;
+            println!(
+                "{}",
+                self.messages()
+                    .start_recorded(&started_at.format("%Y-%m-%d %H:%M").to_string())
+            );
+            return Ok(());
+        }
+        if let Some(day_off) = use_case.day_off_on(started_at.date_naive())? {
+            eprintln!(
+                "{}",
+                self.messages()
+                    .holiday_warning(started_at.date_naive(), &day_off)
+            );
+        }
+        use_case.send_remote_work_start_at(&started_at, args.dry_run)?;
+        println!("{}", self.messages().start_mail_composed());
+        Ok(())
+    }
+
+    fn run_end(&self, args: &SendArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let ended_at = args.recorded_at(&config.time_zone()?)?;
+        let use_case = self
+            .remote_work_mail_use_case(args.queue)?
+            .with_recipient_overrides(args.recipient_overrides());
+
+        if args.no_mail {
+            use_case.record_remote_work_end_at(&ended_at)?;
+            println!(
+                "{}",
+                self.messages()
+                    .end_recorded(&ended_at.format("%Y-%m-%d %H:%M").to_string())
+            );
+            return Ok(());
+        }
+        if let Some(day_off) = use_case.day_off_on(ended_at.date_naive())? {
+            eprintln!(
+                "{}",
+                self.messages()
+                    .holiday_warning(ended_at.date_naive(), &day_off)
+            );
+        }
+        use_case.send_remote_work_end_at(&ended_at, args.dry_run)?;
+        println!("{}", self.messages().end_mail_composed());
+        Ok(())
+    }
+
+    fn run_mail(&self, args: &MailArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let extra_vars: HashMap<String, String> = args.vars.iter().cloned().collect();
+        GenericMailUseCase::new(
+            Self::address_book(config)?,
+            self.configuration_adapter()?,
+            Self::mail_client(
+                config,
+                &self.thunderbird_detection(!self.yes)?,
+                args.compose.queue,
+            )?,
+            Self::mail_config_adapter(config),
+            self.confirmation_adapter(),
+        )
+        .send(&args.mail_type, &extra_vars, args.compose.dry_run)?;
+        println!("{}", self.messages().mail_composed(&args.mail_type));
+        Ok(())
+    }
+
+    fn run_merge(&self, args: &MergeArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let recipients = MailMergeUseCase::new(
+            Self::address_book(config)?,
+            self.configuration_adapter()?,
+            Self::mail_client(
+                config,
+                &self.thunderbird_detection(!self.yes)?,
+                args.compose.queue,
+            )?,
+            Self::mail_config_adapter(config),
+            self.confirmation_adapter(),
+        )
+        .send_personalized(&args.mail_type, args.compose.dry_run)?;
+        println!("{}", self.messages().mails_composed(recipients.len()));
+        Ok(())
+    }
+
+    fn run_leave(&self, args: &LeaveArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        LeaveRequestUseCase::new(
+            Self::address_book(config)?,
+            self.configuration_adapter()?,
+            Self::mail_client(
+                config,
+                &self.thunderbird_detection(!self.yes)?,
+                args.compose.queue,
+            )?,
+            Self::mail_config_adapter(config),
+            self.confirmation_adapter(),
+        )
+        .send_leave_request(
+            args.start,
+            args.end.unwrap_or(args.start),
+            &args.reason,
+            args.compose.dry_run,
+        )?;
+        println!("{}", self.messages().mail_composed(LEAVE_REQUEST_MAIL_TYPE));
+        Ok(())
+    }
+
+    fn run_notice(&self, args: &NoticeArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let kind = AttendanceNoticeKind::from(args.kind);
+        AttendanceNoticeUseCase::new(
+            Self::address_book(config)?,
+            self.configuration_adapter()?,
+            Self::mail_client(
+                config,
+                &self.thunderbird_detection(!self.yes)?,
+                args.compose.queue,
+            )?,
+            Self::mail_config_adapter(config),
+            self.confirmation_adapter(),
+            Self::work_time_adapter(config)?,
+        )
+        .send_notice(
+            kind,
+            args.date.unwrap_or(config.time_zone()?.today()),
+            &args.time,
+            &args.reason,
+            args.compose.dry_run,
+        )?;
+        println!("{}", self.messages().mail_composed(kind.mail_type()));
+        Ok(())
+    }
+
+    fn run_trip(&self, args: &TripArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        BusinessTripUseCase::new(
+            Self::address_book(config)?,
+            self.configuration_adapter()?,
+            Self::mail_client(
+                config,
+                &self.thunderbird_detection(!self.yes)?,
+                args.compose.queue,
+            )?,
+            Self::mail_config_adapter(config),
+            self.confirmation_adapter(),
+            Self::work_time_adapter(config)?,
+        )
+        .with_remote_work_start_suppressed(args.suppress_start_mail)
+        .send_business_trip(
+            args.start,
+            args.end.unwrap_or(args.start),
+            &args.destination,
+            args.compose.dry_run,
+        )?;
+        println!("{}", self.messages().mail_composed(BUSINESS_TRIP_MAIL_TYPE));
+        Ok(())
+    }
+
+    fn run_weekly(&self, args: &ComposeArgs) -> AppResult<()> {
+        self.remote_work_mail_use_case(args.queue)?
+            .send_weekly_report(args.dry_run)?;
+        println!("{}", self.messages().mail_composed(WEEKLY_REPORT_MAIL_TYPE));
+        Ok(())
+    }
+
+    fn run_outbox_flush(&self, is_dry_run: bool) -> AppResult<()> {
+        let config = self.configuration()?;
+        // 再送に失敗したメールはキューに残るため、キューに退避するクライアントは使用しない
+        let report = FlushOutboxUseCase::new(
+            JsonOutboxAdapter::with_default_settings(),
+            DryRunPreviewMailClientAdapter::new(configured_mail_client(
+                config,
+                &self.thunderbird_detection(!self.yes)?,
+            )?),
+        )
+        .flush_outbox(is_dry_run)?;
+
+        let messages = self.messages();
+        println!("{}", messages.outbox_flushed(report.sent.len()));
+        if report.is_all_sent() {
+            return Ok(());
+        }
+        for (id, error) in &report.failed {
+            tracing::warn!(
+                %id,
+                error = %error.localized_message(messages.locale()),
+                "送信待ちのメールを再送できませんでした"
+            );
+        }
+        let failure = Failure::OutboxResend(report.failed.len());
+        Err(AppError::new(ErrorKind::ServiceUnavailable)
+            .with_code("MC-CLI-014")
+            .with_message(messages.failure_message(failure))
+            .with_action(messages.failure_action(failure))
+            .retryable(true))
+    }
+
+    fn run_preview(&self, args: &PreviewArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let address_book = Self::address_book(config)?;
+        let use_case = PreviewUseCase::new(
+            address_book,
+            self.configuration_adapter()?,
+            Self::mail_config_adapter(config),
+        );
+
+        let extra_vars: HashMap<String, String> = args.vars.iter().cloned().collect();
+        let draft = use_case.render(&args.mail_type, &extra_vars)?;
+        let output = match &args.output {
+            Some(path) => PreviewOutput::File(path.clone()),
+            None => PreviewOutput::Stdout,
+        };
+        PreviewMailClientAdapter::new(output).compose_mail(&draft, false)?;
+        if let Some(output) = &args.output {
+            println!("{}", self.messages().written(Written::Preview, output));
+        }
+        Ok(())
+    }
+
+    fn run_config_init(&self, force: bool) -> AppResult<()> {
+        let file_adapter = JsonConfigurationAdapter::locate(self.config.as_deref())?;
+        let address_book = JsonAddressBookAdapter::load_or_empty(
+            &AppConfiguration::default().address_book_path(),
+        )?;
+        let mut use_case = InitConfigurationUseCase::new(
+            TerminalPromptAdapter::new(),
+            ThunderbirdLocatorAdapter::new(),
+            file_adapter,
+            JsonMailConfigAdapter::new(),
+            address_book,
+        )
+        .with_force(force);
+        let report = use_case.run()?;
+
+        let messages = self.messages();
+        println!("{}", messages.configuration_created());
+        if !report.added_addresses.is_empty() {
+            println!(
+                "{}",
+                messages.added_addresses(&report.added_addresses.join(", "))
+            );
+        }
+        Ok(())
+    }
+
+    fn run_config_doctor(&self) -> AppResult<()> {
+        // 設定を読み込めない場合も診断できるよう、テンプレートは既定の配置から読み込む
+        let mail_config = self
+            .configuration()
+            .map(Self::mail_config_adapter)
+            .unwrap_or_else(|_| Box::new(JsonMailConfigAdapter::new()));
+        let report = DoctorUseCase::new(
+            self.configuration_adapter()?,
+            mail_config,
+            JsonAddressBookAdapter::load_from_address_book,
+            LocalEnvironmentProbeAdapter::new(),
+        )
+        .diagnose();
+
+        println!("{}", report.render());
+        if report.is_healthy() {
+            Ok(())
+        } else {
+            let messages = self.messages();
+            Err(AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-CLI-001")
+                .with_message(messages.failure_message(Failure::Doctor))
+                .with_action(messages.failure_action(Failure::Doctor)))
+        }
+    }
+
+    /// 設定ファイル、環境変数、`--set`の順に重ね合わせた設定を取得する（検証は行わない）
+    fn effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        self.layered_configuration()?.resolve_unvalidated()
+    }
+
+    fn run_config_show(&self, json: bool) -> AppResult<()> {
+        let effective = self.effective_configuration()?;
+        if json {
+            let text = serde_json::to_string_pretty(&effective).map_err(|e| {
+                let messages = self.messages();
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-CLI-002")
+                    .with_message(messages.failure_message(Failure::ConfigurationJson))
+                    .with_action(messages.failure_action(Failure::ConfigurationJson))
+                    .with_source(e)
+            })?;
+            println!("{text}");
+        } else {
+            println!("{}", effective.render());
+        }
+        Ok(())
+    }
+
+    fn run_config_path(&self) -> AppResult<()> {
+        let file_adapter = JsonConfigurationAdapter::locate(self.config.as_deref())?;
+        println!("{}", file_adapter.get_absolute_config_path()?.display());
+        if !file_adapter.configuration_exists() {
+            tracing::warn!("設定ファイルがまだありません。`config init`で作成してください。");
+        }
+        Ok(())
+    }
+
+    fn run_config_validate(&self) -> AppResult<()> {
+        let errors = self
+            .effective_configuration()?
+            .configuration
+            .validation_errors();
+        let messages = self.messages();
+        if errors.is_empty() {
+            println!("{}", messages.configuration_valid());
+            return Ok(());
+        }
+
+        for error in &errors {
+            println!("- {}", error.localized_message(messages.locale()));
+            if let Some(action) = error.localized_action(messages.locale()) {
+                println!("  {}: {action}", messages.action_label());
+            }
+        }
+        let failure = Failure::InvalidConfiguration(errors.len());
+        Err(AppError::new(ErrorKind::UnprocessableEntity)
+            .with_code("MC-CLI-003")
+            .with_message(messages.failure_message(failure))
+            .with_action(messages.failure_action(failure)))
+    }
+
+    fn run_config_preflight(&self) -> AppResult<()> {
+        let config = self.configuration()?;
+        let report = PreflightUseCase::new(
+            Self::address_book(config)?,
+            Self::mail_config_adapter(config),
+        )
+        .check()?;
+
+        let messages = self.messages();
+        if report.is_ready() {
+            println!(
+                "{}",
+                messages.recipients_resolved(report.checked_mail_types)
+            );
+            return Ok(());
+        }
+        for unresolved in &report.unresolved {
+            println!(
+                "{}",
+                messages.unresolved_recipient(
+                    &unresolved.mail_type,
+                    unresolved.field,
+                    &unresolved.name,
+                    unresolved.error.localized_message(messages.locale())
+                )
+            );
+        }
+        let failure = Failure::UnresolvedRecipients(report.unresolved.len());
+        Err(AppError::new(ErrorKind::UnprocessableEntity)
+            .with_code("MC-CLI-015")
+            .with_message(messages.failure_message(failure))
+            .with_action(messages.failure_action(failure)))
+    }
+
+    fn run_config_edit(&self) -> AppResult<()> {
+        let file_adapter = JsonConfigurationAdapter::locate(self.config.as_deref())?;
+        let path = file_adapter.get_absolute_config_path()?;
+        if !file_adapter.configuration_exists() {
+            let messages = self.messages();
+            let failure = Failure::MissingConfiguration(&path);
+            return Err(AppError::new(ErrorKind::NotFound)
+                .with_code("MC-CLI-004")
+                .with_message(messages.failure_message(failure))
+                .with_action(messages.failure_action(failure)));
+        }
+
+        ExternalEditorAdapter::from_env().open(&path)?;
+        self.run_config_validate()
+    }
+
+    /// 設定されたパスのアドレスブックを編集するユースケースを作成する（ファイルがない場合は空で作成する）
+    fn address_book_management_use_case(
+        &self,
+    ) -> AppResult<AddressBookManagementUseCase<JsonAddressBookAdapter>> {
+        let config = self.configuration()?;
+        let address_book = JsonAddressBookAdapter::load_or_empty(&config.address_book_path())?;
+        Ok(AddressBookManagementUseCase::new(address_book))
+    }
+
+    fn run_addressbook_list(&self) -> AppResult<()> {
+        let entries = self.address_book_management_use_case()?.list()?;
+        print_entries(&entries, &self.messages());
+        Ok(())
+    }
+
+    fn run_addressbook_search(&self, args: &SearchArgs) -> AppResult<()> {
+        let mut query = AddressQuery::new();
+        if let Some(text) = &args.text {
+            query = query.with_name_contains(text);
+        }
+        if let Some(domain) = &args.domain {
+            query = query.with_domain(domain);
+        }
+        if let Some(group) = &args.group {
+            query = query.with_member_of(group);
+        }
+
+        let entries = self.address_book_management_use_case()?.search(&query)?;
+        print_entries(&entries, &self.messages());
+        Ok(())
+    }
+
+    fn run_addressbook_add(&self, args: &AddArgs) -> AppResult<()> {
+        self.address_book_management_use_case()?
+            .add(args.to_entry())?;
+        println!("{}", self.messages().address_added(&args.name));
+        Ok(())
+    }
+
+    fn run_addressbook_rm(&self, name: &str) -> AppResult<()> {
+        let removed = self.address_book_management_use_case()?.remove(name)?;
+        println!("{}", self.messages().address_removed(&removed.name));
+        Ok(())
+    }
+
+    fn run_addressbook_validate(&self) -> AppResult<()> {
+        let config = self.configuration()?;
+        let address_book = Self::address_book(config)?;
+        let report = AddressBookAuditUseCase::new(address_book).audit()?;
+
+        if report.is_clean() {
+            println!(
+                "{}",
+                self.messages().address_book_valid(report.total_entries)
+            );
+            return Ok(());
+        }
+        for issue in &report.issues {
+            println!("❌ {issue}");
+        }
+        let messages = self.messages();
+        let failure = Failure::AddressBookIssues(report.issues.len());
+        Err(AppError::new(ErrorKind::UnprocessableEntity)
+            .with_code("MC-CLI-005")
+            .with_message(messages.failure_message(failure))
+            .with_action(messages.failure_action(failure)))
+    }
+
+    fn run_addressbook_import(&self, args: &AddressBookImportArgs) -> AppResult<()> {
+        let format = args
+            .format
+            .or_else(|| AddressBookFormat::from_path(&args.path))
+            .ok_or_else(|| {
+                let messages = self.messages();
+                let failure = Failure::UnknownImportFormat(&args.path);
+                AppError::new(ErrorKind::BadRequest)
+                    .with_code("MC-CLI-016")
+                    .with_message(messages.failure_message(failure))
+                    .with_action(messages.failure_action(failure))
+            })?;
+        match format {
+            AddressBookFormat::Vcf => {
+                self.import_address_book(VcfAddressBookAdapter::load_from_vcf(&args.path)?, args)
+            }
+            AddressBookFormat::OutlookCsv => self.import_address_book(
+                OutlookCsvAddressBookAdapter::load_from_csv(&args.path)?,
+                args,
+            ),
+            AddressBookFormat::Xlsx => self.import_address_book(
+                XlsxAddressBookAdapter::load_from_xlsx(
+                    &args.path,
+                    &XlsxAddressBookLayout::default(),
+                )?,
+                args,
+            ),
+            AddressBookFormat::Thunderbird => self.import_address_book(
+                ThunderbirdAddressBookAdapter::load_from_abook(&args.path)?,
+                args,
+            ),
+        }
+    }
+
+    /// 読み込んだアドレスブックを設定されたパスのアドレスブックに取り込み、結果を表示する
+    fn import_address_book(
+        &self,
+        source: impl AddressBookPort,
+        args: &AddressBookImportArgs,
+    ) -> AppResult<()> {
+        let config = self.configuration()?;
+        let target = JsonAddressBookAdapter::load_or_empty(&config.address_book_path())?;
+        let report =
+            AddressBookImportUseCase::new(source, target).import(args.overwrite, args.dry_run)?;
+
+        let messages = self.messages();
+        for conflict in &report.conflicts {
+            println!(
+                "⚠️ {}",
+                messages.import_conflict(&conflict.existing.name, report.overwritten)
+            );
+        }
+        println!(
+            "{}",
+            messages.addresses_imported(
+                report.added.len(),
+                report.unchanged.len(),
+                report.conflicts.len()
+            )
+        );
+        Ok(())
+    }
+
+    /// 設定に従って月ごとの勤務実績のユースケースを作成する
+    fn work_time_report_use_case(
+        config: &AppConfiguration,
+    ) -> AppResult<WorkTimeReportUseCase<Box<dyn WorkTimePort>>> {
+        Ok(WorkTimeReportUseCase::new(Self::work_time_adapter(config)?)
+            .with_standard_hours(config.standard_work_hours()?)
+            .with_duration_format(config.duration_format.unwrap_or_default())
+            .with_time_zone(config.time_zone()?)
+            .with_holiday_calendar(JapaneseHolidayCalendarAdapter::new()))
+    }
+
+    fn run_worktime_show(&self, args: &ShowArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let time_zone = config.time_zone()?;
+        let date = args.date.unwrap_or(time_zone.today());
+        let duration_format = config.duration_format.unwrap_or_default();
+
+        if args.month {
+            let report = Self::work_time_report_use_case(config)?
+                .monthly_report(date.year(), date.month())?;
+            println!("{}", report.render(ReportFormat::Text));
+        } else if args.week {
+            let summary = WeeklySummaryUseCase::new(Self::work_time_adapter(config)?)
+                .with_time_zone(time_zone)
+                .week_of(date)?;
+            println!("{}", summary.render(duration_format));
+            println!();
+            println!("{}", summary.render_daily_table(duration_format));
+        } else {
+            match Self::work_time_adapter(config)?.load_record(date)? {
+                Some(record) => print_work_record(
+                    date,
+                    &record.localized(&time_zone),
+                    duration_format,
+                    &self.messages(),
+                ),
+                None => println!("{}", self.messages().no_work_record(&date.to_string())),
+            }
+        }
+        Ok(())
+    }
+
+    fn run_worktime_report(&self, args: &ReportArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let today = config.time_zone()?.today();
+        let report = Self::work_time_report_use_case(config)?.monthly_report(
+            args.year.unwrap_or(today.year()),
+            args.month.unwrap_or(today.month()),
+        )?;
+        let format = if args.markdown {
+            ReportFormat::Markdown
+        } else {
+            ReportFormat::Text
+        };
+        println!("{}", report.render(format));
+        Ok(())
+    }
+
+    fn run_worktime_edit(&self, args: &EditArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let use_case = WorkTimeEditUseCase::new(Self::work_time_adapter(config)?)
+            .with_time_zone(config.time_zone()?);
+
+        let record = use_case.edit(args.date, &args.to_edit())?;
+        print_work_record(
+            args.date,
+            &record,
+            config.duration_format.unwrap_or_default(),
+            &self.messages(),
+        );
+        Ok(())
+    }
+
+    fn run_worktime_export(&self, args: &ExportArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let today = config.time_zone()?.today();
+        let year = args.year.unwrap_or(today.year());
+        let month = args.month.unwrap_or(today.month());
+        let work_time = Self::work_time_adapter(config)?;
+
+        let path = match args.format {
+            ExportFormat::Csv => WorkTimeExportUseCase::new(
+                work_time,
+                CsvWorkTimeExportAdapter::new(&config.output_dir),
+            )
+            .export_month(year, month)?,
+            ExportFormat::Xlsx => WorkTimeExportUseCase::new(
+                work_time,
+                XlsxWorkTimeExportAdapter::new(&config.output_dir),
+            )
+            .export_month(year, month)?,
+        };
+        println!("{}", self.messages().written(Written::WorkTimes, &path));
+        Ok(())
+    }
+
+    fn run_worktime_flex(&self, args: &FlexArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let time_zone = config.time_zone()?;
+        let balance = FlexBalanceUseCase::new(Self::work_time_adapter(config)?)
+            .with_standard_hours(config.standard_work_hours()?)
+            .with_time_zone(time_zone)
+            .with_holiday_calendar(JapaneseHolidayCalendarAdapter::new())
+            .as_of(args.date.unwrap_or(time_zone.today()))?;
+        println!(
+            "{}",
+            balance.render(config.duration_format.unwrap_or_default())
+        );
+        Ok(())
+    }
+
+    fn run_worktime_stats(&self, args: &StatsArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let time_zone = config.time_zone()?;
+        let to = args.to.unwrap_or(time_zone.today());
+        let from = args.from.unwrap_or(to - Days::new(29));
+        let statistics = WorkStatisticsUseCase::new(Self::work_time_adapter(config)?)
+            .with_duration_format(config.duration_format.unwrap_or_default())
+            .with_time_zone(time_zone)
+            .statistics(from, to)?;
+        let format = if args.json {
+            StatisticsFormat::Json
+        } else {
+            StatisticsFormat::Text
+        };
+        println!("{}", statistics.render(format)?);
+        Ok(())
+    }
+
+    fn run_worktime_import(&self, args: &WorkTimeImportArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let source = TimeTrackerCsvAdapter::load_from_csv(&args.path, &config.time_zone()?)?;
+        let report = WorkTimeImportUseCase::new(source, Self::work_time_adapter(config)?)
+            .import(args.dry_run)?;
+        println!(
+            "{}",
+            self.messages()
+                .work_times_imported(report.imported.len(), report.skipped.len())
+        );
+        Ok(())
+    }
+
+    /// 常駐し、設定のschedulesに従って処理を実行する
+    ///
+    /// 設定ファイルとメールテンプレートは変更を検知して再読み込みし、
+    /// 変更後の内容が不正な場合は直前の内容を使い続ける（schedulesとaddress_book_fileの変更は再起動後に反映する）。
+    /// アドレスブックは起動時のファイルを保持し、ファイルが更新された場合のみ読み込み直す
+    fn run_daemon(&self, args: &DaemonArgs) -> AppResult<()> {
+        let config = self.configuration()?;
+        let config_path =
+            JsonConfigurationAdapter::locate(self.config.as_deref())?.get_absolute_config_path()?;
+        let configuration =
+            HotReloadConfigurationAdapter::new(self.configuration_adapter()?, &config_path);
+        let mail_config: Box<dyn MailConfigPort> = match &config.mail_templates_url {
+            // 一元管理されたテンプレートは読み込むたびにETagで更新を確認する
+            Some(_) => Self::mail_config_adapter(config),
+            None => {
+                let adapter = Self::mail_config_file_adapter(config);
+                let templates_path = adapter.config_file_path().to_path_buf();
+                Box::new(HotReloadMailConfigAdapter::new(adapter, &templates_path))
+            }
+        };
+        // 常駐中は端末から入力できないため、検出したパスは書き戻さない
+        let thunderbird_detection = self.thunderbird_detection(false)?;
+        // 処理のたびにアドレスブックを解析し直さないよう、更新された場合のみ読み込む
+        let address_book = Arc::new(CachingAddressBookAdapter::json(&config.address_book_path()));
+
+        SchedulerUseCase::new(config.schedules.clone())
+            .with_time_zone(config.time_zone()?)
+            .run(|action, at| {
+                Self::run_scheduled_action(
+                    (action, at),
+                    &configuration,
+                    &mail_config,
+                    |config| Self::cached_address_book(config, &address_book),
+                    Self::work_time_adapter,
+                    &thunderbird_detection,
+                    args.dry_run,
+                )
+            })
+    }
+
+    fn run_man(&self, args: &ManArgs) -> AppResult<()> {
+        let messages = self.messages();
+        let command = messages.localize_command(Self::command());
+        let Some(output) = &args.output else {
+            return clap_mangen::Man::new(command)
+                .render(&mut io::stdout())
+                .map_err(|e| {
+                    AppError::new(ErrorKind::InternalServerError)
+                        .with_code("MC-CLI-006")
+                        .with_message(messages.failure_message(Failure::ManPageOutput))
+                        .with_action(messages.failure_action(Failure::ManPageOutput))
+                        .with_source(e)
+                });
+        };
+
+        let failure = Failure::ManPageWrite(output);
+        let page_error = |e: io::Error| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CLI-007")
+                .with_message(messages.failure_message(failure))
+                .with_action(messages.failure_action(failure))
+                .with_source(e)
+        };
+        fs::create_dir_all(output).map_err(page_error)?;
+        clap_mangen::generate_to(command, output).map_err(page_error)?;
+        println!("{}", messages.written(Written::ManPages, output));
+        Ok(())
+    }
+
+    /// スケジュールされた処理を実行する
+    ///
+    /// 設定は実行の都度、変更されていれば読み込み直す。常駐中は端末から入力できないため、
+    /// 送信前の確認は行わず、作業内容は日報のメモからのみ取得する
+    ///
+    /// ## Arguments
+    /// * `(action, at)` - 実行する処理と、実行時点の日時
+    /// * `configuration` - 変更を検知して再読み込みする設定
+    /// * `mail_config` - 変更を検知して再読み込みするメールテンプレート
+    /// * `address_book` - 設定からアドレスブックを作成する関数
+    /// * `work_time_adapter` - 設定から作業記録の保存先を作成する関数
+    /// * `thunderbird_detection` - Thunderbird実行ファイルの自動検出に使用するユースケース
+    /// * `is_dry_run` - ドライランモード（作業記録は変更しない）
+    fn run_scheduled_action(
+        (action, at): (ScheduledAction, DateTime<FixedOffset>),
+        configuration: &impl ConfigurationPort,
+        mail_config: &impl MailConfigPort,
+        address_book: impl Fn(&AppConfiguration) -> AppResult<CompositeAddressBookAdapter>,
+        work_time_adapter: impl Fn(&AppConfiguration) -> AppResult<Box<dyn WorkTimePort>>,
+        thunderbird_detection: &ThunderbirdDetection,
+        is_dry_run: bool,
+    ) -> AppResult<()> {
+        let config = &configuration.load_configuration()?;
+        let time_zone = config.time_zone()?;
+        let at = time_zone.localize(at);
+        let use_case = || {
+            Self::unattended_remote_work_mail_use_case(
+                config,
+                address_book(config)?,
+                configuration,
+                mail_config,
+                work_time_adapter(config)?,
+                Box::new(AutoConfirmationAdapter),
+                Self::mail_client(config, thunderbird_detection, false)?,
+            )
+        };
+        match action {
+            ScheduledAction::StartMail => {
+                let work_time = work_time_adapter(config)?;
+                if let Some(start) = work_time
+                    .load_record(at.date_naive())?
+                    .and_then(|record| record.localized(&time_zone).start)
+                {
+                    tracing::info!(
+                        "作業開始は{}に記録済みのため、開始メールを作成しません",
+                        start.as_str()
+                    );
+                    return Ok(());
+                }
+                let use_case = use_case()?;
+                if use_case.day_off_on(at.date_naive())?.is_some() {
+                    tracing::info!("今日は休日のため、開始メールを作成しません");
+                    return Ok(());
+                }
+                use_case.send_remote_work_start_at(&at, is_dry_run)
+            }
+            ScheduledAction::EndReminder => EndReminderUseCase::new(
+                work_time_adapter(config)?,
+                DesktopNotificationAdapter::new(),
+            )
+            .with_remind_after(config.end_reminder_time()?)
+            .with_time_zone(time_zone)
+            .remind()
+            .map(|_| ()),
+            ScheduledAction::WeeklyReport => use_case()?.send_weekly_report(is_dry_run),
+        }
+    }
+}
+
+/// 1日分の作業記録を項目ごとに表示する
+fn print_work_record(
+    date: NaiveDate,
+    record: &WorkRecord,
+    duration_format: DurationFormat,
+    messages: &Messages,
+) {
+    let time =
+        |time: &Option<WorkTime>| time.as_ref().map_or("--:--", WorkTime::as_str).to_string();
+    let [start, end, break_time, worked, notes_label] = messages.record_labels();
+    println!("{date} ({})", messages.weekday_name(date));
+    println!("{start}: {}", time(&record.start));
+    println!("{end}: {}", time(&record.end));
+    println!(
+        "{break_time}: {}",
+        duration_format.format(record.break_minutes())
+    );
+    println!(
+        "{worked}: {}",
+        record.duration().map_or_else(
+            || "--:--".to_string(),
+            |minutes| duration_format.format(minutes)
+        )
+    );
+    if let Some(notes) = &record.notes {
+        println!("{notes_label}: {notes}");
+    }
+}
+
+/// アドレスブックのエントリを1行ずつ表示する
+fn print_entries(entries: &[AddressBookEntry], messages: &Messages) {
+    for entry in entries {
+        let target = match &entry.address {
+            Some(address) => match &entry.display_name {
+                Some(display_name) => format!("{display_name} <{address}>"),
+                None => address.clone(),
+            },
+            None => format!("{} {}", messages.group_label(), entry.members.join(", ")),
+        };
+        let aliases = if entry.aliases.is_empty() {
+            String::new()
+        } else {
+            messages.aliases(&entry.aliases.join(", "))
+        };
+        println!("{}\t{target}{aliases}", entry.name);
+    }
+    println!("{}", messages.count(entries.len()));
+}
+
+/// `HH:MM`形式の時刻を解析する
+fn parse_work_time(time: &str) -> Result<WorkTime, String> {
+    WorkTime::new(time).map_err(|e| e.message.into_owned())
+}
+
+/// `名前=値`形式の指定を解析する
+fn parse_assignment(assignment: &str) -> Result<(String, String), String> {
+    match assignment.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!(
+            "`名前=値`の形式で指定してください。詳細: {assignment}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_generate_man_pages() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let output = dir.path().to_string_lossy().into_owned();
+        let result = Cli::try_parse_from([APP_NAME, "man", "--output", &output])
+            .unwrap()
+            .run();
+        let root = fs::read_to_string(dir.path().join(format!("{APP_NAME}.1")));
+        let pages = fs::read_dir(dir.path()).map(|entries| entries.count());
+
+        assert!(result.is_ok());
+        assert!(root.unwrap().contains(".TH"));
+        assert!(pages.unwrap() > 1);
+    }
+
+    #[test]
+    fn test_log_level() {
+        let level = |args: &[&str]| {
+            Cli::try_parse_from([APP_NAME].iter().chain(args).chain(&["start"]))
+                .unwrap()
+                .log_level()
+        };
+
+        assert_eq!(level(&[]), LevelFilter::INFO);
+        assert_eq!(level(&["-v"]), LevelFilter::DEBUG);
+        assert_eq!(level(&["-vv"]), LevelFilter::TRACE);
+        assert_eq!(level(&["--quiet"]), LevelFilter::ERROR);
+        assert!(Cli::try_parse_from([APP_NAME, "-v", "-q", "start"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_subcommands() {
+        let cli = Cli::try_parse_from([
+            APP_NAME,
+            "preview",
+            "release_notice",
+            "--var",
+            "version=v2.1",
+            "--set",
+            "from=山田",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.overrides, vec!["from=山田"]);
+        match cli.command {
+            Command::Preview(args) => {
+                assert_eq!(args.mail_type, "release_notice");
+                assert_eq!(args.vars, vec![("version".to_string(), "v2.1".to_string())]);
+                assert_eq!(args.output, None);
+            }
+            command => panic!("unexpected command: {command:?}"),
+        }
+
+        let cli = Cli::try_parse_from([APP_NAME, "end", "--dry-run"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::End(SendArgs { dry_run: true, .. })
+        ));
+
+        let cli = Cli::try_parse_from([
+            APP_NAME,
+            "start",
+            "--date",
+            "2024-06-01",
+            "--time",
+            "09:00",
+            "--no-mail",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Start(args) => {
+                let time_zone = WorkTimeZone::parse("Asia/Tokyo").unwrap();
+                let started_at = args.recorded_at(&time_zone).unwrap();
+                assert_eq!(started_at.to_rfc3339(), "2024-06-01T09:00:00+09:00");
+                assert!(args.no_mail);
+            }
+            command => panic!("unexpected command: {command:?}"),
+        }
+        assert!(Cli::try_parse_from([APP_NAME, "start", "--date", "2024-06-01"]).is_err());
+        assert!(Cli::try_parse_from([APP_NAME, "end", "--time", "25:00"]).is_err());
+        assert!(Cli::try_parse_from([APP_NAME, "end", "--yes"]).unwrap().yes);
+
+        let cli = Cli::try_parse_from([
+            APP_NAME,
+            "start",
+            "--to",
+            "佐藤さん",
+            "--cc",
+            "総務,人事",
+            "--cc",
+            "経理",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Start(args) => assert_eq!(
+                args.recipient_overrides(),
+                RecipientOverrides::new()
+                    .with_to(["佐藤さん"])
+                    .with_cc(["総務", "人事", "経理"])
+            ),
+            command => panic!("unexpected command: {command:?}"),
+        }
+        assert!(Cli::try_parse_from([APP_NAME, "start", "--no-mail", "--to", "佐藤さん"]).is_err());
+        assert!(Cli::try_parse_from([APP_NAME, "start", "--replace-recipients"]).is_err());
+        assert!(
+            Cli::try_parse_from([APP_NAME, "end", "--replace-recipients", "--cc", "総務"]).is_ok()
+        );
+
+        let cli = Cli::try_parse_from([APP_NAME, "config", "show", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Config(ConfigCommand::Show { json: true })
+        ));
+        let cli = Cli::try_parse_from([APP_NAME, "daemon", "--dry-run"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Daemon(DaemonArgs { dry_run: true })
+        ));
+        let cli = Cli::try_parse_from([APP_NAME, "config", "edit"]).unwrap();
+        assert!(matches!(cli.command, Command::Config(ConfigCommand::Edit)));
+
+        assert!(Cli::try_parse_from([APP_NAME, "start", "--queue"]).is_ok());
+        assert!(Cli::try_parse_from([APP_NAME, "start", "--queue", "--dry-run"]).is_err());
+        let cli = Cli::try_parse_from([
+            APP_NAME,
+            "mail",
+            "release_notice",
+            "--var",
+            "version=v2.1",
+            "--queue",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Mail(args) => {
+                assert_eq!(args.mail_type, "release_notice");
+                assert!(args.compose.queue);
+                assert!(!args.compose.dry_run);
+            }
+            command => panic!("unexpected command: {command:?}"),
+        }
+        let cli = Cli::try_parse_from([APP_NAME, "notice", "late", "--time", "10:30"]).unwrap();
+        match cli.command {
+            Command::Notice(args) => {
+                assert!(matches!(args.kind, NoticeKind::Late));
+                assert_eq!(args.time, WorkTime::new("10:30").unwrap());
+                assert_eq!(args.date, None);
+            }
+            command => panic!("unexpected command: {command:?}"),
+        }
+        let cli = Cli::try_parse_from([APP_NAME, "outbox", "flush", "--dry-run"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Outbox(OutboxCommand::Flush { dry_run: true })
+        ));
+    }
+
+    #[test]
+    fn test_address_book_format_from_path() {
+        let format = |path: &str| AddressBookFormat::from_path(Path::new(path));
+
+        assert!(matches!(
+            format("contacts.VCF"),
+            Some(AddressBookFormat::Vcf)
+        ));
+        assert!(matches!(
+            format("contacts.csv"),
+            Some(AddressBookFormat::OutlookCsv)
+        ));
+        assert!(matches!(
+            format("contacts.xlsx"),
+            Some(AddressBookFormat::Xlsx)
+        ));
+        assert!(matches!(
+            format("abook.sqlite"),
+            Some(AddressBookFormat::Thunderbird)
+        ));
+        assert!(format("contacts.txt").is_none());
+        assert!(format("contacts").is_none());
+    }
+
+    #[test]
+    fn test_parse_worktime() {
+        let cli = Cli::try_parse_from([APP_NAME, "worktime", "show", "--week"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Worktime(WorkTimeCommand::Show(ShowArgs {
+                week: true,
+                month: false,
+                date: None,
+            }))
+        ));
+        assert!(Cli::try_parse_from([APP_NAME, "worktime", "show", "--week", "--month"]).is_err());
+
+        let cli = Cli::try_parse_from([
+            APP_NAME,
+            "worktime",
+            "edit",
+            "2024-06-03",
+            "--end",
+            "18:30",
+            "--note",
+            "打刻漏れ",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Worktime(WorkTimeCommand::Edit(args)) => {
+                assert_eq!(args.date, NaiveDate::from_ymd_opt(2024, 6, 3).unwrap());
+                let edit = args.to_edit();
+                assert_eq!(edit.start, None);
+                assert_eq!(edit.end.as_ref().map(WorkTime::as_str), Some("18:30"));
+                assert_eq!(edit.note.as_deref(), Some("打刻漏れ"));
+            }
+            command => panic!("unexpected command: {command:?}"),
+        }
+
+        let cli = Cli::try_parse_from([APP_NAME, "worktime", "export", "--month", "5"]).unwrap();
+        match cli.command {
+            Command::Worktime(WorkTimeCommand::Export(args)) => {
+                assert_eq!(args.month, Some(5));
+                assert_eq!(args.format, ExportFormat::Csv);
+            }
+            command => panic!("unexpected command: {command:?}"),
+        }
+        let cli =
+            Cli::try_parse_from([APP_NAME, "worktime", "export", "--format", "xlsx"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Worktime(WorkTimeCommand::Export(ExportArgs {
+                format: ExportFormat::Xlsx,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_parse_addressbook_add() {
+        let cli = Cli::try_parse_from([
+            APP_NAME,
+            "addressbook",
+            "add",
+            "開発チーム",
+            "--members",
+            "山田,田中",
+            "--alias",
+            "dev",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Addressbook(AddressBookCommand::Add(args)) => {
+                let entry = args.to_entry();
+                assert!(entry.is_group());
+                assert_eq!(entry.members, vec!["山田", "田中"]);
+                assert_eq!(entry.aliases, vec!["dev"]);
+            }
+            command => panic!("unexpected command: {command:?}"),
+        }
+
+        assert!(Cli::try_parse_from([APP_NAME, "addressbook", "add", "山田"]).is_err());
+        assert!(Cli::try_parse_from([APP_NAME, "addressbook", "audit"]).is_ok());
+
+        assert!(Cli::try_parse_from([APP_NAME, "preview", "x", "--var", "novalue"]).is_err());
+        assert!(Cli::try_parse_from([APP_NAME, "worktime", "report", "--month", "13"]).is_err());
+    }
+
+    /// 入出力先を一時ディレクトリにした設定
+    struct StubConfiguration(PathBuf);
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            let dir = self.0.to_string_lossy().into_owned();
+            Ok(AppConfiguration {
+                from: "山田".to_string(),
+                department: "開発部".to_string(),
+                thunderbird_exe: "thunderbird".to_string(),
+                input_dir: dir.clone(),
+                output_dir: dir,
+                timezone: Some("Asia/Tokyo".to_string()),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_scheduled_start_mail_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("address_book.json"),
+            r#"[{ "name": "上長", "address": "boss@example.com" }]"#,
+        )
+        .unwrap();
+        let templates = dir.path().join("mail_templates.json");
+        fs::write(
+            &templates,
+            r#"{
+                "remote_work_start": {
+                    "to_names": ["上長"],
+                    "cc_names": [],
+                    "subject_template": "【在宅勤務開始】{department} {from} {time}",
+                    "body_template": "本日は在宅勤務を開始いたします。"
+                }
+            }"#,
+        )
+        .unwrap();
+        let work_time =
+            || JsonWorkTimeAdapter::new(dir.path().to_string_lossy(), "work_times.json");
+        // 休日の判定で結果が変わらないよう、祝日でない月曜日に実行する
+        let at = DateTime::parse_from_rfc3339("2025-06-02T09:00:00+09:00").unwrap();
+
+        // 常駐モードのドライランでも作業開始日時を記録しない
+        let result = Cli::run_scheduled_action(
+            (ScheduledAction::StartMail, at),
+            &StubConfiguration(dir.path().to_path_buf()),
+            &JsonMailConfigAdapter::from_path(templates.to_string_lossy()),
+            Cli::address_book,
+            |_| Ok(Box::new(work_time()) as Box<dyn WorkTimePort>),
+            &ThunderbirdDetectionUseCase::new(
+                JsonConfigurationAdapter::new(dir.path().join("config.json").to_string_lossy()),
+                ThunderbirdLocatorAdapter::new(),
+                LocalEnvironmentProbeAdapter::new(),
+            ),
+            true,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(work_time().load_record(at.date_naive()).unwrap(), None);
+        assert!(!dir.path().join("work_times.json").exists());
+    }
+}
@@ -0,0 +1,940 @@
+use crate::{
+    application::usecases::remote_work_mail_use_case::DayOff,
+    domain::value_objects::{language::Language, mail_template::weekday_name},
+};
+use chrono::NaiveDate;
+use clap::Command;
+use share::error::catalog::Locale;
+use std::path::Path;
+
+/// 英語のヘルプ（キーはサブコマンドのパス、引数は`パス:引数ID`）
+///
+/// 日本語のヘルプはCLIの定義のドキュメントコメントをそのまま使用する
+const ENGLISH_HELP: &[(&str, &str)] = &[
+    (
+        "",
+        "Compose remote work notification mails and record working hours",
+    ),
+    (
+        ":config",
+        "Path to the configuration file (defaults to MAIL_COMPOSER_CONFIG, then the standard location)",
+    ),
+    (
+        ":overrides",
+        "Override a setting for this run only (e.g. --set from=Yamada, repeatable)",
+    ),
+    (
+        ":verbose",
+        "Show detailed logs (-v for debug, -vv for trace)",
+    ),
+    (":quiet", "Show errors only"),
+    (":yes", "Skip the confirmation before composing mails"),
+    (
+        "start",
+        "Record the start time and compose the remote work start mail",
+    ),
+    (
+        "end",
+        "Record the end time and compose the remote work end mail",
+    ),
+    (
+        "preview",
+        "Show the resolved recipients, subject and body without sending",
+    ),
+    (
+        "preview:mail_type",
+        "Mail type in mail_templates.json (e.g. remote_work_start)",
+    ),
+    (
+        "preview:vars",
+        "Value passed to the template (e.g. --var version=v2.1, repeatable)",
+    ),
+    (
+        "preview:output",
+        "File to write the preview to (defaults to standard output)",
+    ),
+    ("config", "Create and diagnose the configuration file"),
+    (
+        "config init",
+        "Interactively create the configuration file and mail templates",
+    ),
+    (
+        "config doctor",
+        "Diagnose the configuration, address book, templates and Thunderbird",
+    ),
+    (
+        "config show",
+        "Show the effective configuration including environment and --set overrides",
+    ),
+    ("config show:json", "Show as JSON"),
+    (
+        "config path",
+        "Show the path of the configuration file in use",
+    ),
+    (
+        "config validate",
+        "Validate the effective configuration including environment and --set overrides",
+    ),
+    (
+        "config preflight",
+        "Check that every template recipient resolves in the address book",
+    ),
+    (
+        "config edit",
+        "Open the configuration file in $VISUAL/$EDITOR and validate it afterwards",
+    ),
+    (
+        "addressbook",
+        "List, search, add, remove and validate address book entries",
+    ),
+    ("addressbook list", "Show all entries"),
+    (
+        "addressbook search",
+        "Search entries by name, domain or group",
+    ),
+    (
+        "addressbook search:text",
+        "Text contained in the name or an alias",
+    ),
+    (
+        "addressbook search:domain",
+        "Domain of the mail address (e.g. example.com)",
+    ),
+    ("addressbook search:group", "Group the entry belongs to"),
+    ("addressbook add", "Add a person or a group"),
+    (
+        "addressbook add:name",
+        "Entry name (the name used in to_names/cc_names of templates)",
+    ),
+    (
+        "addressbook add:address",
+        "Mail address (use --members for a group)",
+    ),
+    (
+        "addressbook add:members",
+        "Names of the group members (comma separated)",
+    ),
+    (
+        "addressbook add:display_name",
+        "Name shown in the mail recipients",
+    ),
+    ("addressbook add:aliases", "Alias (repeatable)"),
+    ("addressbook rm", "Remove a person or a group"),
+    ("addressbook rm:name", "Name of the entry to remove"),
+    (
+        "addressbook validate",
+        "Detect invalid mail addresses, duplicates and other problems",
+    ),
+    (
+        "addressbook import",
+        "Import another address book (vCard, Outlook contacts CSV, Excel, Thunderbird)",
+    ),
+    ("addressbook import:path", "Path of the file to import"),
+    (
+        "addressbook import:format",
+        "Format of the file (defaults to guessing from the extension)",
+    ),
+    (
+        "addressbook import:overwrite",
+        "Replace existing entries that differ with the imported ones",
+    ),
+    (
+        "addressbook import:dry_run",
+        "Only show the result without saving the address book",
+    ),
+    ("worktime", "Inspect work time records"),
+    (
+        "worktime show",
+        "Show work time records for a day, week or month",
+    ),
+    (
+        "worktime show:week",
+        "Show the week (starting on Monday) of the base date",
+    ),
+    (
+        "worktime show:month",
+        "Show the monthly report of the base date",
+    ),
+    ("worktime show:date", "Base date (defaults to today)"),
+    ("worktime report", "Show the monthly work report"),
+    (
+        "worktime report:year",
+        "Target year (defaults to this year)",
+    ),
+    (
+        "worktime report:month",
+        "Target month (defaults to this month)",
+    ),
+    ("worktime report:markdown", "Output as a Markdown table"),
+    (
+        "worktime edit",
+        "Correct the start/end time and notes of a day's record",
+    ),
+    ("worktime edit:date", "Target date (e.g. 2024-06-01)"),
+    ("worktime edit:start", "Corrected start time (e.g. 09:00)"),
+    ("worktime edit:end", "Corrected end time (e.g. 18:00)"),
+    ("worktime edit:note", "Note appended to the record"),
+    (
+        "worktime export",
+        "Export a month of records for pasting into the attendance sheet",
+    ),
+    (
+        "worktime export:year",
+        "Target year (defaults to this year)",
+    ),
+    (
+        "worktime export:month",
+        "Target month (defaults to this month)",
+    ),
+    ("worktime export:format", "Export format"),
+    (
+        "worktime flex",
+        "Show the flex time balance from the start of the month to the base date",
+    ),
+    ("worktime flex:date", "Base date (defaults to today)"),
+    (
+        "worktime stats",
+        "Summarize start/end times and working hours over a period",
+    ),
+    (
+        "worktime stats:from",
+        "First day of the period (defaults to the 30 days up to the last day)",
+    ),
+    (
+        "worktime stats:to",
+        "Last day of the period (defaults to today)",
+    ),
+    ("worktime stats:json", "Output as JSON"),
+    (
+        "worktime import",
+        "Import a CSV exported from a time tracking service as work time records",
+    ),
+    ("worktime import:path", "Path of the CSV file to import"),
+    (
+        "worktime import:dry_run",
+        "Only show the result without saving the records",
+    ),
+    (
+        "mail",
+        "Compose a mail of any mail type in mail_templates.json",
+    ),
+    (
+        "mail:mail_type",
+        "Mail type in mail_templates.json (e.g. release_notice)",
+    ),
+    (
+        "mail:vars",
+        "Value passed to the template (e.g. --var version=v2.1, repeatable)",
+    ),
+    (
+        "merge",
+        "Compose a separate mail for each recipient in the template's to_names (mail merge)",
+    ),
+    (
+        "merge:mail_type",
+        "Mail type in mail_templates.json ({recipient_name} and {recipient_address} are available)",
+    ),
+    ("leave", "Compose a leave request mail"),
+    ("leave:start", "First day of the leave (e.g. 2024-06-03)"),
+    (
+        "leave:end",
+        "Last day of the leave (defaults to the first day only)",
+    ),
+    ("leave:reason", "Reason for the leave"),
+    (
+        "notice",
+        "Compose a late arrival or early leave mail and note it in the work time record",
+    ),
+    ("notice:kind", "Kind of notice"),
+    (
+        "notice:time",
+        "Expected arrival or leaving time (e.g. 10:30)",
+    ),
+    ("notice:date", "Target date (defaults to today)"),
+    ("notice:reason", "Reason for arriving late or leaving early"),
+    ("trip", "Compose a business trip mail"),
+    ("trip:start", "First day of the trip (e.g. 2024-06-03)"),
+    ("trip:end", "Last day of the trip (defaults to a day trip)"),
+    ("trip:destination", "Destination of the trip"),
+    (
+        "trip:suppress_start_mail",
+        "Record the trip on each day so that no remote work start mail is composed",
+    ),
+    ("weekly", "Compose the weekly report mail for this week"),
+    ("outbox", "Handle the queue of mails waiting to be sent"),
+    ("outbox flush", "Resend every mail in the queue"),
+    (
+        "outbox flush:dry_run",
+        "Show the mails that would be resent without launching the mail client (keeps the queue)",
+    ),
+    (
+        "daemon",
+        "Stay resident and compose start mails or notify forgotten end records according to schedules",
+    ),
+    (
+        "daemon:dry_run",
+        "Show the mails that would be composed without launching the mail client",
+    ),
+    ("man", "Generate man pages (roff) from the CLI definition"),
+    (
+        "man:output",
+        "Directory to write a page per subcommand to (defaults to printing the main page)",
+    ),
+];
+
+/// メールを作成するサブコマンドに共通の引数の英語のヘルプ（キーは引数ID）
+const ENGLISH_SEND_HELP: &[(&str, &str)] = &[
+    (
+        "dry_run",
+        "Show the mail that would be composed without launching the mail client",
+    ),
+    (
+        "date",
+        "Date to backfill a forgotten record (e.g. 2024-06-01, requires --time)",
+    ),
+    ("time", "Time to record (e.g. 09:00, defaults to now)"),
+    ("no_mail", "Only record the time without composing a mail"),
+    (
+        "queue",
+        "Save the mail to the outbox without launching the mail client (send it with `outbox flush`)",
+    ),
+    (
+        "to",
+        "Recipient added to the template's To (address book name, comma separated, repeatable)",
+    ),
+    (
+        "cc",
+        "Recipient added to the template's Cc (address book name, comma separated, repeatable)",
+    ),
+    (
+        "replace_recipients",
+        "Replace the template's recipients with --to/--cc instead of adding to them",
+    ),
+];
+
+/// 英語の値の表示名（キーは引数ID）
+const ENGLISH_VALUE_NAMES: &[(&str, &str)] = &[
+    ("overrides", "FIELD=VALUE"),
+    ("vars", "NAME=VALUE"),
+    ("to", "NAME"),
+    ("cc", "NAME"),
+];
+
+/// CLIのヘルプと処理結果の文言を表示言語に合わせて提供する
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Messages {
+    language: Language,
+}
+
+impl Messages {
+    /// 新しいMessagesを作成する
+    ///
+    /// ## Arguments
+    /// * `language` - 表示言語
+    ///
+    /// ## Returns
+    /// * Messagesのインスタンス
+    pub fn new(language: Language) -> Self {
+        Self { language }
+    }
+
+    /// 表示言語を取得する
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// エラーメッセージカタログの表示言語を取得する
+    pub fn locale(&self) -> Locale {
+        match self.language {
+            Language::Japanese => Locale::Japanese,
+            Language::English => Locale::English,
+        }
+    }
+
+    /// CLIの定義のヘルプを表示言語に置き換える
+    ///
+    /// ## Arguments
+    /// * `command` - 日本語のヘルプを持つCLIの定義
+    ///
+    /// ## Returns
+    /// * ヘルプを置き換えたCLIの定義（日本語の場合はそのまま）
+    pub fn localize_command(&self, command: Command) -> Command {
+        match self.language {
+            Language::Japanese => command,
+            Language::English => localize_english(command, ""),
+        }
+    }
+
+    /// 日本語と英語の文言から表示言語のものを選ぶ
+    fn pick(&self, japanese: &'static str, english: &'static str) -> &'static str {
+        match self.language {
+            Language::Japanese => japanese,
+            Language::English => english,
+        }
+    }
+
+    /// エラーの対処法の見出し
+    pub fn action_label(&self) -> &'static str {
+        self.pick("対処", "Action")
+    }
+
+    /// 作業開始時刻を記録した旨
+    pub fn start_recorded(&self, at: &str) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ 作業開始時刻を記録しました: {at}"),
+            Language::English => format!("✅ Recorded the start time: {at}"),
+        }
+    }
+
+    /// 作業終了時刻を記録した旨
+    pub fn end_recorded(&self, at: &str) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ 作業終了時刻を記録しました: {at}"),
+            Language::English => format!("✅ Recorded the end time: {at}"),
+        }
+    }
+
+    /// 在宅勤務開始メールを作成した旨
+    pub fn start_mail_composed(&self) -> &'static str {
+        self.pick(
+            "✅ 在宅勤務開始メールを作成しました。",
+            "✅ Composed the remote work start mail.",
+        )
+    }
+
+    /// 在宅勤務終了メールを作成した旨
+    pub fn end_mail_composed(&self) -> &'static str {
+        self.pick(
+            "✅ 在宅勤務終了メールを作成しました。",
+            "✅ Composed the remote work end mail.",
+        )
+    }
+
+    /// ファイルを書き出した旨
+    ///
+    /// ## Arguments
+    /// * `kind` - 書き出した内容
+    /// * `path` - 書き出したファイルまたはディレクトリ
+    pub fn written(&self, kind: Written, path: &Path) -> String {
+        let path = path.display();
+        match (self.language, kind) {
+            (Language::Japanese, Written::Preview) => {
+                format!("✅ プレビューを書き出しました: {path}")
+            }
+            (Language::Japanese, Written::WorkTimes) => {
+                format!("✅ 作業記録を書き出しました: {path}")
+            }
+            (Language::Japanese, Written::ManPages) => {
+                format!("✅ manページを書き出しました: {path}")
+            }
+            (Language::English, Written::Preview) => format!("✅ Wrote the preview: {path}"),
+            (Language::English, Written::WorkTimes) => {
+                format!("✅ Exported the work time records: {path}")
+            }
+            (Language::English, Written::ManPages) => format!("✅ Wrote the man pages: {path}"),
+        }
+    }
+
+    /// 設定ファイルとメールテンプレートを作成した旨
+    pub fn configuration_created(&self) -> &'static str {
+        self.pick(
+            "✅ 設定ファイルとメールテンプレートを作成しました。",
+            "✅ Created the configuration file and mail templates.",
+        )
+    }
+
+    /// 初期設定でアドレスブックに追加した宛先
+    pub fn added_addresses(&self, names: &str) -> String {
+        match self.language {
+            Language::Japanese => format!("   アドレスブックに追加した宛先: {names}"),
+            Language::English => format!("   Added to the address book: {names}"),
+        }
+    }
+
+    /// 設定に問題がない旨
+    pub fn configuration_valid(&self) -> &'static str {
+        self.pick(
+            "✅ 設定に問題はありません。",
+            "✅ The configuration is valid.",
+        )
+    }
+
+    /// アドレスブックに問題がない旨
+    pub fn address_book_valid(&self, total_entries: usize) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ {total_entries}件のエントリに問題はありません。"),
+            Language::English => format!("✅ No problems found in {total_entries} entries."),
+        }
+    }
+
+    /// メールを作成した旨
+    pub fn mail_composed(&self, mail_type: &str) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ {mail_type}のメールを作成しました。"),
+            Language::English => format!("✅ Composed the {mail_type} mail."),
+        }
+    }
+
+    /// 差し込み送信でメールを作成した旨
+    pub fn mails_composed(&self, count: usize) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ {count}件のメールを作成しました。"),
+            Language::English => format!("✅ Composed {count} mails."),
+        }
+    }
+
+    /// 送信待ちキューのメールを再送した旨
+    pub fn outbox_flushed(&self, sent: usize) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ 送信待ちのメールを{sent}件再送しました。"),
+            Language::English => format!("✅ Resent {sent} queued mails."),
+        }
+    }
+
+    /// 全てのテンプレートの宛先を解決できた旨
+    pub fn recipients_resolved(&self, mail_types: usize) -> String {
+        match self.language {
+            Language::Japanese => {
+                format!("✅ {mail_types}種類のメールテンプレートの宛先を全て解決できました。")
+            }
+            Language::English => {
+                format!("✅ Resolved all recipients of {mail_types} mail templates.")
+            }
+        }
+    }
+
+    /// アドレスブックを取り込んだ旨
+    pub fn addresses_imported(&self, added: usize, unchanged: usize, conflicts: usize) -> String {
+        match self.language {
+            Language::Japanese => format!(
+                "✅ {added}件を追加しました（変更なし: {unchanged}件、競合: {conflicts}件）。"
+            ),
+            Language::English => format!(
+                "✅ Added {added} entries ({unchanged} unchanged, {conflicts} conflicting)."
+            ),
+        }
+    }
+
+    /// 取り込み元と内容が異なるエントリの表示
+    pub fn import_conflict(&self, name: &str, overwritten: bool) -> String {
+        match (self.language, overwritten) {
+            (Language::Japanese, true) => format!("{name}を取り込んだ内容で上書きしました。"),
+            (Language::Japanese, false) => {
+                format!("{name}は登録済みの内容と異なります（--overwriteで上書きします）。")
+            }
+            (Language::English, true) => format!("Overwrote {name} with the imported entry."),
+            (Language::English, false) => {
+                format!("{name} differs from the existing entry (use --overwrite to replace it).")
+            }
+        }
+    }
+
+    /// 作業記録を取り込んだ旨
+    pub fn work_times_imported(&self, imported: usize, skipped: usize) -> String {
+        match self.language {
+            Language::Japanese => format!(
+                "✅ {imported}日分の作業記録を取り込みました（記録済みのため除外: {skipped}日）。"
+            ),
+            Language::English => format!(
+                "✅ Imported {imported} days of work time records ({skipped} days already recorded)."
+            ),
+        }
+    }
+
+    /// アドレスブックに宛先を追加した旨
+    pub fn address_added(&self, name: &str) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ {name}をアドレスブックに追加しました。"),
+            Language::English => format!("✅ Added {name} to the address book."),
+        }
+    }
+
+    /// アドレスブックから宛先を削除した旨
+    pub fn address_removed(&self, name: &str) -> String {
+        match self.language {
+            Language::Japanese => format!("✅ {name}をアドレスブックから削除しました。"),
+            Language::English => format!("✅ Removed {name} from the address book."),
+        }
+    }
+
+    /// グループの表示の接頭辞
+    pub fn group_label(&self) -> &'static str {
+        self.pick("[グループ]", "[group]")
+    }
+
+    /// 別名の表示
+    pub fn aliases(&self, aliases: &str) -> String {
+        match self.language {
+            Language::Japanese => format!(" (別名: {aliases})"),
+            Language::English => format!(" (aliases: {aliases})"),
+        }
+    }
+
+    /// 表示した件数
+    pub fn count(&self, count: usize) -> String {
+        match self.language {
+            Language::Japanese => format!("{count}件"),
+            Language::English => format!("{count} entries"),
+        }
+    }
+
+    /// 作業記録がない旨
+    pub fn no_work_record(&self, date: &str) -> String {
+        match self.language {
+            Language::Japanese => format!("{date}の作業記録はありません。"),
+            Language::English => format!("No work time record for {date}."),
+        }
+    }
+
+    /// 曜日名（日本語は`月`、英語は`Mon`の形式）
+    pub fn weekday_name(&self, date: NaiveDate) -> String {
+        match self.language {
+            Language::Japanese => weekday_name(date).to_string(),
+            Language::English => date.format("%a").to_string(),
+        }
+    }
+
+    /// 作業記録の項目名（開始・終了・休憩・作業時間・備考）
+    pub fn record_labels(&self) -> [&'static str; 5] {
+        match self.language {
+            Language::Japanese => ["開始", "終了", "休憩", "作業時間", "備考"],
+            Language::English => ["Start", "End", "Break", "Worked", "Notes"],
+        }
+    }
+
+    /// 休日に勤怠メールを送信する前の警告
+    ///
+    /// ## Arguments
+    /// * `date` - 送信対象の日付
+    /// * `day_off` - 休日の種類
+    pub fn holiday_warning(&self, date: NaiveDate, day_off: &DayOff) -> String {
+        match (self.language, day_off) {
+            (Language::Japanese, day_off) => {
+                let reason = match day_off {
+                    DayOff::Saturday => "土曜日".to_string(),
+                    DayOff::Sunday => "日曜日".to_string(),
+                    DayOff::Holiday(name) => format!("祝日（{name}）"),
+                };
+                format!("⚠️ {date}は{reason}です。勤怠メールを送信してよいか確認してください。")
+            }
+            (Language::English, day_off) => {
+                let reason = match day_off {
+                    DayOff::Saturday => "a Saturday".to_string(),
+                    DayOff::Sunday => "a Sunday".to_string(),
+                    DayOff::Holiday(name) => format!("a public holiday ({name})"),
+                };
+                format!("⚠️ {date} is {reason}. Make sure you want to send the attendance mail.")
+            }
+        }
+    }
+
+    /// 解決できなかったテンプレートの宛先
+    ///
+    /// ## Arguments
+    /// * `mail_type` - メール種別
+    /// * `field` - 宛先の項目（`to_names`・`cc_names`）
+    /// * `name` - 解決できなかった名前
+    /// * `reason` - 解決できなかった原因
+    pub fn unresolved_recipient(
+        &self,
+        mail_type: &str,
+        field: &str,
+        name: &str,
+        reason: &str,
+    ) -> String {
+        match self.language {
+            Language::Japanese => format!("❌ {mail_type}.{field}: {name}（{reason}）"),
+            Language::English => format!("❌ {mail_type}.{field}: {name} ({reason})"),
+        }
+    }
+
+    /// コマンドが失敗した場合のエラーメッセージ
+    ///
+    /// ## Arguments
+    /// * `failure` - 失敗の内容
+    pub fn failure_message(&self, failure: Failure) -> String {
+        match (self.language, failure) {
+            (Language::Japanese, Failure::Doctor) => "診断で問題が見つかりました。".to_string(),
+            (Language::Japanese, Failure::ConfigurationJson) => {
+                "設定のJSONへの変換に失敗しました。".to_string()
+            }
+            (Language::Japanese, Failure::InvalidConfiguration(count)) => {
+                format!("設定に{count}件の問題があります。")
+            }
+            (Language::Japanese, Failure::UnresolvedRecipients(count)) => {
+                format!("テンプレートの宛先{count}件を解決できません。")
+            }
+            (Language::Japanese, Failure::MissingConfiguration(path)) => {
+                format!("設定ファイルがありません。詳細: {}", path.display())
+            }
+            (Language::Japanese, Failure::AddressBookIssues(count)) => {
+                format!("アドレスブックに{count}件の問題があります。")
+            }
+            (Language::Japanese, Failure::UnknownImportFormat(path)) => format!(
+                "取り込むファイルの形式を判定できません。詳細: {}",
+                path.display()
+            ),
+            (Language::Japanese, Failure::OutboxResend(count)) => {
+                format!("送信待ちのメール{count}件の再送に失敗しました。")
+            }
+            (Language::Japanese, Failure::ManPageOutput) => {
+                "manページの出力に失敗しました。".to_string()
+            }
+            (Language::Japanese, Failure::ManPageWrite(path)) => format!(
+                "manページの書き込みに失敗しました。詳細: {}",
+                path.display()
+            ),
+            (Language::English, Failure::Doctor) => "The diagnosis found problems.".to_string(),
+            (Language::English, Failure::ConfigurationJson) => {
+                "Failed to convert the configuration to JSON.".to_string()
+            }
+            (Language::English, Failure::InvalidConfiguration(count)) => {
+                format!("The configuration has {count} problems.")
+            }
+            (Language::English, Failure::UnresolvedRecipients(count)) => {
+                format!("{count} template recipients could not be resolved.")
+            }
+            (Language::English, Failure::MissingConfiguration(path)) => {
+                format!("The configuration file does not exist: {}", path.display())
+            }
+            (Language::English, Failure::AddressBookIssues(count)) => {
+                format!("The address book has {count} problems.")
+            }
+            (Language::English, Failure::UnknownImportFormat(path)) => format!(
+                "Could not determine the format of the file to import: {}",
+                path.display()
+            ),
+            (Language::English, Failure::OutboxResend(count)) => {
+                format!("Failed to resend {count} queued mails.")
+            }
+            (Language::English, Failure::ManPageOutput) => {
+                "Failed to output the man page.".to_string()
+            }
+            (Language::English, Failure::ManPageWrite(path)) => {
+                format!("Failed to write the man pages: {}", path.display())
+            }
+        }
+    }
+
+    /// コマンドが失敗した場合の対処法
+    ///
+    /// ## Arguments
+    /// * `failure` - 失敗の内容
+    pub fn failure_action(&self, failure: Failure) -> &'static str {
+        match failure {
+            Failure::Doctor => self.pick(
+                "表示された対処に従って設定を修正し、再度実行してください。",
+                "Fix the configuration as shown above and run the command again.",
+            ),
+            Failure::ConfigurationJson => self.pick(
+                "設定ファイルの内容を確認してください。",
+                "Check the contents of the configuration file.",
+            ),
+            Failure::InvalidConfiguration(_) => self.pick(
+                "表示された対処に従って設定を修正してください。",
+                "Fix the configuration as shown above.",
+            ),
+            Failure::UnresolvedRecipients(_) => self.pick(
+                "アドレスブックに宛先を追加するか、テンプレートの宛先を修正してください。",
+                "Add the recipients to the address book or fix the recipients in the templates.",
+            ),
+            Failure::MissingConfiguration(_) => self.pick(
+                "`config init`で設定ファイルを作成してください。",
+                "Create the configuration file with `config init`.",
+            ),
+            Failure::AddressBookIssues(_) => self.pick(
+                "表示された問題を修正してください。",
+                "Fix the problems shown above.",
+            ),
+            Failure::UnknownImportFormat(_) => self.pick(
+                "--formatで形式を指定してください。",
+                "Specify the format with --format.",
+            ),
+            Failure::OutboxResend(_) => self.pick(
+                "表示された原因を解消し、再度`outbox flush`を実行してください。",
+                "Resolve the causes shown above and run `outbox flush` again.",
+            ),
+            Failure::ManPageOutput => self.pick(
+                "出力先を確認してください。",
+                "Check the output destination.",
+            ),
+            Failure::ManPageWrite(_) => self.pick(
+                "出力ディレクトリのアクセス権限を確認してください。",
+                "Check the permissions of the output directory.",
+            ),
+        }
+    }
+}
+
+/// コマンドが失敗した内容（メッセージと対処法を表示言語に合わせて取得する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure<'a> {
+    /// `config doctor`で問題が見つかった
+    Doctor,
+    /// 設定をJSONに変換できなかった
+    ConfigurationJson,
+    /// 設定に問題がある（問題の件数）
+    InvalidConfiguration(usize),
+    /// テンプレートの宛先を解決できない（件数）
+    UnresolvedRecipients(usize),
+    /// 設定ファイルがない（設定ファイルのパス）
+    MissingConfiguration(&'a Path),
+    /// アドレスブックに問題がある（問題の件数）
+    AddressBookIssues(usize),
+    /// 取り込むファイルの形式を判定できない（ファイルのパス）
+    UnknownImportFormat(&'a Path),
+    /// 送信待ちのメールを再送できなかった（件数）
+    OutboxResend(usize),
+    /// manページを標準出力に出力できなかった
+    ManPageOutput,
+    /// manページを書き込めなかった（出力ディレクトリ）
+    ManPageWrite(&'a Path),
+}
+
+/// 書き出したファイルの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Written {
+    /// メールのプレビュー
+    Preview,
+    /// 作業記録
+    WorkTimes,
+    /// manページ
+    ManPages,
+}
+
+/// CLIの定義とサブコマンドのヘルプを英語に置き換える
+fn localize_english(mut command: Command, path: &str) -> Command {
+    let lookup = |key: &str| {
+        ENGLISH_HELP
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, help)| *help)
+    };
+    if let Some(about) = lookup(path) {
+        command = command.about(about);
+    }
+
+    let arg_ids: Vec<String> = command
+        .get_arguments()
+        .map(|arg| arg.get_id().to_string())
+        .collect();
+    for id in arg_ids {
+        let help = lookup(&format!("{path}:{id}")).or_else(|| {
+            ENGLISH_SEND_HELP
+                .iter()
+                .find(|(name, _)| *name == id)
+                .map(|(_, help)| *help)
+        });
+        let value_name = ENGLISH_VALUE_NAMES
+            .iter()
+            .find(|(name, _)| *name == id)
+            .map(|(_, value_name)| *value_name);
+        command = command.mut_arg(&id, |mut arg| {
+            if let Some(help) = help {
+                arg = arg.help(help);
+            }
+            if let Some(value_name) = value_name {
+                arg = arg.value_name(value_name);
+            }
+            arg
+        });
+    }
+
+    let names: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+    for name in names {
+        let subpath = if path.is_empty() {
+            name.clone()
+        } else {
+            format!("{path} {name}")
+        };
+        command =
+            command.mut_subcommand(&name, |subcommand| localize_english(subcommand, &subpath));
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::inbound::cli::Cli;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_localize_command() {
+        let mut command = Messages::new(Language::English).localize_command(Cli::command());
+        command.build();
+        let start = command.find_subcommand("start").unwrap();
+        let dry_run = start
+            .get_arguments()
+            .find(|arg| arg.get_id() == "dry_run")
+            .unwrap();
+
+        assert_eq!(
+            start.get_about().unwrap().to_string(),
+            "Record the start time and compose the remote work start mail"
+        );
+        assert!(
+            dry_run
+                .get_help()
+                .unwrap()
+                .to_string()
+                .starts_with("Show the mail")
+        );
+        assert_eq!(
+            command
+                .find_subcommand("worktime")
+                .and_then(|worktime| worktime.find_subcommand("edit"))
+                .and_then(|edit| edit.get_about())
+                .unwrap()
+                .to_string(),
+            "Correct the start/end time and notes of a day's record"
+        );
+
+        let japanese = Messages::new(Language::Japanese).localize_command(Cli::command());
+        assert_eq!(
+            japanese
+                .find_subcommand("start")
+                .and_then(|start| start.get_about())
+                .unwrap()
+                .to_string(),
+            "作業開始時刻を記録し、在宅勤務開始メールを作成する"
+        );
+    }
+
+    #[test]
+    fn test_messages() {
+        let english = Messages::new(Language::English);
+        let japanese = Messages::new(Language::Japanese);
+
+        assert_eq!(english.action_label(), "Action");
+        assert_eq!(english.locale(), Locale::English);
+        assert_eq!(japanese.count(3), "3件");
+        assert_eq!(
+            english.written(Written::WorkTimes, Path::new("out.csv")),
+            "✅ Exported the work time records: out.csv"
+        );
+
+        let date = NaiveDate::from_ymd_opt(2025, 4, 29).unwrap();
+        let holiday = DayOff::Holiday("昭和の日".to_string());
+        assert_eq!(
+            japanese.holiday_warning(date, &holiday),
+            "⚠️ 2025-04-29は祝日（昭和の日）です。勤怠メールを送信してよいか確認してください。"
+        );
+        assert!(
+            english
+                .holiday_warning(date, &DayOff::Saturday)
+                .starts_with("⚠️ 2025-04-29 is a Saturday.")
+        );
+        assert_eq!(
+            english.failure_message(Failure::OutboxResend(2)),
+            "Failed to resend 2 queued mails."
+        );
+        assert_eq!(
+            japanese.failure_action(Failure::UnknownImportFormat(Path::new("a.txt"))),
+            "--formatで形式を指定してください。"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::domain::{
+    entities::mail_draft::MailDraft, interfaces::mail_client::MailClientPort,
+    value_objects::email_address::EmailAddress,
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// macOS標準のメール（Apple Mail）のアウトバウンドアダプター
+///
+/// `osascript`でAppleScriptを実行し、作成画面を開いた状態でメッセージを作成する
+pub struct AppleMailClientAdapter {
+    osascript_path: String,
+}
+
+impl AppleMailClientAdapter {
+    /// 新しいAppleMailClientAdapterを作成する
+    ///
+    /// ## Returns
+    /// * AppleMailClientAdapterのインスタンス
+    pub fn new() -> Self {
+        Self {
+            osascript_path: "osascript".to_string(),
+        }
+    }
+
+    /// メッセージを作成するAppleScriptを構築する
+    fn build_script(&self, draft: &MailDraft) -> String {
+        let mut lines = vec![
+            "tell application \"Mail\"".to_string(),
+            format!(
+                "    set newMessage to make new outgoing message with properties {{subject:\"{}\", content:\"{}\", visible:true}}",
+                escape_applescript(draft.subject().as_str()),
+                escape_applescript(draft.body().as_str()),
+            ),
+            "    tell newMessage".to_string(),
+        ];
+        lines.extend(recipient_lines("to", draft.to()));
+        lines.extend(recipient_lines("cc", draft.cc()));
+        for path in draft.attachments() {
+            lines.push(format!(
+                "        tell content to make new attachment with properties {{file name:POSIX file \"{}\"}} at after the last paragraph",
+                escape_applescript(&path.to_string_lossy()),
+            ));
+        }
+        lines.push("    end tell".to_string());
+        lines.push("    activate".to_string());
+        lines.push("end tell".to_string());
+        lines.join("\n")
+    }
+}
+
+impl Default for AppleMailClientAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 宛先を追加するAppleScriptの行を構築する
+fn recipient_lines(kind: &str, addresses: &[EmailAddress]) -> Vec<String> {
+    addresses
+        .iter()
+        .map(|addr| {
+            let properties = match addr.display_name() {
+                Some(name) => format!(
+                    "name:\"{}\", address:\"{}\"",
+                    escape_applescript(name),
+                    escape_applescript(addr.as_str())
+                ),
+                None => format!("address:\"{}\"", escape_applescript(addr.as_str())),
+            };
+            format!(
+                "        make new {kind} recipient at end of {kind} recipients with properties {{{properties}}}"
+            )
+        })
+        .collect()
+}
+
+/// AppleScriptの文字列リテラルに埋め込めるようにエスケープする
+fn escape_applescript(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl MailClientPort for AppleMailClientAdapter {
+    fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        let _span = logging::operation_span("apple_mail_compose").entered();
+        // AppleScriptでは送信前のメッセージに任意のヘッダーを設定できないため、黙って落とさずにエラーとする
+        if !draft.headers().is_empty() {
+            let names = draft.headers().keys().cloned().collect::<Vec<_>>();
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-MAIL-059")
+                .with_message(format!(
+                    "メールアプリではヘッダー（{}）を設定できません。",
+                    names.join(", ")
+                ))
+                .with_action(
+                    "config.jsonのimapを設定して下書きフォルダにアップロードするか、メールテンプレートのheadersを削除してください。",
+                ));
+        }
+        let script = self.build_script(draft);
+
+        if is_dry_run {
+            tracing::debug!(
+                osascript = %self.osascript_path,
+                %script,
+                "[DRY-RUN] メールアプリを起動しません"
+            );
+            return Ok(());
+        }
+
+        let mut child = Command::new(&self.osascript_path)
+            .arg("-")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                // osascriptがない（macOS以外で実行している）場合を除き、再試行できるようにする
+                let retryable = e.kind() != std::io::ErrorKind::NotFound;
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-MAIL-019")
+                    .with_message("osascriptの起動に失敗しました。")
+                    .with_action("macOS上で実行していることを確認してください。")
+                    .retryable(retryable)
+                    .with_source(e)
+            })?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(script.as_bytes())?;
+        }
+
+        let output = child.wait_with_output().map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-020")
+                .with_message("osascriptプロセスの待機に失敗しました。")
+                .with_action("システムリソースを確認してください。")
+                .retryable(true)
+                .with_source(e)
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-021")
+                .with_message(format!(
+                    "メールでのメッセージ作成に失敗しました。詳細: {}",
+                    stderr.trim()
+                ))
+                .with_action(
+                    "システム設定のプライバシーとセキュリティで、ターミナルからメールの操作が許可されていることを確認してください。",
+                )
+                .retryable(true));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::mail_objects::{MailBody, Subject};
+
+    #[test]
+    fn test_build_script() {
+        let adapter = AppleMailClientAdapter::new();
+
+        let to = vec![EmailAddress::parse("test1@example.com").unwrap()];
+        let cc = vec![EmailAddress::parse("test2@example.com").unwrap()];
+        let subject = Subject::new("テスト\"件名\"").unwrap();
+        let body = MailBody::new("テスト本文\n改行あり");
+
+        let draft = MailDraft::new(to, cc, subject, body);
+        let script = adapter.build_script(&draft);
+
+        assert!(script.contains("subject:\"テスト\\\"件名\\\"\""));
+        assert!(script.contains("content:\"テスト本文\n改行あり\""));
+        assert!(script.contains(
+            "make new to recipient at end of to recipients with properties {address:\"test1@example.com\"}"
+        ));
+        assert!(script.contains(
+            "make new cc recipient at end of cc recipients with properties {address:\"test2@example.com\"}"
+        ));
+    }
+}
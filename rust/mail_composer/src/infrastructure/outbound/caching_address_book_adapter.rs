@@ -0,0 +1,160 @@
+use crate::{
+    domain::{
+        entities::address_book::AddressBookEntry,
+        interfaces::address_book::AddressBookPort,
+        value_objects::{address_query::AddressQuery, email_address::EmailAddress},
+    },
+    infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter,
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+/// アドレスブックファイルを読み込む関数
+pub type AddressBookLoader<A> = fn(&Path) -> AppResult<A>;
+
+/// 解析済みのアドレスブックをメモリに保持するアウトバウンドアダプター（デコレーター）
+///
+/// ファイルの更新日時が変わった場合のみ再読み込みを行うため、
+/// 常駐モードで名前解決を繰り返してもファイルの解析は更新時にしか発生しない
+pub struct CachingAddressBookAdapter<A: AddressBookPort> {
+    path: PathBuf,
+    loader: AddressBookLoader<A>,
+    cache: Mutex<Option<CachedAddressBook<A>>>,
+}
+
+/// 読み込み済みのアドレスブックと、読み込み時のファイル更新日時
+struct CachedAddressBook<A> {
+    modified: SystemTime,
+    book: A,
+}
+
+impl CachingAddressBookAdapter<JsonAddressBookAdapter> {
+    /// JSON形式のアドレスブックをキャッシュするアダプターを作成する
+    ///
+    /// ## Arguments
+    /// * `address_book` - AddressBookのパスを表現する`Path`（ワークスペースルートからの相対パス）
+    ///
+    /// ## Returns
+    /// * CachingAddressBookAdapterのインスタンス
+    pub fn json(address_book: &Path) -> Self {
+        Self::new(address_book, JsonAddressBookAdapter::load_from_address_book)
+    }
+}
+
+impl<A: AddressBookPort> CachingAddressBookAdapter<A> {
+    /// 新しいCachingAddressBookAdapterを作成する
+    ///
+    /// ファイルは最初の名前解決時に読み込まれる
+    ///
+    /// ## Arguments
+    /// * `path` - アドレスブックファイルのパス（ワークスペースルートからの相対パス）
+    /// * `loader` - アドレスブックファイルを読み込む関数
+    ///
+    /// ## Returns
+    /// * CachingAddressBookAdapterのインスタンス
+    pub fn new(path: &Path, loader: AddressBookLoader<A>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            loader,
+            cache: Mutex::new(None),
+        }
+    }
+
+    /// ファイルの更新日時を取得する
+    fn modified(&self) -> AppResult<SystemTime> {
+        let path = workspace_path(&self.path)?;
+        fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-ADDR-012")
+                    .with_message("AddressBookファイルの更新日時の取得に失敗しました。")
+                    .with_action("ファイルパスの存在とアクセス権限を確認してください。")
+                    .with_source(e)
+            })
+    }
+
+    /// 必要に応じてアドレスブックを再読み込みし、読み込み済みのアドレスブックで処理を行う
+    fn with_book<T>(&self, f: impl FnOnce(&A) -> AppResult<T>) -> AppResult<T> {
+        let modified = self.modified()?;
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let cached = match cache.take().filter(|cached| cached.modified == modified) {
+            Some(cached) => cached,
+            None => CachedAddressBook {
+                modified,
+                book: (self.loader)(&self.path)?,
+            },
+        };
+        f(&cache.insert(cached).book)
+    }
+}
+
+impl<A: AddressBookPort> AddressBookPort for CachingAddressBookAdapter<A> {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        self.with_book(|book| book.resolve(key_name))
+    }
+
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        self.with_book(|book| book.expand(key_name))
+    }
+
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        self.with_book(|book| book.list_entries())
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        self.with_book(|book| book.reverse_resolve(address))
+    }
+
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        self.with_book(|book| book.search(query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_reload_when_modified() {
+        let path = std::env::temp_dir().join(format!(
+            "mail_composer_caching_address_book_{}.json",
+            std::process::id()
+        ));
+        let file = |address: &str| format!(r#"[{{ "name": "山田さん", "address": "{address}" }}]"#);
+        fs::write(&path, file("old@example.com")).unwrap();
+
+        let adapter = CachingAddressBookAdapter::json(&path);
+        assert_eq!(
+            adapter.resolve("山田さん").unwrap().as_str(),
+            "old@example.com"
+        );
+
+        fs::write(&path, file("new@example.com")).unwrap();
+        let later = SystemTime::now() + Duration::from_secs(10);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(
+            adapter.resolve("山田さん").unwrap().as_str(),
+            "new@example.com"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,191 @@
+use crate::domain::{
+    interfaces::configuration::ConfigurationPort,
+    value_objects::{
+        app_configuration::AppConfiguration,
+        effective_configuration::{ConfigurationSource, EffectiveConfiguration},
+    },
+};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+
+/// コマンドライン引数（`--set 項目名=値`）で設定値を上書きするアウトバウンドアダプター（デコレーター）
+///
+/// 設定ファイルを編集せずに、その実行に限って差出人名などを差し替える場合に使用する。
+/// 上書き後の設定値を検証する
+pub struct CommandLineOverrideConfigurationAdapter<C: ConfigurationPort> {
+    inner: C,
+    overrides: Vec<(String, String)>,
+}
+
+impl<C: ConfigurationPort> CommandLineOverrideConfigurationAdapter<C> {
+    /// `項目名=値`形式の指定から新しいCommandLineOverrideConfigurationAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - 上書き元の設定を読み込むポート
+    /// * `assignments` - `from="山田"`、`department=開発部`形式の指定（後に指定したものが優先される）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<CommandLineOverrideConfigurationAdapter>`
+    /// * 失敗時 - 形式が不正、または未知の設定項目が指定された場合の`Err<AppError>`
+    pub fn from_assignments<S: AsRef<str>>(inner: C, assignments: &[S]) -> AppResult<Self> {
+        let overrides = parse_assignments(assignments)?;
+        Ok(Self { inner, overrides })
+    }
+}
+
+/// `項目名=値`形式の指定（`--set`の値）を設定項目名と値の組に変換する
+///
+/// ## Arguments
+/// * `assignments` - `from="山田"`、`department=開発部`形式の指定
+///
+/// ## Returns
+/// * 成功時 - 指定順の設定項目名と値の組
+/// * 失敗時 - 形式が不正、または未知の設定項目が指定された場合の`Err<AppError>`
+pub fn parse_assignments<S: AsRef<str>>(assignments: &[S]) -> AppResult<Vec<(String, String)>> {
+    assignments
+        .iter()
+        .map(|assignment| parse_assignment(assignment.as_ref()))
+        .collect()
+}
+
+/// `項目名=値`形式の指定を解析する（値を囲む引用符は取り除く）
+fn parse_assignment(assignment: &str) -> AppResult<(String, String)> {
+    let Some((field, value)) = assignment.split_once('=') else {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-CONF-017")
+            .with_message(format!("--setの指定が不正です。詳細: {assignment}"))
+            .with_action("--set 項目名=値 の形式で指定してください（例: --set from=山田）。"));
+    };
+
+    let field = field.trim();
+    if !AppConfiguration::OVERRIDABLE_FIELDS.contains(&field) {
+        return Err(AppError::new(ErrorKind::BadRequest)
+            .with_code("MC-CONF-018")
+            .with_message(format!("設定項目'{field}'は--setで上書きできません。"))
+            .with_action(format!(
+                "次のいずれかを指定してください: {}",
+                AppConfiguration::OVERRIDABLE_FIELDS.join(", ")
+            )));
+    }
+
+    let value = value.trim();
+    let value = ['"', '\'']
+        .iter()
+        .find_map(|quote| {
+            value
+                .strip_prefix(*quote)
+                .and_then(|rest| rest.strip_suffix(*quote))
+        })
+        .unwrap_or(value);
+    Ok((field.to_string(), value.to_string()))
+}
+
+impl<C: ConfigurationPort> ConfigurationPort for CommandLineOverrideConfigurationAdapter<C> {
+    fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        let config = self.load_unvalidated_configuration()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        let mut config = self.inner.load_unvalidated_configuration()?;
+        for (field, value) in &self.overrides {
+            config.set_field(field, value)?;
+        }
+        Ok(config)
+    }
+
+    fn load_effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        let mut effective = self.inner.load_effective_configuration()?;
+        for (field, value) in &self.overrides {
+            effective.configuration.set_field(field, value)?;
+            effective
+                .sources
+                .insert(field.clone(), ConfigurationSource::CommandLine);
+        }
+        Ok(effective)
+    }
+
+    fn configuration_exists(&self) -> bool {
+        self.inner.configuration_exists()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubConfiguration;
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(AppConfiguration {
+                from: "鈴木".to_string(),
+                department: "総務部".to_string(),
+                thunderbird_exe: "thunderbird".to_string(),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_set_overrides() {
+        let adapter = CommandLineOverrideConfigurationAdapter::from_assignments(
+            StubConfiguration,
+            &[
+                "from=\"山田\"",
+                "department=開発部",
+                "messages_per_minute= 5",
+            ],
+        )
+        .unwrap();
+
+        let config = adapter.load_configuration().unwrap();
+
+        assert_eq!(config.from, "山田");
+        assert_eq!(config.department, "開発部");
+        assert_eq!(config.messages_per_minute, Some(5));
+    }
+
+    #[test]
+    fn test_effective_configuration_sources() {
+        let adapter = CommandLineOverrideConfigurationAdapter::from_assignments(
+            StubConfiguration,
+            &["from=山田"],
+        )
+        .unwrap();
+
+        let effective = adapter.load_effective_configuration().unwrap();
+
+        assert_eq!(effective.configuration.from, "山田");
+        assert_eq!(effective.sources["from"], ConfigurationSource::CommandLine);
+        assert_eq!(effective.sources["department"], ConfigurationSource::File);
+    }
+
+    #[test]
+    fn test_invalid_assignments() {
+        let parse = |assignment: &str| {
+            CommandLineOverrideConfigurationAdapter::from_assignments(
+                StubConfiguration,
+                &[assignment],
+            )
+            .map(|_| ())
+        };
+
+        assert!(parse("from").is_err());
+        assert!(parse("signing=gpg").is_err());
+
+        let empty_from = CommandLineOverrideConfigurationAdapter::from_assignments(
+            StubConfiguration,
+            &["from="],
+        )
+        .unwrap();
+        assert!(empty_from.load_configuration().is_err());
+    }
+}
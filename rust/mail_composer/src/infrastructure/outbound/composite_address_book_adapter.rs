@@ -0,0 +1,207 @@
+use crate::domain::{
+    entities::address_book::AddressBookEntry, interfaces::address_book::AddressBookPort,
+    value_objects::email_address::EmailAddress,
+};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+
+/// 複数のアドレスブックを優先順位付きで束ねるアウトバウンドアダプター
+///
+/// 先に追加したアドレスブックほど優先され、名前が見つからない場合のみ次のアドレスブックを参照する
+/// （例: 個人のJSON → チーム共有のファイル → LDAP）
+#[derive(Default)]
+pub struct CompositeAddressBookAdapter {
+    sources: Vec<AddressBookSource>,
+}
+
+/// 優先順位付きで参照されるアドレスブック
+struct AddressBookSource {
+    label: String,
+    port: Box<dyn AddressBookPort>,
+}
+
+/// 複数のアドレスブックで異なるメールアドレスに解決された名前
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressConflict {
+    /// 解決対象の名前
+    pub name: String,
+    /// アドレスブックのラベルと解決されたメールアドレスの組（優先順）
+    pub resolutions: Vec<(String, EmailAddress)>,
+}
+
+impl CompositeAddressBookAdapter {
+    /// 新しいCompositeAddressBookAdapterを作成する
+    ///
+    /// ## Returns
+    /// * アドレスブックを持たないCompositeAddressBookAdapterのインスタンス
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 参照するアドレスブックを追加する
+    ///
+    /// 追加したアドレスブックは既存のものより低い優先順位になる
+    ///
+    /// ## Arguments
+    /// * `label` - エラーや競合の報告に使用するアドレスブックの名前
+    /// * `port` - 追加するアドレスブック
+    ///
+    /// ## Returns
+    /// * アドレスブックが追加されたCompositeAddressBookAdapterのインスタンス
+    pub fn with_source(
+        mut self,
+        label: impl Into<String>,
+        port: impl AddressBookPort + 'static,
+    ) -> Self {
+        self.sources.push(AddressBookSource {
+            label: label.into(),
+            port: Box::new(port),
+        });
+        self
+    }
+
+    /// 名前ごとに、複数のアドレスブックで異なるメールアドレスに解決されるものを検出する
+    ///
+    /// ## Arguments
+    /// * `key_names` - 確認対象の名前のスライス
+    ///
+    /// ## Returns
+    /// * 競合している名前の一覧
+    pub fn find_conflicts(&self, key_names: &[&str]) -> Vec<AddressConflict> {
+        key_names
+            .iter()
+            .filter_map(|key_name| {
+                let resolutions: Vec<(String, EmailAddress)> = self
+                    .sources
+                    .iter()
+                    .filter_map(|source| {
+                        let address = source.port.resolve(key_name).ok()?;
+                        Some((source.label.clone(), address))
+                    })
+                    .collect();
+                let first = &resolutions.first()?.1;
+                resolutions
+                    .iter()
+                    .any(|(_, address)| address != first)
+                    .then(|| AddressConflict {
+                        name: key_name.to_string(),
+                        resolutions: resolutions.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    /// 優先順位の高いアドレスブックから順に、名前が見つかるまで問い合わせる
+    fn first_found<T>(
+        &self,
+        key_name: &str,
+        lookup: impl Fn(&dyn AddressBookPort) -> AppResult<T>,
+    ) -> AppResult<T> {
+        for source in &self.sources {
+            match lookup(source.port.as_ref()) {
+                Err(e) if e.kind == ErrorKind::NotFound => continue,
+                result => return result,
+            }
+        }
+
+        let labels: Vec<&str> = self.sources.iter().map(|s| s.label.as_str()).collect();
+        Err(AppError::new(ErrorKind::NotFound)
+            .with_code("MC-ADDR-013")
+            .with_message(format!(
+                "'{key_name}'はいずれのアドレスブックにも見つかりません。参照したアドレスブック: {}",
+                labels.join(", ")
+            ))
+            .with_action("AddressBookの内容と指定した名前を確認してください。"))
+    }
+}
+
+impl AddressBookPort for CompositeAddressBookAdapter {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        self.first_found(key_name, |port| port.resolve(key_name))
+    }
+
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        self.first_found(key_name, |port| port.expand(key_name))
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        for source in &self.sources {
+            if let Some(name) = source.port.reverse_resolve(address)? {
+                return Ok(Some(name));
+            }
+        }
+        Ok(None)
+    }
+
+    /// 一覧を取得できる全てのアドレスブックのエントリを優先順に結合する
+    ///
+    /// 同じ名前のエントリは優先順位の高いアドレスブックのもののみ残す
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        let mut entries: Vec<AddressBookEntry> = Vec::new();
+        for source in &self.sources {
+            let Ok(source_entries) = source.port.list_entries() else {
+                continue;
+            };
+            for entry in source_entries {
+                if !entries.iter().any(|e| e.name == entry.name) {
+                    entries.push(entry);
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbound::json_address_book_adapter::JsonAddressBookAdapter;
+
+    fn composite() -> CompositeAddressBookAdapter {
+        let personal = JsonAddressBookAdapter::from_json_str(
+            r#"[{ "name": "山田さん", "address": "yamada.private@example.com" }]"#,
+        )
+        .unwrap();
+        let team = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "佐藤さん", "address": "sato@example.com" }
+            ]"#,
+        )
+        .unwrap();
+
+        CompositeAddressBookAdapter::new()
+            .with_source("personal", personal)
+            .with_source("team", team)
+    }
+
+    #[test]
+    fn test_resolve_by_precedence() {
+        let adapter = composite();
+
+        assert_eq!(
+            adapter.resolve("山田さん").unwrap().as_str(),
+            "yamada.private@example.com"
+        );
+        assert_eq!(
+            adapter.resolve("佐藤さん").unwrap().as_str(),
+            "sato@example.com"
+        );
+        assert_eq!(
+            adapter.resolve("鈴木さん").unwrap_err().kind,
+            ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        let conflicts = composite().find_conflicts(&["山田さん", "佐藤さん"]);
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].name, "山田さん");
+        assert_eq!(conflicts[0].resolutions[0].0, "personal");
+        assert_eq!(conflicts[0].resolutions[1].0, "team");
+    }
+}
@@ -0,0 +1,148 @@
+use crate::domain::{
+    entities::work_record::WorkRecord, interfaces::work_time_export::WorkTimeExportPort,
+    value_objects::mail_objects::WorkTime,
+};
+use chrono::NaiveDate;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::{ensure_directory_exists, workspace_path},
+};
+use std::{fs, path::PathBuf};
+
+/// CSVの見出し行
+const HEADERS: [&str; 5] = ["日付", "開始", "終了", "作業時間", "備考"];
+
+/// 作業記録をCSV形式で出力ディレクトリに書き出すアウトバウンドアダプター
+///
+/// Excelで文字化けせずに開けるよう、BOM付きUTF-8・CRLF改行で出力する。
+/// 作業時間はExcelが時刻として解釈できる`H:MM`形式とする
+pub struct CsvWorkTimeExportAdapter {
+    output_dir: String,
+}
+
+impl CsvWorkTimeExportAdapter {
+    /// 新しいCsvWorkTimeExportAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `output_dir` - 出力ディレクトリ（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * CsvWorkTimeExportAdapterのインスタンス
+    pub fn new(output_dir: impl Into<String>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// 作業記録をCSV形式の文字列に変換する
+    fn to_csv(records: &[(NaiveDate, WorkRecord)]) -> AppResult<String> {
+        let csv_error = |e: csv::Error| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-014")
+                .with_message("作業記録のCSVへの変換に失敗しました。")
+                .with_action("作業記録の内容を確認してください。")
+                .with_source(e)
+        };
+
+        let mut writer = csv::WriterBuilder::new()
+            .terminator(csv::Terminator::CRLF)
+            .from_writer(Vec::new());
+        writer.write_record(HEADERS).map_err(csv_error)?;
+        for (date, record) in records {
+            let time = |time: &Option<WorkTime>| {
+                time.as_ref()
+                    .map(|t| t.as_str().to_string())
+                    .unwrap_or_default()
+            };
+            let duration = record
+                .duration()
+                .map(|minutes| format!("{}:{:02}", minutes / 60, minutes % 60))
+                .unwrap_or_default();
+            writer
+                .write_record([
+                    date.to_string(),
+                    time(&record.start),
+                    time(&record.end),
+                    duration,
+                    record.notes.clone().unwrap_or_default(),
+                ])
+                .map_err(csv_error)?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-015")
+                .with_message("作業記録のCSVへの変換に失敗しました。")
+                .with_action("システムリソースを確認してください。")
+                .with_source(e.into_error())
+        })?;
+        let csv = String::from_utf8(bytes).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-016")
+                .with_message("作業記録のCSVへの変換に失敗しました。")
+                .with_action("作業記録の内容を確認してください。")
+                .with_source(e)
+        })?;
+        Ok(format!("\u{feff}{csv}"))
+    }
+}
+
+impl WorkTimeExportPort for CsvWorkTimeExportAdapter {
+    fn export(&self, file_stem: &str, records: &[(NaiveDate, WorkRecord)]) -> AppResult<PathBuf> {
+        let dir = workspace_path(&self.output_dir)?;
+        ensure_directory_exists(&dir)?;
+        let path = dir.join(format!("{file_stem}.csv"));
+
+        fs::write(&path, Self::to_csv(records)?).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-017")
+                .with_message(format!(
+                    "CSVファイルの書き込みに失敗しました。詳細: {}",
+                    path.display()
+                ))
+                .with_action("出力ディレクトリのアクセス権限と、ファイルをExcelで開いていないことを確認してください。")
+                .with_source(e)
+        })?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_csv() {
+        let mut full_day = WorkRecord::default();
+        full_day.set_start(WorkTime::new("09:00").unwrap());
+        full_day.set_end(WorkTime::new("18:30").unwrap());
+        full_day.notes = Some("客先訪問, 直帰".to_string());
+        let mut start_only = WorkRecord::default();
+        start_only.set_start(WorkTime::new("10:00").unwrap());
+        let records = [
+            (NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(), full_day),
+            (NaiveDate::from_ymd_opt(2025, 4, 2).unwrap(), start_only),
+        ];
+
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_csv_export_{}", std::process::id()));
+        let adapter = CsvWorkTimeExportAdapter::new(dir.to_string_lossy());
+        let path = adapter.export("work_times_2025-04", &records);
+        let content = path.as_ref().map(fs::read_to_string);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            path.as_ref().unwrap().file_name().unwrap(),
+            "work_times_2025-04.csv"
+        );
+        assert_eq!(
+            content.unwrap().unwrap(),
+            "\u{feff}日付,開始,終了,作業時間,備考\r\n\
+             2025-04-01,09:00,18:30,9:30,\"客先訪問, 直帰\"\r\n\
+             2025-04-02,10:00,,,\r\n"
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::domain::interfaces::notification::NotificationPort;
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::process::{Command, Stdio};
+
+/// デスクトップ通知を表示するアウトバウンドアダプター
+///
+/// macOSでは`osascript`、Windowsでは`powershell`のバルーン通知、
+/// それ以外では`notify-send`を使用する
+pub struct DesktopNotificationAdapter;
+
+impl DesktopNotificationAdapter {
+    /// 新しいDesktopNotificationAdapterを作成する
+    ///
+    /// ## Returns
+    /// * DesktopNotificationAdapterのインスタンス
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 実行中のプラットフォームで通知を表示するコマンドと引数を構築する
+    fn build_command(title: &str, message: &str) -> (&'static str, Vec<String>) {
+        if cfg!(target_os = "macos") {
+            let script = format!(
+                "display notification \"{}\" with title \"{}\"",
+                escape_double_quoted(message),
+                escape_double_quoted(title)
+            );
+            ("osascript", vec!["-e".to_string(), script])
+        } else if cfg!(target_os = "windows") {
+            let script = format!(
+                "Add-Type -AssemblyName System.Windows.Forms; \
+                 $n = New-Object System.Windows.Forms.NotifyIcon; \
+                 $n.Icon = [System.Drawing.SystemIcons]::Information; \
+                 $n.Visible = $true; \
+                 $n.ShowBalloonTip(10000, '{}', '{}', 'Info'); \
+                 Start-Sleep -Seconds 10; $n.Dispose()",
+                escape_single_quoted(title),
+                escape_single_quoted(message)
+            );
+            (
+                "powershell",
+                vec!["-NoProfile".to_string(), "-Command".to_string(), script],
+            )
+        } else {
+            ("notify-send", vec![title.to_string(), message.to_string()])
+        }
+    }
+}
+
+impl Default for DesktopNotificationAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// AppleScriptの文字列リテラルに埋め込めるようにエスケープする
+fn escape_double_quoted(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// PowerShellの単一引用符の文字列に埋め込めるようにエスケープする
+fn escape_single_quoted(s: &str) -> String {
+    s.replace('\'', "''")
+}
+
+impl NotificationPort for DesktopNotificationAdapter {
+    fn notify(&self, title: &str, message: &str) -> AppResult<()> {
+        let (program, args) = Self::build_command(title, message);
+        let output = Command::new(program)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-CLI-008")
+                    .with_message(format!("通知コマンド'{program}'の起動に失敗しました。"))
+                    .with_action("デスクトップ環境で実行しているか、通知コマンドがインストールされているか確認してください。")
+                    .with_source(e)
+            })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CLI-009")
+                .with_message(format!(
+                    "デスクトップ通知の表示に失敗しました。詳細: {}",
+                    stderr.trim()
+                ))
+                .with_action("通知の許可設定を確認してください。"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_command_escapes_text() {
+        let (program, args) =
+            DesktopNotificationAdapter::build_command("終了\"確認\"", "It's 19:00");
+
+        if cfg!(target_os = "macos") {
+            assert_eq!(program, "osascript");
+            assert!(args[1].contains("with title \"終了\\\"確認\\\"\""));
+        } else if cfg!(target_os = "windows") {
+            assert_eq!(program, "powershell");
+            assert!(args[2].contains("'It''s 19:00'"));
+        } else {
+            assert_eq!(program, "notify-send");
+            assert_eq!(args, ["終了\"確認\"", "It's 19:00"]);
+        }
+    }
+}
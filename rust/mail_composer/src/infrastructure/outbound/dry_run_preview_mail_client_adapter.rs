@@ -0,0 +1,96 @@
+use crate::{
+    domain::{entities::mail_draft::MailDraft, interfaces::mail_client::MailClientPort},
+    infrastructure::outbound::preview_mail_client_adapter::{
+        DEFAULT_PREVIEW_WIDTH, render_preview,
+    },
+};
+use share::error::app_error::AppResult;
+
+/// 枠の上端に表示する見出し
+const DRY_RUN_TITLE: &str = " DRY-RUN: メールは作成されません ";
+
+/// ドライランの場合に、宛先・件名・本文を枠で囲んだプレビューを表示するメールクライアントのデコレーター
+///
+/// 宛先は種別・名前・メールアドレスの表、本文は枠の幅で折り返して表示し、
+/// メールドラフトの補足情報（作業時間など）も合わせて表示する。
+/// ドライランでない場合は何も表示せずに内側のメールクライアントに委譲する
+pub struct DryRunPreviewMailClientAdapter<M: MailClientPort> {
+    inner: M,
+    width: usize,
+}
+
+impl<M: MailClientPort> DryRunPreviewMailClientAdapter<M> {
+    /// 新しいDryRunPreviewMailClientAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - メールを作成するメールクライアント
+    ///
+    /// ## Returns
+    /// * DryRunPreviewMailClientAdapterのインスタンス
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            width: DEFAULT_PREVIEW_WIDTH,
+        }
+    }
+
+    /// 枠内の表示幅を指定する
+    ///
+    /// ## Arguments
+    /// * `width` - 枠内の表示幅（半角文字数、32未満の場合は32）
+    ///
+    /// ## Returns
+    /// * 表示幅が設定されたDryRunPreviewMailClientAdapterのインスタンス
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// ドライランで表示するプレビューを整形する
+    fn render(&self, draft: &MailDraft) -> String {
+        render_preview(draft, DRY_RUN_TITLE, self.width)
+    }
+}
+
+impl<M: MailClientPort> MailClientPort for DryRunPreviewMailClientAdapter<M> {
+    fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        if is_dry_run {
+            println!("{}", self.render(draft));
+        }
+        self.inner.compose_mail(draft, is_dry_run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        email_address::EmailAddress,
+        mail_objects::{MailBody, Subject},
+    };
+
+    struct FailingMailClient;
+
+    impl MailClientPort for FailingMailClient {
+        fn compose_mail(&self, _draft: &MailDraft, _is_dry_run: bool) -> AppResult<()> {
+            panic!("ドライランの結果だけを確認するため呼び出さない")
+        }
+    }
+
+    #[test]
+    fn test_render_with_dry_run_title() {
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("yamada@example.com").unwrap()],
+            vec![],
+            Subject::new("【在宅勤務終了】開発部 佐藤").unwrap(),
+            MailBody::new("お疲れ様です。"),
+        );
+
+        let preview = DryRunPreviewMailClientAdapter::new(FailingMailClient)
+            .with_width(40)
+            .render(&draft);
+
+        assert!(preview.starts_with("╭─ DRY-RUN: メールは作成されません ─"));
+        assert!(preview.contains("件名  【在宅勤務終了】開発部 佐藤"));
+    }
+}
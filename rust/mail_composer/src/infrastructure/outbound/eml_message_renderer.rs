@@ -0,0 +1,317 @@
+use crate::domain::{
+    entities::mail_draft::MailDraft, interfaces::mail_signer::MailSignerPort,
+    value_objects::email_address::EmailAddress,
+};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use chrono::{DateTime, Local};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{fs, path::Path};
+
+/// base64エンコード後の1行あたりの最大文字数（RFC 2045）
+const BASE64_LINE_LENGTH: usize = 76;
+/// encoded-word1つあたりに含める元データの最大バイト数
+const ENCODED_WORD_CHUNK_BYTES: usize = 45;
+
+/// メールドラフトをRFC 5322形式（.eml）のメッセージに変換する
+///
+/// ## Arguments
+/// * `draft` - 変換対象のメールドラフト
+/// * `from_name` - Fromヘッダーに設定する差出人名
+/// * `from_address` - Fromヘッダーに設定する差出人メールアドレス
+///
+/// ## Returns
+/// * 成功時 - `Ok<String>` (CRLF改行のメッセージ)
+/// * 失敗時 - 添付ファイルの読み込みに失敗した場合の`Err<AppError>`
+pub fn render_eml(
+    draft: &MailDraft,
+    from_name: Option<&str>,
+    from_address: &str,
+) -> AppResult<String> {
+    let now = Local::now();
+    let headers = render_headers(draft, from_name, from_address, now);
+    let entity = render_entity(draft, now)?;
+    Ok(format!("{headers}\r\n{entity}"))
+}
+
+/// メールドラフトを署名付きのRFC 5322形式（.eml）のメッセージに変換する
+///
+/// 本文と添付ファイルのMIMEエンティティに署名し、`multipart/signed`のメッセージを作成する
+///
+/// ## Arguments
+/// * `draft` - 変換対象のメールドラフト
+/// * `from_name` - Fromヘッダーに設定する差出人名
+/// * `from_address` - Fromヘッダーに設定する差出人メールアドレス
+/// * `signer` - 署名に使用するポート
+///
+/// ## Returns
+/// * 成功時 - `Ok<String>` (CRLF改行のメッセージ)
+/// * 失敗時 - 添付ファイルの読み込みまたは署名に失敗した場合の`Err<AppError>`
+pub fn render_signed_eml(
+    draft: &MailDraft,
+    from_name: Option<&str>,
+    from_address: &str,
+    signer: &dyn MailSignerPort,
+) -> AppResult<String> {
+    let now = Local::now();
+    let headers = render_headers(draft, from_name, from_address, now);
+    let signed = signer.sign(&render_entity(draft, now)?)?;
+    Ok(format!("{headers}\r\n{signed}"))
+}
+
+/// 本文のMIMEエンティティ以外のヘッダー（`MIME-Version`まで）を構築する
+fn render_headers(
+    draft: &MailDraft,
+    from_name: Option<&str>,
+    from_address: &str,
+    now: DateTime<Local>,
+) -> String {
+    let from = match from_name.filter(|name| !name.trim().is_empty()) {
+        Some(name) => format!("{} <{from_address}>", encode_display_name(name)),
+        None => from_address.to_string(),
+    };
+    let mut headers = vec![
+        format!("Date: {}", now.to_rfc2822()),
+        format!("From: {from}"),
+        format!("To: {}", join_addresses(draft.to())),
+    ];
+    if !draft.cc().is_empty() {
+        headers.push(format!("Cc: {}", join_addresses(draft.cc())));
+    }
+    headers.push(format!(
+        "Subject: {}",
+        encode_header_value(draft.subject().as_str())
+    ));
+    headers.push(format!(
+        "Message-ID: <{}.{}@mail_composer>",
+        now.format("%Y%m%d%H%M%S%f"),
+        std::process::id()
+    ));
+    for (name, value) in draft.headers() {
+        headers.push(format!("{name}: {}", encode_header_value(value)));
+    }
+    headers.push("MIME-Version: 1.0".to_string());
+    headers.join("\r\n")
+}
+
+/// 本文と添付ファイルのMIMEエンティティ（CRLF改行で終わる）を構築する
+fn render_entity(draft: &MailDraft, now: DateTime<Local>) -> AppResult<String> {
+    let text_part = [
+        "Content-Type: text/plain; charset=UTF-8".to_string(),
+        "Content-Transfer-Encoding: base64".to_string(),
+        String::new(),
+        encode_base64_lines(draft.body().to_crlf().as_bytes()),
+    ]
+    .join("\r\n");
+
+    if draft.attachments().is_empty() {
+        return Ok(text_part + "\r\n");
+    }
+
+    let boundary = format!("----=_mail_composer_{}", now.format("%Y%m%d%H%M%S%f"));
+    let mut parts = vec![text_part];
+    for path in draft.attachments() {
+        parts.push(render_attachment_part(path)?);
+    }
+
+    let mut entity = format!("Content-Type: multipart/mixed; boundary=\"{boundary}\"\r\n");
+    for part in parts {
+        entity.push_str(&format!("\r\n--{boundary}\r\n{part}"));
+    }
+    entity.push_str(&format!("\r\n--{boundary}--\r\n"));
+    Ok(entity)
+}
+
+/// 添付ファイルのMIMEパートを構築する
+fn render_attachment_part(path: &Path) -> AppResult<String> {
+    let content = fs::read(path).map_err(|e| {
+        AppError::new(ErrorKind::NotFound)
+            .with_code("MC-MAIL-022")
+            .with_message(format!(
+                "添付ファイルの読み込みに失敗しました。詳細: {}",
+                path.display()
+            ))
+            .with_action("添付ファイルの存在とアクセス権限を確認してください。")
+            .with_source(e)
+    })?;
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "attachment".to_string());
+    let content_type = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("txt") => "text/plain",
+        Some("pdf") => "application/pdf",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        Some("csv") => "text/csv",
+        _ => "application/octet-stream",
+    };
+    let encoded_name = encode_header_value(&file_name);
+
+    Ok([
+        format!("Content-Type: {content_type}; name=\"{encoded_name}\""),
+        format!("Content-Disposition: attachment; filename=\"{encoded_name}\""),
+        "Content-Transfer-Encoding: base64".to_string(),
+        String::new(),
+        encode_base64_lines(&content),
+    ]
+    .join("\r\n"))
+}
+
+/// 宛先のリストをヘッダー値に変換する
+fn join_addresses(addresses: &[EmailAddress]) -> String {
+    addresses
+        .iter()
+        .map(|addr| match addr.display_name() {
+            Some(name) => format!("{} <{}>", encode_display_name(name), addr.as_str()),
+            None => addr.as_str().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 非ASCII文字を含むヘッダー値をRFC 2047のencoded-wordに変換する
+///
+/// ASCII文字のみの場合はそのまま返す
+fn encode_header_value(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+
+    let mut words = Vec::new();
+    let mut chunk = String::new();
+    for c in value.chars() {
+        if chunk.len() + c.len_utf8() > ENCODED_WORD_CHUNK_BYTES {
+            words.push(format!("=?UTF-8?B?{}?=", STANDARD.encode(&chunk)));
+            chunk.clear();
+        }
+        chunk.push(c);
+    }
+    if !chunk.is_empty() {
+        words.push(format!("=?UTF-8?B?{}?=", STANDARD.encode(&chunk)));
+    }
+    words.join("\r\n ")
+}
+
+/// 表示名をヘッダーに設定できる形式に変換する
+///
+/// ASCII文字のみの場合は引用符で囲み、それ以外はencoded-wordに変換する
+fn encode_display_name(name: &str) -> String {
+    if name.is_ascii() {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        encode_header_value(name)
+    }
+}
+
+/// データをbase64エンコードし、76文字ごとに改行する
+fn encode_base64_lines(data: &[u8]) -> String {
+    let encoded = STANDARD.encode(data);
+    encoded
+        .as_bytes()
+        .chunks(BASE64_LINE_LENGTH)
+        .map(|line| String::from_utf8_lossy(line).into_owned())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::mail_objects::{MailBody, Subject};
+
+    #[test]
+    fn test_render_eml() {
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("to@example.com").unwrap()],
+            vec![EmailAddress::parse("cc@example.com").unwrap()],
+            Subject::new("テスト件名").unwrap(),
+            MailBody::new("テスト本文"),
+        )
+        .with_header("Disposition-Notification-To", "sender@example.com")
+        .unwrap();
+
+        let eml = render_eml(&draft, Some("Sender"), "sender@example.com").unwrap();
+
+        assert!(eml.contains("From: \"Sender\" <sender@example.com>\r\n"));
+        assert!(eml.contains("To: to@example.com\r\n"));
+        assert!(eml.contains("Cc: cc@example.com\r\n"));
+        assert!(eml.contains(&format!(
+            "Subject: =?UTF-8?B?{}?=\r\n",
+            STANDARD.encode("テスト件名")
+        )));
+        assert!(eml.contains("Disposition-Notification-To: sender@example.com\r\n"));
+        assert!(eml.contains(&STANDARD.encode("テスト本文")));
+    }
+
+    #[test]
+    fn test_render_eml_with_display_names() {
+        let draft = MailDraft::new(
+            vec![
+                EmailAddress::parse("yamada@example.com")
+                    .unwrap()
+                    .with_display_name("山田太郎"),
+            ],
+            vec![EmailAddress::parse("\"Sato Hanako\" <sato@example.com>").unwrap()],
+            Subject::new("件名").unwrap(),
+            MailBody::new("本文"),
+        );
+
+        let eml = render_eml(&draft, None, "sender@example.com").unwrap();
+
+        assert!(eml.contains(&format!(
+            "To: =?UTF-8?B?{}?= <yamada@example.com>\r\n",
+            STANDARD.encode("山田太郎")
+        )));
+        assert!(eml.contains("Cc: \"Sato Hanako\" <sato@example.com>\r\n"));
+        assert_eq!(
+            draft.to_addresses_as_string(),
+            "\"山田太郎\" <yamada@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_encode_header_value_splits_long_value() {
+        let value = "あ".repeat(40);
+        let encoded = encode_header_value(&value);
+
+        assert!(encoded.contains("\r\n "));
+        assert!(
+            encoded
+                .split("\r\n ")
+                .all(|word| word.starts_with("=?UTF-8?B?") && word.ends_with("?="))
+        );
+    }
+
+    struct StubSigner;
+
+    impl MailSignerPort for StubSigner {
+        fn sign(&self, entity: &str) -> AppResult<String> {
+            Ok(format!(
+                "Content-Type: multipart/signed; boundary=\"b\"\r\n\r\n--b\r\n{entity}--b--\r\n"
+            ))
+        }
+    }
+
+    #[test]
+    fn test_render_signed_eml() {
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("to@example.com").unwrap()],
+            vec![],
+            Subject::new("Signed").unwrap(),
+            MailBody::new("本文"),
+        );
+
+        let eml = render_signed_eml(&draft, None, "sender@example.com", &StubSigner).unwrap();
+        let (headers, entity) = eml.split_once("\r\n\r\n").unwrap();
+
+        assert!(
+            headers
+                .ends_with("MIME-Version: 1.0\r\nContent-Type: multipart/signed; boundary=\"b\"")
+        );
+        assert!(entity.starts_with("--b\r\nContent-Type: text/plain; charset=UTF-8\r\n"));
+        assert!(entity.contains(&STANDARD.encode("本文")));
+    }
+}
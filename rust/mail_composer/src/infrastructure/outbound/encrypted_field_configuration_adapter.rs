@@ -0,0 +1,143 @@
+use crate::domain::{
+    interfaces::{configuration::ConfigurationPort, secret_cipher::SecretCipherPort},
+    value_objects::{
+        app_configuration::AppConfiguration, effective_configuration::EffectiveConfiguration,
+    },
+};
+use serde_json::Value;
+use share::error::app_error::AppResult;
+
+/// 暗号化された設定値を示す接頭辞
+pub const ENCRYPTED_PREFIX: &str = "enc:";
+
+/// `enc:`で始まる設定値を読み込み時に復号するアウトバウンドアダプター（デコレーター）
+///
+/// IMAPやLDAPの接続情報など、設定ファイルに平文で残したくない値に使用する。
+/// 復号後の設定値を検証する
+pub struct EncryptedFieldConfigurationAdapter<C: ConfigurationPort, S: SecretCipherPort> {
+    inner: C,
+    cipher: S,
+}
+
+impl<C: ConfigurationPort, S: SecretCipherPort> EncryptedFieldConfigurationAdapter<C, S> {
+    /// 新しいEncryptedFieldConfigurationAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - 暗号化された値を含む設定を読み込むポート
+    /// * `cipher` - 設定値の復号に使用するポート
+    ///
+    /// ## Returns
+    /// * EncryptedFieldConfigurationAdapterのインスタンス
+    pub fn new(inner: C, cipher: S) -> Self {
+        Self { inner, cipher }
+    }
+
+    /// 設定に含まれる`enc:`で始まる値を全て復号する
+    fn decrypt(&self, config: AppConfiguration) -> AppResult<AppConfiguration> {
+        let mut value = serde_json::to_value(&config)?;
+        self.decrypt_in_json(&mut value)?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// JSON値に含まれる`enc:`で始まる文字列を全て復号する
+    fn decrypt_in_json(&self, value: &mut Value) -> AppResult<()> {
+        match value {
+            Value::String(text) => {
+                if let Some(ciphertext) = text.strip_prefix(ENCRYPTED_PREFIX) {
+                    *text = self.cipher.decrypt(ciphertext)?;
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.decrypt_in_json(item)?;
+                }
+            }
+            Value::Object(fields) => {
+                for field in fields.values_mut() {
+                    self.decrypt_in_json(field)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl<C: ConfigurationPort, S: SecretCipherPort> ConfigurationPort
+    for EncryptedFieldConfigurationAdapter<C, S>
+{
+    fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        let config = self.load_unvalidated_configuration()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        let config = self.inner.load_unvalidated_configuration()?;
+        self.decrypt(config)
+    }
+
+    fn load_effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        let mut effective = self.inner.load_effective_configuration()?;
+        effective.configuration = self.decrypt(effective.configuration)?;
+        Ok(effective)
+    }
+
+    fn configuration_exists(&self) -> bool {
+        self.inner.configuration_exists()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::app_configuration::ImapConfiguration;
+
+    /// 文字列を反転するだけの暗号（テスト用）
+    struct ReversingCipher;
+
+    impl SecretCipherPort for ReversingCipher {
+        fn encrypt(&self, plaintext: &str) -> AppResult<String> {
+            Ok(plaintext.chars().rev().collect())
+        }
+
+        fn decrypt(&self, ciphertext: &str) -> AppResult<String> {
+            self.encrypt(ciphertext)
+        }
+    }
+
+    struct StubConfiguration;
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(AppConfiguration {
+                from: "山田".to_string(),
+                department: "開発部".to_string(),
+                thunderbird_exe: "thunderbird".to_string(),
+                imap: Some(ImapConfiguration {
+                    host: "imap.example.com".to_string(),
+                    port: 993,
+                    username: "enc:adamay".to_string(),
+                    sender_address: "yamada@example.com".to_string(),
+                    folder: "Drafts".to_string(),
+                    use_tls: true,
+                }),
+                ..AppConfiguration::default()
+            })
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_decrypt_prefixed_fields() {
+        let adapter = EncryptedFieldConfigurationAdapter::new(StubConfiguration, ReversingCipher);
+
+        let config = adapter.load_configuration().unwrap();
+
+        assert_eq!(config.from, "山田");
+        assert_eq!(config.imap.unwrap().username, "yamada");
+    }
+}
@@ -0,0 +1,166 @@
+use crate::domain::{
+    interfaces::configuration::ConfigurationPort,
+    value_objects::{
+        app_configuration::AppConfiguration,
+        effective_configuration::{ConfigurationSource, EffectiveConfiguration},
+    },
+};
+use share::error::app_error::AppResult;
+
+pub use crate::domain::value_objects::app_configuration::ENV_PREFIX;
+
+/// 環境変数で設定値を上書きするアウトバウンドアダプター（デコレーター）
+///
+/// `MAIL_COMPOSER_<設定項目名の大文字>`（例: `MAIL_COMPOSER_FROM`、`MAIL_COMPOSER_THUNDERBIRD_EXE`）が
+/// 設定されている場合、設定ファイルの値より優先する。上書き後の設定値を検証する
+///
+/// 起動時に[`load_app_dotenv`](share::utils::dotenv::load_app_dotenv)で読み込んだ
+/// `.env`ファイルの変数も同様に扱う
+pub struct EnvOverrideConfigurationAdapter<C: ConfigurationPort> {
+    inner: C,
+    lookup: fn(&str) -> Option<String>,
+}
+
+impl<C: ConfigurationPort> EnvOverrideConfigurationAdapter<C> {
+    /// 新しいEnvOverrideConfigurationAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - 上書き元の設定を読み込むポート
+    ///
+    /// ## Returns
+    /// * EnvOverrideConfigurationAdapterのインスタンス
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            lookup: |name| std::env::var(name).ok(),
+        }
+    }
+
+    /// 環境変数の値を取得する（空の値は未設定として扱う）
+    fn var(&self, field: &str) -> Option<String> {
+        (self.lookup)(&AppConfiguration::env_var_name(field))
+            .filter(|value| !value.trim().is_empty())
+    }
+
+    /// 環境変数で設定値を上書きし、上書きした設定項目名を返す
+    fn apply_overrides(&self, config: &mut AppConfiguration) -> AppResult<Vec<&'static str>> {
+        let mut applied = Vec::new();
+        for field in AppConfiguration::OVERRIDABLE_FIELDS {
+            if let Some(value) = self.var(field) {
+                config.set_field(field, &value).map_err(|e| {
+                    let message = format!(
+                        "環境変数{}の値が不正です。詳細: {value}",
+                        AppConfiguration::env_var_name(field)
+                    );
+                    e.with_message(message)
+                })?;
+                applied.push(field);
+            }
+        }
+        Ok(applied)
+    }
+}
+
+impl<C: ConfigurationPort> ConfigurationPort for EnvOverrideConfigurationAdapter<C> {
+    fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        let config = self.load_unvalidated_configuration()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        let mut config = self.inner.load_unvalidated_configuration()?;
+        self.apply_overrides(&mut config)?;
+        Ok(config)
+    }
+
+    fn load_effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        let mut effective = self.inner.load_effective_configuration()?;
+        for field in self.apply_overrides(&mut effective.configuration)? {
+            effective
+                .sources
+                .insert(field.to_string(), ConfigurationSource::Environment);
+        }
+        Ok(effective)
+    }
+
+    fn configuration_exists(&self) -> bool {
+        self.inner.configuration_exists()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubConfiguration;
+
+    impl ConfigurationPort for StubConfiguration {
+        fn load_configuration(&self) -> AppResult<AppConfiguration> {
+            let config = self.load_unvalidated_configuration()?;
+            config.validate()?;
+            Ok(config)
+        }
+
+        fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+            Ok(serde_json::from_value(serde_json::json!({
+                "from": "",
+                "department": "開発部",
+                "thunderbird_exe": "C:\\Program Files\\Mozilla Thunderbird\\thunderbird.exe",
+                "log_dir": "logs",
+                "input_dir": "config",
+                "address_book_file": "address_book.json",
+                "output_dir": "data",
+                "start_time_file": "start_time.json"
+            }))?)
+        }
+
+        fn configuration_exists(&self) -> bool {
+            true
+        }
+    }
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "MAIL_COMPOSER_FROM" => Some("山田".to_string()),
+            "MAIL_COMPOSER_THUNDERBIRD_EXE" => Some("/usr/bin/thunderbird".to_string()),
+            "MAIL_COMPOSER_MESSAGES_PER_MINUTE" => Some("10".to_string()),
+            "MAIL_COMPOSER_DEPARTMENT" => Some(" ".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        assert!(StubConfiguration.load_configuration().is_err());
+
+        let adapter = EnvOverrideConfigurationAdapter {
+            inner: StubConfiguration,
+            lookup,
+        };
+        let config = adapter.load_configuration().unwrap();
+
+        assert_eq!(config.from, "山田");
+        assert_eq!(config.department, "開発部");
+        assert_eq!(config.thunderbird_exe, "/usr/bin/thunderbird");
+        assert_eq!(config.messages_per_minute, Some(10));
+
+        let effective = adapter.load_effective_configuration().unwrap();
+        assert_eq!(effective.configuration, config);
+        assert_eq!(effective.sources["from"], ConfigurationSource::Environment);
+        assert_eq!(effective.sources["department"], ConfigurationSource::File);
+    }
+
+    #[test]
+    fn test_invalid_number() {
+        let adapter = EnvOverrideConfigurationAdapter {
+            inner: StubConfiguration,
+            lookup: |name| {
+                (name == "MAIL_COMPOSER_FROM" || name == "MAIL_COMPOSER_THUNDERBIRD_TIMEOUT_SECS")
+                    .then(|| "abc".to_string())
+            },
+        };
+
+        assert!(adapter.load_configuration().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{path::Path, process::Command};
+
+/// ユーザーが指定したエディタでファイルを開くアウトバウンドアダプター
+///
+/// `VISUAL`、`EDITOR`の順に環境変数を参照し、どちらも未設定の場合は
+/// Windowsでは`notepad`、それ以外では`vi`を使用する
+pub struct ExternalEditorAdapter {
+    program: String,
+    args: Vec<String>,
+}
+
+impl ExternalEditorAdapter {
+    /// 環境変数からエディタを決定してExternalEditorAdapterを作成する
+    ///
+    /// ## Returns
+    /// * ExternalEditorAdapterのインスタンス
+    pub fn from_env() -> Self {
+        Self::from_command(
+            std::env::var("VISUAL")
+                .ok()
+                .or_else(|| std::env::var("EDITOR").ok())
+                .as_deref(),
+        )
+    }
+
+    /// エディタのコマンドを指定してExternalEditorAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `command` - 空白区切りのコマンドと引数（例: `code --wait`、未指定または空の場合は既定のエディタ）
+    ///
+    /// ## Returns
+    /// * ExternalEditorAdapterのインスタンス
+    pub fn from_command(command: Option<&str>) -> Self {
+        let mut words = command.unwrap_or_default().split_whitespace();
+        match words.next() {
+            Some(program) => Self {
+                program: program.to_string(),
+                args: words.map(str::to_string).collect(),
+            },
+            None => Self {
+                program: Self::default_program().to_string(),
+                args: Vec::new(),
+            },
+        }
+    }
+
+    /// 実行中のプラットフォームの既定のエディタ
+    fn default_program() -> &'static str {
+        if cfg!(target_os = "windows") {
+            "notepad"
+        } else {
+            "vi"
+        }
+    }
+
+    /// ファイルをエディタで開き、エディタが終了するまで待機する
+    ///
+    /// ## Arguments
+    /// * `path` - 開くファイルのパス
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok(())`
+    /// * 失敗時 - エディタを起動できない場合、またはエディタが異常終了した場合の`Err<AppError>`
+    pub fn open(&self, path: &Path) -> AppResult<()> {
+        tracing::debug!(program = %self.program, path = %path.display(), "エディタを起動します");
+        let status = Command::new(&self.program)
+            .args(&self.args)
+            .arg(path)
+            .status()
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-CLI-010")
+                    .with_message(format!("エディタ'{}'の起動に失敗しました。", self.program))
+                    .with_action("環境変数EDITORに使用するエディタのコマンドを設定してください。")
+                    .with_source(e)
+            })?;
+
+        if !status.success() {
+            return Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CLI-011")
+                .with_message(format!(
+                    "エディタ'{}'が異常終了しました。詳細: {status}",
+                    self.program
+                ))
+                .with_action("エディタの設定を確認してください。"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_command() {
+        let editor = ExternalEditorAdapter::from_command(Some("code --wait"));
+        assert_eq!(editor.program, "code");
+        assert_eq!(editor.args, vec!["--wait"]);
+
+        let editor = ExternalEditorAdapter::from_command(Some("  "));
+        assert_eq!(editor.program, ExternalEditorAdapter::default_program());
+        assert!(editor.args.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::domain::interfaces::mail_signer::MailSignerPort;
+use chrono::Local;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// GPGでMIMEエンティティに分離署名を行い、PGP/MIME（RFC 3156）の`multipart/signed`のエンティティを作成するアウトバウンドアダプター
+pub struct GpgMailSignerAdapter {
+    gpg_exe_path: String,
+    key_id: Option<String>,
+}
+
+impl GpgMailSignerAdapter {
+    /// 新しいGpgMailSignerAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `key_id` - 署名に使用する鍵ID（`None`の場合はGPGのデフォルト鍵）
+    ///
+    /// ## Returns
+    /// * GpgMailSignerAdapterのインスタンス
+    pub fn new(key_id: Option<String>) -> Self {
+        Self {
+            gpg_exe_path: "gpg".to_string(),
+            key_id,
+        }
+    }
+
+    /// GPG実行ファイルのパスを指定する
+    ///
+    /// ## Arguments
+    /// * `gpg_exe_path` - GPG実行ファイルのパス
+    ///
+    /// ## Returns
+    /// * GPG実行ファイルのパスが設定されたGpgMailSignerAdapterのインスタンス
+    pub fn with_gpg_exe(mut self, gpg_exe_path: impl Into<String>) -> Self {
+        self.gpg_exe_path = gpg_exe_path.into();
+        self
+    }
+
+    /// gpgコマンドの引数を構築する
+    fn build_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--batch".to_string(),
+            "--yes".to_string(),
+            "--armor".to_string(),
+            "--detach-sign".to_string(),
+            "--digest-algo".to_string(),
+            "SHA256".to_string(),
+        ];
+        if let Some(key_id) = &self.key_id {
+            args.push("--local-user".to_string());
+            args.push(key_id.clone());
+        }
+        args
+    }
+}
+
+impl MailSignerPort for GpgMailSignerAdapter {
+    fn sign(&self, entity: &str) -> AppResult<String> {
+        let _span = logging::operation_span("gpg_sign").entered();
+        // 境界の直前の改行は署名対象に含まれないため、末尾の改行を除いて署名する
+        let entity = entity.strip_suffix("\r\n").unwrap_or(entity);
+
+        let mut child = Command::new(&self.gpg_exe_path)
+            .args(self.build_args())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-MAIL-023")
+                    .with_message("GPGの起動に失敗しました。")
+                    .with_action("GPGがインストールされ、PATHが通っていることを確認してください。")
+                    .with_source(e)
+            })?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(entity.as_bytes()).map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-MAIL-024")
+                    .with_message("GPGへの署名対象の受け渡しに失敗しました。")
+                    .with_action("システムリソースを確認してください。")
+                    .with_source(e)
+            })?;
+        }
+
+        let output = child.wait_with_output().map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-025")
+                .with_message("GPGプロセスの待機に失敗しました。")
+                .with_action("システムリソースを確認してください。")
+                .with_source(e)
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-026")
+                .with_message(format!(
+                    "GPGによる署名に失敗しました。詳細: {}",
+                    stderr.trim()
+                ))
+                .with_action("署名に使用する鍵IDとパスフレーズの設定を確認してください。"));
+        }
+
+        let signature = String::from_utf8(output.stdout).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-027")
+                .with_message("GPGの出力の解析に失敗しました。")
+                .with_action("GPGの文字コード設定を確認してください。")
+                .with_source(e)
+        })?;
+
+        let boundary = format!(
+            "----=_mail_composer_signed_{}",
+            Local::now().format("%Y%m%d%H%M%S%f")
+        );
+        Ok(build_signed_entity(entity, &signature, &boundary))
+    }
+}
+
+/// 署名対象のエンティティと署名から`multipart/signed`のエンティティを構築する
+fn build_signed_entity(entity: &str, signature: &str, boundary: &str) -> String {
+    let signature = signature
+        .trim_end()
+        .replace("\r\n", "\n")
+        .replace('\n', "\r\n");
+    [
+        format!(
+            "Content-Type: multipart/signed; micalg=pgp-sha256; protocol=\"application/pgp-signature\"; boundary=\"{boundary}\""
+        ),
+        String::new(),
+        format!("--{boundary}"),
+        entity.to_string(),
+        format!("--{boundary}"),
+        "Content-Type: application/pgp-signature; name=\"signature.asc\"".to_string(),
+        "Content-Description: OpenPGP digital signature".to_string(),
+        "Content-Disposition: attachment; filename=\"signature.asc\"".to_string(),
+        String::new(),
+        signature,
+        format!("--{boundary}--"),
+        String::new(),
+    ]
+    .join("\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_args() {
+        let adapter = GpgMailSignerAdapter::new(Some("ABCD1234".to_string()));
+        let args = adapter.build_args();
+
+        assert!(args.contains(&"--detach-sign".to_string()));
+        assert_eq!(args[args.len() - 2..], ["--local-user", "ABCD1234"]);
+
+        let adapter = GpgMailSignerAdapter::new(None);
+        assert!(!adapter.build_args().contains(&"--local-user".to_string()));
+    }
+
+    #[test]
+    fn test_build_signed_entity() {
+        let entity = "Content-Type: text/plain\r\n\r\nbody";
+        let signature = "-----BEGIN PGP SIGNATURE-----\n\nabc\n-----END PGP SIGNATURE-----\n";
+
+        let signed = build_signed_entity(entity, signature, "b");
+
+        assert_eq!(
+            signed,
+            "Content-Type: multipart/signed; micalg=pgp-sha256; protocol=\"application/pgp-signature\"; boundary=\"b\"\r\n\
+             \r\n\
+             --b\r\n\
+             Content-Type: text/plain\r\n\r\nbody\r\n\
+             --b\r\n\
+             Content-Type: application/pgp-signature; name=\"signature.asc\"\r\n\
+             Content-Description: OpenPGP digital signature\r\n\
+             Content-Disposition: attachment; filename=\"signature.asc\"\r\n\
+             \r\n\
+             -----BEGIN PGP SIGNATURE-----\r\n\r\nabc\r\n-----END PGP SIGNATURE-----\r\n\
+             --b--\r\n"
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use crate::domain::{
+    interfaces::{configuration::ConfigurationPort, mail_config::MailConfigPort},
+    value_objects::{
+        app_configuration::AppConfiguration, effective_configuration::EffectiveConfiguration,
+        mail_config::MailConfig,
+    },
+};
+use serde::Serialize;
+use share::{
+    error::app_error::{AppError, AppResult},
+    utils::workspace::workspace_path,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+/// 設定ファイルの再読み込み時に通知されるイベント
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ReloadEvent {
+    /// 変更された設定ファイルを読み込み、検証に成功した
+    Reloaded {
+        /// 再読み込みした設定ファイルのパス
+        path: PathBuf,
+    },
+    /// 変更された設定ファイルの読み込みまたは検証に失敗した（直前の設定を使い続ける）
+    ReloadFailed {
+        /// 再読み込みに失敗した設定ファイルのパス
+        path: PathBuf,
+        /// 失敗の原因
+        error: AppError,
+    },
+}
+
+/// 再読み込みイベントを受け取る関数
+pub type ReloadListener = fn(&ReloadEvent);
+
+/// 再読み込みイベントをログに出力する
+fn log_event(event: &ReloadEvent) {
+    match event {
+        ReloadEvent::Reloaded { path } => {
+            tracing::info!(path = %path.display(), "設定ファイルを再読み込みしました");
+        }
+        ReloadEvent::ReloadFailed { path, error } => {
+            tracing::warn!(
+                path = %path.display(),
+                error = %error,
+                "設定ファイルの再読み込みに失敗したため、直前の設定を使い続けます"
+            );
+        }
+    }
+}
+
+/// 読み込み済みの設定と、最後に読み込みを試みた時点のファイル更新日時
+struct WatchState<T> {
+    attempted: Option<Option<SystemTime>>,
+    value: Option<T>,
+}
+
+/// ファイルの更新日時を監視し、変更時のみ再読み込みを行う
+struct FileWatch<T> {
+    path: PathBuf,
+    listener: ReloadListener,
+    state: Mutex<WatchState<T>>,
+}
+
+impl<T: Clone> FileWatch<T> {
+    fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            listener: log_event,
+            state: Mutex::new(WatchState {
+                attempted: None,
+                value: None,
+            }),
+        }
+    }
+
+    /// ファイルの更新日時を取得する（取得できない場合は`None`）
+    fn modified(&self) -> Option<SystemTime> {
+        let path = workspace_path(&self.path).ok()?;
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// ファイルが変更されていれば再読み込みし、最新の有効な設定を返す
+    ///
+    /// 再読み込みに失敗した場合は[`ReloadEvent::ReloadFailed`]を通知し、直前の設定を返す。
+    /// 一度も読み込みに成功していない場合はエラーを返す
+    fn get(&self, load: impl FnOnce() -> AppResult<T>) -> AppResult<T> {
+        let modified = self.modified();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(value) = &state.value
+            && state.attempted == Some(modified)
+        {
+            return Ok(value.clone());
+        }
+
+        state.attempted = Some(modified);
+        match (load(), state.value.take()) {
+            (Ok(value), previous) => {
+                if previous.is_some() {
+                    (self.listener)(&ReloadEvent::Reloaded {
+                        path: self.path.clone(),
+                    });
+                }
+                Ok(state.value.insert(value).clone())
+            }
+            (Err(error), Some(previous)) => {
+                (self.listener)(&ReloadEvent::ReloadFailed {
+                    path: self.path.clone(),
+                    error,
+                });
+                Ok(state.value.insert(previous).clone())
+            }
+            (Err(error), None) => Err(error),
+        }
+    }
+}
+
+/// 設定ファイルの変更を検知して再読み込みするアウトバウンドアダプター（デコレーター）
+///
+/// 常駐モードで使用する。変更後の設定が不正な場合は直前の設定を使い続け、
+/// [`ReloadEvent::ReloadFailed`]を通知する
+pub struct HotReloadConfigurationAdapter<C: ConfigurationPort> {
+    inner: C,
+    watch: FileWatch<AppConfiguration>,
+}
+
+impl<C: ConfigurationPort> HotReloadConfigurationAdapter<C> {
+    /// 新しいHotReloadConfigurationAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - 設定を読み込むポート
+    /// * `path` - 監視する設定ファイルのパス（ワークスペースルートからの相対パス）
+    ///
+    /// ## Returns
+    /// * HotReloadConfigurationAdapterのインスタンス
+    pub fn new(inner: C, path: &Path) -> Self {
+        Self {
+            inner,
+            watch: FileWatch::new(path),
+        }
+    }
+
+    /// 再読み込みイベントの通知先を設定する（既定ではログに出力する）
+    ///
+    /// ## Arguments
+    /// * `listener` - 再読み込みイベントを受け取る関数
+    ///
+    /// ## Returns
+    /// * 通知先が設定されたHotReloadConfigurationAdapterのインスタンス
+    pub fn with_listener(mut self, listener: ReloadListener) -> Self {
+        self.watch.listener = listener;
+        self
+    }
+}
+
+impl<C: ConfigurationPort> ConfigurationPort for HotReloadConfigurationAdapter<C> {
+    fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        self.watch.get(|| self.inner.load_configuration())
+    }
+
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        self.inner.load_unvalidated_configuration()
+    }
+
+    fn load_effective_configuration(&self) -> AppResult<EffectiveConfiguration> {
+        self.inner.load_effective_configuration()
+    }
+
+    fn configuration_exists(&self) -> bool {
+        self.inner.configuration_exists()
+    }
+}
+
+/// メールテンプレートファイルの変更を検知して再読み込みするアウトバウンドアダプター（デコレーター）
+///
+/// 変更後のテンプレートが不正な場合は直前のテンプレートを使い続け、
+/// [`ReloadEvent::ReloadFailed`]を通知する
+pub struct HotReloadMailConfigAdapter<M: MailConfigPort> {
+    inner: M,
+    watch: FileWatch<MailConfig>,
+}
+
+impl<M: MailConfigPort> HotReloadMailConfigAdapter<M> {
+    /// 新しいHotReloadMailConfigAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - メールテンプレートを読み込むポート
+    /// * `path` - 監視するテンプレートファイルのパス（ワークスペースルートからの相対パス）
+    ///
+    /// ## Returns
+    /// * HotReloadMailConfigAdapterのインスタンス
+    pub fn new(inner: M, path: &Path) -> Self {
+        Self {
+            inner,
+            watch: FileWatch::new(path),
+        }
+    }
+
+    /// 再読み込みイベントの通知先を設定する（既定ではログに出力する）
+    ///
+    /// ## Arguments
+    /// * `listener` - 再読み込みイベントを受け取る関数
+    ///
+    /// ## Returns
+    /// * 通知先が設定されたHotReloadMailConfigAdapterのインスタンス
+    pub fn with_listener(mut self, listener: ReloadListener) -> Self {
+        self.watch.listener = listener;
+        self
+    }
+}
+
+impl<M: MailConfigPort> MailConfigPort for HotReloadMailConfigAdapter<M> {
+    fn load_mail_config(&self) -> AppResult<MailConfig> {
+        self.watch.get(|| self.inner.load_mail_config())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbound::json_configuration_adapter::JsonConfigurationAdapter;
+    use std::time::Duration;
+
+    static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    fn record_event(event: &ReloadEvent) {
+        EVENTS
+            .lock()
+            .unwrap()
+            .push(serde_json::to_string(event).unwrap());
+    }
+
+    fn touch(path: &Path, secs: u64) {
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(secs))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_reload_and_keep_previous_on_failure() {
+        let path = std::env::temp_dir().join(format!(
+            "mail_composer_hot_reload_{}.json",
+            std::process::id()
+        ));
+        let file = |from: &str| {
+            format!(
+                r#"{{ "from": "{from}", "department": "開発部", "thunderbird_exe": "thunderbird",
+                    "log_dir": "logs", "input_dir": "config", "address_book_file": "address_book.json",
+                    "output_dir": "data", "start_time_file": "work_start_time.json" }}"#
+            )
+        };
+        fs::write(&path, file("山田")).unwrap();
+
+        let adapter = HotReloadConfigurationAdapter::new(
+            JsonConfigurationAdapter::new(path.to_string_lossy()),
+            &path,
+        )
+        .with_listener(record_event);
+        assert_eq!(adapter.load_configuration().unwrap().from, "山田");
+
+        fs::write(&path, file("佐藤")).unwrap();
+        touch(&path, 10);
+        assert_eq!(adapter.load_configuration().unwrap().from, "佐藤");
+
+        fs::write(&path, file("")).unwrap();
+        touch(&path, 20);
+        let config = adapter.load_configuration().unwrap();
+        // 同じ変更に対してイベントは一度だけ通知される
+        adapter.load_configuration().unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(config.from, "佐藤");
+        let events = EVENTS.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].contains(r#""event":"reloaded""#));
+        assert!(events[1].contains(r#""event":"reload_failed""#));
+        assert!(events[1].contains("差出人名"));
+    }
+}
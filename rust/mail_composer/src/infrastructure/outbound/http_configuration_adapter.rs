@@ -0,0 +1,410 @@
+use crate::{
+    APP_NAME,
+    domain::{
+        interfaces::{configuration::ConfigurationPort, mail_config::MailConfigPort},
+        value_objects::{app_configuration::AppConfiguration, mail_config::MailConfig},
+    },
+    infrastructure::outbound::{
+        json_configuration_adapter::JsonConfigurationAdapter,
+        json_mail_config_adapter::JsonMailConfigAdapter,
+    },
+};
+use serde_json::Value;
+use share::{
+    config::source::{ConfigSource, JsonFileSource},
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+        result_ext::ResultExt,
+    },
+    logging,
+    utils::{
+        dirs::{AppDirectory, resolve_app_path},
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path},
+    },
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// HTTPSで配布されるファイルを、ETagで更新を確認しながらローカルにキャッシュする
+///
+/// HTTP通信には`curl`を使用し、リダイレクト先を含めてHTTPS以外では取得しない。
+/// サーバーに接続できない場合や取得した内容がJSONでない場合は、キャッシュ済みのファイルを使用する
+struct HttpFileCache {
+    curl_exe_path: String,
+    url: String,
+    cache_path: PathBuf,
+}
+
+impl HttpFileCache {
+    fn new(url: impl Into<String>, cache_path: &Path) -> Self {
+        Self {
+            curl_exe_path: "curl".to_string(),
+            url: url.into(),
+            cache_path: cache_path.to_path_buf(),
+        }
+    }
+
+    /// データディレクトリの`cache`内のキャッシュ先を取得する
+    fn default_cache_path(file_name: &str) -> PathBuf {
+        resolve_app_path(AppDirectory::Data, APP_NAME, "", "rust/mail_composer/data")
+            .join("cache")
+            .join(file_name)
+    }
+
+    /// 前回取得時のETagを保存するファイル
+    fn etag_path(cache_path: &Path) -> PathBuf {
+        let mut path = cache_path.as_os_str().to_owned();
+        path.push(".etag");
+        PathBuf::from(path)
+    }
+
+    /// レスポンスヘッダーから最後のETagを取り出す（リダイレクト時は最終レスポンスのもの）
+    fn parse_etag(headers: &str) -> Option<String> {
+        headers
+            .lines()
+            .filter_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.trim()
+                    .eq_ignore_ascii_case("etag")
+                    .then(|| value.trim().to_string())
+            })
+            .next_back()
+    }
+
+    /// 必要に応じてファイルを取得し、キャッシュファイルの絶対パスを返す
+    fn refresh(&self) -> AppResult<PathBuf> {
+        let cache_path = workspace_path(&self.cache_path)?;
+        if let Some(parent) = cache_path.parent() {
+            ensure_directory_exists(parent)?;
+        }
+
+        match self.download(&cache_path) {
+            Ok(()) => Ok(cache_path),
+            // 接続できない場合もキャッシュがあれば作業を続けられるようにする
+            Err(e) if cache_path.is_file() => {
+                tracing::warn!(
+                    url = %self.url,
+                    error = %e,
+                    "設定ファイルを取得できなかったため、キャッシュ済みのファイルを使用します"
+                );
+                Ok(cache_path)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// サーバーに更新を問い合わせ、更新されていればキャッシュを置き換える
+    ///
+    /// 取得した内容がJSONとして解析できない場合（プロキシのエラーページなど）はキャッシュを置き換えない
+    fn download(&self, cache_path: &Path) -> AppResult<()> {
+        let _span = logging::operation_span("http_configuration_download").entered();
+        let etag_path = Self::etag_path(cache_path);
+        let download_path = cache_path.with_extension("download");
+        let headers_path = cache_path.with_extension("headers");
+
+        let mut command = Command::new(&self.curl_exe_path);
+        command
+            .args(["--silent", "--show-error", "--location"])
+            .args(["--proto", "=https", "--proto-redir", "=https"])
+            .args(["--write-out", "%{http_code}", "--output"])
+            .arg(&download_path)
+            .arg("--dump-header")
+            .arg(&headers_path);
+        if cache_path.is_file()
+            && let Ok(etag) = fs::read_to_string(&etag_path)
+        {
+            command.args(["--header", &format!("If-None-Match: {}", etag.trim())]);
+        }
+        let output = command
+            .arg(&self.url)
+            .output()
+            .kind(ErrorKind::InternalServerError)
+            .ctx_code("MC-CONF-019")
+            .ctx_msg("curlの起動に失敗しました。")
+            .ctx_action("curlがインストールされ、PATHが通っていることを確認してください。")?;
+
+        let headers = fs::read_to_string(&headers_path).unwrap_or_default();
+        let _ = fs::remove_file(&headers_path);
+        let status = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+        match (output.status.success(), status.as_str()) {
+            (true, "304") => {
+                let _ = fs::remove_file(&download_path);
+                Ok(())
+            }
+            (true, "200") => {
+                let content = fs::read(&download_path);
+                let _ = fs::remove_file(&download_path);
+                let content = content
+                    .kind(ErrorKind::InternalServerError)
+                    .ctx_code("MC-CONF-040")
+                    .ctx_msg("取得した設定ファイルの読み込みに失敗しました。")
+                    .ctx_action("一時ファイルのアクセス権限を確認してください。")?;
+                if let Err(e) = serde_json::from_slice::<Value>(&content) {
+                    return Err(AppError::new(ErrorKind::UnprocessableEntity)
+                        .with_code("MC-CONF-041")
+                        .with_message(format!(
+                            "取得した設定ファイルがJSONではありません。詳細: {}",
+                            self.url
+                        ))
+                        .with_action("URLが正しいこと、プロキシを経由せずに取得できることを確認してください。")
+                        .retryable(true)
+                        .with_source(e));
+                }
+                write_atomic(cache_path, &content)
+                    .kind(ErrorKind::InternalServerError)
+                    .ctx_code("MC-CONF-020")
+                    .ctx_msg("取得した設定ファイルの保存に失敗しました。")
+                    .ctx_action("キャッシュディレクトリのアクセス権限を確認してください。")?;
+                match Self::parse_etag(&headers) {
+                    Some(etag) => fs::write(&etag_path, etag)?,
+                    None => {
+                        let _ = fs::remove_file(&etag_path);
+                    }
+                }
+                Ok(())
+            }
+            _ => {
+                let _ = fs::remove_file(&download_path);
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                // 4xxはURLや権限の誤りのため、時間をおいて再取得しても解消しない
+                let is_client_error = status.starts_with('4');
+                Err(AppError::new(ErrorKind::ServiceUnavailable)
+                    .with_code("MC-CONF-021")
+                    .with_message(format!(
+                        "設定ファイルの取得に失敗しました。詳細: {} (HTTP {status}) {}",
+                        self.url,
+                        stderr.trim()
+                    ))
+                    .with_action("URLとネットワーク接続、サーバーの状態を確認してください。")
+                    .retryable(!is_client_error))
+            }
+        }
+    }
+}
+
+/// 社内のHTTPSサーバーで一元管理された`app.json`を読み込むアウトバウンドアダプター
+///
+/// 取得したファイルはローカルにキャッシュし、ETagで更新があった場合のみ再取得する。
+/// [`ConfigSource`]として、ローカルの設定ファイルに重ねる層にも使用できる
+pub struct HttpConfigurationAdapter {
+    cache: HttpFileCache,
+}
+
+impl HttpConfigurationAdapter {
+    /// 新しいHttpConfigurationAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `url` - `app.json`のURL
+    /// * `cache_path` - 取得したファイルのキャッシュ先（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * HttpConfigurationAdapterのインスタンス
+    pub fn new(url: impl Into<String>, cache_path: &Path) -> Self {
+        Self {
+            cache: HttpFileCache::new(url, cache_path),
+        }
+    }
+
+    /// データディレクトリの`cache/app.json`をキャッシュ先とするHttpConfigurationAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `url` - `app.json`のURL
+    ///
+    /// ## Returns
+    /// * HttpConfigurationAdapterのインスタンス
+    pub fn with_default_cache(url: impl Into<String>) -> Self {
+        Self::new(url, &HttpFileCache::default_cache_path("app.json"))
+    }
+
+    /// curl実行ファイルのパスを指定する
+    ///
+    /// ## Arguments
+    /// * `curl_exe_path` - curl実行ファイルのパス
+    ///
+    /// ## Returns
+    /// * curl実行ファイルのパスが設定されたHttpConfigurationAdapterのインスタンス
+    pub fn with_curl_exe(mut self, curl_exe_path: impl Into<String>) -> Self {
+        self.cache.curl_exe_path = curl_exe_path.into();
+        self
+    }
+
+    fn cached_adapter(&self) -> AppResult<JsonConfigurationAdapter> {
+        let path = self.cache.refresh()?;
+        Ok(JsonConfigurationAdapter::new(path.to_string_lossy()))
+    }
+}
+
+impl ConfigurationPort for HttpConfigurationAdapter {
+    fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        self.cached_adapter()?.load_configuration()
+    }
+
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
+        self.cached_adapter()?.load_unvalidated_configuration()
+    }
+
+    fn configuration_exists(&self) -> bool {
+        self.cache.refresh().is_ok()
+    }
+}
+
+impl ConfigSource for HttpConfigurationAdapter {
+    fn name(&self) -> String {
+        self.cache.url.clone()
+    }
+
+    fn load(&self) -> AppResult<Option<Value>> {
+        JsonFileSource::new(self.cache.refresh()?).load()
+    }
+}
+
+/// 社内のHTTPSサーバーで一元管理された`mail_templates.json`を読み込むアウトバウンドアダプター
+///
+/// チーム全体で同じテンプレートを共有する場合に使用する。
+/// 取得したファイルはローカルにキャッシュし、ETagで更新があった場合のみ再取得する
+pub struct HttpMailConfigAdapter {
+    cache: HttpFileCache,
+}
+
+impl HttpMailConfigAdapter {
+    /// 新しいHttpMailConfigAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `url` - `mail_templates.json`のURL
+    /// * `cache_path` - 取得したファイルのキャッシュ先（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * HttpMailConfigAdapterのインスタンス
+    pub fn new(url: impl Into<String>, cache_path: &Path) -> Self {
+        Self {
+            cache: HttpFileCache::new(url, cache_path),
+        }
+    }
+
+    /// データディレクトリの`cache/mail_templates.json`をキャッシュ先とするHttpMailConfigAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `url` - `mail_templates.json`のURL
+    ///
+    /// ## Returns
+    /// * HttpMailConfigAdapterのインスタンス
+    pub fn with_default_cache(url: impl Into<String>) -> Self {
+        Self::new(
+            url,
+            &HttpFileCache::default_cache_path("mail_templates.json"),
+        )
+    }
+
+    /// curl実行ファイルのパスを指定する
+    ///
+    /// ## Arguments
+    /// * `curl_exe_path` - curl実行ファイルのパス
+    ///
+    /// ## Returns
+    /// * curl実行ファイルのパスが設定されたHttpMailConfigAdapterのインスタンス
+    pub fn with_curl_exe(mut self, curl_exe_path: impl Into<String>) -> Self {
+        self.cache.curl_exe_path = curl_exe_path.into();
+        self
+    }
+}
+
+impl MailConfigPort for HttpMailConfigAdapter {
+    fn load_mail_config(&self) -> AppResult<MailConfig> {
+        let path = self.cache.refresh()?;
+        JsonMailConfigAdapter::from_path(path.to_string_lossy()).load_mail_config()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_etag() {
+        let headers = "HTTP/1.1 301 Moved\r\nETag: \"old\"\r\n\r\nHTTP/2 200\r\netag: \"abc123\"\r\ncontent-type: application/json\r\n";
+        assert_eq!(
+            HttpFileCache::parse_etag(headers),
+            Some("\"abc123\"".to_string())
+        );
+        assert_eq!(HttpFileCache::parse_etag("HTTP/2 200\r\n"), None);
+    }
+
+    #[test]
+    fn test_fallback_to_cache_when_unreachable() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("mail_templates.json");
+        fs::write(
+            &cache_path,
+            r#"{ "remote_work_start": { "to_names": ["○○さん"], "cc_names": [],
+                 "subject_template": "件名", "body_template": "本文" } }"#,
+        )
+        .unwrap();
+
+        let adapter =
+            HttpMailConfigAdapter::new("https://config.invalid/mail_templates.json", &cache_path)
+                .with_curl_exe("mail_composer_no_such_curl");
+        let cached = adapter.load_mail_config();
+
+        fs::remove_file(&cache_path).unwrap();
+        let missing = adapter.load_mail_config();
+
+        assert!(cached.unwrap().get_mail_type("remote_work_start").is_some());
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_config_source_reads_cached_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("app.json");
+        fs::write(&cache_path, r#"{ "department": "総務部" }"#).unwrap();
+
+        let adapter = HttpConfigurationAdapter::new("https://config.invalid/app.json", &cache_path)
+            .with_curl_exe("mail_composer_no_such_curl");
+
+        assert_eq!(adapter.name(), "https://config.invalid/app.json");
+        assert_eq!(
+            adapter.load().unwrap(),
+            Some(serde_json::json!({ "department": "総務部" }))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_keep_cache_when_download_is_not_json() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // プロキシのエラーページをHTTP 200で返すcurl
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("curl.sh");
+        fs::write(
+            &script,
+            "#!/bin/sh\nwhile [ $# -gt 0 ]; do\n  [ \"$1\" = --output ] && echo '<html>login</html>' > \"$2\"\n  shift\ndone\nprintf 200\n",
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let cache_path = dir.path().join("app.json");
+        fs::write(&cache_path, r#"{ "department": "総務部" }"#).unwrap();
+        let cache = HttpFileCache {
+            curl_exe_path: script.to_string_lossy().to_string(),
+            url: "https://config.example/app.json".to_string(),
+            cache_path: cache_path.clone(),
+        };
+
+        let error = cache.download(&cache_path).unwrap_err();
+        let refreshed = cache.refresh().unwrap();
+
+        assert_eq!(error.code, Some("MC-CONF-041"));
+        assert_eq!(refreshed, cache_path);
+        assert_eq!(
+            fs::read_to_string(&cache_path).unwrap(),
+            r#"{ "department": "総務部" }"#
+        );
+        assert!(!cache_path.with_extension("download").exists());
+    }
+}
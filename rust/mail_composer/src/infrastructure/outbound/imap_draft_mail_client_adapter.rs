@@ -0,0 +1,315 @@
+use crate::{
+    domain::{
+        entities::mail_draft::MailDraft,
+        interfaces::{mail_client::MailClientPort, mail_signer::MailSignerPort},
+        value_objects::app_configuration::{AppConfiguration, ImapConfiguration},
+    },
+    infrastructure::outbound::{
+        eml_message_renderer::{render_eml, render_signed_eml},
+        mail_client_factory::mail_signer,
+    },
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+        result_ext::ResultExt,
+    },
+    logging,
+};
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// IMAPパスワードを受け取る環境変数名
+pub const IMAP_PASSWORD_ENV: &str = "MAIL_COMPOSER_IMAP_PASSWORD";
+
+/// 作成したメッセージをIMAPの下書きフォルダにアップロード（APPEND）するアウトバウンドアダプター
+///
+/// IMAP通信には`curl`を使用する。署名を設定した場合は、署名付きのメッセージをアップロードする
+pub struct ImapDraftMailClientAdapter {
+    curl_exe_path: String,
+    imap: ImapConfiguration,
+    sender_name: String,
+    password: String,
+    signer: Option<Box<dyn MailSignerPort>>,
+}
+
+impl ImapDraftMailClientAdapter {
+    /// 新しいImapDraftMailClientAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `imap` - IMAPサーバーへの接続設定
+    /// * `sender_name` - Fromヘッダーに設定する差出人名
+    /// * `password` - IMAPのログインパスワード
+    ///
+    /// ## Returns
+    /// * ImapDraftMailClientAdapterのインスタンス
+    pub fn new(
+        imap: ImapConfiguration,
+        sender_name: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            curl_exe_path: "curl".to_string(),
+            imap,
+            sender_name: sender_name.into(),
+            password: password.into(),
+            signer: None,
+        }
+    }
+
+    /// アプリケーション設定からアダプターを作成する
+    ///
+    /// パスワードは環境変数`MAIL_COMPOSER_IMAP_PASSWORD`から読み込み、`signing`が設定されている場合は署名する
+    ///
+    /// ## Arguments
+    /// * `config` - アプリケーション設定
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<ImapDraftMailClientAdapter>`
+    /// * 失敗時 - IMAP設定またはパスワードが未設定の場合の`Err<AppError>`
+    pub fn from_configuration(config: &AppConfiguration) -> AppResult<Self> {
+        let imap = config.imap.clone().ok_or_else(|| {
+            AppError::new(ErrorKind::NotFound)
+                .with_code("MC-MAIL-028")
+                .with_message("IMAPの接続設定が見つかりません。")
+                .with_action("config.jsonにimapフィールド（host、username、sender_address）を設定してください。")
+        })?;
+        let password = std::env::var(IMAP_PASSWORD_ENV).map_err(|e| {
+            AppError::new(ErrorKind::Unauthorized)
+                .with_code("MC-MAIL-029")
+                .with_message("IMAPのパスワードが設定されていません。")
+                .with_action(format!(
+                    "環境変数{IMAP_PASSWORD_ENV}にIMAPのパスワードを設定してください。"
+                ))
+                .with_source(e)
+        })?;
+        let adapter = Self::new(imap, config.from.clone(), password);
+        Ok(match &config.signing {
+            Some(signing) => adapter.with_signer(mail_signer(signing)),
+            None => adapter,
+        })
+    }
+
+    /// アップロードするメッセージの署名に使用するポートを指定する
+    ///
+    /// ## Arguments
+    /// * `signer` - 署名に使用するポート
+    ///
+    /// ## Returns
+    /// * 署名が設定されたImapDraftMailClientAdapterのインスタンス
+    pub fn with_signer(mut self, signer: impl MailSignerPort + 'static) -> Self {
+        self.signer = Some(Box::new(signer));
+        self
+    }
+
+    /// curl実行ファイルのパスを指定する
+    ///
+    /// ## Arguments
+    /// * `curl_exe_path` - curl実行ファイルのパス
+    ///
+    /// ## Returns
+    /// * curl実行ファイルのパスが設定されたImapDraftMailClientAdapterのインスタンス
+    pub fn with_curl_exe(mut self, curl_exe_path: impl Into<String>) -> Self {
+        self.curl_exe_path = curl_exe_path.into();
+        self
+    }
+
+    /// アップロード先フォルダのURLを構築する
+    fn folder_url(&self) -> String {
+        let scheme = if self.imap.use_tls { "imaps" } else { "imap" };
+        format!(
+            "{scheme}://{}:{}/{}",
+            self.imap.host,
+            self.imap.port,
+            self.imap.folder.replace(' ', "%20")
+        )
+    }
+
+    /// アップロードするメッセージ（署名が設定されていれば署名済み）を作成する
+    ///
+    /// [`MailDraft::headers`]のヘッダーもメッセージのヘッダーとして出力する
+    fn render_message(&self, draft: &MailDraft) -> AppResult<String> {
+        let from_name = Some(self.sender_name.as_str());
+        match &self.signer {
+            Some(signer) => render_signed_eml(draft, from_name, &self.imap.sender_address, signer),
+            None => render_eml(draft, from_name, &self.imap.sender_address),
+        }
+    }
+}
+
+impl MailClientPort for ImapDraftMailClientAdapter {
+    fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        let _span = logging::operation_span("imap_draft_append").entered();
+        let message = self.render_message(draft)?;
+
+        if is_dry_run {
+            tracing::debug!(
+                folder = %self.folder_url(),
+                bytes = message.len(),
+                "[DRY-RUN] IMAPのAPPENDを実行しません"
+            );
+            return Ok(());
+        }
+
+        // メッセージは所有者のみ読み書きできる一時ファイルに書き出し、処理を終えた時点で（エラーの場合も）削除する
+        let message_file = tempfile::Builder::new()
+            .prefix("mail_composer_imap_")
+            .suffix(".eml")
+            .tempfile()
+            .and_then(|mut file| {
+                file.write_all(message.as_bytes())?;
+                file.flush()?;
+                Ok(file)
+            })
+            .kind(ErrorKind::InternalServerError)
+            .ctx_code("MC-MAIL-030")
+            .ctx_msg("アップロードするメッセージの書き出しに失敗しました。")
+            .ctx_action("一時ディレクトリの容量とアクセス権限を確認してください。")?;
+
+        // パスワードがプロセス一覧に表示されないよう、認証情報は標準入力の設定ファイルとして渡す
+        let mut child = Command::new(&self.curl_exe_path)
+            .args(["--silent", "--show-error", "--config", "-", "--url"])
+            .arg(self.folder_url())
+            .arg("--upload-file")
+            .arg(message_file.path())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .kind(ErrorKind::InternalServerError)
+            .ctx_code("MC-MAIL-031")
+            .ctx_msg("curlの起動に失敗しました。")
+            .ctx_action("curlがインストールされ、PATHが通っていることを確認してください。")?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            let credentials = format!(
+                "user = \"{}:{}\"\n",
+                self.imap
+                    .username
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\""),
+                self.password.replace('\\', "\\\\").replace('"', "\\\"")
+            );
+            if let Err(e) = stdin.write_all(credentials.as_bytes()) {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-MAIL-064")
+                    .with_message("curlへの認証情報の受け渡しに失敗しました。")
+                    .with_action("curlが正常に起動できることを確認してください。")
+                    .with_source(e));
+            }
+        }
+
+        let output = child
+            .wait_with_output()
+            .kind(ErrorKind::InternalServerError)
+            .ctx_code("MC-MAIL-032")
+            .ctx_msg("curlプロセスの待機に失敗しました。")
+            .ctx_action("システムリソースを確認してください。")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // curlの終了コード67はログイン拒否のため、再試行しても解消しない
+            let is_login_denied = output.status.code() == Some(67);
+            return Err(AppError::new(ErrorKind::ServiceUnavailable)
+                .with_code("MC-MAIL-033")
+                .with_message(format!(
+                    "IMAPサーバーへの下書きのアップロードに失敗しました。詳細: {}",
+                    stderr.trim()
+                ))
+                .with_action("IMAPの接続設定、パスワード、ネットワーク接続を確認してください。")
+                .retryable(!is_login_denied));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        email_address::EmailAddress,
+        mail_objects::{MailBody, Subject},
+    };
+
+    fn sample_imap() -> ImapConfiguration {
+        ImapConfiguration {
+            host: "imap.example.com".to_string(),
+            port: 993,
+            username: "user@example.com".to_string(),
+            sender_address: "user@example.com".to_string(),
+            folder: "Drafts".to_string(),
+            use_tls: true,
+        }
+    }
+
+    #[test]
+    fn test_folder_url() {
+        let adapter = ImapDraftMailClientAdapter::new(sample_imap(), "差出太郎", "secret");
+        assert_eq!(adapter.folder_url(), "imaps://imap.example.com:993/Drafts");
+
+        let mut imap = sample_imap();
+        imap.use_tls = false;
+        imap.port = 143;
+        let adapter = ImapDraftMailClientAdapter::new(imap, "差出太郎", "secret");
+        assert_eq!(adapter.folder_url(), "imap://imap.example.com:143/Drafts");
+    }
+
+    #[test]
+    fn test_render_message_with_headers() {
+        let adapter = ImapDraftMailClientAdapter::new(sample_imap(), "差出太郎", "secret");
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("to@example.com").unwrap()],
+            vec![],
+            Subject::new("件名").unwrap(),
+            MailBody::new("本文"),
+        )
+        .with_header("X-Priority", "1")
+        .unwrap();
+
+        let message = adapter.render_message(&draft).unwrap();
+        let (headers, _) = message.split_once("\r\n\r\n").unwrap();
+
+        assert!(headers.contains("\r\nX-Priority: 1\r\n"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_message_file_is_removed_after_failure() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // アップロードしたファイルのパスと内容を記録し、ログイン拒否（終了コード67）で終了するcurl
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("curl.sh");
+        let uploaded = dir.path().join("uploaded_path");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\ncat > /dev/null\nprev=\nfor arg; do\n  [ \"$prev\" = --upload-file ] && echo \"$arg\" > '{}'\n  prev=$arg\ndone\nexit 67\n",
+                uploaded.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let adapter = ImapDraftMailClientAdapter::new(sample_imap(), "差出太郎", "secret")
+            .with_curl_exe(script.to_string_lossy());
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("to@example.com").unwrap()],
+            vec![],
+            Subject::new("件名").unwrap(),
+            MailBody::new("本文"),
+        );
+
+        let error = adapter.compose_mail(&draft, false).unwrap_err();
+
+        assert_eq!(error.code, Some("MC-MAIL-033"));
+        assert!(!error.is_retryable());
+        let message_path = std::fs::read_to_string(&uploaded).unwrap();
+        assert!(!std::path::Path::new(message_path.trim()).exists());
+    }
+}
@@ -0,0 +1,271 @@
+use crate::domain::interfaces::holiday_calendar::HolidayCalendarPort;
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// 日本の国民の祝日を判定するアウトバウンドアダプター
+///
+/// 祝日法の規定（振替休日・国民の休日を含む）から祝日を算出する。
+/// 内閣府が公開している`syukujitsu.csv`を読み込んだ場合、CSVに含まれる年はCSVの内容を優先する
+/// （2020年・2021年の東京オリンピックに伴う移動のような特例はCSVで補う）
+#[derive(Debug, Clone, Default)]
+pub struct JapaneseHolidayCalendarAdapter {
+    /// CSVから読み込んだ年ごとの祝日
+    published: BTreeMap<i32, BTreeMap<NaiveDate, String>>,
+}
+
+impl JapaneseHolidayCalendarAdapter {
+    /// 祝日法の規定から祝日を算出するアダプターを作成する
+    ///
+    /// ## Returns
+    /// * JapaneseHolidayCalendarAdapterのインスタンス
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 内閣府の祝日CSV（`syukujitsu.csv`）を読み込む
+    ///
+    /// ## Arguments
+    /// * `csv_path` - 祝日CSVのパス（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<JapaneseHolidayCalendarAdapter>`
+    /// * 失敗時 - ファイルを読み込めない、または形式が不正な場合の`Err<AppError>`
+    pub fn load_from_csv(csv_path: &Path) -> AppResult<Self> {
+        let path = workspace_path(csv_path)?;
+        let bytes = fs::read(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-018")
+                .with_message(format!(
+                    "祝日CSVの読み込みに失敗しました。詳細: {}",
+                    path.display()
+                ))
+                .with_action("ファイルパスの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+        // 内閣府の配布ファイルはShift_JISのため、UTF-8に変換したものを受け付ける
+        let content = String::from_utf8(bytes).map_err(|e| {
+            AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-WORK-019")
+                .with_message("祝日CSVの文字コードがUTF-8ではありません。")
+                .with_action("内閣府のsyukujitsu.csvは、UTF-8で保存し直してから指定してください。")
+                .with_source(e)
+        })?;
+
+        Self::from_csv_str(&content)
+    }
+
+    /// CSV形式の文字列からアダプターを作成する
+    ///
+    /// 1列目を日付（`2025/1/1`または`2025-01-01`）、2列目を祝日名として読み込む
+    ///
+    /// ## Arguments
+    /// * `content` - 見出し行付きの祝日CSV
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<JapaneseHolidayCalendarAdapter>`
+    /// * 失敗時 - CSVの形式が不正な場合の`Err<AppError>`
+    pub fn from_csv_str(content: &str) -> AppResult<Self> {
+        let csv_error = |e: csv::Error| {
+            AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-WORK-020")
+                .with_message("祝日CSVの解析に失敗しました。")
+                .with_action("内閣府の「国民の祝日」CSVファイルを指定してください。")
+                .with_source(e)
+        };
+
+        let content = content.trim_start_matches('\u{feff}');
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(content.as_bytes());
+        let mut published: BTreeMap<i32, BTreeMap<NaiveDate, String>> = BTreeMap::new();
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            let value = record.get(0).unwrap_or_default().trim();
+            if value.is_empty() {
+                continue;
+            }
+            let date = NaiveDate::parse_from_str(value, "%Y/%m/%d")
+                .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+                .map_err(|_| {
+                    AppError::new(ErrorKind::UnprocessableEntity)
+                        .with_code("MC-WORK-021")
+                        .with_message(format!("祝日CSVの日付が不正です。詳細: {value}"))
+                        .with_action("日付はYYYY/M/D形式で記載してください。")
+                })?;
+            let name = record.get(1).unwrap_or_default().trim().to_string();
+            published.entry(date.year()).or_default().insert(date, name);
+        }
+
+        Ok(Self { published })
+    }
+
+    /// 指定年の祝日を取得する（CSVに含まれる年はCSVの内容を使用する）
+    ///
+    /// ## Arguments
+    /// * `year` - 対象年
+    ///
+    /// ## Returns
+    /// * 日付と祝日名の一覧（日付順）
+    pub fn holidays_of_year(&self, year: i32) -> BTreeMap<NaiveDate, String> {
+        match self.published.get(&year) {
+            Some(holidays) => holidays.clone(),
+            None => statutory_holidays(year)
+                .into_iter()
+                .map(|(date, name)| (date, name.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl HolidayCalendarPort for JapaneseHolidayCalendarAdapter {
+    fn holiday_name(&self, date: NaiveDate) -> AppResult<Option<String>> {
+        Ok(self.holidays_of_year(date.year()).remove(&date))
+    }
+}
+
+/// 指定月の第n月曜日
+fn nth_monday(year: i32, month: u32, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Mon, n)
+}
+
+/// 春分日・秋分日の日付（1980〜2099年の近似式）
+fn equinox_day(year: i32, base: f64) -> u32 {
+    let elapsed = year - 1980;
+    (base + 0.242194 * f64::from(elapsed) - f64::from(elapsed.div_euclid(4))).floor() as u32
+}
+
+/// 祝日法の規定から指定年の祝日を算出する（2020年以降の規定による）
+fn statutory_holidays(year: i32) -> BTreeMap<NaiveDate, &'static str> {
+    let date = |month: u32, day: u32| NaiveDate::from_ymd_opt(year, month, day);
+    let mut holidays: BTreeMap<NaiveDate, &'static str> = [
+        (date(1, 1), "元日"),
+        (nth_monday(year, 1, 2), "成人の日"),
+        (date(2, 11), "建国記念の日"),
+        (date(2, 23), "天皇誕生日"),
+        (date(3, equinox_day(year, 20.8431)), "春分の日"),
+        (date(4, 29), "昭和の日"),
+        (date(5, 3), "憲法記念日"),
+        (date(5, 4), "みどりの日"),
+        (date(5, 5), "こどもの日"),
+        (nth_monday(year, 7, 3), "海の日"),
+        (date(8, 11), "山の日"),
+        (nth_monday(year, 9, 3), "敬老の日"),
+        (date(9, equinox_day(year, 23.2488)), "秋分の日"),
+        (nth_monday(year, 10, 2), "スポーツの日"),
+        (date(11, 3), "文化の日"),
+        (date(11, 23), "勤労感謝の日"),
+    ]
+    .into_iter()
+    .filter_map(|(date, name)| Some((date?, name)))
+    .collect();
+
+    // 前日と翌日が祝日である日は国民の休日とする
+    let sandwiched: Vec<NaiveDate> = holidays
+        .keys()
+        .filter_map(|date| date.checked_add_days(Days::new(2)))
+        .filter(|after| holidays.contains_key(after))
+        .filter_map(|after| after.pred_opt())
+        .filter(|between| !holidays.contains_key(between) && between.weekday() != Weekday::Sun)
+        .collect();
+    holidays.extend(sandwiched.into_iter().map(|date| (date, "国民の休日")));
+
+    // 日曜日の祝日は、その後の最初の祝日でない日を振替休日とする
+    let sundays: Vec<NaiveDate> = holidays
+        .keys()
+        .copied()
+        .filter(|date| date.weekday() == Weekday::Sun)
+        .collect();
+    for sunday in sundays {
+        let substitute = sunday
+            .iter_days()
+            .skip(1)
+            .find(|date| !holidays.contains_key(date));
+        if let Some(substitute) = substitute.filter(|date| date.year() == year) {
+            holidays.insert(substitute, "振替休日");
+        }
+    }
+
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_statutory_holidays_2025() {
+        let holidays = JapaneseHolidayCalendarAdapter::new().holidays_of_year(2025);
+        let dates: Vec<String> = holidays
+            .keys()
+            .map(|d| d.format("%m-%d").to_string())
+            .collect();
+
+        assert_eq!(
+            dates,
+            [
+                "01-01", "01-13", "02-11", "02-23", "02-24", "03-20", "04-29", "05-03", "05-04",
+                "05-05", "05-06", "07-21", "08-11", "09-15", "09-23", "10-13", "11-03", "11-23",
+                "11-24",
+            ]
+        );
+        assert_eq!(holidays[&date("2025-05-06")], "振替休日");
+    }
+
+    #[test]
+    fn test_citizens_holiday() {
+        let calendar = JapaneseHolidayCalendarAdapter::new();
+
+        // 2026年は敬老の日（9/21）と秋分の日（9/23）に挟まれた9/22が休日となる
+        assert_eq!(
+            calendar
+                .holiday_name(date("2026-09-22"))
+                .unwrap()
+                .as_deref(),
+            Some("国民の休日")
+        );
+        assert!(!calendar.is_holiday(date("2025-09-16")).unwrap());
+    }
+
+    #[test]
+    fn test_working_days() {
+        let calendar = JapaneseHolidayCalendarAdapter::new();
+
+        // 2025年5月: 平日22日のうち5/5と5/6が祝日
+        assert_eq!(
+            calendar
+                .working_days(date("2025-05-01"), date("2025-05-31"))
+                .unwrap(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_csv_overrides_year() {
+        let calendar = JapaneseHolidayCalendarAdapter::from_csv_str(
+            "国民の祝日・休日月日,国民の祝日・休日名称\n2021/7/22,海の日\n2021/7/23,スポーツの日\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            calendar
+                .holiday_name(date("2021-07-22"))
+                .unwrap()
+                .as_deref(),
+            Some("海の日")
+        );
+        assert!(!calendar.is_holiday(date("2021-07-19")).unwrap());
+        // CSVに含まれない年は祝日法の規定から算出する
+        assert!(calendar.is_holiday(date("2025-01-13")).unwrap());
+    }
+}
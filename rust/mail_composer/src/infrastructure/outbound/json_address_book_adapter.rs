@@ -1,27 +1,32 @@
 use crate::domain::{
-    interfaces::address_book::AddressBookPort, value_objects::email_address::EmailAddress,
+    entities::address_book::AddressBook,
+    interfaces::address_book::{AddressBookPort, AddressBookWritePort},
+    value_objects::{address_query::AddressQuery, email_address::EmailAddress},
 };
-use serde::{Deserialize, Serialize};
 use share::{
     error::{
         app_error::{AppError, AppResult},
         kind::ErrorKind,
     },
-    utils::workspace::workspace_root,
+    utils::{
+        env_interpolation::expand_env_vars_in_json,
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path},
+    },
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
 };
-use std::{collections::BTreeMap, fs, path::Path};
 
-/// AddressBookエントリを表現する構造体
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct AddressBookEntry {
-    pub name: String,
-    pub address: String,
-}
+pub use crate::domain::entities::address_book::AddressBookEntry;
 
 /// JSON形式のアドレスブックを処理するアウトバウンドアダプター
 pub struct JsonAddressBookAdapter {
-    map: BTreeMap<String, String>,
-    entries: Vec<AddressBookEntry>,
+    book: AddressBook,
+    /// `${VAR}`を展開する前のエントリ（保存時に使用し、`book`のエントリと同じ順序を保つ）
+    raw_entries: Vec<AddressBookEntry>,
+    path: Option<PathBuf>,
 }
 
 impl JsonAddressBookAdapter {
@@ -34,39 +39,74 @@ impl JsonAddressBookAdapter {
     /// * 成功時 - `Ok<JsonAddressBookAdapter>`
     /// * 失敗時 - `Err<AppError>`
     pub fn load_from_address_book(address_book: &Path) -> AppResult<Self> {
-        let root = workspace_root()?;
-        let path = root.join(address_book);
+        let path = workspace_path(address_book)?;
         let content = fs::read_to_string(&path).map_err(|e| {
             AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-ADDR-014")
                 .with_message("AddressBookファイルの読み込みに失敗しました。")
                 .with_action("ファイルパスの存在とアクセス権限を確認してください。")
                 .with_source(e)
         })?;
 
-        let entries: Vec<AddressBookEntry> = serde_json::from_str(&content).map_err(|e| {
-            AppError::new(ErrorKind::UnavailableForLegalReasons)
-                .with_message("AddressBookの解析に失敗しました。")
-                .with_action("JSONファイルの形式が正しいことを確認してください。期待される形式: [{\"name\": \"...\", \"address\": \"...\"}]")
-                .with_source(e)
-        })?;
+        let adapter = Self::from_json_str(&content)?;
+        Ok(Self {
+            path: Some(path),
+            ..adapter
+        })
+    }
 
-        // 重複チェック
-        let mut names = std::collections::HashSet::new();
-        for entry in &entries {
-            if !names.insert(&entry.name) {
-                return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
-                    .with_message("重複する名前が見つかりました。")
-                    .with_action("AddressBook内の名前は一意である必要があります。"));
-            }
+    /// 指定されたパスからAddressBookを読み込む（ファイルが存在しない場合は空で作成する）
+    ///
+    /// 初期設定など、これから作成するAddressBookを編集する場合に使用する
+    ///
+    /// ## Arguments
+    /// * `address_book` - AddressBookのパスを表現する`Path`
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<JsonAddressBookAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn load_or_empty(address_book: &Path) -> AppResult<Self> {
+        let path = workspace_path(address_book)?;
+        if path.exists() {
+            return Self::load_from_address_book(&path);
         }
 
-        // Vec<AddressBookEntry>をBTreeMap<String, String>に変換
-        let map = entries
-            .iter()
-            .map(|entry| (entry.name.clone(), entry.address.clone()))
-            .collect();
+        Ok(Self {
+            book: AddressBook::from_entries(Vec::new())?,
+            raw_entries: Vec::new(),
+            path: Some(path),
+        })
+    }
 
-        Ok(Self { map, entries })
+    /// JSON形式の文字列からアダプターを作成する
+    ///
+    /// 値に含まれる`${VAR}`は環境変数の値に展開する（保存時は展開前の値を書き込む）
+    ///
+    /// ## Arguments
+    /// * `content` - JSON形式のAddressBook
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<JsonAddressBookAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn from_json_str(content: &str) -> AppResult<Self> {
+        let parse_error = |e: serde_json::Error| {
+            AppError::new(ErrorKind::UnavailableForLegalReasons)
+                .with_code("MC-ADDR-015")
+                .with_message("AddressBookの解析に失敗しました。")
+                .with_action("JSONファイルの形式が正しいことを確認してください。期待される形式: [{\"name\": \"...\", \"address\": \"...\"}, {\"name\": \"...\", \"members\": [\"...\"]}]")
+                .with_source(e)
+        };
+        let mut value: serde_json::Value = serde_json::from_str(content).map_err(parse_error)?;
+        let raw_entries: Vec<AddressBookEntry> =
+            serde_json::from_value(value.clone()).map_err(parse_error)?;
+        expand_env_vars_in_json(&mut value)?;
+        let entries: Vec<AddressBookEntry> = serde_json::from_value(value).map_err(parse_error)?;
+
+        Ok(Self {
+            book: AddressBook::from_entries(entries)?,
+            raw_entries,
+            path: None,
+        })
     }
 
     /// 全てのエントリを取得する
@@ -74,7 +114,7 @@ impl JsonAddressBookAdapter {
     /// ## Returns
     /// * 全てのAddressBookエントリのスライス
     pub fn entries(&self) -> &[AddressBookEntry] {
-        &self.entries
+        self.book.entries()
     }
 
     /// 名前の一覧を取得する
@@ -82,21 +122,32 @@ impl JsonAddressBookAdapter {
     /// ## Returns
     /// * 登録されている名前の一覧
     pub fn names(&self) -> Vec<&str> {
-        self.map.keys().map(|s| s.as_str()).collect()
+        self.book.names()
     }
 
-    /// AddressBookの内容を表示する（デバッグ用）
-    ///
-    /// ## Returns
-    /// * 成功時 - `Ok(())`
-    /// * 失敗時 - `Err<AppError>`
-    pub fn display_contents(&self) -> AppResult<()> {
-        println!("=== AddressBook Contents ===");
-        for entry in &self.entries {
-            println!("Name: {}, Address: {}", entry.name, entry.address);
+    /// 名前（別名は含まない）に対応するエントリの位置を取得する
+    fn position(&self, key_name: &str) -> Option<usize> {
+        self.book
+            .entries()
+            .iter()
+            .position(|entry| entry.name == key_name)
+    }
+
+    /// AddressBookの内容をデバッグレベルのログに出力する
+    pub fn log_contents(&self) {
+        for entry in self.entries() {
+            match &entry.address {
+                Some(address) => {
+                    tracing::debug!(name = %entry.name, %address, "アドレスブックのエントリ")
+                }
+                None => tracing::debug!(
+                    name = %entry.name,
+                    members = %entry.members.join(", "),
+                    "アドレスブックのグループ"
+                ),
+            }
         }
-        println!("Total entries: {}", self.entries.len());
-        Ok(())
+        tracing::debug!(total = self.entries().len(), "アドレスブックのエントリ数");
     }
 }
 
@@ -110,13 +161,76 @@ impl AddressBookPort for JsonAddressBookAdapter {
     /// * 成功時 - `Ok<EmailAddress>`
     /// * 失敗時 - `Err<AppError>`
     fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
-        let address = self.map.get(key_name).ok_or_else(|| {
-            AppError::new(ErrorKind::NotFound)
-                .with_message("指定された名前に対応するメールアドレスが見つかりません。")
-                .with_action("AddressBookの内容と指定した名前を確認してください。")
+        self.book.resolve(key_name)
+    }
+
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        self.book.expand(key_name)
+    }
+
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.entries().to_vec())
+    }
+
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.search(query)?.into_iter().cloned().collect())
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        Ok(self.book.reverse_resolve(address).map(str::to_string))
+    }
+}
+
+impl AddressBookWritePort for JsonAddressBookAdapter {
+    fn add_entry(&mut self, entry: AddressBookEntry) -> AppResult<()> {
+        self.book.add_entry(entry.clone())?;
+        self.raw_entries.push(entry);
+        Ok(())
+    }
+
+    fn update_entry(&mut self, key_name: &str, entry: AddressBookEntry) -> AppResult<()> {
+        let index = self.position(key_name);
+        self.book.update_entry(key_name, entry.clone())?;
+        if let Some(index) = index {
+            self.raw_entries[index] = entry;
+        }
+        Ok(())
+    }
+
+    fn remove_entry(&mut self, key_name: &str) -> AppResult<AddressBookEntry> {
+        let index = self.position(key_name);
+        let removed = self.book.remove_entry(key_name)?;
+        if let Some(index) = index {
+            self.raw_entries.remove(index);
+        }
+        Ok(removed)
+    }
+
+    /// AddressBookを読み込み元のファイルに整形済みJSONとして書き込む
+    ///
+    /// エントリは読み込み時および追加した順序のまま保存される
+    /// （`${VAR}`で参照していた値は、展開前の`${VAR}`のまま保存される）
+    fn save(&self) -> AppResult<()> {
+        let path = self.path.as_ref().ok_or_else(|| {
+            AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-ADDR-016")
+                .with_message("保存先のAddressBookファイルが指定されていません。")
+                .with_action("ファイルから読み込んだAddressBookのみ保存できます。")
         })?;
-        // 文字列のクローンを避けて、参照から直接EmailAddressを作成
-        EmailAddress::parse(address)
+
+        if let Some(parent) = path.parent() {
+            ensure_directory_exists(parent)?;
+        }
+
+        let mut json = serde_json::to_string_pretty(&self.raw_entries)?;
+        json.push('\n');
+        write_atomic(path, json).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-ADDR-017")
+                .with_message("AddressBookファイルの書き込みに失敗しました。")
+                .with_action("ファイルパスの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })
     }
 }
 
@@ -133,7 +247,7 @@ mod tests {
         match result {
             Ok(address_book) => {
                 println!("✅ AddressBook loaded successfully!");
-                let _ = address_book.display_contents();
+                address_book.log_contents();
 
                 // テスト: "○○さん"を検索
                 match address_book.resolve("○○さん") {
@@ -152,4 +266,281 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_resolve_group() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "○○さん", "address": "one@example.com" },
+                { "name": "△△さん", "address": "two@example.com" },
+                { "name": "開発チーム", "members": ["○○さん", "△△さん"] }
+            ]"#,
+        )
+        .unwrap();
+
+        let addresses = adapter.resolve_many(&["開発チーム", "○○さん"]).unwrap();
+        let addresses: Vec<&str> = addresses.iter().map(|a| a.as_str()).collect();
+        assert_eq!(addresses, vec!["one@example.com", "two@example.com"]);
+        assert!(adapter.resolve("開発チーム").is_err());
+    }
+
+    #[test]
+    fn test_group_with_unknown_member() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[{ "name": "開発チーム", "members": ["存在しない人"] }]"#,
+        )
+        .unwrap();
+
+        assert!(adapter.expand("開発チーム").is_err());
+    }
+
+    #[test]
+    fn test_resolve_suggests_similar_names() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "タナカさん", "address": "tanaka@example.com" },
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "佐藤さん", "address": "sato@example.com" }
+            ]"#,
+        )
+        .unwrap();
+
+        let error = adapter.resolve("たなか").unwrap_err();
+        assert!(error.action.unwrap().contains("タナカさん"));
+
+        let error = adapter.resolve("山本さん").unwrap_err();
+        let action = error.action.unwrap();
+        assert!(action.contains("山田さん"));
+        assert!(!action.contains("佐藤さん"));
+    }
+
+    #[test]
+    fn test_resolve_alias() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[{ "name": "田中さん", "address": "tanaka@example.com", "aliases": ["tanaka", "TanakaT"] }]"#,
+        )
+        .unwrap();
+
+        for name in ["田中さん", "tanaka", "TanakaT"] {
+            assert_eq!(
+                adapter.resolve(name).unwrap().as_str(),
+                "tanaka@example.com"
+            );
+        }
+        assert_eq!(adapter.names(), vec!["田中さん"]);
+    }
+
+    #[test]
+    fn test_alias_collision() {
+        let result = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "田中さん", "address": "tanaka@example.com", "aliases": ["tanaka"] },
+                { "name": "tanaka", "address": "other@example.com" }
+            ]"#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_crud_and_save() {
+        let path = std::env::temp_dir().join(format!(
+            "mail_composer_address_book_{}.json",
+            std::process::id()
+        ));
+        fs::write(
+            &path,
+            r#"[{ "name": "山田さん", "address": "yamada@example.com" }]"#,
+        )
+        .unwrap();
+
+        let mut adapter = JsonAddressBookAdapter::load_from_address_book(&path).unwrap();
+        adapter
+            .add_entry(AddressBookEntry::person("佐藤さん", "sato@example.com"))
+            .unwrap();
+        adapter
+            .add_entry(AddressBookEntry::person("鈴木さん", "suzuki@example.com"))
+            .unwrap();
+        adapter
+            .update_entry(
+                "山田さん",
+                AddressBookEntry::person("山田さん", "t-yamada@example.com"),
+            )
+            .unwrap();
+        adapter.remove_entry("佐藤さん").unwrap();
+        assert!(
+            adapter
+                .add_entry(AddressBookEntry::person("山田さん", "dup@example.com"))
+                .is_err()
+        );
+        adapter.save().unwrap();
+
+        let reloaded = JsonAddressBookAdapter::load_from_address_book(&path).unwrap();
+        let names: Vec<&str> = reloaded.entries().iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["山田さん", "鈴木さん"]);
+        assert_eq!(
+            reloaded.resolve("山田さん").unwrap().as_str(),
+            "t-yamada@example.com"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_save_keeps_env_var_references() {
+        let path = std::env::temp_dir().join(format!(
+            "mail_composer_address_book_raw_{}.json",
+            std::process::id()
+        ));
+        fs::write(
+            &path,
+            r#"[
+                { "name": "上長", "address": "${MAIL_COMPOSER_UNSET_BOSS:-boss}@example.com" },
+                { "name": "山田さん", "address": "yamada@example.com" }
+            ]"#,
+        )
+        .unwrap();
+
+        let mut adapter = JsonAddressBookAdapter::load_from_address_book(&path).unwrap();
+        assert_eq!(
+            adapter.resolve("上長").unwrap().as_str(),
+            "boss@example.com"
+        );
+        adapter.remove_entry("山田さん").unwrap();
+        adapter
+            .add_entry(AddressBookEntry::person("佐藤さん", "sato@example.com"))
+            .unwrap();
+        adapter.save().unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(saved.contains("${MAIL_COMPOSER_UNSET_BOSS:-boss}@example.com"));
+        assert!(!saved.contains("yamada@example.com"));
+        assert!(saved.contains("sato@example.com"));
+    }
+
+    #[test]
+    fn test_reverse_resolve() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "Yamada@example.com" },
+                { "name": "開発チーム", "members": ["山田さん"] }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            adapter.reverse_resolve("yamada@example.com").unwrap(),
+            Some("山田さん".to_string())
+        );
+        assert_eq!(adapter.reverse_resolve("sato@example.com").unwrap(), None);
+    }
+
+    #[test]
+    fn test_expand_nested_groups() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "佐藤さん", "address": "sato@example.com" },
+                { "name": "鈴木さん", "address": "suzuki@example.com" },
+                { "name": "開発チーム", "members": ["山田さん", "佐藤さん"] },
+                { "name": "運用チーム", "members": ["佐藤さん", "鈴木さん"] },
+                { "name": "技術部", "members": ["開発チーム", "運用チーム"] }
+            ]"#,
+        )
+        .unwrap();
+
+        let addresses = adapter.expand("技術部").unwrap();
+        let addresses: Vec<&str> = addresses.iter().map(|a| a.as_str()).collect();
+        assert_eq!(
+            addresses,
+            vec![
+                "yamada@example.com",
+                "sato@example.com",
+                "suzuki@example.com"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_cyclic_groups() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "A", "members": ["山田さん", "B"] },
+                { "name": "B", "members": ["A"] }
+            ]"#,
+        )
+        .unwrap();
+
+        let error = adapter.expand("A").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Conflict);
+        assert!(error.message.contains("A → B → A"));
+    }
+
+    #[test]
+    fn test_validity_period() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com" },
+                { "name": "佐藤さん", "address": "sato@example.com", "valid_until": "2000-03-31" },
+                { "name": "鈴木さん", "address": "suzuki@example.com", "valid_from": "2999-04-01" },
+                { "name": "承認者", "members": ["山田さん", "佐藤さん", "鈴木さん"] }
+            ]"#,
+        )
+        .unwrap();
+
+        assert!(adapter.resolve("山田さん").is_ok());
+        let error = adapter.resolve("佐藤さん").unwrap_err();
+        assert!(error.message.contains("2000-03-31"));
+        assert!(adapter.resolve("鈴木さん").is_err());
+
+        let addresses = adapter.expand("承認者").unwrap();
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses[0].as_str(), "yamada@example.com");
+    }
+
+    #[test]
+    fn test_search() {
+        let adapter = JsonAddressBookAdapter::from_json_str(
+            r#"[
+                { "name": "山田さん", "address": "yamada@example.com", "aliases": ["Yamada"] },
+                { "name": "山本さん", "address": "yamamoto@partner.example.jp" },
+                { "name": "佐藤さん", "address": "sato@example.com" },
+                { "name": "開発チーム", "members": ["山田さん"] },
+                { "name": "技術部", "members": ["開発チーム", "山本さん"] }
+            ]"#,
+        )
+        .unwrap();
+        let names = |query: &AddressQuery| -> Vec<String> {
+            adapter
+                .search(query)
+                .unwrap()
+                .into_iter()
+                .map(|entry| entry.name)
+                .collect()
+        };
+
+        assert_eq!(
+            names(&AddressQuery::new().with_name_contains("山")),
+            vec!["山田さん", "山本さん"]
+        );
+        assert_eq!(
+            names(&AddressQuery::new().with_name_contains("yamada")),
+            vec!["山田さん"]
+        );
+        assert_eq!(
+            names(&AddressQuery::new().with_domain("example.com")),
+            vec!["山田さん", "佐藤さん"]
+        );
+        assert_eq!(
+            names(&AddressQuery::new().with_member_of("技術部")),
+            vec!["山田さん", "山本さん", "開発チーム"]
+        );
+        assert!(
+            adapter
+                .search(&AddressQuery::new().with_member_of("営業部"))
+                .is_err()
+        );
+    }
 }
@@ -1,14 +1,28 @@
+use crate::APP_NAME;
 use crate::domain::{
-    interfaces::configuration::ConfigurationPort, value_objects::app_configuration::AppConfiguration,
+    interfaces::configuration::{ConfigurationPort, ConfigurationWritePort},
+    value_objects::app_configuration::AppConfiguration,
 };
 use share::{
+    config::json::{deserialize_value, parse_json_str},
     error::{
         app_error::{AppError, AppResult},
         kind::ErrorKind,
     },
-    utils::workspace::workspace_root,
+    utils::{
+        dirs::{AppDirectory, resolve_app_path},
+        env_interpolation::expand_env_vars_in_json,
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path, workspace_root},
+    },
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
 };
-use std::fs;
+
+/// 設定ファイルのパスを指定する環境変数名
+pub const CONFIG_PATH_ENV: &str = "MAIL_COMPOSER_CONFIG";
 
 /// JSON形式の設定ファイルを処理するアウトバウンドアダプター
 pub struct JsonConfigurationAdapter {
@@ -31,10 +45,65 @@ impl JsonConfigurationAdapter {
 
     /// デフォルト設定でアダプターを作成する
     ///
+    /// プラットフォーム標準の設定ディレクトリ（例: `~/.config/mail_composer/app.json`）に
+    /// 設定ファイルがあればそれを使用し、なければワークスペース内の従来の配置を使用する
+    ///
     /// ## Returns
     /// * デフォルト設定のJsonConfigurationAdapterのインスタンス
     pub fn with_default_path() -> Self {
-        Self::new("rust/mail_composer/config/app.json")
+        let path = resolve_app_path(
+            AppDirectory::Config,
+            APP_NAME,
+            "app.json",
+            "rust/mail_composer/config/app.json",
+        );
+        Self::new(path.to_string_lossy())
+    }
+
+    /// コマンドライン引数、環境変数、既定の配置の順に設定ファイルを決定してアダプターを作成する
+    ///
+    /// `--config <path>`または環境変数`MAIL_COMPOSER_CONFIG`で指定された相対パスは、
+    /// ワークスペースではなくカレントディレクトリを基準に解決する
+    ///
+    /// ## Arguments
+    /// * `cli_path` - `--config`で指定されたパス（未指定の場合は`None`）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<JsonConfigurationAdapter>`
+    /// * 失敗時 - カレントディレクトリを取得できない場合の`Err<AppError>`
+    pub fn locate(cli_path: Option<&Path>) -> AppResult<Self> {
+        Self::locate_with(cli_path, |name| std::env::var(name).ok())
+    }
+
+    fn locate_with(cli_path: Option<&Path>, lookup: fn(&str) -> Option<String>) -> AppResult<Self> {
+        let specified = cli_path.map(Path::to_path_buf).or_else(|| {
+            lookup(CONFIG_PATH_ENV)
+                .filter(|value| !value.trim().is_empty())
+                .map(PathBuf::from)
+        });
+        let Some(path) = specified else {
+            return Ok(Self::with_default_path());
+        };
+
+        let absolute = std::path::absolute(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CONF-022")
+                .with_message(format!(
+                    "設定ファイルのパスの解決に失敗しました。詳細: {}",
+                    path.display()
+                ))
+                .with_action("カレントディレクトリが存在し、アクセスできることを確認してください。")
+                .with_source(e)
+        })?;
+        Ok(Self::new(absolute.to_string_lossy()))
+    }
+
+    /// 設定ファイルのパスを取得する
+    ///
+    /// ## Returns
+    /// * 設定ファイルのパス（相対パスの場合はワークスペースルート基準）
+    pub fn config_file_path(&self) -> &Path {
+        Path::new(&self.config_file_path)
     }
 
     /// 設定ファイルの絶対パスを取得する
@@ -42,9 +111,8 @@ impl JsonConfigurationAdapter {
     /// ## Returns
     /// * 成功時 - 設定ファイルの絶対パス
     /// * 失敗時 - ワークスペースルート取得エラー
-    fn get_absolute_config_path(&self) -> AppResult<std::path::PathBuf> {
-        let root = workspace_root()?;
-        Ok(root.join(&self.config_file_path))
+    pub fn get_absolute_config_path(&self) -> AppResult<PathBuf> {
+        workspace_path(&self.config_file_path)
     }
 }
 
@@ -55,27 +123,51 @@ impl ConfigurationPort for JsonConfigurationAdapter {
     /// * 成功時 - [`Ok<AppConfiguration>`]
     /// * 失敗時 - [`Err<AppError>`]
     fn load_configuration(&self) -> AppResult<AppConfiguration> {
+        let config = self.load_unvalidated_configuration()?;
+
+        // 設定値を検証
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// 検証を行わずにアプリケーション設定を読み込む
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok<AppConfiguration>`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn load_unvalidated_configuration(&self) -> AppResult<AppConfiguration> {
         let config_path = self.get_absolute_config_path()?;
 
-        let content = fs::read_to_string(&config_path).map_err(|e| {
-            AppError::new(ErrorKind::InternalServerError)
-                .with_message("設定ファイルの読み込みに失敗しました。")
-                .with_action("config.jsonファイルの存在とアクセス権限を確認してください。")
-                .with_source(e)
-        })?;
+        let content = Self::read_json(&config_path)?;
 
-        let mut config: AppConfiguration = serde_json::from_str(&content).map_err(|e| {
-            AppError::new(ErrorKind::UnavailableForLegalReasons)
-                .with_message("設定ファイルの解析に失敗しました。")
-                .with_action("config.jsonファイルの形式が正しいことを確認してください。")
-                .with_source(e)
-        })?;
+        // 解析エラーは設定ファイルの行・列を示す
+        let origin = config_path.display().to_string();
+        let mut value: serde_json::Value = parse_json_str(&content, &origin)?;
+
+        // `${VAR}`形式の環境変数を展開
+        expand_env_vars_in_json(&mut value)?;
+
+        let mut config: AppConfiguration =
+            deserialize_value(value, &[(origin.as_str(), content.as_str())])?;
 
         // パスの正規化（Windows/Unix互換）
         config.thunderbird_exe = config.thunderbird_exe.replace('\\', "/");
 
-        // 設定値を検証
-        config.validate()?;
+        // ソースツリー外で実行している場合、相対パスは設定ファイルのディレクトリを基準にする
+        if workspace_root().is_err()
+            && let Some(base_dir) = config_path.parent()
+        {
+            for dir in [
+                &mut config.log_dir,
+                &mut config.input_dir,
+                &mut config.output_dir,
+            ] {
+                if Path::new(dir.as_str()).is_relative() {
+                    *dir = base_dir.join(dir.as_str()).to_string_lossy().into_owned();
+                }
+            }
+        }
 
         Ok(config)
     }
@@ -94,6 +186,83 @@ impl ConfigurationPort for JsonConfigurationAdapter {
     }
 }
 
+impl ConfigurationWritePort for JsonConfigurationAdapter {
+    /// アプリケーション設定をJSON形式で保存する
+    ///
+    /// 保存先のディレクトリが存在しない場合は作成する
+    ///
+    /// ## Arguments
+    /// * `config` - 保存するアプリケーション設定
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn save_configuration(&self, config: &AppConfiguration) -> AppResult<()> {
+        let config_path = self.get_absolute_config_path()?;
+        if let Some(parent) = config_path.parent() {
+            ensure_directory_exists(parent)?;
+        }
+
+        Self::write_json(&config_path, config)
+    }
+
+    /// 設定ファイルの1項目のみを書き換える
+    ///
+    /// 環境変数の展開やパスの正規化を行う前の記述を読み込み、指定した項目以外はそのまま保存する
+    ///
+    /// ## Arguments
+    /// * `field` - 設定項目名
+    /// * `value` - 設定する値
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn save_field(&self, field: &str, value: &str) -> AppResult<()> {
+        let config_path = self.get_absolute_config_path()?;
+        let content = Self::read_json(&config_path)?;
+
+        let origin = config_path.display().to_string();
+        let mut value_json: serde_json::Value = parse_json_str(&content, &origin)?;
+        let Some(fields) = value_json.as_object_mut() else {
+            return Err(AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-CONF-036")
+                .with_message(format!(
+                    "設定ファイルがJSONオブジェクトではありません。詳細: {origin}"
+                ))
+                .with_action("config.jsonの内容を確認してください。"));
+        };
+        fields.insert(field.to_string(), value.into());
+
+        Self::write_json(&config_path, &value_json)
+    }
+}
+
+impl JsonConfigurationAdapter {
+    /// 設定ファイルの内容を読み込む
+    fn read_json(config_path: &Path) -> AppResult<String> {
+        fs::read_to_string(config_path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CONF-023")
+                .with_message("設定ファイルの読み込みに失敗しました。")
+                .with_action("config.jsonファイルの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })
+    }
+
+    /// 値を整形したJSONとして設定ファイルに書き込む
+    fn write_json(config_path: &Path, value: &impl serde::Serialize) -> AppResult<()> {
+        let mut json = serde_json::to_string_pretty(value)?;
+        json.push('\n');
+        write_atomic(config_path, json).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CONF-024")
+                .with_message("設定ファイルの書き込みに失敗しました。")
+                .with_action("保存先ディレクトリのアクセス権限を確認してください。")
+                .with_source(e)
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +296,135 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_save_configuration_round_trip() {
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_save_config_{}", std::process::id()));
+        let path = dir.join("nested").join("app.json");
+        let adapter = JsonConfigurationAdapter::new(path.to_string_lossy());
+        let config = AppConfiguration {
+            from: "山田".to_string(),
+            department: "開発部".to_string(),
+            thunderbird_exe: "/usr/bin/thunderbird".to_string(),
+            ..AppConfiguration::default()
+        };
+
+        adapter.save_configuration(&config).unwrap();
+        let loaded = adapter.load_configuration().unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(loaded.from, "山田");
+        assert_eq!(loaded.department, "開発部");
+        assert_eq!(loaded.address_book_file, config.address_book_file);
+    }
+
+    #[test]
+    fn test_save_field_keeps_other_fields_raw() {
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_save_field_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.json");
+        fs::write(
+            &path,
+            r#"{ "from": "${MAIL_COMPOSER_UNSET_FROM:-山田}", "thunderbird_exe": "/old/thunderbird" }"#,
+        )
+        .unwrap();
+        let adapter = JsonConfigurationAdapter::new(path.to_string_lossy());
+
+        adapter
+            .save_field("thunderbird_exe", "/usr/bin/thunderbird")
+            .unwrap();
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            saved,
+            serde_json::json!({
+                "from": "${MAIL_COMPOSER_UNSET_FROM:-山田}",
+                "thunderbird_exe": "/usr/bin/thunderbird"
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_configuration_reports_all_missing_fields() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_invalid_config_{}",
+            std::process::id()
+        ));
+        let path = dir.join("app.json");
+        let adapter = JsonConfigurationAdapter::new(path.to_string_lossy());
+        let config = AppConfiguration {
+            thunderbird_exe: "/usr/bin/thunderbird".to_string(),
+            ..AppConfiguration::default()
+        };
+
+        adapter.save_configuration(&config).unwrap();
+        let result = adapter.load_configuration();
+        let _ = fs::remove_dir_all(&dir);
+
+        let error = result.unwrap_err();
+        assert!(error.message.starts_with("2件の問題があります。"));
+        assert!(error.message.contains("差出人名"));
+        assert!(error.message.contains("差出部署"));
+        assert!(error.action.unwrap().contains("departmentフィールド"));
+    }
+
+    #[test]
+    fn test_load_configuration_reports_location() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_malformed_config_{}",
+            std::process::id()
+        ));
+        let path = dir.join("app.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            &path,
+            "{\n  \"from\": \"山田\",\n  \"thunderbird_timeout_secs\": \"30\"\n}",
+        )
+        .unwrap();
+
+        let result =
+            JsonConfigurationAdapter::new(path.to_string_lossy()).load_unvalidated_configuration();
+        let _ = fs::remove_dir_all(&dir);
+
+        let error = result.unwrap_err();
+        assert!(error.message.contains("app.jsonの3行"));
+        assert!(
+            error
+                .message
+                .contains("\"thunderbird_timeout_secs\": \"30\"")
+        );
+    }
+
+    #[test]
+    fn test_locate_resolves_against_current_dir() {
+        let cwd = std::env::current_dir().unwrap();
+
+        let from_flag =
+            JsonConfigurationAdapter::locate_with(Some(Path::new("custom/app.json")), |_| {
+                Some("/ignored/app.json".to_string())
+            })
+            .unwrap();
+        assert_eq!(
+            from_flag.config_file_path(),
+            cwd.join("custom").join("app.json")
+        );
+
+        let from_env = JsonConfigurationAdapter::locate_with(None, |name| {
+            (name == CONFIG_PATH_ENV).then(|| "env.json".to_string())
+        })
+        .unwrap();
+        assert_eq!(from_env.config_file_path(), cwd.join("env.json"));
+
+        let default = JsonConfigurationAdapter::locate_with(None, |_| None).unwrap();
+        assert_eq!(
+            default.config_file_path(),
+            JsonConfigurationAdapter::with_default_path().config_file_path()
+        );
+    }
+
     #[test]
     fn test_configuration_exists() {
         let adapter = JsonConfigurationAdapter::with_default_path();
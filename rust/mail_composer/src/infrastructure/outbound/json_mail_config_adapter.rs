@@ -1,61 +1,428 @@
-use crate::domain::interfaces::mail_config::MailConfigPort;
-use crate::domain::value_objects::mail_config::MailConfig;
+use crate::APP_NAME;
+use crate::domain::entities::mail_draft::validate_header;
+use crate::domain::interfaces::mail_config::{MailConfigPort, MailConfigWritePort};
+use crate::domain::value_objects::mail_config::{MailConfig, MailTypeConfig};
 use share::{
     error::{
         app_error::{AppError, AppResult},
         kind::ErrorKind,
     },
-    utils::workspace::workspace_root,
+    utils::{
+        dirs::{AppDirectory, resolve_app_path},
+        env_interpolation::expand_env_vars_in_json,
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path},
+    },
 };
-use std::collections::HashMap;
-use std::fs;
+use std::collections::{BTreeMap, HashMap};
+use std::{fs, path::Path};
 
 pub struct JsonMailConfigAdapter {
     config_file_path: String,
 }
 
+impl Default for JsonMailConfigAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl JsonMailConfigAdapter {
     pub fn new() -> Self {
         Self {
-            config_file_path: "rust/mail_composer/config/mail_templates.json".to_string(),
+            config_file_path: resolve_app_path(
+                AppDirectory::Config,
+                APP_NAME,
+                "mail_templates.json",
+                "rust/mail_composer/config/mail_templates.json",
+            )
+            .to_string_lossy()
+            .into_owned(),
         }
     }
+
+    /// 指定されたパスのテンプレートファイルを扱うアダプターを作成する
+    ///
+    /// ## Arguments
+    /// * `config_file_path` - テンプレートファイルのパス（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * JsonMailConfigAdapterのインスタンス
+    pub fn from_path(config_file_path: impl Into<String>) -> Self {
+        Self {
+            config_file_path: config_file_path.into(),
+        }
+    }
+
+    /// テンプレートファイルのパスを取得する
+    ///
+    /// ## Returns
+    /// * テンプレートファイルのパス（相対パスはワークスペースルート基準）
+    pub fn config_file_path(&self) -> &Path {
+        Path::new(&self.config_file_path)
+    }
 }
 
 impl MailConfigPort for JsonMailConfigAdapter {
     fn load_mail_config(&self) -> AppResult<MailConfig> {
-        let workspace_root = workspace_root().map_err(|e| {
+        let path = workspace_path(&self.config_file_path).map_err(|e| {
             e.with_message("ワークスペースのルートディレクトリの取得に失敗しました。")
         })?;
-        let path = workspace_root.join(&self.config_file_path);
 
         let content = fs::read_to_string(&path).map_err(|e| {
             AppError::new(ErrorKind::NotFound)
+                .with_code("MC-MAIL-034")
                 .with_message("mail_config.jsonファイルの読み込みに失敗しました。")
                 .with_action("ファイルの存在とアクセス権限を確認してください。")
                 .with_source(e)
         })?;
 
-        let raw_config: HashMap<String, serde_json::Value> = serde_json::from_str(&content)
+        let raw_config: BTreeMap<String, serde_json::Value> = serde_json::from_str(&content)
             .map_err(|e| {
                 AppError::new(ErrorKind::UnprocessableEntity)
+                    .with_code("MC-MAIL-035")
                     .with_message("mail_config.jsonファイルの解析に失敗しました。")
                     .with_action("ファイルの形式が正しいことを確認してください。")
                     .with_source(e)
             })?;
 
+        // `file:`で指定された本文テンプレートはテンプレートファイルと同じディレクトリを基準に読み込む
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+
+        // 最初の問題で中断せず、全てのメール種別の問題をまとめて報告する
         let mut mail_types = HashMap::new();
-        for (key, value) in raw_config {
-            let mail_type_config = serde_json::from_value(value).map_err(|e| {
-                let message = format!("mail_configのmail type '{}'の解析に失敗しました。", key);
+        let mut variables = BTreeMap::new();
+        let mut errors = Vec::new();
+        for (key, mut value) in raw_config {
+            // `${VAR}`形式の環境変数を展開
+            if let Err(e) = expand_env_vars_in_json(&mut value) {
+                errors.push(e);
+                continue;
+            }
+            // 利用者定義の値はメール種別ではなく、名前と値の組として読み込む
+            if key == MailConfig::VARIABLES_KEY {
+                match serde_json::from_value(value) {
+                    Ok(parsed) => variables = parsed,
+                    Err(e) => errors.push(
+                        AppError::new(ErrorKind::UnprocessableEntity)
+                            .with_code("MC-MAIL-036")
+                            .with_message("mail_configのvariablesの解析に失敗しました。")
+                            .with_action(
+                                "variablesには名前と値の組をJSONオブジェクトで記述してください。",
+                            )
+                            .with_source(e),
+                    ),
+                }
+                continue;
+            }
+            match serde_json::from_value::<MailTypeConfig>(value) {
+                Ok(mut mail_type_config) => {
+                    if let Err(e) = load_body_template_file(&mut mail_type_config, base_dir, &key) {
+                        errors.push(e);
+                        continue;
+                    }
+                    errors.extend(mail_type_config.validation_errors(&key));
+                    errors.extend(header_errors(&mail_type_config, &key));
+                    mail_types.insert(key, mail_type_config);
+                }
+                Err(e) => {
+                    let message = format!("mail_configのmail type '{}'の解析に失敗しました。", key);
+                    errors.push(
+                        AppError::new(ErrorKind::UnprocessableEntity)
+                            .with_code("MC-MAIL-037")
+                            .with_message(message)
+                            .with_action("設定ファイルの形式を確認してください。")
+                            .with_source(e),
+                    );
+                }
+            }
+        }
+
+        if let Some(error) = AppError::aggregate(errors) {
+            return Err(error);
+        }
+
+        Ok(MailConfig {
+            mail_types,
+            variables,
+        })
+    }
+}
+
+/// `body_template`が`file:<パス>`の形式の場合、外部ファイルの内容を本文テンプレートとして読み込む
+///
+/// ## Arguments
+/// * `mail_type_config` - 読み込んだ内容で`body_template`を置き換えるテンプレート
+/// * `base_dir` - 相対パスの基準ディレクトリ
+/// * `mail_type` - エラーメッセージに表示するメール種別
+///
+/// ## Returns
+/// * 成功時 - `Ok(())`
+/// * 失敗時 - ファイルの読み込みに失敗した場合の`Err<AppError>`
+fn load_body_template_file(
+    mail_type_config: &mut MailTypeConfig,
+    base_dir: &Path,
+    mail_type: &str,
+) -> AppResult<()> {
+    let Some(file) = mail_type_config.body_template_file() else {
+        return Ok(());
+    };
+    let path = base_dir.join(file);
+    let content = fs::read_to_string(&path).map_err(|e| {
+        AppError::new(ErrorKind::NotFound)
+            .with_code("MC-MAIL-038")
+            .with_message(format!(
+                "mail type '{mail_type}'の本文テンプレートファイルの読み込みに失敗しました。詳細: {}",
+                path.display()
+            ))
+            .with_action(
+                "ファイルの存在とアクセス権限を確認してください（相対パスはmail_templates.jsonのディレクトリ基準）。",
+            )
+            .with_source(e)
+    })?;
+
+    // JSONの文字列と同じ扱いになるように改行コードを揃え、末尾の改行を取り除く
+    mail_type_config.body_template = content.replace("\r\n", "\n").trim_end().to_string();
+    Ok(())
+}
+
+/// テンプレートの追加ヘッダーの問題を全て取得する
+///
+/// ヘッダーの名前と値を検証し、大文字・小文字のみが異なる名前の重複も問題とする
+///
+/// ## Arguments
+/// * `mail_type_config` - 検証するテンプレート
+/// * `mail_type` - エラーメッセージに表示するメール種別
+///
+/// ## Returns
+/// * 検証エラーの一覧（問題がない場合は空）
+fn header_errors(mail_type_config: &MailTypeConfig, mail_type: &str) -> Vec<AppError> {
+    let mut errors = Vec::new();
+    let mut seen: Vec<&str> = Vec::new();
+    for (name, value) in &mail_type_config.headers {
+        if let Err(e) = validate_header(name, value) {
+            let message = format!("mail type '{mail_type}'の{}", e.message);
+            errors.push(e.with_message(message));
+        }
+        if let Some(previous) = seen.iter().find(|seen| seen.eq_ignore_ascii_case(name)) {
+            errors.push(
                 AppError::new(ErrorKind::UnprocessableEntity)
-                    .with_message(message)
-                    .with_action("設定ファイルの形式を確認してください。")
-                    .with_source(e)
-            })?;
-            mail_types.insert(key, mail_type_config);
+                    .with_code("MC-MAIL-063")
+                    .with_message(format!(
+                        "mail type '{mail_type}'のヘッダー'{previous}'と'{name}'が重複しています。"
+                    ))
+                    .with_action(
+                        "ヘッダー名は大文字・小文字を区別しないため、1つにまとめてください。",
+                    ),
+            );
         }
+        seen.push(name);
+    }
+    errors
+}
+
+impl MailConfigWritePort for JsonMailConfigAdapter {
+    /// メールテンプレートをJSON形式で保存する
+    ///
+    /// メール種別はキーの昇順で出力し、保存先のディレクトリが存在しない場合は作成する
+    ///
+    /// ## Arguments
+    /// * `mail_config` - 保存するメールテンプレート
+    ///
+    /// ## Returns
+    /// * 成功時 - [`Ok(())`]
+    /// * 失敗時 - [`Err<AppError>`]
+    fn save_mail_config(&self, mail_config: &MailConfig) -> AppResult<()> {
+        let path = workspace_path(&self.config_file_path)?;
+        if let Some(parent) = path.parent() {
+            ensure_directory_exists(parent)?;
+        }
+
+        let mut sorted: BTreeMap<_, _> = mail_config
+            .mail_types
+            .iter()
+            .map(|(key, mail_type)| Ok((key.as_str(), serde_json::to_value(mail_type)?)))
+            .collect::<AppResult<_>>()?;
+        if !mail_config.variables.is_empty() {
+            sorted.insert(
+                MailConfig::VARIABLES_KEY,
+                serde_json::to_value(&mail_config.variables)?,
+            );
+        }
+        let mut json = serde_json::to_string_pretty(&sorted)?;
+        json.push('\n');
+        write_atomic(&path, json).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-039")
+                .with_message("mail_config.jsonファイルの書き込みに失敗しました。")
+                .with_action("保存先ディレクトリのアクセス権限を確認してください。")
+                .with_source(e)
+        })
+    }
+
+    /// メールテンプレートのファイルが存在するかチェックする
+    ///
+    /// ## Returns
+    /// * ファイルが存在する場合 - `true`
+    /// * ファイルが存在しない場合 - `false`
+    fn mail_config_exists(&self) -> bool {
+        workspace_path(&self.config_file_path).is_ok_and(|path| path.exists())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::mail_objects::BodyFormat;
+
+    #[test]
+    fn test_save_mail_config_round_trip() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_save_templates_{}",
+            std::process::id()
+        ));
+        let path = dir.join("mail_templates.json");
+        let adapter = JsonMailConfigAdapter::from_path(path.to_string_lossy());
+        let mail_config = MailConfig {
+            mail_types: HashMap::from([(
+                "remote_work_start".to_string(),
+                MailTypeConfig {
+                    to_names: vec!["上司".to_string()],
+                    cc_names: vec![],
+                    subject_template: "【在宅勤務開始】{department} {from} {time}".to_string(),
+                    body_template: "本文".to_string(),
+                    headers: BTreeMap::new(),
+                    body_format: BodyFormat::default(),
+                    attachments: Vec::new(),
+                },
+            )]),
+            variables: BTreeMap::from([("project".to_string(), serde_json::json!("基盤刷新"))]),
+        };
+
+        adapter.save_mail_config(&mail_config).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        let loaded = adapter.load_mail_config().unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(!content.contains("headers"));
+        let template = loaded.get_mail_type("remote_work_start").unwrap();
+        assert_eq!(template.to_names, vec!["上司"]);
+        assert_eq!(loaded.variables, mail_config.variables);
+    }
+
+    #[test]
+    fn test_load_body_template_from_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_body_file_templates_{}",
+            std::process::id()
+        ));
+        let path = dir.join("mail_templates.json");
+        fs::create_dir_all(dir.join("templates")).unwrap();
+        fs::write(
+            dir.join("templates").join("remote_work_end.txt"),
+            "お疲れ様です。\r\n\r\n本日の作業時間: {work_time}\r\n",
+        )
+        .unwrap();
+        fs::write(
+            &path,
+            r#"{
+                "remote_work_end": {
+                    "to_names": ["上司"],
+                    "cc_names": [],
+                    "subject_template": "【在宅勤務終了】{from}",
+                    "body_template": "file:templates/remote_work_end.txt"
+                },
+                "missing": {
+                    "to_names": ["上司"],
+                    "cc_names": [],
+                    "subject_template": "件名",
+                    "body_template": "file:templates/missing.txt"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let result = JsonMailConfigAdapter::from_path(path.to_string_lossy()).load_mail_config();
+        let error = result.unwrap_err();
+        assert!(
+            error
+                .message
+                .contains("'missing'の本文テンプレートファイル")
+        );
+
+        fs::write(dir.join("templates").join("missing.txt"), "本文").unwrap();
+        let loaded = JsonMailConfigAdapter::from_path(path.to_string_lossy())
+            .load_mail_config()
+            .unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        let template = loaded.get_mail_type("remote_work_end").unwrap();
+        assert_eq!(
+            template.body_template,
+            "お疲れ様です。\n\n本日の作業時間: {work_time}"
+        );
+    }
+
+    #[test]
+    fn test_load_mail_config_reports_all_problems() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_invalid_templates_{}",
+            std::process::id()
+        ));
+        let path = dir.join("mail_templates.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            &path,
+            r#"{
+                "broken": { "to_names": "not a list" },
+                "empty": { "to_names": [], "cc_names": [], "subject_template": "", "body_template": "本文" }
+            }"#,
+        )
+        .unwrap();
+
+        let result = JsonMailConfigAdapter::from_path(path.to_string_lossy()).load_mail_config();
+        let _ = fs::remove_dir_all(&dir);
+
+        let error = result.unwrap_err();
+        assert!(error.message.starts_with("3件の問題があります。"));
+        assert!(error.message.contains("'broken'"));
+        assert!(error.message.contains("'empty'の宛先"));
+        assert!(error.message.contains("'empty'の件名テンプレート"));
+    }
+
+    #[test]
+    fn test_load_mail_config_rejects_invalid_headers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mail_templates.json");
+        fs::write(
+            &path,
+            r#"{
+                "notice": {
+                    "to_names": ["山田"], "cc_names": [], "subject_template": "件名", "body_template": "本文",
+                    "headers": {
+                        "X-Foo": "1",
+                        "x-foo": "2",
+                        "From": "evil@example.com",
+                        "X Bad": "1",
+                        "X-Injected": "1\r\nBcc: evil@example.com"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let error = JsonMailConfigAdapter::from_path(path.to_string_lossy())
+            .load_mail_config()
+            .unwrap_err();
 
-        Ok(MailConfig { mail_types })
+        assert!(error.message.starts_with("4件の問題があります。"));
+        assert!(error.message.contains("'X-Foo'と'x-foo'が重複"));
+        assert!(
+            error
+                .message
+                .contains("'From'は追加ヘッダーとして設定できません")
+        );
+        assert!(error.message.contains("ヘッダー名'X Bad'が不正"));
+        assert!(error.message.contains("'X-Injected'の値に改行"));
     }
 }
@@ -0,0 +1,194 @@
+use crate::APP_NAME;
+use crate::domain::{
+    entities::{mail_draft::MailDraft, outbox_item::OutboxItem},
+    interfaces::outbox::OutboxPort,
+};
+use chrono::Local;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+    utils::{
+        dirs::{AppDirectory, resolve_app_path},
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path},
+    },
+};
+use std::{fs, path::PathBuf};
+
+/// 送信待ちのメールドラフトを1件1ファイルのJSONとして保存するアウトバウンドアダプター
+pub struct JsonOutboxAdapter {
+    outbox_dir: String,
+}
+
+impl JsonOutboxAdapter {
+    /// 新しいJsonOutboxAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `outbox_dir` - 送信待ちキューのディレクトリ（ワークスペースルートからの相対パス）
+    ///
+    /// ## Returns
+    /// * JsonOutboxAdapterのインスタンス
+    pub fn new(outbox_dir: impl Into<String>) -> Self {
+        Self {
+            outbox_dir: outbox_dir.into(),
+        }
+    }
+
+    /// デフォルト設定でアダプターを作成する
+    ///
+    /// ## Returns
+    /// * デフォルト設定のJsonOutboxAdapterのインスタンス
+    pub fn with_default_settings() -> Self {
+        let dir = resolve_app_path(
+            AppDirectory::Data,
+            APP_NAME,
+            "outbox",
+            "rust/mail_composer/data/outbox",
+        );
+        Self::new(dir.to_string_lossy())
+    }
+
+    /// 送信待ちキューのディレクトリを取得する
+    fn get_outbox_dir(&self) -> AppResult<PathBuf> {
+        let dir_path = workspace_path(&self.outbox_dir)?;
+        ensure_directory_exists(&dir_path)?;
+        Ok(dir_path)
+    }
+
+    /// 識別子に対応するファイルパスを取得する
+    fn item_path(&self, id: &str) -> AppResult<PathBuf> {
+        Ok(self.get_outbox_dir()?.join(format!("{id}.json")))
+    }
+
+    /// 新しい識別子を採番する
+    ///
+    /// 作成日時をもとに採番し、ファイル名順が作成順になるようにする
+    fn next_id(&self) -> AppResult<String> {
+        let base = Local::now().format("%Y%m%d%H%M%S%3f").to_string();
+        let mut id = base.clone();
+        let mut seq = 1;
+        while self.item_path(&id)?.exists() {
+            id = format!("{base}-{seq}");
+            seq += 1;
+        }
+        Ok(id)
+    }
+}
+
+impl OutboxPort for JsonOutboxAdapter {
+    fn enqueue(&self, draft: &MailDraft) -> AppResult<String> {
+        let _span = logging::operation_span("outbox_enqueue").entered();
+        let id = self.next_id()?;
+        let path = self.item_path(&id)?;
+
+        let json = serde_json::to_string_pretty(draft).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-040")
+                .with_message("メールドラフトのJSONへの変換に失敗しました。")
+                .with_action("メールドラフトの内容を確認してください。")
+                .with_source(e)
+        })?;
+
+        write_atomic(&path, json).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-041")
+                .with_message("送信待ちキューへの書き込みに失敗しました。")
+                .with_action("ディスクの容量とアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+
+        Ok(id)
+    }
+
+    fn list(&self) -> AppResult<Vec<OutboxItem>> {
+        let dir = self.get_outbox_dir()?;
+        let read_dir = fs::read_dir(&dir).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-042")
+                .with_message("送信待ちキューの読み込みに失敗しました。")
+                .with_action("ディレクトリの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+
+        let mut paths = Vec::new();
+        for entry in read_dir {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let id = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let content = fs::read_to_string(&path).map_err(|e| {
+                    AppError::new(ErrorKind::InternalServerError)
+                        .with_code("MC-MAIL-043")
+                        .with_message(format!("送信待ちメール'{id}'の読み込みに失敗しました。"))
+                        .with_action("ファイルの存在とアクセス権限を確認してください。")
+                        .with_source(e)
+                })?;
+                let draft: MailDraft = serde_json::from_str(&content).map_err(|e| {
+                    AppError::new(ErrorKind::UnprocessableEntity)
+                        .with_code("MC-MAIL-044")
+                        .with_message(format!("送信待ちメール'{id}'の解析に失敗しました。"))
+                        .with_action(
+                            "ファイルの形式が正しいことを確認し、不要であれば削除してください。",
+                        )
+                        .with_source(e)
+                })?;
+                Ok(OutboxItem::new(id, draft))
+            })
+            .collect()
+    }
+
+    fn remove(&self, id: &str) -> AppResult<()> {
+        let path = self.item_path(id)?;
+        fs::remove_file(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-045")
+                .with_message(format!("送信待ちメール'{id}'の削除に失敗しました。"))
+                .with_action("ファイルの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        email_address::EmailAddress,
+        mail_objects::{MailBody, Subject},
+    };
+
+    #[test]
+    fn test_outbox_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = JsonOutboxAdapter::new(dir.path().to_string_lossy());
+
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("test@example.com").unwrap()],
+            vec![],
+            Subject::new("テスト件名").unwrap(),
+            MailBody::new("テスト本文"),
+        );
+
+        let id = adapter.enqueue(&draft).unwrap();
+        let items = adapter.list().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id(), id);
+        assert_eq!(items[0].draft().subject().as_str(), "テスト件名");
+
+        adapter.remove(&id).unwrap();
+        assert!(adapter.list().unwrap().is_empty());
+    }
+}
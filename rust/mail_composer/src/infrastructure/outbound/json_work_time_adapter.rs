@@ -1,21 +1,38 @@
+use crate::APP_NAME;
 use crate::domain::{
-    entities::start_time_map::StartTimeMap, interfaces::work_time::WorkTimePort,
-    value_objects::mail_objects::WorkTime,
+    entities::work_record::{WorkRecord, WorkRecordMap},
+    interfaces::{
+        work_time::WorkTimePort,
+        work_time_archive::{WorkTimeArchivePort, retention_cutoff},
+    },
 };
-use chrono::NaiveDate;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate};
 use share::{
     error::{
         app_error::{AppError, AppResult},
         kind::ErrorKind,
     },
-    utils::workspace::{ensure_directory_exists, workspace_path},
+    utils::{
+        dirs::{AppDirectory, resolve_app_path},
+        file_lock::{with_exclusive_lock, with_shared_lock},
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path},
+    },
+};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
 };
-use std::{fs, path::PathBuf};
 
 /// JSON形式で作業時間を管理するアウトバウンドアダプター
+///
+/// アーカイブした作業記録は、同じディレクトリの年ごとのファイル（例: `work_times_2024.json`）に保存する。
+/// 読み込みと保存はロックファイル（例: `work_times.json.lock`）で排他し、同時に実行しても記録を失わない
 pub struct JsonWorkTimeAdapter {
     log_dir: String,
     file_name: String,
+    retention_months: Option<u32>,
 }
 
 impl JsonWorkTimeAdapter {
@@ -31,6 +48,7 @@ impl JsonWorkTimeAdapter {
         Self {
             log_dir: log_dir.into(),
             file_name: file_name.into(),
+            retention_months: None,
         }
     }
 
@@ -39,7 +57,20 @@ impl JsonWorkTimeAdapter {
     /// ## Returns
     /// * デフォルト設定のJsonWorkTimeAdapterのインスタンス
     pub fn with_default_settings() -> Self {
-        Self::new("rust/mail_composer/data", "work_times.json")
+        let dir = resolve_app_path(AppDirectory::Data, APP_NAME, "", "rust/mail_composer/data");
+        Self::new(dir.to_string_lossy(), "work_times.json")
+    }
+
+    /// 保存時に古い作業記録を自動でアーカイブするよう設定する
+    ///
+    /// ## Arguments
+    /// * `months` - 保存する日の月に加えて、作業時間ファイルに残す月数
+    ///
+    /// ## Returns
+    /// * 自動アーカイブが設定されたJsonWorkTimeAdapterのインスタンス
+    pub fn with_retention_months(mut self, months: u32) -> Self {
+        self.retention_months = Some(months);
+        self
     }
 
     /// ログファイルのパスを取得する
@@ -49,23 +80,45 @@ impl JsonWorkTimeAdapter {
         Ok(dir_path.join(&self.file_name))
     }
 
-    /// StartTimeMapを読み込む
-    fn load_start_time_map(&self) -> AppResult<StartTimeMap> {
+    /// 年ごとのアーカイブファイルのパスを取得する（例: `work_times_2024.json`）
+    fn get_archive_file_path(&self, year: i32) -> AppResult<PathBuf> {
         let path = self.get_output_file_path()?;
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let file_name = match path.extension() {
+            Some(extension) => format!("{stem}_{year}.{}", extension.to_string_lossy()),
+            None => format!("{stem}_{year}"),
+        };
+        Ok(path.with_file_name(file_name))
+    }
+
+    /// WorkRecordMapを読み込む
+    ///
+    /// 開始時刻のみを保存していた以前の形式のファイルも読み込める
+    fn load_record_map(&self) -> AppResult<WorkRecordMap> {
+        Self::load_map_from(&self.get_output_file_path()?)
+    }
+
+    /// 指定したファイルからWorkRecordMapを読み込む（ファイルがない場合は空）
+    fn load_map_from(path: &Path) -> AppResult<WorkRecordMap> {
         if !path.exists() {
-            return Ok(StartTimeMap::new());
+            return Ok(WorkRecordMap::new());
         }
 
-        let content = fs::read_to_string(&path).map_err(|e| {
+        let content = fs::read_to_string(path).map_err(|e| {
             AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-022")
                 .with_message("作業時間ファイルの読み込みに失敗しました。")
                 .with_action("ファイルの存在とアクセス権限を確認してください。")
                 .with_source(e)
         })?;
 
-        let map: StartTimeMap = serde_json::from_str(&content).map_err(|e| {
+        let map: WorkRecordMap = serde_json::from_str(&content).map_err(|e| {
             AppError::new(ErrorKind::UnavailableForLegalReasons)
-                .with_message("作業時間ファイルの解析に失敗しました。")
+                .with_code("MC-WORK-023")
+                .with_message(format!(
+                    "作業時間ファイルの解析に失敗しました。詳細: {}",
+                    path.display()
+                ))
                 .with_action("ファイルの形式が正しいことを確認してください。")
                 .with_source(e)
         })?;
@@ -73,19 +126,24 @@ impl JsonWorkTimeAdapter {
         Ok(map)
     }
 
-    /// StartTimeMapを保存する
-    fn save_start_time_map(&self, map: &StartTimeMap) -> AppResult<()> {
-        let path = self.get_output_file_path()?;
+    /// WorkRecordMapを保存する
+    fn save_record_map(&self, map: &WorkRecordMap) -> AppResult<()> {
+        Self::save_map_to(&self.get_output_file_path()?, map)
+    }
 
+    /// 指定したファイルにWorkRecordMapを保存する
+    fn save_map_to(path: &Path, map: &WorkRecordMap) -> AppResult<()> {
         let json = serde_json::to_string_pretty(map).map_err(|e| {
             AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-024")
                 .with_message("JSONへの変換に失敗しました。")
                 .with_action("データの内容を確認してください。")
                 .with_source(e)
         })?;
 
-        fs::write(path, json).map_err(|e| {
+        write_atomic(path, json).map_err(|e| {
             AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-025")
                 .with_message("作業時間ファイルの書き込みに失敗しました。")
                 .with_action("ディスクの容量とアクセス権限を確認してください。")
                 .with_source(e)
@@ -93,50 +151,336 @@ impl JsonWorkTimeAdapter {
 
         Ok(())
     }
+
+    /// 境界日より前の作業記録をWorkRecordMapから取り除き、年ごとのアーカイブファイルに追記する
+    fn move_to_archives(&self, map: &mut WorkRecordMap, cutoff: NaiveDate) -> AppResult<usize> {
+        let recent = map.0.split_off(&cutoff.to_string());
+        let old = std::mem::replace(&mut map.0, recent);
+
+        let mut by_year: BTreeMap<i32, Vec<(String, WorkRecord)>> = BTreeMap::new();
+        for (key, record) in old {
+            match key.parse::<NaiveDate>() {
+                Ok(date) => by_year.entry(date.year()).or_default().push((key, record)),
+                // 日付として解釈できないキーは移動しない
+                Err(_) => {
+                    map.0.insert(key, record);
+                }
+            }
+        }
+
+        let mut moved = 0;
+        for (year, records) in by_year {
+            let path = self.get_archive_file_path(year)?;
+            let mut archive = Self::load_map_from(&path)?;
+            moved += records.len();
+            archive.0.extend(records);
+            Self::save_map_to(&path, &archive)?;
+        }
+        Ok(moved)
+    }
+
+    /// アーカイブ済みの作業記録をアーカイブファイルから取り除き、WorkRecordMapに戻す
+    ///
+    /// アーカイブ済みの日付を更新する際に、部分的な記録がアーカイブの記録を隠さないようにする
+    fn restore_from_archive(&self, map: &mut WorkRecordMap, date: NaiveDate) -> AppResult<()> {
+        let key = date.to_string();
+        if map.get(&key).is_some() {
+            return Ok(());
+        }
+
+        let path = self.get_archive_file_path(date.year())?;
+        let mut archive = Self::load_map_from(&path)?;
+        if let Some(record) = archive.0.remove(&key) {
+            map.0.insert(key, record);
+            Self::save_map_to(&path, &archive)?;
+        }
+        Ok(())
+    }
+
+    /// 作業記録を更新し、設定に応じて古い作業記録をアーカイブしてから保存する
+    ///
+    /// アーカイブ済みの日付は作業時間ファイルに戻してから更新する。
+    /// 読み込みから保存までは排他ロックを保持する
+    fn update_record(
+        &self,
+        date: NaiveDate,
+        update: impl FnOnce(&mut WorkRecordMap, String) -> AppResult<WorkRecord>,
+    ) -> AppResult<WorkRecord> {
+        with_exclusive_lock(self.get_output_file_path()?, || {
+            let mut map = self.load_record_map()?;
+            self.restore_from_archive(&mut map, date)?;
+            let record = update(&mut map, date.to_string())?;
+            if let Some(months) = self.retention_months {
+                self.move_to_archives(&mut map, retention_cutoff(date, months))?;
+            }
+            self.save_record_map(&map)?;
+            Ok(record)
+        })
+    }
+
+    /// 指定した年のアーカイブファイルを読み込む
+    fn load_archive(&self, year: i32) -> AppResult<WorkRecordMap> {
+        Self::load_map_from(&self.get_archive_file_path(year)?)
+    }
 }
 
 impl WorkTimePort for JsonWorkTimeAdapter {
-    fn save_start_time(&self, date: NaiveDate, start_time: &WorkTime) -> AppResult<()> {
-        let mut map = self.load_start_time_map()?;
-        map.set_start_time(date.to_string(), start_time.as_str().to_string());
-        self.save_start_time_map(&map)
-    }
-
-    fn load_start_time(&self, date: NaiveDate) -> AppResult<Option<WorkTime>> {
-        let map = self.load_start_time_map()?;
-        if let Some(time_str) = map.get_start_time(&date.to_string()) {
-            let work_time = WorkTime::new(time_str)?;
-            Ok(Some(work_time))
-        } else {
-            Ok(None)
-        }
+    fn save_start_time(
+        &self,
+        date: NaiveDate,
+        started_at: &DateTime<FixedOffset>,
+    ) -> AppResult<()> {
+        self.update_record(date, |map, key| {
+            Ok(map.set_started_at(key, *started_at).clone())
+        })?;
+        Ok(())
+    }
+
+    fn save_end_time(
+        &self,
+        date: NaiveDate,
+        ended_at: &DateTime<FixedOffset>,
+    ) -> AppResult<WorkRecord> {
+        self.update_record(date, |map, key| {
+            Ok(map.set_ended_at(key, *ended_at).clone())
+        })
+    }
+
+    fn pause(&self, date: NaiveDate, paused_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        self.update_record(date, |map, key| Ok(map.pause(key, *paused_at)?.clone()))
+    }
+
+    fn resume(&self, date: NaiveDate, resumed_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        self.update_record(date, |map, key| Ok(map.resume(key, *resumed_at)?.clone()))
+    }
+
+    fn annotate(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        self.update_record(date, |map, key| Ok(map.add_note(key, note).clone()))
+    }
+
+    fn replace_note(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        self.update_record(date, |map, key| Ok(map.set_note(key, note).clone()))
+    }
+
+    fn load_record(&self, date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+        let key = date.to_string();
+        with_shared_lock(self.get_output_file_path()?, || {
+            match self.load_record_map()?.get(&key) {
+                Some(record) => Ok(Some(record.clone())),
+                None => Ok(self.load_archive(date.year())?.get(&key).cloned()),
+            }
+        })
+    }
+
+    fn load_records(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+        // アーカイブを先に読み込み、同じ日付の記録は作業時間ファイルのものを優先する
+        let records = with_shared_lock(self.get_output_file_path()?, || {
+            let mut records = BTreeMap::new();
+            for year in from.year()..=to.year() {
+                records.extend(self.load_archive(year)?.0);
+            }
+            records.extend(self.load_record_map()?.0);
+            Ok(records)
+        })?;
+
+        // キーはYYYY-MM-DD形式のため、日付として解釈できないものは読み飛ばす
+        Ok(records
+            .into_iter()
+            .filter_map(|(key, record)| Some((key.parse::<NaiveDate>().ok()?, record)))
+            .filter(|(date, _)| (from..=to).contains(date))
+            .collect())
+    }
+}
+
+impl WorkTimeArchivePort for JsonWorkTimeAdapter {
+    fn archive_before(&self, cutoff: NaiveDate) -> AppResult<usize> {
+        with_exclusive_lock(self.get_output_file_path()?, || {
+            let mut map = self.load_record_map()?;
+            let moved = self.move_to_archives(&mut map, cutoff)?;
+            if moved > 0 {
+                self.save_record_map(&map)?;
+            }
+            Ok(moved)
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::time_zone::WorkTimeZone;
+
+    /// 指定した日付・時刻・UTCオフセットの日時を作成する
+    fn at(date: NaiveDate, time: &str, offset: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(&format!("{date}T{time}:00{offset}")).unwrap()
+    }
 
     #[test]
     fn test_work_time_roundtrip() {
-        let adapter = JsonWorkTimeAdapter::with_default_settings();
-        let work_time = WorkTime::new("09:30").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = JsonWorkTimeAdapter::new(dir.path().to_string_lossy(), "work_times.json");
+        let time_zone = WorkTimeZone::parse("Asia/Tokyo").unwrap();
+        let started_at = at(time_zone.today(), "09:30", "+09:00");
 
         // まずは初期状態（空）で確認
-        let initial_time = adapter.load_today_start_time();
+        let initial_time = adapter.load_today_start_time(&time_zone);
         match initial_time {
-            Ok(None) => {}, // 空は正常
-            Ok(Some(_)) => {}, // データがあっても良い
-            Err(_) => {}, // エラーでも一旦続行
+            Ok(None) => {}    // 空は正常
+            Ok(Some(_)) => {} // データがあっても良い
+            Err(_) => {}      // エラーでも一旦続行
         }
 
         // 今日の時間を保存
-        adapter.save_today_start_time(&work_time).unwrap();
+        adapter.save_today_start_time(&started_at).unwrap();
 
         // 今日の時間を読み込み
-        let loaded_time = adapter.load_today_start_time().unwrap();
+        let loaded_time = adapter.load_today_start_time(&time_zone).unwrap();
 
         assert!(loaded_time.is_some());
         assert_eq!(loaded_time.unwrap().as_str(), "09:30");
     }
+
+    #[test]
+    fn test_end_time_and_legacy_format() {
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_work_time_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("work_times.json"), r#"{ "2025-04-01": "09:15" }"#).unwrap();
+        let adapter = JsonWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.json");
+        let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+        let legacy_start = adapter.load_start_time(date);
+        let record = adapter.save_end_time(date, &at(date, "18:00", "+09:00"));
+        let records = adapter.load_records(date, date.succ_opt().unwrap());
+        let saved = fs::read_to_string(dir.join("work_times.json")).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(legacy_start.unwrap().unwrap().as_str(), "09:15");
+        let record = record.unwrap();
+        assert_eq!(record.end.as_ref().unwrap().as_str(), "18:00");
+        assert_eq!(record.duration_minutes, Some(525));
+        assert!(saved.contains(r#""duration_minutes": 525"#));
+        assert_eq!(records.unwrap(), vec![(date, record)]);
+    }
+
+    #[test]
+    fn test_annotate_appends_notes() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_work_time_notes_{}",
+            std::process::id()
+        ));
+        let adapter = JsonWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.json");
+        let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+        adapter
+            .annotate(date, "遅刻連絡（出社予定 10:30）")
+            .unwrap();
+        let annotated = adapter.annotate(date, "客先訪問");
+        let loaded = adapter.load_record(date);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            annotated.unwrap().notes.as_deref(),
+            Some("遅刻連絡（出社予定 10:30）, 客先訪問")
+        );
+        assert!(loaded.unwrap().unwrap().start.is_none());
+    }
+
+    #[test]
+    fn test_timestamps_across_time_zones() {
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_work_time_tz_{}", std::process::id()));
+        let adapter = JsonWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.json");
+        let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+        // 東京で開始し、UTCのサーバーで終了を記録した場合
+        adapter
+            .save_start_time(date, &at(date, "09:00", "+09:00"))
+            .unwrap();
+        let record = adapter.save_end_time(date, &at(date, "09:30", "+00:00"));
+        let saved = fs::read_to_string(dir.join("work_times.json")).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        let record = record.unwrap();
+        assert_eq!(record.duration_minutes, Some(9 * 60 + 30));
+        assert!(saved.contains(r#""started_at": "2025-04-01T09:00:00+09:00""#));
+
+        let tokyo = record.localized(&WorkTimeZone::parse("Asia/Tokyo").unwrap());
+        assert_eq!(tokyo.start.unwrap().as_str(), "09:00");
+        assert_eq!(tokyo.end.unwrap().as_str(), "18:30");
+    }
+
+    #[test]
+    fn test_archive_old_records_by_year() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_work_time_archive_{}",
+            std::process::id()
+        ));
+        let adapter = JsonWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.json")
+            .with_retention_months(2);
+        let old = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+        let cutoff_day = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        adapter
+            .save_start_time(old, &at(old, "09:00", "+09:00"))
+            .unwrap();
+        adapter
+            .save_start_time(cutoff_day, &at(cutoff_day, "09:00", "+09:00"))
+            .unwrap();
+        // 2025-03の保存時に、2025-01-01より前の記録がアーカイブされる
+        adapter
+            .save_start_time(today, &at(today, "09:00", "+09:00"))
+            .unwrap();
+        let hot = fs::read_to_string(dir.join("work_times.json")).unwrap();
+        let archive = fs::read_to_string(dir.join("work_times_2024.json")).unwrap();
+        let archived_record = adapter.load_record(old);
+        let records = adapter.load_records(old, today);
+        let moved_again = adapter.archive_before(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap());
+        let archive_2025 = fs::read_to_string(dir.join("work_times_2025.json")).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(!hot.contains("2024-12-20"));
+        assert!(hot.contains("2025-01-31"));
+        assert!(archive.contains("2024-12-20"));
+        assert_eq!(
+            archived_record.unwrap().unwrap().start.unwrap().as_str(),
+            "09:00"
+        );
+        let dates: Vec<NaiveDate> = records.unwrap().into_iter().map(|(d, _)| d).collect();
+        assert_eq!(dates, vec![old, cutoff_day, today]);
+        assert_eq!(moved_again.unwrap(), 1);
+        assert!(archive_2025.contains("2025-01-31"));
+    }
+
+    #[test]
+    fn test_update_archived_record() {
+        let dir = std::env::temp_dir().join(format!(
+            "mail_composer_work_time_archived_update_{}",
+            std::process::id()
+        ));
+        let adapter = JsonWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.json");
+        let old = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+
+        adapter
+            .save_start_time(old, &at(old, "09:00", "+09:00"))
+            .unwrap();
+        adapter
+            .archive_before(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+            .unwrap();
+        let updated = adapter.save_end_time(old, &at(old, "18:00", "+09:00"));
+        let loaded = adapter.load_record(old);
+        let archive = fs::read_to_string(dir.join("work_times_2024.json")).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        let updated = updated.unwrap();
+        assert_eq!(updated.start.unwrap().as_str(), "09:00");
+        assert_eq!(updated.end.unwrap().as_str(), "18:00");
+        assert_eq!(loaded.unwrap().unwrap().start.unwrap().as_str(), "09:00");
+        assert!(!archive.contains("2024-12-20"));
+    }
 }
@@ -0,0 +1,445 @@
+use crate::APP_NAME;
+use crate::domain::{
+    entities::work_record::{WorkRecord, WorkRecordMap},
+    interfaces::work_time::WorkTimePort,
+};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::{
+        dirs::{AppDirectory, resolve_app_path},
+        file_lock::FileLock,
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path},
+    },
+};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+
+/// 作業記録の変更を表すイベントの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkTimeEventKind {
+    /// 作業開始
+    Start,
+    /// 作業終了
+    End,
+    /// 休憩開始（一時停止）
+    BreakStart,
+    /// 休憩終了（再開）
+    BreakEnd,
+    /// 備考の追記
+    Note,
+    /// 備考の置き換え
+    NoteReplace,
+}
+
+/// 作業記録ログの1行に対応するイベント
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkTimeEvent {
+    /// 対象日付
+    pub date: NaiveDate,
+    /// イベントの種類
+    pub kind: WorkTimeEventKind,
+    /// 発生日時（記録時のUTCオフセット付き）
+    pub at: DateTime<FixedOffset>,
+    /// 追記する備考、または置き換え後の備考（`note`・`note_replace`イベントのみ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl WorkTimeEvent {
+    /// イベントを日ごとの作業記録に適用する
+    fn apply(&self, map: &mut WorkRecordMap) -> AppResult<WorkRecord> {
+        let key = self.date.to_string();
+        let record = match self.kind {
+            WorkTimeEventKind::Start => map.set_started_at(key, self.at),
+            WorkTimeEventKind::End => map.set_ended_at(key, self.at),
+            WorkTimeEventKind::BreakStart => map.pause(key, self.at)?,
+            WorkTimeEventKind::BreakEnd => map.resume(key, self.at)?,
+            WorkTimeEventKind::Note => map.add_note(key, self.note.as_deref().unwrap_or_default()),
+            WorkTimeEventKind::NoteReplace => {
+                map.set_note(key, self.note.as_deref().unwrap_or_default())
+            }
+        };
+        Ok(record.clone())
+    }
+}
+
+/// 1行1イベントのJSON Lines形式で作業時間を管理するアウトバウンドアダプター
+///
+/// ファイル全体を書き換えずにイベントを追記するため、書き込み中に異常終了しても既存の記録は失われず、
+/// 変更の履歴を後から確認できる。日ごとの作業記録はイベントを順に適用して導出する
+pub struct JsonlWorkTimeAdapter {
+    log_dir: String,
+    file_name: String,
+}
+
+impl JsonlWorkTimeAdapter {
+    /// 新しいJsonlWorkTimeAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `log_dir` - ログディレクトリのパス
+    /// * `file_name` - ファイル名
+    ///
+    /// ## Returns
+    /// * JsonlWorkTimeAdapterのインスタンス
+    pub fn new(log_dir: impl Into<String>, file_name: impl Into<String>) -> Self {
+        Self {
+            log_dir: log_dir.into(),
+            file_name: file_name.into(),
+        }
+    }
+
+    /// デフォルト設定でアダプターを作成する
+    ///
+    /// ## Returns
+    /// * デフォルト設定のJsonlWorkTimeAdapterのインスタンス
+    pub fn with_default_settings() -> Self {
+        let dir = resolve_app_path(AppDirectory::Data, APP_NAME, "", "rust/mail_composer/data");
+        Self::new(dir.to_string_lossy(), "work_times.jsonl")
+    }
+
+    /// イベントログのパスを取得する
+    fn get_output_file_path(&self) -> AppResult<PathBuf> {
+        let dir_path = workspace_path(&self.log_dir)?;
+        ensure_directory_exists(&dir_path)?;
+        Ok(dir_path.join(&self.file_name))
+    }
+
+    /// 圧縮した日ごとの作業記録を保存するファイルのパスを取得する（例: `work_times.snapshot.json`）
+    ///
+    /// 同じディレクトリで`JsonWorkTimeAdapter`が使用する`work_times.json`を上書きしないよう、別の名前にする
+    fn get_snapshot_file_path(&self) -> AppResult<PathBuf> {
+        Ok(self.get_output_file_path()?.with_extension("snapshot.json"))
+    }
+
+    /// イベントログを記録順に読み込む
+    ///
+    /// 書き込み途中で異常終了した場合に残る、改行で終わらない最終行は読み飛ばす。
+    /// 読み込み中は共有ロックを保持する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Vec<WorkTimeEvent>>` (ファイルがない場合は空)
+    /// * 失敗時 - 読み込みに失敗した場合、または不正な行がある場合の`Err<AppError>`
+    pub fn events(&self) -> AppResult<Vec<WorkTimeEvent>> {
+        let path = self.get_output_file_path()?;
+        let _lock = FileLock::shared(&path)?;
+        self.read_events()
+    }
+
+    /// ロックを取得せずにイベントログを読み込む（[`JsonlWorkTimeAdapter::events`]を参照）
+    fn read_events(&self) -> AppResult<Vec<WorkTimeEvent>> {
+        let path = self.get_output_file_path()?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-026")
+                .with_message("作業時間ログの読み込みに失敗しました。")
+                .with_action("ファイルの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+
+        let complete = content.ends_with('\n');
+        let lines: Vec<&str> = content.lines().collect();
+        let mut events = Vec::with_capacity(lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(event) => events.push(event),
+                Err(_) if !complete && index + 1 == lines.len() => {}
+                Err(e) => {
+                    return Err(AppError::new(ErrorKind::UnavailableForLegalReasons)
+                        .with_code("MC-WORK-027")
+                        .with_message(format!(
+                            "作業時間ログの解析に失敗しました。詳細: {}:{}",
+                            path.display(),
+                            index + 1
+                        ))
+                        .with_action("該当する行の形式が正しいことを確認してください。")
+                        .with_source(e));
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    /// イベントを順に適用し、日ごとの作業記録を導出する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<WorkRecordMap>`
+    /// * 失敗時 - ログの読み込みに失敗した場合、または矛盾するイベントがある場合の`Err<AppError>`
+    pub fn daily_view(&self) -> AppResult<WorkRecordMap> {
+        let mut map = WorkRecordMap::new();
+        for event in self.events()? {
+            event.apply(&mut map)?;
+        }
+        Ok(map)
+    }
+
+    /// 日ごとの作業記録を導出し、JSON形式のファイル（`JsonWorkTimeAdapter`と同じ形式）に保存する
+    ///
+    /// イベントログはそのまま残すため、保存したファイルはいつでも作り直せる
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<usize>` (保存した日数)
+    /// * 失敗時 - `Err<AppError>`
+    pub fn compact(&self) -> AppResult<usize> {
+        let map = self.daily_view()?;
+        let json = serde_json::to_string_pretty(&map).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-028")
+                .with_message("JSONへの変換に失敗しました。")
+                .with_action("データの内容を確認してください。")
+                .with_source(e)
+        })?;
+
+        write_atomic(&self.get_snapshot_file_path()?, json).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-029")
+                .with_message("作業時間ファイルの書き込みに失敗しました。")
+                .with_action("ディスクの容量とアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+
+        Ok(map.entries().len())
+    }
+
+    /// イベントを検証してからログの末尾に追記する
+    ///
+    /// 既存の記録に適用できないイベント（作業中でない日の一時停止など）は追記しない。
+    /// 検証から追記までは排他ロックを保持する
+    fn append(
+        &self,
+        date: NaiveDate,
+        kind: WorkTimeEventKind,
+        at: &DateTime<FixedOffset>,
+    ) -> AppResult<WorkRecord> {
+        self.append_event(WorkTimeEvent {
+            date,
+            kind,
+            at: *at,
+            note: None,
+        })
+    }
+
+    /// イベントを検証してからログの末尾に追記する（[`JsonlWorkTimeAdapter::append`]を参照）
+    fn append_event(&self, event: WorkTimeEvent) -> AppResult<WorkRecord> {
+        let date = event.date;
+        let path = self.get_output_file_path()?;
+        let _lock = FileLock::exclusive(&path)?;
+        let mut map = WorkRecordMap::new();
+        for past in self.read_events()?.into_iter().filter(|e| e.date == date) {
+            past.apply(&mut map)?;
+        }
+        let record = event.apply(&mut map)?;
+
+        let json = serde_json::to_string(&event).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-030")
+                .with_message("JSONへの変換に失敗しました。")
+                .with_action("データの内容を確認してください。")
+                .with_source(e)
+        })?;
+
+        let write_error = |e: std::io::Error| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-031")
+                .with_message("作業時間ログへの追記に失敗しました。")
+                .with_action("ディスクの容量とアクセス権限を確認してください。")
+                .with_source(e)
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(write_error)?;
+        // 異常終了で残った改行で終わらない最終行は、読み込み時と同様に捨ててから追記する
+        let content = fs::read(&path).map_err(write_error)?;
+        if content.last().is_some_and(|b| *b != b'\n') {
+            let complete_len = content
+                .iter()
+                .rposition(|b| *b == b'\n')
+                .map_or(0, |index| index + 1);
+            file.set_len(complete_len as u64).map_err(write_error)?;
+        }
+        let line = format!("{json}\n");
+        file.write_all(line.as_bytes()).map_err(write_error)?;
+        file.sync_data().map_err(write_error)?;
+
+        Ok(record)
+    }
+}
+
+impl WorkTimePort for JsonlWorkTimeAdapter {
+    fn save_start_time(
+        &self,
+        date: NaiveDate,
+        started_at: &DateTime<FixedOffset>,
+    ) -> AppResult<()> {
+        self.append(date, WorkTimeEventKind::Start, started_at)?;
+        Ok(())
+    }
+
+    fn save_end_time(
+        &self,
+        date: NaiveDate,
+        ended_at: &DateTime<FixedOffset>,
+    ) -> AppResult<WorkRecord> {
+        self.append(date, WorkTimeEventKind::End, ended_at)
+    }
+
+    fn pause(&self, date: NaiveDate, paused_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        self.append(date, WorkTimeEventKind::BreakStart, paused_at)
+    }
+
+    fn resume(&self, date: NaiveDate, resumed_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        self.append(date, WorkTimeEventKind::BreakEnd, resumed_at)
+    }
+
+    fn annotate(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        self.append_event(WorkTimeEvent {
+            date,
+            kind: WorkTimeEventKind::Note,
+            at: Local::now().fixed_offset(),
+            note: Some(note.to_string()),
+        })
+    }
+
+    fn replace_note(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        self.append_event(WorkTimeEvent {
+            date,
+            kind: WorkTimeEventKind::NoteReplace,
+            at: Local::now().fixed_offset(),
+            note: Some(note.to_string()),
+        })
+    }
+
+    fn load_record(&self, date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+        Ok(self.daily_view()?.get(&date.to_string()).cloned())
+    }
+
+    fn load_records(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+        Ok(self
+            .daily_view()?
+            .0
+            .into_iter()
+            .filter_map(|(key, record)| Some((key.parse::<NaiveDate>().ok()?, record)))
+            .filter(|(date, _)| (from..=to).contains(date))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 指定した日付・時刻・UTCオフセットの日時を作成する
+    fn at(date: NaiveDate, time: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(&format!("{date}T{time}:00+09:00")).unwrap()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("mail_composer_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_events_are_appended_and_replayed() {
+        let dir = temp_dir("jsonl_work_time");
+        let adapter = JsonlWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.jsonl");
+        let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+        adapter.save_start_time(date, &at(date, "09:00")).unwrap();
+        adapter.pause(date, &at(date, "12:00")).unwrap();
+        let rejected = adapter.pause(date, &at(date, "12:30"));
+        adapter.resume(date, &at(date, "13:00")).unwrap();
+        let ended = adapter.save_end_time(date, &at(date, "18:00")).unwrap();
+        let log = fs::read_to_string(dir.join("work_times.jsonl")).unwrap();
+        let loaded = adapter.load_record(date).unwrap();
+        let days = adapter.compact().unwrap();
+        let snapshot = fs::read_to_string(dir.join("work_times.snapshot.json")).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(rejected.is_err());
+        assert_eq!(log.lines().count(), 4);
+        assert!(
+            log.lines()
+                .nth(1)
+                .unwrap()
+                .contains(r#""kind":"break_start""#)
+        );
+        assert_eq!(ended.duration(), Some(8 * 60));
+        assert_eq!(loaded, Some(ended));
+        assert_eq!(days, 1);
+        assert!(snapshot.contains(r#""duration_minutes": 480"#));
+    }
+
+    #[test]
+    fn test_compact_keeps_json_adapter_store() {
+        use crate::infrastructure::outbound::json_work_time_adapter::JsonWorkTimeAdapter;
+
+        let dir = temp_dir("jsonl_work_time_shared_dir");
+        let json = JsonWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.json");
+        let jsonl = JsonlWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.jsonl");
+        let json_date = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        let jsonl_date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+        json.save_start_time(json_date, &at(json_date, "09:00"))
+            .unwrap();
+        jsonl
+            .save_start_time(jsonl_date, &at(jsonl_date, "10:00"))
+            .unwrap();
+        let days = jsonl.compact();
+        let json_record = json.load_record(json_date);
+        let json_missing = json.load_record(jsonl_date);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(days.unwrap(), 1);
+        assert_eq!(
+            json_record.unwrap().unwrap().start.unwrap().as_str(),
+            "09:00"
+        );
+        assert_eq!(json_missing.unwrap(), None);
+    }
+
+    #[test]
+    fn test_torn_last_line_is_ignored() {
+        let dir = temp_dir("jsonl_work_time_torn");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("work_times.jsonl"),
+            "{\"date\":\"2025-04-01\",\"kind\":\"start\",\"at\":\"2025-04-01T09:00:00+09:00\"}\n{\"date\":\"2025-04-01\",\"ki",
+        )
+        .unwrap();
+        let adapter = JsonlWorkTimeAdapter::new(dir.to_string_lossy(), "work_times.jsonl");
+        let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+        let before = adapter.events().map(|events| events.len());
+        let ended = adapter.save_end_time(date, &at(date, "17:30"));
+        let after = adapter.events().map(|events| events.len());
+        let log = fs::read_to_string(dir.join("work_times.jsonl")).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(before.unwrap(), 1);
+        assert_eq!(ended.unwrap().duration(), Some(510));
+        assert_eq!(after.unwrap(), 2);
+        // 途中までの行は捨てて追記する
+        assert_eq!(log.lines().count(), 2);
+    }
+}
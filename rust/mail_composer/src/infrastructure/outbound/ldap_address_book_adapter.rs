@@ -0,0 +1,322 @@
+use crate::domain::{
+    interfaces::address_book::AddressBookPort,
+    value_objects::{app_configuration::LdapConfiguration, email_address::EmailAddress},
+};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+    utils::{
+        fs::write_atomic,
+        workspace::{ensure_directory_exists, workspace_path},
+    },
+};
+use std::{collections::BTreeMap, fs, io::Write, path::PathBuf, process::Command, sync::Mutex};
+use tempfile::NamedTempFile;
+
+/// LDAPのバインドパスワードを受け取る環境変数名
+pub const LDAP_PASSWORD_ENV: &str = "MAIL_COMPOSER_LDAP_PASSWORD";
+
+/// 社内ディレクトリ（LDAP/Active Directory）で名前を解決するアウトバウンドアダプター
+///
+/// 検索には`ldapsearch`を使用し、解決できた名前はキャッシュファイルに保存して再利用する
+pub struct LdapAddressBookAdapter {
+    ldapsearch_path: String,
+    config: LdapConfiguration,
+    password: Option<String>,
+    cache: Mutex<BTreeMap<String, String>>,
+}
+
+impl LdapAddressBookAdapter {
+    /// 新しいLdapAddressBookAdapterを作成する
+    ///
+    /// キャッシュファイルが存在する場合は読み込む
+    ///
+    /// ## Arguments
+    /// * `config` - LDAPの接続と検索の設定
+    /// * `password` - バインドパスワード（匿名バインドの場合は`None`）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<LdapAddressBookAdapter>`
+    /// * 失敗時 - キャッシュファイルの読み込みに失敗した場合の`Err<AppError>`
+    pub fn new(config: LdapConfiguration, password: Option<String>) -> AppResult<Self> {
+        let adapter = Self {
+            ldapsearch_path: "ldapsearch".to_string(),
+            config,
+            password,
+            cache: Mutex::new(BTreeMap::new()),
+        };
+        let cache = adapter.load_cache()?;
+        *adapter.cache.lock().unwrap_or_else(|e| e.into_inner()) = cache;
+        Ok(adapter)
+    }
+
+    /// LDAPの設定からアダプターを作成する
+    ///
+    /// バインドパスワードは環境変数`MAIL_COMPOSER_LDAP_PASSWORD`から読み込む
+    ///
+    /// ## Arguments
+    /// * `config` - LDAPの接続と検索の設定
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<LdapAddressBookAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn from_configuration(config: LdapConfiguration) -> AppResult<Self> {
+        let password = std::env::var(LDAP_PASSWORD_ENV).ok();
+        Self::new(config, password)
+    }
+
+    /// ldapsearch実行ファイルのパスを指定する
+    ///
+    /// ## Arguments
+    /// * `ldapsearch_path` - ldapsearch実行ファイルのパス
+    ///
+    /// ## Returns
+    /// * ldapsearch実行ファイルのパスが設定されたLdapAddressBookAdapterのインスタンス
+    pub fn with_ldapsearch(mut self, ldapsearch_path: impl Into<String>) -> Self {
+        self.ldapsearch_path = ldapsearch_path.into();
+        self
+    }
+
+    /// キャッシュファイルのパスを取得する
+    fn cache_path(&self) -> AppResult<PathBuf> {
+        workspace_path(&self.config.cache_file)
+    }
+
+    /// キャッシュファイルを読み込む
+    fn load_cache(&self) -> AppResult<BTreeMap<String, String>> {
+        let path = self.cache_path()?;
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let content = fs::read_to_string(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-ADDR-018")
+                .with_message("LDAPキャッシュファイルの読み込みに失敗しました。")
+                .with_action("ファイルのアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-ADDR-019")
+                .with_message("LDAPキャッシュファイルの解析に失敗しました。")
+                .with_action("キャッシュファイルを削除してから再度実行してください。")
+                .with_source(e)
+        })
+    }
+
+    /// キャッシュファイルを保存する
+    fn save_cache(&self, cache: &BTreeMap<String, String>) -> AppResult<()> {
+        let path = self.cache_path()?;
+        if let Some(parent) = path.parent() {
+            ensure_directory_exists(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(cache)?;
+        write_atomic(&path, json).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-ADDR-020")
+                .with_message("LDAPキャッシュファイルの書き込みに失敗しました。")
+                .with_action("ディスクの容量とアクセス権限を確認してください。")
+                .with_source(e)
+        })
+    }
+
+    /// 名前から検索フィルターを構築する
+    fn build_filter(&self, key_name: &str) -> String {
+        self.config
+            .filter_template
+            .replace("{name}", &escape_filter_value(key_name))
+    }
+
+    /// LDAPを検索し、名前に対応するメールアドレスを取得する
+    fn search(&self, key_name: &str) -> AppResult<String> {
+        let _span = logging::operation_span("ldap_search").entered();
+        let mut command = Command::new(&self.ldapsearch_path);
+        command
+            .args([
+                "-LLL",
+                "-x",
+                "-H",
+                &self.config.url,
+                "-b",
+                &self.config.base_dn,
+            ])
+            .args(["-z", "2"]);
+
+        // パスワードファイルは検索が終わった時点で（エラーの場合も）破棄して削除する
+        let mut password_file = None;
+        if let Some(bind_dn) = &self.config.bind_dn {
+            command.args(["-D", bind_dn]);
+            if let Some(password) = &self.password {
+                // パスワードがプロセス一覧に表示されないよう、ファイル経由で渡す
+                let file = write_secret_file(password)?;
+                command.arg("-y").arg(file.path());
+                password_file = Some(file);
+            }
+        }
+        command
+            .arg(self.build_filter(key_name))
+            .arg(&self.config.mail_attribute);
+
+        let output = command.output();
+        drop(password_file);
+        let output = output.map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-ADDR-021")
+                .with_message("ldapsearchの起動に失敗しました。")
+                .with_action(
+                    "OpenLDAPのクライアントツールがインストールされていることを確認してください。",
+                )
+                .with_source(e)
+        })?;
+
+        // 終了コード4（サイズ制限超過）は複数件ヒットしたことを示すため、結果の解析に進む
+        if !output.status.success() && output.status.code() != Some(4) {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::new(ErrorKind::ServiceUnavailable)
+                .with_code("MC-ADDR-022")
+                .with_message(format!("LDAPの検索に失敗しました。詳細: {}", stderr.trim()))
+                .with_action("LDAPのURL、ベースDN、バインドDNとパスワードを確認してください。"));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let addresses = parse_ldif_attribute(&stdout, &self.config.mail_attribute);
+        match addresses.as_slice() {
+            [] => Err(AppError::new(ErrorKind::NotFound)
+                .with_code("MC-ADDR-023")
+                .with_message(format!(
+                    "ディレクトリに'{key_name}'に対応するメールアドレスが見つかりません。"
+                ))
+                .with_action("名前の表記と、LDAPの検索フィルター設定を確認してください。")),
+            [address] => Ok(address.clone()),
+            _ => Err(AppError::new(ErrorKind::Conflict)
+                .with_code("MC-ADDR-024")
+                .with_message(format!(
+                    "ディレクトリに'{key_name}'に該当するエントリが複数見つかりました。"
+                ))
+                .with_action(
+                    "一意に特定できる名前を指定するか、検索フィルター設定を見直してください。",
+                )),
+        }
+    }
+}
+
+impl AddressBookPort for LdapAddressBookAdapter {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(address) = cache.get(key_name) {
+            return EmailAddress::parse(address.as_str());
+        }
+
+        let address = self.search(key_name)?;
+        let email = EmailAddress::parse(address.as_str())?;
+        cache.insert(key_name.to_string(), address);
+        self.save_cache(&cache)?;
+        Ok(email)
+    }
+}
+
+/// 秘密情報を所有者のみ読み書きできる一時ファイルに書き出す
+///
+/// ファイルは推測できない名前で新規に作成し、戻り値を破棄した時点で削除する
+fn write_secret_file(secret: &str) -> AppResult<NamedTempFile> {
+    let mut file = tempfile::Builder::new()
+        .prefix("mail_composer_ldap_")
+        .tempfile()?;
+    file.write_all(secret.as_bytes())?;
+    file.flush()?;
+    Ok(file)
+}
+
+/// 検索フィルターに埋め込む値をエスケープする（RFC 4515）
+fn escape_filter_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '*' => escaped.push_str("\\2a"),
+            '(' => escaped.push_str("\\28"),
+            ')' => escaped.push_str("\\29"),
+            '\\' => escaped.push_str("\\5c"),
+            '\0' => escaped.push_str("\\00"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// LDIF形式の出力から指定された属性の値を全て取り出す
+///
+/// 折り返された行の連結と、base64エンコードされた値（`attr:: value`）のデコードを行う
+fn parse_ldif_attribute(ldif: &str, attribute: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ldif.lines() {
+        if let Some(continued) = line.strip_prefix(' ')
+            && let Some(last) = lines.last_mut()
+        {
+            last.push_str(continued);
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+
+    lines
+        .iter()
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            if !name.eq_ignore_ascii_case(attribute) {
+                return None;
+            }
+            match value.strip_prefix(':') {
+                Some(encoded) => STANDARD
+                    .decode(encoded.trim())
+                    .ok()
+                    .and_then(|bytes| String::from_utf8(bytes).ok()),
+                None => Some(value.trim().to_string()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_filter_value() {
+        assert_eq!(escape_filter_value("山田*(太郎)"), "山田\\2a\\28太郎\\29");
+    }
+
+    #[test]
+    fn test_parse_ldif_attribute() {
+        let ldif = format!(
+            "dn: cn=yamada,ou=people,dc=example,dc=com\nmail: yamada@exam\n ple.com\n\ndn: cn=sato,ou=people,dc=example,dc=com\nmail:: {}\n",
+            STANDARD.encode("sato@example.com")
+        );
+
+        assert_eq!(
+            parse_ldif_attribute(&ldif, "mail"),
+            vec!["yamada@example.com", "sato@example.com"]
+        );
+    }
+
+    #[test]
+    fn test_write_secret_file() {
+        let file = write_secret_file("secret").unwrap();
+        let path = file.path().to_path_buf();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "secret");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        drop(file);
+        assert!(!path.exists());
+    }
+}
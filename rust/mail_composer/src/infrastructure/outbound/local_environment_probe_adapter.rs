@@ -0,0 +1,162 @@
+use crate::domain::interfaces::environment_probe::EnvironmentProbePort;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// ローカルのファイルシステムで実行環境を確認するアウトバウンドアダプター
+pub struct LocalEnvironmentProbeAdapter;
+
+impl LocalEnvironmentProbeAdapter {
+    /// 新しいLocalEnvironmentProbeAdapterを作成する
+    ///
+    /// ## Returns
+    /// * LocalEnvironmentProbeAdapterのインスタンス
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// パス区切りを含まないコマンド名を`PATH`から探す
+    fn find_in_path(command: &Path) -> Option<PathBuf> {
+        if command.components().count() != 1 || command.is_absolute() {
+            return None;
+        }
+        let search_path = env::var_os("PATH")?;
+        env::split_paths(&search_path)
+            .flat_map(|dir| {
+                let plain = dir.join(command);
+                let exe = dir.join(command).with_extension("exe");
+                [plain, exe]
+            })
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// 実行権限があるかどうか（Windowsではファイルが存在すれば実行可能とみなす）
+    #[cfg(unix)]
+    fn is_executable(metadata: &fs::Metadata) -> bool {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode() & 0o111 != 0
+    }
+
+    #[cfg(not(unix))]
+    fn is_executable(_metadata: &fs::Metadata) -> bool {
+        true
+    }
+}
+
+impl Default for LocalEnvironmentProbeAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnvironmentProbePort for LocalEnvironmentProbeAdapter {
+    fn check_writable_dir(&self, path: &Path) -> AppResult<()> {
+        let dir = workspace_path(path)?;
+        if !dir.is_dir() {
+            return Err(AppError::new(ErrorKind::NotFound)
+                .with_code("MC-CONF-025")
+                .with_message(format!(
+                    "ディレクトリが存在しません。詳細: {}",
+                    dir.display()
+                ))
+                .with_action("ディレクトリを作成するか、config.jsonのパスを修正してください。"));
+        }
+
+        let probe = dir.join(format!(".mail_composer_write_test_{}", std::process::id()));
+        fs::write(&probe, b"").map_err(|e| {
+            AppError::new(ErrorKind::Forbidden)
+                .with_code("MC-CONF-026")
+                .with_message(format!(
+                    "ディレクトリに書き込めません。詳細: {}",
+                    dir.display()
+                ))
+                .with_action("ディレクトリの書き込み権限を確認してください。")
+                .with_source(e)
+        })?;
+        let _ = fs::remove_file(&probe);
+        Ok(())
+    }
+
+    fn check_executable(&self, path: &Path) -> AppResult<()> {
+        let resolved = if path.is_file() {
+            path.to_path_buf()
+        } else {
+            Self::find_in_path(path).ok_or_else(|| {
+                AppError::new(ErrorKind::NotFound)
+                    .with_code("MC-CONF-027")
+                    .with_message(format!(
+                        "実行ファイルが見つかりません。詳細: {}",
+                        path.display()
+                    ))
+                    .with_action(
+                        "config.jsonのthunderbird_exeフィールドにThunderbirdの実行ファイルのパスを設定してください。",
+                    )
+            })?
+        };
+
+        let metadata = fs::metadata(&resolved).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CONF-028")
+                .with_message("実行ファイルの情報の取得に失敗しました。")
+                .with_action("ファイルのアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+        if !Self::is_executable(&metadata) {
+            return Err(AppError::new(ErrorKind::Forbidden)
+                .with_code("MC-CONF-029")
+                .with_message(format!(
+                    "ファイルに実行権限がありません。詳細: {}",
+                    resolved.display()
+                ))
+                .with_action("chmod +x などで実行権限を付与してください。"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_writable_dir() {
+        let probe = LocalEnvironmentProbeAdapter::new();
+        assert!(probe.check_writable_dir(&env::temp_dir()).is_ok());
+        assert!(
+            probe
+                .check_writable_dir(&env::temp_dir().join("mail_composer_missing_dir"))
+                .is_err()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let probe = LocalEnvironmentProbeAdapter::new();
+        let path = env::temp_dir().join(format!("mail_composer_probe_exe_{}", std::process::id()));
+        fs::write(&path, "").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        let not_executable = probe.check_executable(&path);
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        let executable = probe.check_executable(&path);
+        let _ = fs::remove_file(&path);
+
+        assert!(not_executable.is_err());
+        assert!(executable.is_ok());
+        assert!(
+            probe
+                .check_executable(Path::new("mail_composer_no_such_command"))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    application::usecases::thunderbird_detection_use_case::ThunderbirdDetectionUseCase,
+    domain::{
+        interfaces::{mail_client::MailClientPort, mail_signer::MailSignerPort},
+        value_objects::app_configuration::{AppConfiguration, SigningConfiguration},
+    },
+    infrastructure::outbound::{
+        apple_mail_client_adapter::AppleMailClientAdapter,
+        gpg_mail_signer_adapter::GpgMailSignerAdapter,
+        imap_draft_mail_client_adapter::ImapDraftMailClientAdapter,
+        json_configuration_adapter::JsonConfigurationAdapter,
+        local_environment_probe_adapter::LocalEnvironmentProbeAdapter,
+        rate_limited_mail_client::RateLimitedMailClient,
+        smime_mail_signer_adapter::SmimeMailSignerAdapter,
+        thunderbird_locator_adapter::ThunderbirdLocatorAdapter,
+        thunderbird_mail_client_adapter::{
+            DEFAULT_THUNDERBIRD_TIMEOUT, ThunderbirdMailClientAdapter,
+        },
+    },
+};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{path::Path, time::Duration};
+
+/// 設定されたThunderbird実行ファイルが無効な場合に、実行ファイルを自動検出するユースケース
+pub type ThunderbirdDetection = ThunderbirdDetectionUseCase<
+    JsonConfigurationAdapter,
+    ThunderbirdLocatorAdapter,
+    LocalEnvironmentProbeAdapter,
+>;
+
+/// 設定に従ってメールクライアントを選択する
+///
+/// `imap`が設定されている場合はIMAPの下書きフォルダにアップロードし（`signing`が設定されていれば署名する）、
+/// それ以外はプラットフォームのメールクライアントを使用する。
+/// プラットフォームのメールクライアントは署名できないため、`signing`のみが設定されている場合はエラーとする。
+/// `messages_per_minute`が設定されている場合は送信間隔を制限する
+///
+/// ## Arguments
+/// * `config` - アプリケーション設定
+/// * `thunderbird_detection` - Thunderbird実行ファイルの自動検出に使用するユースケース
+///
+/// ## Returns
+/// * 成功時 - `Ok<Box<dyn MailClientPort>>`
+/// * 失敗時 - IMAPのパスワードが未設定の場合や、署名できないメールクライアントで署名が必要な場合の`Err<AppError>`
+pub fn configured_mail_client(
+    config: &AppConfiguration,
+    thunderbird_detection: &ThunderbirdDetection,
+) -> AppResult<Box<dyn MailClientPort>> {
+    let client: Box<dyn MailClientPort> = if config.imap.is_some() {
+        Box::new(ImapDraftMailClientAdapter::from_configuration(config)?)
+    } else if config.signing.is_some() {
+        return Err(AppError::new(ErrorKind::UnprocessableEntity)
+            .with_code("MC-CONF-039")
+            .with_message("署名が設定されていますが、署名できるメールクライアントが設定されていません。")
+            .with_action(
+                "署名する場合はimapを設定してIMAPの下書きフォルダを使用してください。署名しない場合はsigningを削除してください。",
+            ));
+    } else {
+        platform_mail_client(config, thunderbird_detection)
+    };
+    Ok(match config.messages_per_minute {
+        Some(messages_per_minute) => {
+            Box::new(RateLimitedMailClient::new(client, messages_per_minute)?)
+        }
+        None => client,
+    })
+}
+
+/// 実行中のプラットフォームに応じたメールクライアントを選択する
+///
+/// 設定されたThunderbird実行ファイルが空または存在しない場合は自動検出を行う。
+/// macOSでは、Thunderbirdが検出できない場合にApple Mailを使用する
+///
+/// ## Arguments
+/// * `config` - アプリケーション設定
+/// * `thunderbird_detection` - Thunderbird実行ファイルの自動検出に使用するユースケース
+///
+/// ## Returns
+/// * 選択されたメールクライアント
+pub fn platform_mail_client(
+    config: &AppConfiguration,
+    thunderbird_detection: &ThunderbirdDetection,
+) -> Box<dyn MailClientPort> {
+    let thunderbird_exe = if Path::new(&config.thunderbird_exe).is_file() {
+        Some(config.thunderbird_exe.clone())
+    } else {
+        detect_thunderbird(thunderbird_detection)
+    };
+    let thunderbird = |path: &str| {
+        Box::new(
+            ThunderbirdMailClientAdapter::new(path)
+                .with_timeout(thunderbird_timeout(config))
+                .with_detach_on_timeout(config.thunderbird_detach_on_timeout),
+        )
+    };
+    match thunderbird_exe {
+        Some(path) => thunderbird(&path),
+        None if cfg!(target_os = "macos") => Box::new(AppleMailClientAdapter::new()),
+        // 検出できない場合は設定値のまま起動を試み、起動時のエラーで設定の修正を促す
+        None => thunderbird(&config.thunderbird_exe),
+    }
+}
+
+/// Thunderbird実行ファイルを自動検出する
+///
+/// ## Returns
+/// * 検出できた場合（設定値が`PATH`上のコマンド名として有効な場合を含む）はそのパス、それ以外は`None`
+fn detect_thunderbird(thunderbird_detection: &ThunderbirdDetection) -> Option<String> {
+    match thunderbird_detection.resolve(false) {
+        Ok(report) => Some(report.resolved),
+        Err(e) => {
+            tracing::warn!(error = %e, "Thunderbird実行ファイルを検出できませんでした");
+            None
+        }
+    }
+}
+
+/// 設定からThunderbirdプロセスの待機タイムアウトを決定する
+///
+/// ## Arguments
+/// * `config` - アプリケーション設定
+///
+/// ## Returns
+/// * タイムアウト（未設定または0秒が設定された場合は既定値）
+pub fn thunderbird_timeout(config: &AppConfiguration) -> Duration {
+    config
+        .thunderbird_timeout_secs
+        .filter(|&secs| secs > 0)
+        .map_or(DEFAULT_THUNDERBIRD_TIMEOUT, Duration::from_secs)
+}
+
+/// 署名設定から署名に使用するアダプターを作成する
+///
+/// ## Arguments
+/// * `signing` - 署名設定
+///
+/// ## Returns
+/// * 署名方式に応じたアダプター
+pub fn mail_signer(signing: &SigningConfiguration) -> Box<dyn MailSignerPort> {
+    match signing {
+        SigningConfiguration::Smime {
+            certificate,
+            private_key,
+        } => Box::new(SmimeMailSignerAdapter::new(certificate, private_key)),
+        SigningConfiguration::Gpg { key_id } => Box::new(GpgMailSignerAdapter::new(key_id.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::usecases::thunderbird_detection_use_case::ThunderbirdDetectionUseCase;
+
+    #[test]
+    fn test_reject_signing_without_imap() {
+        let dir = tempfile::tempdir().unwrap();
+        let detection = ThunderbirdDetectionUseCase::new(
+            JsonConfigurationAdapter::new(dir.path().join("app.json").to_string_lossy()),
+            ThunderbirdLocatorAdapter::new(),
+            LocalEnvironmentProbeAdapter::new(),
+        );
+        let config = AppConfiguration {
+            signing: Some(SigningConfiguration::Gpg {
+                key_id: Some("0123456789ABCDEF".to_string()),
+            }),
+            ..AppConfiguration::default()
+        };
+
+        let error = configured_mail_client(&config, &detection).err().unwrap();
+
+        assert_eq!(error.code, Some("MC-CONF-039"));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::domain::interfaces::daily_notes::DailyNotesPort;
+use chrono::NaiveDate;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{
+    fs,
+    io::ErrorKind as IoErrorKind,
+    path::{Path, PathBuf},
+};
+
+/// 日付ごとのMarkdownファイル（例: `data/notes/2024-06-03.md`）から作業メモを読み込むアウトバウンドアダプター
+pub struct MarkdownDailyNotesAdapter {
+    notes_dir: PathBuf,
+}
+
+impl MarkdownDailyNotesAdapter {
+    /// 新しいMarkdownDailyNotesAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `notes_dir` - 作業メモのディレクトリ（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * MarkdownDailyNotesAdapterのインスタンス
+    pub fn new(notes_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            notes_dir: notes_dir.into(),
+        }
+    }
+
+    /// 指定日の作業メモのファイル名を取得する
+    fn file_name(date: NaiveDate) -> String {
+        format!("{}.md", date.format("%Y-%m-%d"))
+    }
+
+    /// 作業メモのファイルを読み込む（ファイルが存在しない場合は`None`）
+    fn read_note(path: &Path) -> AppResult<Option<String>> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == IoErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-032")
+                .with_message(format!(
+                    "作業メモの読み込みに失敗しました。詳細: {}",
+                    path.display()
+                ))
+                .with_action("ファイルのアクセス権限を確認してください。")
+                .with_source(e)),
+        }
+    }
+}
+
+impl DailyNotesPort for MarkdownDailyNotesAdapter {
+    fn load_note(&self, date: NaiveDate) -> AppResult<Option<String>> {
+        let path = workspace_path(self.notes_dir.join(Self::file_name(date)))?;
+        let note = Self::read_note(&path)?
+            .map(|content| content.replace("\r\n", "\n").trim().to_string())
+            .filter(|content| !content.is_empty());
+        Ok(note)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_note() {
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_daily_notes_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("2024-06-03.md"), "- 資料作成\r\n- レビュー\r\n").unwrap();
+        fs::write(dir.join("2024-06-04.md"), "\n").unwrap();
+        let adapter = MarkdownDailyNotesAdapter::new(&dir);
+
+        let note = adapter.load_note(NaiveDate::from_ymd_opt(2024, 6, 3).unwrap());
+        let empty = adapter.load_note(NaiveDate::from_ymd_opt(2024, 6, 4).unwrap());
+        let missing = adapter.load_note(NaiveDate::from_ymd_opt(2024, 6, 5).unwrap());
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(note.unwrap().as_deref(), Some("- 資料作成\n- レビュー"));
+        assert_eq!(empty.unwrap(), None);
+        assert_eq!(missing.unwrap(), None);
+    }
+}
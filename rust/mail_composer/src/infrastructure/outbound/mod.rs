@@ -1,5 +1,43 @@
+pub mod apple_mail_client_adapter;
+pub mod caching_address_book_adapter;
+pub mod command_line_override_configuration_adapter;
+pub mod composite_address_book_adapter;
+pub mod csv_work_time_export_adapter;
+pub mod desktop_notification_adapter;
+pub mod dry_run_preview_mail_client_adapter;
+pub mod eml_message_renderer;
+pub mod encrypted_field_configuration_adapter;
+pub mod env_override_configuration_adapter;
+pub mod external_editor_adapter;
+pub mod gpg_mail_signer_adapter;
+pub mod hot_reload_configuration_adapter;
+pub mod http_configuration_adapter;
+pub mod imap_draft_mail_client_adapter;
+pub mod japanese_holiday_calendar_adapter;
 pub mod json_address_book_adapter;
 pub mod json_configuration_adapter;
 pub mod json_mail_config_adapter;
+pub mod json_outbox_adapter;
 pub mod json_work_time_adapter;
+pub mod jsonl_work_time_adapter;
+pub mod ldap_address_book_adapter;
+pub mod local_environment_probe_adapter;
+pub mod mail_client_factory;
+pub mod markdown_daily_notes_adapter;
+pub mod openssl_secret_cipher_adapter;
+pub mod outlook_csv_address_book_adapter;
+pub mod preview_mail_client_adapter;
+pub mod queueing_mail_client_adapter;
+pub mod rate_limited_mail_client;
+pub mod smime_mail_signer_adapter;
+pub mod sqlite_work_time_adapter;
+pub mod terminal_confirmation_adapter;
+pub mod terminal_notification_adapter;
+pub mod terminal_prompt_adapter;
+pub mod thunderbird_address_book_adapter;
+pub mod thunderbird_locator_adapter;
 pub mod thunderbird_mail_client_adapter;
+pub mod time_tracker_csv_adapter;
+pub mod vcf_address_book_adapter;
+pub mod xlsx_address_book_adapter;
+pub mod xlsx_work_time_export_adapter;
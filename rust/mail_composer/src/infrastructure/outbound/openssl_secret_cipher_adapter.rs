@@ -0,0 +1,232 @@
+use crate::{APP_NAME, domain::interfaces::secret_cipher::SecretCipherPort};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// 暗号鍵を指定する環境変数名
+pub const CONFIG_KEY_ENV: &str = "MAIL_COMPOSER_CONFIG_KEY";
+
+/// OSのキーリングに暗号鍵を登録する際のアカウント名
+const KEYRING_ACCOUNT: &str = "config_key";
+
+/// 暗号鍵を取得する関数
+pub type KeyLookup = fn() -> Option<String>;
+
+/// OpenSSL（AES-256-CBC、PBKDF2による鍵導出）で機密値を暗号化・復号するアウトバウンドアダプター
+///
+/// 暗号鍵は環境変数`MAIL_COMPOSER_CONFIG_KEY`、未設定の場合はOSのキーリング
+/// （Linuxは`secret-tool`、macOSは`security`）から取得する
+pub struct OpenSslSecretCipherAdapter {
+    openssl_exe_path: String,
+    key_lookup: KeyLookup,
+}
+
+impl OpenSslSecretCipherAdapter {
+    /// 新しいOpenSslSecretCipherAdapterを作成する
+    ///
+    /// ## Returns
+    /// * OpenSslSecretCipherAdapterのインスタンス
+    pub fn new() -> Self {
+        Self {
+            openssl_exe_path: "openssl".to_string(),
+            key_lookup: || {
+                std::env::var(CONFIG_KEY_ENV)
+                    .ok()
+                    .filter(|key| !key.is_empty())
+                    .or_else(Self::key_from_keyring)
+            },
+        }
+    }
+
+    /// OpenSSL実行ファイルのパスを指定する
+    ///
+    /// ## Arguments
+    /// * `openssl_exe_path` - OpenSSL実行ファイルのパス
+    ///
+    /// ## Returns
+    /// * OpenSSL実行ファイルのパスが設定されたOpenSslSecretCipherAdapterのインスタンス
+    pub fn with_openssl_exe(mut self, openssl_exe_path: impl Into<String>) -> Self {
+        self.openssl_exe_path = openssl_exe_path.into();
+        self
+    }
+
+    /// 暗号鍵の取得方法を指定する
+    ///
+    /// ## Arguments
+    /// * `key_lookup` - 暗号鍵を取得する関数
+    ///
+    /// ## Returns
+    /// * 暗号鍵の取得方法が設定されたOpenSslSecretCipherAdapterのインスタンス
+    pub fn with_key_lookup(mut self, key_lookup: KeyLookup) -> Self {
+        self.key_lookup = key_lookup;
+        self
+    }
+
+    /// OSのキーリングから暗号鍵を取得する
+    fn key_from_keyring() -> Option<String> {
+        let mut command = if cfg!(target_os = "macos") {
+            let mut command = Command::new("security");
+            command.args([
+                "find-generic-password",
+                "-s",
+                APP_NAME,
+                "-a",
+                KEYRING_ACCOUNT,
+                "-w",
+            ]);
+            command
+        } else if cfg!(unix) {
+            let mut command = Command::new("secret-tool");
+            command.args(["lookup", "service", APP_NAME, "account", KEYRING_ACCOUNT]);
+            command
+        } else {
+            return None;
+        };
+
+        let output = command.stderr(Stdio::null()).output().ok()?;
+        let key = String::from_utf8(output.stdout).ok()?;
+        let key = key.trim_end_matches(['\r', '\n']);
+        (output.status.success() && !key.is_empty()).then(|| key.to_string())
+    }
+
+    /// 暗号鍵を取得する
+    fn key(&self) -> AppResult<String> {
+        (self.key_lookup)().ok_or_else(|| {
+            AppError::new(ErrorKind::Unauthorized)
+                .with_code("MC-CONF-030")
+                .with_message("設定値の暗号鍵が見つかりません。")
+                .with_action(format!(
+                    "環境変数{CONFIG_KEY_ENV}に暗号鍵を設定するか、OSのキーリングにサービス名'{APP_NAME}'、アカウント名'{KEYRING_ACCOUNT}'で登録してください。"
+                ))
+        })
+    }
+
+    /// OpenSSLに入力を渡して実行する
+    fn run_openssl(&self, decrypt: bool, input: &str) -> AppResult<String> {
+        let key = self.key()?;
+        let mut command = Command::new(&self.openssl_exe_path);
+        command.args(["enc", "-aes-256-cbc", "-pbkdf2", "-a", "-A", "-salt"]);
+        if decrypt {
+            command.arg("-d");
+        }
+        // 暗号鍵はコマンドライン引数に残らないよう、環境変数で受け渡す
+        let mut child = command
+            .args(["-pass", &format!("env:{CONFIG_KEY_ENV}")])
+            .env(CONFIG_KEY_ENV, key)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-CONF-031")
+                    .with_message("OpenSSLの起動に失敗しました。")
+                    .with_action(
+                        "OpenSSLがインストールされ、PATHが通っていることを確認してください。",
+                    )
+                    .with_source(e)
+            })?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            let input = if decrypt {
+                format!("{input}\n")
+            } else {
+                input.to_string()
+            };
+            stdin.write_all(input.as_bytes()).map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-CONF-032")
+                    .with_message("OpenSSLへの入力の受け渡しに失敗しました。")
+                    .with_action("システムリソースを確認してください。")
+                    .with_source(e)
+            })?;
+        }
+
+        let output = child.wait_with_output().map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CONF-033")
+                .with_message("OpenSSLプロセスの待機に失敗しました。")
+                .with_action("システムリソースを確認してください。")
+                .with_source(e)
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let message = if decrypt {
+                format!("設定値の復号に失敗しました。詳細: {}", stderr.trim())
+            } else {
+                format!("設定値の暗号化に失敗しました。詳細: {}", stderr.trim())
+            };
+            return Err(AppError::new(ErrorKind::Unauthorized)
+                .with_code("MC-CONF-034")
+                .with_message(message)
+                .with_action("暗号鍵が暗号化したときと同じであることを確認してください。"));
+        }
+
+        let text = String::from_utf8(output.stdout).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CONF-035")
+                .with_message("OpenSSLの出力の解析に失敗しました。")
+                .with_action("暗号化した値がUTF-8の文字列であることを確認してください。")
+                .with_source(e)
+        })?;
+        Ok(if decrypt {
+            text
+        } else {
+            text.trim_end().to_string()
+        })
+    }
+}
+
+impl Default for OpenSslSecretCipherAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecretCipherPort for OpenSslSecretCipherAdapter {
+    fn encrypt(&self, plaintext: &str) -> AppResult<String> {
+        self.run_openssl(false, plaintext)
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> AppResult<String> {
+        self.run_openssl(true, ciphertext.trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let cipher =
+            OpenSslSecretCipherAdapter::new().with_key_lookup(|| Some("test-key".to_string()));
+
+        let encrypted = match cipher.encrypt("秘密のパスワード") {
+            Ok(encrypted) => encrypted,
+            Err(e) => {
+                println!("⚠️  OpenSSL not available - skipping test: {}", e);
+                return;
+            }
+        };
+
+        assert_ne!(encrypted, "秘密のパスワード");
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "秘密のパスワード");
+
+        let wrong_key =
+            OpenSslSecretCipherAdapter::new().with_key_lookup(|| Some("other-key".to_string()));
+        assert!(wrong_key.decrypt(&encrypted).is_err());
+    }
+
+    #[test]
+    fn test_missing_key() {
+        let cipher = OpenSslSecretCipherAdapter::new().with_key_lookup(|| None);
+        assert!(cipher.decrypt("U2FsdGVkX1").is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::domain::{
+    entities::address_book::{AddressBook, AddressBookEntry},
+    interfaces::address_book::AddressBookPort,
+    value_objects::{address_query::AddressQuery, email_address::EmailAddress},
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{collections::HashSet, fs, path::Path};
+
+/// 表示名の列の見出し（英語版・日本語版Outlook）
+const DISPLAY_NAME_COLUMNS: [&str; 2] = ["E-mail Display Name", "電子メール 表示名"];
+/// 名の列の見出し
+const FIRST_NAME_COLUMNS: [&str; 2] = ["First Name", "名"];
+/// 姓の列の見出し
+const LAST_NAME_COLUMNS: [&str; 2] = ["Last Name", "姓"];
+/// メールアドレスの列の見出し（優先順）
+const EMAIL_COLUMNS: [&str; 4] = [
+    "E-mail Address",
+    "電子メール アドレス",
+    "E-mail 2 Address",
+    "電子メール 2 アドレス",
+];
+
+/// Outlookからエクスポートした連絡先CSVをアドレスブックとして扱うアウトバウンドアダプター
+///
+/// 表示名（未設定の場合は姓名）を名前、電子メールアドレスをメールアドレスとして読み込む
+pub struct OutlookCsvAddressBookAdapter {
+    book: AddressBook,
+}
+
+impl OutlookCsvAddressBookAdapter {
+    /// 指定されたパスから連絡先CSVを読み込む
+    ///
+    /// ## Arguments
+    /// * `csv_path` - 連絡先CSVのパスを表現する`Path`（ワークスペースルートからの相対パス）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<OutlookCsvAddressBookAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn load_from_csv(csv_path: &Path) -> AppResult<Self> {
+        let path = workspace_path(csv_path)?;
+        let content = fs::read_to_string(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-ADDR-025")
+                .with_message("連絡先CSVの読み込みに失敗しました。")
+                .with_action("ファイルパスの存在とアクセス権限、文字コードがUTF-8であることを確認してください。")
+                .with_source(e)
+        })?;
+
+        Self::from_csv_str(&content)
+    }
+
+    /// CSV形式の文字列からアダプターを作成する
+    ///
+    /// 名前またはメールアドレスがない行と、既出の名前の行は読み飛ばす
+    ///
+    /// ## Arguments
+    /// * `content` - Outlook形式の連絡先CSV
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<OutlookCsvAddressBookAdapter>`
+    /// * 失敗時 - CSVの形式が不正な場合の`Err<AppError>`
+    pub fn from_csv_str(content: &str) -> AppResult<Self> {
+        let csv_error = |e: csv::Error| {
+            AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-ADDR-026")
+                .with_message("連絡先CSVの解析に失敗しました。")
+                .with_action("Outlookの「エクスポート」で出力したCSVファイルを指定してください。")
+                .with_source(e)
+        };
+
+        let content = content.trim_start_matches('\u{feff}');
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(content.as_bytes());
+        let headers = reader.headers().map_err(csv_error)?.clone();
+        let column = |candidates: &[&str]| -> Vec<usize> {
+            candidates
+                .iter()
+                .filter_map(|candidate| headers.iter().position(|h| h.trim() == *candidate))
+                .collect()
+        };
+        let display_name_columns = column(&DISPLAY_NAME_COLUMNS);
+        let first_name_columns = column(&FIRST_NAME_COLUMNS);
+        let last_name_columns = column(&LAST_NAME_COLUMNS);
+        let email_columns = column(&EMAIL_COLUMNS);
+        if email_columns.is_empty() {
+            return Err(AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-ADDR-027")
+                .with_message("連絡先CSVに電子メールアドレスの列が見つかりません。")
+                .with_action(
+                    "Outlookの「エクスポート」で出力したCSVファイルを指定してください。",
+                ));
+        }
+
+        let mut names = HashSet::new();
+        let mut entries = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            let first_value = |columns: &[usize]| {
+                columns
+                    .iter()
+                    .filter_map(|&i| record.get(i))
+                    .map(str::trim)
+                    .find(|value| !value.is_empty())
+                    .map(str::to_string)
+            };
+
+            let name = first_value(&display_name_columns).or_else(|| {
+                let full_name = [
+                    first_value(&last_name_columns),
+                    first_value(&first_name_columns),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+                (!full_name.is_empty()).then_some(full_name)
+            });
+            let (Some(name), Some(email)) = (name, first_value(&email_columns)) else {
+                continue;
+            };
+            if names.insert(name.clone()) {
+                entries.push(AddressBookEntry::person(name, email));
+            }
+        }
+
+        Ok(Self {
+            book: AddressBook::from_entries(entries)?,
+        })
+    }
+
+    /// 全てのエントリを取得する
+    ///
+    /// ## Returns
+    /// * 全てのAddressBookエントリのスライス
+    pub fn entries(&self) -> &[AddressBookEntry] {
+        self.book.entries()
+    }
+}
+
+impl AddressBookPort for OutlookCsvAddressBookAdapter {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        self.book.resolve(key_name)
+    }
+
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.entries().to_vec())
+    }
+
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.search(query)?.into_iter().cloned().collect())
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        Ok(self.book.reverse_resolve(address).map(str::to_string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv_str() {
+        let csv = "\u{feff}First Name,Middle Name,Last Name,E-mail Address,E-mail 2 Address,E-mail Display Name\n\
+                   太郎,,山田,yamada@example.com,,\n\
+                   花子,,佐藤,,sato@example.com,佐藤 花子（営業部）\n\
+                   次郎,,鈴木,,,\n";
+
+        let adapter = OutlookCsvAddressBookAdapter::from_csv_str(csv).unwrap();
+
+        assert_eq!(adapter.entries().len(), 2);
+        assert_eq!(
+            adapter.resolve("山田 太郎").unwrap().as_str(),
+            "yamada@example.com"
+        );
+        assert_eq!(
+            adapter.resolve("佐藤 花子（営業部）").unwrap().as_str(),
+            "sato@example.com"
+        );
+    }
+
+    #[test]
+    fn test_from_csv_str_japanese_headers() {
+        let csv = "姓,名,電子メール アドレス\n山田,太郎,yamada@example.com\n";
+
+        let adapter = OutlookCsvAddressBookAdapter::from_csv_str(csv).unwrap();
+
+        assert_eq!(
+            adapter.resolve("山田 太郎").unwrap().as_str(),
+            "yamada@example.com"
+        );
+    }
+}
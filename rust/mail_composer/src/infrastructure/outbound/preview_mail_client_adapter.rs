@@ -0,0 +1,310 @@
+use crate::domain::{
+    entities::mail_draft::MailDraft, interfaces::mail_client::MailClientPort,
+    value_objects::email_address::EmailAddress,
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::ensure_directory_exists,
+};
+use std::{fs, path::PathBuf};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// 枠内の既定の表示幅（半角文字数）
+pub const DEFAULT_PREVIEW_WIDTH: usize = 72;
+/// 枠内の最小の表示幅（半角文字数）
+const MIN_PREVIEW_WIDTH: usize = 32;
+/// `preview`コマンドで枠の上端に表示する見出し
+const PREVIEW_TITLE: &str = " PREVIEW ";
+/// 項目名と値の間の空白
+const LABEL_GAP: &str = "  ";
+
+/// プレビューの出力先
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewOutput {
+    /// 標準出力に表示する
+    Stdout,
+    /// 指定されたファイルに書き出す
+    File(PathBuf),
+}
+
+/// メールドラフトを送信せずに整形して出力するアウトバウンドアダプター
+pub struct PreviewMailClientAdapter {
+    output: PreviewOutput,
+    width: usize,
+}
+
+impl PreviewMailClientAdapter {
+    /// 新しいPreviewMailClientAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `output` - プレビューの出力先
+    ///
+    /// ## Returns
+    /// * PreviewMailClientAdapterのインスタンス
+    pub fn new(output: PreviewOutput) -> Self {
+        Self {
+            output,
+            width: DEFAULT_PREVIEW_WIDTH,
+        }
+    }
+
+    /// 標準出力に表示するアダプターを作成する
+    ///
+    /// ## Returns
+    /// * 標準出力に表示するPreviewMailClientAdapterのインスタンス
+    pub fn stdout() -> Self {
+        Self::new(PreviewOutput::Stdout)
+    }
+
+    /// 枠内の表示幅を指定する
+    ///
+    /// ## Arguments
+    /// * `width` - 枠内の表示幅（半角文字数、32未満の場合は32）
+    ///
+    /// ## Returns
+    /// * 表示幅が設定されたPreviewMailClientAdapterのインスタンス
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width.max(MIN_PREVIEW_WIDTH);
+        self
+    }
+}
+
+impl MailClientPort for PreviewMailClientAdapter {
+    fn compose_mail(&self, draft: &MailDraft, _is_dry_run: bool) -> AppResult<()> {
+        let rendered = render_preview(draft, PREVIEW_TITLE, self.width);
+
+        match &self.output {
+            PreviewOutput::Stdout => {
+                println!("{rendered}");
+                Ok(())
+            }
+            PreviewOutput::File(path) => {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    ensure_directory_exists(parent)?;
+                }
+                fs::write(path, rendered).map_err(|e| {
+                    AppError::new(ErrorKind::InternalServerError)
+                        .with_code("MC-MAIL-046")
+                        .with_message("プレビューファイルの書き込みに失敗しました。")
+                        .with_action("出力先のパスとアクセス権限を確認してください。")
+                        .with_source(e)
+                })
+            }
+        }
+    }
+}
+
+/// メールドラフトを枠で囲んだプレビューに整形する
+///
+/// `preview`コマンドとドライランの表示で共通して使用する
+///
+/// ## Arguments
+/// * `draft` - 整形対象のメールドラフト
+/// * `title` - 枠の上端に表示する見出し
+/// * `width` - 枠内の表示幅（半角文字数、32未満の場合は32）
+///
+/// ## Returns
+/// * 整形されたテキスト
+pub fn render_preview(draft: &MailDraft, title: &str, width: usize) -> String {
+    let width = width.max(MIN_PREVIEW_WIDTH);
+    // 枠内の各行（`None`は区切り線）
+    let mut content = recipient_table(draft);
+
+    content.push(None);
+    content.extend(labeled_lines("件名", draft.subject().as_str(), 4, width));
+    for (name, value) in draft.headers() {
+        content.extend(labeled_lines(name, value, name.width(), width));
+    }
+    for attachment in draft.attachments() {
+        content.extend(labeled_lines(
+            "添付",
+            &attachment.display().to_string(),
+            4,
+            width,
+        ));
+    }
+
+    if !draft.details().is_empty() {
+        content.push(None);
+        let label_width = draft
+            .details()
+            .iter()
+            .map(|(name, _)| name.width())
+            .max()
+            .unwrap_or(0);
+        for (name, value) in draft.details() {
+            content.extend(labeled_lines(name, value, label_width, width));
+        }
+    }
+
+    content.push(None);
+    content.extend(wrap(draft.body().as_str(), width).into_iter().map(Some));
+
+    let mut lines = vec![format!(
+        "╭─{title}{}╮",
+        "─".repeat((width + 1).saturating_sub(title.width()))
+    )];
+    for line in content {
+        match line {
+            Some(text) => lines.push(format!("│ {} │", pad(&text, width))),
+            None => lines.push(format!("├{}┤", "─".repeat(width + 2))),
+        }
+    }
+    lines.push(format!("╰{}╯", "─".repeat(width + 2)));
+    lines.join("\n")
+}
+
+/// 宛先を種別・名前・メールアドレスの表に整形する
+fn recipient_table(draft: &MailDraft) -> Vec<Option<String>> {
+    let row = |kind: &str, address: &EmailAddress| {
+        [
+            kind.to_string(),
+            address.display_name().unwrap_or("-").to_string(),
+            address.as_str().to_string(),
+        ]
+    };
+    let rows: Vec<[String; 3]> = draft
+        .to()
+        .iter()
+        .map(|address| row("To", address))
+        .chain(draft.cc().iter().map(|address| row("Cc", address)))
+        .collect();
+    if rows.is_empty() {
+        return vec![Some(format!("宛先{LABEL_GAP}(なし)"))];
+    }
+
+    let header = [
+        "種別".to_string(),
+        "名前".to_string(),
+        "アドレス".to_string(),
+    ];
+    let widths: Vec<usize> = (0..3)
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[column].width())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let format_row = |row: &[String; 3]| {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| pad(cell, *width))
+            .collect::<Vec<_>>()
+            .join(LABEL_GAP);
+        Some(line.trim_end().to_string())
+    };
+
+    let mut lines = vec![format_row(&header)];
+    lines.extend(rows.iter().map(format_row));
+    lines
+}
+
+/// 項目名と値を1行に並べ、値が収まらない場合は値の位置に揃えて折り返す
+fn labeled_lines(
+    label: &str,
+    value: &str,
+    label_width: usize,
+    width: usize,
+) -> Vec<Option<String>> {
+    let indent = label_width + LABEL_GAP.width();
+    let value_width = width.saturating_sub(indent).max(1);
+    wrap(value, value_width)
+        .into_iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let head = if index == 0 {
+                format!("{}{LABEL_GAP}", pad(label, label_width))
+            } else {
+                " ".repeat(indent)
+            };
+            Some(format!("{head}{line}"))
+        })
+        .collect()
+}
+
+/// テキストを表示幅で折り返す（全角文字は2文字分として数える）
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for source in text.replace('\t', "    ").lines() {
+        let mut line = String::new();
+        let mut line_width = 0;
+        for ch in source.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if line_width + ch_width > width && !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(ch);
+            line_width += ch_width;
+        }
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// 表示幅が指定した幅になるまで末尾に空白を追加する
+fn pad(text: &str, width: usize) -> String {
+    format!("{text}{}", " ".repeat(width.saturating_sub(text.width())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::mail_objects::{MailBody, Subject};
+
+    fn sample_draft() -> MailDraft {
+        MailDraft::new(
+            vec![
+                EmailAddress::parse("yamada@example.com")
+                    .unwrap()
+                    .with_display_name("山田"),
+            ],
+            vec![EmailAddress::parse("boss@example.com").unwrap()],
+            Subject::new("【在宅勤務終了】開発部 佐藤").unwrap(),
+            MailBody::new(format!("お疲れ様です。\n{}", "あ".repeat(40))),
+        )
+        .with_header("Reply-To", "reply@example.com")
+        .unwrap()
+        .with_detail("作業時間", "09:00-18:00")
+        .with_detail("実働", "8:00")
+    }
+
+    #[test]
+    fn test_render_preview() {
+        let preview = render_preview(&sample_draft(), PREVIEW_TITLE, 40);
+        let lines: Vec<&str> = preview.lines().collect();
+
+        assert!(lines[0].starts_with("╭─ PREVIEW ─"));
+        assert!(lines.iter().all(|line| line.width() == 44));
+        assert!(preview.contains("To    山田  yamada@example.com"));
+        assert!(preview.contains("Cc    -     boss@example.com"));
+        assert!(preview.contains("件名  【在宅勤務終了】開発部 佐藤"));
+        assert!(preview.contains("Reply-To  reply@example.com"));
+        assert!(preview.contains("作業時間  09:00-18:00"));
+        assert!(preview.contains("実働      8:00"));
+        assert!(preview.contains(&format!("│ {} │", "あ".repeat(20))));
+    }
+
+    #[test]
+    fn test_preview_to_file() {
+        let path = std::env::temp_dir()
+            .join(format!("mail_composer_preview_{}", std::process::id()))
+            .join("preview.txt");
+        let adapter = PreviewMailClientAdapter::new(PreviewOutput::File(path.clone()));
+
+        adapter.compose_mail(&sample_draft(), false).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+        assert!(content.contains("件名  【在宅勤務終了】開発部 佐藤"));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::domain::{
+    entities::mail_draft::MailDraft,
+    interfaces::{mail_client::MailClientPort, outbox::OutboxPort},
+};
+use share::error::app_error::AppResult;
+
+/// 送信に失敗したメールドラフトを送信待ちキューに退避するメールクライアントのデコレーター
+///
+/// 退避するのは再試行で解消する見込みがある失敗（[`AppError::is_retryable`](share::error::app_error::AppError::is_retryable)）のみで、
+/// メールクライアントの起動や終了の失敗は再試行できるものとして退避し、
+/// 宛先や設定の誤りなど再送しても失敗するエラーはそのまま返す。
+/// `queue_only`が有効な場合は送信を試みず、常に送信待ちキューに保存する
+pub struct QueueingMailClientAdapter<M, O>
+where
+    M: MailClientPort,
+    O: OutboxPort,
+{
+    inner: M,
+    outbox: O,
+    queue_only: bool,
+}
+
+impl<M, O> QueueingMailClientAdapter<M, O>
+where
+    M: MailClientPort,
+    O: OutboxPort,
+{
+    /// 新しいQueueingMailClientAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - 実際の送信を行うメールクライアント
+    /// * `outbox` - 送信待ちキュー
+    /// * `queue_only` - 送信を試みずにキューへ保存する場合は`true`
+    ///
+    /// ## Returns
+    /// * QueueingMailClientAdapterのインスタンス
+    pub fn new(inner: M, outbox: O, queue_only: bool) -> Self {
+        Self {
+            inner,
+            outbox,
+            queue_only,
+        }
+    }
+}
+
+impl<M, O> MailClientPort for QueueingMailClientAdapter<M, O>
+where
+    M: MailClientPort,
+    O: OutboxPort,
+{
+    fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        if is_dry_run {
+            return self.inner.compose_mail(draft, is_dry_run);
+        }
+
+        if self.queue_only {
+            let id = self.outbox.enqueue(draft)?;
+            tracing::info!(%id, "メールを送信待ちキューに保存しました。");
+            return Ok(());
+        }
+
+        match self.inner.compose_mail(draft, is_dry_run) {
+            Ok(()) => Ok(()),
+            Err(e) if e.is_retryable() => {
+                let id = self.outbox.enqueue(draft)?;
+                tracing::warn!(
+                    %id,
+                    error = %e,
+                    "メールの送信に失敗したため、送信待ちキューに保存しました。"
+                );
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::outbox_item::OutboxItem,
+        value_objects::{
+            email_address::EmailAddress,
+            mail_objects::{MailBody, Subject},
+        },
+    };
+    use share::error::{app_error::AppError, kind::ErrorKind};
+    use std::sync::Mutex;
+
+    struct FailingMailClient {
+        kind: ErrorKind,
+    }
+
+    impl MailClientPort for FailingMailClient {
+        fn compose_mail(&self, _draft: &MailDraft, _is_dry_run: bool) -> AppResult<()> {
+            Err(AppError::new(self.kind))
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryOutbox {
+        enqueued: Mutex<usize>,
+    }
+
+    impl OutboxPort for MemoryOutbox {
+        fn enqueue(&self, _draft: &MailDraft) -> AppResult<String> {
+            *self.enqueued.lock().unwrap() += 1;
+            Ok("queued".to_string())
+        }
+
+        fn list(&self) -> AppResult<Vec<OutboxItem>> {
+            Ok(vec![])
+        }
+
+        fn remove(&self, _id: &str) -> AppResult<()> {
+            Ok(())
+        }
+    }
+
+    fn draft() -> MailDraft {
+        MailDraft::new(
+            vec![EmailAddress::parse("test@example.com").unwrap()],
+            vec![],
+            Subject::new("テスト").unwrap(),
+            MailBody::new("テスト本文"),
+        )
+    }
+
+    #[test]
+    fn test_queue_only_retryable_failures() {
+        let transient = QueueingMailClientAdapter::new(
+            FailingMailClient {
+                kind: ErrorKind::ServiceUnavailable,
+            },
+            MemoryOutbox::default(),
+            false,
+        );
+        assert!(transient.compose_mail(&draft(), false).is_ok());
+        assert_eq!(*transient.outbox.enqueued.lock().unwrap(), 1);
+
+        let permanent = QueueingMailClientAdapter::new(
+            FailingMailClient {
+                kind: ErrorKind::BadRequest,
+            },
+            MemoryOutbox::default(),
+            false,
+        );
+        let result = permanent.compose_mail(&draft(), false);
+        assert_eq!(result.unwrap_err().kind, ErrorKind::BadRequest);
+        assert_eq!(*permanent.outbox.enqueued.lock().unwrap(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_queue_thunderbird_failures() {
+        use crate::infrastructure::outbound::thunderbird_mail_client_adapter::ThunderbirdMailClientAdapter;
+        use std::os::unix::fs::PermissionsExt;
+
+        // 起動はできるがエラー終了するThunderbirdの代わり
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("thunderbird.sh");
+        std::fs::write(&script, "#!/bin/sh\nexit 1\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let failing = QueueingMailClientAdapter::new(
+            ThunderbirdMailClientAdapter::new(script.to_string_lossy()),
+            MemoryOutbox::default(),
+            false,
+        );
+        let queued = failing.compose_mail(&draft(), false);
+        assert!(queued.is_ok());
+        assert_eq!(*failing.outbox.enqueued.lock().unwrap(), 1);
+
+        // 実行ファイルがない場合は再送しても失敗するため、キューに保存しない
+        let missing = QueueingMailClientAdapter::new(
+            ThunderbirdMailClientAdapter::new("mail_composer_no_such_thunderbird"),
+            MemoryOutbox::default(),
+            false,
+        );
+        let result = missing.compose_mail(&draft(), false);
+        assert_eq!(result.unwrap_err().code, Some("MC-MAIL-053"));
+        assert_eq!(*missing.outbox.enqueued.lock().unwrap(), 0);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::domain::{entities::mail_draft::MailDraft, interfaces::mail_client::MailClientPort};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// 送信間隔を制限するメールクライアントのデコレーター
+///
+/// 1分あたりの送信数を超えないよう、前回の送信から一定間隔が空くまで待機してから送信する
+pub struct RateLimitedMailClient<M>
+where
+    M: MailClientPort,
+{
+    inner: M,
+    interval: Duration,
+    last_sent: Mutex<Option<Instant>>,
+}
+
+impl<M> RateLimitedMailClient<M>
+where
+    M: MailClientPort,
+{
+    /// 新しいRateLimitedMailClientを作成する
+    ///
+    /// ## Arguments
+    /// * `inner` - 実際の送信を行うメールクライアント
+    /// * `messages_per_minute` - 1分あたりの最大送信数
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<RateLimitedMailClient>`
+    /// * 失敗時 - 最大送信数が0の場合の`Err<AppError>`
+    pub fn new(inner: M, messages_per_minute: u32) -> AppResult<Self> {
+        if messages_per_minute == 0 {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-MAIL-047")
+                .with_message("1分あたりの最大送信数には1以上を指定してください。")
+                .with_action("config.jsonのmessages_per_minuteフィールドを確認してください。"));
+        }
+
+        Ok(Self {
+            inner,
+            interval: Duration::from_secs(60) / messages_per_minute,
+            last_sent: Mutex::new(None),
+        })
+    }
+}
+
+impl<M> MailClientPort for RateLimitedMailClient<M>
+where
+    M: MailClientPort,
+{
+    fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        if is_dry_run {
+            return self.inner.compose_mail(draft, is_dry_run);
+        }
+
+        // 送信中もロックを保持し、複数スレッドからの送信も間隔を空けて直列化する
+        let mut last_sent = self.last_sent.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(last) = *last_sent {
+            let wait = self.interval.saturating_sub(last.elapsed());
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+        }
+
+        let result = self.inner.compose_mail(draft, is_dry_run);
+        *last_sent = Some(Instant::now());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        email_address::EmailAddress,
+        mail_objects::{MailBody, Subject},
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingMailClient {
+        count: AtomicUsize,
+    }
+
+    impl MailClientPort for CountingMailClient {
+        fn compose_mail(&self, _draft: &MailDraft, _is_dry_run: bool) -> AppResult<()> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let inner = CountingMailClient {
+            count: AtomicUsize::new(0),
+        };
+        // 600通/分 = 100ミリ秒間隔
+        let client = RateLimitedMailClient::new(inner, 600).unwrap();
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("test@example.com").unwrap()],
+            vec![],
+            Subject::new("テスト").unwrap(),
+            MailBody::new("テスト本文"),
+        );
+
+        let started = Instant::now();
+        for _ in 0..3 {
+            client.compose_mail(&draft, false).unwrap();
+        }
+
+        assert_eq!(client.inner.count.load(Ordering::SeqCst), 3);
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_zero_rate_is_rejected() {
+        let inner = CountingMailClient {
+            count: AtomicUsize::new(0),
+        };
+        assert!(RateLimitedMailClient::new(inner, 0).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::domain::interfaces::mail_signer::MailSignerPort;
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    logging,
+};
+use std::{
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// OpenSSLの出力の先頭に付与されるヘッダー（メッセージのヘッダーに含めるため取り除く）
+const MIME_VERSION_HEADER: &str = "MIME-Version: 1.0\r\n";
+
+/// OpenSSLでMIMEエンティティにS/MIME分離署名を行い、`multipart/signed`のエンティティを作成するアウトバウンドアダプター
+///
+/// 署名対象のエンティティは標準入力で受け渡すため、一時ファイルは作成しない
+pub struct SmimeMailSignerAdapter {
+    openssl_exe_path: String,
+    certificate: PathBuf,
+    private_key: PathBuf,
+}
+
+impl SmimeMailSignerAdapter {
+    /// 新しいSmimeMailSignerAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `certificate` - 署名に使用する証明書（PEM形式）のパス
+    /// * `private_key` - 署名に使用する秘密鍵（PEM形式）のパス
+    ///
+    /// ## Returns
+    /// * SmimeMailSignerAdapterのインスタンス
+    pub fn new(certificate: impl Into<PathBuf>, private_key: impl Into<PathBuf>) -> Self {
+        Self {
+            openssl_exe_path: "openssl".to_string(),
+            certificate: certificate.into(),
+            private_key: private_key.into(),
+        }
+    }
+
+    /// OpenSSL実行ファイルのパスを指定する
+    ///
+    /// ## Arguments
+    /// * `openssl_exe_path` - OpenSSL実行ファイルのパス
+    ///
+    /// ## Returns
+    /// * OpenSSL実行ファイルのパスが設定されたSmimeMailSignerAdapterのインスタンス
+    pub fn with_openssl_exe(mut self, openssl_exe_path: impl Into<String>) -> Self {
+        self.openssl_exe_path = openssl_exe_path.into();
+        self
+    }
+}
+
+impl MailSignerPort for SmimeMailSignerAdapter {
+    fn sign(&self, entity: &str) -> AppResult<String> {
+        let _span = logging::operation_span("smime_sign").entered();
+        // エンティティは既にCRLF改行のため、-binaryで変換せずにそのまま署名する
+        let mut child = Command::new(&self.openssl_exe_path)
+            .args(["smime", "-sign", "-binary", "-crlfeol", "-md", "sha256"])
+            .arg("-signer")
+            .arg(&self.certificate)
+            .arg("-inkey")
+            .arg(&self.private_key)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-MAIL-049")
+                    .with_message("OpenSSLの起動に失敗しました。")
+                    .with_action(
+                        "OpenSSLがインストールされ、PATHが通っていることを確認してください。",
+                    )
+                    .with_source(e)
+            })?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(entity.as_bytes()).map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-MAIL-048")
+                    .with_message("OpenSSLへの署名対象の受け渡しに失敗しました。")
+                    .with_action("システムリソースを確認してください。")
+                    .with_source(e)
+            })?;
+        }
+
+        let output = child.wait_with_output().map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-056")
+                .with_message("OpenSSLプロセスの待機に失敗しました。")
+                .with_action("システムリソースを確認してください。")
+                .with_source(e)
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-050")
+                .with_message(format!("S/MIME署名に失敗しました。詳細: {}", stderr.trim()))
+                .with_action(
+                    "config.jsonのsigningに設定した証明書と秘密鍵のパスを確認してください。",
+                ));
+        }
+
+        let signed = String::from_utf8(output.stdout).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-057")
+                .with_message("OpenSSLの出力の解析に失敗しました。")
+                .with_action("OpenSSLのバージョンを確認してください。")
+                .with_source(e)
+        })?;
+        Ok(signed
+            .strip_prefix(MIME_VERSION_HEADER)
+            .map(str::to_string)
+            .unwrap_or(signed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_sign_with_openssl_output() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // 標準入力の内容をS/MIMEの出力形式で囲んで返すOpenSSLの代わり
+        let dir = std::env::temp_dir().join(format!("mail_composer_smime_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("openssl.sh");
+        std::fs::write(
+            &script,
+            "#!/bin/sh\n\
+             printf 'MIME-Version: 1.0\\r\\nContent-Type: multipart/signed; protocol=\"application/x-pkcs7-signature\"; micalg=\"sha-256\"; boundary=\"b\"\\r\\n\\r\\n--b\\r\\n'\n\
+             cat\n\
+             printf '\\r\\n--b--\\r\\n'\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let adapter = SmimeMailSignerAdapter::new("cert.pem", "key.pem")
+            .with_openssl_exe(script.to_string_lossy());
+        let signed = adapter.sign("Content-Type: text/plain\r\n\r\nbody\r\n");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let signed = signed.unwrap();
+        assert!(signed.starts_with("Content-Type: multipart/signed;"));
+        assert!(signed.contains("--b\r\nContent-Type: text/plain\r\n\r\nbody\r\n"));
+    }
+
+    #[test]
+    fn test_sign_fails_without_openssl() {
+        let adapter = SmimeMailSignerAdapter::new("cert.pem", "key.pem")
+            .with_openssl_exe("mail_composer_no_such_openssl");
+
+        let error = adapter.sign("body").unwrap_err();
+
+        assert_eq!(error.code, Some("MC-MAIL-049"));
+    }
+}
@@ -0,0 +1,593 @@
+use crate::APP_NAME;
+use crate::domain::{
+    entities::work_record::{WorkBreak, WorkRecord},
+    interfaces::{
+        work_time::WorkTimePort,
+        work_time_archive::{WorkTimeArchivePort, retention_cutoff},
+    },
+    value_objects::mail_objects::WorkTime,
+};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use rusqlite::{Connection, params};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::{
+        dirs::{AppDirectory, resolve_app_path},
+        workspace::{ensure_directory_exists, workspace_path},
+    },
+};
+use std::{collections::BTreeMap, path::Path};
+
+/// 作業記録のテーブル定義
+///
+/// 日付を主キーとするため、日付・期間による検索はインデックスを使用する。
+/// アーカイブした作業記録は`_archive`テーブルに移動し、読み込みは同じ日付の
+/// 記録がある場合に作業記録テーブルを優先するビューを通して行う
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS work_records (
+        work_date        TEXT PRIMARY KEY,
+        start_time       TEXT,
+        end_time         TEXT,
+        started_at       TEXT,
+        ended_at         TEXT,
+        duration_minutes INTEGER,
+        notes            TEXT
+    );
+    CREATE TABLE IF NOT EXISTS work_breaks (
+        id         INTEGER PRIMARY KEY AUTOINCREMENT,
+        work_date  TEXT NOT NULL REFERENCES work_records (work_date) ON DELETE CASCADE,
+        start_time TEXT NOT NULL,
+        end_time   TEXT
+    );
+    CREATE INDEX IF NOT EXISTS idx_work_breaks_work_date ON work_breaks (work_date);
+    CREATE TABLE IF NOT EXISTS work_records_archive (
+        work_date        TEXT PRIMARY KEY,
+        start_time       TEXT,
+        end_time         TEXT,
+        started_at       TEXT,
+        ended_at         TEXT,
+        duration_minutes INTEGER,
+        notes            TEXT
+    );
+    CREATE TABLE IF NOT EXISTS work_breaks_archive (
+        id         INTEGER PRIMARY KEY AUTOINCREMENT,
+        work_date  TEXT NOT NULL REFERENCES work_records_archive (work_date) ON DELETE CASCADE,
+        start_time TEXT NOT NULL,
+        end_time   TEXT
+    );
+    CREATE INDEX IF NOT EXISTS idx_work_breaks_archive_work_date
+        ON work_breaks_archive (work_date);
+    CREATE VIEW IF NOT EXISTS all_work_records AS
+        SELECT work_date, start_time, end_time, started_at, ended_at, duration_minutes, notes
+        FROM work_records
+        UNION ALL
+        SELECT work_date, start_time, end_time, started_at, ended_at, duration_minutes, notes
+        FROM work_records_archive
+        WHERE work_date NOT IN (SELECT work_date FROM work_records);
+    CREATE VIEW IF NOT EXISTS all_work_breaks AS
+        SELECT id, work_date, start_time, end_time FROM work_breaks
+        UNION ALL
+        SELECT id, work_date, start_time, end_time FROM work_breaks_archive
+        WHERE work_date NOT IN (SELECT work_date FROM work_records);
+";
+
+/// データベースに保存されている1日分の行
+struct StoredRow {
+    work_date: String,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    started_at: Option<String>,
+    ended_at: Option<String>,
+    duration_minutes: Option<i64>,
+    notes: Option<String>,
+}
+
+/// SQLiteで作業時間を管理するアウトバウンドアダプター
+///
+/// 作業記録が長期間にわたって蓄積されても、期間を指定した読み込みが遅くならないようにする
+pub struct SqliteWorkTimeAdapter {
+    connection: Connection,
+    retention_months: Option<u32>,
+}
+
+impl SqliteWorkTimeAdapter {
+    /// 指定したデータベースファイルを開く（存在しない場合は作成する）
+    ///
+    /// ## Arguments
+    /// * `db_path` - データベースファイルのパス（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<SqliteWorkTimeAdapter>`
+    /// * 失敗時 - データベースを開けない、またはテーブルを作成できない場合の`Err<AppError>`
+    pub fn open(db_path: &Path) -> AppResult<Self> {
+        let path = workspace_path(db_path)?;
+        if let Some(parent) = path.parent() {
+            ensure_directory_exists(parent)?;
+        }
+        let connection = Connection::open(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-033")
+                .with_message(format!(
+                    "作業時間データベースを開けませんでした。詳細: {}",
+                    path.display()
+                ))
+                .with_action("データベースファイルのパスとアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+        Self::from_connection(connection)
+    }
+
+    /// デフォルト設定でアダプターを作成する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<SqliteWorkTimeAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn with_default_settings() -> AppResult<Self> {
+        let dir = resolve_app_path(AppDirectory::Data, APP_NAME, "", "rust/mail_composer/data");
+        Self::open(&dir.join("work_times.sqlite3"))
+    }
+
+    /// データベース接続からアダプターを作成し、テーブルを用意する
+    fn from_connection(connection: Connection) -> AppResult<Self> {
+        connection
+            .execute_batch(&format!("PRAGMA foreign_keys = ON;{SCHEMA}"))
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-WORK-034")
+                    .with_message("作業時間データベースの初期化に失敗しました。")
+                    .with_action(
+                        "データベースファイルが破損していないか、書き込み権限があるか確認してください。",
+                    )
+                    .with_source(e)
+            })?;
+        Ok(Self {
+            connection,
+            retention_months: None,
+        })
+    }
+
+    /// 保存時に古い作業記録を自動でアーカイブテーブルに移動するよう設定する
+    ///
+    /// ## Arguments
+    /// * `months` - 保存する日の月に加えて、作業記録テーブルに残す月数
+    ///
+    /// ## Returns
+    /// * 自動アーカイブが設定されたSqliteWorkTimeAdapterのインスタンス
+    pub fn with_retention_months(mut self, months: u32) -> Self {
+        self.retention_months = Some(months);
+        self
+    }
+
+    /// クエリの失敗をAppErrorに変換する
+    fn query_error(e: rusqlite::Error) -> AppError {
+        AppError::new(ErrorKind::InternalServerError)
+            .with_code("MC-WORK-035")
+            .with_message("作業時間データベースの操作に失敗しました。")
+            .with_action("データベースファイルの状態とアクセス権限を確認してください。")
+            .with_source(e)
+    }
+
+    /// 保存されている値の解析に失敗した場合のAppErrorを作成する
+    fn corrupted(work_date: &str, value: &str) -> AppError {
+        AppError::new(ErrorKind::UnprocessableEntity)
+            .with_code("MC-WORK-036")
+            .with_message(format!(
+                "作業時間データベースの値を解析できませんでした。詳細: {work_date} {value}"
+            ))
+            .with_action("データベースの該当する行を修正または削除してください。")
+    }
+
+    /// 期間内の作業記録を日付順に読み込む
+    fn select_records(&self, from: &str, to: &str) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+        let mut statement = self
+            .connection
+            .prepare_cached(
+                "SELECT work_date, start_time, end_time, started_at, ended_at, duration_minutes, notes
+                 FROM all_work_records WHERE work_date BETWEEN ?1 AND ?2 ORDER BY work_date",
+            )
+            .map_err(Self::query_error)?;
+        let rows = statement
+            .query_map(params![from, to], |row| {
+                Ok(StoredRow {
+                    work_date: row.get(0)?,
+                    start_time: row.get(1)?,
+                    end_time: row.get(2)?,
+                    started_at: row.get(3)?,
+                    ended_at: row.get(4)?,
+                    duration_minutes: row.get(5)?,
+                    notes: row.get(6)?,
+                })
+            })
+            .and_then(|rows| rows.collect::<rusqlite::Result<Vec<_>>>())
+            .map_err(Self::query_error)?;
+
+        let mut breaks = self.select_breaks(from, to)?;
+        rows.into_iter()
+            .map(|row| {
+                let breaks = breaks.remove(&row.work_date).unwrap_or_default();
+                Self::record_from_row(row, breaks)
+            })
+            .collect()
+    }
+
+    /// 期間内の休憩を日付ごとに読み込む
+    fn select_breaks(&self, from: &str, to: &str) -> AppResult<BTreeMap<String, Vec<WorkBreak>>> {
+        let mut statement = self
+            .connection
+            .prepare_cached(
+                "SELECT work_date, start_time, end_time FROM all_work_breaks
+                 WHERE work_date BETWEEN ?1 AND ?2 ORDER BY work_date, id",
+            )
+            .map_err(Self::query_error)?;
+        let rows = statement
+            .query_map(params![from, to], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            })
+            .and_then(|rows| rows.collect::<rusqlite::Result<Vec<_>>>())
+            .map_err(Self::query_error)?;
+
+        let mut breaks: BTreeMap<String, Vec<WorkBreak>> = BTreeMap::new();
+        for (work_date, start, end) in rows {
+            let parse =
+                |time: &str| WorkTime::new(time).map_err(|_| Self::corrupted(&work_date, time));
+            let work_break = WorkBreak {
+                start: parse(&start)?,
+                end: end.as_deref().map(parse).transpose()?,
+            };
+            breaks
+                .entry(work_date.clone())
+                .or_default()
+                .push(work_break);
+        }
+        Ok(breaks)
+    }
+
+    /// 保存されている行を作業記録に変換する
+    fn record_from_row(
+        row: StoredRow,
+        breaks: Vec<WorkBreak>,
+    ) -> AppResult<(NaiveDate, WorkRecord)> {
+        let work_date = row.work_date.as_str();
+        let date = work_date
+            .parse::<NaiveDate>()
+            .map_err(|_| Self::corrupted(work_date, work_date))?;
+        let time = |value: Option<String>| {
+            value
+                .map(|value| WorkTime::new(&value).map_err(|_| Self::corrupted(work_date, &value)))
+                .transpose()
+        };
+        let timestamp = |value: Option<String>| {
+            value
+                .map(|value| {
+                    DateTime::parse_from_rfc3339(&value)
+                        .map_err(|_| Self::corrupted(work_date, &value))
+                })
+                .transpose()
+        };
+        let record = WorkRecord {
+            start: time(row.start_time)?,
+            end: time(row.end_time)?,
+            started_at: timestamp(row.started_at)?,
+            ended_at: timestamp(row.ended_at)?,
+            breaks,
+            duration_minutes: row.duration_minutes,
+            notes: row.notes,
+        };
+        Ok((date, record))
+    }
+
+    /// 作業記録を休憩も含めて書き込む
+    fn upsert_record(&self, date: NaiveDate, record: &WorkRecord) -> AppResult<()> {
+        let work_date = date.to_string();
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(Self::query_error)?;
+        transaction
+            .execute(
+                "INSERT INTO work_records
+                     (work_date, start_time, end_time, started_at, ended_at, duration_minutes, notes)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT (work_date) DO UPDATE SET
+                     start_time = excluded.start_time,
+                     end_time = excluded.end_time,
+                     started_at = excluded.started_at,
+                     ended_at = excluded.ended_at,
+                     duration_minutes = excluded.duration_minutes,
+                     notes = excluded.notes",
+                params![
+                    work_date,
+                    record.start.as_ref().map(WorkTime::as_str),
+                    record.end.as_ref().map(WorkTime::as_str),
+                    record.started_at.map(|at| at.to_rfc3339()),
+                    record.ended_at.map(|at| at.to_rfc3339()),
+                    record.duration_minutes,
+                    record.notes,
+                ],
+            )
+            .map_err(Self::query_error)?;
+        transaction
+            .execute(
+                "DELETE FROM work_breaks WHERE work_date = ?1",
+                params![work_date],
+            )
+            .map_err(Self::query_error)?;
+        for work_break in &record.breaks {
+            transaction
+                .execute(
+                    "INSERT INTO work_breaks (work_date, start_time, end_time) VALUES (?1, ?2, ?3)",
+                    params![
+                        work_date,
+                        work_break.start.as_str(),
+                        work_break.end.as_ref().map(WorkTime::as_str),
+                    ],
+                )
+                .map_err(Self::query_error)?;
+        }
+        transaction.commit().map_err(Self::query_error)
+    }
+
+    /// 指定日の作業記録を更新して保存する
+    fn update_record(
+        &self,
+        date: NaiveDate,
+        update: impl FnOnce(&mut WorkRecord) -> AppResult<()>,
+    ) -> AppResult<WorkRecord> {
+        let mut record = self.load_record(date)?.unwrap_or_default();
+        update(&mut record)?;
+        self.upsert_record(date, &record)?;
+        if let Some(months) = self.retention_months {
+            self.archive_before(retention_cutoff(date, months))?;
+        }
+        Ok(record)
+    }
+}
+
+impl WorkTimePort for SqliteWorkTimeAdapter {
+    fn save_start_time(
+        &self,
+        date: NaiveDate,
+        started_at: &DateTime<FixedOffset>,
+    ) -> AppResult<()> {
+        self.update_record(date, |record| {
+            record.set_started_at(*started_at);
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    fn save_end_time(
+        &self,
+        date: NaiveDate,
+        ended_at: &DateTime<FixedOffset>,
+    ) -> AppResult<WorkRecord> {
+        self.update_record(date, |record| {
+            record.set_ended_at(*ended_at);
+            Ok(())
+        })
+    }
+
+    fn pause(&self, date: NaiveDate, paused_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        self.update_record(date, |record| {
+            record.pause(WorkTime::from_time(paused_at.time()))
+        })
+    }
+
+    fn resume(&self, date: NaiveDate, resumed_at: &DateTime<FixedOffset>) -> AppResult<WorkRecord> {
+        self.update_record(date, |record| {
+            record.resume(WorkTime::from_time(resumed_at.time()))
+        })
+    }
+
+    fn annotate(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        self.update_record(date, |record| {
+            record.add_note(note);
+            Ok(())
+        })
+    }
+
+    fn replace_note(&self, date: NaiveDate, note: &str) -> AppResult<WorkRecord> {
+        self.update_record(date, |record| {
+            record.set_note(note);
+            Ok(())
+        })
+    }
+
+    fn load_record(&self, date: NaiveDate) -> AppResult<Option<WorkRecord>> {
+        let key = date.to_string();
+        Ok(self
+            .select_records(&key, &key)?
+            .into_iter()
+            .next()
+            .map(|(_, record)| record))
+    }
+
+    fn load_records(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<(NaiveDate, WorkRecord)>> {
+        // 日付はYYYY-MM-DD形式で保存しているため、文字列の比較で期間を絞り込める
+        self.select_records(&from.to_string(), &to.to_string())
+    }
+}
+
+impl WorkTimeArchivePort for SqliteWorkTimeAdapter {
+    fn archive_before(&self, cutoff: NaiveDate) -> AppResult<usize> {
+        let cutoff = cutoff.to_string();
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(Self::query_error)?;
+        // 同じ日付のアーカイブがある場合は、休憩ごと置き換える
+        transaction
+            .execute(
+                "DELETE FROM work_records_archive
+                 WHERE work_date IN (SELECT work_date FROM work_records WHERE work_date < ?1)",
+                params![cutoff],
+            )
+            .map_err(Self::query_error)?;
+        transaction
+            .execute(
+                "INSERT INTO work_records_archive
+                     (work_date, start_time, end_time, started_at, ended_at, duration_minutes, notes)
+                 SELECT work_date, start_time, end_time, started_at, ended_at, duration_minutes, notes
+                 FROM work_records WHERE work_date < ?1",
+                params![cutoff],
+            )
+            .map_err(Self::query_error)?;
+        transaction
+            .execute(
+                "INSERT INTO work_breaks_archive (work_date, start_time, end_time)
+                 SELECT work_date, start_time, end_time FROM work_breaks
+                 WHERE work_date < ?1 ORDER BY id",
+                params![cutoff],
+            )
+            .map_err(Self::query_error)?;
+        let moved = transaction
+            .execute(
+                "DELETE FROM work_records WHERE work_date < ?1",
+                params![cutoff],
+            )
+            .map_err(Self::query_error)?;
+        transaction.commit().map_err(Self::query_error)?;
+        Ok(moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter() -> SqliteWorkTimeAdapter {
+        SqliteWorkTimeAdapter::from_connection(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    fn at(date: NaiveDate, time: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(&format!("{date}T{time}:00+09:00")).unwrap()
+    }
+
+    #[test]
+    fn test_save_and_load_record() {
+        let adapter = adapter();
+        let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+
+        assert_eq!(adapter.load_record(date).unwrap(), None);
+        adapter.save_start_time(date, &at(date, "09:00")).unwrap();
+        let record = adapter.save_end_time(date, &at(date, "18:00")).unwrap();
+
+        assert_eq!(record.duration_minutes, Some(540));
+        assert_eq!(adapter.load_record(date).unwrap(), Some(record));
+    }
+
+    #[test]
+    fn test_breaks_and_notes() {
+        let adapter = adapter();
+        let date = NaiveDate::from_ymd_opt(2025, 4, 2).unwrap();
+
+        adapter.save_start_time(date, &at(date, "09:00")).unwrap();
+        adapter.pause(date, &at(date, "12:00")).unwrap();
+        let paused = adapter.load_record(date).unwrap().unwrap();
+        adapter.resume(date, &at(date, "13:00")).unwrap();
+        adapter
+            .update_record(date, |record| {
+                record.notes = Some("定例会議".to_string());
+                Ok(())
+            })
+            .unwrap();
+        let record = adapter.save_end_time(date, &at(date, "18:00")).unwrap();
+        let loaded = adapter.load_record(date).unwrap().unwrap();
+
+        assert!(paused.is_paused());
+        assert!(adapter.resume(date, &at(date, "18:30")).is_err());
+        assert_eq!(record.duration_minutes, Some(480));
+        assert_eq!(loaded.sessions().len(), 2);
+        assert_eq!(loaded.notes.as_deref(), Some("定例会議"));
+        assert_eq!(loaded, record);
+    }
+
+    #[test]
+    fn test_load_records_in_range() {
+        let adapter = adapter();
+        for date in ["2025-03-31", "2025-04-01", "2025-04-30", "2025-05-01"] {
+            let date = date.parse().unwrap();
+            adapter.save_start_time(date, &at(date, "09:00")).unwrap();
+        }
+
+        let records = adapter
+            .load_records(
+                NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 4, 30).unwrap(),
+            )
+            .unwrap();
+
+        let dates: Vec<String> = records.iter().map(|(date, _)| date.to_string()).collect();
+        assert_eq!(dates, ["2025-04-01", "2025-04-30"]);
+    }
+
+    #[test]
+    fn test_archive_before_keeps_records_readable() {
+        let adapter = adapter();
+        let old = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+        let recent = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        adapter.save_start_time(old, &at(old, "09:00")).unwrap();
+        adapter.pause(old, &at(old, "12:00")).unwrap();
+        adapter.resume(old, &at(old, "13:00")).unwrap();
+        let old_record = adapter.save_end_time(old, &at(old, "18:00")).unwrap();
+        adapter
+            .save_start_time(recent, &at(recent, "09:00"))
+            .unwrap();
+
+        let moved = adapter
+            .archive_before(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+            .unwrap();
+        let hot_count: i64 = adapter
+            .connection
+            .query_row("SELECT COUNT(*) FROM work_records", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(moved, 1);
+        assert_eq!(hot_count, 1);
+        assert_eq!(adapter.load_record(old).unwrap(), Some(old_record));
+        assert_eq!(adapter.load_records(old, recent).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_retention_archives_on_save() {
+        let adapter = adapter().with_retention_months(1);
+        let old = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        let today = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+
+        adapter.save_start_time(old, &at(old, "09:00")).unwrap();
+        adapter.save_start_time(today, &at(today, "09:00")).unwrap();
+        // アーカイブ済みの日の記録を更新しても、以前の記録は失われない
+        let updated = adapter.save_end_time(old, &at(old, "17:00")).unwrap();
+        let archived_count: i64 = adapter
+            .connection
+            .query_row("SELECT COUNT(*) FROM work_records_archive", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+
+        assert_eq!(updated.start.unwrap().as_str(), "09:00");
+        assert_eq!(updated.duration_minutes, Some(480));
+        assert_eq!(archived_count, 1);
+        assert_eq!(
+            adapter
+                .load_record(old)
+                .unwrap()
+                .unwrap()
+                .end
+                .unwrap()
+                .as_str(),
+            "17:00"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::domain::{
+    entities::mail_draft::MailDraft,
+    interfaces::confirmation::ConfirmationPort,
+    value_objects::{email_address::EmailAddress, language::Language},
+};
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::io::{self, BufRead, Write};
+
+/// 端末で送信可否を問い合わせるアウトバウンドアダプター
+pub struct TerminalConfirmationAdapter {
+    language: Language,
+}
+
+impl TerminalConfirmationAdapter {
+    /// 新しいTerminalConfirmationAdapterを作成する
+    ///
+    /// ## Returns
+    /// * TerminalConfirmationAdapterのインスタンス
+    pub fn new() -> Self {
+        Self {
+            language: Language::default(),
+        }
+    }
+
+    /// 確認メッセージの表示言語を指定する
+    ///
+    /// ## Arguments
+    /// * `language` - 表示言語
+    ///
+    /// ## Returns
+    /// * 表示言語が設定されたTerminalConfirmationAdapterのインスタンス
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// 確認メッセージを構築する
+    ///
+    /// 宛先は表示名がある場合は`表示名 <メールアドレス>`、ない場合はメールアドレスのみで表示する
+    fn build_prompt(&self, draft: &MailDraft) -> String {
+        let join = |addresses: &[EmailAddress]| {
+            addresses
+                .iter()
+                .map(|address| match address.display_name() {
+                    Some(name) => format!("{name} <{}>", address.as_str()),
+                    None => address.as_str().to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut recipients = join(draft.to());
+        if !draft.cc().is_empty() {
+            recipients.push_str(&format!(" (CC: {})", join(draft.cc())));
+        }
+        let subject = draft.subject().as_str();
+        match self.language {
+            Language::Japanese => {
+                format!("件名: {subject}\n{recipients} に送信しますか？ [y/N]: ")
+            }
+            Language::English => format!("Subject: {subject}\nSend to {recipients}? [y/N]: "),
+        }
+    }
+
+    /// 入力された回答が肯定かどうかを判定する
+    ///
+    /// `y`または`yes`（大文字・小文字を区別しない）のみを肯定とみなす
+    fn is_affirmative(answer: &str) -> bool {
+        matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+    }
+}
+
+impl Default for TerminalConfirmationAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfirmationPort for TerminalConfirmationAdapter {
+    fn confirm(&self, draft: &MailDraft) -> AppResult<bool> {
+        let mut stdout = io::stdout();
+        write!(stdout, "{}", self.build_prompt(draft))?;
+        stdout.flush()?;
+
+        let mut answer = String::new();
+        io::stdin().lock().read_line(&mut answer).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-051")
+                .with_message("確認の入力の読み込みに失敗しました。")
+                .with_action("端末から実行していることを確認してください。")
+                .with_source(e)
+        })?;
+
+        Ok(Self::is_affirmative(&answer))
+    }
+}
+
+/// 問い合わせを行わず、常に送信を許可するアウトバウンドアダプター
+///
+/// 確認を省略する指定がされた場合や、非対話環境での実行に使用する
+pub struct AutoConfirmationAdapter;
+
+impl ConfirmationPort for AutoConfirmationAdapter {
+    fn confirm(&self, _draft: &MailDraft) -> AppResult<bool> {
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        email_address::EmailAddress,
+        mail_objects::{MailBody, Subject},
+    };
+
+    #[test]
+    fn test_is_affirmative() {
+        assert!(TerminalConfirmationAdapter::is_affirmative("y\n"));
+        assert!(TerminalConfirmationAdapter::is_affirmative("YES"));
+        assert!(!TerminalConfirmationAdapter::is_affirmative("\n"));
+        assert!(!TerminalConfirmationAdapter::is_affirmative("no"));
+    }
+
+    #[test]
+    fn test_build_prompt() {
+        let draft = MailDraft::new(
+            vec![
+                EmailAddress::parse("test1@example.com")
+                    .unwrap()
+                    .with_display_name("山田"),
+                EmailAddress::parse("test2@example.com").unwrap(),
+            ],
+            vec![EmailAddress::parse("test3@example.com").unwrap()],
+            Subject::new("テスト件名").unwrap(),
+            MailBody::new("テスト本文"),
+        );
+        let prompt = TerminalConfirmationAdapter::new().build_prompt(&draft);
+
+        assert_eq!(
+            prompt,
+            "件名: テスト件名\n山田 <test1@example.com>, test2@example.com (CC: test3@example.com) に送信しますか？ [y/N]: "
+        );
+        assert_eq!(
+            TerminalConfirmationAdapter::new()
+                .with_language(Language::English)
+                .build_prompt(&draft),
+            "Subject: テスト件名\nSend to 山田 <test1@example.com>, test2@example.com (CC: test3@example.com)? [y/N]: "
+        );
+    }
+}
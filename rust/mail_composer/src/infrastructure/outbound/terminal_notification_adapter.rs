@@ -0,0 +1,48 @@
+use crate::domain::interfaces::notification::NotificationPort;
+use share::error::app_error::AppResult;
+
+/// 通知を標準エラー出力に警告として表示するアウトバウンドアダプター
+///
+/// デスクトップ通知を使用できない環境や、コマンドを実行するたびに確認する場合に使用する
+pub struct TerminalNotificationAdapter;
+
+impl TerminalNotificationAdapter {
+    /// 新しいTerminalNotificationAdapterを作成する
+    ///
+    /// ## Returns
+    /// * TerminalNotificationAdapterのインスタンス
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 警告として表示する文字列を構築する
+    fn build_warning(title: &str, message: &str) -> String {
+        format!("[警告] {title}: {message}")
+    }
+}
+
+impl Default for TerminalNotificationAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationPort for TerminalNotificationAdapter {
+    fn notify(&self, title: &str, message: &str) -> AppResult<()> {
+        eprintln!("{}", Self::build_warning(title, message));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_warning() {
+        assert_eq!(
+            TerminalNotificationAdapter::build_warning("記録忘れ", "終了が記録されていません。"),
+            "[警告] 記録忘れ: 終了が記録されていません。"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::domain::interfaces::prompt::PromptPort;
+use share::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::io::{self, BufRead, Write};
+
+/// 端末で値の入力を求めるアウトバウンドアダプター
+pub struct TerminalPromptAdapter;
+
+impl TerminalPromptAdapter {
+    /// 新しいTerminalPromptAdapterを作成する
+    ///
+    /// ## Returns
+    /// * TerminalPromptAdapterのインスタンス
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 質問文を構築する
+    fn build_prompt(question: &str, default: Option<&str>) -> String {
+        match default {
+            Some(default) if !default.is_empty() => format!("{question} [{default}]: "),
+            _ => format!("{question}: "),
+        }
+    }
+
+    /// 入力された値を整形し、空の場合は既定値を使用する
+    fn normalize_answer(answer: &str, default: Option<&str>) -> String {
+        let answer = answer.trim();
+        if answer.is_empty() {
+            default.unwrap_or_default().to_string()
+        } else {
+            answer.to_string()
+        }
+    }
+}
+
+impl Default for TerminalPromptAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PromptPort for TerminalPromptAdapter {
+    fn ask(&self, question: &str, default: Option<&str>) -> AppResult<String> {
+        let mut stdout = io::stdout();
+        write!(stdout, "{}", Self::build_prompt(question, default))?;
+        stdout.flush()?;
+
+        let mut answer = String::new();
+        let read = io::stdin().lock().read_line(&mut answer).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-CLI-012")
+                .with_message("入力の読み込みに失敗しました。")
+                .with_action("端末から実行していることを確認してください。")
+                .with_source(e)
+        })?;
+        if read == 0 {
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-CLI-013")
+                .with_message("入力が終了したため、処理を中断しました。")
+                .with_action("端末から対話的に実行してください。"));
+        }
+
+        Ok(Self::normalize_answer(&answer, default))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_prompt() {
+        assert_eq!(
+            TerminalPromptAdapter::build_prompt("差出人名", None),
+            "差出人名: "
+        );
+        assert_eq!(
+            TerminalPromptAdapter::build_prompt("差出人名", Some("山田")),
+            "差出人名 [山田]: "
+        );
+    }
+
+    #[test]
+    fn test_normalize_answer() {
+        assert_eq!(
+            TerminalPromptAdapter::normalize_answer("  山田 \n", None),
+            "山田"
+        );
+        assert_eq!(
+            TerminalPromptAdapter::normalize_answer("\n", Some("既定")),
+            "既定"
+        );
+        assert_eq!(TerminalPromptAdapter::normalize_answer("\n", None), "");
+    }
+}
@@ -0,0 +1,255 @@
+use crate::domain::{
+    entities::address_book::{AddressBook, AddressBookEntry},
+    interfaces::address_book::AddressBookPort,
+    value_objects::{address_query::AddressQuery, email_address::EmailAddress},
+};
+use rusqlite::{Connection, OpenFlags};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::Path,
+};
+
+/// Thunderbirdのアドレス帳（プロファイル内の`abook.sqlite`）を読み込むアウトバウンドアダプター
+///
+/// 連絡先は表示名（未設定の場合は姓名）を名前、優先メールアドレスをメールアドレスとして読み込み、
+/// 連絡先リストはグループとして読み込む
+pub struct ThunderbirdAddressBookAdapter {
+    book: AddressBook,
+}
+
+/// 連絡先1件分のプロパティ
+#[derive(Default)]
+struct CardProperties {
+    display_name: Option<String>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    primary_email: Option<String>,
+    second_email: Option<String>,
+}
+
+impl CardProperties {
+    /// 表示に使用する名前を取得する
+    fn name(&self) -> Option<String> {
+        let name = match &self.display_name {
+            Some(display_name) => display_name.clone(),
+            None => [&self.last_name, &self.first_name]
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        let name = name.trim().to_string();
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// メールアドレスを取得する
+    fn email(&self) -> Option<String> {
+        self.primary_email
+            .clone()
+            .or_else(|| self.second_email.clone())
+            .filter(|email| !email.trim().is_empty())
+    }
+}
+
+impl ThunderbirdAddressBookAdapter {
+    /// 指定されたパスから`abook.sqlite`を読み込む
+    ///
+    /// Thunderbirdの起動中でも読み込めるよう、読み取り専用で開く
+    ///
+    /// ## Arguments
+    /// * `abook_path` - `abook.sqlite`のパス（通常はThunderbirdプロファイルディレクトリ内の絶対パス）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<ThunderbirdAddressBookAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn load_from_abook(abook_path: &Path) -> AppResult<Self> {
+        let path = workspace_path(abook_path)?;
+        let connection = Connection::open_with_flags(&path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| {
+                AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-ADDR-028")
+                    .with_message("Thunderbirdのアドレス帳を開けませんでした。")
+                    .with_action("abook.sqliteのパスとアクセス権限を確認してください。")
+                    .with_source(e)
+            })?;
+
+        Self::from_connection(&connection)
+    }
+
+    /// データベース接続からアドレス帳を読み込む
+    fn from_connection(connection: &Connection) -> AppResult<Self> {
+        let query_error = |e: rusqlite::Error| {
+            AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-ADDR-029")
+                .with_message("Thunderbirdのアドレス帳の読み込みに失敗しました。")
+                .with_action(
+                    "abook.sqliteがThunderbirdのアドレス帳ファイルであることを確認してください。",
+                )
+                .with_source(e)
+        };
+
+        let cards = read_cards(connection).map_err(query_error)?;
+        let lists = read_lists(connection).map_err(query_error)?;
+
+        // 連絡先の表示名は重複し得るため、最初に見つかったものを優先する
+        let mut names = HashSet::new();
+        let mut card_names = BTreeMap::new();
+        let mut entries = Vec::new();
+        for (uid, card) in &cards {
+            let (Some(name), Some(email)) = (card.name(), card.email()) else {
+                continue;
+            };
+            card_names.insert(uid.as_str(), name.clone());
+            if names.insert(name.clone()) {
+                entries.push(AddressBookEntry::person(name, email));
+            }
+        }
+
+        for (name, members) in lists {
+            let members: Vec<String> = members
+                .iter()
+                .filter_map(|uid| card_names.get(uid.as_str()).cloned())
+                .collect();
+            if !members.is_empty() && names.insert(name.clone()) {
+                entries.push(AddressBookEntry::group(name, members));
+            }
+        }
+
+        Ok(Self {
+            book: AddressBook::from_entries(entries)?,
+        })
+    }
+
+    /// 全てのエントリを取得する
+    ///
+    /// ## Returns
+    /// * 全てのAddressBookエントリのスライス
+    pub fn entries(&self) -> &[AddressBookEntry] {
+        self.book.entries()
+    }
+}
+
+impl AddressBookPort for ThunderbirdAddressBookAdapter {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        self.book.resolve(key_name)
+    }
+
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        self.book.expand(key_name)
+    }
+
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.entries().to_vec())
+    }
+
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.search(query)?.into_iter().cloned().collect())
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        Ok(self.book.reverse_resolve(address).map(str::to_string))
+    }
+}
+
+/// `properties`テーブルから連絡先を読み込む
+fn read_cards(connection: &Connection) -> rusqlite::Result<BTreeMap<String, CardProperties>> {
+    let mut statement = connection.prepare(
+        "SELECT card, name, value FROM properties \
+         WHERE name IN ('DisplayName', 'FirstName', 'LastName', 'PrimaryEmail', 'SecondEmail') \
+         ORDER BY rowid",
+    )?;
+    let rows = statement.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+        ))
+    })?;
+
+    let mut cards: BTreeMap<String, CardProperties> = BTreeMap::new();
+    for row in rows {
+        let (card, name, value) = row?;
+        let properties = cards.entry(card).or_default();
+        let field = match name.as_str() {
+            "DisplayName" => &mut properties.display_name,
+            "FirstName" => &mut properties.first_name,
+            "LastName" => &mut properties.last_name,
+            "PrimaryEmail" => &mut properties.primary_email,
+            _ => &mut properties.second_email,
+        };
+        *field = value.filter(|v| !v.trim().is_empty());
+    }
+    Ok(cards)
+}
+
+/// `lists`テーブルと`list_cards`テーブルから連絡先リストを読み込む
+fn read_lists(connection: &Connection) -> rusqlite::Result<Vec<(String, Vec<String>)>> {
+    let mut statement = connection.prepare(
+        "SELECT lists.name, list_cards.card FROM lists \
+         JOIN list_cards ON list_cards.list = lists.uid \
+         ORDER BY lists.name, list_cards.rowid",
+    )?;
+    let rows = statement.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+
+    let mut lists: Vec<(String, Vec<String>)> = Vec::new();
+    for row in rows {
+        let (name, card) = row?;
+        match lists.last_mut() {
+            Some((last, members)) if *last == name => members.push(card),
+            _ => lists.push((name, vec![card])),
+        }
+    }
+    Ok(lists)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_connection() -> Connection {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE properties (card TEXT, name TEXT, value TEXT);
+                 CREATE TABLE lists (uid TEXT PRIMARY KEY, name TEXT, nickName TEXT, description TEXT);
+                 CREATE TABLE list_cards (list TEXT, card TEXT, PRIMARY KEY(list, card));
+                 INSERT INTO properties VALUES
+                     ('c1', 'DisplayName', '山田 太郎'),
+                     ('c1', 'PrimaryEmail', 'yamada@example.com'),
+                     ('c2', 'FirstName', '花子'),
+                     ('c2', 'LastName', '佐藤'),
+                     ('c2', 'SecondEmail', 'sato@example.com'),
+                     ('c3', 'DisplayName', 'メールなし');
+                 INSERT INTO lists VALUES ('l1', '開発チーム', '', '');
+                 INSERT INTO list_cards VALUES ('l1', 'c1'), ('l1', 'c2');",
+            )
+            .unwrap();
+        connection
+    }
+
+    #[test]
+    fn test_from_connection() {
+        let adapter = ThunderbirdAddressBookAdapter::from_connection(&sample_connection()).unwrap();
+
+        assert_eq!(
+            adapter.resolve("山田 太郎").unwrap().as_str(),
+            "yamada@example.com"
+        );
+        assert_eq!(
+            adapter.resolve("佐藤 花子").unwrap().as_str(),
+            "sato@example.com"
+        );
+        assert!(adapter.resolve("メールなし").is_err());
+        assert_eq!(adapter.expand("開発チーム").unwrap().len(), 2);
+    }
+}
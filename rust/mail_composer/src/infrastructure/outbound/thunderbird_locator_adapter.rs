@@ -0,0 +1,184 @@
+use crate::domain::interfaces::thunderbird_locator::ThunderbirdLocatorPort;
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Thunderbirdのインストール先が登録されるレジストリキー（Windows）
+const REGISTRY_KEYS: [&str; 2] = [
+    r"HKLM\SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\thunderbird.exe",
+    r"HKCU\SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\thunderbird.exe",
+];
+
+/// レジストリ（Windows）、標準的なインストール先、`PATH`の順にThunderbirdを探すアウトバウンドアダプター
+pub struct ThunderbirdLocatorAdapter {
+    registry_keys: Vec<&'static str>,
+    candidates: Vec<PathBuf>,
+    executable_names: Vec<&'static str>,
+    search_path: Option<String>,
+}
+
+impl ThunderbirdLocatorAdapter {
+    /// 実行中のプラットフォームに合わせたアダプターを作成する
+    ///
+    /// ## Returns
+    /// * ThunderbirdLocatorAdapterのインスタンス
+    pub fn new() -> Self {
+        Self {
+            registry_keys: if cfg!(windows) {
+                REGISTRY_KEYS.to_vec()
+            } else {
+                Vec::new()
+            },
+            candidates: Self::platform_candidates(),
+            executable_names: Self::executable_names(),
+            search_path: env::var("PATH").ok(),
+        }
+    }
+
+    /// プラットフォームごとの標準的なインストール先
+    fn platform_candidates() -> Vec<PathBuf> {
+        if cfg!(windows) {
+            ["ProgramFiles", "ProgramFiles(x86)", "LOCALAPPDATA"]
+                .iter()
+                .filter_map(env::var_os)
+                .map(|dir| {
+                    PathBuf::from(dir)
+                        .join("Mozilla Thunderbird")
+                        .join("thunderbird.exe")
+                })
+                .collect()
+        } else if cfg!(target_os = "macos") {
+            vec![PathBuf::from(
+                "/Applications/Thunderbird.app/Contents/MacOS/thunderbird",
+            )]
+        } else {
+            vec![
+                PathBuf::from("/usr/bin/thunderbird"),
+                PathBuf::from("/usr/local/bin/thunderbird"),
+                PathBuf::from("/snap/bin/thunderbird"),
+                PathBuf::from("/var/lib/flatpak/exports/bin/org.mozilla.Thunderbird"),
+            ]
+        }
+    }
+
+    /// `PATH`から探す実行ファイル名
+    fn executable_names() -> Vec<&'static str> {
+        if cfg!(windows) {
+            vec!["thunderbird.exe"]
+        } else {
+            vec!["thunderbird"]
+        }
+    }
+
+    /// レジストリに登録されたインストール先を探す（`reg query`を使用する）
+    fn find_in_registry(&self) -> Option<PathBuf> {
+        self.registry_keys.iter().find_map(|key| {
+            let output = Command::new("reg")
+                .args(["query", key, "/ve"])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())?;
+            Self::parse_registry_default_value(&String::from_utf8_lossy(&output.stdout))
+                .filter(|path| Self::is_executable_file(path))
+        })
+    }
+
+    /// `reg query <キー> /ve`の出力から既定値を取り出す
+    ///
+    /// 出力例: `    (既定)    REG_SZ    C:\Program Files\Mozilla Thunderbird\thunderbird.exe`
+    fn parse_registry_default_value(output: &str) -> Option<PathBuf> {
+        output.lines().find_map(|line| {
+            let (_, value) = line
+                .split_once("REG_EXPAND_SZ")
+                .or_else(|| line.split_once("REG_SZ"))?;
+            let value = value.trim().trim_matches('"');
+            (!value.is_empty()).then(|| PathBuf::from(value))
+        })
+    }
+
+    /// `PATH`に含まれるディレクトリから実行ファイルを探す（`which`と同じ探索順）
+    fn find_in_path(&self) -> Option<PathBuf> {
+        let search_path = self.search_path.as_deref()?;
+        env::split_paths(search_path).find_map(|dir| {
+            self.executable_names
+                .iter()
+                .map(|name| dir.join(name))
+                .find(|path| Self::is_executable_file(path))
+        })
+    }
+
+    fn is_executable_file(path: &Path) -> bool {
+        path.is_file()
+    }
+}
+
+impl Default for ThunderbirdLocatorAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThunderbirdLocatorPort for ThunderbirdLocatorAdapter {
+    fn locate(&self) -> Option<PathBuf> {
+        self.find_in_registry()
+            .or_else(|| {
+                self.candidates
+                    .iter()
+                    .find(|path| Self::is_executable_file(path))
+                    .cloned()
+            })
+            .or_else(|| self.find_in_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_locate_prefers_candidates_then_path() {
+        let dir = env::temp_dir().join(format!("mail_composer_tb_locator_{}", std::process::id()));
+        let bin_dir = dir.join("bin");
+        fs::create_dir_all(&bin_dir).unwrap();
+        let in_path = bin_dir.join("thunderbird");
+        fs::write(&in_path, "").unwrap();
+
+        let mut locator = ThunderbirdLocatorAdapter {
+            registry_keys: Vec::new(),
+            candidates: vec![dir.join("missing")],
+            executable_names: vec!["thunderbird"],
+            search_path: Some(bin_dir.to_string_lossy().into_owned()),
+        };
+        assert_eq!(locator.locate(), Some(in_path.clone()));
+
+        let installed = dir.join("installed");
+        fs::write(&installed, "").unwrap();
+        locator.candidates.push(installed.clone());
+        assert_eq!(locator.locate(), Some(installed));
+
+        locator.search_path = None;
+        locator.candidates.clear();
+        let located = locator.locate();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(located, None);
+    }
+
+    #[test]
+    fn test_parse_registry_default_value() {
+        let output = "\r\nHKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\App Paths\\thunderbird.exe\r\n    (既定)    REG_SZ    C:\\Program Files\\Mozilla Thunderbird\\thunderbird.exe\r\n";
+        assert_eq!(
+            ThunderbirdLocatorAdapter::parse_registry_default_value(output),
+            Some(PathBuf::from(
+                r"C:\Program Files\Mozilla Thunderbird\thunderbird.exe"
+            ))
+        );
+        assert_eq!(
+            ThunderbirdLocatorAdapter::parse_registry_default_value("ERROR"),
+            None
+        );
+    }
+}
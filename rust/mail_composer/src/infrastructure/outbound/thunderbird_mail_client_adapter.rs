@@ -1,18 +1,31 @@
-use crate::domain::{
-    entities::mail_draft::MailDraft,
-    interfaces::mail_client::MailClientPort,
-};
+use crate::domain::{entities::mail_draft::MailDraft, interfaces::mail_client::MailClientPort};
 use share::{
     error::{
         app_error::{AppError, AppResult},
         kind::ErrorKind,
     },
+    logging,
+};
+use std::{
+    io::Read,
+    path::Path,
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
 };
-use std::process::Command;
+
+/// Thunderbirdプロセスの終了を待機する既定のタイムアウト
+pub const DEFAULT_THUNDERBIRD_TIMEOUT: Duration = Duration::from_secs(60);
+/// Thunderbirdプロセスの終了を確認する間隔
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// プロセス終了後に標準エラー出力の読み込み完了を待つ時間
+const STDERR_GRACE_PERIOD: Duration = Duration::from_secs(1);
 
 /// Thunderbirdメールクライアントのアウトバウンドアダプター
 pub struct ThunderbirdMailClientAdapter {
     thunderbird_exe_path: String,
+    timeout: Duration,
+    detach_on_timeout: bool,
 }
 
 impl ThunderbirdMailClientAdapter {
@@ -26,54 +39,225 @@ impl ThunderbirdMailClientAdapter {
     pub fn new(thunderbird_exe_path: impl Into<String>) -> Self {
         Self {
             thunderbird_exe_path: thunderbird_exe_path.into(),
+            timeout: DEFAULT_THUNDERBIRD_TIMEOUT,
+            detach_on_timeout: false,
+        }
+    }
+
+    /// Thunderbirdプロセスの終了を待機するタイムアウトを設定する
+    ///
+    /// タイムアウトを過ぎてもプロセスが終了しない場合は、プロセスを強制終了する
+    ///
+    /// ## Arguments
+    /// * `timeout` - タイムアウト
+    ///
+    /// ## Returns
+    /// * タイムアウトが設定されたThunderbirdMailClientAdapterのインスタンス
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// タイムアウトを過ぎたプロセスを強制終了せずに切り離すかどうかを設定する
+    ///
+    /// Thunderbirdが起動していない状態で起動したプロセスはメール作成後も終了しないため、
+    /// 作成画面を閉じさせたくない場合に使用する
+    ///
+    /// ## Arguments
+    /// * `detach_on_timeout` - 切り離す場合は`true`（既定は`false`で強制終了する）
+    ///
+    /// ## Returns
+    /// * 設定が反映されたThunderbirdMailClientAdapterのインスタンス
+    pub fn with_detach_on_timeout(mut self, detach_on_timeout: bool) -> Self {
+        self.detach_on_timeout = detach_on_timeout;
+        self
+    }
+
+    /// プロセスの終了をタイムアウトまで待機する
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<Some<ExitStatus>>` (タイムアウトした場合は`Ok<None>`)
+    /// * 失敗時 - `Err<AppError>`
+    fn wait_with_timeout(&self, child: &mut Child) -> AppResult<Option<ExitStatus>> {
+        let wait_error = |e: std::io::Error| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-052")
+                .with_message("Thunderbirdプロセスの待機に失敗しました。")
+                .with_action("システムリソースを確認してください。")
+                .retryable(true)
+                .with_source(e)
+        };
+
+        let started = Instant::now();
+        loop {
+            if let Some(status) = child.try_wait().map_err(wait_error)? {
+                return Ok(Some(status));
+            }
+            if started.elapsed() >= self.timeout {
+                return Ok(None);
+            }
+            thread::sleep(POLL_INTERVAL);
         }
     }
 
+    /// 標準エラー出力の読み込み結果を取得する
+    ///
+    /// Thunderbirdが起動した子プロセスがパイプを保持し続ける場合に備え、
+    /// 一定時間内に読み込みが完了しなければ空文字列を返す
+    fn collect_stderr(reader: Option<thread::JoinHandle<String>>) -> String {
+        let Some(handle) = reader else {
+            return String::new();
+        };
+
+        let started = Instant::now();
+        while !handle.is_finished() && started.elapsed() < STDERR_GRACE_PERIOD {
+            thread::sleep(POLL_INTERVAL);
+        }
+        if !handle.is_finished() {
+            return String::new();
+        }
+
+        handle
+            .join()
+            .map(|output| output.trim().to_string())
+            .unwrap_or_default()
+    }
+
     /// Thunderbird compose引数を構築する
+    ///
+    /// HTML形式の本文（[`MailDraft::html_body`]）が設定されている場合はHTMLメールとして作成する
     fn build_compose_arg(&self, draft: &MailDraft) -> String {
         let to = draft.to_addresses_as_string();
         let cc = draft.cc_addresses_as_string();
         let subject = draft.subject().as_str();
-        let body = draft.body().to_crlf();
+        let (format, body) = match draft.html_body() {
+            Some(html) => ("html", html.to_string()),
+            None => ("plain", draft.body().to_crlf()),
+        };
 
-        // 必要に応じてエスケープ処理
-        let escape_quotes = |s: &str| s.replace('\'', "'");
+        let mut compose_arg =
+            format!("format={format},to='{to}',cc='{cc}',subject='{subject}',body='{body}'");
 
-        format!(
-            "format=plain,to='{}',cc='{}',subject='{}',body='{}'",
-            escape_quotes(&to),
-            escape_quotes(&cc),
-            escape_quotes(subject),
-            escape_quotes(&body),
-        )
+        if !draft.attachments().is_empty() {
+            let attachments = draft
+                .attachments()
+                .iter()
+                .map(|path| Self::to_file_url(path))
+                .collect::<Vec<_>>()
+                .join(",");
+            compose_arg.push_str(&format!(",attachment='{attachments}'"));
+        }
+
+        compose_arg
+    }
+
+    /// 添付ファイルのパスをThunderbirdが解釈できるfile URLに変換する
+    fn to_file_url(path: &Path) -> String {
+        let path = path.to_string_lossy().replace('\\', "/");
+        format!("file:///{}", path.trim_start_matches('/'))
     }
 }
 
 impl MailClientPort for ThunderbirdMailClientAdapter {
     fn compose_mail(&self, draft: &MailDraft, is_dry_run: bool) -> AppResult<()> {
+        let _span = logging::operation_span("thunderbird_compose").entered();
+        // `-compose`引数は任意のヘッダーを受け付けないため、黙って落とさずにエラーとする
+        if !draft.headers().is_empty() {
+            let names = draft.headers().keys().cloned().collect::<Vec<_>>();
+            return Err(AppError::new(ErrorKind::BadRequest)
+                .with_code("MC-MAIL-058")
+                .with_message(format!(
+                    "Thunderbirdではヘッダー（{}）を設定できません。",
+                    names.join(", ")
+                ))
+                .with_action(
+                    "config.jsonのimapを設定して下書きフォルダにアップロードするか、メールテンプレートのheadersを削除してください。",
+                ));
+        }
         let compose_arg = self.build_compose_arg(draft);
 
         if is_dry_run {
-            println!("[DRY-RUN] {} -compose {}", self.thunderbird_exe_path, compose_arg);
+            tracing::debug!(
+                exe = %self.thunderbird_exe_path,
+                compose = %compose_arg,
+                "[DRY-RUN] Thunderbirdを起動しません"
+            );
             return Ok(());
         }
 
         let mut child = Command::new(&self.thunderbird_exe_path)
-            .args(&["-compose", &compose_arg])
+            .args(["-compose", &compose_arg])
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
             .spawn()
             .map_err(|e| {
+                // 実行ファイルがない場合を除き、起動の失敗は一時的なものとして再試行できるようにする
+                let retryable = e.kind() != std::io::ErrorKind::NotFound;
                 AppError::new(ErrorKind::InternalServerError)
+                    .with_code("MC-MAIL-053")
                     .with_message("Thunderbirdの起動に失敗しました。")
                     .with_action("Thunderbirdのパスが正しいことを確認してください。")
+                    .retryable(retryable)
                     .with_source(e)
             })?;
 
-        child.wait().map_err(|e| {
-            AppError::new(ErrorKind::InternalServerError)
-                .with_message("Thunderbirdプロセスの待機に失敗しました。")
-                .with_action("システムリソースを確認してください。")
-                .with_source(e)
-        })?;
+        // パイプの詰まりで待機が終わらなくならないよう、標準エラー出力は別スレッドで読み続ける
+        let stderr_reader = child.stderr.take().map(|mut stderr| {
+            thread::spawn(move || {
+                let mut output = String::new();
+                let _ = stderr.read_to_string(&mut output);
+                output
+            })
+        });
+        let status = match self.wait_with_timeout(&mut child) {
+            Ok(status) => status,
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(e);
+            }
+        };
+
+        let Some(status) = status else {
+            let secs = self.timeout.as_secs();
+            if self.detach_on_timeout {
+                tracing::warn!(
+                    pid = child.id(),
+                    timeout_secs = secs,
+                    "Thunderbirdがタイムアウトまでに終了しなかったため、終了を待たずに処理を続行します"
+                );
+                return Ok(());
+            }
+            let _ = child.kill();
+            let _ = child.wait();
+            let stderr = Self::collect_stderr(stderr_reader);
+            let mut message = format!(
+                "Thunderbirdが{secs}秒以内に終了しなかったため、プロセスを強制終了しました。"
+            );
+            if !stderr.is_empty() {
+                message.push_str(&format!("詳細: {stderr}"));
+            }
+            return Err(AppError::new(ErrorKind::RequestTimeout)
+                .with_code("MC-MAIL-054")
+                .with_message(message)
+                .with_action("Thunderbirdが応答しているか確認し、必要に応じてthunderbird_timeout_secsを延長してください。")
+                .retryable(true));
+        };
+
+        let stderr = Self::collect_stderr(stderr_reader);
+        if !status.success() {
+            let mut message = format!("Thunderbirdがエラー終了しました。({status})");
+            if !stderr.is_empty() {
+                message.push_str(&format!("詳細: {stderr}"));
+            }
+            return Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-MAIL-055")
+                .with_message(message)
+                .with_action(
+                    "Thunderbirdのパスと、Thunderbirdが正常に起動できることを確認してください。",
+                )
+                .retryable(true));
+        }
 
         Ok(())
     }
@@ -90,33 +274,164 @@ mod tests {
     #[test]
     fn test_compose_arg_building() {
         let adapter = ThunderbirdMailClientAdapter::new("thunderbird");
-        
+
         let to = vec![EmailAddress::parse("test1@example.com").unwrap()];
         let cc = vec![EmailAddress::parse("test2@example.com").unwrap()];
         let subject = Subject::new("テスト件名").unwrap();
         let body = MailBody::new("テスト本文\n改行あり");
-        
+
         let draft = MailDraft::new(to, cc, subject, body);
         let compose_arg = adapter.build_compose_arg(&draft);
-        
+
         assert!(compose_arg.contains("to='test1@example.com'"));
         assert!(compose_arg.contains("cc='test2@example.com'"));
         assert!(compose_arg.contains("subject='テスト件名'"));
         assert!(compose_arg.contains("テスト本文\r\n改行あり"));
     }
 
+    #[test]
+    fn test_compose_arg_with_attachment() {
+        let adapter = ThunderbirdMailClientAdapter::new("thunderbird");
+
+        let to = vec![EmailAddress::parse("test@example.com").unwrap()];
+        let subject = Subject::new("テスト").unwrap();
+        let body = MailBody::new("テスト本文");
+
+        let draft = MailDraft::new(to, vec![], subject, body).with_attachment("/tmp/report.pdf");
+        let compose_arg = adapter.build_compose_arg(&draft);
+
+        assert!(compose_arg.contains("attachment='file:///tmp/report.pdf'"));
+    }
+
+    #[test]
+    fn test_compose_arg_with_html_body() {
+        let adapter = ThunderbirdMailClientAdapter::new("thunderbird");
+
+        let to = vec![EmailAddress::parse("test@example.com").unwrap()];
+        let subject = Subject::new("テスト").unwrap();
+        let body = MailBody::new("- 資料作成\n- レビュー");
+        let html_body = Some(body.to_html());
+
+        let draft = MailDraft::new(to, vec![], subject, body).with_html_body(html_body);
+        let compose_arg = adapter.build_compose_arg(&draft);
+
+        assert!(compose_arg.starts_with("format=html,"));
+        assert!(compose_arg.contains("<li>資料作成</li>"));
+    }
+
     #[test]
     fn test_dry_run() {
         let adapter = ThunderbirdMailClientAdapter::new("thunderbird");
-        
+
         let to = vec![EmailAddress::parse("test@example.com").unwrap()];
         let cc = vec![];
         let subject = Subject::new("テスト").unwrap();
         let body = MailBody::new("テスト本文");
-        
+
         let draft = MailDraft::new(to, cc, subject, body);
-        
+
         // ドライランは常に成功するはず
         adapter.compose_mail(&draft, true).unwrap();
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_reject_custom_headers() {
+        let adapter = ThunderbirdMailClientAdapter::new("thunderbird");
+        let draft = MailDraft::new(
+            vec![EmailAddress::parse("test@example.com").unwrap()],
+            vec![],
+            Subject::new("テスト").unwrap(),
+            MailBody::new("テスト本文"),
+        )
+        .with_header("X-Priority", "1")
+        .unwrap();
+
+        let error = adapter.compose_mail(&draft, true).unwrap_err();
+
+        assert_eq!(error.code, Some("MC-MAIL-058"));
+        assert!(error.message.contains("X-Priority"));
+    }
+
+    #[cfg(unix)]
+    fn sample_draft() -> MailDraft {
+        MailDraft::new(
+            vec![EmailAddress::parse("test@example.com").unwrap()],
+            vec![],
+            Subject::new("テスト").unwrap(),
+            MailBody::new("テスト本文"),
+        )
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_error_output_is_captured() {
+        // `sleep`は`-compose`を不正なオプションとして標準エラー出力に出力し、エラー終了する
+        let adapter = ThunderbirdMailClientAdapter::new("sleep");
+
+        let error = adapter.compose_mail(&sample_draft(), false).unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InternalServerError);
+        assert!(error.message.contains("詳細: "));
+    }
+
+    #[cfg(unix)]
+    fn timeout_script(dir: &Path) -> (std::path::PathBuf, std::path::PathBuf) {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = dir.join("hung_thunderbird.sh");
+        let marker = dir.join("still_running");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho 'compose window opened' >&2\nsleep 1 2>/dev/null\ntouch '{}'\n",
+                marker.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        (script, marker)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_on_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let (script, marker) = timeout_script(dir.path());
+        let adapter = ThunderbirdMailClientAdapter::new(script.to_string_lossy())
+            .with_timeout(Duration::from_millis(200));
+
+        let started = Instant::now();
+        let error = adapter.compose_mail(&sample_draft(), false).unwrap_err();
+
+        assert_eq!(error.code, Some("MC-MAIL-054"));
+        assert!(error.is_retryable());
+        assert!(error.message.contains("compose window opened"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        // 強制終了されたプロセスは最後まで実行されない
+        thread::sleep(Duration::from_millis(1500));
+        assert!(!marker.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_detach_on_timeout_when_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let (script, marker) = timeout_script(dir.path());
+        let adapter = ThunderbirdMailClientAdapter::new(script.to_string_lossy())
+            .with_timeout(Duration::from_millis(200))
+            .with_detach_on_timeout(true);
+
+        let started = Instant::now();
+        let result = adapter.compose_mail(&sample_draft(), false);
+        let returned_after = started.elapsed();
+
+        // 切り離されたプロセスは強制終了されず、最後まで実行される
+        while !marker.exists() && started.elapsed() < Duration::from_secs(10) {
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        assert!(result.is_ok());
+        assert!(returned_after < Duration::from_secs(1));
+        assert!(marker.exists());
+    }
+}
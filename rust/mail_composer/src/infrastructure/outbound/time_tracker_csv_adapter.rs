@@ -0,0 +1,231 @@
+use crate::domain::{
+    interfaces::time_entry_source::{TimeEntry, TimeEntrySourcePort},
+    value_objects::time_zone::WorkTimeZone,
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{fs, path::Path};
+
+/// 開始日の列の見出し（Toggl Track・Clockify）
+const START_DATE_COLUMNS: [&str; 1] = ["start date"];
+/// 開始時刻の列の見出し
+const START_TIME_COLUMNS: [&str; 1] = ["start time"];
+/// 終了日の列の見出し
+const END_DATE_COLUMNS: [&str; 1] = ["end date"];
+/// 終了時刻の列の見出し
+const END_TIME_COLUMNS: [&str; 1] = ["end time"];
+/// 説明の列の見出し
+const DESCRIPTION_COLUMNS: [&str; 1] = ["description"];
+
+/// 日付の形式（Toggl Trackは`2025-04-01`、Clockifyは既定で`04/01/2025`）
+const DATE_FORMATS: [&str; 4] = ["%Y-%m-%d", "%m/%d/%Y", "%d.%m.%Y", "%Y/%m/%d"];
+/// 時刻の形式（Clockifyは設定により12時間表記）
+const TIME_FORMATS: [&str; 4] = ["%H:%M:%S", "%H:%M", "%I:%M:%S %p", "%I:%M %p"];
+
+/// Toggl TrackやClockifyの詳細レポートCSVを作業時間として読み込むアウトバウンドアダプター
+///
+/// 開始日・開始時刻・終了日・終了時刻の列を見出しで判別する（大文字・小文字は区別しない）。
+/// CSVの日時はUTCオフセットを含まないため、指定したタイムゾーンの日時として扱う
+pub struct TimeTrackerCsvAdapter {
+    entries: Vec<TimeEntry>,
+}
+
+impl TimeTrackerCsvAdapter {
+    /// 指定されたパスから詳細レポートCSVを読み込む
+    ///
+    /// ## Arguments
+    /// * `csv_path` - CSVのパスを表現する`Path`（ワークスペースルートからの相対パス）
+    /// * `time_zone` - CSVの日時を解釈するタイムゾーン
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<TimeTrackerCsvAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn load_from_csv(csv_path: &Path, time_zone: &WorkTimeZone) -> AppResult<Self> {
+        let path = workspace_path(csv_path)?;
+        let content = fs::read_to_string(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-WORK-037")
+                .with_message("作業時間CSVの読み込みに失敗しました。")
+                .with_action("ファイルパスの存在とアクセス権限、文字コードがUTF-8であることを確認してください。")
+                .with_source(e)
+        })?;
+
+        Self::from_csv_str(&content, time_zone)
+    }
+
+    /// CSV形式の文字列からアダプターを作成する
+    ///
+    /// 開始日時または終了日時が空の行（計測中の作業など）は読み飛ばす
+    ///
+    /// ## Arguments
+    /// * `content` - Toggl TrackまたはClockifyの詳細レポートCSV
+    /// * `time_zone` - CSVの日時を解釈するタイムゾーン
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<TimeTrackerCsvAdapter>`
+    /// * 失敗時 - CSVの形式が不正な場合の`Err<AppError>`
+    pub fn from_csv_str(content: &str, time_zone: &WorkTimeZone) -> AppResult<Self> {
+        let csv_error = |e: csv::Error| {
+            AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-WORK-038")
+                .with_message("作業時間CSVの解析に失敗しました。")
+                .with_action(
+                    "Toggl TrackまたはClockifyの詳細レポートからエクスポートしたCSVファイルを指定してください。",
+                )
+                .with_source(e)
+        };
+
+        let content = content.trim_start_matches('\u{feff}');
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(content.as_bytes());
+        let headers = reader.headers().map_err(csv_error)?.clone();
+        let column = |candidates: &[&str]| {
+            headers
+                .iter()
+                .position(|h| candidates.iter().any(|c| h.trim().eq_ignore_ascii_case(c)))
+        };
+        let (Some(start_date), Some(start_time), Some(end_date), Some(end_time)) = (
+            column(&START_DATE_COLUMNS),
+            column(&START_TIME_COLUMNS),
+            column(&END_DATE_COLUMNS),
+            column(&END_TIME_COLUMNS),
+        ) else {
+            return Err(AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("MC-WORK-039")
+                .with_message("作業時間CSVに開始日・開始時刻・終了日・終了時刻の列が見つかりません。")
+                .with_action(
+                    "Toggl TrackまたはClockifyの詳細レポートからエクスポートしたCSVファイルを指定してください。",
+                ));
+        };
+        let description = column(&DESCRIPTION_COLUMNS);
+
+        let mut entries = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            let value = |index: usize| record.get(index).unwrap_or_default().trim();
+            if [start_date, start_time, end_date, end_time]
+                .iter()
+                .any(|&index| value(index).is_empty())
+            {
+                continue;
+            }
+
+            let started_at =
+                time_zone.resolve_local(parse_date_time(value(start_date), value(start_time))?)?;
+            let ended_at =
+                time_zone.resolve_local(parse_date_time(value(end_date), value(end_time))?)?;
+            if ended_at < started_at {
+                return Err(AppError::new(ErrorKind::UnprocessableEntity)
+                    .with_code("MC-WORK-040")
+                    .with_message(format!(
+                        "作業時間CSVの終了日時が開始日時より前です。詳細: {started_at} - {ended_at}"
+                    ))
+                    .with_action("エクスポート元のデータを確認してください。"));
+            }
+            entries.push(TimeEntry {
+                started_at,
+                ended_at,
+                description: description
+                    .map(value)
+                    .filter(|d| !d.is_empty())
+                    .map(str::to_string),
+            });
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+impl TimeEntrySourcePort for TimeTrackerCsvAdapter {
+    fn list_entries(&self) -> AppResult<Vec<TimeEntry>> {
+        Ok(self.entries.clone())
+    }
+}
+
+/// 日付と時刻の文字列を、いずれかの対応形式で解析する
+fn parse_date_time(date: &str, time: &str) -> AppResult<NaiveDateTime> {
+    let parsed_date = DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date, format).ok());
+    let parsed_time = TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(time, format).ok());
+    match (parsed_date, parsed_time) {
+        (Some(date), Some(time)) => Ok(date.and_time(time)),
+        _ => Err(AppError::new(ErrorKind::UnprocessableEntity)
+            .with_code("MC-WORK-041")
+            .with_message(format!("作業時間CSVの日時が不正です。詳細: {date} {time}"))
+            .with_action(
+                "日付はYYYY-MM-DDまたはMM/DD/YYYY、時刻はHH:MM:SS形式でエクスポートしてください。",
+            )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokyo() -> WorkTimeZone {
+        WorkTimeZone::parse("Asia/Tokyo").unwrap()
+    }
+
+    #[test]
+    fn test_toggl_export() {
+        let adapter = TimeTrackerCsvAdapter::from_csv_str(
+            "User,Email,Client,Project,Task,Description,Billable,Start date,Start time,End date,End time,Duration,Tags\n\
+             山田,yamada@example.com,,社内,,設計,No,2025-04-01,09:00:00,2025-04-01,12:00:00,03:00:00,\n\
+             山田,yamada@example.com,,社内,,,No,2025-04-01,13:00:00,,,,\n",
+            &tokyo(),
+        )
+        .unwrap();
+        let entries = adapter.list_entries().unwrap();
+
+        // 終了日時がない計測中の行は読み飛ばす
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].started_at.to_rfc3339(),
+            "2025-04-01T09:00:00+09:00"
+        );
+        assert_eq!(
+            entries[0].ended_at.to_rfc3339(),
+            "2025-04-01T12:00:00+09:00"
+        );
+        assert_eq!(entries[0].description.as_deref(), Some("設計"));
+    }
+
+    #[test]
+    fn test_clockify_export() {
+        let adapter = TimeTrackerCsvAdapter::from_csv_str(
+            "Project,Client,Description,Task,User,Group,Email,Tags,Billable,Start Date,Start Time,End Date,End Time,Duration (h)\n\
+             社内,,レビュー,,山田,,yamada@example.com,,No,04/01/2025,01:30:00 PM,04/01/2025,06:00:00 PM,4.5\n",
+            &tokyo(),
+        )
+        .unwrap();
+        let entries = adapter.list_entries().unwrap();
+
+        assert_eq!(
+            entries[0].date(),
+            NaiveDate::from_ymd_opt(2025, 4, 1).unwrap()
+        );
+        assert_eq!(
+            entries[0].started_at.to_rfc3339(),
+            "2025-04-01T13:30:00+09:00"
+        );
+        assert_eq!(
+            entries[0].ended_at.to_rfc3339(),
+            "2025-04-01T18:00:00+09:00"
+        );
+    }
+
+    #[test]
+    fn test_missing_columns() {
+        assert!(TimeTrackerCsvAdapter::from_csv_str("Name,Hours\n山田,8\n", &tokyo()).is_err());
+    }
+}
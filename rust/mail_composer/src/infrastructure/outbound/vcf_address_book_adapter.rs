@@ -0,0 +1,249 @@
+use crate::domain::{
+    entities::address_book::{AddressBook, AddressBookEntry},
+    interfaces::address_book::AddressBookPort,
+    value_objects::{address_query::AddressQuery, email_address::EmailAddress},
+};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::{fs, path::Path};
+
+/// vCard（.vcf）形式の連絡先ファイルをアドレスブックとして扱うアウトバウンドアダプター
+///
+/// 表示名（FN、未設定の場合はN）を名前、EMAIL（PREF指定を優先）をメールアドレスとして読み込む
+pub struct VcfAddressBookAdapter {
+    book: AddressBook,
+}
+
+impl VcfAddressBookAdapter {
+    /// 指定されたパスからvCardファイルを読み込む
+    ///
+    /// ## Arguments
+    /// * `vcf_path` - vCardファイルのパスを表現する`Path`（ワークスペースルートからの相対パス）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<VcfAddressBookAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn load_from_vcf(vcf_path: &Path) -> AppResult<Self> {
+        let path = workspace_path(vcf_path)?;
+        let content = fs::read_to_string(&path).map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("MC-ADDR-030")
+                .with_message("vCardファイルの読み込みに失敗しました。")
+                .with_action("ファイルパスの存在とアクセス権限、文字コードがUTF-8であることを確認してください。")
+                .with_source(e)
+        })?;
+
+        Self::from_vcf_str(&content)
+    }
+
+    /// vCard形式の文字列からアダプターを作成する
+    ///
+    /// ## Arguments
+    /// * `content` - vCard形式の文字列
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<VcfAddressBookAdapter>`
+    /// * 失敗時 - 名前が重複している場合の`Err<AppError>`
+    pub fn from_vcf_str(content: &str) -> AppResult<Self> {
+        Ok(Self {
+            book: AddressBook::from_entries(parse_vcards(content))?,
+        })
+    }
+
+    /// 全てのエントリを取得する
+    ///
+    /// ## Returns
+    /// * 全てのAddressBookエントリのスライス
+    pub fn entries(&self) -> &[AddressBookEntry] {
+        self.book.entries()
+    }
+}
+
+impl AddressBookPort for VcfAddressBookAdapter {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        self.book.resolve(key_name)
+    }
+
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        self.book.expand(key_name)
+    }
+
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.entries().to_vec())
+    }
+
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.search(query)?.into_iter().cloned().collect())
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        Ok(self.book.reverse_resolve(address).map(str::to_string))
+    }
+}
+
+/// 読み込み中の1件分の連絡先
+#[derive(Default)]
+struct VcardBuilder {
+    formatted_name: Option<String>,
+    structured_name: Option<String>,
+    email: Option<String>,
+    preferred_email: Option<String>,
+}
+
+impl VcardBuilder {
+    /// 名前とメールアドレスが揃っている場合にエントリに変換する
+    fn build(self) -> Option<AddressBookEntry> {
+        let name = self
+            .formatted_name
+            .or(self.structured_name)
+            .filter(|name| !name.trim().is_empty())?;
+        let address = self.preferred_email.or(self.email)?;
+        Some(AddressBookEntry::person(name.trim(), address))
+    }
+}
+
+/// vCard形式の文字列を解析し、名前とメールアドレスを持つ連絡先をエントリに変換する
+///
+/// ## Arguments
+/// * `content` - vCard形式の文字列
+///
+/// ## Returns
+/// * 名前とメールアドレスを持つ連絡先のエントリ一覧
+fn parse_vcards(content: &str) -> Vec<AddressBookEntry> {
+    let mut entries = Vec::new();
+    let mut current: Option<VcardBuilder> = None;
+
+    for line in unfold_lines(content) {
+        let Some((property, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut params = property.split(';');
+        let name = params.next().unwrap_or_default();
+        // "item1.EMAIL"のようなグループ接頭辞を取り除く
+        let name = name.rsplit('.').next().unwrap_or(name).to_ascii_uppercase();
+        let params: Vec<String> = params.map(|p| p.to_ascii_uppercase()).collect();
+
+        match name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VCARD") => {
+                current = Some(VcardBuilder::default());
+            }
+            "END" if value.eq_ignore_ascii_case("VCARD") => {
+                if let Some(entry) = current.take().and_then(VcardBuilder::build) {
+                    entries.push(entry);
+                }
+            }
+            "FN" => {
+                if let Some(card) = current.as_mut() {
+                    card.formatted_name = Some(unescape(value));
+                }
+            }
+            "N" => {
+                if let Some(card) = current.as_mut() {
+                    // N:姓;名;ミドルネーム;敬称（前）;敬称（後）
+                    let parts: Vec<String> = value.split(';').take(2).map(unescape).collect();
+                    card.structured_name = Some(parts.join(" ").trim().to_string());
+                }
+            }
+            "EMAIL" => {
+                if let Some(card) = current.as_mut() {
+                    let address = unescape(value).trim().to_string();
+                    if address.is_empty() {
+                        continue;
+                    }
+                    let is_preferred = params.iter().any(|p| p.contains("PREF"));
+                    if is_preferred && card.preferred_email.is_none() {
+                        card.preferred_email = Some(address.clone());
+                    }
+                    card.email.get_or_insert(address);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    entries
+}
+
+/// 折り返された行（先頭が空白またはタブの行）を前の行に連結する
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        if let Some(continued) = line.strip_prefix([' ', '\t'])
+            && let Some(last) = lines.last_mut()
+        {
+            last.push_str(continued);
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+    lines
+}
+
+/// vCardのエスケープシーケンスを元の文字に戻す
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => result.push('\n'),
+            Some(escaped) => result.push(escaped),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_VCF: &[&str] = &[
+        "BEGIN:VCARD",
+        "VERSION:3.0",
+        "FN:山田 太郎",
+        "N:山田;太郎;;;",
+        "EMAIL;TYPE=INTERNET:yamada.home@example.com",
+        "EMAIL;TYPE=INTERNET,PREF:yamada@example.com",
+        "END:VCARD",
+        "BEGIN:VCARD",
+        "VERSION:4.0",
+        "N:佐藤;花子;;;",
+        "item1.EMAIL:sato@exam",
+        " ple.com",
+        "END:VCARD",
+        "BEGIN:VCARD",
+        "VERSION:3.0",
+        "FN:メールなし",
+        "END:VCARD",
+    ];
+
+    #[test]
+    fn test_parse_vcards() {
+        let adapter = VcfAddressBookAdapter::from_vcf_str(&SAMPLE_VCF.join("\r\n")).unwrap();
+
+        assert_eq!(adapter.entries().len(), 2);
+        assert_eq!(
+            adapter.resolve("山田 太郎").unwrap().as_str(),
+            "yamada@example.com"
+        );
+        assert_eq!(
+            adapter.resolve("佐藤 花子").unwrap().as_str(),
+            "sato@example.com"
+        );
+        assert!(adapter.resolve("メールなし").is_err());
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape(r"a\,b\;c\nd\\e"), "a,b;c\nd\\e");
+    }
+}
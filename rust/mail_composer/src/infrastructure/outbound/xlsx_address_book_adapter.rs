@@ -0,0 +1,195 @@
+use crate::domain::{
+    entities::address_book::{AddressBook, AddressBookEntry},
+    interfaces::address_book::AddressBookPort,
+    value_objects::{address_query::AddressQuery, email_address::EmailAddress},
+};
+use calamine::{Data, Reader, Xlsx, open_workbook};
+use share::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::workspace::workspace_path,
+};
+use std::path::Path;
+
+/// Excelのアドレスブックで読み込むシートと列の指定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XlsxAddressBookLayout {
+    /// 読み込むシート名
+    pub sheet: String,
+    /// 名前が記載された列の見出し
+    pub name_column: String,
+    /// メールアドレスが記載された列の見出し
+    pub address_column: String,
+}
+
+impl Default for XlsxAddressBookLayout {
+    fn default() -> Self {
+        Self {
+            sheet: "名簿".to_string(),
+            name_column: "名前".to_string(),
+            address_column: "メールアドレス".to_string(),
+        }
+    }
+}
+
+/// Excelブック（.xlsx）の名簿をアドレスブックとして扱うアウトバウンドアダプター
+///
+/// シートの1行目を見出しとして扱い、指定された列から名前とメールアドレスを読み込む
+pub struct XlsxAddressBookAdapter {
+    book: AddressBook,
+}
+
+impl XlsxAddressBookAdapter {
+    /// 指定されたパスからExcelブックを読み込む
+    ///
+    /// ## Arguments
+    /// * `xlsx_path` - Excelブックのパスを表現する`Path`（ワークスペースルートからの相対パス）
+    /// * `layout` - 読み込むシートと列の指定
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<XlsxAddressBookAdapter>`
+    /// * 失敗時 - `Err<AppError>`
+    pub fn load_from_xlsx(xlsx_path: &Path, layout: &XlsxAddressBookLayout) -> AppResult<Self> {
+        let path = workspace_path(xlsx_path)?;
+        let mut workbook: Xlsx<_> = open_workbook(&path)?;
+        let range = workbook.worksheet_range(&layout.sheet).map_err(|e| {
+            AppError::new(ErrorKind::NotFound)
+                .with_code("MC-ADDR-031")
+                .with_message(format!(
+                    "シート'{}'の読み込みに失敗しました。",
+                    layout.sheet
+                ))
+                .with_action("Excelブックに指定したシートが存在することを確認してください。")
+                .with_source(e)
+        })?;
+
+        Ok(Self {
+            book: AddressBook::from_entries(entries_from_rows(range.rows(), layout)?)?,
+        })
+    }
+
+    /// 全てのエントリを取得する
+    ///
+    /// ## Returns
+    /// * 全てのAddressBookエントリのスライス
+    pub fn entries(&self) -> &[AddressBookEntry] {
+        self.book.entries()
+    }
+}
+
+impl AddressBookPort for XlsxAddressBookAdapter {
+    fn resolve(&self, key_name: &str) -> AppResult<EmailAddress> {
+        self.book.resolve(key_name)
+    }
+
+    fn expand(&self, key_name: &str) -> AppResult<Vec<EmailAddress>> {
+        self.book.expand(key_name)
+    }
+
+    fn list_entries(&self) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.entries().to_vec())
+    }
+
+    fn search(&self, query: &AddressQuery) -> AppResult<Vec<AddressBookEntry>> {
+        Ok(self.book.search(query)?.into_iter().cloned().collect())
+    }
+
+    fn reverse_resolve(&self, address: &str) -> AppResult<Option<String>> {
+        Ok(self.book.reverse_resolve(address).map(str::to_string))
+    }
+}
+
+/// シートの行からAddressBookエントリを取り出す
+///
+/// 名前とメールアドレスのどちらかが空の行は読み飛ばす
+fn entries_from_rows<'a>(
+    mut rows: impl Iterator<Item = &'a [Data]>,
+    layout: &XlsxAddressBookLayout,
+) -> AppResult<Vec<AddressBookEntry>> {
+    let header = rows.next().unwrap_or_default();
+    let column_index = |column: &str| {
+        header
+            .iter()
+            .position(|cell| cell_text(cell) == column)
+            .ok_or_else(|| {
+                AppError::new(ErrorKind::UnprocessableEntity)
+                    .with_code("MC-ADDR-032")
+                    .with_message(format!(
+                        "シート'{}'に列'{}'が見つかりません。",
+                        layout.sheet, column
+                    ))
+                    .with_action(
+                        "シートの1行目に名前とメールアドレスの見出しがあることを確認してください。",
+                    )
+            })
+    };
+    let name_index = column_index(&layout.name_column)?;
+    let address_index = column_index(&layout.address_column)?;
+
+    Ok(rows
+        .filter_map(|row| {
+            let name = row.get(name_index).map(cell_text)?;
+            let address = row.get(address_index).map(cell_text)?;
+            (!name.is_empty() && !address.is_empty())
+                .then_some(AddressBookEntry::person(name, address))
+        })
+        .collect())
+}
+
+/// セルの値を前後の空白を除いた文字列として取得する
+fn cell_text(cell: &Data) -> String {
+    cell.to_string().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cells: &[&str]) -> Vec<Data> {
+        cells
+            .iter()
+            .map(|cell| match *cell {
+                "" => Data::Empty,
+                text => Data::String(text.to_string()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_entries_from_rows() {
+        let rows = [
+            row(&["社員番号", "メールアドレス", "名前"]),
+            row(&["001", "yamada@example.com", " 山田さん "]),
+            row(&["002", "", "佐藤さん"]),
+            row(&["003", "suzuki@example.com", "鈴木さん"]),
+        ];
+
+        let entries = entries_from_rows(
+            rows.iter().map(|r| r.as_slice()),
+            &XlsxAddressBookLayout::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                AddressBookEntry::person("山田さん", "yamada@example.com"),
+                AddressBookEntry::person("鈴木さん", "suzuki@example.com"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entries_from_rows_missing_column() {
+        let rows = [row(&["名前", "電話番号"])];
+
+        let result = entries_from_rows(
+            rows.iter().map(|r| r.as_slice()),
+            &XlsxAddressBookLayout::default(),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use crate::domain::{
+    entities::work_record::WorkRecord, interfaces::work_time_export::WorkTimeExportPort,
+    value_objects::mail_objects::WorkTime,
+};
+use chrono::{Datelike, NaiveDate};
+use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Workbook};
+use share::{
+    error::app_error::AppResult,
+    utils::workspace::{ensure_directory_exists, workspace_path},
+};
+use std::path::PathBuf;
+
+/// 見出し行と列幅
+const COLUMNS: [(&str, f64); 6] = [
+    ("日付", 12.0),
+    ("曜日", 6.0),
+    ("開始", 8.0),
+    ("終了", 8.0),
+    ("作業時間", 10.0),
+    ("備考", 40.0),
+];
+
+/// 曜日の日本語表記（月曜始まり）
+const WEEKDAYS: [&str; 7] = ["月", "火", "水", "木", "金", "土", "日"];
+
+/// 1日の分数（Excelの時刻は1日を1とする小数で表す）
+const MINUTES_PER_DAY: f64 = 24.0 * 60.0;
+
+/// 作業記録を書式付きのExcelブック（.xlsx）として出力ディレクトリに書き出すアウトバウンドアダプター
+///
+/// 1日1行で出力し、最終行に作業時間の合計を記載する。
+/// 時刻と作業時間はExcel上で計算できるよう、書式付きの数値として書き込む
+pub struct XlsxWorkTimeExportAdapter {
+    output_dir: String,
+}
+
+impl XlsxWorkTimeExportAdapter {
+    /// 新しいXlsxWorkTimeExportAdapterを作成する
+    ///
+    /// ## Arguments
+    /// * `output_dir` - 出力ディレクトリ（相対パスはワークスペースルート基準）
+    ///
+    /// ## Returns
+    /// * XlsxWorkTimeExportAdapterのインスタンス
+    pub fn new(output_dir: impl Into<String>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    /// 時刻をExcelの時刻（1日を1とする小数）に変換する
+    fn excel_time(time: &WorkTime) -> f64 {
+        time.minutes_of_day() as f64 / MINUTES_PER_DAY
+    }
+
+    /// 作業記録のシートを持つブックを作成する
+    fn build_workbook(
+        sheet_name: &str,
+        records: &[(NaiveDate, WorkRecord)],
+    ) -> AppResult<Workbook> {
+        let border = || Format::new().set_border(FormatBorder::Thin);
+        let header_format = border()
+            .set_bold()
+            .set_align(FormatAlign::Center)
+            .set_background_color("#D9E1F2");
+        let text_format = border();
+        let center_format = border().set_align(FormatAlign::Center);
+        let time_format = border().set_num_format("hh:mm");
+        let duration_format = border().set_num_format("[h]:mm");
+        let total_label_format = border().set_bold().set_background_color("#F2F2F2");
+        let total_format = total_label_format.clone().set_num_format("[h]:mm");
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(sheet_name)?;
+        for (col, (title, width)) in (0u16..).zip(COLUMNS) {
+            sheet.write_string_with_format(0, col, title, &header_format)?;
+            sheet.set_column_width(col, width)?;
+        }
+        sheet.set_freeze_panes(1, 0)?;
+
+        let mut total_minutes = 0;
+        for (row, (date, record)) in (1u32..).zip(records) {
+            let weekday = WEEKDAYS[date.weekday().num_days_from_monday() as usize];
+            sheet.write_string_with_format(row, 0, date.to_string(), &center_format)?;
+            sheet.write_string_with_format(row, 1, weekday, &center_format)?;
+            for (col, time) in [(2, &record.start), (3, &record.end)] {
+                match time.as_ref().map(Self::excel_time) {
+                    Some(value) => sheet.write_number_with_format(row, col, value, &time_format)?,
+                    None => sheet.write_blank(row, col, &time_format)?,
+                };
+            }
+            match record.duration() {
+                Some(minutes) => {
+                    total_minutes += minutes;
+                    sheet.write_number_with_format(
+                        row,
+                        4,
+                        minutes as f64 / MINUTES_PER_DAY,
+                        &duration_format,
+                    )?
+                }
+                None => sheet.write_blank(row, 4, &duration_format)?,
+            };
+            sheet.write_string_with_format(
+                row,
+                5,
+                record.notes.as_deref().unwrap_or_default(),
+                &text_format,
+            )?;
+        }
+
+        let total_row = records.len() as u32 + 1;
+        sheet.write_string_with_format(total_row, 0, "合計", &total_label_format)?;
+        for col in [1, 2, 3, 5] {
+            sheet.write_blank(total_row, col, &total_label_format)?;
+        }
+        sheet.write_number_with_format(
+            total_row,
+            4,
+            total_minutes as f64 / MINUTES_PER_DAY,
+            &total_format,
+        )?;
+
+        Ok(workbook)
+    }
+}
+
+impl WorkTimeExportPort for XlsxWorkTimeExportAdapter {
+    fn export(&self, file_stem: &str, records: &[(NaiveDate, WorkRecord)]) -> AppResult<PathBuf> {
+        let dir = workspace_path(&self.output_dir)?;
+        ensure_directory_exists(&dir)?;
+        let path = dir.join(format!("{file_stem}.xlsx"));
+
+        // シート名は31文字以内のため、ファイル名が長い場合は末尾を使用する
+        let sheet_name: String = {
+            let chars: Vec<char> = file_stem.chars().collect();
+            chars[chars.len().saturating_sub(31)..].iter().collect()
+        };
+        let mut workbook = Self::build_workbook(&sheet_name, records)?;
+        workbook.save(&path)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calamine::{Data, Reader, Xlsx, open_workbook};
+    use std::fs;
+
+    #[test]
+    fn test_export_xlsx() {
+        let mut full_day = WorkRecord::default();
+        full_day.set_start(WorkTime::new("09:00").unwrap());
+        full_day.set_end(WorkTime::new("18:00").unwrap());
+        full_day.notes = Some("客先訪問".to_string());
+        let mut half_day = WorkRecord::default();
+        half_day.set_start(WorkTime::new("13:00").unwrap());
+        half_day.set_end(WorkTime::new("18:00").unwrap());
+        let records = [
+            (NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(), full_day),
+            (NaiveDate::from_ymd_opt(2025, 4, 2).unwrap(), half_day),
+        ];
+
+        let dir =
+            std::env::temp_dir().join(format!("mail_composer_xlsx_export_{}", std::process::id()));
+        let path = XlsxWorkTimeExportAdapter::new(dir.to_string_lossy())
+            .export("work_times_2025-04", &records)
+            .unwrap();
+        let mut workbook: Xlsx<_> = open_workbook(&path).unwrap();
+        let range = workbook.worksheet_range("work_times_2025-04").unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(range.height(), 4);
+        assert_eq!(
+            range.get((1, 0)),
+            Some(&Data::String("2025-04-01".to_string()))
+        );
+        assert_eq!(range.get((1, 1)), Some(&Data::String("火".to_string())));
+        assert_eq!(
+            range.get((1, 5)),
+            Some(&Data::String("客先訪問".to_string()))
+        );
+        assert_eq!(range.get((3, 0)), Some(&Data::String("合計".to_string())));
+        let total = match range.get((3, 4)) {
+            Some(Data::Float(value)) => *value,
+            Some(Data::DateTime(value)) => value.as_f64(),
+            other => panic!("unexpected total cell: {other:?}"),
+        };
+        assert!((total * MINUTES_PER_DAY - 840.0).abs() < 1e-6);
+    }
+}
@@ -1,3 +1,7 @@
 pub mod application;
 pub mod domain;
+pub mod error_codes;
 pub mod infrastructure;
+
+/// プラットフォーム標準のディレクトリ名などに使用するアプリケーション名
+pub const APP_NAME: &str = "mail_composer";
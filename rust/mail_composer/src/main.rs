@@ -0,0 +1,32 @@
+use mail_composer::{
+    APP_NAME,
+    infrastructure::inbound::{cli::Cli, messages::Messages},
+};
+use share::{error::app_error::AppError, utils::dotenv::load_app_dotenv};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    // SAFETY: 他のスレッドを起動する前のmainの先頭で呼び出している
+    if let Err(e) = unsafe { load_app_dotenv(APP_NAME) } {
+        report_error(&e, &Messages::new(Cli::env_language()));
+        return ExitCode::FAILURE;
+    }
+
+    let cli = Cli::parse_localized();
+    if let Err(e) = cli.init_logging() {
+        report_error(&e, &Messages::new(Cli::env_language()));
+        return ExitCode::FAILURE;
+    }
+    match cli.run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            report_error(&e, &cli.messages());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// エラーメッセージと元のエラー、対処法を標準エラー出力に表示する
+fn report_error(error: &AppError, messages: &Messages) {
+    eprintln!("❌ {}", error.report(messages.locale()));
+}
@@ -0,0 +1,170 @@
+use crate::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, error::Category};
+
+/// `(取得元の名前, 元のテキスト)`の組
+///
+/// 型の不一致を行・列で報告するために使用する
+pub type SourceText<'a> = (&'a str, &'a str);
+
+/// JSONのテキストを解析し、エラー時は取得元・行・列と該当行を示すAppErrorを返す
+///
+/// ## Arguments
+/// * `text` - 解析するJSONのテキスト
+/// * `origin` - 診断メッセージに表示する取得元の名前（ファイルパスなど）
+///
+/// ## Returns
+/// * 成功時 - 解析結果
+/// * 失敗時 - 行・列を含むAppError
+///
+/// ## Examples
+/// ```rust
+/// use share::config::json::parse_json_str;
+///
+/// let error = parse_json_str::<serde_json::Value>("{\n  \"from\": \"山田\",\n}", "app.json")
+///     .unwrap_err();
+/// assert!(error.message.starts_with("app.jsonの3行1列目"));
+/// ```
+pub fn parse_json_str<T: DeserializeOwned>(text: &str, origin: &str) -> AppResult<T> {
+    serde_json::from_str(text).map_err(|e| located_error(e, text, origin))
+}
+
+/// マージ済みのJSONの値を任意の型に変換する
+///
+/// 変換に失敗した場合は、優先度の高い取得元から順に元のテキストを解析し直し、
+/// 原因となった箇所の行・列を特定する
+///
+/// ## Arguments
+/// * `value` - 変換するJSONの値
+/// * `sources` - 値の元になった`(取得元の名前, テキスト)`の一覧（優先度の低い順）
+///
+/// ## Returns
+/// * 成功時 - 変換結果
+/// * 失敗時 - 箇所を特定できた場合は行・列を含むAppError
+pub fn deserialize_value<T: DeserializeOwned>(
+    value: Value,
+    sources: &[SourceText<'_>],
+) -> AppResult<T> {
+    let error = match serde_json::from_value(value) {
+        Ok(parsed) => return Ok(parsed),
+        Err(e) => e,
+    };
+
+    // 必須項目の欠落は他の取得元で補われている可能性があるため、型の不一致のみを対象とする
+    for (origin, text) in sources.iter().rev() {
+        if let Err(located) = serde_json::from_str::<T>(text)
+            && located.classify() == Category::Data
+            && located.line() > 0
+            && !located.to_string().starts_with("missing field")
+        {
+            return Err(located_error(located, text, origin));
+        }
+    }
+
+    let origins = sources
+        .iter()
+        .map(|(origin, _)| *origin)
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(AppError::new(ErrorKind::UnprocessableEntity)
+        .with_code("SH-CONF-001")
+        .with_message(format!("設定値の形式が正しくありません。詳細: {error}"))
+        .with_action(if origins.is_empty() {
+            "設定値の型と必須項目を確認してください。".to_string()
+        } else {
+            format!("次の設定の型と必須項目を確認してください: {origins}")
+        })
+        .with_source(error))
+}
+
+/// serde_jsonのエラーを取得元・行・列と該当行の抜粋を含むAppErrorに変換する
+fn located_error(error: serde_json::Error, text: &str, origin: &str) -> AppError {
+    let (line, column) = (error.line(), error.column());
+    let detail = error.to_string();
+    let detail = detail
+        .strip_suffix(&format!(" at line {line} column {column}"))
+        .unwrap_or(&detail);
+
+    let mut message =
+        format!("{origin}の{line}行{column}列目で設定の解析に失敗しました。詳細: {detail}");
+    if let Some(excerpt) = excerpt(text, line, column) {
+        message.push('\n');
+        message.push_str(&excerpt);
+    }
+
+    AppError::new(ErrorKind::UnprocessableEntity)
+        .with_code("SH-CONF-002")
+        .with_message(message)
+        .with_action(format!(
+            "{origin}の{line}行目付近の記述（カンマや引用符、値の型）を確認してください。"
+        ))
+        .with_source(error)
+}
+
+/// エラー箇所の行と、列を指し示す`^`を整形する
+fn excerpt(text: &str, line: usize, column: usize) -> Option<String> {
+    let source_line = text.lines().nth(line.checked_sub(1)?)?;
+    // serde_jsonの列はバイト単位のため、文字数に換算して位置を合わせる
+    let prefix_chars = source_line
+        .char_indices()
+        .take_while(|(index, _)| *index < column.saturating_sub(1))
+        .count();
+    let gutter = line.to_string().len();
+    Some(format!(
+        "{line} | {source_line}\n{} | {}^",
+        " ".repeat(gutter),
+        " ".repeat(prefix_chars)
+    ))
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Sample {
+        #[allow(dead_code)]
+        from: String,
+        #[allow(dead_code)]
+        timeout: u64,
+    }
+
+    #[test]
+    fn syntax_error_location() {
+        let text = "{\n  \"from\": \"山田\"\n  \"timeout\": 30\n}";
+        let error = parse_json_str::<Value>(text, "app.json").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::UnprocessableEntity);
+        assert!(error.message.starts_with("app.jsonの3行3列目"));
+        assert!(error.message.ends_with("3 |   \"timeout\": 30\n  |   ^"));
+    }
+
+    #[test]
+    fn type_error_location() {
+        let defaults = r#"{ "from": "", "timeout": 30 }"#;
+        let file = "{\n  \"from\": \"山田\",\n  \"timeout\": \"遅め\"\n}";
+        let value = serde_json::json!({ "from": "山田", "timeout": "遅め" });
+
+        let error =
+            deserialize_value::<Sample>(value, &[("defaults", defaults), ("app.json", file)])
+                .unwrap_err();
+
+        assert!(error.message.starts_with("app.jsonの3行"));
+        assert!(error.message.contains("invalid type: string \"遅め\""));
+    }
+
+    #[test]
+    fn missing_field_without_location() {
+        let file = r#"{ "from": "山田" }"#;
+        let value: Value = serde_json::from_str(file).unwrap();
+
+        let error = deserialize_value::<Sample>(value, &[("app.json", file)]).unwrap_err();
+
+        assert!(error.message.contains("missing field `timeout`"));
+        assert!(error.action.unwrap().contains("app.json"));
+    }
+}
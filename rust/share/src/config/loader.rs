@@ -0,0 +1,167 @@
+use crate::{
+    config::{json::deserialize_value, merge::merge_values, source::ConfigSource},
+    error::app_error::AppResult,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// 複数の取得元の設定値を重ね合わせ、任意の型として読み込む
+///
+/// 後に追加した取得元ほど優先される。マージの規則は[`merge_values`]を参照
+///
+/// ## Examples
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_json::json;
+/// use share::config::{loader::ConfigLoader, source::ValueSource};
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     from: String,
+///     timeout: u64,
+/// }
+///
+/// let config: Config = ConfigLoader::new()
+///     .with_source(ValueSource::new("defaults", json!({ "from": "", "timeout": 30 })))
+///     .with_source(ValueSource::new("command line", json!({ "from": "山田" })))
+///     .load()
+///     .unwrap();
+/// assert_eq!((config.from.as_str(), config.timeout), ("山田", 30));
+/// ```
+#[derive(Default)]
+pub struct ConfigLoader {
+    sources: Vec<Box<dyn ConfigSource>>,
+}
+
+impl ConfigLoader {
+    /// 取得元を持たないConfigLoaderを作成する
+    ///
+    /// ## Returns
+    /// * ConfigLoaderのインスタンス
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 取得元を追加する（既存の取得元より優先される）
+    ///
+    /// ## Arguments
+    /// * `source` - 追加する取得元
+    ///
+    /// ## Returns
+    /// * 取得元が追加されたConfigLoaderのインスタンス
+    pub fn with_source(mut self, source: impl ConfigSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    /// 全ての取得元を読み込み、マージしたJSONの値を取得する
+    ///
+    /// ## Returns
+    /// * 成功時 - マージ後の値（値を返した取得元の名前の一覧と共に返す）
+    /// * 失敗時 - いずれかの取得元の読み込みに失敗した場合のAppError
+    pub fn load_value(&self) -> AppResult<(Value, Vec<String>)> {
+        let mut merged = Value::Object(Map::new());
+        let mut loaded = Vec::new();
+        for source in &self.sources {
+            if let Some(value) = source.load()? {
+                merge_values(&mut merged, value);
+                loaded.push(source.name());
+            }
+        }
+        Ok((merged, loaded))
+    }
+
+    /// 全ての取得元をマージし、指定した型に変換する
+    ///
+    /// ## Returns
+    /// * 成功時 - 変換結果
+    /// * 失敗時 - 読み込みに失敗した場合、または型が一致しない場合のAppError
+    ///   （型の不一致は可能な限り取得元と行・列を示す）
+    pub fn load<T: DeserializeOwned>(&self) -> AppResult<T> {
+        let (merged, loaded) = self.load_value()?;
+        let texts = self
+            .sources
+            .iter()
+            .filter(|source| loaded.contains(&source.name()))
+            .map(|source| (source.name(), source.text().unwrap_or_default()))
+            .collect::<Vec<_>>();
+        let sources = texts
+            .iter()
+            .map(|(name, text)| (name.as_str(), text.as_str()))
+            .collect::<Vec<_>>();
+        deserialize_value(merged, &sources)
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+    use crate::config::source::{JsonFileSource, ValueSource};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::fs;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        from: String,
+        timeout: u64,
+        #[serde(default)]
+        cc: Vec<String>,
+    }
+
+    #[test]
+    fn later_sources_take_precedence() {
+        let dir = std::env::temp_dir().join(format!("share_config_loader_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.json");
+        fs::write(&path, r#"{ "from": "山田", "cc": ["佐藤"] }"#).unwrap();
+
+        let defaults = Config {
+            from: String::new(),
+            timeout: 30,
+            cc: Vec::new(),
+        };
+        let config: AppResult<Config> = ConfigLoader::new()
+            .with_source(ValueSource::from_serialize("defaults", &defaults).unwrap())
+            .with_source(JsonFileSource::new(&path))
+            .with_source(JsonFileSource::new(dir.join("local.json")).optional())
+            .with_source(ValueSource::new("command line", json!({ "timeout": 60 })))
+            .load();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            config.unwrap(),
+            Config {
+                from: "山田".to_string(),
+                timeout: 60,
+                cc: vec!["佐藤".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn missing_required_file() {
+        let result = ConfigLoader::new()
+            .with_source(JsonFileSource::new("/nonexistent/share_config/app.json"))
+            .load::<Config>();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn type_error_points_to_file() {
+        let dir =
+            std::env::temp_dir().join(format!("share_config_loader_type_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.json");
+        fs::write(&path, "{\n  \"from\": \"山田\",\n  \"timeout\": \"60\"\n}").unwrap();
+
+        let result = ConfigLoader::new()
+            .with_source(ValueSource::new("defaults", json!({ "timeout": 30 })))
+            .with_source(JsonFileSource::new(&path))
+            .load::<Config>();
+        let _ = fs::remove_dir_all(&dir);
+
+        let error = result.unwrap_err();
+        assert!(error.message.contains("app.jsonの3行"));
+    }
+}
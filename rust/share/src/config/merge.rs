@@ -0,0 +1,76 @@
+use serde_json::Value;
+
+/// `overlay`の値を`base`に重ね合わせる
+///
+/// * 両方がオブジェクトの場合 - キーごとに再帰的にマージする
+/// * `overlay`が`null`の場合 - 未指定とみなし、`base`の値を維持する
+/// * それ以外（配列を含む） - `overlay`の値で置き換える
+///
+/// ## Arguments
+/// * `base` - マージ先の値（優先度の低い取得元）
+/// * `overlay` - 重ね合わせる値（優先度の高い取得元）
+///
+/// ## Examples
+/// ```rust
+/// use serde_json::json;
+/// use share::config::merge::merge_values;
+///
+/// let mut base = json!({ "imap": { "host": "localhost", "port": 143 }, "tags": ["a"] });
+/// merge_values(&mut base, json!({ "imap": { "port": 993 }, "tags": ["b"] }));
+/// assert_eq!(base, json!({ "imap": { "host": "localhost", "port": 993 }, "tags": ["b"] }));
+/// ```
+pub fn merge_values(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (_, Value::Null) => {}
+        (Value::Object(base_fields), Value::Object(overlay_fields)) => {
+            for (key, value) in overlay_fields {
+                match base_fields.get_mut(&key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => {
+                        if !value.is_null() {
+                            base_fields.insert(key, value);
+                        }
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn merge_nested_objects() {
+        let mut base = json!({ "from": "山田", "imap": { "host": "localhost", "port": 143 } });
+        merge_values(
+            &mut base,
+            json!({ "department": "開発部", "imap": { "port": 993, "use_tls": true } }),
+        );
+        assert_eq!(
+            base,
+            json!({
+                "from": "山田",
+                "department": "開発部",
+                "imap": { "host": "localhost", "port": 993, "use_tls": true }
+            })
+        );
+    }
+
+    #[test]
+    fn null_keeps_base_value() {
+        let mut base = json!({ "from": "山田", "timeout": 30 });
+        merge_values(&mut base, json!({ "from": null, "extra": null }));
+        assert_eq!(base, json!({ "from": "山田", "timeout": 30 }));
+    }
+
+    #[test]
+    fn scalar_and_array_replace() {
+        let mut base = json!({ "names": ["a", "b"], "imap": { "port": 143 } });
+        merge_values(&mut base, json!({ "names": ["c"], "imap": "disabled" }));
+        assert_eq!(base, json!({ "names": ["c"], "imap": "disabled" }));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod json;
+pub mod loader;
+pub mod merge;
+pub mod source;
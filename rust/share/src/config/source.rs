@@ -0,0 +1,141 @@
+use crate::{
+    config::json::parse_json_str,
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// 設定値の取得元
+///
+/// 取得した値はJSONの値として返し、[`ConfigLoader`](crate::config::loader::ConfigLoader)で
+/// 後に追加した取得元ほど優先してマージする
+pub trait ConfigSource {
+    /// 診断メッセージに表示する取得元の名前（ファイルパスなど）
+    fn name(&self) -> String;
+
+    /// 設定値を読み込む
+    ///
+    /// ## Returns
+    /// * 成功時 - 取得元が存在する場合は`Ok(Some(値))`、存在しない場合は`Ok(None)`
+    /// * 失敗時 - 読み込みまたは解析に失敗した場合の`Err<AppError>`
+    fn load(&self) -> AppResult<Option<Value>>;
+
+    /// 型の不一致を行・列で報告するために、元のテキストを取得する
+    ///
+    /// ## Returns
+    /// * テキストを持たない取得元の場合は`None`
+    fn text(&self) -> Option<String> {
+        None
+    }
+}
+
+/// JSONファイルの取得元
+pub struct JsonFileSource {
+    path: PathBuf,
+    required: bool,
+}
+
+impl JsonFileSource {
+    /// 存在しなければならないJSONファイルの取得元を作成する
+    ///
+    /// ## Arguments
+    /// * `path` - JSONファイルのパス
+    ///
+    /// ## Returns
+    /// * JsonFileSourceのインスタンス
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            required: true,
+        }
+    }
+
+    /// ファイルが存在しない場合は読み飛ばすようにする
+    ///
+    /// ## Returns
+    /// * 省略可能なJsonFileSourceのインスタンス
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+}
+
+impl ConfigSource for JsonFileSource {
+    fn name(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    fn load(&self) -> AppResult<Option<Value>> {
+        if !self.required && !self.path.exists() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(&self.path).map_err(|e| {
+            AppError::new(ErrorKind::NotFound)
+                .with_code("SH-CONF-003")
+                .with_message(format!(
+                    "設定ファイルの読み込みに失敗しました。詳細: {}",
+                    self.name()
+                ))
+                .with_action("設定ファイルの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })?;
+        parse_json_str(&text, &self.name()).map(Some)
+    }
+
+    fn text(&self) -> Option<String> {
+        fs::read_to_string(&self.path).ok()
+    }
+}
+
+/// メモリ上の値の取得元（既定値やコマンドライン引数など）
+pub struct ValueSource {
+    name: String,
+    value: Value,
+}
+
+impl ValueSource {
+    /// JSONの値から取得元を作成する
+    ///
+    /// ## Arguments
+    /// * `name` - 取得元の名前
+    /// * `value` - 設定値
+    ///
+    /// ## Returns
+    /// * ValueSourceのインスタンス
+    pub fn new(name: impl Into<String>, value: Value) -> Self {
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+
+    /// シリアライズ可能な値（既定値の構造体など）から取得元を作成する
+    ///
+    /// ## Arguments
+    /// * `name` - 取得元の名前
+    /// * `value` - 設定値
+    ///
+    /// ## Returns
+    /// * 成功時 - ValueSourceのインスタンス
+    /// * 失敗時 - JSONに変換できない場合のAppError
+    pub fn from_serialize<T: Serialize>(name: impl Into<String>, value: &T) -> AppResult<Self> {
+        Ok(Self::new(name, serde_json::to_value(value)?))
+    }
+}
+
+impl ConfigSource for ValueSource {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn load(&self) -> AppResult<Option<Value>> {
+        Ok(Some(self.value.clone()))
+    }
+}
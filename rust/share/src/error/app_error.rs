@@ -1,7 +1,9 @@
-use crate::error::kind::ErrorKind;
-use derive_more::Display;
+use crate::error::{
+    catalog::{self, Locale},
+    kind::ErrorKind,
+};
 use serde::Serialize;
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt};
 use thiserror::Error;
 
 /// 本プロジェクト内で使用する結果型
@@ -26,8 +28,10 @@ pub type AppResult<T> = Result<T, AppError>;
 ///
 /// ## Fields
 /// * `kind` - エラー種別（[`ErrorKind`]）
+/// * `code` - エラーを一意に識別するコード（オプション、例: `XX-DEMO-001`）
 /// * `message` - ユーザー向けのエラーメッセージ
 /// * `action` - ユーザー向けの対処法（オプション）
+/// * `retryable` - 再試行で解消する見込みがあるか（オプション、未設定時は[`ErrorKind`]から判定）
 /// * `source` - 元となったエラー（オプション、シリアライズ対象外）
 ///
 /// ## Examples
@@ -38,13 +42,16 @@ pub type AppResult<T> = Result<T, AppError>;
 ///     .with_message("無効なリクエストです。")
 ///     .with_action("入力内容を確認してください。");
 /// ```
-#[derive(Debug, Error, Serialize, Display)]
-#[display("kind: {}, message: {message}", kind.as_str())]
+#[derive(Debug, Error, Serialize)]
 pub struct AppError {
     pub kind: ErrorKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'static str>,
     pub message: Cow<'static, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action: Option<Cow<'static, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retryable: Option<bool>,
     #[serde(skip_serializing)]
     #[source]
     pub source: Option<Box<dyn std::error::Error + Send + Sync>>,
@@ -72,12 +79,73 @@ impl AppError {
     pub fn new(kind: ErrorKind) -> Self {
         Self {
             kind,
-            message: Cow::Borrowed("エラーが発生しました。"),
+            code: None,
+            message: Cow::Borrowed(catalog::DEFAULT_MESSAGE),
             action: None,
+            retryable: None,
             source: None,
         }
     }
 
+    /// エラーメッセージカタログに登録された既定のメッセージと対処法で[`AppError`]を作成する
+    ///
+    /// カタログに登録されていないコードの場合は、コードのみを設定する
+    ///
+    /// ## Arguments
+    /// * `kind` - エラー種別（[`ErrorKind`]）
+    /// * `code` - カタログに登録されたエラーコード（例: `SH-IO-001`）
+    ///
+    /// ## Returns
+    /// * 日本語のメッセージと対処法が設定された[`AppError`]インスタンス
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::{app_error::AppError, kind::ErrorKind};
+    ///
+    /// let error = AppError::from_catalog(ErrorKind::NotFound, "SH-IO-001");
+    /// assert_eq!(error.code, Some("SH-IO-001"));
+    /// assert_eq!(error.message, "指定されたファイルまたはディレクトリが見つかりません。");
+    /// assert_eq!(error.action.as_deref(), Some("ファイルパスを確認してください。"));
+    /// ```
+    pub fn from_catalog(kind: ErrorKind, code: &'static str) -> Self {
+        let error = Self::new(kind).with_code(code);
+        match catalog::lookup(code) {
+            Some(entry) => error
+                .with_message(entry.japanese.message)
+                .with_action(entry.japanese.action),
+            None => error,
+        }
+    }
+
+    /// エラーを一意に識別するコードを設定する
+    ///
+    /// コードは`<ツール>-<分類>-<連番>`の形式とし、一度割り当てたコードは変更しない
+    ///
+    /// ## Arguments
+    /// * `code` - 設定するコード（例: `XX-DEMO-001`）
+    ///
+    /// ## Returns
+    /// * コードが設定された[`AppError`]インスタンス
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::{app_error::AppError, kind::ErrorKind};
+    ///
+    /// let error = AppError::new(ErrorKind::NotFound)
+    ///     .with_code("XX-DEMO-001")
+    ///     .with_message("宛先が見つかりません。");
+    /// assert_eq!(error.code, Some("XX-DEMO-001"));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "code: XX-DEMO-001, kind: Not Found, message: 宛先が見つかりません。"
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
     /// エラーメッセージを設定する
     ///
     /// ## Arguments
@@ -134,6 +202,48 @@ impl AppError {
         self
     }
 
+    /// 再試行で解消する見込みがあるかを明示する
+    ///
+    /// エラー種別の既定の判定（[`ErrorKind::is_retryable`]）と異なる場合に指定する
+    ///
+    /// ## Arguments
+    /// * `retryable` - 再試行で解消する見込みがある場合は`true`
+    ///
+    /// ## Returns
+    /// * 再試行可否が設定された[`AppError`]インスタンス
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::{app_error::AppError, kind::ErrorKind};
+    ///
+    /// // 接続先の誤りはサービス停止と同じ種別でも再試行しない
+    /// let error = AppError::new(ErrorKind::ServiceUnavailable).retryable(false);
+    /// assert!(!error.is_retryable());
+    /// ```
+    #[inline]
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = Some(retryable);
+        self
+    }
+
+    /// 再試行で解消する見込みがあるかを判定する
+    ///
+    /// ## Returns
+    /// * [`AppError::retryable`]で明示された値、未設定の場合はエラー種別の既定の判定
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::{app_error::AppError, kind::ErrorKind};
+    ///
+    /// assert!(AppError::new(ErrorKind::RequestTimeout).is_retryable());
+    /// assert!(!AppError::new(ErrorKind::NotFound).is_retryable());
+    /// assert!(AppError::new(ErrorKind::InternalServerError).retryable(true).is_retryable());
+    /// ```
+    #[inline]
+    pub fn is_retryable(&self) -> bool {
+        self.retryable.unwrap_or(self.kind.is_retryable())
+    }
+
     /// 元のエラーを設定する
     ///
     /// 任意のエラー値を引数で渡す
@@ -166,4 +276,250 @@ impl AppError {
         self.source = Some(source.into());
         self
     }
+
+    /// 指定した言語のエラーメッセージを取得する
+    ///
+    /// メッセージがカタログの既定値のままの場合は指定した言語の文言を返し、
+    /// 個別に設定されたメッセージはそのまま返す
+    ///
+    /// ## Arguments
+    /// * `locale` - 表示する言語
+    ///
+    /// ## Returns
+    /// * 指定した言語のエラーメッセージ
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::{app_error::AppError, catalog::Locale, kind::ErrorKind};
+    ///
+    /// let error = AppError::from_catalog(ErrorKind::NotFound, "SH-IO-001");
+    /// assert_eq!(
+    ///     error.localized_message(Locale::English),
+    ///     "The specified file or directory was not found."
+    /// );
+    /// assert_eq!(
+    ///     AppError::new(ErrorKind::NotFound).localized_message(Locale::English),
+    ///     "An error occurred."
+    /// );
+    /// ```
+    pub fn localized_message(&self, locale: Locale) -> &str {
+        match self.code.and_then(catalog::lookup) {
+            Some(entry) if self.message == entry.japanese.message => entry.text(locale).message,
+            _ if self.message == catalog::DEFAULT_MESSAGE => catalog::default_message(locale),
+            _ => &self.message,
+        }
+    }
+
+    /// 指定した言語の対処法を取得する
+    ///
+    /// 対処法がカタログの既定値のままの場合は指定した言語の文言を返し、
+    /// 個別に設定された対処法はそのまま返す
+    ///
+    /// ## Arguments
+    /// * `locale` - 表示する言語
+    ///
+    /// ## Returns
+    /// * 対処法が設定されている場合 - `Some<&str>`
+    /// * 設定されていない場合 - `None`
+    pub fn localized_action(&self, locale: Locale) -> Option<&str> {
+        let action = self.action.as_deref()?;
+        match self.code.and_then(catalog::lookup) {
+            Some(entry) if action == entry.japanese.action => Some(entry.text(locale).action),
+            _ => Some(action),
+        }
+    }
+
+    /// 元のエラーと対処法を含む、複数行のエラーレポートを指定した言語で作成する
+    ///
+    /// 1行目に`[コード] メッセージ`を表示し、元のエラーを近いものから順に1行ずつ列挙した後、
+    /// 自身と元の[`AppError`]に設定された対処法を重複を除いて表示する。
+    /// 代替形式の表示（`{:#}`）は日本語のレポートと同じ内容になる
+    ///
+    /// ## Arguments
+    /// * `locale` - 表示する言語
+    ///
+    /// ## Returns
+    /// * エラーレポートの文字列
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::{app_error::AppError, catalog::Locale, kind::ErrorKind};
+    /// use std::io;
+    ///
+    /// let error = AppError::new(ErrorKind::InternalServerError)
+    ///     .with_code("SH-FS-001")
+    ///     .with_message("設定ファイルの読み込みに失敗しました。")
+    ///     .with_action("ファイルのアクセス権限を確認してください。")
+    ///     .with_source(io::Error::new(io::ErrorKind::PermissionDenied, "permission denied"));
+    /// assert_eq!(
+    ///     error.report(Locale::Japanese),
+    ///     "[SH-FS-001] 設定ファイルの読み込みに失敗しました。\n\
+    ///      \x20 原因: permission denied\n\
+    ///      \x20 対処: ファイルのアクセス権限を確認してください。"
+    /// );
+    /// ```
+    pub fn report(&self, locale: Locale) -> String {
+        let (cause_label, action_label) = match locale {
+            Locale::Japanese => ("原因", "対処"),
+            Locale::English => ("Cause", "Action"),
+        };
+
+        let mut lines = vec![self.headline(locale)];
+        let mut actions: Vec<&str> = self.localized_action(locale).into_iter().collect();
+        let mut source = std::error::Error::source(self);
+        while let Some(cause) = source {
+            match cause.downcast_ref::<AppError>() {
+                Some(error) => {
+                    lines.push(format!("  {cause_label}: {}", error.headline(locale)));
+                    if let Some(action) = error.localized_action(locale)
+                        && !actions.contains(&action)
+                    {
+                        actions.push(action);
+                    }
+                }
+                None => lines.push(format!("  {cause_label}: {cause}")),
+            }
+            source = cause.source();
+        }
+        lines.extend(
+            actions
+                .iter()
+                .map(|action| format!("  {action_label}: {action}")),
+        );
+        lines.join("\n")
+    }
+
+    /// エラーコードとメッセージを`[コード] メッセージ`の形式で表示する
+    fn headline(&self, locale: Locale) -> String {
+        let message = self.localized_message(locale);
+        match self.code {
+            Some(code) => format!("[{code}] {message}"),
+            None => message.to_string(),
+        }
+    }
+
+    /// 複数のエラーを1つのエラーにまとめる
+    ///
+    /// エラー種別は先頭のエラーのものを使用し、メッセージと対処法は箇条書きで列挙する
+    /// エラーが1件のみの場合はそのまま返す
+    ///
+    /// ## Arguments
+    /// * `errors` - まとめるエラーの一覧
+    ///
+    /// ## Returns
+    /// * エラーがない場合 - `None`
+    /// * エラーがある場合 - 全てのエラーを列挙した[`AppError`]インスタンス
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::{app_error::AppError, kind::ErrorKind};
+    ///
+    /// assert!(AppError::aggregate(Vec::new()).is_none());
+    ///
+    /// let error = AppError::aggregate(vec![
+    ///     AppError::new(ErrorKind::BadRequest).with_message("名前が空です。"),
+    ///     AppError::new(ErrorKind::NotFound).with_message("部署が空です。"),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(error.kind, ErrorKind::BadRequest);
+    /// assert_eq!(error.message, "2件の問題があります。\n- 名前が空です。\n- 部署が空です。");
+    /// ```
+    pub fn aggregate(errors: Vec<AppError>) -> Option<Self> {
+        if errors.len() <= 1 {
+            return errors.into_iter().next();
+        }
+
+        let kind = errors[0].kind;
+        let mut message = format!("{}件の問題があります。", errors.len());
+        let mut actions: Vec<&str> = Vec::new();
+        for error in &errors {
+            message.push_str(&format!("\n- {}", error.message));
+            if let Some(action) = error.action.as_deref()
+                && !actions.contains(&action)
+            {
+                actions.push(action);
+            }
+        }
+
+        let aggregated = Self::new(kind).with_message(message);
+        if actions.is_empty() {
+            Some(aggregated)
+        } else {
+            let action = actions
+                .iter()
+                .map(|action| format!("- {action}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            Some(aggregated.with_action(action))
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    /// エラーを1行で表示する
+    ///
+    /// 代替形式（`{:#}`）では、日本語の[`AppError::report`]と同じく元のエラーと対処法を含めて表示する
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(&self.report(Locale::Japanese));
+        }
+        if let Some(code) = self.code {
+            write!(f, "code: {code}, ")?;
+        }
+        write!(f, "kind: {}, message: {}", self.kind.as_str(), self.message)
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_report_walks_source_chain() {
+        let inner = AppError::new(ErrorKind::NotFound)
+            .with_code("SH-FS-002")
+            .with_message("ファイルが見つかりません。")
+            .with_action("ファイル名を確認してください。")
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+        let error = AppError::new(ErrorKind::InternalServerError)
+            .with_message("読み込みに失敗しました。")
+            .with_action("パスを確認してください。")
+            .with_source(inner);
+
+        assert_eq!(
+            error.to_string(),
+            "kind: Internal Server Error, message: 読み込みに失敗しました。"
+        );
+        assert_eq!(format!("{error:#}"), error.report(Locale::Japanese));
+        assert_eq!(
+            format!("{error:#}").lines().next(),
+            Some("読み込みに失敗しました。")
+        );
+        assert_eq!(
+            error.report(Locale::Japanese).lines().collect::<Vec<_>>(),
+            vec![
+                "読み込みに失敗しました。",
+                "  原因: [SH-FS-002] ファイルが見つかりません。",
+                "  原因: no such file",
+                "  対処: パスを確認してください。",
+                "  対処: ファイル名を確認してください。",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_localizes_catalog_entries() {
+        let error = AppError::new(ErrorKind::InternalServerError)
+            .with_message("読み込みに失敗しました。")
+            .with_source(AppError::from_catalog(ErrorKind::NotFound, "SH-IO-001"));
+
+        let report = error.report(Locale::English);
+
+        assert!(
+            report.contains("  Cause: [SH-IO-001] The specified file or directory was not found.")
+        );
+        assert!(report.lines().last().unwrap().starts_with("  Action: "));
+        assert!(!report.contains("原因"));
+    }
 }
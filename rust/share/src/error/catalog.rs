@@ -0,0 +1,389 @@
+/// エラーメッセージを表示する言語
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    Japanese,
+    English,
+}
+
+impl Locale {
+    /// 言語コードから[`Locale`]を取得する
+    ///
+    /// ## Arguments
+    /// * `code` - 言語コード（例: `ja`、`en_US.UTF-8`）
+    ///
+    /// ## Returns
+    /// * 対応する言語がある場合 - `Some<Locale>`
+    /// * 対応する言語がない場合 - `None`
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::catalog::Locale;
+    ///
+    /// assert_eq!(Locale::from_code("en_US.UTF-8"), Some(Locale::English));
+    /// assert_eq!(Locale::from_code("ja"), Some(Locale::Japanese));
+    /// assert_eq!(Locale::from_code("fr"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code.trim().split(['_', '-', '.', '@']).next()?;
+        if primary.eq_ignore_ascii_case("ja") {
+            Some(Self::Japanese)
+        } else if primary.eq_ignore_ascii_case("en") {
+            Some(Self::English)
+        } else {
+            None
+        }
+    }
+}
+
+/// 1つの言語でのエラーメッセージと対処法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CatalogText {
+    pub message: &'static str,
+    pub action: &'static str,
+}
+
+/// エラーコードに対応する既定のエラーメッセージと対処法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CatalogEntry {
+    pub code: &'static str,
+    pub japanese: CatalogText,
+    pub english: CatalogText,
+}
+
+impl CatalogEntry {
+    /// 指定した言語のメッセージと対処法を取得する
+    ///
+    /// ## Arguments
+    /// * `locale` - 表示する言語
+    ///
+    /// ## Returns
+    /// * 指定した言語の[`CatalogText`]
+    pub fn text(&self, locale: Locale) -> &CatalogText {
+        match locale {
+            Locale::Japanese => &self.japanese,
+            Locale::English => &self.english,
+        }
+    }
+}
+
+/// [`AppError::new`](crate::error::app_error::AppError::new)で設定される既定のメッセージ
+pub(crate) const DEFAULT_MESSAGE: &str = "エラーが発生しました。";
+
+/// [`DEFAULT_MESSAGE`]の英語表現
+const DEFAULT_MESSAGE_EN: &str = "An error occurred.";
+
+macro_rules! entry {
+    ($code:literal, ($ja_message:literal, $ja_action:literal), ($en_message:literal, $en_action:literal) $(,)?) => {
+        CatalogEntry {
+            code: $code,
+            japanese: CatalogText {
+                message: $ja_message,
+                action: $ja_action,
+            },
+            english: CatalogText {
+                message: $en_message,
+                action: $en_action,
+            },
+        }
+    };
+}
+
+/// `share`が組み込みで持つエラーメッセージの一覧
+const CATALOG: &[CatalogEntry] = &[
+    entry!(
+        "SH-CONV-001",
+        (
+            "外部ライブラリでエラーが発生しました。",
+            "システム管理者にお問い合わせください。"
+        ),
+        (
+            "An error occurred in an external library.",
+            "Please contact your system administrator."
+        ),
+    ),
+    entry!(
+        "SH-CONV-002",
+        (
+            "JSONの処理中にエラーが発生しました。",
+            "JSONの形式を確認してください。"
+        ),
+        ("Failed to process JSON.", "Check the JSON format."),
+    ),
+    entry!(
+        "SH-CONV-003",
+        (
+            "Excelファイルの読み込み中にエラーが発生しました。",
+            "Excelファイルの形式を確認してください。"
+        ),
+        (
+            "Failed to read the Excel file.",
+            "Check the format of the Excel file."
+        ),
+    ),
+    entry!(
+        "SH-CONV-004",
+        (
+            "Excelファイルの書き込み中にエラーが発生しました。",
+            "出力先のアクセス権限と、ファイルをExcelで開いていないことを確認してください。"
+        ),
+        (
+            "Failed to write the Excel file.",
+            "Check the permissions of the destination and make sure the file is not open in Excel."
+        ),
+    ),
+    entry!(
+        "SH-CONV-005",
+        (
+            "日付または時刻の形式が正しくありません。",
+            "YYYY-MM-DDやHH:MMなど、指定された形式で入力してください。"
+        ),
+        (
+            "The date or time format is invalid.",
+            "Use the expected format, such as YYYY-MM-DD or HH:MM."
+        ),
+    ),
+    entry!(
+        "SH-CONV-006",
+        (
+            "CSVファイルの読み書きに失敗しました。",
+            "ファイルパスとアクセス権限を確認してください。"
+        ),
+        (
+            "Failed to read or write the CSV file.",
+            "Check the file path and permissions."
+        ),
+    ),
+    entry!(
+        "SH-CONV-007",
+        (
+            "CSVの処理中にエラーが発生しました。",
+            "CSVの列数と文字コード（UTF-8）を確認してください。"
+        ),
+        (
+            "Failed to process CSV.",
+            "Check the number of columns and that the file is UTF-8 encoded."
+        ),
+    ),
+    entry!(
+        "SH-CONV-008",
+        (
+            "TOMLの解析中にエラーが発生しました。",
+            "TOMLの形式を確認してください。"
+        ),
+        ("Failed to parse TOML.", "Check the TOML format."),
+    ),
+    entry!(
+        "SH-IO-001",
+        (
+            "指定されたファイルまたはディレクトリが見つかりません。",
+            "ファイルパスを確認してください。"
+        ),
+        (
+            "The specified file or directory was not found.",
+            "Check the file path."
+        ),
+    ),
+    entry!(
+        "SH-IO-002",
+        (
+            "ファイルへのアクセス権限がありません。",
+            "ファイルの権限設定を確認してください。"
+        ),
+        (
+            "Permission to access the file was denied.",
+            "Check the file permissions."
+        ),
+    ),
+    entry!(
+        "SH-IO-003",
+        (
+            "ファイルまたはディレクトリが既に存在します。",
+            "別の名前を使用するか、既存のファイルを削除してください。"
+        ),
+        (
+            "The file or directory already exists.",
+            "Use a different name or remove the existing file."
+        ),
+    ),
+    entry!(
+        "SH-IO-004",
+        (
+            "無効な入力が指定されました。",
+            "入力内容を確認してください。"
+        ),
+        ("An invalid input was given.", "Check the input."),
+    ),
+    entry!(
+        "SH-IO-005",
+        (
+            "ファイル操作中にエラーが発生しました。",
+            "ディスク容量やファイル権限を確認してください。"
+        ),
+        (
+            "A file operation failed.",
+            "Check the free disk space and file permissions."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-001",
+        (
+            "サーバーからの応答がタイムアウトしました。",
+            "時間をおいて再度実行してください。"
+        ),
+        ("The server did not respond in time.", "Try again later."),
+    ),
+    entry!(
+        "SH-HTTP-002",
+        (
+            "サーバーに接続できませんでした。",
+            "URLとネットワーク接続を確認してください。"
+        ),
+        (
+            "Could not connect to the server.",
+            "Check the URL and the network connection."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-003",
+        (
+            "サーバーでの認証に失敗しました。",
+            "認証情報を確認してください。"
+        ),
+        (
+            "Authentication with the server failed.",
+            "Check your credentials."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-004",
+        (
+            "サーバーへのアクセスが拒否されました。",
+            "アクセス権限を確認してください。"
+        ),
+        (
+            "Access to the server was denied.",
+            "Check your access rights."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-005",
+        ("指定されたURLが見つかりません。", "URLを確認してください。"),
+        ("The specified URL was not found.", "Check the URL."),
+    ),
+    entry!(
+        "SH-HTTP-006",
+        (
+            "サーバーへのリクエストが多すぎます。",
+            "時間をおいて再度実行してください。"
+        ),
+        (
+            "Too many requests were sent to the server.",
+            "Try again later."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-007",
+        (
+            "サーバーでエラーが発生しました。",
+            "時間をおいて再度実行し、解決しない場合はサーバーの管理者に連絡してください。"
+        ),
+        (
+            "An error occurred on the server.",
+            "Try again later, and contact the server administrator if the problem persists."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-008",
+        (
+            "サーバーがリクエストを受け付けませんでした。",
+            "URLとリクエストの内容を確認してください。"
+        ),
+        (
+            "The server rejected the request.",
+            "Check the URL and the request."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-009",
+        (
+            "サーバーからの応答を解析できませんでした。",
+            "サーバーが返す内容の形式を確認してください。"
+        ),
+        (
+            "Could not parse the response from the server.",
+            "Check the format of the server response."
+        ),
+    ),
+    entry!(
+        "SH-HTTP-010",
+        (
+            "HTTP通信中にエラーが発生しました。",
+            "URLとネットワーク接続を確認してください。"
+        ),
+        (
+            "An HTTP error occurred.",
+            "Check the URL and the network connection."
+        ),
+    ),
+];
+
+/// エラーコードに対応するカタログの項目を取得する
+///
+/// ## Arguments
+/// * `code` - エラーコード（例: `SH-IO-001`）
+///
+/// ## Returns
+/// * カタログに登録されている場合 - `Some<&CatalogEntry>`
+/// * 登録されていない場合 - `None`
+///
+/// ## Examples
+/// ```rust
+/// use share::error::catalog::{Locale, lookup};
+///
+/// let entry = lookup("SH-IO-001").unwrap();
+/// assert_eq!(
+///     entry.text(Locale::English).message,
+///     "The specified file or directory was not found."
+/// );
+/// assert!(lookup("XX-DEMO-001").is_none());
+/// ```
+pub fn lookup(code: &str) -> Option<&'static CatalogEntry> {
+    CATALOG.iter().find(|entry| entry.code == code)
+}
+
+/// コードを持たないエラーの既定のメッセージを取得する
+///
+/// ## Arguments
+/// * `locale` - 表示する言語
+///
+/// ## Returns
+/// * 既定のエラーメッセージ
+pub fn default_message(locale: Locale) -> &'static str {
+    match locale {
+        Locale::Japanese => DEFAULT_MESSAGE,
+        Locale::English => DEFAULT_MESSAGE_EN,
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn test_catalog_codes_are_unique_and_translated() {
+        for (i, entry) in CATALOG.iter().enumerate() {
+            assert!(
+                CATALOG[i + 1..]
+                    .iter()
+                    .all(|other| other.code != entry.code),
+                "{} is duplicated",
+                entry.code
+            );
+            for locale in [Locale::Japanese, Locale::English] {
+                assert!(!entry.text(locale).message.is_empty(), "{}", entry.code);
+                assert!(!entry.text(locale).action.is_empty(), "{}", entry.code);
+            }
+        }
+    }
+}
@@ -2,51 +2,27 @@ use crate::error::{app_error::AppError, kind::ErrorKind};
 
 impl From<anyhow::Error> for AppError {
     fn from(value: anyhow::Error) -> Self {
-        AppError::new(ErrorKind::InternalServerError)
-            .with_message("外部ライブラリでエラーが発生しました。")
-            .with_source("システム管理者にお問い合わせください。")
-            .with_source(value)
+        AppError::from_catalog(ErrorKind::InternalServerError, "SH-CONV-001").with_source(value)
     }
 }
 
 impl From<std::io::Error> for AppError {
     fn from(value: std::io::Error) -> Self {
-        let (message, action) = match value.kind() {
-            std::io::ErrorKind::NotFound => (
-                "指定されたファイルまたはディレクトリが見つかりません。",
-                "ファイルパスを確認してください。",
-            ),
-            std::io::ErrorKind::PermissionDenied => (
-                "ファイルへのアクセス権限がありません。",
-                "ファイルの権限設定を確認してください。",
-            ),
-            std::io::ErrorKind::AlreadyExists => (
-                "ファイルまたはディレクトリが既に存在します。",
-                "別の名前を使用するか、既存のファイルを削除してください。",
-            ),
-            std::io::ErrorKind::InvalidInput => (
-                "無効な入力が指定されました。",
-                "入力内容を確認してください。",
-            ),
-            _ => (
-                "ファイル操作中にエラーが発生しました。",
-                "ディスク容量やファイル権限を確認してください。",
-            ),
+        let code = match value.kind() {
+            std::io::ErrorKind::NotFound => "SH-IO-001",
+            std::io::ErrorKind::PermissionDenied => "SH-IO-002",
+            std::io::ErrorKind::AlreadyExists => "SH-IO-003",
+            std::io::ErrorKind::InvalidInput => "SH-IO-004",
+            _ => "SH-IO-005",
         };
 
-        AppError::new(ErrorKind::InternalServerError)
-            .with_message(message)
-            .with_action(action)
-            .with_source(value)
+        AppError::from_catalog(ErrorKind::InternalServerError, code).with_source(value)
     }
 }
 
 impl From<serde_json::Error> for AppError {
     fn from(value: serde_json::Error) -> Self {
-        AppError::new(ErrorKind::UnprocessableEntity)
-            .with_message("JSONの処理中にエラーが発生しました。")
-            .with_action("JSONの形式を確認してください。")
-            .with_source(value)
+        AppError::from_catalog(ErrorKind::UnprocessableEntity, "SH-CONV-002").with_source(value)
     }
 }
 
@@ -59,9 +35,235 @@ impl From<calamine::XlsxError> for AppError {
     /// ## Returns
     /// * 変換後の[`AppError`]
     fn from(value: calamine::XlsxError) -> Self {
-        AppError::new(ErrorKind::InternalServerError)
-            .with_message(format!("Excelファイルの読み込み中にエラーが発生しました。"))
-            .with_action("Excelファイルの形式を確認してください。")
-            .with_source(value)
+        AppError::from_catalog(ErrorKind::InternalServerError, "SH-CONV-003").with_source(value)
+    }
+}
+
+#[cfg(feature = "xlsx")]
+impl From<rust_xlsxwriter::XlsxError> for AppError {
+    /// [`rust_xlsxwriter::XlsxError`]を[`AppError`]に変換する
+    ///
+    /// ## Arguments
+    /// * `value` - 変換対象の[`rust_xlsxwriter::XlsxError`]
+    ///
+    /// ## Returns
+    /// * 変換後の[`AppError`]
+    fn from(value: rust_xlsxwriter::XlsxError) -> Self {
+        AppError::from_catalog(ErrorKind::InternalServerError, "SH-CONV-004").with_source(value)
+    }
+}
+
+impl From<chrono::ParseError> for AppError {
+    /// [`chrono::ParseError`]を[`AppError`]に変換する
+    ///
+    /// ## Arguments
+    /// * `value` - 変換対象の[`chrono::ParseError`]
+    ///
+    /// ## Returns
+    /// * 変換後の[`AppError`]
+    fn from(value: chrono::ParseError) -> Self {
+        AppError::from_catalog(ErrorKind::BadRequest, "SH-CONV-005").with_source(value)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for AppError {
+    /// [`csv::Error`]を[`AppError`]に変換する
+    ///
+    /// ## Arguments
+    /// * `value` - 変換対象の[`csv::Error`]
+    ///
+    /// ## Returns
+    /// * 変換後の[`AppError`]（ファイルの読み書きの失敗と、内容の誤りを区別する）
+    fn from(value: csv::Error) -> Self {
+        if value.is_io_error() {
+            AppError::from_catalog(ErrorKind::InternalServerError, "SH-CONV-006").with_source(value)
+        } else {
+            AppError::from_catalog(ErrorKind::UnprocessableEntity, "SH-CONV-007").with_source(value)
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for AppError {
+    /// [`toml::de::Error`]を[`AppError`]に変換する
+    ///
+    /// ## Arguments
+    /// * `value` - 変換対象の[`toml::de::Error`]
+    ///
+    /// ## Returns
+    /// * 変換後の[`AppError`]
+    fn from(value: toml::de::Error) -> Self {
+        AppError::from_catalog(ErrorKind::UnprocessableEntity, "SH-CONV-008").with_source(value)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for AppError {
+    /// [`reqwest::Error`]を[`AppError`]に変換する
+    ///
+    /// ## Arguments
+    /// * `value` - 変換対象の[`reqwest::Error`]
+    ///
+    /// ## Returns
+    /// * 変換後の[`AppError`]（タイムアウト・接続失敗・HTTPステータスに応じた種別）
+    fn from(value: reqwest::Error) -> Self {
+        let status = value.status().map(|status| status.as_u16());
+        let (kind, code) = if value.is_timeout() {
+            (ErrorKind::RequestTimeout, "SH-HTTP-001")
+        } else if value.is_connect() {
+            (ErrorKind::ServiceUnavailable, "SH-HTTP-002")
+        } else if let Some(status) = status {
+            match status {
+                401 => (ErrorKind::Unauthorized, "SH-HTTP-003"),
+                403 => (ErrorKind::Forbidden, "SH-HTTP-004"),
+                404 => (ErrorKind::NotFound, "SH-HTTP-005"),
+                429 => (ErrorKind::TooManyRequests, "SH-HTTP-006"),
+                500..=599 => (ErrorKind::ServiceUnavailable, "SH-HTTP-007"),
+                _ => (ErrorKind::BadRequest, "SH-HTTP-008"),
+            }
+        } else if value.is_decode() {
+            (ErrorKind::UnprocessableEntity, "SH-HTTP-009")
+        } else {
+            (ErrorKind::InternalServerError, "SH-HTTP-010")
+        };
+
+        AppError::from_catalog(kind, code).with_source(value)
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn test_from_anyhow_error() {
+        let error = AppError::from(anyhow::anyhow!("接続が切断されました"));
+
+        assert_eq!(error.kind, ErrorKind::InternalServerError);
+        assert_eq!(error.code, Some("SH-CONV-001"));
+        assert_eq!(
+            error.action.as_deref(),
+            Some("システム管理者にお問い合わせください。")
+        );
+        assert_eq!(error.source.unwrap().to_string(), "接続が切断されました");
+    }
+
+    #[test]
+    fn test_from_chrono_parse_error() {
+        let error = AppError::from(
+            chrono::NaiveDate::parse_from_str("2024-13-01", "%Y-%m-%d").unwrap_err(),
+        );
+
+        assert_eq!(error.kind, ErrorKind::BadRequest);
+        assert_eq!(error.code, Some("SH-CONV-005"));
+        assert_eq!(
+            error.action.as_deref(),
+            Some("YYYY-MM-DDやHH:MMなど、指定された形式で入力してください。")
+        );
+        assert!(error.source.is_some());
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_from_csv_error() {
+        let records = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader("a,b\nc\n".as_bytes())
+            .records()
+            .collect::<Result<Vec<_>, _>>();
+        let error = AppError::from(records.unwrap_err());
+
+        assert_eq!(error.kind, ErrorKind::UnprocessableEntity);
+        assert_eq!(error.code, Some("SH-CONV-007"));
+        assert_eq!(
+            error.action.as_deref(),
+            Some("CSVの列数と文字コード（UTF-8）を確認してください。")
+        );
+
+        let error = AppError::from(csv::Error::from(std::io::Error::other("disk full")));
+
+        assert_eq!(error.kind, ErrorKind::InternalServerError);
+        assert_eq!(error.code, Some("SH-CONV-006"));
+        assert_eq!(
+            error.action.as_deref(),
+            Some("ファイルパスとアクセス権限を確認してください。")
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_error() {
+        let error = AppError::from(toml::from_str::<toml::Table>("key = ").unwrap_err());
+
+        assert_eq!(error.kind, ErrorKind::UnprocessableEntity);
+        assert_eq!(error.code, Some("SH-CONV-008"));
+        assert_eq!(
+            error.action.as_deref(),
+            Some("TOMLの形式を確認してください。")
+        );
+    }
+
+    /// 1回だけ接続を受け付け、リクエストを読み込んだ後に`response`を返す（`None`の場合は応答しない）HTTPサーバー
+    #[cfg(feature = "reqwest")]
+    fn serve_once(response: Option<&'static str>) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0; 1024]);
+            match response {
+                Some(response) => {
+                    let _ = stream.write_all(response.as_bytes());
+                }
+                None => std::thread::sleep(std::time::Duration::from_secs(2)),
+            }
+        });
+        format!("http://{address}/")
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_from_reqwest_error() {
+        use reqwest::blocking::Client;
+        use std::time::Duration;
+
+        let status_error = |status: &'static str| {
+            let url = serve_once(Some(status));
+            let response = Client::new().get(url).send().unwrap();
+            AppError::from(response.error_for_status().unwrap_err())
+        };
+        let error = status_error("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n");
+        assert_eq!(
+            (error.kind, error.code),
+            (ErrorKind::NotFound, Some("SH-HTTP-005"))
+        );
+        let error = status_error("HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n");
+        assert_eq!(
+            (error.kind, error.code),
+            (ErrorKind::ServiceUnavailable, Some("SH-HTTP-007"))
+        );
+
+        let client = Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let error = AppError::from(client.get(serve_once(None)).send().unwrap_err());
+        assert_eq!(
+            (error.kind, error.code),
+            (ErrorKind::RequestTimeout, Some("SH-HTTP-001"))
+        );
+
+        // 接続を受け付けないポート（割り当て後すぐに閉じる）
+        let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", closed.local_addr().unwrap());
+        drop(closed);
+        let error = AppError::from(Client::new().get(url).send().unwrap_err());
+        assert_eq!(
+            (error.kind, error.code),
+            (ErrorKind::ServiceUnavailable, Some("SH-HTTP-002"))
+        );
     }
 }
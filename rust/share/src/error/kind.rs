@@ -1,10 +1,24 @@
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 
 /// 本プロジェクトで使用するエラー種別の列挙体
 ///
 /// ## Notes
 /// * `non_exhaustive` - 将来的に列挙子が追加される可能性があることを示す
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// * `Custom` - 各ツールが`share`を変更せずに独自のエラー種別を定義するための列挙子
+/// * `Serialize` - 組み込みの列挙子は列挙子名（例: `"NotFound"`）、`Custom`は表示名の文字列としてシリアライズする
+///
+/// ## Examples
+/// ```rust
+/// use share::error::kind::ErrorKind;
+///
+/// const QUOTA_EXCEEDED: ErrorKind = ErrorKind::Custom {
+///     code: 507,
+///     name: "Quota Exceeded",
+/// };
+/// assert_eq!(QUOTA_EXCEEDED.as_str(), "Quota Exceeded");
+/// assert_eq!(QUOTA_EXCEEDED.as_code(), 507);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ErrorKind {
     BadRequest,
@@ -19,6 +33,11 @@ pub enum ErrorKind {
     InternalServerError,
     ServiceUnavailable,
     UnexpectedServerError,
+    /// 独自のエラー種別（`code`はHTTPステータスコードに準拠した数値、`name`は表示名）
+    Custom {
+        code: u16,
+        name: &'static str,
+    },
 }
 
 impl ErrorKind {
@@ -49,6 +68,7 @@ impl ErrorKind {
             ErrorKind::InternalServerError => "Internal Server Error",
             ErrorKind::ServiceUnavailable => "Service Unavailable",
             ErrorKind::UnexpectedServerError => "Unexpected Server Error",
+            ErrorKind::Custom { name, .. } => name,
         }
     }
 
@@ -79,8 +99,54 @@ impl ErrorKind {
             ErrorKind::InternalServerError => 500,
             ErrorKind::ServiceUnavailable => 503,
             ErrorKind::UnexpectedServerError => 599,
+            ErrorKind::Custom { code, .. } => *code,
         }
     }
+
+    /// [`ErrorKind`]が一時的な障害を表し、再試行で解消する見込みがあるかを判定する
+    ///
+    /// ## Arguments
+    /// * `&self` - 判定対象の[`ErrorKind`]
+    ///
+    /// ## Returns
+    /// * `true` - タイムアウト・流量制限・サービス停止など、時間をおいて再試行すべき場合
+    /// * `false` - 入力や設定の誤りなど、再試行しても結果が変わらない場合（`Custom`を含む）
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::error::kind::ErrorKind;
+    /// assert!(ErrorKind::ServiceUnavailable.is_retryable());
+    /// assert!(!ErrorKind::BadRequest.is_retryable());
+    /// ```
+    pub const fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorKind::RequestTimeout | ErrorKind::TooManyRequests | ErrorKind::ServiceUnavailable
+        )
+    }
+}
+
+impl Serialize for ErrorKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (index, variant) = match self {
+            ErrorKind::BadRequest => (0, "BadRequest"),
+            ErrorKind::Unauthorized => (1, "Unauthorized"),
+            ErrorKind::Forbidden => (2, "Forbidden"),
+            ErrorKind::NotFound => (3, "NotFound"),
+            ErrorKind::RequestTimeout => (4, "RequestTimeout"),
+            ErrorKind::Conflict => (5, "Conflict"),
+            ErrorKind::UnprocessableEntity => (6, "UnprocessableEntity"),
+            ErrorKind::TooManyRequests => (7, "TooManyRequests"),
+            ErrorKind::UnavailableForLegalReasons => (8, "UnavailableForLegalReasons"),
+            ErrorKind::InternalServerError => (9, "InternalServerError"),
+            ErrorKind::ServiceUnavailable => (10, "ServiceUnavailable"),
+            ErrorKind::UnexpectedServerError => (11, "UnexpectedServerError"),
+            // 独自の種別は列挙子名では区別できないため、表示名を使用する
+            ErrorKind::Custom { name, .. } => return serializer.serialize_str(name),
+        };
+        // 組み込みの列挙子は`derive(Serialize)`と同じ表現を保つ
+        serializer.serialize_unit_variant("ErrorKind", index, variant)
+    }
 }
 
 #[cfg(test)]
@@ -133,4 +199,52 @@ mod ut {
         assert_eq!(ErrorKind::ServiceUnavailable.as_code(), 503);
         assert_eq!(ErrorKind::UnexpectedServerError.as_code(), 599);
     }
+
+    #[test]
+    fn test_error_kind_custom() {
+        let kind = ErrorKind::Custom {
+            code: 460,
+            name: "Holiday",
+        };
+        assert_eq!(kind.as_str(), "Holiday");
+        assert_eq!(kind.as_code(), 460);
+        assert!(!kind.is_retryable());
+        assert_ne!(
+            kind,
+            ErrorKind::Custom {
+                code: 460,
+                name: "Closed",
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_kind_serialize() {
+        let custom = ErrorKind::Custom {
+            code: 460,
+            name: "Holiday",
+        };
+        assert_eq!(
+            serde_json::to_string(&ErrorKind::NotFound).unwrap(),
+            r#""NotFound""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ErrorKind::UnexpectedServerError).unwrap(),
+            r#""UnexpectedServerError""#
+        );
+        assert_eq!(serde_json::to_string(&custom).unwrap(), r#""Holiday""#);
+    }
+
+    #[test]
+    fn test_error_kind_is_retryable() {
+        assert!(ErrorKind::RequestTimeout.is_retryable());
+        assert!(ErrorKind::TooManyRequests.is_retryable());
+        assert!(ErrorKind::ServiceUnavailable.is_retryable());
+        assert!(!ErrorKind::BadRequest.is_retryable());
+        assert!(!ErrorKind::Unauthorized.is_retryable());
+        assert!(!ErrorKind::NotFound.is_retryable());
+        assert!(!ErrorKind::Conflict.is_retryable());
+        assert!(!ErrorKind::InternalServerError.is_retryable());
+        assert!(!ErrorKind::UnexpectedServerError.is_retryable());
+    }
 }
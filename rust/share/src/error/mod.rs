@@ -1,3 +1,5 @@
 pub mod app_error;
+pub mod catalog;
 pub mod error_conversions;
 pub mod kind;
+pub mod result_ext;
@@ -0,0 +1,148 @@
+use crate::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{borrow::Cow, error::Error};
+
+/// [`Result`]のエラーを[`AppError`]に変換しながら文脈を付加する拡張トレイト
+///
+/// `map_err`のクロージャで[`AppError`]を組み立てる代わりに、メソッドチェーンで記述できるようにする。
+/// 元のエラーが[`AppError`]の場合はその値を変更し、それ以外の場合は元のエラーを`source`に持つ
+/// [`AppError`]（種別の既定値は[`ErrorKind::InternalServerError`]）を作成する
+///
+/// ## Examples
+/// ```rust
+/// use share::error::{app_error::AppResult, kind::ErrorKind, result_ext::ResultExt};
+///
+/// fn read_config(path: &str) -> AppResult<String> {
+///     std::fs::read_to_string(path)
+///         .kind(ErrorKind::NotFound)
+///         .ctx_code("SH-DOC-001")
+///         .ctx_msg(format!("設定ファイルが見つかりません。詳細: {path}"))
+///         .ctx_action("ファイルパスを確認してください。")
+/// }
+///
+/// let error = read_config("no_such_config.json").unwrap_err();
+/// assert_eq!(error.kind, ErrorKind::NotFound);
+/// assert_eq!(error.code, Some("SH-DOC-001"));
+/// assert!(error.source.is_some());
+/// ```
+pub trait ResultExt<T> {
+    /// エラー種別を設定する
+    ///
+    /// ## Arguments
+    /// * `kind` - 設定するエラー種別
+    ///
+    /// ## Returns
+    /// * エラーの場合 - 種別が設定された`Err<AppError>`
+    fn kind(self, kind: ErrorKind) -> AppResult<T>;
+
+    /// エラーを一意に識別するコードを設定する
+    ///
+    /// ## Arguments
+    /// * `code` - 設定するコード（例: `XX-DEMO-001`）
+    ///
+    /// ## Returns
+    /// * エラーの場合 - コードが設定された`Err<AppError>`
+    fn ctx_code(self, code: &'static str) -> AppResult<T>;
+
+    /// エラーメッセージを設定する
+    ///
+    /// ## Arguments
+    /// * `msg` - 設定するエラーメッセージ
+    ///
+    /// ## Returns
+    /// * エラーの場合 - メッセージが設定された`Err<AppError>`
+    fn ctx_msg<S>(self, msg: S) -> AppResult<T>
+    where
+        S: Into<Cow<'static, str>>;
+
+    /// ユーザー向けの対処法を設定する
+    ///
+    /// ## Arguments
+    /// * `action` - 設定する対処法
+    ///
+    /// ## Returns
+    /// * エラーの場合 - 対処法が設定された`Err<AppError>`
+    fn ctx_action<S>(self, action: S) -> AppResult<T>
+    where
+        S: Into<Cow<'static, str>>;
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    fn kind(self, kind: ErrorKind) -> AppResult<T> {
+        self.map_err(|e| {
+            let mut error = into_app_error(e);
+            error.kind = kind;
+            error
+        })
+    }
+
+    fn ctx_code(self, code: &'static str) -> AppResult<T> {
+        self.map_err(|e| into_app_error(e).with_code(code))
+    }
+
+    fn ctx_msg<S>(self, msg: S) -> AppResult<T>
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        self.map_err(|e| into_app_error(e).with_message(msg))
+    }
+
+    fn ctx_action<S>(self, action: S) -> AppResult<T>
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        self.map_err(|e| into_app_error(e).with_action(action))
+    }
+}
+
+/// エラーを[`AppError`]に変換する（[`AppError`]以外は`source`に格納する）
+fn into_app_error<E>(error: E) -> AppError
+where
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    match error.into().downcast::<AppError>() {
+        Ok(error) => *error,
+        Err(other) => AppError::new(ErrorKind::InternalServerError).with_source(other),
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_wraps_foreign_error() {
+        let result: Result<(), io::Error> = Err(io::Error::other("disk full"));
+        let error = result
+            .ctx_msg("書き込みに失敗しました。")
+            .ctx_action("ディスク容量を確認してください。")
+            .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InternalServerError);
+        assert_eq!(error.message, "書き込みに失敗しました。");
+        assert_eq!(
+            error.action.as_deref(),
+            Some("ディスク容量を確認してください。")
+        );
+        assert_eq!(error.source.unwrap().to_string(), "disk full");
+    }
+
+    #[test]
+    fn test_updates_app_error_in_place() {
+        let result: AppResult<()> = Err(AppError::new(ErrorKind::NotFound)
+            .with_code("SH-FS-001")
+            .with_message("見つかりません。"));
+        let error = result.kind(ErrorKind::Conflict).unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Conflict);
+        assert_eq!(error.code, Some("SH-FS-001"));
+        assert_eq!(error.message, "見つかりません。");
+        assert!(error.source.is_none());
+    }
+}
@@ -0,0 +1,291 @@
+pub mod rotating_file;
+
+use crate::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use chrono::NaiveDate;
+use rotating_file::RotatingFileWriter;
+use std::{error::Error, io, path::Path, sync::Mutex};
+use tracing::{Span, level_filters::LevelFilter};
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// [`log_error`]が出力するエラーイベントのターゲット
+///
+/// 利用者向けのエラー表示と重複しないよう、詳細表示でない端末出力では抑止する
+pub const ERROR_EVENT_TARGET: &str = "app_error";
+
+/// ログの出力対象を指定する環境変数名を取得する
+///
+/// ## Arguments
+/// * `app_name` - アプリケーション名（例: `mail_composer`）
+///
+/// ## Returns
+/// * 環境変数名（例: `MAIL_COMPOSER_LOG`）
+///
+/// ## Examples
+/// ```rust
+/// use share::logging::log_env_var;
+/// assert_eq!(log_env_var("mail_composer"), "MAIL_COMPOSER_LOG");
+/// ```
+pub fn log_env_var(app_name: &str) -> String {
+    format!("{}_LOG", app_name.to_ascii_uppercase())
+}
+
+/// 既定の出力レベルとenv-filter形式の指定から出力対象のフィルタを作成する
+///
+/// ## Arguments
+/// * `default_level` - 指定に含まれないモジュールの出力レベル
+/// * `directives` - env-filter形式の指定（例: `mail_composer=debug,share=trace`）
+///
+/// ## Returns
+/// * 成功時 - `Ok<EnvFilter>`
+/// * 失敗時 - 指定の形式が不正な場合のAppError
+///
+/// ## Examples
+/// ```rust
+/// use share::logging::env_filter;
+/// use tracing::level_filters::LevelFilter;
+///
+/// assert!(env_filter(LevelFilter::INFO, Some("mail_composer=debug")).is_ok());
+/// assert!(env_filter(LevelFilter::INFO, Some("mail_composer=loud")).is_err());
+/// ```
+pub fn env_filter(default_level: LevelFilter, directives: Option<&str>) -> AppResult<EnvFilter> {
+    let mut filter = parse_filter(default_level, directives)?;
+    if directives.is_none() && default_level <= LevelFilter::INFO {
+        filter = filter.add_directive(
+            format!("{ERROR_EVENT_TARGET}=off")
+                .parse()
+                .expect("固定のディレクティブは常に解析できる"),
+        );
+    }
+    Ok(filter)
+}
+
+/// env-filter形式の指定を解析する
+///
+/// 既定のディレクティブは指定が空の場合にしか使用されないため、
+/// モジュールを指定しないレベルが含まれていなければ既定の出力レベルを追加する
+fn parse_filter(default_level: LevelFilter, directives: Option<&str>) -> AppResult<EnvFilter> {
+    let directives = directives.unwrap_or_default();
+    let has_global_level = directives
+        .split(',')
+        .any(|directive| directive.trim().parse::<LevelFilter>().is_ok());
+
+    EnvFilter::builder()
+        .with_default_directive(default_level.into())
+        .parse(directives)
+        .map(|filter| {
+            if has_global_level || directives.trim().is_empty() {
+                filter
+            } else {
+                filter.add_directive(default_level.into())
+            }
+        })
+        .map_err(|e| {
+            AppError::new(ErrorKind::BadRequest)
+                .with_code("SH-LOG-001")
+                .with_message(format!(
+                    "ログの出力対象の指定が不正です。詳細: {directives}"
+                ))
+                .with_action(
+                    "`mail_composer=debug,share=trace`のようなenv-filter形式で指定してください。",
+                )
+                .with_source(e)
+        })
+}
+
+/// 標準エラー出力にログを書き出すロガーを初期化する
+///
+/// 出力対象は環境変数`<APP_NAME>_LOG`、`RUST_LOG`の順にenv-filter形式で指定でき、
+/// 未指定のモジュールには`default_level`を使用する。ロガーが既に初期化されている場合は何もしない
+///
+/// ## Arguments
+/// * `app_name` - アプリケーション名（環境変数名に使用する）
+/// * `default_level` - 既定の出力レベル
+///
+/// ## Returns
+/// * 成功時 - `Ok(())`
+/// * 失敗時 - 環境変数の指定の形式が不正な場合のAppError
+pub fn init(app_name: &str, default_level: LevelFilter) -> AppResult<()> {
+    install(app_name, default_level, None)
+}
+
+/// 標準エラー出力に加えて、ログディレクトリのファイルにもログを書き出すロガーを初期化する
+///
+/// ファイルには`<app_name>.<YYYY-MM-DD>.log`の名前で日ごとに書き込み、
+/// 合計サイズが上限（[`rotating_file::DEFAULT_MAX_TOTAL_BYTES`]）を超えた分は古いファイルから削除する。
+/// ファイルには`default_level`によらずINFO以上のログとエラーイベントを書き込む
+///
+/// ## Arguments
+/// * `app_name` - アプリケーション名（環境変数名とログファイル名に使用する）
+/// * `default_level` - 標準エラー出力の既定の出力レベル
+/// * `log_dir` - ログファイルを書き込むディレクトリ（存在しない場合は作成する）
+/// * `today` - ログファイル名の日付に使用する今日の日付を返す関数
+///
+/// ## Returns
+/// * 成功時 - `Ok(())`
+/// * 失敗時 - 環境変数の指定の形式が不正な場合、またはディレクトリを作成できない場合のAppError
+pub fn init_with_file(
+    app_name: &str,
+    default_level: LevelFilter,
+    log_dir: &Path,
+    today: impl Fn() -> NaiveDate + Send + 'static,
+) -> AppResult<()> {
+    let writer = RotatingFileWriter::new(log_dir, app_name)?.with_today(today);
+    install(app_name, default_level, Some(writer))
+}
+
+/// 標準エラー出力と、指定された場合はログファイルに書き出すロガーを登録する
+fn install(
+    app_name: &str,
+    default_level: LevelFilter,
+    file_writer: Option<RotatingFileWriter>,
+) -> AppResult<()> {
+    let directives = std::env::var(log_env_var(app_name))
+        .or_else(|_| std::env::var("RUST_LOG"))
+        .ok();
+
+    let console = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_target(false)
+        .without_time()
+        .with_filter(env_filter(default_level, directives.as_deref())?);
+    let file = match file_writer {
+        Some(writer) => Some(
+            tracing_subscriber::fmt::layer()
+                .with_writer(Mutex::new(writer))
+                .with_ansi(false)
+                .with_filter(parse_filter(
+                    default_level.max(LevelFilter::INFO),
+                    directives.as_deref(),
+                )?),
+        ),
+        None => None,
+    };
+    let _ = tracing_subscriber::registry()
+        .with(console)
+        .with(file)
+        .try_init();
+    Ok(())
+}
+
+/// 処理単位のスパンを作成する
+///
+/// スパン内で出力したログには処理名が付く
+///
+/// ## Arguments
+/// * `operation` - 処理名（例: `remote_work_start`）
+///
+/// ## Returns
+/// * INFOレベルのスパン
+pub fn operation_span(operation: &str) -> Span {
+    tracing::info_span!("operation", name = operation)
+}
+
+/// 処理単位のスパン内で処理を実行し、失敗した場合はエラーイベントを出力する
+///
+/// ## Arguments
+/// * `operation` - 処理名（例: `remote_work_start`）
+/// * `f` - 実行する処理
+///
+/// ## Returns
+/// * 処理の結果
+///
+/// ## Examples
+/// ```rust
+/// use share::{error::{app_error::AppError, kind::ErrorKind}, logging::in_span};
+///
+/// assert_eq!(in_span("sum", || Ok(1 + 1)).unwrap(), 2);
+/// assert!(in_span::<()>("fail", || Err(AppError::new(ErrorKind::NotFound))).is_err());
+/// ```
+pub fn in_span<T>(operation: &str, f: impl FnOnce() -> AppResult<T>) -> AppResult<T> {
+    let span = operation_span(operation);
+    let _entered = span.enter();
+    tracing::debug!("処理を開始します");
+    let result = f();
+    match &result {
+        Ok(_) => tracing::debug!("処理が完了しました"),
+        Err(e) => log_error(e),
+    }
+    result
+}
+
+/// エラーの種別・対処法・元のエラーを含むエラーイベントを出力する
+///
+/// ## Arguments
+/// * `error` - 出力するエラー
+pub fn log_error(error: &AppError) {
+    let sources = error_chain(error).join(" <- ");
+    tracing::error!(
+        target: ERROR_EVENT_TARGET,
+        code = error.code.unwrap_or_default(),
+        kind = error.kind.as_str(),
+        action = error.action.as_deref().unwrap_or_default(),
+        retryable = error.is_retryable(),
+        sources,
+        "{}",
+        error.message
+    );
+}
+
+/// エラーの元になったエラーを近いものから順に文字列にする
+///
+/// ## Arguments
+/// * `error` - 対象のエラー
+///
+/// ## Returns
+/// * 元のエラーのメッセージの一覧（元のエラーがない場合は空）
+///
+/// ## Examples
+/// ```rust
+/// use share::{error::{app_error::AppError, kind::ErrorKind}, logging::error_chain};
+/// use std::io;
+///
+/// let error = AppError::new(ErrorKind::InternalServerError)
+///     .with_source(io::Error::new(io::ErrorKind::NotFound, "file not found"));
+/// assert_eq!(error_chain(&error), vec!["file not found".to_string()]);
+/// ```
+pub fn error_chain(error: &(dyn Error + 'static)) -> Vec<String> {
+    let mut chain = Vec::new();
+    let mut source = error.source();
+    while let Some(e) = source {
+        chain.push(e.to_string());
+        source = e.source();
+    }
+    chain
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn test_env_filter() {
+        let quiet = env_filter(LevelFilter::INFO, None).unwrap().to_string();
+        let verbose = env_filter(LevelFilter::DEBUG, None).unwrap().to_string();
+        let directed = env_filter(LevelFilter::WARN, Some("mail_composer=trace"))
+            .unwrap()
+            .to_string();
+
+        assert!(quiet.contains("app_error=off"));
+        assert!(!verbose.contains("app_error"));
+        assert!(directed.contains("mail_composer=trace"));
+        assert!(directed.contains("warn"));
+    }
+
+    #[test]
+    fn test_error_chain() {
+        let inner = AppError::new(ErrorKind::NotFound)
+            .with_source(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+        let error = AppError::new(ErrorKind::InternalServerError).with_source(inner);
+
+        assert_eq!(
+            error_chain(&error),
+            vec![
+                "kind: Not Found, message: エラーが発生しました。".to_string(),
+                "no such file".to_string()
+            ]
+        );
+    }
+}
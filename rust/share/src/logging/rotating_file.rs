@@ -0,0 +1,226 @@
+use crate::{error::app_error::AppResult, utils::workspace::ensure_directory_exists};
+use chrono::{Local, NaiveDate};
+use std::{
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// ログディレクトリに保持するログファイルの合計サイズの既定値（10MiB）
+pub const DEFAULT_MAX_TOTAL_BYTES: u64 = 10 * 1024 * 1024;
+
+/// 日ごとにファイルを切り替えてログを書き込むライター
+///
+/// `<prefix>.<YYYY-MM-DD>.log`に追記し、日付が変わると新しいファイルに切り替える。
+/// 切り替え時にログファイルの合計サイズが上限を超えている場合は、古いファイルから削除する
+pub struct RotatingFileWriter {
+    dir: PathBuf,
+    prefix: String,
+    max_total_bytes: u64,
+    today: Box<dyn Fn() -> NaiveDate + Send>,
+    current: Option<(NaiveDate, File)>,
+}
+
+impl fmt::Debug for RotatingFileWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatingFileWriter")
+            .field("dir", &self.dir)
+            .field("prefix", &self.prefix)
+            .field("max_total_bytes", &self.max_total_bytes)
+            .field("current", &self.current)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RotatingFileWriter {
+    /// 新しいRotatingFileWriterを作成する
+    ///
+    /// ## Arguments
+    /// * `dir` - ログファイルを書き込むディレクトリ（存在しない場合は作成する）
+    /// * `prefix` - ログファイル名の接頭辞（例: `mail_composer`）
+    ///
+    /// ## Returns
+    /// * 成功時 - `Ok<RotatingFileWriter>`
+    /// * 失敗時 - ディレクトリを作成できない場合のAppError
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::logging::rotating_file::RotatingFileWriter;
+    /// use std::io::Write;
+    ///
+    /// let dir = std::env::temp_dir().join("share_rotating_file_doctest");
+    /// let mut writer = RotatingFileWriter::new(&dir, "doctest").unwrap();
+    /// writer.write_all(b"started\n").unwrap();
+    /// assert!(writer.current_path().unwrap().exists());
+    /// ```
+    pub fn new(dir: impl Into<PathBuf>, prefix: impl Into<String>) -> AppResult<Self> {
+        let dir = dir.into();
+        ensure_directory_exists(&dir)?;
+        Ok(Self {
+            dir,
+            prefix: prefix.into(),
+            max_total_bytes: DEFAULT_MAX_TOTAL_BYTES,
+            today: Box::new(|| Local::now().date_naive()),
+            current: None,
+        })
+    }
+
+    /// 保持するログファイルの合計サイズの上限を指定する
+    ///
+    /// ## Arguments
+    /// * `max_total_bytes` - 合計サイズの上限（バイト、書き込み中のファイルは上限を超えても削除しない）
+    ///
+    /// ## Returns
+    /// * 上限が設定されたRotatingFileWriterのインスタンス
+    pub fn with_max_total_bytes(mut self, max_total_bytes: u64) -> Self {
+        self.max_total_bytes = max_total_bytes;
+        self
+    }
+
+    /// ログファイル名の日付を決める今日の日付の取得方法を指定する（既定では実行環境のローカル日付）
+    ///
+    /// ## Arguments
+    /// * `today` - 今日の日付を返す関数（例: 設定したタイムゾーンでの今日の日付）
+    ///
+    /// ## Returns
+    /// * 日付の取得方法が設定されたRotatingFileWriterのインスタンス
+    pub fn with_today(mut self, today: impl Fn() -> NaiveDate + Send + 'static) -> Self {
+        self.today = Box::new(today);
+        self
+    }
+
+    /// 書き込み中のログファイルのパスを取得する
+    ///
+    /// ## Returns
+    /// * ログファイルのパス（まだ書き込んでいない場合は`None`）
+    pub fn current_path(&self) -> Option<PathBuf> {
+        self.current.as_ref().map(|(date, _)| self.file_path(*date))
+    }
+
+    /// 指定日のログファイルのパスを取得する
+    fn file_path(&self, date: NaiveDate) -> PathBuf {
+        self.dir
+            .join(format!("{}.{}.log", self.prefix, date.format("%Y-%m-%d")))
+    }
+
+    /// 指定日のログファイルに切り替える（同じ日のファイルを開いている場合は何もしない）
+    fn rotate_to(&mut self, date: NaiveDate) -> io::Result<&mut File> {
+        if self
+            .current
+            .as_ref()
+            .is_none_or(|(opened, _)| *opened != date)
+        {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.file_path(date))?;
+            self.current = Some((date, file));
+            self.prune(&self.file_path(date))?;
+        }
+        Ok(&mut self.current.as_mut().expect("直前に開いている").1)
+    }
+
+    /// 合計サイズが上限を超えている分の古いログファイルを削除する
+    fn prune(&self, keep: &Path) -> io::Result<()> {
+        let mut files: Vec<(PathBuf, u64)> = fs::read_dir(&self.dir)?
+            .filter_map(Result::ok)
+            .filter(|entry| self.is_log_file(&entry.path()))
+            .filter_map(|entry| Some((entry.path(), entry.metadata().ok()?.len())))
+            .collect();
+        // ファイル名の日付の新しい順に残す
+        files.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let mut total = 0;
+        for (path, size) in files {
+            total += size;
+            if total > self.max_total_bytes && path != keep {
+                // 他のプロセスが先に削除した場合は削除済みとして扱う
+                match fs::remove_file(&path) {
+                    Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// このライターが書き込むログファイルかを判定する
+    fn is_log_file(&self, path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(&format!("{}.", self.prefix)))
+            .and_then(|rest| rest.strip_suffix(".log"))
+            .is_some_and(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok())
+    }
+
+    /// 指定日のログファイルに書き込む
+    fn write_on(&mut self, date: NaiveDate, buf: &[u8]) -> io::Result<usize> {
+        self.rotate_to(date)?.write(buf)
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let today = (self.today)();
+        self.write_on(today, buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.current {
+            Some((_, file)) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn test_rotate_and_prune() {
+        let dir = std::env::temp_dir().join(format!("share_rotating_file_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let date = |day| NaiveDate::from_ymd_opt(2024, 6, day).unwrap();
+        let mut writer = RotatingFileWriter::new(&dir, "app")
+            .unwrap()
+            .with_max_total_bytes(10);
+        fs::write(dir.join("other.txt"), "unrelated file").unwrap();
+
+        writer.write_on(date(1), b"day1-line\n").unwrap();
+        writer.write_on(date(1), b"day1-more\n").unwrap();
+        writer.write_on(date(2), b"day2\n").unwrap();
+        let day1 = dir.join("app.2024-06-01.log");
+        let day2 = dir.join("app.2024-06-02.log");
+        let day1_exists = day1.exists();
+        let day2_text = fs::read_to_string(&day2);
+
+        writer.write_on(date(3), b"day3-line\n").unwrap();
+        let day2_exists = day2.exists();
+        let other_exists = dir.join("other.txt").exists();
+        let current = writer.current_path();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(!day1_exists);
+        assert_eq!(day2_text.unwrap(), "day2\n");
+        assert!(day2_exists);
+        assert!(other_exists);
+        assert_eq!(current, Some(dir.join("app.2024-06-03.log")));
+    }
+
+    #[test]
+    fn test_write_uses_given_today() {
+        let dir = std::env::temp_dir().join(format!("share_rotating_today_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let mut writer = RotatingFileWriter::new(&dir, "app")
+            .unwrap()
+            .with_today(|| NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+
+        writer.write_all(b"line\n").unwrap();
+        let current = writer.current_path();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(current, Some(dir.join("app.2024-06-01.log")));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::utils::workspace::workspace_root;
+use std::path::{Path, PathBuf};
+
+/// アプリケーションが使用するディレクトリの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppDirectory {
+    /// 設定ファイルを配置するディレクトリ
+    Config,
+    /// 実行中に生成されるデータを配置するディレクトリ
+    Data,
+}
+
+/// プラットフォーム標準のアプリケーションディレクトリを返す
+///
+/// * Windows - 設定は`%APPDATA%\<app>`、データは`%LOCALAPPDATA%\<app>`
+/// * macOS - `~/Library/Application Support/<app>`
+/// * その他 - 設定は`$XDG_CONFIG_HOME/<app>`（既定は`~/.config/<app>`）、
+///   データは`$XDG_DATA_HOME/<app>`（既定は`~/.local/share/<app>`）
+///
+/// ## Arguments
+/// * `kind` - ディレクトリの種類
+/// * `app_name` - アプリケーション名
+///
+/// ## Returns
+/// * ディレクトリのパス（ホームディレクトリなどが特定できない場合は`None`）
+pub fn platform_directory(kind: AppDirectory, app_name: &str) -> Option<PathBuf> {
+    platform_directory_with(kind, app_name, std::env::consts::OS, |name| {
+        std::env::var_os(name).map(PathBuf::from)
+    })
+}
+
+/// アプリケーションのファイルのパスを解決する
+///
+/// 次の順で探索し、最初に見つかったパスを返す
+/// 1. プラットフォーム標準のディレクトリ内のファイル
+/// 2. ワークスペース内のファイル（ソースツリーから実行している場合の従来の配置）
+///
+/// どちらにも存在しない場合は、ワークスペース内で実行していればワークスペース内のパスを、
+/// それ以外の場合はプラットフォーム標準のディレクトリ内のパスを返す
+///
+/// ## Arguments
+/// * `kind` - ディレクトリの種類
+/// * `app_name` - アプリケーション名
+/// * `file_name` - ディレクトリ内のファイル名（空の場合はディレクトリ自体）
+/// * `workspace_fallback` - ワークスペースルートからの相対パスで表した従来の配置
+///
+/// ## Returns
+/// * 解決されたパス（ワークスペース内のパスの場合は絶対パス）
+pub fn resolve_app_path(
+    kind: AppDirectory,
+    app_name: &str,
+    file_name: impl AsRef<Path>,
+    workspace_fallback: impl AsRef<Path>,
+) -> PathBuf {
+    let platform_path = platform_directory(kind, app_name).map(|dir| dir.join(file_name));
+    if let Some(path) = &platform_path
+        && path.exists()
+    {
+        return path.clone();
+    }
+
+    match (workspace_root(), platform_path) {
+        (Ok(root), _) => root.join(workspace_fallback),
+        (Err(_), Some(path)) => path,
+        (Err(_), None) => workspace_fallback.as_ref().to_path_buf(),
+    }
+}
+
+/// OSと環境変数の参照関数を指定してプラットフォーム標準のディレクトリを返す
+fn platform_directory_with(
+    kind: AppDirectory,
+    app_name: &str,
+    os: &str,
+    env: impl Fn(&str) -> Option<PathBuf>,
+) -> Option<PathBuf> {
+    // 相対パスが設定されている場合は無視する（XDG Base Directory仕様に準拠）
+    let absolute_env = |name: &str| env(name).filter(|path| path.is_absolute());
+    let home = || absolute_env("HOME");
+
+    let base = match (os, kind) {
+        ("windows", AppDirectory::Config) => absolute_env("APPDATA"),
+        ("windows", AppDirectory::Data) => {
+            absolute_env("LOCALAPPDATA").or_else(|| absolute_env("APPDATA"))
+        }
+        ("macos", _) => home().map(|home| home.join("Library").join("Application Support")),
+        (_, AppDirectory::Config) => {
+            absolute_env("XDG_CONFIG_HOME").or_else(|| home().map(|home| home.join(".config")))
+        }
+        (_, AppDirectory::Data) => absolute_env("XDG_DATA_HOME")
+            .or_else(|| home().map(|home| home.join(".local").join("share"))),
+    }?;
+
+    Some(base.join(app_name))
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    fn env(name: &str) -> Option<PathBuf> {
+        match name {
+            "HOME" => Some(PathBuf::from("/home/user")),
+            "XDG_CONFIG_HOME" => Some(PathBuf::from("relative/config")),
+            "XDG_DATA_HOME" => Some(PathBuf::from("/data")),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn linux_directories() {
+        assert_eq!(
+            platform_directory_with(AppDirectory::Config, "app", "linux", env),
+            Some(PathBuf::from("/home/user/.config/app"))
+        );
+        assert_eq!(
+            platform_directory_with(AppDirectory::Data, "app", "linux", env),
+            Some(PathBuf::from("/data/app"))
+        );
+    }
+
+    #[test]
+    fn macos_directories() {
+        assert_eq!(
+            platform_directory_with(AppDirectory::Data, "app", "macos", env),
+            Some(PathBuf::from("/home/user/Library/Application Support/app"))
+        );
+    }
+
+    #[test]
+    fn missing_home() {
+        assert_eq!(
+            platform_directory_with(AppDirectory::Config, "app", "linux", |_| None),
+            None
+        );
+    }
+
+    #[test]
+    fn resolve_falls_back_to_workspace() {
+        let path = resolve_app_path(
+            AppDirectory::Config,
+            "share_test_app_that_does_not_exist",
+            "app.json",
+            "rust/share/Cargo.toml",
+        );
+        assert_eq!(
+            path,
+            workspace_root().unwrap().join("rust/share/Cargo.toml")
+        );
+    }
+}
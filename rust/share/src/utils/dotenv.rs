@@ -0,0 +1,253 @@
+use crate::{
+    error::{
+        app_error::{AppError, AppResult},
+        kind::ErrorKind,
+    },
+    utils::{
+        dirs::{AppDirectory, platform_directory},
+        workspace::workspace_root,
+    },
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// `.env`ファイルの内容を解析する
+///
+/// * `#`で始まる行と空行は無視する
+/// * `export KEY=VALUE`の`export`は省略可能
+/// * `"..."`内では`\n`、`\t`、`\"`、`\\`をエスケープとして扱う
+/// * `'...'`内は記述どおりの値とする
+/// * 引用符のない値は、` #`以降をコメントとして除く
+///
+/// ## Arguments
+/// * `text` - `.env`ファイルの内容
+///
+/// ## Returns
+/// * 成功時 - 記述順の`(変数名, 値)`の一覧
+/// * 失敗時 - 形式が正しくない行がある場合のAppError（行番号を含む）
+///
+/// ## Examples
+/// ```rust
+/// use share::utils::dotenv::parse_dotenv;
+///
+/// let entries = parse_dotenv("# ローカル設定\nexport MAIL_COMPOSER_FROM=\"山田 太郎\"\n").unwrap();
+/// assert_eq!(entries, vec![("MAIL_COMPOSER_FROM".to_string(), "山田 太郎".to_string())]);
+/// ```
+pub fn parse_dotenv(text: &str) -> AppResult<Vec<(String, String)>> {
+    let mut entries = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line
+            .strip_prefix("export ")
+            .map(str::trim_start)
+            .unwrap_or(line);
+
+        let invalid = |reason: &str| {
+            AppError::new(ErrorKind::BadRequest)
+                .with_code("SH-ENV-001")
+                .with_message(format!(".envの{}行目が不正です。{reason}", index + 1))
+                .with_action("`KEY=VALUE`の形式で記述してください。")
+        };
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(invalid("`=`がありません。"));
+        };
+        let key = key.trim();
+        let valid_key = key
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_key {
+            return Err(invalid("変数名には英数字と`_`のみを使用してください。"));
+        }
+        let value = parse_value(value.trim()).ok_or_else(|| invalid("引用符が閉じていません。"))?;
+        entries.push((key.to_string(), value));
+    }
+    Ok(entries)
+}
+
+/// `.env`の値の部分を解析する（引用符が閉じていない場合は`None`）
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(rest) = value.strip_prefix('"') {
+        let mut parsed = String::new();
+        let mut chars = rest.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return Some(parsed),
+                '\\' => match chars.next()? {
+                    'n' => parsed.push('\n'),
+                    't' => parsed.push('\t'),
+                    'r' => parsed.push('\r'),
+                    escaped => parsed.push(escaped),
+                },
+                c => parsed.push(c),
+            }
+        }
+        None
+    } else if let Some(rest) = value.strip_prefix('\'') {
+        rest.find('\'').map(|end| rest[..end].to_string())
+    } else {
+        let value = value.find(" #").map_or(value, |end| &value[..end]);
+        Some(value.trim_end().to_string())
+    }
+}
+
+/// `.env`ファイルを探索する場所の一覧を返す（優先度の高い順）
+///
+/// 1. ワークスペースルートの`.env`（ソースツリーから実行している場合）
+/// 2. プラットフォーム標準の設定ディレクトリ内の`.env`（例: `~/.config/<app>/.env`）
+///
+/// ## Arguments
+/// * `app_name` - アプリケーション名
+///
+/// ## Returns
+/// * `.env`ファイルのパスの一覧（存在しないファイルも含む）
+pub fn dotenv_paths(app_name: &str) -> Vec<PathBuf> {
+    workspace_root()
+        .ok()
+        .into_iter()
+        .chain(platform_directory(AppDirectory::Config, app_name))
+        .map(|dir| dir.join(".env"))
+        .collect()
+}
+
+/// `.env`ファイルの変数をプロセスの環境変数に設定する
+///
+/// 既に設定されている環境変数は上書きしない。ファイルが存在しない場合は何もしない
+///
+/// ## Arguments
+/// * `path` - `.env`ファイルのパス
+///
+/// ## Returns
+/// * 成功時 - 新たに設定した変数名の一覧
+/// * 失敗時 - 読み込みまたは解析に失敗した場合のAppError
+///
+/// ## Safety
+/// * 環境変数を変更するため、他のスレッドを起動する前（`main`の先頭など）に呼び出すこと
+pub unsafe fn load_dotenv(path: &Path) -> AppResult<Vec<String>> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let text = fs::read_to_string(path).map_err(|e| {
+        AppError::new(ErrorKind::InternalServerError)
+            .with_code("SH-ENV-002")
+            .with_message(format!(
+                ".envファイルの読み込みに失敗しました。詳細: {}",
+                path.display()
+            ))
+            .with_action(".envファイルのアクセス権限を確認してください。")
+            .with_source(e)
+    })?;
+    let entries = parse_dotenv(&text).map_err(|e| {
+        let message = format!("{} ({})", e.message, path.display());
+        e.with_message(message)
+    })?;
+
+    let mut applied = Vec::new();
+    for (key, value) in entries {
+        if std::env::var_os(&key).is_none() {
+            // SAFETY: 呼び出し元がシングルスレッドであることを保証している
+            unsafe { std::env::set_var(&key, value) };
+            applied.push(key);
+        }
+    }
+    Ok(applied)
+}
+
+/// ワークスペースとプラットフォーム標準の設定ディレクトリにある`.env`を読み込む
+///
+/// 優先度の高いファイルから順に読み込むため、同じ変数は
+/// 実際の環境変数 → ワークスペースの`.env` → 設定ディレクトリの`.env`の順に優先される
+///
+/// ## Arguments
+/// * `app_name` - アプリケーション名
+///
+/// ## Returns
+/// * 成功時 - 読み込んだ`.env`ファイルのパスの一覧
+/// * 失敗時 - 読み込みまたは解析に失敗した場合のAppError
+///
+/// ## Safety
+/// * 環境変数を変更するため、他のスレッドを起動する前（`main`の先頭など）に呼び出すこと
+pub unsafe fn load_app_dotenv(app_name: &str) -> AppResult<Vec<PathBuf>> {
+    let mut loaded = Vec::new();
+    for path in dotenv_paths(app_name) {
+        if path.is_file() {
+            // SAFETY: 呼び出し元と同じ条件を引き継ぐ
+            unsafe { load_dotenv(&path) }?;
+            loaded.push(path);
+        }
+    }
+    Ok(loaded)
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn parse_entries() {
+        let text = r#"
+# コメント
+MAIL_COMPOSER_FROM=山田 太郎 # 差出人
+export MAIL_COMPOSER_DEPARTMENT = "開発部\n第1課"
+MAIL_COMPOSER_THUNDERBIRD_EXE='C:\Program Files\Mozilla Thunderbird\thunderbird.exe'
+EMPTY=
+"#;
+        assert_eq!(
+            parse_dotenv(text).unwrap(),
+            vec![
+                ("MAIL_COMPOSER_FROM".to_string(), "山田 太郎".to_string()),
+                (
+                    "MAIL_COMPOSER_DEPARTMENT".to_string(),
+                    "開発部\n第1課".to_string()
+                ),
+                (
+                    "MAIL_COMPOSER_THUNDERBIRD_EXE".to_string(),
+                    r"C:\Program Files\Mozilla Thunderbird\thunderbird.exe".to_string()
+                ),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = parse_dotenv("OK=1\nNO_EQUALS\n").unwrap_err();
+        assert!(error.message.starts_with(".envの2行目"));
+        assert!(parse_dotenv("1ST=value").is_err());
+        assert!(parse_dotenv("KEY=\"unterminated").is_err());
+    }
+
+    #[test]
+    fn load_keeps_existing_variables() {
+        let dir = std::env::temp_dir().join(format!("share_dotenv_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(".env");
+        fs::write(
+            &path,
+            "SHARE_DOTENV_TEST_PATH=from_dotenv\nSHARE_DOTENV_TEST_NEW=added\n",
+        )
+        .unwrap();
+
+        // SAFETY: このテスト専用の変数のみを変更する
+        let applied = unsafe {
+            std::env::set_var("SHARE_DOTENV_TEST_PATH", "from_env");
+            load_dotenv(&path)
+        };
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(applied.unwrap(), vec!["SHARE_DOTENV_TEST_NEW".to_string()]);
+        assert_eq!(std::env::var("SHARE_DOTENV_TEST_PATH").unwrap(), "from_env");
+        assert_eq!(std::env::var("SHARE_DOTENV_TEST_NEW").unwrap(), "added");
+        assert!(
+            unsafe { load_dotenv(&dir.join(".env")) }
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use serde_json::Value;
+
+/// 文字列中の`${VAR}`を環境変数の値に展開する
+///
+/// * `${VAR}` - 環境変数`VAR`の値（未設定の場合はエラー）
+/// * `${VAR:-default}` - 環境変数`VAR`の値（未設定または空の場合は`default`）
+/// * `$${` - `${`そのもの
+///
+/// ## Arguments
+/// * `input` - 展開対象の文字列
+///
+/// ## Returns
+/// * 成功時 - 展開後の文字列
+/// * 失敗時 - 未設定の環境変数を参照している場合、または`}`が閉じていない場合のAppError
+///
+/// ## Examples
+/// ```rust
+/// use share::utils::env_interpolation::expand_env_vars;
+/// let expanded = expand_env_vars("${MAIL_COMPOSER_UNSET_SAMPLE:-user}@example.com").unwrap();
+/// assert_eq!(expanded, "user@example.com");
+/// ```
+pub fn expand_env_vars(input: &str) -> AppResult<String> {
+    expand_with(input, |name| std::env::var(name).ok())
+}
+
+/// JSONの値に含まれる全ての文字列に対して環境変数を展開する
+///
+/// オブジェクトのキーは展開しない
+///
+/// ## Arguments
+/// * `value` - 展開対象のJSONの値
+///
+/// ## Returns
+/// * 成功時 - `Ok(())`
+/// * 失敗時 - 展開に失敗した場合のAppError
+pub fn expand_env_vars_in_json(value: &mut Value) -> AppResult<()> {
+    match value {
+        Value::String(s) => {
+            if s.contains('$') {
+                *s = expand_env_vars(s)?;
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                expand_env_vars_in_json(value)?;
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                expand_env_vars_in_json(value)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// 指定された参照関数を使用して`${VAR}`を展開する
+fn expand_with(input: &str, lookup: impl Fn(&str) -> Option<String>) -> AppResult<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('$') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        if let Some(escaped) = after.strip_prefix("${") {
+            output.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let Some(body) = after.strip_prefix('{') else {
+            output.push('$');
+            rest = after;
+            continue;
+        };
+        let Some(end) = body.find('}') else {
+            return Err(AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("SH-ENV-003")
+                .with_message(format!("環境変数の参照が閉じられていません。詳細: {input}"))
+                .with_action("`${VAR}`の形式で環境変数を参照してください。"));
+        };
+
+        let expression = &body[..end];
+        let (name, default) = match expression.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expression, None),
+        };
+        let value = lookup(name).filter(|value| default.is_none() || !value.is_empty());
+        match (value, default) {
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(default)) => output.push_str(default),
+            (None, None) => {
+                return Err(AppError::new(ErrorKind::UnprocessableEntity)
+                    .with_code("SH-ENV-004")
+                    .with_message(format!("環境変数'{name}'が設定されていません。"))
+                    .with_action(format!(
+                        "環境変数'{name}'を設定するか、`${{{name}:-既定値}}`の形式で既定値を指定してください。"
+                    )));
+            }
+        }
+        rest = &body[end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "USERNAME" => Some("yamada".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expand_variables() {
+        assert_eq!(
+            expand_with("${USERNAME}@example.com", lookup).unwrap(),
+            "yamada@example.com"
+        );
+        assert_eq!(
+            expand_with("${EMPTY:-guest} ${UNSET:-C:/Program Files}", lookup).unwrap(),
+            "guest C:/Program Files"
+        );
+        assert_eq!(
+            expand_with("$${USERNAME} costs $5", lookup).unwrap(),
+            "${USERNAME} costs $5"
+        );
+    }
+
+    #[test]
+    fn expand_errors() {
+        assert!(expand_with("${UNSET}", lookup).is_err());
+        assert!(expand_with("${USERNAME", lookup).is_err());
+    }
+
+    #[test]
+    fn expand_json() {
+        let mut value = serde_json::json!({
+            "name": "${MAIL_COMPOSER_UNSET_SAMPLE:-山田さん}",
+            "list": ["${MAIL_COMPOSER_UNSET_SAMPLE:-a}", 1, null],
+        });
+
+        expand_env_vars_in_json(&mut value).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!({ "name": "山田さん", "list": ["a", 1, null] })
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::error::{
+    app_error::{AppError, AppResult},
+    kind::ErrorKind,
+};
+use std::{
+    ffi::OsString,
+    fs::{File, OpenOptions},
+    path::{Path, PathBuf},
+};
+
+/// ファイルに対するOSのアドバイザリロックを保持するガード
+///
+/// 対象ファイルと同じディレクトリのロックファイル（例: `work_times.json.lock`）をロックするため、
+/// 対象ファイルを一時ファイルからの名前変更で置き換えてもロックは維持される。
+/// ガードを破棄するとロックを解放する
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+}
+
+impl FileLock {
+    /// 排他ロックを取得する（他のプロセスがロックを保持している間は待機する）
+    ///
+    /// ## Arguments
+    /// * `path` - ロックする対象ファイルのパス
+    ///
+    /// ## Returns
+    /// * 成功時 - ロックを保持する`FileLock`
+    /// * 失敗時 - ロックファイルを開けない、またはロックを取得できない場合のAppError
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use share::utils::file_lock::FileLock;
+    /// let path = std::env::temp_dir().join("share_file_lock_doctest.json");
+    /// let lock = FileLock::exclusive(&path).unwrap();
+    /// drop(lock);
+    /// ```
+    pub fn exclusive<P: AsRef<Path>>(path: P) -> AppResult<Self> {
+        let file = open_lock_file(path.as_ref())?;
+        file.lock().map_err(lock_error)?;
+        Ok(Self { file })
+    }
+
+    /// 共有ロックを取得する（他のプロセスが排他ロックを保持している間は待機する）
+    ///
+    /// ## Arguments
+    /// * `path` - ロックする対象ファイルのパス
+    ///
+    /// ## Returns
+    /// * 成功時 - ロックを保持する`FileLock`
+    /// * 失敗時 - ロックファイルを開けない、またはロックを取得できない場合のAppError
+    pub fn shared<P: AsRef<Path>>(path: P) -> AppResult<Self> {
+        let file = open_lock_file(path.as_ref())?;
+        file.lock_shared().map_err(lock_error)?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        // ファイルを閉じるとロックも解放されるため、解放の失敗は無視する
+        let _ = self.file.unlock();
+    }
+}
+
+/// 排他ロックを保持した状態で処理を実行する
+///
+/// ## Arguments
+/// * `path` - ロックする対象ファイルのパス
+/// * `f` - ロック中に実行する処理
+///
+/// ## Returns
+/// * 成功時 - 処理の戻り値
+/// * 失敗時 - ロックの取得または処理に失敗した場合のAppError
+pub fn with_exclusive_lock<P: AsRef<Path>, T>(
+    path: P,
+    f: impl FnOnce() -> AppResult<T>,
+) -> AppResult<T> {
+    let _lock = FileLock::exclusive(path)?;
+    f()
+}
+
+/// 共有ロックを保持した状態で処理を実行する
+///
+/// ## Arguments
+/// * `path` - ロックする対象ファイルのパス
+/// * `f` - ロック中に実行する処理
+///
+/// ## Returns
+/// * 成功時 - 処理の戻り値
+/// * 失敗時 - ロックの取得または処理に失敗した場合のAppError
+pub fn with_shared_lock<P: AsRef<Path>, T>(
+    path: P,
+    f: impl FnOnce() -> AppResult<T>,
+) -> AppResult<T> {
+    let _lock = FileLock::shared(path)?;
+    f()
+}
+
+/// 対象ファイルに対応するロックファイルのパスを返す
+///
+/// ## Arguments
+/// * `path` - ロックする対象ファイルのパス
+///
+/// ## Returns
+/// * 対象ファイル名に`.lock`を付けたパス
+pub fn lock_file_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().map(OsString::from).unwrap_or_default();
+    file_name.push(".lock");
+    path.with_file_name(file_name)
+}
+
+/// ロックファイルを開く（存在しない場合は作成する）
+fn open_lock_file(path: &Path) -> AppResult<File> {
+    let lock_path = lock_file_path(path);
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(|e| {
+            AppError::new(ErrorKind::InternalServerError)
+                .with_code("SH-FS-001")
+                .with_message(format!(
+                    "ロックファイルを開けませんでした。詳細: {}",
+                    lock_path.display()
+                ))
+                .with_action("ディレクトリの存在とアクセス権限を確認してください。")
+                .with_source(e)
+        })
+}
+
+/// ロックの取得に失敗した場合のエラーを作成する
+fn lock_error(e: std::io::Error) -> AppError {
+    AppError::new(ErrorKind::Conflict)
+        .with_code("SH-FS-002")
+        .with_message("ファイルのロックを取得できませんでした。")
+        .with_action("他に実行中のプロセスがないか確認してください。")
+        .with_source(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+        time::Duration,
+    };
+
+    #[test]
+    fn test_lock_file_path() {
+        assert_eq!(
+            lock_file_path(Path::new("data/work_times.json")),
+            PathBuf::from("data/work_times.json.lock")
+        );
+    }
+
+    #[test]
+    fn test_exclusive_lock_serializes_writers() {
+        let path =
+            std::env::temp_dir().join(format!("share_file_lock_{}.json", std::process::id()));
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let lock = FileLock::exclusive(&path).unwrap();
+        let waiter = {
+            let path = path.clone();
+            let order = Arc::clone(&order);
+            thread::spawn(move || {
+                with_exclusive_lock(&path, || {
+                    order.lock().unwrap().push("second");
+                    Ok(())
+                })
+            })
+        };
+        thread::sleep(Duration::from_millis(100));
+        order.lock().unwrap().push("first");
+        drop(lock);
+        waiter.join().unwrap().unwrap();
+        let _ = std::fs::remove_file(lock_file_path(&path));
+
+        assert_eq!(*order.lock().unwrap(), ["first", "second"]);
+    }
+}
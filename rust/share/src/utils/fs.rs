@@ -0,0 +1,136 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// 同じプロセス内で一時ファイル名が重複しないようにするための連番
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// ファイルを一時ファイルへの書き込みと名前変更で置き換える
+///
+/// 同じディレクトリの一時ファイルに書き込んで同期してから名前を変更するため、
+/// 書き込み中に異常終了したりディスクが一杯になったりしても、元のファイルは途中までの内容にならない
+///
+/// ## Arguments
+/// * `path` - 書き込み先のファイルパス
+/// * `bytes` - 書き込む内容
+///
+/// ## Returns
+/// * 成功時 - `Ok(())`
+/// * 失敗時 - 一時ファイルの書き込みまたは名前変更に失敗した場合の`io::Error`（一時ファイルは削除する）
+///
+/// ## Examples
+/// ```rust
+/// use share::utils::fs::write_atomic;
+///
+/// let dir = std::env::temp_dir().join("share_write_atomic_doctest");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("settings.json");
+/// write_atomic(&path, r#"{ "name": "山田" }"#).unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), r#"{ "name": "山田" }"#);
+/// ```
+pub fn write_atomic(path: impl AsRef<Path>, bytes: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref();
+    let temp_path = temp_file_path(path);
+    let result = (|| {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(bytes.as_ref())?;
+        file.sync_all()?;
+        drop(file);
+        replace_file(&temp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+        return result;
+    }
+    sync_parent_directory(path);
+    Ok(())
+}
+
+/// 書き込み先と同じディレクトリの一時ファイルのパスを返す（例: `.work_times.json.1234.0.tmp`）
+fn temp_file_path(path: &Path) -> PathBuf {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let counter = TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(".{file_name}.{}.{counter}.tmp", std::process::id()))
+}
+
+/// 一時ファイルの名前を変更して書き込み先を置き換える
+///
+/// Windowsでは既存のファイルも置き換えられるが、ウイルス対策ソフトや検索インデクサーが
+/// 書き込み先を一時的に開いているとアクセス拒否になるため、少し待って再試行する
+fn replace_file(from: &Path, to: &Path) -> io::Result<()> {
+    #[cfg(windows)]
+    {
+        const RETRIES: u32 = 5;
+        for attempt in 1..=RETRIES {
+            match fs::rename(from, to) {
+                Err(e) if e.kind() == io::ErrorKind::PermissionDenied && attempt < RETRIES => {
+                    std::thread::sleep(std::time::Duration::from_millis(20 * u64::from(attempt)));
+                }
+                result => return result,
+            }
+        }
+        unreachable!("最後の試行で結果を返している")
+    }
+    #[cfg(not(windows))]
+    fs::rename(from, to)
+}
+
+/// 名前変更をディスクに反映するため、親ディレクトリを同期する（失敗しても置き換えは完了している）
+fn sync_parent_directory(path: &Path) {
+    #[cfg(unix)]
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty())
+        && let Ok(dir) = File::open(parent)
+    {
+        let _ = dir.sync_all();
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic_replaces_file() {
+        let dir = std::env::temp_dir().join(format!("share_atomic_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("work_times.json");
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, "new").unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        let leftovers = fs::read_dir(&dir).unwrap().count();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(content, "new");
+        assert_eq!(leftovers, 1);
+    }
+
+    #[test]
+    fn test_failed_write_keeps_original() {
+        let dir = std::env::temp_dir().join(format!("share_atomic_fail_{}", std::process::id()));
+        let target = dir.join("config.json");
+        fs::create_dir_all(&target).unwrap();
+
+        // ディレクトリはファイルで置き換えられないため、名前変更に失敗する
+        let result = write_atomic(&target, "{}");
+        let leftovers = fs::read_dir(&dir).unwrap().count();
+        let still_dir = target.is_dir();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(result.is_err());
+        assert_eq!(leftovers, 1);
+        assert!(still_dir);
+    }
+
+    #[test]
+    fn test_temp_file_paths_are_unique() {
+        let path = Path::new("data").join("outbox.json");
+        assert_ne!(temp_file_path(&path), temp_file_path(&path));
+    }
+}
@@ -1 +1,6 @@
+pub mod dirs;
+pub mod dotenv;
+pub mod env_interpolation;
+pub mod file_lock;
+pub mod fs;
 pub mod workspace;
@@ -21,6 +21,7 @@ pub fn workspace_root() -> AppResult<PathBuf> {
         Ok(root)
     } else {
         Err(AppError::new(ErrorKind::NotFound)
+            .with_code("SH-FS-003")
             .with_message("ワークスペースのルートディレクトリが見つかりません。")
             .with_action(
                 "プロジェクト最上階層のCargo.tomlファイルにワークスペース設定があることを確認してください。",
@@ -50,6 +51,7 @@ pub fn find_workspace_root_from(start_dir: &Path) -> AppResult<PathBuf> {
         }
         if !dir.pop() {
             return Err(AppError::new(ErrorKind::NotFound)
+                .with_code("SH-FS-004")
                 .with_message("ワークスペースのルートディレクトリが見つかりません。")
                 .with_action(
                     "プロジェクト最上階層のCargo.tomlファイルにワークスペース設定があることを確認してください。",
@@ -71,6 +73,7 @@ pub fn find_workspace_root_from(start_dir: &Path) -> AppResult<PathBuf> {
 fn has_workspace_section(cargo_toml: &Path) -> AppResult<bool> {
     let contents = fs::read_to_string(cargo_toml).map_err(|e| {
         AppError::new(ErrorKind::InternalServerError)
+            .with_code("SH-FS-005")
             .with_message("Cargo.tomlファイルの読み込みに失敗しました。")
             .with_action("Cargo.tomlファイルの存在およびアクセス権限を確認してください。")
             .with_source(e)
@@ -98,6 +101,7 @@ pub fn ensure_directory_exists<P: AsRef<Path>>(path: P) -> AppResult<()> {
     if path.exists() {
         if !path.is_dir() {
             return Err(AppError::new(ErrorKind::InternalServerError)
+                .with_code("SH-FS-006")
                 .with_message("パスが存在しますが、ディレクトリではありません。")
                 .with_action("指定されたパスがファイルでないことを確認し、適切なディレクトリパスを指定してください。"));
         }
@@ -106,6 +110,7 @@ pub fn ensure_directory_exists<P: AsRef<Path>>(path: P) -> AppResult<()> {
 
     fs::create_dir_all(path).map_err(|e| {
         AppError::new(ErrorKind::InternalServerError)
+            .with_code("SH-FS-007")
             .with_message("ディレクトリの作成に失敗しました。")
             .with_action("ディレクトリの作成権限があることを確認し、親ディレクトリが存在することを確認してください。")
             .with_source(e)
@@ -114,6 +119,8 @@ pub fn ensure_directory_exists<P: AsRef<Path>>(path: P) -> AppResult<()> {
 
 /// ワークスペースルートからの相対パスを絶対パスに変換する
 ///
+/// 絶対パスが指定された場合は、ワークスペースルートを参照せずにそのまま返す
+///
 /// ## Arguments
 /// * `relative_path` - 変換対象の相対パス
 ///
@@ -121,6 +128,9 @@ pub fn ensure_directory_exists<P: AsRef<Path>>(path: P) -> AppResult<()> {
 /// * 成功時 - ワークスペースルートと結合された絶対パスの`PathBuf`
 /// * 失敗時 - ワークスペースルートの取得に失敗した場合のAppError
 pub fn workspace_path<P: AsRef<Path>>(relative_path: P) -> AppResult<PathBuf> {
+    if relative_path.as_ref().is_absolute() {
+        return Ok(relative_path.as_ref().to_path_buf());
+    }
     let root = workspace_root()?;
     Ok(root.join(&relative_path))
 }
@@ -142,6 +152,7 @@ pub fn relative_to_workspace<P: AsRef<Path>>(absolute_path: P) -> AppResult<Path
         .map(|p| p.to_path_buf())
         .map_err(|e| {
             AppError::new(ErrorKind::UnprocessableEntity)
+                .with_code("SH-FS-008")
                 .with_message("パスがワークスペース内に存在しません。")
                 .with_action("ワークスペース内の有効なパスを指定してください。")
                 .with_source(e)